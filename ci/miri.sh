@@ -20,6 +20,10 @@ git submodule update --init
 # Test our Miri logic
 rustup component add --toolchain nightly miri &2 > /dev/null || true
 
+# Ensure no pointers are created from integers, so downstream crates
+# can run their own tests under strict provenance.
+export MIRIFLAGS="${MIRIFLAGS} -Zmiri-strict-provenance"
+
 # these are our simple tests
 cargo +nightly miri test --all-features
 cargo +nightly miri test --features radix,format,write-integers,write-floats,parse-integers,parse-floats
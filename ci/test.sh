@@ -34,6 +34,9 @@ FEATURES=(
     "compact,radix"
     "format,power-of-two"
     "format,radix"
+    "safe"
+    "compact,safe"
+    "format,radix,safe"
 )
 
 check_error() {
@@ -44,6 +47,18 @@ check_error() {
     fi
 }
 
+check_safe() {
+    local feature=$1
+    local output
+    # lint only for unchecked indexing, with `ci/safe/clippy.toml`
+    output=$(CLIPPY_CONF_DIR=ci/safe cargo ${version} clippy $DEFAULT_FEATURES --features="${feature}" \
+        -- --cap-lints warn -W clippy::disallowed_methods 2>&1)
+    if grep "disallowed method" <<< "${output}" ; then
+        >&2 echo "The feature ${feature} can reach unchecked indexing..."
+        exit 1
+    fi
+}
+
 # Don't build the target, but ensure the syntax is correct.
 check() {
     if [ ! -z $NO_FEATURES ]; then
@@ -56,6 +71,13 @@ check() {
         cargo ${version} check --tests $check_features
     done
 
+    # ensure no unchecked indexing is reachable with the safe feature
+    for features in "${FEATURES[@]}"; do
+        if [[ "${features}" == *safe* ]]; then
+            check_safe "$REQUIRED_FEATURES,$features"
+        fi
+    done
+
     # Check each of our sub-crates compiles.
     cd lexical-parse-float
     cargo ${version} check --tests
@@ -69,8 +91,18 @@ check() {
     cd ../lexical-write-integer
     cargo ${version} check --tests
 
-    # ensure our partial features aren't allowed, as are unsupported features
+    # the C API and macro crates are excluded from the workspace
+    cd ../lexical-capi
+    cargo ${version} check --tests
+
+    cd ../lexical-macros
+    cargo ${version} check --tests
+
+    # ensure the minimal, decimal-only build from docs/MinimalBuild.md compiles
     cd ../lexical-core
+    cargo ${version} check --tests --no-default-features --features=parse-integers,write-integers,compact
+
+    # ensure our partial features aren't allowed, as are unsupported features
     partial=(parse write floats integers)
     for feature in "${partial[@]}"; do
         check_error "${feature}"
@@ -114,9 +146,12 @@ test() {
 
     # this fixes an issue where the lexical and lexical-core tests weren't being run
     cd lexical-core
+    cargo ${version} test --no-default-features --features=parse-integers,write-integers,compact --tests
     cargo ${version} test $test_features,format
     cargo ${version} test $test_features,radix
     cargo ${version} test $test_features,format,radix
+    cargo ${version} test $test_features,safe
+    cargo ${version} test $test_features,format,radix,safe
     cd ..
 
     # this fixes an issue where the lexical and lexical-core tests weren't being run
@@ -125,6 +160,13 @@ test() {
     cargo ${version} test $test_features,radix
     cargo ${version} test $test_features,format,radix
     cd ..
+
+    # test the crates excluded from the workspace with their own features
+    cd lexical-capi
+    cargo ${version} test
+    cd ../lexical-macros
+    cargo ${version} test
+    cd ..
 }
 
 # Dry-run bench target
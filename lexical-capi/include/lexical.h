@@ -0,0 +1,130 @@
+/**
+ *  C bindings for lexical, to- and from-string conversion routines.
+ *
+ *  Every function returns a `lexical_error`, where a `code` of
+ *  `LEXICAL_SUCCESS` indicates success. For parse errors, `index` is
+ *  the index of the byte where the error occurred. If the buffer is
+ *  too small to write a number, `index` is the number of bytes required.
+ *  The written bytes are not null-terminated.
+ */
+
+#ifndef LEXICAL_H_
+#define LEXICAL_H_
+
+#include <stddef.h>
+#include <stdint.h>
+
+#ifdef __cplusplus
+extern "C" {
+#endif
+
+/* Maximum number of bytes required to write any number. */
+#define LEXICAL_BUFFER_SIZE 64
+
+/* Error codes. */
+#define LEXICAL_SUCCESS 0
+#define LEXICAL_OVERFLOW 1
+#define LEXICAL_UNDERFLOW 2
+#define LEXICAL_INVALID_DIGIT 3
+#define LEXICAL_EMPTY 4
+#define LEXICAL_EMPTY_MANTISSA 5
+#define LEXICAL_EMPTY_EXPONENT 6
+#define LEXICAL_EMPTY_INTEGER 7
+#define LEXICAL_EMPTY_FRACTION 8
+#define LEXICAL_INVALID_POSITIVE_MANTISSA_SIGN 9
+#define LEXICAL_MISSING_MANTISSA_SIGN 10
+#define LEXICAL_INVALID_EXPONENT 11
+#define LEXICAL_INVALID_POSITIVE_EXPONENT_SIGN 12
+#define LEXICAL_MISSING_EXPONENT_SIGN 13
+#define LEXICAL_EXPONENT_WITHOUT_FRACTION 14
+#define LEXICAL_INVALID_LEADING_ZEROS 15
+#define LEXICAL_MISSING_EXPONENT 16
+#define LEXICAL_MISSING_SIGN 17
+#define LEXICAL_INVALID_POSITIVE_SIGN 18
+#define LEXICAL_INVALID_NEGATIVE_SIGN 19
+#define LEXICAL_BUFFER_TOO_SMALL 100
+#define LEXICAL_UNKNOWN 255
+
+/* Error code and index for a conversion. */
+typedef struct lexical_error {
+    uint32_t code;
+    size_t index;
+} lexical_error;
+
+/* i8 */
+lexical_error lexical_parse_i8(const uint8_t* bytes, size_t length, int8_t* value);
+lexical_error lexical_parse_cstr_i8(const char* string, int8_t* value);
+lexical_error lexical_parse_partial_i8(const uint8_t* bytes, size_t length, int8_t* value, size_t* count);
+lexical_error lexical_write_i8(int8_t value, uint8_t* bytes, size_t length, size_t* count);
+
+/* i16 */
+lexical_error lexical_parse_i16(const uint8_t* bytes, size_t length, int16_t* value);
+lexical_error lexical_parse_cstr_i16(const char* string, int16_t* value);
+lexical_error lexical_parse_partial_i16(const uint8_t* bytes, size_t length, int16_t* value, size_t* count);
+lexical_error lexical_write_i16(int16_t value, uint8_t* bytes, size_t length, size_t* count);
+
+/* i32 */
+lexical_error lexical_parse_i32(const uint8_t* bytes, size_t length, int32_t* value);
+lexical_error lexical_parse_cstr_i32(const char* string, int32_t* value);
+lexical_error lexical_parse_partial_i32(const uint8_t* bytes, size_t length, int32_t* value, size_t* count);
+lexical_error lexical_write_i32(int32_t value, uint8_t* bytes, size_t length, size_t* count);
+
+/* i64 */
+lexical_error lexical_parse_i64(const uint8_t* bytes, size_t length, int64_t* value);
+lexical_error lexical_parse_cstr_i64(const char* string, int64_t* value);
+lexical_error lexical_parse_partial_i64(const uint8_t* bytes, size_t length, int64_t* value, size_t* count);
+lexical_error lexical_write_i64(int64_t value, uint8_t* bytes, size_t length, size_t* count);
+
+/* isize */
+lexical_error lexical_parse_isize(const uint8_t* bytes, size_t length, ptrdiff_t* value);
+lexical_error lexical_parse_cstr_isize(const char* string, ptrdiff_t* value);
+lexical_error lexical_parse_partial_isize(const uint8_t* bytes, size_t length, ptrdiff_t* value, size_t* count);
+lexical_error lexical_write_isize(ptrdiff_t value, uint8_t* bytes, size_t length, size_t* count);
+
+/* u8 */
+lexical_error lexical_parse_u8(const uint8_t* bytes, size_t length, uint8_t* value);
+lexical_error lexical_parse_cstr_u8(const char* string, uint8_t* value);
+lexical_error lexical_parse_partial_u8(const uint8_t* bytes, size_t length, uint8_t* value, size_t* count);
+lexical_error lexical_write_u8(uint8_t value, uint8_t* bytes, size_t length, size_t* count);
+
+/* u16 */
+lexical_error lexical_parse_u16(const uint8_t* bytes, size_t length, uint16_t* value);
+lexical_error lexical_parse_cstr_u16(const char* string, uint16_t* value);
+lexical_error lexical_parse_partial_u16(const uint8_t* bytes, size_t length, uint16_t* value, size_t* count);
+lexical_error lexical_write_u16(uint16_t value, uint8_t* bytes, size_t length, size_t* count);
+
+/* u32 */
+lexical_error lexical_parse_u32(const uint8_t* bytes, size_t length, uint32_t* value);
+lexical_error lexical_parse_cstr_u32(const char* string, uint32_t* value);
+lexical_error lexical_parse_partial_u32(const uint8_t* bytes, size_t length, uint32_t* value, size_t* count);
+lexical_error lexical_write_u32(uint32_t value, uint8_t* bytes, size_t length, size_t* count);
+
+/* u64 */
+lexical_error lexical_parse_u64(const uint8_t* bytes, size_t length, uint64_t* value);
+lexical_error lexical_parse_cstr_u64(const char* string, uint64_t* value);
+lexical_error lexical_parse_partial_u64(const uint8_t* bytes, size_t length, uint64_t* value, size_t* count);
+lexical_error lexical_write_u64(uint64_t value, uint8_t* bytes, size_t length, size_t* count);
+
+/* usize */
+lexical_error lexical_parse_usize(const uint8_t* bytes, size_t length, size_t* value);
+lexical_error lexical_parse_cstr_usize(const char* string, size_t* value);
+lexical_error lexical_parse_partial_usize(const uint8_t* bytes, size_t length, size_t* value, size_t* count);
+lexical_error lexical_write_usize(size_t value, uint8_t* bytes, size_t length, size_t* count);
+
+/* f32 */
+lexical_error lexical_parse_f32(const uint8_t* bytes, size_t length, float* value);
+lexical_error lexical_parse_cstr_f32(const char* string, float* value);
+lexical_error lexical_parse_partial_f32(const uint8_t* bytes, size_t length, float* value, size_t* count);
+lexical_error lexical_write_f32(float value, uint8_t* bytes, size_t length, size_t* count);
+
+/* f64 */
+lexical_error lexical_parse_f64(const uint8_t* bytes, size_t length, double* value);
+lexical_error lexical_parse_cstr_f64(const char* string, double* value);
+lexical_error lexical_parse_partial_f64(const uint8_t* bytes, size_t length, double* value, size_t* count);
+lexical_error lexical_write_f64(double value, uint8_t* bytes, size_t length, size_t* count);
+
+#ifdef __cplusplus
+}
+#endif
+
+#endif  /* LEXICAL_H_ */
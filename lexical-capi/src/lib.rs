@@ -0,0 +1,338 @@
+//! C bindings for lexical, to- and from-string conversion routines.
+//!
+//! This exports `extern "C"` functions to parse and write numbers, with
+//! explicit buffer lengths and error codes, so the same correctly-rounded
+//! conversions can be used from C, C++, or Python (via `ctypes`). The
+//! matching declarations are in `include/lexical.h`.
+//!
+//! Every function returns a [`LexicalError`], where a `code` of
+//! [`LEXICAL_SUCCESS`] indicates success. For parse errors, `index`
+//! is the index of the byte where the error occurred. If the buffer
+//! is too small to write a number, `index` is the number of bytes
+//! required.
+//!
+//! # Exports
+//!
+//! For each of the `i8`, `i16`, `i32`, `i64`, `isize`, `u8`, `u16`,
+//! `u32`, `u64`, `usize`, `f32`, and `f64` types, the following functions
+//! are exported, replacing `{type}` with the type name:
+//!
+//! - `lexical_parse_{type}`: parse a complete number.
+//! - `lexical_parse_cstr_{type}`: parse a complete number from a
+//!   null-terminated string.
+//! - `lexical_parse_partial_{type}`: parse until the first invalid digit.
+//! - `lexical_write_{type}`: write a number to a buffer.
+//!
+//! # Example
+//!
+//! ```c
+//! #include "lexical.h"
+//!
+//! const char* string = "1.5e3";
+//! double value;
+//! lexical_error error = lexical_parse_f64((const uint8_t*)string, strlen(string), &value);
+//! if (error.code != LEXICAL_SUCCESS) {
+//!     // handle the error
+//! }
+//!
+//! uint8_t buffer[LEXICAL_BUFFER_SIZE];
+//! size_t count;
+//! error = lexical_write_f64(value, buffer, sizeof(buffer), &count);
+//! ```
+
+#![allow(unused_unsafe)]
+#![cfg_attr(feature = "lint", warn(unsafe_op_in_unsafe_fn))]
+#![deny(
+    clippy::doc_markdown,
+    clippy::unnecessary_safety_comment,
+    clippy::semicolon_if_nothing_returned,
+    clippy::unwrap_used,
+    clippy::as_underscore
+)]
+
+use core::slice;
+use std::ffi::CStr;
+use std::os::raw::c_char;
+
+use lexical_core::{BufferTooSmall, Error, FormattedSize};
+
+/// Maximum number of bytes required to write any number.
+///
+/// Numbers are always written in decimal, so this does not depend on
+/// the features enabled for `lexical-core`.
+pub const LEXICAL_BUFFER_SIZE: usize = <f64 as FormattedSize>::FORMATTED_SIZE_DECIMAL;
+
+// ERROR CODES
+// These values are stable, and must not be changed.
+
+/// The conversion was successful.
+pub const LEXICAL_SUCCESS: u32 = 0;
+/// Integral overflow occurred during numeric parsing.
+pub const LEXICAL_OVERFLOW: u32 = 1;
+/// Integral underflow occurred during numeric parsing.
+pub const LEXICAL_UNDERFLOW: u32 = 2;
+/// Invalid digit found before string termination.
+pub const LEXICAL_INVALID_DIGIT: u32 = 3;
+/// Empty byte array found.
+pub const LEXICAL_EMPTY: u32 = 4;
+/// Empty mantissa found.
+pub const LEXICAL_EMPTY_MANTISSA: u32 = 5;
+/// Empty exponent found.
+pub const LEXICAL_EMPTY_EXPONENT: u32 = 6;
+/// Empty integer found.
+pub const LEXICAL_EMPTY_INTEGER: u32 = 7;
+/// Empty fraction found.
+pub const LEXICAL_EMPTY_FRACTION: u32 = 8;
+/// Invalid positive mantissa sign was found.
+pub const LEXICAL_INVALID_POSITIVE_MANTISSA_SIGN: u32 = 9;
+/// Mantissa sign was required, but not found.
+pub const LEXICAL_MISSING_MANTISSA_SIGN: u32 = 10;
+/// Exponent was present but not allowed.
+pub const LEXICAL_INVALID_EXPONENT: u32 = 11;
+/// Invalid positive exponent sign was found.
+pub const LEXICAL_INVALID_POSITIVE_EXPONENT_SIGN: u32 = 12;
+/// Exponent sign was required, but not found.
+pub const LEXICAL_MISSING_EXPONENT_SIGN: u32 = 13;
+/// Exponent was present without fraction component.
+pub const LEXICAL_EXPONENT_WITHOUT_FRACTION: u32 = 14;
+/// Integer or integer component of float had invalid leading zeros.
+pub const LEXICAL_INVALID_LEADING_ZEROS: u32 = 15;
+/// No exponent with required exponent notation.
+pub const LEXICAL_MISSING_EXPONENT: u32 = 16;
+/// Integral sign was required, but not found.
+pub const LEXICAL_MISSING_SIGN: u32 = 17;
+/// Invalid positive sign for an integer was found.
+pub const LEXICAL_INVALID_POSITIVE_SIGN: u32 = 18;
+/// Invalid negative sign for an unsigned type was found.
+pub const LEXICAL_INVALID_NEGATIVE_SIGN: u32 = 19;
+/// The buffer is too small to write the number.
+pub const LEXICAL_BUFFER_TOO_SMALL: u32 = 100;
+/// Any other error, which cannot occur with the default formats.
+pub const LEXICAL_UNKNOWN: u32 = 255;
+
+/// C-compatible error type, with an error code and an index.
+#[repr(C)]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct LexicalError {
+    /// The error code, or [`LEXICAL_SUCCESS`].
+    pub code: u32,
+    /// The index of the error, or the number of bytes required for
+    /// [`LEXICAL_BUFFER_TOO_SMALL`].
+    pub index: usize,
+}
+
+impl LexicalError {
+    /// Error for a successful conversion.
+    pub const SUCCESS: Self = Self {
+        code: LEXICAL_SUCCESS,
+        index: 0,
+    };
+}
+
+impl From<Error> for LexicalError {
+    fn from(error: Error) -> Self {
+        let code = match error {
+            Error::Overflow(_) => LEXICAL_OVERFLOW,
+            Error::Underflow(_) => LEXICAL_UNDERFLOW,
+            Error::InvalidDigit(_) => LEXICAL_INVALID_DIGIT,
+            Error::Empty(_) => LEXICAL_EMPTY,
+            Error::EmptyMantissa(_) => LEXICAL_EMPTY_MANTISSA,
+            Error::EmptyExponent(_) => LEXICAL_EMPTY_EXPONENT,
+            Error::EmptyInteger(_) => LEXICAL_EMPTY_INTEGER,
+            Error::EmptyFraction(_) => LEXICAL_EMPTY_FRACTION,
+            Error::InvalidPositiveMantissaSign(_) => LEXICAL_INVALID_POSITIVE_MANTISSA_SIGN,
+            Error::MissingMantissaSign(_) => LEXICAL_MISSING_MANTISSA_SIGN,
+            Error::InvalidExponent(_) => LEXICAL_INVALID_EXPONENT,
+            Error::InvalidPositiveExponentSign(_) => LEXICAL_INVALID_POSITIVE_EXPONENT_SIGN,
+            Error::MissingExponentSign(_) => LEXICAL_MISSING_EXPONENT_SIGN,
+            Error::ExponentWithoutFraction(_) => LEXICAL_EXPONENT_WITHOUT_FRACTION,
+            Error::InvalidLeadingZeros(_) => LEXICAL_INVALID_LEADING_ZEROS,
+            Error::MissingExponent(_) => LEXICAL_MISSING_EXPONENT,
+            Error::MissingSign(_) => LEXICAL_MISSING_SIGN,
+            Error::InvalidPositiveSign(_) => LEXICAL_INVALID_POSITIVE_SIGN,
+            Error::InvalidNegativeSign(_) => LEXICAL_INVALID_NEGATIVE_SIGN,
+            Error::Success => LEXICAL_SUCCESS,
+            _ => LEXICAL_UNKNOWN,
+        };
+        Self {
+            code,
+            index: error.index().copied().unwrap_or(0),
+        }
+    }
+}
+
+impl From<BufferTooSmall> for LexicalError {
+    fn from(error: BufferTooSmall) -> Self {
+        Self {
+            code: LEXICAL_BUFFER_TOO_SMALL,
+            index: error.max_needed,
+        }
+    }
+}
+
+/// Create a byte slice from a pointer and length, allowing null for
+/// empty slices.
+///
+/// # Safety
+///
+/// Safe if `ptr` is null and `length` is 0, or if `ptr` is valid for
+/// reads of `length` bytes.
+#[inline(always)]
+unsafe fn as_slice<'a>(ptr: *const u8, length: usize) -> &'a [u8] {
+    if length == 0 {
+        &[]
+    } else {
+        // SAFETY: safe if the caller upholds the safety invariants.
+        unsafe { slice::from_raw_parts(ptr, length) }
+    }
+}
+
+/// Create a mutable byte slice from a pointer and length, allowing null
+/// for empty slices.
+///
+/// # Safety
+///
+/// Safe if `ptr` is null and `length` is 0, or if `ptr` is valid for
+/// reads and writes of `length` bytes.
+#[inline(always)]
+unsafe fn as_slice_mut<'a>(ptr: *mut u8, length: usize) -> &'a mut [u8] {
+    if length == 0 {
+        &mut []
+    } else {
+        // SAFETY: safe if the caller upholds the safety invariants.
+        unsafe { slice::from_raw_parts_mut(ptr, length) }
+    }
+}
+
+macro_rules! capi_impl {
+    ($($t:ident $parse:ident $parse_cstr:ident $parse_partial:ident $write:ident ; )*) => ($(
+        #[doc = concat!("Parse a complete `", stringify!($t), "` from a string.")]
+        ///
+        /// On success, the number is stored in `value`, otherwise, `value`
+        /// is not modified.
+        ///
+        /// # Safety
+        ///
+        /// Safe if `bytes` is valid for reads of `length` bytes (or
+        /// `length` is 0), and `value` is valid for writes.
+        #[no_mangle]
+        pub unsafe extern "C" fn $parse(
+            bytes: *const u8,
+            length: usize,
+            value: *mut $t,
+        ) -> LexicalError {
+            // SAFETY: safe if the caller upholds the safety invariants.
+            let bytes = unsafe { as_slice(bytes, length) };
+            match lexical_core::parse::<$t>(bytes) {
+                Ok(result) => {
+                    // SAFETY: safe if the caller upholds the safety invariants.
+                    unsafe { value.write(result) };
+                    LexicalError::SUCCESS
+                },
+                Err(error) => error.into(),
+            }
+        }
+
+        #[doc = concat!("Parse a complete `", stringify!($t), "` from a null-terminated string.")]
+        ///
+        /// This is identical to the parser with an explicit length, except
+        /// the string ends at the first null byte. On success, the number
+        /// is stored in `value`, otherwise, `value` is not modified.
+        ///
+        /// # Safety
+        ///
+        /// Safe if `string` is a valid pointer to a null-terminated string,
+        /// and `value` is valid for writes.
+        #[no_mangle]
+        pub unsafe extern "C" fn $parse_cstr(string: *const c_char, value: *mut $t) -> LexicalError {
+            // SAFETY: safe if the caller upholds the safety invariants.
+            let string = unsafe { CStr::from_ptr(string) };
+            match lexical_core::parse_cstr::<$t>(string) {
+                Ok(result) => {
+                    // SAFETY: safe if the caller upholds the safety invariants.
+                    unsafe { value.write(result) };
+                    LexicalError::SUCCESS
+                },
+                Err(error) => error.into(),
+            }
+        }
+
+        #[doc = concat!("Parse a `", stringify!($t), "` from a string, stopping at the first invalid digit.")]
+        ///
+        /// On success, the number is stored in `value`, and the number of
+        /// bytes processed is stored in `count`. Otherwise, `value` and
+        /// `count` are not modified.
+        ///
+        /// # Safety
+        ///
+        /// Safe if `bytes` is valid for reads of `length` bytes (or
+        /// `length` is 0), and `value` and `count` are valid for writes.
+        #[no_mangle]
+        pub unsafe extern "C" fn $parse_partial(
+            bytes: *const u8,
+            length: usize,
+            value: *mut $t,
+            count: *mut usize,
+        ) -> LexicalError {
+            // SAFETY: safe if the caller upholds the safety invariants.
+            let bytes = unsafe { as_slice(bytes, length) };
+            match lexical_core::parse_partial::<$t>(bytes) {
+                Ok((result, processed)) => {
+                    // SAFETY: safe if the caller upholds the safety invariants.
+                    unsafe {
+                        value.write(result);
+                        count.write(processed);
+                    }
+                    LexicalError::SUCCESS
+                },
+                Err(error) => error.into(),
+            }
+        }
+
+        #[doc = concat!("Write a `", stringify!($t), "` to a buffer.")]
+        ///
+        /// On success, the number of bytes written is stored in `count`.
+        /// If the buffer is too small, no bytes are written, `count` is
+        /// not modified, and the error index is the number of bytes
+        /// required. A buffer of `LEXICAL_BUFFER_SIZE` bytes is always
+        /// large enough. The written bytes are not null-terminated.
+        ///
+        /// # Safety
+        ///
+        /// Safe if `bytes` is valid for writes of `length` bytes (or
+        /// `length` is 0), and `count` is valid for writes.
+        #[no_mangle]
+        pub unsafe extern "C" fn $write(
+            value: $t,
+            bytes: *mut u8,
+            length: usize,
+            count: *mut usize,
+        ) -> LexicalError {
+            // SAFETY: safe if the caller upholds the safety invariants.
+            let bytes = unsafe { as_slice_mut(bytes, length) };
+            match lexical_core::try_write(value, bytes) {
+                Ok(written) => {
+                    // SAFETY: safe if the caller upholds the safety invariants.
+                    unsafe { count.write(written.len()) };
+                    LexicalError::SUCCESS
+                },
+                Err(error) => error.into(),
+            }
+        }
+    )*);
+}
+
+capi_impl! {
+    i8 lexical_parse_i8 lexical_parse_cstr_i8 lexical_parse_partial_i8 lexical_write_i8 ;
+    i16 lexical_parse_i16 lexical_parse_cstr_i16 lexical_parse_partial_i16 lexical_write_i16 ;
+    i32 lexical_parse_i32 lexical_parse_cstr_i32 lexical_parse_partial_i32 lexical_write_i32 ;
+    i64 lexical_parse_i64 lexical_parse_cstr_i64 lexical_parse_partial_i64 lexical_write_i64 ;
+    isize lexical_parse_isize lexical_parse_cstr_isize lexical_parse_partial_isize lexical_write_isize ;
+    u8 lexical_parse_u8 lexical_parse_cstr_u8 lexical_parse_partial_u8 lexical_write_u8 ;
+    u16 lexical_parse_u16 lexical_parse_cstr_u16 lexical_parse_partial_u16 lexical_write_u16 ;
+    u32 lexical_parse_u32 lexical_parse_cstr_u32 lexical_parse_partial_u32 lexical_write_u32 ;
+    u64 lexical_parse_u64 lexical_parse_cstr_u64 lexical_parse_partial_u64 lexical_write_u64 ;
+    usize lexical_parse_usize lexical_parse_cstr_usize lexical_parse_partial_usize lexical_write_usize ;
+    f32 lexical_parse_f32 lexical_parse_cstr_f32 lexical_parse_partial_f32 lexical_write_f32 ;
+    f64 lexical_parse_f64 lexical_parse_cstr_f64 lexical_parse_partial_f64 lexical_write_f64 ;
+}
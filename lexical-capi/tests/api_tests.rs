@@ -0,0 +1,129 @@
+use core::ptr;
+
+use lexical_capi::*;
+
+#[test]
+fn parse_test() {
+    let mut value = 0i32;
+    let bytes = b"-123";
+    let error = unsafe { lexical_parse_i32(bytes.as_ptr(), bytes.len(), &mut value) };
+    assert_eq!(error, LexicalError::SUCCESS);
+    assert_eq!(value, -123);
+
+    let mut value = 0.0f64;
+    let bytes = b"1.5e3";
+    let error = unsafe { lexical_parse_f64(bytes.as_ptr(), bytes.len(), &mut value) };
+    assert_eq!(error.code, LEXICAL_SUCCESS);
+    assert_eq!(value, 1500.0);
+}
+
+#[test]
+fn parse_error_test() {
+    let mut value = 7u8;
+    let bytes = b"256";
+    let error = unsafe { lexical_parse_u8(bytes.as_ptr(), bytes.len(), &mut value) };
+    assert_eq!(error, LexicalError {
+        code: LEXICAL_OVERFLOW,
+        index: 2,
+    });
+    assert_eq!(value, 7);
+
+    let bytes = b"12a";
+    let error = unsafe { lexical_parse_u8(bytes.as_ptr(), bytes.len(), &mut value) };
+    assert_eq!(error, LexicalError {
+        code: LEXICAL_INVALID_DIGIT,
+        index: 2,
+    });
+
+    let error = unsafe { lexical_parse_u8(ptr::null(), 0, &mut value) };
+    assert_eq!(error, LexicalError {
+        code: LEXICAL_EMPTY,
+        index: 0,
+    });
+
+    let mut value = 0.0f32;
+    let bytes = b"1e";
+    let error = unsafe { lexical_parse_f32(bytes.as_ptr(), bytes.len(), &mut value) };
+    assert_eq!(error.code, LEXICAL_EMPTY_EXPONENT);
+}
+
+#[test]
+fn parse_cstr_test() {
+    let mut value = 0i16;
+    let string = b"-123\0";
+    let error = unsafe { lexical_parse_cstr_i16(string.as_ptr().cast(), &mut value) };
+    assert_eq!(error, LexicalError::SUCCESS);
+    assert_eq!(value, -123);
+
+    // The string ends at the first null byte.
+    let mut value = 0.0f64;
+    let string = b"1.5e3\0junk";
+    let error = unsafe { lexical_parse_cstr_f64(string.as_ptr().cast(), &mut value) };
+    assert_eq!(error, LexicalError::SUCCESS);
+    assert_eq!(value, 1500.0);
+
+    let mut value = 7u8;
+    let string = b"12a\0";
+    let error = unsafe { lexical_parse_cstr_u8(string.as_ptr().cast(), &mut value) };
+    assert_eq!(error, LexicalError {
+        code: LEXICAL_INVALID_DIGIT,
+        index: 2,
+    });
+    let error = unsafe { lexical_parse_cstr_u8(b"\0".as_ptr().cast(), &mut value) };
+    assert_eq!(error.code, LEXICAL_EMPTY);
+    assert_eq!(value, 7);
+}
+
+#[test]
+fn parse_partial_test() {
+    let mut value = 0u64;
+    let mut count = 0usize;
+    let bytes = b"42,43";
+    let error =
+        unsafe { lexical_parse_partial_u64(bytes.as_ptr(), bytes.len(), &mut value, &mut count) };
+    assert_eq!(error, LexicalError::SUCCESS);
+    assert_eq!((value, count), (42, 2));
+}
+
+#[test]
+fn write_test() {
+    let mut buffer = [0u8; LEXICAL_BUFFER_SIZE];
+    let mut count = 0usize;
+    let error =
+        unsafe { lexical_write_i64(i64::MIN, buffer.as_mut_ptr(), buffer.len(), &mut count) };
+    assert_eq!(error, LexicalError::SUCCESS);
+    assert_eq!(&buffer[..count], b"-9223372036854775808");
+
+    let error = unsafe { lexical_write_f64(0.1, buffer.as_mut_ptr(), buffer.len(), &mut count) };
+    assert_eq!(error, LexicalError::SUCCESS);
+    assert_eq!(&buffer[..count], b"0.1");
+
+    count = 0;
+    let error = unsafe { lexical_write_u32(12345, buffer.as_mut_ptr(), 4, &mut count) };
+    assert_eq!(error, LexicalError {
+        code: LEXICAL_BUFFER_TOO_SMALL,
+        index: 5,
+    });
+    assert_eq!(count, 0);
+
+    let error = unsafe { lexical_write_u8(0, ptr::null_mut(), 0, &mut count) };
+    assert_eq!(error, LexicalError {
+        code: LEXICAL_BUFFER_TOO_SMALL,
+        index: 1,
+    });
+}
+
+#[test]
+fn header_test() {
+    // The header must be kept in sync with the exported constants.
+    let header = include_str!("../include/lexical.h");
+    assert!(header
+        .contains(&["#define LEXICAL_BUFFER_SIZE ", &LEXICAL_BUFFER_SIZE.to_string()].concat()));
+    assert!(header.contains(
+        &["#define LEXICAL_BUFFER_TOO_SMALL ", &LEXICAL_BUFFER_TOO_SMALL.to_string()].concat()
+    ));
+    assert!(header.contains(
+        &["#define LEXICAL_INVALID_NEGATIVE_SIGN ", &LEXICAL_INVALID_NEGATIVE_SIGN.to_string()]
+            .concat()
+    ));
+}
@@ -0,0 +1,204 @@
+//! Convert slices of numbers, for columnar data.
+
+#![cfg(any(feature = "parse", feature = "write"))]
+
+#[cfg(feature = "parse")]
+use core::fmt;
+
+#[cfg(feature = "parse")]
+use crate::{Error, FromLexical, FromLexicalWithOptions};
+#[cfg(feature = "write")]
+use crate::{ToLexical, ToLexicalWithOptions};
+
+/// Error from a batch conversion, with the index of the element that failed.
+#[cfg(feature = "parse")]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd)]
+pub struct BatchError {
+    /// Index of the element that failed to parse.
+    pub index: usize,
+    /// Error for the element.
+    pub error: Error,
+}
+
+#[cfg(feature = "parse")]
+impl fmt::Display for BatchError {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(formatter, "element {}: {}", self.index, self.error)
+    }
+}
+
+#[cfg(all(feature = "parse", feature = "std"))]
+impl std::error::Error for BatchError {
+}
+
+/// Write many numbers to string, back-to-back.
+///
+/// This is optimized for columnar data: the written bytes for each value
+/// are contiguous, and `offsets[i]` is set to the end of the value at
+/// `values[i]`, so it spans `offsets[i - 1]..offsets[i]`, or `0..offsets[0]`
+/// for the first value. Returns a subslice of the input buffer containing
+/// all the written bytes.
+///
+/// * `values`  - Numbers to serialize.
+/// * `bytes`   - Buffer to write numbers to.
+/// * `offsets` - Buffer to write the end of each number to.
+///
+/// # Panics
+///
+/// Panics if `offsets` is not the same length as `values`, or if the
+/// buffer may not be large enough to hold the serialized numbers. In
+/// order to ensure the function will not panic, provide a buffer with
+/// at least `values.len() * {integer}::FORMATTED_SIZE` elements.
+///
+/// # Example
+///
+/// ```
+/// # pub fn main() {
+/// #[cfg(feature = "write-integers")] {
+/// use lexical_core::FormattedSize;
+///
+/// let mut buffer = [0u8; 3 * u32::FORMATTED_SIZE];
+/// let mut offsets = [0usize; 3];
+/// let bytes = lexical_core::write_slice(&[1u32, 23, 456], &mut buffer, &mut offsets);
+/// assert_eq!(bytes, b"123456");
+/// assert_eq!(offsets, [1, 3, 6]);
+/// # }
+/// # }
+/// ```
+#[inline]
+#[cfg(feature = "write")]
+pub fn write_slice<'a, N: ToLexical>(
+    values: &[N],
+    bytes: &'a mut [u8],
+    offsets: &mut [usize],
+) -> &'a mut [u8] {
+    assert_eq!(values.len(), offsets.len());
+    let mut index = 0;
+    for (&value, offset) in values.iter().zip(offsets.iter_mut()) {
+        index += value.to_lexical(&mut bytes[index..]).len();
+        *offset = index;
+    }
+    &mut bytes[..index]
+}
+
+/// Write many numbers to string, back-to-back, with custom options.
+///
+/// This is optimized for columnar data: the written bytes for each value
+/// are contiguous, and `offsets[i]` is set to the end of the value at
+/// `values[i]`, so it spans `offsets[i - 1]..offsets[i]`, or `0..offsets[0]`
+/// for the first value. Returns a subslice of the input buffer containing
+/// all the written bytes.
+///
+/// * `FORMAT`  - Packed struct containing the number format.
+/// * `values`  - Numbers to serialize.
+/// * `bytes`   - Buffer to write numbers to.
+/// * `offsets` - Buffer to write the end of each number to.
+/// * `options` - Options to customize number writing.
+///
+/// # Panics
+///
+/// Panics if `offsets` is not the same length as `values`, or if the
+/// buffer may not be large enough to hold the serialized numbers. In
+/// order to ensure the function will not panic, provide a buffer with
+/// at least `values.len() * options.buffer_size::<N, FORMAT>()` elements.
+///
+/// If the provided `FORMAT` is not valid, the function may panic. Please
+/// ensure `is_valid()` is called prior to using the format, or checking
+/// its validity using a static assertion.
+#[inline]
+#[cfg(feature = "write")]
+pub fn write_slice_with_options<'a, N: ToLexicalWithOptions, const FORMAT: u128>(
+    values: &[N],
+    bytes: &'a mut [u8],
+    offsets: &mut [usize],
+    options: &N::Options,
+) -> &'a mut [u8] {
+    assert_eq!(values.len(), offsets.len());
+    let mut index = 0;
+    for (&value, offset) in values.iter().zip(offsets.iter_mut()) {
+        index += value.to_lexical_with_options::<FORMAT>(&mut bytes[index..], options).len();
+        *offset = index;
+    }
+    &mut bytes[..index]
+}
+
+/// Parse many complete numbers from strings.
+///
+/// This method parses each entire string into the output at the same
+/// index, returning the index and error of the first string with any
+/// invalid digits. Outputs before the failing index are parsed, while
+/// the remaining outputs are unchanged.
+///
+/// * `inputs`  - Byte slices containing numeric strings.
+/// * `outputs` - Buffer to store the parsed numbers.
+///
+/// # Panics
+///
+/// Panics if `outputs` is not the same length as `inputs`.
+///
+/// # Example
+///
+/// ```
+/// # pub fn main() {
+/// #[cfg(feature = "parse-integers")] {
+/// let mut outputs = [0u32; 3];
+/// let result = lexical_core::parse_slice(&["1", "23", "456"], &mut outputs);
+/// assert_eq!(result, Ok(()));
+/// assert_eq!(outputs, [1, 23, 456]);
+/// # }
+/// # }
+/// ```
+#[inline]
+#[cfg(feature = "parse")]
+pub fn parse_slice<N: FromLexical, Bytes: AsRef<[u8]>>(
+    inputs: &[Bytes],
+    outputs: &mut [N],
+) -> core::result::Result<(), BatchError> {
+    assert_eq!(inputs.len(), outputs.len());
+    for (index, (bytes, output)) in inputs.iter().zip(outputs.iter_mut()).enumerate() {
+        *output = N::from_lexical(bytes.as_ref()).map_err(|error| BatchError {
+            index,
+            error,
+        })?;
+    }
+    Ok(())
+}
+
+/// Parse many complete numbers from strings with custom parsing options.
+///
+/// This method parses each entire string into the output at the same
+/// index, returning the index and error of the first string with any
+/// invalid digits. Outputs before the failing index are parsed, while
+/// the remaining outputs are unchanged.
+///
+/// * `FORMAT`  - Packed struct containing the number format.
+/// * `inputs`  - Byte slices containing numeric strings.
+/// * `outputs` - Buffer to store the parsed numbers.
+/// * `options` - Options to customize number parsing.
+///
+/// # Panics
+///
+/// Panics if `outputs` is not the same length as `inputs`.
+#[inline]
+#[cfg(feature = "parse")]
+pub fn parse_slice_with_options<
+    N: FromLexicalWithOptions,
+    Bytes: AsRef<[u8]>,
+    const FORMAT: u128,
+>(
+    inputs: &[Bytes],
+    outputs: &mut [N],
+    options: &N::Options,
+) -> core::result::Result<(), BatchError> {
+    assert_eq!(inputs.len(), outputs.len());
+    for (index, (bytes, output)) in inputs.iter().zip(outputs.iter_mut()).enumerate() {
+        *output =
+            N::from_lexical_with_options::<FORMAT>(bytes.as_ref(), options).map_err(|error| {
+                BatchError {
+                    index,
+                    error,
+                }
+            })?;
+    }
+    Ok(())
+}
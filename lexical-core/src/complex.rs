@@ -0,0 +1,108 @@
+//! Parse and write complex numbers as [`num_complex::Complex`].
+
+#![cfg(feature = "num-complex")]
+
+#[cfg(feature = "parse-floats")]
+use lexical_parse_float::parse::ParseFloat;
+#[cfg(feature = "write-floats")]
+use lexical_write_float::ToLexicalWithOptions as ToFloatWithOptions;
+
+#[cfg(feature = "parse-floats")]
+use crate::{parse_complex, parse_complex_with_options, ParseFloatOptions, Result};
+#[cfg(feature = "write-floats")]
+use crate::{write_complex, write_complex_with_options, WriteFloatOptions};
+
+/// Parse a complete complex number, like `3+4i`, to a [`Complex`].
+///
+/// This is identical to [`parse_complex`], except the parts are returned
+/// as a [`Complex`].
+///
+/// # Examples
+///
+/// ```rust
+/// use num_complex::Complex;
+///
+/// assert_eq!(lexical_core::parse_num_complex::<f64>(b"3+4i"), Ok(Complex::new(3.0, 4.0)));
+/// ```
+///
+/// [`Complex`]: num_complex::Complex
+#[inline]
+#[cfg(feature = "parse-floats")]
+pub fn parse_num_complex<F: ParseFloat>(bytes: &[u8]) -> Result<num_complex::Complex<F>> {
+    let (re, im) = parse_complex(bytes)?;
+    Ok(num_complex::Complex::new(re, im))
+}
+
+/// Parse a complete complex number to a [`Complex`], using a custom format
+/// and options.
+///
+/// This is identical to [`parse_complex_with_options`], except the parts
+/// are returned as a [`Complex`].
+///
+/// [`Complex`]: num_complex::Complex
+#[inline]
+#[cfg(feature = "parse-floats")]
+pub fn parse_num_complex_with_options<F, const FORMAT: u128>(
+    bytes: &[u8],
+    options: &ParseFloatOptions,
+) -> Result<num_complex::Complex<F>>
+where
+    F: ParseFloat,
+{
+    let (re, im) = parse_complex_with_options::<F, FORMAT>(bytes, options)?;
+    Ok(num_complex::Complex::new(re, im))
+}
+
+/// Write a [`Complex`] to a buffer, like `3.0+4.0i`.
+///
+/// This is identical to [`write_complex`], including the required buffer
+/// size.
+///
+/// # Panics
+///
+/// Panics if the buffer is not large enough to hold both parts, like
+/// [`write_complex`].
+///
+/// # Examples
+///
+/// ```rust
+/// use num_complex::Complex;
+///
+/// let mut buffer = [0u8; 128];
+/// let bytes = lexical_core::write_num_complex(Complex::new(3.0f64, -4.0), &mut buffer);
+/// assert_eq!(bytes, b"3.0-4.0i");
+/// ```
+///
+/// [`Complex`]: num_complex::Complex
+#[inline]
+#[cfg(feature = "write-floats")]
+pub fn write_num_complex<F>(value: num_complex::Complex<F>, bytes: &mut [u8]) -> &mut [u8]
+where
+    F: ToFloatWithOptions<Options = WriteFloatOptions>,
+{
+    write_complex(value.re, value.im, bytes)
+}
+
+/// Write a [`Complex`] to a buffer, using a custom format and options.
+///
+/// This is identical to [`write_complex_with_options`], including the
+/// required buffer size.
+///
+/// # Panics
+///
+/// Panics if the buffer is not large enough to hold both parts, like
+/// [`write_complex_with_options`].
+///
+/// [`Complex`]: num_complex::Complex
+#[inline]
+#[cfg(feature = "write-floats")]
+pub fn write_num_complex_with_options<'a, F, const FORMAT: u128>(
+    value: num_complex::Complex<F>,
+    bytes: &'a mut [u8],
+    options: &WriteFloatOptions,
+) -> &'a mut [u8]
+where
+    F: ToFloatWithOptions<Options = WriteFloatOptions>,
+{
+    write_complex_with_options::<F, FORMAT>(value.re, value.im, bytes, options)
+}
@@ -0,0 +1,136 @@
+//! Parse numbers from C strings and NUL-terminated input.
+
+#![cfg(feature = "parse")]
+
+#[cfg(feature = "std")]
+use std::ffi::CStr;
+
+use crate::{FromLexical, FromLexicalWithOptions, Result};
+
+/// Parse complete number from a C string.
+///
+/// This is identical to [`parse`](crate::parse), except the bytes are the
+/// contents of `string`, without the NUL terminator. This requires the `std`
+/// feature.
+///
+/// * `string` - NUL-terminated C string containing a numeric string.
+///
+/// # Example
+///
+/// ```
+/// # pub fn main() {
+/// #[cfg(all(feature = "parse-floats", feature = "std"))] {
+/// use std::ffi::CStr;
+///
+/// let string = CStr::from_bytes_with_nul(b"3.5\0").unwrap();
+/// assert_eq!(lexical_core::parse_cstr::<f64>(string), Ok(3.5));
+/// # }
+/// # }
+/// ```
+#[inline]
+#[cfg(feature = "std")]
+pub fn parse_cstr<N: FromLexical>(string: &CStr) -> Result<N> {
+    N::from_lexical(string.to_bytes())
+}
+
+/// Parse complete number from a C string with custom parsing options.
+///
+/// This is identical to [`parse_with_options`](crate::parse_with_options),
+/// except the bytes are the contents of `string`, without the NUL terminator.
+/// This requires the `std` feature.
+///
+/// * `FORMAT`  - Packed struct containing the number format.
+/// * `string`  - NUL-terminated C string containing a numeric string.
+/// * `options` - Options to customize number parsing.
+///
+/// # Example
+///
+/// ```
+/// # pub fn main() {
+/// #[cfg(all(feature = "parse-integers", feature = "format", feature = "std"))] {
+/// use std::ffi::CStr;
+///
+/// const JSON: u128 = lexical_core::format::JSON;
+/// let options = lexical_core::ParseIntegerOptions::new();
+/// let string = CStr::from_bytes_with_nul(b"-12\0").unwrap();
+/// let result = lexical_core::parse_cstr_with_options::<i32, JSON>(string, &options);
+/// assert_eq!(result, Ok(-12));
+/// # }
+/// # }
+/// ```
+#[inline]
+#[cfg(feature = "std")]
+pub fn parse_cstr_with_options<N: FromLexicalWithOptions, const FORMAT: u128>(
+    string: &CStr,
+    options: &N::Options,
+) -> Result<N> {
+    N::from_lexical_with_options::<FORMAT>(string.to_bytes(), options)
+}
+
+/// Parse complete number from string, stopping at the first NUL byte.
+///
+/// This is identical to [`parse`](crate::parse), except only the bytes before
+/// the first NUL byte are parsed, or all the bytes if there is no NUL byte.
+/// This is useful for fixed-size buffers filled by C code, which may or may not
+/// be NUL-terminated. Error indexes are relative to the start of `bytes`.
+///
+/// * `bytes` - Byte slice containing a numeric string.
+///
+/// # Example
+///
+/// ```
+/// # pub fn main() {
+/// #[cfg(feature = "parse-integers")] {
+/// use lexical_core::Error;
+///
+/// assert_eq!(lexical_core::parse_until_nul::<u32>(b"15\0\0\0"), Ok(15));
+/// assert_eq!(lexical_core::parse_until_nul::<u32>(b"15\0junk"), Ok(15));
+/// assert_eq!(lexical_core::parse_until_nul::<u32>(b"15"), Ok(15));
+/// assert_eq!(lexical_core::parse_until_nul::<u32>(b"\0"), Err(Error::Empty(0)));
+/// # }
+/// # }
+/// ```
+#[inline]
+pub fn parse_until_nul<N: FromLexical>(bytes: &[u8]) -> Result<N> {
+    N::from_lexical(until_nul(bytes))
+}
+
+/// Parse complete number from string with custom parsing options,
+/// stopping at the first NUL byte.
+///
+/// This is identical to [`parse_with_options`](crate::parse_with_options),
+/// except only the bytes before the first NUL byte are parsed. See
+/// [`parse_until_nul`].
+///
+/// * `FORMAT`  - Packed struct containing the number format.
+/// * `bytes`   - Byte slice containing a numeric string.
+/// * `options` - Options to customize number parsing.
+///
+/// # Example
+///
+/// ```
+/// # pub fn main() {
+/// #[cfg(all(feature = "parse-floats", feature = "format"))] {
+/// const JSON: u128 = lexical_core::format::JSON;
+/// let options = lexical_core::ParseFloatOptions::new();
+/// let result = lexical_core::parse_until_nul_with_options::<f64, JSON>(b"1.5\0", &options);
+/// assert_eq!(result, Ok(1.5));
+/// # }
+/// # }
+/// ```
+#[inline]
+pub fn parse_until_nul_with_options<N: FromLexicalWithOptions, const FORMAT: u128>(
+    bytes: &[u8],
+    options: &N::Options,
+) -> Result<N> {
+    N::from_lexical_with_options::<FORMAT>(until_nul(bytes), options)
+}
+
+/// Get the bytes before the first NUL byte, or all the bytes.
+#[inline(always)]
+fn until_nul(bytes: &[u8]) -> &[u8] {
+    match bytes.iter().position(|&c| c == 0) {
+        Some(index) => &bytes[..index],
+        None => bytes,
+    }
+}
@@ -0,0 +1,179 @@
+//! Exact decimal representations of floats.
+
+#![cfg(all(feature = "std", any(feature = "parse-floats", feature = "write-floats")))]
+
+#[cfg(feature = "parse-floats")]
+use lexical_parse_float::FromLexical as FromFloat;
+#[cfg(feature = "write-floats")]
+use lexical_write_float::ToLexical as ToFloat;
+
+#[cfg(feature = "write-floats")]
+use crate::BUFFER_SIZE;
+#[cfg(feature = "parse-floats")]
+use crate::{parse_parts, Error, Result};
+
+/// Decimal representation of a finite float, between text and binary floats.
+///
+/// The value is `digits * 10^exponent`, negated if `sign` is set, where
+/// `digits` are the ASCII decimal digits of an integer, without leading or
+/// trailing zeros. Zero has no digits and an exponent of 0. Unlike
+/// [`RawNumber`](crate::RawNumber), every significant digit is kept, so
+/// converting to a float is correctly rounded, and a grammar other than
+/// lexical's may build the decimal from its own tokens.
+///
+/// # Example
+///
+/// ```
+/// # pub fn main() {
+/// #[cfg(all(feature = "parse-floats", feature = "write-floats"))] {
+/// use lexical_core::Decimal;
+///
+/// let decimal: Decimal = "-0.01250e5".parse().unwrap();
+/// assert_eq!(decimal, Decimal {
+///     sign: true,
+///     digits: b"125".to_vec(),
+///     exponent: 1,
+/// });
+/// assert_eq!(decimal.to_float::<f64>(), Ok(-1250.0));
+///
+/// let decimal = Decimal::from_float(0.1f32).unwrap();
+/// assert_eq!((decimal.digits.as_slice(), decimal.exponent), (&b"1"[..], -1));
+/// assert_eq!(Decimal::from_float(f64::NAN), None);
+/// # }
+/// # }
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Decimal {
+    /// If the number is negative.
+    pub sign: bool,
+    /// The significant ASCII digits, without leading or trailing zeros.
+    pub digits: Vec<u8>,
+    /// The decimal exponent, scaled to the digits.
+    pub exponent: i32,
+}
+
+impl Decimal {
+    /// Create a decimal from the shortest representation of a float that
+    /// round-trips.
+    ///
+    /// Returns `None` if the float is `NaN` or infinite.
+    #[cfg(feature = "write-floats")]
+    #[inline]
+    pub fn from_float<F: ToFloat>(float: F) -> Option<Self> {
+        let mut buffer = [0u8; BUFFER_SIZE];
+        let bytes = float.to_lexical(&mut buffer);
+        match bytes.iter().find(|&&c| c != b'-') {
+            Some(c) if c.is_ascii_digit() => Some(Self::from_standard(bytes)),
+            _ => None,
+        }
+    }
+
+    /// Round the decimal to the nearest float, with ties to even.
+    ///
+    /// Values that overflow or underflow round to infinity or zero. Returns
+    /// [`Error::InvalidDigit`], with the index within `digits`, if `digits`
+    /// contains a byte that is not a decimal digit.
+    #[cfg(feature = "parse-floats")]
+    #[allow(clippy::cast_possible_truncation)] // reason = "a digit is always < 10"
+    #[inline]
+    pub fn to_float<F: FromFloat>(&self) -> Result<F> {
+        if let Some(index) = self.digits.iter().position(|c| !c.is_ascii_digit()) {
+            return Err(Error::InvalidDigit(index));
+        }
+        let mut bytes = Vec::with_capacity(self.digits.len() + 13);
+        if self.sign {
+            bytes.push(b'-');
+        }
+        if self.digits.is_empty() {
+            bytes.push(b'0');
+        }
+        bytes.extend_from_slice(&self.digits);
+        bytes.push(b'e');
+        if self.exponent < 0 {
+            bytes.push(b'-');
+        }
+        let start = bytes.len();
+        let mut exponent = self.exponent.unsigned_abs();
+        loop {
+            bytes.push(b'0' + (exponent % 10) as u8);
+            exponent /= 10;
+            if exponent == 0 {
+                break;
+            }
+        }
+        bytes[start..].reverse();
+        F::from_lexical(&bytes)
+    }
+
+    /// Create a decimal from a valid, finite float in the standard format.
+    #[allow(clippy::cast_possible_truncation)] // reason = "exponent is clamped to i32"
+    fn from_standard(bytes: &[u8]) -> Self {
+        let (sign, bytes) = match bytes {
+            [b'-', rest @ ..] => (true, rest),
+            [b'+', rest @ ..] => (false, rest),
+            _ => (false, bytes),
+        };
+        let (mantissa, explicit) = match bytes.iter().position(|&c| c == b'e' || c == b'E') {
+            Some(index) => (&bytes[..index], &bytes[index + 1..]),
+            None => (bytes, &[][..]),
+        };
+
+        // Skip leading zeros, and scale the exponent by the fraction digits.
+        let mut digits = Vec::with_capacity(mantissa.len());
+        let mut exponent = 0_i64;
+        let mut is_fraction = false;
+        for &c in mantissa {
+            if c == b'.' {
+                is_fraction = true;
+                continue;
+            }
+            if c != b'0' || !digits.is_empty() {
+                digits.push(c);
+            }
+            if is_fraction {
+                exponent -= 1;
+            }
+        }
+        while digits.last() == Some(&b'0') {
+            digits.pop();
+            exponent += 1;
+        }
+        if digits.is_empty() {
+            exponent = 0;
+        } else {
+            let (is_negative, explicit) = match explicit {
+                [b'-', rest @ ..] => (true, rest),
+                [b'+', rest @ ..] => (false, rest),
+                _ => (false, explicit),
+            };
+            let mut value = 0_i64;
+            for &c in explicit {
+                value = value.saturating_mul(10).saturating_add(i64::from(c - b'0'));
+            }
+            if is_negative {
+                value = -value;
+            }
+            exponent = exponent.saturating_add(value);
+        }
+
+        Self {
+            sign,
+            digits,
+            exponent: exponent.clamp(i64::from(i32::MIN), i64::from(i32::MAX)) as i32,
+        }
+    }
+}
+
+#[cfg(feature = "parse-floats")]
+impl core::str::FromStr for Decimal {
+    type Err = Error;
+
+    /// Parse a complete, finite float in the standard format, keeping every
+    /// significant digit.
+    #[inline]
+    fn from_str(string: &str) -> Result<Self> {
+        let bytes = string.as_bytes();
+        parse_parts(bytes)?;
+        Ok(Self::from_standard(bytes))
+    }
+}
@@ -0,0 +1,137 @@
+//! Iterate over the bytes of a written number.
+
+#![cfg(feature = "write")]
+
+use core::iter::FusedIterator;
+
+use crate::{try_write_with_options, BufferTooSmall, ToLexical, ToLexicalWithOptions, BUFFER_SIZE};
+
+/// Iterator over the bytes of a written number.
+///
+/// This yields the bytes of the number one at a time, or in chunks with
+/// [`next_chunk`](Digits::next_chunk), for streaming encoders or
+/// fixed-width displays that consume the output incrementally. The
+/// number is written when the iterator is created, to a buffer of
+/// [`BUFFER_SIZE`] bytes owned by the iterator, so the caller does not
+/// need to provide an output buffer.
+///
+/// # Example
+///
+/// ```
+/// # pub fn main() {
+/// #[cfg(all(feature = "write-integers", feature = "write-floats"))] {
+/// use lexical_core::Digits;
+///
+/// let mut digits = Digits::new(-1234i32);
+/// assert_eq!(digits.len(), 5);
+/// assert_eq!(digits.next(), Some(b'-'));
+/// assert_eq!(digits.next_chunk(2), b"12");
+/// assert_eq!(digits.as_bytes(), b"34");
+/// assert_eq!(digits.collect::<Vec<u8>>(), b"34");
+///
+/// let digits = Digits::new(1.5f64);
+/// assert_eq!(digits.rev().collect::<Vec<u8>>(), b"5.1");
+/// # }
+/// # }
+/// ```
+#[derive(Clone, Debug)]
+pub struct Digits {
+    buffer: [u8; BUFFER_SIZE],
+    start: usize,
+    end: usize,
+}
+
+impl Digits {
+    /// Create an iterator over the bytes of a number, using the standard
+    /// format.
+    ///
+    /// * `value`   - Number to serialize.
+    #[inline]
+    pub fn new<N: ToLexical>(n: N) -> Self {
+        let mut buffer = [0u8; BUFFER_SIZE];
+        let end = n.to_lexical(&mut buffer).len();
+        Self {
+            buffer,
+            start: 0,
+            end,
+        }
+    }
+
+    /// Create an iterator over the bytes of a number, using a custom
+    /// format and options.
+    ///
+    /// Returns an error if the options may require more than
+    /// [`BUFFER_SIZE`] bytes, with the upper bound from
+    /// [`WriteOptions::buffer_size`](crate::WriteOptions::buffer_size).
+    ///
+    /// * `FORMAT`  - Packed struct containing the number format.
+    /// * `value`   - Number to serialize.
+    /// * `options` - Options to customize number writing.
+    ///
+    /// # Panics
+    ///
+    /// If the provided `FORMAT` is not valid, the function may panic. Please
+    /// ensure `is_valid()` is called prior to using the format, or checking
+    /// its validity using a static assertion.
+    #[inline]
+    pub fn with_options<N: ToLexicalWithOptions, const FORMAT: u128>(
+        n: N,
+        options: &N::Options,
+    ) -> core::result::Result<Self, BufferTooSmall> {
+        let mut buffer = [0u8; BUFFER_SIZE];
+        let end = try_write_with_options::<_, FORMAT>(n, &mut buffer, options)?.len();
+        Ok(Self {
+            buffer,
+            start: 0,
+            end,
+        })
+    }
+
+    /// Get the remaining bytes, without advancing the iterator.
+    #[inline(always)]
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.buffer[self.start..self.end]
+    }
+
+    /// Get the next chunk of up to `max` bytes, advancing the iterator.
+    ///
+    /// Returns an empty slice once all bytes have been consumed.
+    #[inline]
+    pub fn next_chunk(&mut self, max: usize) -> &[u8] {
+        let start = self.start;
+        self.start += max.min(self.end - start);
+        &self.buffer[start..self.start]
+    }
+}
+
+impl Iterator for Digits {
+    type Item = u8;
+
+    #[inline]
+    fn next(&mut self) -> Option<u8> {
+        let byte = self.as_bytes().first().copied()?;
+        self.start += 1;
+        Some(byte)
+    }
+
+    #[inline(always)]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.end - self.start;
+        (len, Some(len))
+    }
+}
+
+impl DoubleEndedIterator for Digits {
+    #[inline]
+    fn next_back(&mut self) -> Option<u8> {
+        let byte = self.as_bytes().last().copied()?;
+        self.end -= 1;
+        Some(byte)
+    }
+}
+
+impl ExactSizeIterator for Digits {
+}
+
+impl FusedIterator for Digits {
+}
@@ -0,0 +1,154 @@
+//! Format numbers with [`core::fmt`] without allocating.
+
+#![cfg(feature = "write")]
+
+use core::fmt;
+
+use crate::{ToLexical, ToLexicalWithOptions, WriteOptions, BUFFER_SIZE};
+
+/// Maximum number of bytes written by the formatting adapters with
+/// custom options.
+///
+/// This is always enough for the output with a custom radix, unless
+/// `min_significant_digits` or the exponent break points are large.
+pub const FMT_BUFFER_SIZE: usize = 1200;
+
+/// Convert the bytes for a serialized number to a string.
+#[inline(always)]
+fn bytes_to_str(bytes: &[u8]) -> core::result::Result<&str, fmt::Error> {
+    // The written numbers are always ASCII, unless the options contain
+    // invalid special strings, which were not validated.
+    core::str::from_utf8(bytes).map_err(|_| fmt::Error)
+}
+
+/// Write number to a [`fmt::Write`] without an intermediate allocation.
+///
+/// The number is written to a stack buffer and then passed to the writer
+/// as a single string slice.
+///
+/// * `value`   - Number to serialize.
+/// * `writer`  - Formatter or string to write the number to.
+///
+/// # Example
+///
+/// ```
+/// # pub fn main() {
+/// #[cfg(feature = "write-floats")] {
+/// let mut string = String::new();
+/// lexical_core::write_to_fmt(1.5f64, &mut string).unwrap();
+/// assert_eq!(string, "1.5");
+/// # }
+/// # }
+/// ```
+#[inline]
+pub fn write_to_fmt<N: ToLexical, W: fmt::Write + ?Sized>(n: N, writer: &mut W) -> fmt::Result {
+    let mut buffer = [0u8; BUFFER_SIZE];
+    writer.write_str(bytes_to_str(n.to_lexical(&mut buffer))?)
+}
+
+/// Write number to a [`fmt::Write`] with custom options without an
+/// intermediate allocation.
+///
+/// The number is written to a stack buffer of [`FMT_BUFFER_SIZE`] bytes
+/// and then passed to the writer as a single string slice.
+///
+/// * `FORMAT`  - Packed struct containing the number format.
+/// * `value`   - Number to serialize.
+/// * `writer`  - Formatter or string to write the number to.
+/// * `options` - Options to customize number writing.
+///
+/// # Errors
+///
+/// Returns an error if the writer fails, or if the options may require
+/// more than [`FMT_BUFFER_SIZE`] bytes.
+///
+/// # Panics
+///
+/// If the provided `FORMAT` is not valid, the function may panic. Please
+/// ensure `is_valid()` is called prior to using the format, or checking
+/// its validity using a static assertion.
+#[inline]
+pub fn write_with_options_to_fmt<N, W, const FORMAT: u128>(
+    n: N,
+    writer: &mut W,
+    options: &N::Options,
+) -> fmt::Result
+where
+    N: ToLexicalWithOptions,
+    W: fmt::Write + ?Sized,
+{
+    if options.buffer_size::<N, FORMAT>() > FMT_BUFFER_SIZE {
+        return Err(fmt::Error);
+    }
+    let mut buffer = [0u8; FMT_BUFFER_SIZE];
+    writer.write_str(bytes_to_str(n.to_lexical_with_options::<FORMAT>(&mut buffer, options))?)
+}
+
+/// Adapter to format a number with lexical using [`fmt::Display`].
+///
+/// This can be used with `write!` and `format_args!` without an
+/// intermediate allocation. The width, fill, and alignment from the
+/// format specifier are applied to the written number.
+///
+/// # Example
+///
+/// ```
+/// # pub fn main() {
+/// #[cfg(feature = "write-floats")] {
+/// use lexical_core::DisplayLexical;
+///
+/// assert_eq!(format!("[{}]", DisplayLexical(1e300f64)), "[1.0e300]");
+/// assert_eq!(format!("[{:>6}]", DisplayLexical(0.5f32)), "[   0.5]");
+/// # }
+/// # }
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, PartialOrd)]
+pub struct DisplayLexical<N: ToLexical>(pub N);
+
+impl<N: ToLexical> fmt::Display for DisplayLexical<N> {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut buffer = [0u8; BUFFER_SIZE];
+        formatter.pad(bytes_to_str(self.0.to_lexical(&mut buffer))?)
+    }
+}
+
+/// Adapter to format a number with lexical and custom options using
+/// [`fmt::Display`].
+///
+/// This can be used with `write!` and `format_args!` without an
+/// intermediate allocation. The width, fill, and alignment from the
+/// format specifier are applied to the written number. Formatting fails
+/// if the options may require more than [`FMT_BUFFER_SIZE`] bytes.
+///
+/// * `FORMAT`  - Packed struct containing the number format.
+#[derive(Debug)]
+pub struct DisplayLexicalWithOptions<'a, N: ToLexicalWithOptions, const FORMAT: u128> {
+    /// Number to serialize.
+    pub value: N,
+    /// Options to customize number writing.
+    pub options: &'a N::Options,
+}
+
+impl<'a, N: ToLexicalWithOptions, const FORMAT: u128> DisplayLexicalWithOptions<'a, N, FORMAT> {
+    /// Create a new adapter from the number and options.
+    #[inline(always)]
+    pub const fn new(value: N, options: &'a N::Options) -> Self {
+        Self {
+            value,
+            options,
+        }
+    }
+}
+
+impl<N: ToLexicalWithOptions, const FORMAT: u128> fmt::Display
+    for DisplayLexicalWithOptions<'_, N, FORMAT>
+{
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.options.buffer_size::<N, FORMAT>() > FMT_BUFFER_SIZE {
+            return Err(fmt::Error);
+        }
+        let mut buffer = [0u8; FMT_BUFFER_SIZE];
+        let bytes = self.value.to_lexical_with_options::<FORMAT>(&mut buffer, self.options);
+        formatter.pad(bytes_to_str(bytes)?)
+    }
+}
@@ -0,0 +1,262 @@
+//! Parse trimmed and quoted fields, and records, from delimited text.
+
+#![cfg(feature = "parse")]
+
+use crate::{BatchError, FromLexical, FromLexicalWithOptions, Result};
+
+/// Trim ASCII whitespace and a single pair of enclosing double quotes
+/// from a delimited field.
+#[inline]
+fn trim_field(field: &[u8]) -> &[u8] {
+    let field = trim_ascii_whitespace(field);
+    match field {
+        [b'"', inner @ .., b'"'] => trim_ascii_whitespace(inner),
+        _ => field,
+    }
+}
+
+/// Trim leading and trailing ASCII whitespace.
+#[inline]
+pub fn trim_ascii_whitespace(mut bytes: &[u8]) -> &[u8] {
+    while let [first, rest @ ..] = bytes {
+        if !first.is_ascii_whitespace() {
+            break;
+        }
+        bytes = rest;
+    }
+    while let [rest @ .., last] = bytes {
+        if !last.is_ascii_whitespace() {
+            break;
+        }
+        bytes = rest;
+    }
+    bytes
+}
+
+/// Parse a number from a delimited field, such as a CSV cell.
+///
+/// Leading and trailing ASCII whitespace, and a single pair of enclosing
+/// double quotes, are removed before parsing. A field that is empty after
+/// trimming is a null value, and returns `None`. Any error index is
+/// relative to the trimmed field.
+///
+/// * `field` - Byte slice containing the field.
+///
+/// # Errors
+///
+/// Returns an error if the trimmed field is not empty and is not a
+/// valid number.
+///
+/// # Example
+///
+/// ```
+/// # pub fn main() {
+/// #[cfg(feature = "parse-floats")] {
+/// assert_eq!(lexical_core::parse_field::<f64>(b" \"1.5\" "), Ok(Some(1.5)));
+/// assert_eq!(lexical_core::parse_field::<f64>(b"  "), Ok(None));
+/// assert!(lexical_core::parse_field::<f64>(b"1.5x").is_err());
+/// # }
+/// # }
+/// ```
+#[inline]
+pub fn parse_field<N: FromLexical>(field: &[u8]) -> Result<Option<N>> {
+    match trim_field(field) {
+        [] => Ok(None),
+        bytes => N::from_lexical(bytes).map(Some),
+    }
+}
+
+/// Parse a number from a delimited field, such as a CSV cell, with custom
+/// parsing options.
+///
+/// Leading and trailing ASCII whitespace, and a single pair of enclosing
+/// double quotes, are removed before parsing. A field that is empty after
+/// trimming is a null value, and returns `None`. Any error index is
+/// relative to the trimmed field.
+///
+/// * `FORMAT`  - Packed struct containing the number format.
+/// * `field`   - Byte slice containing the field.
+/// * `options` - Options to customize number parsing.
+///
+/// # Errors
+///
+/// Returns an error if the trimmed field is not empty and is not a
+/// valid number.
+#[inline]
+pub fn parse_field_with_options<N: FromLexicalWithOptions, const FORMAT: u128>(
+    field: &[u8],
+    options: &N::Options,
+) -> Result<Option<N>> {
+    match trim_field(field) {
+        [] => Ok(None),
+        bytes => N::from_lexical_with_options::<FORMAT>(bytes, options).map(Some),
+    }
+}
+
+/// Parse a number from a delimited field, such as a CSV cell, returning
+/// `default` for null values.
+///
+/// This is identical to [`parse_field`], except empty fields return
+/// `default`, for example, `f64::NAN` or `0`.
+///
+/// * `field`   - Byte slice containing the field.
+/// * `default` - Value for empty fields.
+///
+/// # Errors
+///
+/// Returns an error if the trimmed field is not empty and is not a
+/// valid number.
+///
+/// # Example
+///
+/// ```
+/// # pub fn main() {
+/// #[cfg(feature = "parse-floats")] {
+/// let value = lexical_core::parse_field_or(b"", f64::NAN).unwrap();
+/// assert!(value.is_nan());
+/// assert_eq!(lexical_core::parse_field_or(b"2.5\r", f64::NAN), Ok(2.5));
+/// # }
+/// # }
+/// ```
+#[inline]
+pub fn parse_field_or<N: FromLexical>(field: &[u8], default: N) -> Result<N> {
+    parse_field(field).map(|value| value.unwrap_or(default))
+}
+
+/// Parse a number from a delimited field, such as a CSV cell, with custom
+/// parsing options, returning `default` for null values.
+///
+/// This is identical to [`parse_field_with_options`], except empty fields
+/// return `default`, for example, `f64::NAN` or `0`.
+///
+/// * `FORMAT`  - Packed struct containing the number format.
+/// * `field`   - Byte slice containing the field.
+/// * `default` - Value for empty fields.
+/// * `options` - Options to customize number parsing.
+///
+/// # Errors
+///
+/// Returns an error if the trimmed field is not empty and is not a
+/// valid number.
+#[inline]
+pub fn parse_field_or_with_options<N: FromLexicalWithOptions, const FORMAT: u128>(
+    field: &[u8],
+    default: N,
+    options: &N::Options,
+) -> Result<N> {
+    parse_field_with_options::<N, FORMAT>(field, options).map(|value| value.unwrap_or(default))
+}
+
+/// Parse each field of a delimited record, such as a CSV row, with a
+/// parser for a single field.
+#[inline]
+fn parse_record_impl<N, F>(
+    record: &[u8],
+    delimiter: u8,
+    output: &mut [Option<N>],
+    mut parse: F,
+) -> core::result::Result<usize, BatchError>
+where
+    F: FnMut(&[u8]) -> Result<Option<N>>,
+{
+    let mut count = 0;
+    let mut parse_next = |field: &[u8]| {
+        if let Some(value) = output.get_mut(count) {
+            *value = parse(field).map_err(|error| BatchError {
+                index: count,
+                error,
+            })?;
+        }
+        count += 1;
+        Ok(())
+    };
+
+    // Delimiters inside double quotes are part of the field.
+    let mut start = 0;
+    let mut quoted = false;
+    for (index, &byte) in record.iter().enumerate() {
+        if byte == b'"' {
+            quoted = !quoted;
+        } else if byte == delimiter && !quoted {
+            parse_next(&record[start..index])?;
+            start = index + 1;
+        }
+    }
+    parse_next(&record[start..])?;
+    Ok(count)
+}
+
+/// Parse the fields of a delimited record, such as a CSV row, in a single
+/// pass.
+///
+/// The record is split on `delimiter`, except inside double quotes, and
+/// each field is parsed like [`parse_field`]: empty fields are null values
+/// and are written as `None`. The value of field `i` is written to
+/// `output[i]`. Returns the number of fields in the record, which may be
+/// compared to the expected number of columns. Fields past the end of
+/// `output` are counted but not parsed, and elements of `output` past the
+/// number of fields are unchanged. A trailing line terminator is ignored,
+/// since it is whitespace.
+///
+/// * `record`    - Byte slice containing the record.
+/// * `delimiter` - Byte separating the fields, such as `b','`.
+/// * `output`    - Buffer to write the parsed fields to.
+///
+/// # Errors
+///
+/// Returns an error with the column index of the first field that is not
+/// empty and is not a valid number. Any fields before it have already
+/// been written to `output`.
+///
+/// # Example
+///
+/// ```
+/// # pub fn main() {
+/// #[cfg(feature = "parse-floats")] {
+/// use lexical_core::{BatchError, Error};
+///
+/// let mut row = [None; 3];
+/// assert_eq!(lexical_core::parse_record::<f64>(b"1.5,,\"-2\"\r\n", b',', &mut row), Ok(3));
+/// assert_eq!(row, [Some(1.5), None, Some(-2.0)]);
+///
+/// let result = lexical_core::parse_record::<f64>(b"1\t2x\t3", b'\t', &mut row);
+/// assert_eq!(result, Err(BatchError { index: 1, error: Error::InvalidDigit(1) }));
+/// # }
+/// # }
+/// ```
+#[inline]
+pub fn parse_record<N: FromLexical>(
+    record: &[u8],
+    delimiter: u8,
+    output: &mut [Option<N>],
+) -> core::result::Result<usize, BatchError> {
+    parse_record_impl(record, delimiter, output, parse_field)
+}
+
+/// Parse the fields of a delimited record, such as a CSV row, in a single
+/// pass, with custom parsing options.
+///
+/// This is identical to [`parse_record`], except each field is parsed
+/// like [`parse_field_with_options`].
+///
+/// * `FORMAT`    - Packed struct containing the number format.
+/// * `record`    - Byte slice containing the record.
+/// * `delimiter` - Byte separating the fields, such as `b','`.
+/// * `output`    - Buffer to write the parsed fields to.
+/// * `options`   - Options to customize number parsing.
+///
+/// # Errors
+///
+/// Returns an error with the column index of the first field that is not
+/// empty and is not a valid number.
+#[inline]
+pub fn parse_record_with_options<N: FromLexicalWithOptions, const FORMAT: u128>(
+    record: &[u8],
+    delimiter: u8,
+    output: &mut [Option<N>],
+    options: &N::Options,
+) -> core::result::Result<usize, BatchError> {
+    parse_record_impl(record, delimiter, output, |field| {
+        parse_field_with_options::<N, FORMAT>(field, options)
+    })
+}
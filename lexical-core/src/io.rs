@@ -0,0 +1,92 @@
+//! Write numbers to an [`io::Write`](std::io::Write) without allocating.
+
+#![cfg(all(feature = "write", feature = "std"))]
+
+use crate::{
+    BufferTooSmall,
+    ToLexical,
+    ToLexicalWithOptions,
+    WriteOptions,
+    BUFFER_SIZE,
+    FMT_BUFFER_SIZE,
+};
+
+/// Write number to an [`io::Write`] without an intermediate allocation.
+///
+/// The number is written to a stack buffer and then passed to the writer
+/// in a single `write_all` call.
+///
+/// * `value`   - Number to serialize.
+/// * `writer`  - Writer to write the number to, such as a file or socket.
+///
+/// # Errors
+///
+/// Returns an error if the writer fails.
+///
+/// # Example
+///
+/// ```
+/// # pub fn main() {
+/// #[cfg(feature = "write-integers")] {
+/// let mut bytes = Vec::new();
+/// lexical_core::write_to_io(-15i32, &mut bytes).unwrap();
+/// assert_eq!(bytes, b"-15");
+/// # }
+/// # }
+/// ```
+///
+/// [`io::Write`]: std::io::Write
+#[inline]
+pub fn write_to_io<N: ToLexical, W: std::io::Write + ?Sized>(
+    n: N,
+    writer: &mut W,
+) -> std::io::Result<()> {
+    let mut buffer = [0u8; BUFFER_SIZE];
+    writer.write_all(n.to_lexical(&mut buffer))
+}
+
+/// Write number to an [`io::Write`] with custom options without an
+/// intermediate allocation.
+///
+/// The number is written to a stack buffer of [`FMT_BUFFER_SIZE`] bytes
+/// and then passed to the writer in a single `write_all` call.
+///
+/// * `FORMAT`  - Packed struct containing the number format.
+/// * `value`   - Number to serialize.
+/// * `writer`  - Writer to write the number to, such as a file or socket.
+/// * `options` - Options to customize number writing.
+///
+/// # Errors
+///
+/// Returns an error if the writer fails, or an error of kind
+/// [`io::ErrorKind::InvalidInput`] if the options may require more than
+/// [`FMT_BUFFER_SIZE`] bytes.
+///
+/// # Panics
+///
+/// If the provided `FORMAT` is not valid, the function may panic. Please
+/// ensure `is_valid()` is called prior to using the format, or checking
+/// its validity using a static assertion.
+///
+/// [`io::Write`]: std::io::Write
+/// [`io::ErrorKind::InvalidInput`]: std::io::ErrorKind::InvalidInput
+#[inline]
+pub fn write_with_options_to_io<N, W, const FORMAT: u128>(
+    n: N,
+    writer: &mut W,
+    options: &N::Options,
+) -> std::io::Result<()>
+where
+    N: ToLexicalWithOptions,
+    W: std::io::Write + ?Sized,
+{
+    let size = options.buffer_size::<N, FORMAT>();
+    if size > FMT_BUFFER_SIZE {
+        let error = BufferTooSmall {
+            max_needed: size,
+        };
+        return Err(std::io::Error::new(std::io::ErrorKind::InvalidInput, error));
+    }
+    let mut buffer = [0u8; FMT_BUFFER_SIZE];
+    writer.write_all(n.to_lexical_with_options::<FORMAT>(&mut buffer, options))
+}
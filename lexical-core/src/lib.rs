@@ -81,6 +81,32 @@
 #![cfg_attr(feature = "write", doc = "")]
 #![cfg_attr(feature = "write", doc = " - [`write`]")]
 #![cfg_attr(feature = "write", doc = " - [`write_with_options`]")]
+#![cfg_attr(feature = "write", doc = " - [`try_write`]")]
+#![cfg_attr(feature = "write", doc = " - [`try_write_with_options`]")]
+#![cfg_attr(feature = "write", doc = " - [`write_to_fmt`]")]
+#![cfg_attr(feature = "write", doc = " - [`write_with_options_to_fmt`]")]
+#![cfg_attr(feature = "write", doc = " - [`Digits`]")]
+#![cfg_attr(all(feature = "write", feature = "std"), doc = " - [`write_to_io`]")]
+#![cfg_attr(all(feature = "write", feature = "std"), doc = " - [`write_with_options_to_io`]")]
+#![cfg_attr(all(feature = "write", feature = "std"), doc = " - [`write_to_vec`]")]
+#![cfg_attr(all(feature = "write", feature = "std"), doc = " - [`write_with_options_to_vec`]")]
+#![cfg_attr(feature = "write", doc = " - [`write_slice`]")]
+#![cfg_attr(feature = "write", doc = " - [`write_slice_with_options`]")]
+#![cfg_attr(all(feature = "write", feature = "parallel"), doc = " - [`write_slice_parallel`]")]
+#![cfg_attr(
+    all(feature = "write", feature = "parallel"),
+    doc = " - [`write_slice_parallel_with_options`]"
+)]
+#![cfg_attr(feature = "write-floats", doc = " - [`write_complex`]")]
+#![cfg_attr(feature = "write-floats", doc = " - [`write_complex_with_options`]")]
+#![cfg_attr(
+    all(feature = "write-floats", feature = "num-complex"),
+    doc = " - [`write_num_complex`]"
+)]
+#![cfg_attr(
+    all(feature = "write-floats", feature = "num-complex"),
+    doc = " - [`write_num_complex_with_options`]"
+)]
 //!
 #![cfg_attr(feature = "write", doc = " **From String**")]
 #![cfg_attr(feature = "write", doc = "")]
@@ -88,6 +114,63 @@
 #![cfg_attr(feature = "parse", doc = " - [`parse_partial`]")]
 #![cfg_attr(feature = "parse", doc = " - [`parse_with_options`]")]
 #![cfg_attr(feature = "parse", doc = " - [`parse_partial_with_options`]")]
+#![cfg_attr(feature = "parse", doc = " - [`parse_or`]")]
+#![cfg_attr(feature = "parse", doc = " - [`parse_or_else`]")]
+#![cfg_attr(feature = "parse", doc = " - [`parse_optional`]")]
+#![cfg_attr(feature = "parse", doc = " - [`parse_optional_with_options`]")]
+#![cfg_attr(feature = "parse", doc = " - [`parse_in_range`]")]
+#![cfg_attr(feature = "parse", doc = " - [`parse_in_range_with_options`]")]
+#![cfg_attr(all(feature = "parse", feature = "std"), doc = " - [`parse_cstr`]")]
+#![cfg_attr(all(feature = "parse", feature = "std"), doc = " - [`parse_cstr_with_options`]")]
+#![cfg_attr(feature = "parse", doc = " - [`parse_until_nul`]")]
+#![cfg_attr(feature = "parse", doc = " - [`parse_until_nul_with_options`]")]
+#![cfg_attr(feature = "parse", doc = " - [`parse_slice`]")]
+#![cfg_attr(feature = "parse", doc = " - [`parse_slice_with_options`]")]
+#![cfg_attr(all(feature = "parse", feature = "parallel"), doc = " - [`parse_slice_parallel`]")]
+#![cfg_attr(
+    all(feature = "parse", feature = "parallel"),
+    doc = " - [`parse_slice_parallel_with_options`]"
+)]
+#![cfg_attr(feature = "parse", doc = " - [`parse_field`]")]
+#![cfg_attr(feature = "parse", doc = " - [`parse_field_with_options`]")]
+#![cfg_attr(feature = "parse", doc = " - [`parse_field_or`]")]
+#![cfg_attr(feature = "parse", doc = " - [`parse_field_or_with_options`]")]
+#![cfg_attr(feature = "parse", doc = " - [`parse_record`]")]
+#![cfg_attr(feature = "parse", doc = " - [`parse_record_with_options`]")]
+#![cfg_attr(feature = "parse", doc = " - [`parse_many`]")]
+#![cfg_attr(feature = "parse", doc = " - [`parse_many_with_options`]")]
+#![cfg_attr(feature = "parse-integers", doc = " - [`is_integer`]")]
+#![cfg_attr(feature = "parse-integers", doc = " - [`is_integer_with_options`]")]
+#![cfg_attr(feature = "parse-floats", doc = " - [`is_float`]")]
+#![cfg_attr(feature = "parse-floats", doc = " - [`is_float_with_options`]")]
+#![cfg_attr(feature = "parse-floats", doc = " - [`is_exact`]")]
+#![cfg_attr(feature = "parse-floats", doc = " - [`is_exact_with_options`]")]
+#![cfg_attr(feature = "parse-floats", doc = " - [`parse_parts`]")]
+#![cfg_attr(feature = "parse-floats", doc = " - [`parse_parts_with_options`]")]
+#![cfg_attr(feature = "parse-floats", doc = " - [`parse_ratio`]")]
+#![cfg_attr(feature = "parse-floats", doc = " - [`parse_ratio_parts`]")]
+#![cfg_attr(feature = "parse-floats", doc = " - [`parse_complex`]")]
+#![cfg_attr(feature = "parse-floats", doc = " - [`parse_complex_with_options`]")]
+#![cfg_attr(
+    all(feature = "parse-floats", feature = "num-complex"),
+    doc = " - [`parse_num_complex`]"
+)]
+#![cfg_attr(
+    all(feature = "parse-floats", feature = "num-complex"),
+    doc = " - [`parse_num_complex_with_options`]"
+)]
+#![cfg_attr(feature = "parse-floats", doc = " - [`parse_raw`]")]
+#![cfg_attr(feature = "parse-floats", doc = " - [`assemble`]")]
+#![cfg_attr(feature = "parse-floats", doc = " - [`parse_exactness`]")]
+#![cfg_attr(feature = "parse-floats", doc = " - [`parse_exactness_with_options`]")]
+#![cfg_attr(feature = "parse-floats", doc = " - [`parse_interval`]")]
+#![cfg_attr(feature = "parse-floats", doc = " - [`parse_interval_with_options`]")]
+#![cfg_attr(feature = "parse-floats", doc = " - [`parse_lossy`]")]
+#![cfg_attr(feature = "parse-floats", doc = " - [`parse_partial_lossy`]")]
+#![cfg_attr(feature = "parse-floats", doc = " - [`compare_halfway`]")]
+#![cfg_attr(feature = "parse-floats", doc = " - [`compare_halfway_with_options`]")]
+#![cfg_attr(feature = "parse-floats", doc = " - [`parse_bigint`]")]
+#![cfg_attr(feature = "parse-floats", doc = " - [`parse_bigint_with_options`]")]
 //!
 //! # Features
 //!
@@ -174,7 +257,9 @@
 //! This requires substantially more static storage than `power-of-two`,
 //! and increases compile times by a fair amount, but can be quite useful
 //! for esoteric programming languages which use duodecimal floats, for
-//! example.
+//! example. With `parse-floats`, this also adds `rebase` and
+//! `rebase_integer`, to convert numeric strings between radixes using
+//! exact arithmetic.
 //!
 //! #### compact
 //!
@@ -182,15 +267,54 @@
 //! the number of static tables, inlining, and generics used, drastically
 //! reducing the size of the generated binaries.
 //!
+//! #### simd
+//!
+//! Use SIMD instructions (SSE4.1 on x86, NEON on `aarch64`) to validate
+//! and convert 16 decimal digits at a time when parsing long numbers,
+//! with a scalar fallback. On x86, the CPU features are detected at
+//! runtime when the `std` feature is enabled. This requires the multi-digit
+//! optimizations to be enabled for integers.
+//!
+//! #### unicode
+//!
+//! Enable parsing integers from strings containing Unicode decimal digits,
+//! such as the Arabic-Indic `٠١٢` or fullwidth `０１２` digits, through
+//! the `FromLexicalUnicode` trait. Only decimal integers are supported.
+//!
+//! #### parallel
+//!
+//! Enable multi-threaded versions of the batch conversion APIs using
+//! [rayon](https://docs.rs/rayon), which split large slices across the
+//! global thread pool. This requires the `std` feature.
+//!
+//! #### num-complex
+//!
+//! Enable parsing and writing complex numbers as
+//! [`num_complex::Complex`](https://docs.rs/num-complex), in addition to
+//! the `(re, im)` pairs of [`parse_complex`] and [`write_complex`].
+//!
+//! #### proptest
+//!
+//! Implement [`Arbitrary`] for the options, digit alphabets, and number
+//! format builder, so downstream crates can property-test their code
+//! against random, valid configurations. This requires the `std` feature.
+//!
+//! [`Arbitrary`]: https://docs.rs/proptest/latest/proptest/arbitrary/trait.Arbitrary.html
+//!
 //! #### safe
 //!
-//! This replaces most unchecked indexing, required in cases where the
-//! compiler cannot elide the check, with checked indexing. However,
-//! it does not fully replace all unsafe behavior with safe behavior.
-//! To minimize the risk of undefined behavior and out-of-bounds reads/writers,
-//! extensive edge-cases, property-based tests, and fuzzing is done with both
-//! the safe feature enabled and disabled, with the tests verified by Miri
-//! and Valgrind.
+//! This replaces all unchecked indexing, required in cases where the
+//! compiler cannot elide the check, with checked indexing, and all raw
+//! pointer reads and writes with safe slice operations. The SIMD routines
+//! enabled by `simd` are also replaced with the scalar fallback. This is
+//! slower, but an out-of-bounds access panics rather than causing undefined
+//! behavior. The only remaining unsafe code converts the initialized bytes
+//! for `write_uninit`, since uninitialized buffers cannot be written to
+//! safely otherwise, and sets the x87 FPU precision on x86 targets without
+//! SSE2. To minimize the risk of undefined behavior and out-of-bounds
+//! reads/writes in the default algorithms, extensive edge-cases,
+//! property-based tests, and fuzzing is done with both the safe feature
+//! enabled and disabled, with the tests verified by Miri and Valgrind.
 //!
 //! # Configuration API
 //!
@@ -245,6 +369,11 @@
 //! In addition, pre-defined constants for each category of options may
 //! be found in their respective modules.
 //!
+//! Options for each conversion may also be registered under a name
+//! with `Format::register` and looked up later with `Format::get`,
+//! such as to select options from a configuration file. This requires
+//! the `std` feature.
+//!
 //! ## Example
 //!
 //! An example of creating your own options to parse European-style
@@ -365,19 +494,70 @@
     clippy::semicolon_inside_block,
 )]
 
+mod batch;
+mod complex;
+mod cstr;
+mod decimal;
+mod digits;
+mod display;
+mod field;
+mod io;
+mod many;
+mod optional;
+mod parallel;
+mod range;
+mod registry;
+mod try_write;
+mod uninit;
+mod wrappers;
+
 // Re-exports
 #[cfg(feature = "parse-floats")]
+pub use lexical_parse_float::{assemble, parse_raw, RawNumber};
+#[cfg(feature = "parse-floats")]
+pub use lexical_parse_float::{
+    compare_halfway,
+    compare_halfway_with_options,
+    parse_bigint,
+    parse_bigint_with_options,
+};
+#[cfg(feature = "parse-floats")]
+pub use lexical_parse_float::{is_exact, is_exact_with_options};
+#[cfg(feature = "parse-floats")]
+pub use lexical_parse_float::{is_float, is_float_with_options};
+#[cfg(feature = "parse-floats")]
 pub use lexical_parse_float::{
     options as parse_float_options,
     Options as ParseFloatOptions,
     OptionsBuilder as ParseFloatOptionsBuilder,
+    Parser as FloatParser,
 };
 #[cfg(feature = "parse-floats")]
+pub use lexical_parse_float::{parse_complex, parse_complex_with_options};
+#[cfg(feature = "parse-floats")]
+pub use lexical_parse_float::{parse_exactness, parse_exactness_with_options};
+#[cfg(feature = "parse-floats")]
+pub use lexical_parse_float::{parse_interval, parse_interval_with_options};
+#[cfg(feature = "parse-floats")]
+pub use lexical_parse_float::{parse_lossy, parse_partial_lossy};
+#[cfg(feature = "parse-floats")]
+pub use lexical_parse_float::{parse_parts, parse_parts_with_options, NumberParts};
+#[cfg(feature = "parse-floats")]
+pub use lexical_parse_float::{parse_ratio, parse_ratio_parts, Ratio};
+#[cfg(all(feature = "parse-floats", feature = "radix"))]
+pub use lexical_parse_float::{rebase, rebase_buffer_size, rebase_integer};
+#[cfg(feature = "parse-floats")]
 use lexical_parse_float::{
     FromLexical as FromFloat,
     FromLexicalWithOptions as FromFloatWithOptions,
 };
 #[cfg(feature = "parse-integers")]
+pub use lexical_parse_integer::parse_const;
+#[cfg(all(feature = "parse-integers", feature = "unicode"))]
+pub use lexical_parse_integer::FromLexicalUnicode;
+#[cfg(feature = "parse-integers")]
+pub use lexical_parse_integer::{is_integer, is_integer_with_options};
+#[cfg(feature = "parse-integers")]
 pub use lexical_parse_integer::{
     options as parse_integer_options,
     Options as ParseIntegerOptions,
@@ -388,19 +568,21 @@ use lexical_parse_integer::{
     FromLexical as FromInteger,
     FromLexicalWithOptions as FromIntegerWithOptions,
 };
+#[cfg(feature = "integers")]
+pub use lexical_util::alphabet::{self, DigitAlphabet};
 #[cfg(feature = "f16")]
 pub use lexical_util::bf16::bf16;
 #[cfg(feature = "write")]
 pub use lexical_util::constants::{FormattedSize, BUFFER_SIZE};
 #[cfg(feature = "parse")]
-pub use lexical_util::error::Error;
+pub use lexical_util::error::{CharacterClass, Error, ErrorKind};
 #[cfg(feature = "f16")]
 pub use lexical_util::f16::f16;
 pub use lexical_util::format::{self, format_error, format_is_valid, NumberFormatBuilder};
 #[cfg(feature = "parse")]
 pub use lexical_util::options::ParseOptions;
 #[cfg(feature = "write")]
-pub use lexical_util::options::WriteOptions;
+pub use lexical_util::options::{Alignment, WriteOptions};
 #[cfg(feature = "parse")]
 pub use lexical_util::result::Result;
 #[cfg(feature = "parse")]
@@ -409,14 +591,28 @@ use lexical_util::{from_lexical, from_lexical_with_options};
 use lexical_util::{to_lexical, to_lexical_with_options};
 #[cfg(feature = "write-floats")]
 pub use lexical_write_float::{
+    exponential_buffer_size,
+    fixed_buffer_size,
     options as write_float_options,
+    shortest_digits,
+    write_complex,
+    write_complex_with_options,
+    write_exponential,
+    write_exponential_with_rounding,
+    write_fixed,
+    write_fixed_with_rounding,
+    write_printf,
     Options as WriteFloatOptions,
     OptionsBuilder as WriteFloatOptionsBuilder,
+    PrintfSpec,
 };
 #[cfg(feature = "write-floats")]
 use lexical_write_float::{ToLexical as ToFloat, ToLexicalWithOptions as ToFloatWithOptions};
 #[cfg(feature = "write-integers")]
+pub use lexical_write_integer::write_const;
+#[cfg(feature = "write-integers")]
 pub use lexical_write_integer::{
+    digit_count,
     options as write_integer_options,
     Options as WriteIntegerOptions,
     OptionsBuilder as WriteIntegerOptionsBuilder,
@@ -424,6 +620,64 @@ pub use lexical_write_integer::{
 #[cfg(feature = "write-integers")]
 use lexical_write_integer::{ToLexical as ToInteger, ToLexicalWithOptions as ToIntegerWithOptions};
 
+#[cfg(feature = "parse")]
+pub use self::batch::{parse_slice, parse_slice_with_options, BatchError};
+#[cfg(feature = "write")]
+pub use self::batch::{write_slice, write_slice_with_options};
+#[cfg(all(feature = "parse-floats", feature = "num-complex"))]
+pub use self::complex::{parse_num_complex, parse_num_complex_with_options};
+#[cfg(all(feature = "write-floats", feature = "num-complex"))]
+pub use self::complex::{write_num_complex, write_num_complex_with_options};
+#[cfg(all(feature = "parse", feature = "std"))]
+pub use self::cstr::{parse_cstr, parse_cstr_with_options};
+#[cfg(feature = "parse")]
+pub use self::cstr::{parse_until_nul, parse_until_nul_with_options};
+#[cfg(all(feature = "std", any(feature = "parse-floats", feature = "write-floats")))]
+pub use self::decimal::Decimal;
+#[cfg(feature = "write")]
+pub use self::digits::Digits;
+#[cfg(feature = "write")]
+pub use self::display::{
+    write_to_fmt,
+    write_with_options_to_fmt,
+    DisplayLexical,
+    DisplayLexicalWithOptions,
+    FMT_BUFFER_SIZE,
+};
+#[cfg(feature = "parse")]
+pub use self::field::{
+    parse_field,
+    parse_field_or,
+    parse_field_or_with_options,
+    parse_field_with_options,
+    parse_record,
+    parse_record_with_options,
+};
+#[cfg(all(feature = "write", feature = "std"))]
+pub use self::io::{write_to_io, write_with_options_to_io};
+#[cfg(feature = "parse")]
+pub use self::many::{parse_many, parse_many_with_options, Delimiter, ParseMany};
+#[cfg(feature = "parse")]
+pub use self::optional::{parse_optional, parse_optional_with_options, parse_or, parse_or_else};
+#[cfg(all(feature = "parse", feature = "parallel"))]
+pub use self::parallel::{parse_slice_parallel, parse_slice_parallel_with_options};
+#[cfg(all(feature = "write", feature = "parallel"))]
+pub use self::parallel::{write_slice_parallel, write_slice_parallel_with_options};
+#[cfg(feature = "parse")]
+pub use self::range::{parse_in_range, parse_in_range_with_options};
+#[cfg(all(feature = "std", any(feature = "parse", feature = "write")))]
+pub use self::registry::Format;
+#[cfg(feature = "write")]
+pub use self::try_write::{try_write, try_write_with_options, BufferTooSmall};
+#[cfg(all(feature = "write", feature = "std"))]
+pub use self::uninit::{write_to_vec, write_with_options_to_vec};
+#[cfg(feature = "write")]
+pub use self::uninit::{write_uninit, write_with_options_uninit};
+#[cfg(all(feature = "parse-floats", feature = "format"))]
+pub use self::wrappers::{JsonF32, JsonF64};
+#[cfg(feature = "parse-floats")]
+pub use self::wrappers::{LossyF32, LossyF64};
+
 // API
 // ---
 
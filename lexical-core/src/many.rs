@@ -0,0 +1,230 @@
+//! Iterate over the numbers in a delimited buffer.
+
+#![cfg(feature = "parse")]
+
+use core::fmt;
+use core::iter::FusedIterator;
+
+use crate::field::trim_ascii_whitespace;
+use crate::{format, FromLexical, FromLexicalWithOptions, Result};
+
+/// Delimiter between the numbers for [`parse_many`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Delimiter {
+    /// Numbers are separated by one or more ASCII whitespace characters.
+    Whitespace,
+    /// Numbers are separated by a comma, with optional ASCII whitespace
+    /// around it.
+    Comma,
+    /// Numbers are separated by a byte, with optional ASCII whitespace
+    /// around it.
+    Byte(u8),
+}
+
+/// Iterator over the numbers in a delimited buffer.
+///
+/// This is created by [`parse_many`] and [`parse_many_with_options`].
+/// Each item is the result of parsing a single number, and the iterator
+/// continues after an invalid number. Error indexes are relative to the
+/// start of the buffer, and [`offset`](ParseMany::offset) returns the
+/// start of the most recent number.
+pub struct ParseMany<'a, N: FromLexicalWithOptions, const FORMAT: u128 = { format::STANDARD }> {
+    bytes: &'a [u8],
+    index: usize,
+    offset: usize,
+    finished: bool,
+    delimiter: Delimiter,
+    options: Option<&'a N::Options>,
+}
+
+impl<'a, N: FromLexicalWithOptions, const FORMAT: u128> ParseMany<'a, N, FORMAT> {
+    /// Create an iterator over the numbers in a buffer.
+    #[inline]
+    fn new(bytes: &'a [u8], delimiter: Delimiter, options: Option<&'a N::Options>) -> Self {
+        // Blank input contains no numbers, rather than a single empty one.
+        let finished = trim_ascii_whitespace(bytes).is_empty();
+        Self {
+            bytes,
+            index: 0,
+            offset: 0,
+            finished,
+            delimiter,
+            options,
+        }
+    }
+
+    /// Get the byte offset of the start of the most recent number.
+    ///
+    /// For an empty field, this is the position the number was expected.
+    #[inline(always)]
+    pub const fn offset(&self) -> usize {
+        self.offset
+    }
+
+    /// Get the byte range of the next field, advancing the iterator.
+    #[inline]
+    fn next_field(&mut self) -> Option<(usize, usize)> {
+        if self.finished {
+            return None;
+        }
+        let bytes = self.bytes;
+        let delimiter = match self.delimiter {
+            Delimiter::Whitespace => {
+                let start = match bytes[self.index..].iter().position(|c| !c.is_ascii_whitespace())
+                {
+                    Some(count) => self.index + count,
+                    None => {
+                        self.finished = true;
+                        return None;
+                    },
+                };
+                let end = bytes[start..]
+                    .iter()
+                    .position(u8::is_ascii_whitespace)
+                    .map_or(bytes.len(), |count| start + count);
+                self.index = end;
+                return Some((start, end));
+            },
+            Delimiter::Comma => b',',
+            Delimiter::Byte(delimiter) => delimiter,
+        };
+
+        let mut start = self.index;
+        let mut end = match bytes[start..].iter().position(|&c| c == delimiter) {
+            Some(count) => {
+                self.index = start + count + 1;
+                start + count
+            },
+            None => {
+                self.finished = true;
+                bytes.len()
+            },
+        };
+        while start < end && bytes[start].is_ascii_whitespace() {
+            start += 1;
+        }
+        while start < end && bytes[end - 1].is_ascii_whitespace() {
+            end -= 1;
+        }
+        Some((start, end))
+    }
+}
+
+impl<'a, N, const FORMAT: u128> Iterator for ParseMany<'a, N, FORMAT>
+where
+    N: FromLexical + FromLexicalWithOptions,
+{
+    type Item = Result<N>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Result<N>> {
+        let (start, end) = self.next_field()?;
+        self.offset = start;
+        let field = &self.bytes[start..end];
+        let result = match self.options {
+            Some(options) => N::from_lexical_with_options::<FORMAT>(field, options),
+            None => N::from_lexical(field),
+        };
+        Some(result.map_err(|mut error| {
+            if let Some(index) = error.index_mut() {
+                *index += start;
+            }
+            error
+        }))
+    }
+}
+
+impl<'a, N, const FORMAT: u128> FusedIterator for ParseMany<'a, N, FORMAT> where
+    N: FromLexical + FromLexicalWithOptions
+{
+}
+
+impl<'a, N: FromLexicalWithOptions, const FORMAT: u128> Clone for ParseMany<'a, N, FORMAT> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            bytes: self.bytes,
+            index: self.index,
+            offset: self.offset,
+            finished: self.finished,
+            delimiter: self.delimiter,
+            options: self.options,
+        }
+    }
+}
+
+impl<'a, N: FromLexicalWithOptions, const FORMAT: u128> fmt::Debug for ParseMany<'a, N, FORMAT> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ParseMany")
+            .field("bytes", &self.bytes)
+            .field("index", &self.index)
+            .field("offset", &self.offset)
+            .field("delimiter", &self.delimiter)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Parse many numbers from a delimited buffer, such as a row of a vector
+/// or matrix.
+///
+/// Returns an iterator over the result of parsing each number, without
+/// splitting the buffer first. With [`Delimiter::Whitespace`], numbers are
+/// separated by runs of ASCII whitespace. Otherwise, numbers are separated
+/// by the delimiter, ASCII whitespace around each number is ignored, and
+/// an empty field, such as from a trailing delimiter, is an error. A blank
+/// buffer contains no numbers.
+///
+/// * `bytes`     - Byte slice containing the numbers.
+/// * `delimiter` - Delimiter between the numbers.
+///
+/// # Example
+///
+/// ```
+/// # pub fn main() {
+/// #[cfg(feature = "parse-floats")] {
+/// use lexical_core::{Delimiter, Error};
+///
+/// let values = lexical_core::parse_many::<f64>(b"1.5 -2\t3e2\n", Delimiter::Whitespace);
+/// assert_eq!(values.collect::<Result<Vec<_>, _>>(), Ok(vec![1.5, -2.0, 300.0]));
+///
+/// let mut values = lexical_core::parse_many::<f64>(b"1, 2.5,x", Delimiter::Comma);
+/// assert_eq!(values.next(), Some(Ok(1.0)));
+/// assert_eq!(values.next(), Some(Ok(2.5)));
+/// assert_eq!(values.offset(), 3);
+/// assert_eq!(values.next(), Some(Err(Error::InvalidDigit(7))));
+/// assert_eq!(values.next(), None);
+/// # }
+/// # }
+/// ```
+#[inline]
+pub fn parse_many<N: FromLexical + FromLexicalWithOptions>(
+    bytes: &[u8],
+    delimiter: Delimiter,
+) -> ParseMany<'_, N> {
+    ParseMany::new(bytes, delimiter, None)
+}
+
+/// Parse many numbers from a delimited buffer, with custom parsing
+/// options.
+///
+/// This is identical to [`parse_many`], except each number is parsed with
+/// [`FromLexicalWithOptions::from_lexical_with_options`]. Note that if the
+/// format allows whitespace or the delimiter inside a number, the
+/// delimiter still takes precedence.
+///
+/// * `FORMAT`    - Packed struct containing the number format.
+/// * `bytes`     - Byte slice containing the numbers.
+/// * `delimiter` - Delimiter between the numbers.
+/// * `options`   - Options to customize number parsing.
+#[inline]
+pub fn parse_many_with_options<'a, N, const FORMAT: u128>(
+    bytes: &'a [u8],
+    delimiter: Delimiter,
+    options: &'a N::Options,
+) -> ParseMany<'a, N, FORMAT>
+where
+    N: FromLexical + FromLexicalWithOptions,
+{
+    ParseMany::new(bytes, delimiter, Some(options))
+}
@@ -0,0 +1,131 @@
+//! Parse numbers with a fallback value, or as `None` for blank input.
+
+#![cfg(feature = "parse")]
+
+use crate::{FromLexical, FromLexicalWithOptions, Result};
+
+/// Parse complete number from string, returning `default` on any error.
+///
+/// This is identical to [`parse`](crate::parse), except invalid input returns
+/// `default` rather than an error. This is inlined, so the error is never used
+/// and the compiler can remove the code to create it, which is useful when
+/// malformed input is expected, such as cleaning bulk data.
+///
+/// * `bytes`   - Byte slice containing a numeric string.
+/// * `default` - Value for invalid input.
+///
+/// # Example
+///
+/// ```
+/// # pub fn main() {
+/// #[cfg(feature = "parse-integers")] {
+/// assert_eq!(lexical_core::parse_or(b"15", 0u32), 15);
+/// assert_eq!(lexical_core::parse_or(b"1a", 0u32), 0);
+/// assert_eq!(lexical_core::parse_or(b"", u32::MAX), u32::MAX);
+/// # }
+/// # }
+/// ```
+#[inline(always)]
+pub fn parse_or<N: FromLexical>(bytes: &[u8], default: N) -> N {
+    N::from_lexical(bytes).unwrap_or(default)
+}
+
+/// Parse complete number from string, computing a fallback from a closure
+/// on any error.
+///
+/// This is identical to [`parse_or`], except the fallback is only computed
+/// if the input is invalid.
+///
+/// * `bytes`   - Byte slice containing a numeric string.
+/// * `default` - Closure to compute the value for invalid input.
+///
+/// # Example
+///
+/// ```
+/// # pub fn main() {
+/// #[cfg(feature = "parse-floats")] {
+/// assert_eq!(lexical_core::parse_or_else(b"1.5", || f64::NAN), 1.5);
+/// assert!(lexical_core::parse_or_else(b"1.5.", || f64::NAN).is_nan());
+/// # }
+/// # }
+/// ```
+#[inline(always)]
+pub fn parse_or_else<N: FromLexical, F: FnOnce() -> N>(bytes: &[u8], default: F) -> N {
+    N::from_lexical(bytes).unwrap_or_else(|_| default())
+}
+
+/// Parse complete number from string, returning `None` for blank input.
+///
+/// Empty input, or input with only ASCII whitespace, is a null value and
+/// returns `Ok(None)`, like an empty column in a CSV file or database.
+/// Otherwise, this is identical to [`parse`](crate::parse), so leading or
+/// trailing whitespace around a number is still an error. Use
+/// [`parse_field`](crate::parse_field) to trim fields before parsing.
+///
+/// * `bytes` - Byte slice containing a numeric string.
+///
+/// # Example
+///
+/// ```
+/// # pub fn main() {
+/// #[cfg(feature = "parse-integers")] {
+/// use lexical_core::Error;
+///
+/// assert_eq!(lexical_core::parse_optional::<u32>(b"15"), Ok(Some(15)));
+/// assert_eq!(lexical_core::parse_optional::<u32>(b""), Ok(None));
+/// assert_eq!(lexical_core::parse_optional::<u32>(b" \t"), Ok(None));
+/// assert_eq!(lexical_core::parse_optional::<u32>(b" 15"), Err(Error::InvalidDigit(0)));
+/// # }
+/// # }
+/// ```
+#[inline]
+pub fn parse_optional<N: FromLexical>(bytes: &[u8]) -> Result<Option<N>> {
+    if is_blank(bytes) {
+        Ok(None)
+    } else {
+        N::from_lexical(bytes).map(Some)
+    }
+}
+
+/// Parse complete number from string with custom parsing options,
+/// returning `None` for blank input.
+///
+/// This is identical to [`parse_with_options`](crate::parse_with_options),
+/// except empty input, or input with only ASCII whitespace, returns `Ok(None)`.
+/// See [`parse_optional`].
+///
+/// * `FORMAT`  - Packed struct containing the number format.
+/// * `bytes`   - Byte slice containing a numeric string.
+/// * `options` - Options to customize number parsing.
+///
+/// # Example
+///
+/// ```
+/// # pub fn main() {
+/// #[cfg(all(feature = "parse-floats", feature = "format"))] {
+/// const JSON: u128 = lexical_core::format::JSON;
+/// let options = lexical_core::ParseFloatOptions::new();
+/// let result = lexical_core::parse_optional_with_options::<f64, JSON>(b"1.5", &options);
+/// assert_eq!(result, Ok(Some(1.5)));
+/// let result = lexical_core::parse_optional_with_options::<f64, JSON>(b"\n", &options);
+/// assert_eq!(result, Ok(None));
+/// # }
+/// # }
+/// ```
+#[inline]
+pub fn parse_optional_with_options<N: FromLexicalWithOptions, const FORMAT: u128>(
+    bytes: &[u8],
+    options: &N::Options,
+) -> Result<Option<N>> {
+    if is_blank(bytes) {
+        Ok(None)
+    } else {
+        N::from_lexical_with_options::<FORMAT>(bytes, options).map(Some)
+    }
+}
+
+/// Check if the input is empty or only contains ASCII whitespace.
+#[inline(always)]
+fn is_blank(bytes: &[u8]) -> bool {
+    bytes.iter().all(u8::is_ascii_whitespace)
+}
@@ -0,0 +1,246 @@
+//! Convert slices of numbers using multiple threads with rayon.
+
+#![cfg(feature = "parallel")]
+
+use rayon::prelude::*;
+
+#[cfg(feature = "parse")]
+use crate::{
+    parse_slice,
+    parse_slice_with_options,
+    BatchError,
+    FromLexical,
+    FromLexicalWithOptions,
+};
+#[cfg(feature = "write")]
+use crate::{write_slice, write_slice_with_options, ToLexical, ToLexicalWithOptions, WriteOptions};
+
+/// The number of values converted by each task in the parallel batch APIs.
+const PARALLEL_CHUNK_SIZE: usize = 1024;
+
+/// Write chunks of numbers in parallel, and then compact the written bytes.
+///
+/// Each chunk is written to its own `stride * PARALLEL_CHUNK_SIZE` region
+/// of the buffer, so every value must fit in `stride` bytes.
+#[cfg(feature = "write")]
+fn write_slice_parallel_impl<'a, N, Cb>(
+    values: &[N],
+    bytes: &'a mut [u8],
+    offsets: &mut [usize],
+    stride: usize,
+    cb: Cb,
+) -> &'a mut [u8]
+where
+    N: Sync,
+    Cb: Fn(&[N], &mut [u8], &mut [usize]) -> usize + Sync,
+{
+    assert_eq!(values.len(), offsets.len());
+    assert!(bytes.len() >= values.len() * stride);
+    if values.is_empty() {
+        return &mut bytes[..0];
+    }
+
+    let region = stride * PARALLEL_CHUNK_SIZE;
+    bytes
+        .par_chunks_mut(region)
+        .zip(values.par_chunks(PARALLEL_CHUNK_SIZE))
+        .zip(offsets.par_chunks_mut(PARALLEL_CHUNK_SIZE))
+        .for_each(|((bytes, values), offsets)| {
+            cb(values, bytes, offsets);
+        });
+
+    // Move each chunk to directly after the previous one, and make the
+    // offsets relative to the start of the buffer.
+    let mut index = 0;
+    for (chunk, offsets) in offsets.chunks_mut(PARALLEL_CHUNK_SIZE).enumerate() {
+        let start = chunk * region;
+        let count = offsets[offsets.len() - 1];
+        bytes.copy_within(start..start + count, index);
+        for offset in offsets.iter_mut() {
+            *offset += index;
+        }
+        index += count;
+    }
+    &mut bytes[..index]
+}
+
+/// Write many numbers to string, back-to-back, using multiple threads.
+///
+/// This has the same output as [`write_slice`], but splits the values
+/// into chunks that are written in parallel using rayon. The chunks
+/// are then compacted, so each value is contiguous with the next.
+///
+/// * `values`  - Numbers to serialize.
+/// * `bytes`   - Buffer to write numbers to.
+/// * `offsets` - Buffer to write the end of each number to.
+///
+/// # Panics
+///
+/// Panics if `offsets` is not the same length as `values`, or if the
+/// buffer has fewer than `values.len() * {integer}::FORMATTED_SIZE_DECIMAL`
+/// elements. Unlike [`write_slice`], this size is always required,
+/// since each chunk is written independently.
+///
+/// # Example
+///
+/// ```
+/// # pub fn main() {
+/// #[cfg(feature = "write-integers")] {
+/// use lexical_core::FormattedSize;
+///
+/// let mut buffer = [0u8; 3 * u32::FORMATTED_SIZE_DECIMAL];
+/// let mut offsets = [0usize; 3];
+/// let bytes = lexical_core::write_slice_parallel(&[1u32, 23, 456], &mut buffer, &mut offsets);
+/// assert_eq!(bytes, b"123456");
+/// assert_eq!(offsets, [1, 3, 6]);
+/// # }
+/// # }
+/// ```
+#[inline]
+#[cfg(feature = "write")]
+pub fn write_slice_parallel<'a, N: ToLexical + Sync>(
+    values: &[N],
+    bytes: &'a mut [u8],
+    offsets: &mut [usize],
+) -> &'a mut [u8] {
+    write_slice_parallel_impl(values, bytes, offsets, N::FORMATTED_SIZE_DECIMAL, |v, b, o| {
+        write_slice(v, b, o).len()
+    })
+}
+
+/// Write many numbers to string, back-to-back, with custom options
+/// using multiple threads.
+///
+/// This has the same output as [`write_slice_with_options`], but splits
+/// the values into chunks that are written in parallel using rayon. The
+/// chunks are then compacted, so each value is contiguous with the next.
+///
+/// * `FORMAT`  - Packed struct containing the number format.
+/// * `values`  - Numbers to serialize.
+/// * `bytes`   - Buffer to write numbers to.
+/// * `offsets` - Buffer to write the end of each number to.
+/// * `options` - Options to customize number writing.
+///
+/// # Panics
+///
+/// Panics if `offsets` is not the same length as `values`, or if the
+/// buffer has fewer than `values.len() * options.buffer_size::<N, FORMAT>()`
+/// elements.
+///
+/// If the provided `FORMAT` is not valid, the function may panic. Please
+/// ensure `is_valid()` is called prior to using the format, or checking
+/// its validity using a static assertion.
+#[inline]
+#[cfg(feature = "write")]
+pub fn write_slice_parallel_with_options<'a, N, const FORMAT: u128>(
+    values: &[N],
+    bytes: &'a mut [u8],
+    offsets: &mut [usize],
+    options: &N::Options,
+) -> &'a mut [u8]
+where
+    N: ToLexicalWithOptions + Sync,
+    N::Options: Sync,
+{
+    let stride = options.buffer_size::<N, FORMAT>();
+    write_slice_parallel_impl(values, bytes, offsets, stride, |v, b, o| {
+        write_slice_with_options::<N, FORMAT>(v, b, o, options).len()
+    })
+}
+
+/// Parse many complete numbers from strings using multiple threads.
+///
+/// This splits the inputs into chunks that are parsed in parallel using
+/// rayon. If any strings are invalid, this returns the error with the
+/// lowest index, like [`parse_slice`]. However, the values of the other
+/// outputs are unspecified on error, since all chunks are parsed.
+///
+/// * `inputs`  - Byte slices containing numeric strings.
+/// * `outputs` - Buffer to store the parsed numbers.
+///
+/// # Panics
+///
+/// Panics if `outputs` is not the same length as `inputs`.
+///
+/// # Example
+///
+/// ```
+/// # pub fn main() {
+/// #[cfg(feature = "parse-integers")] {
+/// let mut outputs = [0u32; 3];
+/// let result = lexical_core::parse_slice_parallel(&["1", "23", "456"], &mut outputs);
+/// assert_eq!(result, Ok(()));
+/// assert_eq!(outputs, [1, 23, 456]);
+/// # }
+/// # }
+/// ```
+#[inline]
+#[cfg(feature = "parse")]
+pub fn parse_slice_parallel<N, Bytes>(
+    inputs: &[Bytes],
+    outputs: &mut [N],
+) -> core::result::Result<(), BatchError>
+where
+    N: FromLexical + Send,
+    Bytes: AsRef<[u8]> + Sync,
+{
+    assert_eq!(inputs.len(), outputs.len());
+    let error = inputs
+        .par_chunks(PARALLEL_CHUNK_SIZE)
+        .zip(outputs.par_chunks_mut(PARALLEL_CHUNK_SIZE))
+        .enumerate()
+        .filter_map(|(chunk, (inputs, outputs))| {
+            parse_slice(inputs, outputs).err().map(|error| BatchError {
+                index: chunk * PARALLEL_CHUNK_SIZE + error.index,
+                error: error.error,
+            })
+        })
+        .min();
+    error.map_or(Ok(()), Err)
+}
+
+/// Parse many complete numbers from strings with custom parsing options
+/// using multiple threads.
+///
+/// This splits the inputs into chunks that are parsed in parallel using
+/// rayon. If any strings are invalid, this returns the error with the
+/// lowest index, like [`parse_slice_with_options`]. However, the values
+/// of the other outputs are unspecified on error, since all chunks are
+/// parsed.
+///
+/// * `FORMAT`  - Packed struct containing the number format.
+/// * `inputs`  - Byte slices containing numeric strings.
+/// * `outputs` - Buffer to store the parsed numbers.
+/// * `options` - Options to customize number parsing.
+///
+/// # Panics
+///
+/// Panics if `outputs` is not the same length as `inputs`.
+#[inline]
+#[cfg(feature = "parse")]
+pub fn parse_slice_parallel_with_options<N, Bytes, const FORMAT: u128>(
+    inputs: &[Bytes],
+    outputs: &mut [N],
+    options: &N::Options,
+) -> core::result::Result<(), BatchError>
+where
+    N: FromLexicalWithOptions + Send,
+    N::Options: Sync,
+    Bytes: AsRef<[u8]> + Sync,
+{
+    assert_eq!(inputs.len(), outputs.len());
+    let error = inputs
+        .par_chunks(PARALLEL_CHUNK_SIZE)
+        .zip(outputs.par_chunks_mut(PARALLEL_CHUNK_SIZE))
+        .enumerate()
+        .filter_map(|(chunk, (inputs, outputs))| {
+            parse_slice_with_options::<N, Bytes, FORMAT>(inputs, outputs, options).err().map(
+                |error| BatchError {
+                    index: chunk * PARALLEL_CHUNK_SIZE + error.index,
+                    error: error.error,
+                },
+            )
+        })
+        .min();
+    error.map_or(Ok(()), Err)
+}
@@ -0,0 +1,94 @@
+//! Parse numbers, rejecting values outside a range.
+
+#![cfg(feature = "parse")]
+
+use core::ops::RangeBounds;
+
+use crate::{Error, FromLexical, FromLexicalWithOptions, Result};
+
+/// Parse complete number from string, rejecting values outside `range`.
+///
+/// This is identical to [`parse`](crate::parse), except a value outside `range`
+/// returns [`Error::OutOfRange`], with an index of `0` for the start of the
+/// number. Invalid input returns the same error as [`parse`](crate::parse). A
+/// `NaN` is not contained in any range with a bound, so it is rejected for
+/// floats.
+///
+/// * `bytes` - Byte slice containing a numeric string.
+/// * `range` - Range of allowed values.
+///
+/// # Example
+///
+/// ```
+/// # pub fn main() {
+/// #[cfg(feature = "parse-integers")] {
+/// use lexical_core::Error;
+///
+/// assert_eq!(lexical_core::parse_in_range(b"8080", 1u16..=65535), Ok(8080));
+/// assert_eq!(lexical_core::parse_in_range(b"0", 1u16..=65535), Err(Error::OutOfRange(0)));
+/// assert_eq!(lexical_core::parse_in_range(b"-1", 0i32..), Err(Error::OutOfRange(0)));
+/// assert_eq!(lexical_core::parse_in_range(b"1x", 0i32..), Err(Error::InvalidDigit(1)));
+/// # }
+/// # }
+/// ```
+#[inline]
+pub fn parse_in_range<N, R>(bytes: &[u8], range: R) -> Result<N>
+where
+    N: FromLexical + PartialOrd,
+    R: RangeBounds<N>,
+{
+    check_range(N::from_lexical(bytes)?, &range)
+}
+
+/// Parse complete number from string with custom parsing options,
+/// rejecting values outside `range`.
+///
+/// This is identical to [`parse_with_options`](crate::parse_with_options),
+/// except a value outside `range` returns [`Error::OutOfRange`]. See
+/// [`parse_in_range`].
+///
+/// * `FORMAT`  - Packed struct containing the number format.
+/// * `bytes`   - Byte slice containing a numeric string.
+/// * `options` - Options to customize number parsing.
+/// * `range`   - Range of allowed values.
+///
+/// # Example
+///
+/// ```
+/// # pub fn main() {
+/// #[cfg(all(feature = "parse-floats", feature = "format"))] {
+/// use lexical_core::Error;
+///
+/// const JSON: u128 = lexical_core::format::JSON;
+/// let options = lexical_core::ParseFloatOptions::new();
+/// let result =
+///     lexical_core::parse_in_range_with_options::<f64, _, JSON>(b"0.25", &options, 0.0..=1.0);
+/// assert_eq!(result, Ok(0.25));
+/// let result =
+///     lexical_core::parse_in_range_with_options::<f64, _, JSON>(b"1.5", &options, 0.0..=1.0);
+/// assert_eq!(result, Err(Error::OutOfRange(0)));
+/// # }
+/// # }
+/// ```
+#[inline]
+pub fn parse_in_range_with_options<N, R, const FORMAT: u128>(
+    bytes: &[u8],
+    options: &N::Options,
+    range: R,
+) -> Result<N>
+where
+    N: FromLexicalWithOptions + PartialOrd,
+    R: RangeBounds<N>,
+{
+    check_range(N::from_lexical_with_options::<FORMAT>(bytes, options)?, &range)
+}
+
+/// Check a parsed value is within the allowed range.
+#[inline(always)]
+fn check_range<N: PartialOrd, R: RangeBounds<N>>(value: N, range: &R) -> Result<N> {
+    if range.contains(&value) {
+        Ok(value)
+    } else {
+        Err(Error::OutOfRange(0))
+    }
+}
@@ -0,0 +1,103 @@
+//! A registry of named formats, with the options for each conversion.
+
+#![cfg(all(feature = "std", any(feature = "parse", feature = "write")))]
+
+#[cfg(feature = "parse-floats")]
+use crate::ParseFloatOptions;
+#[cfg(feature = "parse-integers")]
+use crate::ParseIntegerOptions;
+#[cfg(feature = "write-floats")]
+use crate::WriteFloatOptions;
+#[cfg(feature = "write-integers")]
+use crate::WriteIntegerOptions;
+
+/// Registered formats, by name.
+static FORMAT_REGISTRY: std::sync::RwLock<Vec<(String, Format)>> =
+    std::sync::RwLock::new(Vec::new());
+
+/// Runtime options for each enabled conversion, registered by name.
+///
+/// This allows applications to define formats once, such as from a
+/// configuration file, and look them up by name when converting numbers.
+/// The packed number format is a compile-time parameter, so only the
+/// options are stored: the `FORMAT` used with them must be fixed by the
+/// caller. The registry is global and shared between threads.
+///
+/// # Example
+///
+/// ```
+/// # pub fn main() {
+/// #[cfg(all(feature = "parse-floats", feature = "write-floats"))] {
+/// use lexical_core::{format::STANDARD, Format, ParseFloatOptions, WriteFloatOptions};
+///
+/// let format = Format {
+///     parse_float: ParseFloatOptions::builder().decimal_point(b',').build().unwrap(),
+///     write_float: WriteFloatOptions::builder().decimal_point(b',').build().unwrap(),
+///     ..Format::default()
+/// };
+/// Format::register("european", format);
+///
+/// let format = Format::get("european").unwrap();
+/// let value: f64 =
+///     lexical_core::parse_with_options::<_, STANDARD>(b"1,5", &format.parse_float).unwrap();
+/// assert_eq!(value, 1.5);
+/// assert_eq!(Format::get("unknown"), None);
+/// # }
+/// # }
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Format {
+    /// Options to parse floats.
+    #[cfg(feature = "parse-floats")]
+    pub parse_float: ParseFloatOptions,
+    /// Options to parse integers.
+    #[cfg(feature = "parse-integers")]
+    pub parse_integer: ParseIntegerOptions,
+    /// Options to write floats.
+    #[cfg(feature = "write-floats")]
+    pub write_float: WriteFloatOptions,
+    /// Options to write integers.
+    #[cfg(feature = "write-integers")]
+    pub write_integer: WriteIntegerOptions,
+}
+
+impl Format {
+    /// Create a format with the default options for each conversion.
+    #[inline(always)]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register a format under a name, returning the format it replaced.
+    pub fn register(name: &str, format: Self) -> Option<Self> {
+        let mut registry =
+            FORMAT_REGISTRY.write().unwrap_or_else(std::sync::PoisonError::into_inner);
+        match registry.iter_mut().find(|(key, _)| key == name) {
+            Some((_, value)) => Some(core::mem::replace(value, format)),
+            None => {
+                registry.push((name.to_string(), format));
+                None
+            },
+        }
+    }
+
+    /// Get a copy of the format registered under a name.
+    pub fn get(name: &str) -> Option<Self> {
+        let registry = FORMAT_REGISTRY.read().unwrap_or_else(std::sync::PoisonError::into_inner);
+        registry.iter().find(|(key, _)| key == name).map(|(_, value)| value.clone())
+    }
+
+    /// Remove the format registered under a name, returning it.
+    pub fn unregister(name: &str) -> Option<Self> {
+        let mut registry =
+            FORMAT_REGISTRY.write().unwrap_or_else(std::sync::PoisonError::into_inner);
+        let index = registry.iter().position(|(key, _)| key == name)?;
+        Some(registry.remove(index).1)
+    }
+
+    /// Get the names of all registered formats, in registration order.
+    pub fn names() -> Vec<String> {
+        let registry = FORMAT_REGISTRY.read().unwrap_or_else(std::sync::PoisonError::into_inner);
+        registry.iter().map(|(key, _)| key.clone()).collect()
+    }
+}
@@ -0,0 +1,128 @@
+//! Write numbers, returning an error rather than panicking if the buffer
+//! is too small.
+
+#![cfg(feature = "write")]
+
+use core::fmt;
+
+use crate::{ToLexical, ToLexicalWithOptions, WriteOptions, BUFFER_SIZE};
+
+/// Error when the buffer is too small to hold the serialized number.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd)]
+pub struct BufferTooSmall {
+    /// An upper bound on the number of bytes required to write the number.
+    ///
+    /// A buffer of this many bytes is always large enough. This is the
+    /// exact number of bytes, unless the options require more than
+    /// [`BUFFER_SIZE`] bytes, where it is the upper bound from
+    /// [`WriteOptions::buffer_size`].
+    pub max_needed: usize,
+}
+
+impl fmt::Display for BufferTooSmall {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            formatter,
+            "lexical write error: buffer too small, at most {} bytes needed",
+            self.max_needed
+        )
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for BufferTooSmall {
+}
+
+/// Write number to string, checking the buffer is large enough.
+///
+/// Returns a subslice of the input buffer containing the written bytes,
+/// starting from the same address in memory as the input slice. If the
+/// buffer is too small, no bytes are written and this returns an error
+/// with the exact number of bytes required to write `n`.
+///
+/// * `value`   - Number to serialize.
+/// * `bytes`   - Buffer to write number to.
+///
+/// # Example
+///
+/// ```
+/// # pub fn main() {
+/// #[cfg(feature = "write-integers")] {
+/// use lexical_core::BufferTooSmall;
+///
+/// let mut buffer = [0u8; 4];
+/// assert_eq!(lexical_core::try_write(1234u32, &mut buffer).map(|x| &*x), Ok(&b"1234"[..]));
+/// assert_eq!(lexical_core::try_write(12345u32, &mut buffer), Err(BufferTooSmall {
+///     max_needed: 5,
+/// }));
+/// # }
+/// # }
+/// ```
+#[inline]
+pub fn try_write<N: ToLexical>(
+    n: N,
+    bytes: &mut [u8],
+) -> core::result::Result<&mut [u8], BufferTooSmall> {
+    if bytes.len() >= N::FORMATTED_SIZE_DECIMAL {
+        return Ok(n.to_lexical(bytes));
+    }
+    let mut buffer = [0u8; BUFFER_SIZE];
+    let count = n.to_lexical(&mut buffer).len();
+    copy_to_buffer(&buffer[..count], bytes)
+}
+
+/// Write number to string with custom options, checking the buffer
+/// is large enough.
+///
+/// Returns a subslice of the input buffer containing the written bytes,
+/// starting from the same address in memory as the input slice. If the
+/// buffer is too small, no bytes are written and this returns an error
+/// with the number of bytes required to write `n`. If the options
+/// require more than [`BUFFER_SIZE`] bytes, this is only an upper bound,
+/// from [`WriteOptions::buffer_size`], since the number is not written.
+///
+/// * `FORMAT`  - Packed struct containing the number format.
+/// * `value`   - Number to serialize.
+/// * `bytes`   - Buffer to write number to.
+/// * `options` - Options to customize number writing.
+///
+/// # Panics
+///
+/// If the provided `FORMAT` is not valid, the function may panic. Please
+/// ensure `is_valid()` is called prior to using the format, or checking
+/// its validity using a static assertion.
+#[inline]
+pub fn try_write_with_options<'a, N: ToLexicalWithOptions, const FORMAT: u128>(
+    n: N,
+    bytes: &'a mut [u8],
+    options: &N::Options,
+) -> core::result::Result<&'a mut [u8], BufferTooSmall> {
+    let size = options.buffer_size::<N, FORMAT>();
+    if bytes.len() >= size {
+        return Ok(n.to_lexical_with_options::<FORMAT>(bytes, options));
+    } else if size > BUFFER_SIZE {
+        return Err(BufferTooSmall {
+            max_needed: size,
+        });
+    }
+    let mut buffer = [0u8; BUFFER_SIZE];
+    let count = n.to_lexical_with_options::<FORMAT>(&mut buffer, options).len();
+    copy_to_buffer(&buffer[..count], bytes)
+}
+
+/// Copy the written bytes from a temporary buffer, if they fit.
+#[inline(always)]
+fn copy_to_buffer<'a>(
+    src: &[u8],
+    dst: &'a mut [u8],
+) -> core::result::Result<&'a mut [u8], BufferTooSmall> {
+    match dst.get_mut(..src.len()) {
+        Some(dst) => {
+            dst.copy_from_slice(src);
+            Ok(dst)
+        },
+        None => Err(BufferTooSmall {
+            max_needed: src.len(),
+        }),
+    }
+}
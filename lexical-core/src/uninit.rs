@@ -0,0 +1,164 @@
+//! Write numbers to uninitialized buffers, such as the spare capacity of
+//! a vector, without zeroing them first.
+
+#![cfg(feature = "write")]
+
+use core::mem::MaybeUninit;
+
+#[cfg(feature = "std")]
+use crate::WriteOptions;
+use crate::{ToLexical, ToLexicalWithOptions};
+
+/// Write number to an uninitialized buffer.
+///
+/// Returns the initialized prefix of the buffer containing the written
+/// bytes, starting from the same address in memory as the input slice.
+/// Only the bytes that may be written are initialized, so this can write
+/// to the spare capacity of a vector without zeroing it first.
+///
+/// * `value`   - Number to serialize.
+/// * `bytes`   - Buffer to write number to.
+///
+/// # Panics
+///
+/// Panics if the buffer may not be large enough to hold the serialized
+/// number, like [`write`].
+///
+/// # Example
+///
+/// ```
+/// # pub fn main() {
+/// #[cfg(feature = "write-floats")] {
+/// use lexical_core::FormattedSize;
+///
+/// let mut vec = Vec::with_capacity(f64::FORMATTED_SIZE_DECIMAL);
+/// let count = lexical_core::write_uninit(1.5f64, vec.spare_capacity_mut()).len();
+/// // SAFETY: safe since the first `count` bytes were initialized.
+/// unsafe { vec.set_len(count) };
+/// assert_eq!(vec, b"1.5");
+/// # }
+/// # }
+/// ```
+#[inline]
+pub fn write_uninit<N: ToLexical>(n: N, bytes: &mut [MaybeUninit<u8>]) -> &mut [u8] {
+    n.to_lexical_uninit(bytes)
+}
+
+/// Write number to an uninitialized buffer with custom options.
+///
+/// Returns the initialized prefix of the buffer containing the written
+/// bytes, starting from the same address in memory as the input slice.
+/// Only the bytes that may be written, up to
+/// [`WriteOptions::buffer_size`](crate::WriteOptions::buffer_size),
+/// are initialized, so this can write to the spare capacity of a vector
+/// without zeroing it first.
+///
+/// * `FORMAT`  - Packed struct containing the number format.
+/// * `value`   - Number to serialize.
+/// * `bytes`   - Buffer to write number to.
+/// * `options` - Options to customize number writing.
+///
+/// # Panics
+///
+/// Panics if the buffer may not be large enough to hold the serialized
+/// number, like [`write_with_options`](crate::write_with_options).
+///
+/// If the provided `FORMAT` is not valid, the function may panic. Please
+/// ensure `is_valid()` is called prior to using the format, or checking
+/// its validity using a static assertion.
+#[inline]
+pub fn write_with_options_uninit<'a, N: ToLexicalWithOptions, const FORMAT: u128>(
+    n: N,
+    bytes: &'a mut [MaybeUninit<u8>],
+    options: &N::Options,
+) -> &'a mut [u8] {
+    n.to_lexical_with_options_uninit::<FORMAT>(bytes, options)
+}
+
+/// Append a number to a vector, writing directly into its spare capacity.
+///
+/// This reserves the maximum number of bytes the number may require,
+/// writes the number without zeroing the spare capacity or using a
+/// temporary buffer, and then extends the length of the vector by the
+/// bytes written. Returns the appended bytes.
+///
+/// * `value`   - Number to serialize.
+/// * `vec`     - Vector to append the number to.
+///
+/// # Example
+///
+/// ```
+/// # pub fn main() {
+/// #[cfg(feature = "write-integers")] {
+/// let mut bytes = b"x=".to_vec();
+/// assert_eq!(lexical_core::write_to_vec(-15i32, &mut bytes), b"-15");
+/// assert_eq!(bytes, b"x=-15");
+/// # }
+/// # }
+/// ```
+#[inline]
+#[cfg(feature = "std")]
+pub fn write_to_vec<N: ToLexical>(n: N, vec: &mut Vec<u8>) -> &mut [u8] {
+    let start = vec.len();
+    vec.reserve(N::FORMATTED_SIZE_DECIMAL);
+    #[cfg(not(feature = "safe"))]
+    {
+        let len = n.to_lexical_uninit(vec.spare_capacity_mut()).len();
+        // SAFETY: safe since the first `len` bytes of the spare capacity
+        // were initialized.
+        unsafe { vec.set_len(start + len) };
+    }
+    #[cfg(feature = "safe")]
+    {
+        vec.resize(start + N::FORMATTED_SIZE_DECIMAL, 0);
+        let len = n.to_lexical(&mut vec[start..]).len();
+        vec.truncate(start + len);
+    }
+    &mut vec[start..]
+}
+
+/// Append a number to a vector with custom options, writing directly into
+/// its spare capacity.
+///
+/// This reserves
+/// [`WriteOptions::buffer_size`](crate::WriteOptions::buffer_size) bytes,
+/// writes the number without zeroing the spare capacity or using a temporary
+/// buffer, and then extends the length of the vector by the bytes written.
+/// Returns the appended bytes.
+///
+/// * `FORMAT`  - Packed struct containing the number format.
+/// * `value`   - Number to serialize.
+/// * `vec`     - Vector to append the number to.
+/// * `options` - Options to customize number writing.
+///
+/// # Panics
+///
+/// If the provided `FORMAT` is not valid, the function may panic. Please
+/// ensure `is_valid()` is called prior to using the format, or checking
+/// its validity using a static assertion.
+#[inline]
+#[cfg(feature = "std")]
+pub fn write_with_options_to_vec<'a, N: ToLexicalWithOptions, const FORMAT: u128>(
+    n: N,
+    vec: &'a mut Vec<u8>,
+    options: &N::Options,
+) -> &'a mut [u8] {
+    let start = vec.len();
+    let size = options.buffer_size::<N, FORMAT>();
+    vec.reserve(size);
+    #[cfg(not(feature = "safe"))]
+    {
+        let slc = vec.spare_capacity_mut();
+        let len = n.to_lexical_with_options_uninit::<FORMAT>(slc, options).len();
+        // SAFETY: safe since the first `len` bytes of the spare capacity
+        // were initialized.
+        unsafe { vec.set_len(start + len) };
+    }
+    #[cfg(feature = "safe")]
+    {
+        vec.resize(start + size, 0);
+        let len = n.to_lexical_with_options::<FORMAT>(&mut vec[start..], options).len();
+        vec.truncate(start + len);
+    }
+    &mut vec[start..]
+}
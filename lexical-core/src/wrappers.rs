@@ -0,0 +1,103 @@
+//! Float wrappers that implement `FromStr` using lexical.
+
+#![cfg(feature = "parse-floats")]
+
+use crate::{format, parse_with_options, Error, ParseFloatOptions, Result};
+
+/// Define a float wrapper that implements `FromStr` with a lexical format.
+macro_rules! from_str_wrapper {
+    ($(#[$meta:meta])* $name:ident, $t:ty, $format:expr, $options:expr) => {
+        $(#[$meta])*
+        #[repr(transparent)]
+        #[derive(Copy, Clone, Debug, Default, PartialEq, PartialOrd)]
+        pub struct $name(pub $t);
+
+        impl core::str::FromStr for $name {
+            type Err = Error;
+
+            #[inline]
+            fn from_str(string: &str) -> Result<Self> {
+                const OPTIONS: ParseFloatOptions = $options;
+                parse_with_options::<$t, { $format }>(string.as_bytes(), &OPTIONS).map(Self)
+            }
+        }
+
+        impl From<$t> for $name {
+            #[inline(always)]
+            fn from(value: $t) -> Self {
+                Self(value)
+            }
+        }
+
+        impl From<$name> for $t {
+            #[inline(always)]
+            fn from(value: $name) -> Self {
+                value.0
+            }
+        }
+    };
+}
+
+from_str_wrapper!(
+    /// An `f32` parsed with the lossy algorithm, which may not be
+    /// correctly rounded, using `str::parse`.
+    ///
+    /// ```rust
+    /// # pub fn main() {
+    /// #[cfg(feature = "parse-floats")] {
+    /// use lexical_core::LossyF32;
+    ///
+    /// let value: LossyF32 = "1.5".parse().unwrap();
+    /// assert_eq!(value.0, 1.5);
+    /// # }
+    /// # }
+    /// ```
+    LossyF32,
+    f32,
+    format::STANDARD,
+    ParseFloatOptions::builder().lossy(true).build_unchecked()
+);
+
+from_str_wrapper!(
+    /// An `f64` parsed with the lossy algorithm, which may not be
+    /// correctly rounded, using `str::parse`.
+    LossyF64,
+    f64,
+    format::STANDARD,
+    ParseFloatOptions::builder().lossy(true).build_unchecked()
+);
+
+#[cfg(feature = "format")]
+from_str_wrapper!(
+    /// An `f32` parsed from a JSON number using `str::parse`.
+    ///
+    /// This rejects numbers JSON does not allow, such as `+1`, `.5`,
+    /// `1.`, or `NaN`.
+    ///
+    /// ```rust
+    /// # pub fn main() {
+    /// #[cfg(all(feature = "parse-floats", feature = "format"))] {
+    /// use lexical_core::JsonF32;
+    ///
+    /// assert_eq!("-1.5e3".parse::<JsonF32>().map(|x| x.0), Ok(-1500.0));
+    /// assert!(".5".parse::<JsonF32>().is_err());
+    /// # }
+    /// # }
+    /// ```
+    JsonF32,
+    f32,
+    format::JSON,
+    crate::parse_float_options::JSON
+);
+
+#[cfg(feature = "format")]
+from_str_wrapper!(
+    /// An `f64` parsed from a JSON number using `str::parse`.
+    ///
+    /// This rejects numbers JSON does not allow, such as `+1`, `.5`,
+    /// `1.`, or `NaN`.
+    JsonF64,
+    f64,
+    format::JSON,
+    crate::parse_float_options::JSON
+);
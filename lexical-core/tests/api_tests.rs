@@ -53,3 +53,768 @@ fn string_to_float_test() {
         Ok((12345.0f32, 7))
     );
 }
+
+#[test]
+#[cfg(feature = "write-integers")]
+fn write_slice_test() {
+    use lexical_core::FormattedSize;
+
+    let mut buffer = [b'0'; 4 * i32::FORMATTED_SIZE_DECIMAL];
+    let mut offsets = [0usize; 4];
+    let values = [1i32, -23, 0, i32::MAX];
+    let bytes = lexical_core::write_slice(&values, &mut buffer, &mut offsets);
+    assert_eq!(bytes, b"1-2302147483647");
+    assert_eq!(offsets, [1, 4, 5, 15]);
+
+    let bytes = lexical_core::write_slice::<u8>(&[], &mut buffer, &mut []);
+    assert_eq!(bytes, b"");
+
+    let options = lexical_core::WriteIntegerOptions::new();
+    const FORMAT: u128 = lexical_core::format::STANDARD;
+    let bytes = lexical_core::write_slice_with_options::<_, FORMAT>(
+        &values,
+        &mut buffer,
+        &mut offsets,
+        &options,
+    );
+    assert_eq!(bytes, b"1-2302147483647");
+    assert_eq!(offsets, [1, 4, 5, 15]);
+}
+
+#[test]
+#[cfg(feature = "write-floats")]
+fn write_slice_float_test() {
+    let mut buffer = [b'0'; lexical_core::BUFFER_SIZE];
+    let mut offsets = [0usize; 2];
+    let bytes = lexical_core::write_slice(&[1.5f64, -0.25], &mut buffer, &mut offsets);
+    assert_eq!(bytes, b"1.5-0.25");
+    assert_eq!(offsets, [3, 8]);
+}
+
+#[test]
+#[cfg(feature = "parse-integers")]
+fn parse_slice_test() {
+    let mut outputs = [0i32; 3];
+    assert_eq!(lexical_core::parse_slice(&[b"1" as &[u8], b"-23", b"456"], &mut outputs), Ok(()));
+    assert_eq!(outputs, [1, -23, 456]);
+
+    let inputs = ["7", "8", "9x", "10"];
+    let result = lexical_core::parse_slice(&inputs, &mut [0i32; 4]);
+    assert_eq!(
+        result,
+        Err(lexical_core::BatchError {
+            index: 2,
+            error: lexical_core::Error::InvalidDigit(1),
+        })
+    );
+
+    let mut outputs = [0u8; 4];
+    let result = lexical_core::parse_slice(&inputs, &mut outputs);
+    assert_eq!(result.unwrap_err().index, 2);
+    assert_eq!(outputs, [7, 8, 0, 0]);
+
+    let options = lexical_core::ParseIntegerOptions::new();
+    const FORMAT: u128 = lexical_core::format::STANDARD;
+    let mut outputs = [0u64; 2];
+    let result =
+        lexical_core::parse_slice_with_options::<_, _, FORMAT>(&["12", ""], &mut outputs, &options);
+    assert_eq!(
+        result,
+        Err(lexical_core::BatchError {
+            index: 1,
+            error: lexical_core::Error::Empty(0),
+        })
+    );
+}
+
+#[test]
+#[cfg(feature = "parse-floats")]
+fn parse_slice_float_test() {
+    let mut outputs = [0f64; 2];
+    assert_eq!(lexical_core::parse_slice(&["1.5", "-2e3"], &mut outputs), Ok(()));
+    assert_eq!(outputs, [1.5, -2000.0]);
+}
+
+#[test]
+#[should_panic]
+#[cfg(feature = "parse-integers")]
+fn parse_slice_length_mismatch_test() {
+    _ = lexical_core::parse_slice(&["1", "2"], &mut [0u32; 1]);
+}
+
+#[test]
+#[cfg(feature = "write-integers")]
+fn try_write_test() {
+    use lexical_core::BufferTooSmall;
+
+    let mut buffer = [b'0'; lexical_core::BUFFER_SIZE];
+    assert_eq!(lexical_core::try_write(12345u32, &mut buffer).map(|x| &*x), Ok(&b"12345"[..]));
+    assert_eq!(lexical_core::try_write(-12i8, &mut buffer[..3]).map(|x| &*x), Ok(&b"-12"[..]));
+    assert_eq!(
+        lexical_core::try_write(-123i8, &mut buffer[..3]),
+        Err(BufferTooSmall {
+            max_needed: 4
+        })
+    );
+    assert_eq!(
+        lexical_core::try_write(0u64, &mut []),
+        Err(BufferTooSmall {
+            max_needed: 1
+        })
+    );
+
+    let options = lexical_core::WriteIntegerOptions::new();
+    const FORMAT: u128 = lexical_core::format::STANDARD;
+    let mut buffer = [b'0'; 4];
+    assert_eq!(
+        lexical_core::try_write_with_options::<_, FORMAT>(1234u64, &mut buffer, &options)
+            .map(|x| &*x),
+        Ok(&b"1234"[..])
+    );
+    assert_eq!(
+        lexical_core::try_write_with_options::<_, FORMAT>(12345u64, &mut buffer, &options),
+        Err(BufferTooSmall {
+            max_needed: 5
+        })
+    );
+    assert_eq!(&buffer, b"1234");
+}
+
+#[test]
+#[cfg(feature = "write-floats")]
+fn try_write_float_test() {
+    use lexical_core::BufferTooSmall;
+
+    let mut buffer = [b'0'; 8];
+    assert_eq!(lexical_core::try_write(1.5f64, &mut buffer).map(|x| &*x), Ok(&b"1.5"[..]));
+    assert_eq!(
+        lexical_core::try_write(1.2345678e-7f64, &mut buffer),
+        Err(BufferTooSmall {
+            max_needed: 12
+        })
+    );
+
+    // Options that require more than `BUFFER_SIZE` bytes report an upper bound.
+    const FORMAT: u128 = lexical_core::format::STANDARD;
+    let options = lexical_core::WriteFloatOptions::builder()
+        .min_significant_digits(core::num::NonZeroUsize::new(1000))
+        .build()
+        .unwrap();
+    let size = lexical_core::WriteOptions::buffer_size::<f64, FORMAT>(&options);
+    assert!(size > lexical_core::BUFFER_SIZE);
+    assert_eq!(
+        lexical_core::try_write_with_options::<_, FORMAT>(1.5f64, &mut buffer, &options),
+        Err(BufferTooSmall {
+            max_needed: size
+        })
+    );
+}
+
+#[test]
+#[cfg(all(feature = "write", feature = "std"))]
+fn buffer_too_small_display_test() {
+    let error = lexical_core::BufferTooSmall {
+        max_needed: 12,
+    };
+    assert_eq!(error.to_string(), "lexical write error: buffer too small, at most 12 bytes needed");
+}
+
+#[test]
+#[cfg(feature = "write-integers")]
+fn write_uninit_test() {
+    use core::mem::MaybeUninit;
+
+    use lexical_core::FormattedSize;
+
+    let mut buffer = [MaybeUninit::<u8>::uninit(); lexical_core::BUFFER_SIZE];
+    assert_eq!(lexical_core::write_uninit(-12345i32, &mut buffer), b"-12345");
+    assert_eq!(lexical_core::write_uninit(0u8, &mut buffer[..3]), b"0");
+
+    let options = lexical_core::WriteIntegerOptions::new();
+    const FORMAT: u128 = lexical_core::format::STANDARD;
+    let mut vec = Vec::<u8>::with_capacity(u64::FORMATTED_SIZE_DECIMAL);
+    let count = lexical_core::write_with_options_uninit::<_, FORMAT>(
+        u64::MAX,
+        vec.spare_capacity_mut(),
+        &options,
+    )
+    .len();
+    unsafe { vec.set_len(count) };
+    assert_eq!(vec, b"18446744073709551615");
+}
+
+#[test]
+#[cfg(feature = "write-floats")]
+fn write_uninit_float_test() {
+    use core::mem::MaybeUninit;
+
+    let mut buffer = [MaybeUninit::<u8>::uninit(); lexical_core::BUFFER_SIZE];
+    assert_eq!(lexical_core::write_uninit(1.5f64, &mut buffer), b"1.5");
+    assert_eq!(lexical_core::write_uninit(-1.2345678e-7f32, &mut buffer), b"-1.2345679e-7");
+}
+
+#[test]
+#[cfg(feature = "write-integers")]
+fn write_to_fmt_test() {
+    use core::fmt::Write;
+
+    use lexical_core::{DisplayLexical, DisplayLexicalWithOptions};
+
+    let mut string = String::new();
+    lexical_core::write_to_fmt(-123i32, &mut string).unwrap();
+    string.push(',');
+    lexical_core::write_to_fmt(u128::MAX, &mut string).unwrap();
+    assert_eq!(string, "-123,340282366920938463463374607431768211455");
+
+    let options = lexical_core::WriteIntegerOptions::new();
+    const FORMAT: u128 = lexical_core::format::STANDARD;
+    let mut string = String::new();
+    lexical_core::write_with_options_to_fmt::<_, _, FORMAT>(42u8, &mut string, &options).unwrap();
+    assert_eq!(string, "42");
+
+    let mut string = String::new();
+    write!(
+        string,
+        "{}|{:<5}|{:*^7}",
+        DisplayLexical(1u8),
+        DisplayLexical(-2i64),
+        DisplayLexical(300u16)
+    )
+    .unwrap();
+    assert_eq!(string, "1|-2   |**300**");
+
+    let display = DisplayLexicalWithOptions::<_, FORMAT>::new(12345u32, &options);
+    assert_eq!(display.to_string(), "12345");
+
+    let options = lexical_core::WriteIntegerOptions::builder()
+        .min_width(core::num::NonZeroUsize::new(lexical_core::FMT_BUFFER_SIZE + 1))
+        .build()
+        .unwrap();
+    let mut string = String::new();
+    let result =
+        lexical_core::write_with_options_to_fmt::<_, _, FORMAT>(1u8, &mut string, &options);
+    assert!(result.is_err());
+}
+
+#[test]
+#[cfg(feature = "write-floats")]
+fn float_write_to_fmt_test() {
+    use core::fmt::Write;
+
+    use lexical_core::DisplayLexical;
+
+    let mut string = String::new();
+    lexical_core::write_to_fmt(1.5f32, &mut string).unwrap();
+    assert_eq!(string, "1.5");
+    assert_eq!(DisplayLexical(f64::MAX).to_string(), "1.7976931348623157e308");
+
+    let mut string = String::new();
+    write!(string, "{:>8}", DisplayLexical(-0.25f64)).unwrap();
+    assert_eq!(string, "   -0.25");
+}
+
+#[test]
+#[cfg(all(feature = "write-integers", feature = "std"))]
+fn write_to_io_test() {
+    let mut bytes = Vec::new();
+    let writer: &mut dyn std::io::Write = &mut bytes;
+    lexical_core::write_to_io(12u8, writer).unwrap();
+    lexical_core::write_to_io(-34i64, writer).unwrap();
+    assert_eq!(bytes, b"12-34");
+
+    let options = lexical_core::WriteIntegerOptions::new();
+    const FORMAT: u128 = lexical_core::format::STANDARD;
+    let mut bytes = Vec::new();
+    lexical_core::write_with_options_to_io::<_, _, FORMAT>(u64::MAX, &mut bytes, &options).unwrap();
+    assert_eq!(bytes, b"18446744073709551615");
+
+    let options = lexical_core::WriteIntegerOptions::builder()
+        .min_width(core::num::NonZeroUsize::new(lexical_core::FMT_BUFFER_SIZE + 1))
+        .build()
+        .unwrap();
+    let result = lexical_core::write_with_options_to_io::<_, _, FORMAT>(1u8, &mut bytes, &options);
+    assert_eq!(result.unwrap_err().kind(), std::io::ErrorKind::InvalidInput);
+
+    let mut buffer = [0u8; 2];
+    let result = lexical_core::write_to_io(123u32, &mut &mut buffer[..]);
+    assert_eq!(result.unwrap_err().kind(), std::io::ErrorKind::WriteZero);
+}
+
+#[test]
+#[cfg(all(feature = "write-integers", feature = "std"))]
+fn write_to_vec_test() {
+    let mut bytes = Vec::new();
+    assert_eq!(lexical_core::write_to_vec(12u8, &mut bytes), b"12");
+    assert_eq!(lexical_core::write_to_vec(-34i64, &mut bytes), b"-34");
+    assert_eq!(bytes, b"12-34");
+    assert_eq!(lexical_core::write_to_vec(u128::MAX, &mut bytes).len(), 39);
+    assert_eq!(bytes.len(), 44);
+
+    let options = lexical_core::WriteIntegerOptions::builder()
+        .min_width(core::num::NonZeroUsize::new(300))
+        .build()
+        .unwrap();
+    const FORMAT: u128 = lexical_core::format::STANDARD;
+    let mut bytes = b"x".to_vec();
+    let written = lexical_core::write_with_options_to_vec::<_, FORMAT>(7u32, &mut bytes, &options);
+    assert_eq!(written.len(), 300);
+    assert_eq!(written[299], b'7');
+    assert_eq!(bytes.len(), 301);
+}
+
+#[test]
+#[cfg(feature = "write-integers")]
+fn digits_test() {
+    use lexical_core::Digits;
+
+    let mut digits = Digits::new(u64::MAX);
+    assert_eq!(digits.len(), 20);
+    assert_eq!(digits.next(), Some(b'1'));
+    assert_eq!(digits.next_back(), Some(b'5'));
+    assert_eq!(digits.next_chunk(4), b"8446");
+    assert_eq!(digits.len(), 14);
+    assert_eq!(digits.next_chunk(100), b"74407370955161");
+    assert_eq!(digits.next_chunk(1), b"");
+    assert_eq!(digits.next(), None);
+    assert_eq!(digits.next_back(), None);
+
+    let digits = Digits::new(-5i8);
+    assert!(digits.clone().eq(b"-5".iter().copied()));
+    assert_eq!(digits.as_bytes(), b"-5");
+
+    const FORMAT: u128 = lexical_core::format::STANDARD;
+    let options = lexical_core::WriteIntegerOptions::new();
+    let digits = Digits::with_options::<_, FORMAT>(255u8, &options).unwrap();
+    assert_eq!(digits.as_bytes(), b"255");
+}
+
+#[test]
+#[cfg(feature = "write-floats")]
+fn float_digits_test() {
+    use core::num::NonZeroUsize;
+
+    use lexical_core::{BufferTooSmall, Digits};
+
+    let digits = Digits::new(-1.5e300f64);
+    assert_eq!(digits.as_bytes(), b"-1.5e300");
+
+    const FORMAT: u128 = lexical_core::format::STANDARD;
+    let options = lexical_core::WriteFloatOptions::builder()
+        .min_significant_digits(NonZeroUsize::new(1000))
+        .build()
+        .unwrap();
+    let size = lexical_core::WriteOptions::buffer_size::<f64, FORMAT>(&options);
+    let result = Digits::with_options::<_, FORMAT>(1.5f64, &options);
+    assert_eq!(
+        result.map(|x| x.len()),
+        Err(BufferTooSmall {
+            max_needed: size,
+        })
+    );
+}
+
+#[test]
+#[cfg(all(feature = "write-floats", feature = "std"))]
+fn float_write_to_io_test() {
+    let mut bytes = Vec::new();
+    lexical_core::write_to_io(1.25f64, &mut bytes).unwrap();
+    assert_eq!(bytes, b"1.25");
+}
+
+#[test]
+#[cfg(feature = "parse-integers")]
+fn parse_or_test() {
+    assert_eq!(lexical_core::parse_or(b"12345", 0u32), 12345);
+    assert_eq!(lexical_core::parse_or(b"-1", 7u32), 7);
+    assert_eq!(lexical_core::parse_or(b"256", 7u8), 7);
+    assert_eq!(lexical_core::parse_or(b"", -1i32), -1);
+    assert_eq!(lexical_core::parse_or(b"12 ", -1i32), -1);
+
+    let mut calls = 0;
+    let mut fallback = || {
+        calls += 1;
+        -1i64
+    };
+    assert_eq!(lexical_core::parse_or_else(b"42", &mut fallback), 42);
+    assert_eq!(lexical_core::parse_or_else(b"4x2", &mut fallback), -1);
+    assert_eq!(calls, 1);
+}
+
+#[test]
+#[cfg(feature = "parse-floats")]
+fn float_parse_or_test() {
+    assert_eq!(lexical_core::parse_or(b"1.5e3", f64::NAN), 1500.0);
+    assert!(lexical_core::parse_or(b"1.5e", f64::NAN).is_nan());
+    assert_eq!(lexical_core::parse_or_else(b"", || 0.5f32), 0.5);
+}
+
+#[test]
+#[cfg(feature = "parse-integers")]
+fn parse_optional_test() {
+    use lexical_core::Error;
+
+    assert_eq!(lexical_core::parse_optional::<i32>(b"-12"), Ok(Some(-12)));
+    assert_eq!(lexical_core::parse_optional::<i32>(b"0"), Ok(Some(0)));
+    assert_eq!(lexical_core::parse_optional::<i32>(b""), Ok(None));
+    assert_eq!(lexical_core::parse_optional::<i32>(b" \t\r\n"), Ok(None));
+    assert_eq!(lexical_core::parse_optional::<i32>(b"12 "), Err(Error::InvalidDigit(2)));
+    assert_eq!(lexical_core::parse_optional::<u8>(b"256"), Err(Error::Overflow(2)));
+
+    let options = lexical_core::ParseIntegerOptions::new();
+    const FORMAT: u128 = lexical_core::format::STANDARD;
+    let parse = |x| lexical_core::parse_optional_with_options::<u64, FORMAT>(x, &options);
+    assert_eq!(parse(b"99"), Ok(Some(99)));
+    assert_eq!(parse(b" "), Ok(None));
+}
+
+#[test]
+#[cfg(feature = "parse-integers")]
+fn parse_in_range_test() {
+    use lexical_core::Error;
+
+    assert_eq!(lexical_core::parse_in_range(b"1", 1u16..=65535), Ok(1));
+    assert_eq!(lexical_core::parse_in_range(b"65535", 1u16..=65535), Ok(65535));
+    assert_eq!(lexical_core::parse_in_range(b"0", 1u16..=65535), Err(Error::OutOfRange(0)));
+    assert_eq!(lexical_core::parse_in_range(b"10", 0u8..10), Err(Error::OutOfRange(0)));
+    assert_eq!(lexical_core::parse_in_range(b"-5", ..0i32), Ok(-5));
+    assert_eq!(lexical_core::parse_in_range(b"5", ..), Ok(5i32));
+    assert_eq!(lexical_core::parse_in_range(b"256", 0u8..), Err(Error::Overflow(2)));
+    assert_eq!(lexical_core::parse_in_range(b"", 0u8..), Err(Error::Empty(0)));
+    assert!(Error::OutOfRange(0).is_out_of_range());
+
+    let options = lexical_core::ParseIntegerOptions::new();
+    const FORMAT: u128 = lexical_core::format::STANDARD;
+    let result = lexical_core::parse_in_range_with_options::<i8, _, FORMAT>(b"-3", &options, -2..);
+    assert_eq!(result, Err(Error::OutOfRange(0)));
+}
+
+#[test]
+#[cfg(feature = "parse-floats")]
+fn float_parse_in_range_test() {
+    use lexical_core::Error;
+
+    assert_eq!(lexical_core::parse_in_range(b"0.5", 0.0f64..=1.0), Ok(0.5));
+    assert_eq!(lexical_core::parse_in_range(b"1", 0.0f64..1.0), Err(Error::OutOfRange(0)));
+    assert_eq!(lexical_core::parse_in_range(b"-0", 0.0f32..), Ok(-0.0));
+    assert_eq!(lexical_core::parse_in_range(b"inf", ..f64::MAX), Err(Error::OutOfRange(0)));
+    assert_eq!(lexical_core::parse_in_range(b"NaN", f64::MIN..), Err(Error::OutOfRange(0)));
+    assert!(lexical_core::parse_in_range::<f64, _>(b"NaN", ..).unwrap().is_nan());
+}
+
+#[test]
+#[cfg(all(feature = "parse-integers", feature = "std"))]
+fn parse_cstr_test() {
+    use std::ffi::{CStr, CString};
+
+    use lexical_core::Error;
+
+    let string = CString::new("-123").unwrap();
+    assert_eq!(lexical_core::parse_cstr::<i32>(&string), Ok(-123));
+    let string = CStr::from_bytes_with_nul(b"\0").unwrap();
+    assert_eq!(lexical_core::parse_cstr::<i32>(string), Err(Error::Empty(0)));
+    let string = CStr::from_bytes_with_nul(b"12a\0").unwrap();
+    assert_eq!(lexical_core::parse_cstr::<u8>(string), Err(Error::InvalidDigit(2)));
+
+    let options = lexical_core::ParseIntegerOptions::new();
+    const FORMAT: u128 = lexical_core::format::STANDARD;
+    let string = CStr::from_bytes_with_nul(b"255\0").unwrap();
+    let result = lexical_core::parse_cstr_with_options::<u8, FORMAT>(string, &options);
+    assert_eq!(result, Ok(255));
+}
+
+#[test]
+#[cfg(feature = "parse-integers")]
+fn parse_until_nul_test() {
+    use lexical_core::Error;
+
+    assert_eq!(lexical_core::parse_until_nul::<u32>(b"15"), Ok(15));
+    assert_eq!(lexical_core::parse_until_nul::<u32>(b"15\0"), Ok(15));
+    assert_eq!(lexical_core::parse_until_nul::<u32>(b"15\0\0\0\0"), Ok(15));
+    assert_eq!(lexical_core::parse_until_nul::<u32>(b"15\0a"), Ok(15));
+    assert_eq!(lexical_core::parse_until_nul::<u32>(b""), Err(Error::Empty(0)));
+    assert_eq!(lexical_core::parse_until_nul::<u32>(b"\0"), Err(Error::Empty(0)));
+    assert_eq!(lexical_core::parse_until_nul::<u32>(b"1a\0"), Err(Error::InvalidDigit(1)));
+    assert_eq!(lexical_core::parse_until_nul::<u8>(b"256\0"), Err(Error::Overflow(2)));
+
+    let options = lexical_core::ParseIntegerOptions::new();
+    const FORMAT: u128 = lexical_core::format::STANDARD;
+    let result = lexical_core::parse_until_nul_with_options::<i8, FORMAT>(b"-3\0", &options);
+    assert_eq!(result, Ok(-3));
+}
+
+#[test]
+#[cfg(all(feature = "parse-floats", feature = "std"))]
+fn float_parse_cstr_test() {
+    use std::ffi::CStr;
+
+    let string = CStr::from_bytes_with_nul(b"1.5e3\0").unwrap();
+    assert_eq!(lexical_core::parse_cstr::<f64>(string), Ok(1500.0));
+    assert_eq!(lexical_core::parse_until_nul::<f32>(b"-0.25\0\0"), Ok(-0.25));
+    assert_eq!(lexical_core::parse_until_nul::<f64>(b"1.5\0e3"), Ok(1.5));
+}
+
+#[test]
+#[cfg(feature = "parse-integers")]
+fn parse_record_test() {
+    use lexical_core::{BatchError, Error};
+
+    let mut row = [None; 3];
+    assert_eq!(lexical_core::parse_record::<i32>(b"1,2,3", b',', &mut row), Ok(3));
+    assert_eq!(row, [Some(1), Some(2), Some(3)]);
+    assert_eq!(lexical_core::parse_record::<i32>(b" 4 ,, \"-5\"\r\n", b',', &mut row), Ok(3));
+    assert_eq!(row, [Some(4), None, Some(-5)]);
+    assert_eq!(lexical_core::parse_record::<i32>(b"", b',', &mut row), Ok(1));
+    assert_eq!(row, [None, None, Some(-5)]);
+    assert_eq!(lexical_core::parse_record::<i32>(b"6\t7\t8\t9", b'\t', &mut row), Ok(4));
+    assert_eq!(row, [Some(6), Some(7), Some(8)]);
+    assert_eq!(lexical_core::parse_record::<i32>(b"1;2", b';', &mut row), Ok(2));
+    assert_eq!(row, [Some(1), Some(2), Some(8)]);
+
+    let result = lexical_core::parse_record::<i32>(b"1,\"2,3\",4", b',', &mut row);
+    assert_eq!(
+        result,
+        Err(BatchError {
+            index: 1,
+            error: Error::InvalidDigit(1),
+        })
+    );
+    assert_eq!(row[0], Some(1));
+    let result = lexical_core::parse_record::<u8>(b"1,2,256", b',', &mut [None; 3]);
+    assert_eq!(
+        result,
+        Err(BatchError {
+            index: 2,
+            error: Error::Overflow(2),
+        })
+    );
+
+    let options = lexical_core::ParseIntegerOptions::new();
+    const FORMAT: u128 = lexical_core::format::STANDARD;
+    let mut row = [None; 2];
+    let result =
+        lexical_core::parse_record_with_options::<u16, FORMAT>(b"65535,", b',', &mut row, &options);
+    assert_eq!(result, Ok(2));
+    assert_eq!(row, [Some(65535), None]);
+}
+
+#[test]
+#[cfg(all(feature = "parse-floats", feature = "format"))]
+fn parse_record_quoted_test() {
+    const FORMAT: u128 = lexical_core::NumberFormatBuilder::new()
+        .digit_separator(std::num::NonZeroU8::new(b','))
+        .integer_internal_digit_separator(true)
+        .build_strict();
+    let options = lexical_core::ParseFloatOptions::new();
+    let mut row = [None; 2];
+    let record = b"\"1,000.5\",2";
+    let result =
+        lexical_core::parse_record_with_options::<f64, FORMAT>(record, b',', &mut row, &options);
+    assert_eq!(result, Ok(2));
+    assert_eq!(row, [Some(1000.5), Some(2.0)]);
+}
+
+#[test]
+#[cfg(feature = "parse-integers")]
+fn parse_many_test() {
+    use lexical_core::{Delimiter, Error};
+
+    let parse = |bytes: &[u8], delimiter| {
+        lexical_core::parse_many::<i32>(bytes, delimiter).collect::<Vec<_>>()
+    };
+    assert_eq!(parse(b"", Delimiter::Whitespace), []);
+    assert_eq!(parse(b" \n ", Delimiter::Comma), []);
+    assert_eq!(parse(b"1 -2\t\r\n3", Delimiter::Whitespace), [Ok(1), Ok(-2), Ok(3)]);
+    assert_eq!(parse(b"  1  2  ", Delimiter::Whitespace), [Ok(1), Ok(2)]);
+    assert_eq!(parse(b"1,2 , 3", Delimiter::Comma), [Ok(1), Ok(2), Ok(3)]);
+    assert_eq!(parse(b"1|2", Delimiter::Byte(b'|')), [Ok(1), Ok(2)]);
+    assert_eq!(parse(b"1,,2,", Delimiter::Comma), [
+        Ok(1),
+        Err(Error::Empty(2)),
+        Ok(2),
+        Err(Error::Empty(5))
+    ]);
+    assert_eq!(parse(b"1 x2 3", Delimiter::Whitespace), [
+        Ok(1),
+        Err(Error::InvalidDigit(2)),
+        Ok(3)
+    ]);
+    assert_eq!(parse(b"1, 2 3", Delimiter::Comma), [Ok(1), Err(Error::InvalidDigit(4))]);
+
+    let mut iter = lexical_core::parse_many::<u8>(b" 1, 256", Delimiter::Comma);
+    assert_eq!(iter.next(), Some(Ok(1)));
+    assert_eq!(iter.offset(), 1);
+    assert_eq!(iter.next(), Some(Err(Error::Overflow(6))));
+    assert_eq!(iter.offset(), 4);
+    assert_eq!(iter.next(), None);
+    assert_eq!(iter.next(), None);
+
+    let options = lexical_core::ParseIntegerOptions::new();
+    const FORMAT: u128 = lexical_core::format::STANDARD;
+    let iter = lexical_core::parse_many_with_options::<u16, FORMAT>(
+        b"1\n65535",
+        Delimiter::Whitespace,
+        &options,
+    );
+    assert_eq!(iter.collect::<Vec<_>>(), [Ok(1), Ok(65535)]);
+}
+
+#[test]
+#[cfg(feature = "parse-floats")]
+fn parse_many_float_test() {
+    use lexical_core::Delimiter;
+
+    let iter = lexical_core::parse_many::<f64>(b"1.5, -2e3 ,NaN", Delimiter::Comma);
+    let values = iter.collect::<lexical_core::Result<Vec<_>>>().unwrap();
+    assert_eq!(values[..2], [1.5, -2000.0]);
+    assert!(values[2].is_nan());
+}
+
+#[test]
+#[cfg(feature = "parse-integers")]
+fn parse_field_test() {
+    use lexical_core::Error;
+
+    assert_eq!(lexical_core::parse_field::<i32>(b"-12"), Ok(Some(-12)));
+    assert_eq!(lexical_core::parse_field::<i32>(b" \t42\r\n"), Ok(Some(42)));
+    assert_eq!(lexical_core::parse_field::<i32>(b"\"7\""), Ok(Some(7)));
+    assert_eq!(lexical_core::parse_field::<i32>(b" \" 7 \" "), Ok(Some(7)));
+    assert_eq!(lexical_core::parse_field::<i32>(b""), Ok(None));
+    assert_eq!(lexical_core::parse_field::<i32>(b"   "), Ok(None));
+    assert_eq!(lexical_core::parse_field::<i32>(b"\"\""), Ok(None));
+    assert_eq!(lexical_core::parse_field::<i32>(b"\""), Err(Error::InvalidDigit(0)));
+    assert_eq!(lexical_core::parse_field::<i32>(b"\"1"), Err(Error::InvalidDigit(0)));
+    assert_eq!(lexical_core::parse_field::<i32>(b" 1 2 "), Err(Error::InvalidDigit(1)));
+    assert_eq!(lexical_core::parse_field::<u8>(b"256"), Err(Error::Overflow(2)));
+
+    assert_eq!(lexical_core::parse_field_or(b"", 0i64), Ok(0));
+    assert_eq!(lexical_core::parse_field_or(b" 5 ", 0i64), Ok(5));
+
+    let options = lexical_core::ParseIntegerOptions::new();
+    const FORMAT: u128 = lexical_core::format::STANDARD;
+    let result = lexical_core::parse_field_with_options::<u16, FORMAT>(b" 65535 ", &options);
+    assert_eq!(result, Ok(Some(65535)));
+    let result = lexical_core::parse_field_or_with_options::<u16, FORMAT>(b"", 1, &options);
+    assert_eq!(result, Ok(1));
+}
+
+#[test]
+#[cfg(feature = "parse-floats")]
+fn parse_float_field_test() {
+    assert_eq!(lexical_core::parse_field::<f64>(b" 1.5e3 "), Ok(Some(1500.0)));
+    assert_eq!(
+        lexical_core::parse_field::<f64>(b"\"NaN\"").map(|x| x.map(f64::is_nan)),
+        Ok(Some(true))
+    );
+    assert!(lexical_core::parse_field_or(b"\"\"", f32::NAN).unwrap().is_nan());
+    assert_eq!(lexical_core::parse_field_or(b"0.1", f32::NAN), Ok(0.1));
+}
+
+#[test]
+#[cfg(feature = "parse-floats")]
+fn lossy_wrapper_test() {
+    use lexical_core::{LossyF32, LossyF64};
+
+    assert_eq!("1.5".parse::<LossyF32>(), Ok(LossyF32(1.5)));
+    assert_eq!("-2e3".parse::<LossyF64>(), Ok(LossyF64(-2000.0)));
+    assert_eq!("1.5x".parse::<LossyF64>(), Err(lexical_core::Error::InvalidDigit(3)));
+    assert_eq!(f64::from(LossyF64(0.5)), 0.5);
+    assert_eq!(LossyF32::from(0.25), LossyF32(0.25));
+}
+
+#[test]
+#[cfg(all(feature = "parse-floats", feature = "format"))]
+fn json_wrapper_test() {
+    use lexical_core::{JsonF32, JsonF64};
+
+    assert_eq!("-1.5e3".parse::<JsonF32>(), Ok(JsonF32(-1500.0)));
+    assert_eq!("0.1".parse::<JsonF64>(), Ok(JsonF64(0.1)));
+    assert_eq!("1".parse::<JsonF64>(), Ok(JsonF64(1.0)));
+    assert!("+1".parse::<JsonF64>().is_err());
+    assert!(".5".parse::<JsonF64>().is_err());
+    assert!("1.".parse::<JsonF64>().is_err());
+    assert!("NaN".parse::<JsonF64>().is_err());
+    assert!("01".parse::<JsonF64>().is_err());
+}
+
+#[test]
+#[cfg(all(feature = "parse-floats", feature = "write-floats"))]
+fn decimal_test() {
+    use lexical_core::{Decimal, Error};
+
+    let decimal = |sign: bool, digits: &[u8], exponent: i32| Decimal {
+        sign,
+        digits: digits.to_vec(),
+        exponent,
+    };
+    assert_eq!("1.5".parse::<Decimal>(), Ok(decimal(false, b"15", -1)));
+    assert_eq!("-001200".parse::<Decimal>(), Ok(decimal(true, b"12", 2)));
+    assert_eq!("+0.0012e-3".parse::<Decimal>(), Ok(decimal(false, b"12", -7)));
+    assert_eq!("0.000e10".parse::<Decimal>(), Ok(decimal(false, b"", 0)));
+    assert_eq!("-0".parse::<Decimal>(), Ok(decimal(true, b"", 0)));
+    assert_eq!("1e99999999999".parse::<Decimal>(), Ok(decimal(false, b"1", i32::MAX)));
+    assert!("NaN".parse::<Decimal>().is_err());
+    assert!("1.5x".parse::<Decimal>().is_err());
+    assert!("".parse::<Decimal>().is_err());
+
+    // Every digit is kept, so the halfway point rounds correctly.
+    let halfway: Decimal = "9007199254740993.0000000000000000000001".parse().unwrap();
+    assert_eq!(halfway.digits.len(), 38);
+    assert_eq!(halfway.to_float::<f64>(), Ok(9007199254740994.0));
+    assert_eq!(decimal(true, b"", 0).to_float::<f64>().map(f64::to_bits), Ok((-0.0f64).to_bits()));
+    assert_eq!(decimal(false, b"125", -2).to_float::<f32>(), Ok(1.25));
+    assert_eq!(decimal(false, b"1", 400).to_float::<f64>(), Ok(f64::INFINITY));
+    assert_eq!(decimal(false, b"1", i32::MIN).to_float::<f64>(), Ok(0.0));
+    assert_eq!(decimal(false, b"1x", 0).to_float::<f64>(), Err(Error::InvalidDigit(1)));
+    assert_eq!(decimal(true, b"1.5", 0).to_float::<f64>(), Err(Error::InvalidDigit(1)));
+    assert_eq!(decimal(false, b"e5", 0).to_float::<f64>(), Err(Error::InvalidDigit(0)));
+
+    assert_eq!(Decimal::from_float(1.5f64), Some(decimal(false, b"15", -1)));
+    assert_eq!(Decimal::from_float(-1e22f64), Some(decimal(true, b"1", 22)));
+    assert_eq!(Decimal::from_float(0.0f64), Some(decimal(false, b"", 0)));
+    assert_eq!(Decimal::from_float(-0.0f32), Some(decimal(true, b"", 0)));
+    assert_eq!(Decimal::from_float(f32::NEG_INFINITY), None);
+    for &float in &[f64::MIN_POSITIVE, f64::MAX, -f64::from_bits(1), 0.1, 123456.789] {
+        let decimal = Decimal::from_float(float).unwrap();
+        assert_eq!(decimal.to_float::<f64>(), Ok(float));
+    }
+}
+
+#[test]
+#[cfg(all(feature = "std", feature = "parse-floats", feature = "write-integers"))]
+fn format_registry_test() {
+    use core::num::NonZeroUsize;
+
+    use lexical_core::{Format, FormattedSize, ParseFloatOptions, WriteIntegerOptions};
+
+    const FORMAT: u128 = lexical_core::format::STANDARD;
+    let format = Format {
+        parse_float: ParseFloatOptions::builder().exponent(b'd').build().unwrap(),
+        write_integer: WriteIntegerOptions::builder()
+            .min_width(NonZeroUsize::new(4))
+            .build()
+            .unwrap(),
+        ..Format::new()
+    };
+    assert_eq!(Format::register("registry_test", format.clone()), None);
+    assert!(Format::names().contains(&"registry_test".to_string()));
+
+    let registered = Format::get("registry_test").unwrap();
+    assert_eq!(registered, format);
+    let value: f64 =
+        lexical_core::parse_with_options::<_, FORMAT>(b"1.5d2", &registered.parse_float).unwrap();
+    assert_eq!(value, 150.0);
+    let mut buffer = [0u8; u32::FORMATTED_SIZE_DECIMAL];
+    let bytes = lexical_core::write_with_options::<_, FORMAT>(
+        12u32,
+        &mut buffer,
+        &registered.write_integer,
+    );
+    assert_eq!(bytes, b"0012");
+
+    // Registering under the same name replaces the format.
+    assert_eq!(Format::register("registry_test", Format::new()), Some(format));
+    assert_eq!(Format::get("registry_test"), Some(Format::new()));
+    assert_eq!(Format::unregister("registry_test"), Some(Format::new()));
+    assert_eq!(Format::get("registry_test"), None);
+    assert_eq!(Format::unregister("registry_test"), None);
+}
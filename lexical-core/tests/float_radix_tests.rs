@@ -96,43 +96,67 @@ macro_rules! test_radix {
     }};
 }
 
+macro_rules! test_radix_exact {
+    ($f:ident, $radix:expr, $buffer:ident, $data:ident) => {{
+        use lexical_core::{
+            FromLexicalWithOptions,
+            NumberFormatBuilder,
+            ParseFloatOptions,
+            ToLexicalWithOptions,
+            WriteFloatOptions,
+        };
+
+        const FORMAT: u128 = NumberFormatBuilder::from_radix($radix);
+        let write_options = WriteFloatOptions::builder().exponent(b'^').build().unwrap();
+        let parse_options = ParseFloatOptions::builder().exponent(b'^').build().unwrap();
+        for &float in $data.iter() {
+            let data = float.to_lexical_with_options::<FORMAT>(&mut $buffer, &write_options);
+            let roundtrip = $f::from_lexical_with_options::<FORMAT>(data, &parse_options).unwrap();
+            assert_eq!(float, roundtrip, "radix {}: {:?}", $radix, data);
+        }
+    }};
+}
+
 macro_rules! test_all {
     ($f:ident, $buffer:ident, $data:ident) => {{
-        test_radix!($f, 2, $buffer, $data);
-        test_radix!($f, 3, $buffer, $data);
-        test_radix!($f, 4, $buffer, $data);
-        test_radix!($f, 5, $buffer, $data);
-        test_radix!($f, 6, $buffer, $data);
-        test_radix!($f, 7, $buffer, $data);
-        test_radix!($f, 8, $buffer, $data);
-        test_radix!($f, 9, $buffer, $data);
-        test_radix!($f, 19, $buffer, $data);
-        test_radix!($f, 11, $buffer, $data);
-        test_radix!($f, 12, $buffer, $data);
-        test_radix!($f, 13, $buffer, $data);
-        test_radix!($f, 14, $buffer, $data);
-        test_radix!($f, 15, $buffer, $data);
-        test_radix!($f, 16, $buffer, $data);
-        test_radix!($f, 17, $buffer, $data);
-        test_radix!($f, 18, $buffer, $data);
-        test_radix!($f, 19, $buffer, $data);
-        test_radix!($f, 20, $buffer, $data);
-        test_radix!($f, 21, $buffer, $data);
-        test_radix!($f, 22, $buffer, $data);
-        test_radix!($f, 23, $buffer, $data);
-        test_radix!($f, 24, $buffer, $data);
-        test_radix!($f, 25, $buffer, $data);
-        test_radix!($f, 26, $buffer, $data);
-        test_radix!($f, 27, $buffer, $data);
-        test_radix!($f, 28, $buffer, $data);
-        test_radix!($f, 29, $buffer, $data);
-        test_radix!($f, 30, $buffer, $data);
-        test_radix!($f, 31, $buffer, $data);
-        test_radix!($f, 32, $buffer, $data);
-        test_radix!($f, 33, $buffer, $data);
-        test_radix!($f, 34, $buffer, $data);
-        test_radix!($f, 35, $buffer, $data);
-        test_radix!($f, 36, $buffer, $data);
+        test_all!(test_radix, $f, $buffer, $data)
+    }};
+    ($test:ident, $f:ident, $buffer:ident, $data:ident) => {{
+        $test!($f, 2, $buffer, $data);
+        $test!($f, 3, $buffer, $data);
+        $test!($f, 4, $buffer, $data);
+        $test!($f, 5, $buffer, $data);
+        $test!($f, 6, $buffer, $data);
+        $test!($f, 7, $buffer, $data);
+        $test!($f, 8, $buffer, $data);
+        $test!($f, 9, $buffer, $data);
+        $test!($f, 19, $buffer, $data);
+        $test!($f, 11, $buffer, $data);
+        $test!($f, 12, $buffer, $data);
+        $test!($f, 13, $buffer, $data);
+        $test!($f, 14, $buffer, $data);
+        $test!($f, 15, $buffer, $data);
+        $test!($f, 16, $buffer, $data);
+        $test!($f, 17, $buffer, $data);
+        $test!($f, 18, $buffer, $data);
+        $test!($f, 19, $buffer, $data);
+        $test!($f, 20, $buffer, $data);
+        $test!($f, 21, $buffer, $data);
+        $test!($f, 22, $buffer, $data);
+        $test!($f, 23, $buffer, $data);
+        $test!($f, 24, $buffer, $data);
+        $test!($f, 25, $buffer, $data);
+        $test!($f, 26, $buffer, $data);
+        $test!($f, 27, $buffer, $data);
+        $test!($f, 28, $buffer, $data);
+        $test!($f, 29, $buffer, $data);
+        $test!($f, 30, $buffer, $data);
+        $test!($f, 31, $buffer, $data);
+        $test!($f, 32, $buffer, $data);
+        $test!($f, 33, $buffer, $data);
+        $test!($f, 34, $buffer, $data);
+        $test!($f, 35, $buffer, $data);
+        $test!($f, 36, $buffer, $data);
     }};
 }
 
@@ -147,3 +171,22 @@ fn parse_f64_radix_roundtrip_test() {
     let mut buffer = [0u8; 1024];
     test_all!(f64, buffer, F64_DATA);
 }
+
+const F32_EDGE_DATA: [f32; 6] =
+    [f32::MIN_POSITIVE, f32::MAX, 1.0e-45, 1.1754942e-38, 0.3, 16777215.0];
+const F64_EDGE_DATA: [f64; 6] =
+    [f64::MIN_POSITIVE, f64::MAX, 5.0e-324, 2.2250738585072009e-308, 0.3, 9007199254740991.0];
+
+#[test]
+fn write_f32_radix_exact_test() {
+    let mut buffer = [0u8; 1024];
+    test_all!(test_radix_exact, f32, buffer, F32_DATA);
+    test_all!(test_radix_exact, f32, buffer, F32_EDGE_DATA);
+}
+
+#[test]
+fn write_f64_radix_exact_test() {
+    let mut buffer = [0u8; 1024];
+    test_all!(test_radix_exact, f64, buffer, F64_DATA);
+    test_all!(test_radix_exact, f64, buffer, F64_EDGE_DATA);
+}
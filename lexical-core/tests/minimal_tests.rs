@@ -0,0 +1,43 @@
+// Only use the API available in the minimal build, from `docs/MinimalBuild.md`.
+
+#![cfg(all(feature = "parse-integers", feature = "write-integers"))]
+
+use lexical_core::{format, FormattedSize, ParseIntegerOptions, WriteIntegerOptions};
+
+#[test]
+fn parse_test() {
+    assert_eq!(lexical_core::parse::<u8>(b"255"), Ok(255));
+    assert_eq!(lexical_core::parse::<i32>(b"-12345"), Ok(-12345));
+    assert_eq!(lexical_core::parse_partial::<u16>(b"80,443"), Ok((80, 2)));
+    assert!(lexical_core::parse::<u8>(b"256").is_err());
+    assert!(lexical_core::parse::<u32>(b"ff").is_err());
+
+    const FORMAT: u128 = format::STANDARD;
+    let options = ParseIntegerOptions::new();
+    let result = lexical_core::parse_with_options::<u64, FORMAT>(b"18446744073709551615", &options);
+    assert_eq!(result, Ok(u64::MAX));
+    let result = lexical_core::parse_partial_with_options::<i8, FORMAT>(b"-128 ", &options);
+    assert_eq!(result, Ok((i8::MIN, 4)));
+}
+
+#[test]
+fn write_test() {
+    let mut buffer = [0u8; u32::FORMATTED_SIZE_DECIMAL];
+    assert_eq!(lexical_core::write(4294967295u32, &mut buffer), b"4294967295");
+
+    const FORMAT: u128 = format::STANDARD;
+    let options = WriteIntegerOptions::new();
+    let mut buffer = [0u8; i16::FORMATTED_SIZE_DECIMAL];
+    let bytes = lexical_core::write_with_options::<_, FORMAT>(i16::MIN, &mut buffer, &options);
+    assert_eq!(bytes, b"-32768");
+}
+
+#[test]
+fn const_test() {
+    const PORT: u16 = match lexical_core::parse_const::u16(b"8080") {
+        Ok(value) => value,
+        Err(_) => panic!("invalid port"),
+    };
+    assert_eq!(PORT, 8080);
+    assert_eq!(lexical_core::write_const::u16(PORT).as_str(), "8080");
+}
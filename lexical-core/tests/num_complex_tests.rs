@@ -0,0 +1,50 @@
+#![cfg(feature = "num-complex")]
+
+use num_complex::Complex;
+
+#[test]
+#[cfg(feature = "parse-floats")]
+fn parse_num_complex_test() {
+    assert_eq!(lexical_core::parse_num_complex::<f64>(b"3+4i"), Ok(Complex::new(3.0, 4.0)));
+    assert_eq!(lexical_core::parse_num_complex::<f32>(b"-2.5j"), Ok(Complex::new(0.0, -2.5)));
+    assert_eq!(lexical_core::parse_num_complex::<f64>(b"1e3"), Ok(Complex::new(1000.0, 0.0)));
+    assert!(lexical_core::parse_num_complex::<f64>(b"3+4").is_err());
+
+    const FORMAT: u128 = lexical_core::format::STANDARD;
+    let options = lexical_core::ParseFloatOptions::builder().imaginary_units(b"I").build().unwrap();
+    let result = lexical_core::parse_num_complex_with_options::<f64, FORMAT>(b"1-2I", &options);
+    assert_eq!(result, Ok(Complex::new(1.0, -2.0)));
+}
+
+#[test]
+#[cfg(feature = "write-floats")]
+fn write_num_complex_test() {
+    let mut buffer = [0u8; 2 * lexical_core::BUFFER_SIZE + 2];
+    let value = Complex::new(3.0f64, -4.0);
+    assert_eq!(lexical_core::write_num_complex(value, &mut buffer), b"3.0-4.0i");
+
+    const FORMAT: u128 = lexical_core::format::STANDARD;
+    let options = lexical_core::WriteFloatOptions::builder()
+        .imaginary_unit(b'j')
+        .trim_floats(true)
+        .build()
+        .unwrap();
+    let bytes =
+        lexical_core::write_num_complex_with_options::<_, FORMAT>(value, &mut buffer, &options);
+    assert_eq!(bytes, b"3-4j");
+}
+
+#[test]
+#[cfg(all(feature = "parse-floats", feature = "write-floats"))]
+fn num_complex_roundtrip_test() {
+    let mut buffer = [0u8; 2 * lexical_core::BUFFER_SIZE + 2];
+    for value in [
+        Complex::new(0.1f64, -0.2),
+        Complex::new(-1.5e300, 2.5e-300),
+        Complex::new(f64::INFINITY, f64::NEG_INFINITY),
+        Complex::new(0.0, -0.0),
+    ] {
+        let bytes = lexical_core::write_num_complex(value, &mut buffer);
+        assert_eq!(lexical_core::parse_num_complex::<f64>(bytes), Ok(value));
+    }
+}
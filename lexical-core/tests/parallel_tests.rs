@@ -0,0 +1,97 @@
+#![cfg(feature = "parallel")]
+
+#[test]
+#[cfg(feature = "write-integers")]
+fn write_slice_parallel_test() {
+    use lexical_core::FormattedSize;
+
+    let values: Vec<i64> = (0..5000)
+        .map(|x| {
+            x * x
+                * if x % 3 == 0 {
+                    -1
+                } else {
+                    1
+                }
+        })
+        .collect();
+    let mut expected = vec![0u8; values.len() * i64::FORMATTED_SIZE_DECIMAL];
+    let mut expected_offsets = vec![0usize; values.len()];
+    let expected = lexical_core::write_slice(&values, &mut expected, &mut expected_offsets);
+
+    let mut buffer = vec![0u8; values.len() * i64::FORMATTED_SIZE_DECIMAL];
+    let mut offsets = vec![0usize; values.len()];
+    let bytes = lexical_core::write_slice_parallel(&values, &mut buffer, &mut offsets);
+    assert_eq!(bytes, expected);
+    assert_eq!(offsets, expected_offsets);
+
+    let bytes = lexical_core::write_slice_parallel::<u8>(&[], &mut buffer, &mut []);
+    assert_eq!(bytes, b"");
+
+    let options = lexical_core::WriteIntegerOptions::new();
+    const FORMAT: u128 = lexical_core::format::STANDARD;
+    let bytes = lexical_core::write_slice_parallel_with_options::<_, FORMAT>(
+        &values,
+        &mut buffer,
+        &mut offsets,
+        &options,
+    );
+    assert_eq!(bytes, expected);
+    assert_eq!(offsets, expected_offsets);
+}
+
+#[test]
+#[should_panic]
+#[cfg(feature = "write-integers")]
+fn write_slice_parallel_small_buffer_test() {
+    let mut buffer = [0u8; 4];
+    _ = lexical_core::write_slice_parallel(&[1u32, 2], &mut buffer, &mut [0; 2]);
+}
+
+#[test]
+#[cfg(feature = "parse-integers")]
+fn parse_slice_parallel_test() {
+    let inputs: Vec<String> = (0..5000).map(|x: i64| (x * x - 1000).to_string()).collect();
+    let mut outputs = vec![0i64; inputs.len()];
+    assert_eq!(lexical_core::parse_slice_parallel(&inputs, &mut outputs), Ok(()));
+    assert!(outputs.iter().enumerate().all(|(i, &x)| x == (i * i) as i64 - 1000));
+
+    // The error with the lowest index is always returned.
+    let mut inputs = inputs;
+    inputs[4500] = "x".to_string();
+    inputs[3000] = "1a".to_string();
+    inputs[1500] = "".to_string();
+    let result = lexical_core::parse_slice_parallel(&inputs, &mut outputs);
+    assert_eq!(
+        result,
+        Err(lexical_core::BatchError {
+            index: 1500,
+            error: lexical_core::Error::Empty(0),
+        })
+    );
+
+    let options = lexical_core::ParseIntegerOptions::new();
+    const FORMAT: u128 = lexical_core::format::STANDARD;
+    inputs[1500] = "0".to_string();
+    let result = lexical_core::parse_slice_parallel_with_options::<_, _, FORMAT>(
+        &inputs,
+        &mut outputs,
+        &options,
+    );
+    assert_eq!(
+        result,
+        Err(lexical_core::BatchError {
+            index: 3000,
+            error: lexical_core::Error::InvalidDigit(1),
+        })
+    );
+}
+
+#[test]
+#[cfg(feature = "parse-floats")]
+fn parse_slice_parallel_float_test() {
+    let inputs: Vec<String> = (0..3000).map(|x| x.to_string() + ".5").collect();
+    let mut outputs = vec![0f64; inputs.len()];
+    assert_eq!(lexical_core::parse_slice_parallel(&inputs, &mut outputs), Ok(()));
+    assert!(outputs.iter().enumerate().all(|(i, &x)| x == i as f64 + 0.5));
+}
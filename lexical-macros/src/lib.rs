@@ -0,0 +1,281 @@
+//! Procedural macros to parse numeric literals at compile time with lexical.
+//!
+//! The [`lexical_parse!`] macro parses a string literal with lexical's
+//! parsers when the crate is compiled, and expands to a typed numeric
+//! literal, so there is no runtime cost. Unlike Rust's own literals, the
+//! string may contain a sign, `_` digit separators in the integer,
+//! fraction, and exponent, and floats may use any radix from 2 to 36.
+//!
+//! ```rust
+//! use lexical_macros::lexical_parse;
+//!
+//! const SCALE: f64 = lexical_parse!("1_234.5e6", f64);
+//! const MASK: u32 = lexical_parse!("ff_ff", u32, radix = 16);
+//! const HALF: f32 = lexical_parse!("0.1", f32, radix = 2);
+//! assert_eq!(SCALE, 1234.5e6);
+//! assert_eq!(MASK, 0xffff);
+//! assert_eq!(HALF, 0.5);
+//! ```
+//!
+//! Invalid numbers, such as integers that overflow, are reported as
+//! compile errors.
+//!
+//! # Syntax
+//!
+//! ```text
+//! lexical_parse!(<string literal>, <type> [, radix = <2-36>])
+//! ```
+//!
+//! The supported types are `u8`, `u16`, `u32`, `u64`, `u128`, `usize`,
+//! `i8`, `i16`, `i32`, `i64`, `i128`, `isize`, `f32`, and `f64`. Floats
+//! with a radix of 15 or higher use `^` for the exponent character,
+//! since `e` is a valid digit. Special values, such as `NaN` or `inf`,
+//! are not supported.
+
+#![deny(missing_docs, unsafe_code)]
+
+extern crate proc_macro;
+
+use core::num::NonZeroU8;
+
+use lexical_core::format::NumberFormatBuilder;
+use lexical_core::{FromLexicalWithOptions, ParseFloatOptions, ParseIntegerOptions};
+use proc_macro::{Delimiter, Group, Ident, Literal, Punct, Spacing, Span, TokenStream, TokenTree};
+
+/// Parse a numeric string literal at compile time.
+///
+/// See the [crate documentation](crate) for the syntax and examples.
+#[proc_macro]
+pub fn lexical_parse(input: TokenStream) -> TokenStream {
+    match expand(input) {
+        Ok(tokens) => tokens,
+        Err((message, span)) => compile_error(&message, span),
+    }
+}
+
+/// Result of the expansion, with an error message and its location.
+type Result<T> = core::result::Result<T, (String, Span)>;
+
+/// Create the number format for the given radix.
+const fn format(radix: u8) -> u128 {
+    NumberFormatBuilder::new()
+        .radix(radix)
+        .exponent_base(NonZeroU8::new(radix))
+        .exponent_radix(NonZeroU8::new(radix))
+        .digit_separator(NonZeroU8::new(b'_'))
+        .internal_digit_separator(true)
+        .trailing_digit_separator(true)
+        .consecutive_digit_separator(true)
+        .no_special(true)
+        .build()
+}
+
+/// Parse the entire string using the format for the radix.
+fn parse<N: FromLexicalWithOptions>(
+    bytes: &[u8],
+    radix: u8,
+    options: &N::Options,
+) -> lexical_core::Result<N> {
+    macro_rules! dispatch {
+        ($($radix:literal)*) => {
+            match radix {
+                $($radix => N::from_lexical_with_options::<{ format($radix) }>(bytes, options),)*
+                _ => unreachable!(),
+            }
+        };
+    }
+
+    dispatch! {
+        2 3 4 5 6 7 8 9 10 11 12 13 14 15 16 17 18 19
+        20 21 22 23 24 25 26 27 28 29 30 31 32 33 34 35 36
+    }
+}
+
+/// Parse the macro arguments and expand to the literal.
+fn expand(input: TokenStream) -> Result<TokenStream> {
+    let mut tokens = input.into_iter().map(flatten);
+    let call_site = Span::call_site();
+
+    let (string, string_span) = match tokens.next() {
+        Some(TokenTree::Literal(literal)) => (string_value(&literal)?, literal.span()),
+        Some(token) => return Err(("expected a string literal".to_string(), token.span())),
+        None => return Err(("expected a string literal".to_string(), call_site)),
+    };
+    expect_punct(tokens.next(), ',')?;
+    let ty = match tokens.next() {
+        Some(TokenTree::Ident(ident)) => ident,
+        Some(token) => return Err(("expected a numeric type".to_string(), token.span())),
+        None => return Err(("expected a numeric type".to_string(), call_site)),
+    };
+
+    let mut radix = 10u8;
+    if let Some(token) = tokens.next() {
+        expect_punct(Some(token), ',')?;
+        match tokens.next() {
+            Some(TokenTree::Ident(ident)) if ident.to_string() == "radix" => {
+                expect_punct(tokens.next(), '=')?;
+                radix = match tokens.next() {
+                    Some(TokenTree::Literal(literal)) => match literal.to_string().parse::<u8>() {
+                        Ok(value @ 2..=36) => value,
+                        _ => {
+                            let message = "radix must be an integer from 2 to 36";
+                            return Err((message.to_string(), literal.span()));
+                        },
+                    },
+                    Some(token) => return Err(("expected a radix".to_string(), token.span())),
+                    None => return Err(("expected a radix".to_string(), call_site)),
+                };
+                if let Some(token) = tokens.next() {
+                    expect_punct(Some(token), ',')?;
+                }
+            },
+            // Allow a trailing comma.
+            None => (),
+            Some(token) => return Err(("expected `radix = ...`".to_string(), token.span())),
+        }
+    }
+    if let Some(token) = tokens.next() {
+        return Err(("unexpected token".to_string(), token.span()));
+    }
+
+    let bytes = string.as_bytes();
+    let error = |error: lexical_core::Error| {
+        let message = "invalid ".to_string() + &ty.to_string() + " literal: " + &error.to_string();
+        (message, string_span)
+    };
+    macro_rules! integer {
+        ($t:ty) => {{
+            let options = ParseIntegerOptions::new();
+            let value = parse::<$t>(bytes, radix, &options).map_err(error)?;
+            integer_literal(value.to_string(), &ty.to_string(), string_span)
+        }};
+    }
+    macro_rules! float {
+        ($t:ty, $literal:ident) => {{
+            let exponent = if radix >= 15 {
+                b'^'
+            } else {
+                b'e'
+            };
+            let options = ParseFloatOptions::builder()
+                .exponent(exponent)
+                .build()
+                .map_err(|error| (error.to_string(), call_site))?;
+            let value = parse::<$t>(bytes, radix, &options).map_err(error)?;
+            if !value.is_finite() {
+                let message = "float literal is out of range for ".to_string() + &ty.to_string();
+                return Err((message, string_span));
+            }
+            let mut literal = Literal::$literal(value.abs());
+            literal.set_span(string_span);
+            negate(value.is_sign_negative(), TokenTree::Literal(literal))
+        }};
+    }
+
+    let tokens = match ty.to_string().as_str() {
+        "u8" => integer!(u8),
+        "u16" => integer!(u16),
+        "u32" => integer!(u32),
+        "u64" => integer!(u64),
+        "u128" => integer!(u128),
+        // The target pointer width may differ from the host, so parse to
+        // the largest type and let the compiler check the range.
+        "usize" => integer!(u128),
+        "i8" => integer!(i8),
+        "i16" => integer!(i16),
+        "i32" => integer!(i32),
+        "i64" => integer!(i64),
+        "i128" => integer!(i128),
+        "isize" => integer!(i128),
+        "f32" => float!(f32, f32_suffixed),
+        "f64" => float!(f64, f64_suffixed),
+        _ => return Err(("unsupported numeric type".to_string(), ty.span())),
+    };
+    Ok(tokens)
+}
+
+/// Remove invisible groups, which wrap tokens passed through `macro_rules`.
+fn flatten(token: TokenTree) -> TokenTree {
+    match token {
+        TokenTree::Group(group) if group.delimiter() == Delimiter::None => {
+            let mut inner = group.stream().into_iter();
+            match (inner.next(), inner.next()) {
+                (Some(token), None) => flatten(token),
+                _ => TokenTree::Group(group),
+            }
+        },
+        token => token,
+    }
+}
+
+/// Get the value of a string literal, which cannot contain escapes.
+fn string_value(literal: &Literal) -> Result<String> {
+    let repr = literal.to_string();
+    let raw = repr.trim_start_matches('r').trim_matches('#');
+    let is_string = raw.len() >= 2 && raw.starts_with('"') && raw.ends_with('"');
+    if !is_string || (raw.len() == repr.len() && raw.contains('\\')) {
+        return Err(("expected a string literal without escapes".to_string(), literal.span()));
+    }
+    Ok(raw[1..raw.len() - 1].to_string())
+}
+
+/// Check that the token is the expected punctuation.
+fn expect_punct(token: Option<TokenTree>, expected: char) -> Result<()> {
+    let message = "expected `".to_string() + &expected.to_string() + "`";
+    match token {
+        Some(TokenTree::Punct(punct)) if punct.as_char() == expected => Ok(()),
+        Some(token) => Err((message, token.span())),
+        None => Err((message, Span::call_site())),
+    }
+}
+
+/// Create a suffixed integer literal from its decimal digits.
+fn integer_literal(digits: String, suffix: &str, span: Span) -> TokenStream {
+    let (is_negative, digits) = match digits.strip_prefix('-') {
+        Some(digits) => (true, digits),
+        None => (false, digits.as_str()),
+    };
+    let mut literal: Literal = match (digits.to_string() + suffix).parse::<TokenStream>() {
+        Ok(stream) => match stream.into_iter().next() {
+            Some(TokenTree::Literal(literal)) => literal,
+            _ => unreachable!(),
+        },
+        Err(_) => unreachable!(),
+    };
+    literal.set_span(span);
+    negate(is_negative, TokenTree::Literal(literal))
+}
+
+/// Wrap the literal in parentheses, with a leading minus sign if negative.
+fn negate(is_negative: bool, literal: TokenTree) -> TokenStream {
+    let mut tokens = Vec::with_capacity(2);
+    if is_negative {
+        tokens.push(TokenTree::Punct(Punct::new('-', Spacing::Alone)));
+    }
+    tokens.push(literal);
+    let group = Group::new(Delimiter::Parenthesis, tokens.into_iter().collect());
+    TokenTree::Group(group).into()
+}
+
+/// Expand to a `compile_error!` with the message at the span.
+fn compile_error(message: &str, span: Span) -> TokenStream {
+    let mut literal = Literal::string(message);
+    literal.set_span(span);
+    let tokens: [TokenTree; 8] = [
+        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+        TokenTree::Punct(Punct::new(':', Spacing::Alone)),
+        TokenTree::Ident(Ident::new("core", span)),
+        TokenTree::Punct(Punct::new(':', Spacing::Joint)),
+        TokenTree::Punct(Punct::new(':', Spacing::Alone)),
+        TokenTree::Ident(Ident::new("compile_error", span)),
+        TokenTree::Punct(Punct::new('!', Spacing::Alone)),
+        TokenTree::Group(Group::new(Delimiter::Parenthesis, TokenTree::Literal(literal).into())),
+    ];
+    tokens
+        .into_iter()
+        .map(|mut token| {
+            token.set_span(span);
+            token
+        })
+        .collect()
+}
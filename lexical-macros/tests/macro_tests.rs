@@ -0,0 +1,50 @@
+use lexical_macros::lexical_parse;
+
+#[test]
+fn integer_test() {
+    assert_eq!(lexical_parse!("1_234", u32), 1234);
+    assert_eq!(lexical_parse!("-128", i8), i8::MIN);
+    assert_eq!(lexical_parse!("+255", u8), 255);
+    assert_eq!(lexical_parse!("1__000_", i64), 1000);
+    assert_eq!(lexical_parse!("340282366920938463463374607431768211455", u128), u128::MAX);
+    assert_eq!(lexical_parse!("-9223372036854775808", isize), isize::MIN);
+    assert_eq!(lexical_parse!("42", usize), 42);
+    assert_eq!(lexical_parse!("-1", i32).abs(), 1);
+}
+
+#[test]
+fn radix_test() {
+    assert_eq!(lexical_parse!("ff_ff", u32, radix = 16), 0xffff);
+    assert_eq!(lexical_parse!("-1010", i16, radix = 2), -10);
+    assert_eq!(lexical_parse!("zz", u16, radix = 36,), 1295);
+    assert_eq!(lexical_parse!("777", u16, radix = 8), 0o777);
+}
+
+#[test]
+fn float_test() {
+    assert_eq!(lexical_parse!("1_234.5e6", f64), 1234.5e6);
+    assert_eq!(lexical_parse!("-0.1", f32), -0.1);
+    assert_eq!(lexical_parse!("1e-400", f64), 0.0);
+    assert!(lexical_parse!("-0.0", f64).is_sign_negative());
+    assert_eq!(lexical_parse!("0.1", f64, radix = 2), 0.5);
+    assert_eq!(lexical_parse!("1.8^1", f64, radix = 16), 24.0);
+    assert_eq!(lexical_parse!(r"2.5", f64), 2.5);
+}
+
+macro_rules! forward {
+    ($string:literal, $t:ty) => {
+        lexical_parse!($string, $t)
+    };
+}
+
+#[test]
+fn macro_rules_test() {
+    assert_eq!(forward!("12_3", u8), 123);
+}
+
+const CONSTANT: f64 = lexical_parse!("6.022_140_76e23", f64);
+
+#[test]
+fn const_test() {
+    assert_eq!(CONSTANT, 6.02214076e23);
+}
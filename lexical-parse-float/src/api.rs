@@ -8,7 +8,7 @@ use lexical_util::error::Error;
 #[cfg(feature = "f16")]
 use lexical_util::f16::f16;
 use lexical_util::format::{is_valid_options_punctuation, NumberFormat, STANDARD};
-use lexical_util::{from_lexical, from_lexical_with_options};
+use lexical_util::{from_lexical, from_lexical_with_options, ignore, trim};
 
 use crate::options::Options;
 use crate::parse::ParseFloat;
@@ -56,7 +56,11 @@ macro_rules! float_from_lexical {
                 } else if !is_valid_options_punctuation(FORMAT, options.exponent(), options.decimal_point()) {
                     return Err(Error::InvalidPunctuation);
                 }
-                Self::parse_complete::<FORMAT>(bytes, options)
+                ignore::parse_complete(bytes, options.ignored_chars(), |bytes| {
+                    trim::parse_complete::<_, _, FORMAT>(bytes, |bytes| {
+                        Self::parse_complete::<FORMAT>(bytes, options)
+                    })
+                })
             }
 
             #[cfg_attr(not(feature = "compact"), inline)]
@@ -65,7 +69,11 @@ macro_rules! float_from_lexical {
                 options: &Self::Options,
             ) -> lexical_util::result::Result<(Self, usize)>
             {
-                Self::parse_partial::<FORMAT>(bytes, options)
+                ignore::parse_partial(bytes, options.ignored_chars(), |bytes| {
+                    trim::parse_partial::<_, _, FORMAT>(bytes, |bytes| {
+                        Self::parse_partial::<FORMAT>(bytes, options)
+                    })
+                })
             }
         }
     )*)
@@ -20,7 +20,7 @@
 use lexical_util::format::NumberFormat;
 
 use crate::float::{ExtendedFloat80, RawFloat};
-use crate::mask::{lower_n_halfway, lower_n_mask};
+use crate::interval::Interval;
 use crate::number::Number;
 use crate::shared;
 use crate::table::bellerophon_powers;
@@ -85,43 +85,52 @@ pub fn bellerophon<F: RawFloat, const FORMAT: u128>(num: &Number, lossy: bool) -
     // Within the valid exponent range, multiply by the large and small
     // exponents and return the resulting value.
 
-    // Track errors to as a factor of unit in last-precision.
-    let mut errors: u32 = 0;
+    // Track the bounds of the real value, to determine if the
+    // extended-precision float can be unambiguously rounded.
+    let mut fp = ExtendedFloat80 {
+        mant: num.mantissa,
+        exp: 0,
+    };
+    let mut bounds = Interval::exact(fp);
     if num.many_digits {
-        errors += error_halfscale();
+        // The truncated digits are less than 1 in the last digit.
+        let one = ExtendedFloat80 {
+            mant: 1,
+            exp: 0,
+        };
+        bounds = bounds.add(&Interval(fp_zero, one));
     }
 
     // Multiply by the small power.
     // Check if we can directly multiply by an integer, if not,
     // use extended-precision multiplication.
-    let mut fp = ExtendedFloat80 {
-        mant: num.mantissa,
-        exp: 0,
-    };
     match fp.mant.overflowing_mul(powers.get_small_int(small_index as usize)) {
         // Overflow, multiplication unsuccessful, go slow path.
         (_, true) => {
             normalize(&mut fp);
-            fp = mul(&fp, &powers.get_small(small_index as usize));
-            errors += error_halfscale();
+            let small = powers.get_small(small_index as usize);
+            fp = mul(&fp, &small);
+            bounds = bounds.mul(&Interval::approximate(small));
         },
         // No overflow, multiplication successful.
         (mant, false) => {
+            let small = ExtendedFloat80 {
+                mant: powers.get_small_int(small_index as usize),
+                exp: 0,
+            };
             fp.mant = mant;
             normalize(&mut fp);
+            bounds = bounds.mul(&Interval::exact(small));
         },
     }
 
     // Multiply by the large power.
-    fp = mul(&fp, &powers.get_large(large_index as usize));
-    if errors > 0 {
-        errors += 1;
-    }
-    errors += error_halfscale();
+    let large = powers.get_large(large_index as usize);
+    fp = mul(&fp, &large);
+    bounds = bounds.mul(&Interval::approximate(large));
 
-    // Normalize the floating point (and the errors).
-    let shift = normalize(&mut fp);
-    errors <<= shift;
+    // Normalize the floating point.
+    normalize(&mut fp);
     fp.exp += F::EXPONENT_BIAS;
 
     // Check for literal overflow, even with halfway cases.
@@ -129,11 +138,15 @@ pub fn bellerophon<F: RawFloat, const FORMAT: u128>(num: &Number, lossy: bool) -
         return fp_zero;
     }
 
-    // Too many errors accumulated, return an error.
-    if !lossy && !error_is_accurate::<F>(errors, &fp) {
-        // Bias the exponent so we know it's invalid.
-        fp.exp += shared::INVALID_FP;
-        return fp;
+    // The bounds round to different floats, return an error.
+    if !lossy {
+        let lower = round_bound::<F>(bounds.lower());
+        if lower != round_bound::<F>(bounds.upper()) {
+            // Bias the exponent so we know it's invalid.
+            fp.exp += shared::INVALID_FP;
+            return fp;
+        }
+        return lower;
     }
 
     // Check if we have a literal 0 or overflow here.
@@ -155,130 +168,34 @@ pub fn bellerophon<F: RawFloat, const FORMAT: u128>(num: &Number, lossy: bool) -
 // ERRORS
 // ------
 
-// Calculate if the errors in calculating the extended-precision float.
-//
-// Specifically, we want to know if we are close to a halfway representation,
-// or halfway between `b` and `b+1`, or `b+h`. The halfway representation
-// has the form:
-//     `SEEEEEEEHMMMMMMMMMMMMMMMMMMMMMMM100...`
-// where:
-//     S = Sign Bit
-//     E = Exponent Bits
-//     H = Hidden Bit
-//     M = Mantissa Bits
+// Calculate if the errors in calculating the extended-precision float
+// prevent unambiguous rounding.
 //
-// The halfway representation has a bit set 1-after the mantissa digits,
-// and no bits set immediately afterward, making it impossible to
-// round between `b` and `b+1` with this representation.
-
-/// Get the full error scale.
-#[inline(always)]
-const fn error_scale() -> u32 {
-    8
-}
-
-/// Get the half error scale.
-#[inline(always)]
-const fn error_halfscale() -> u32 {
-    error_scale() / 2
-}
+// The real value is within the bounds of an interval, which are rounded
+// down and up for each operation. Rounding is monotonic, so if both bounds
+// round to the same float, so does the real value. Otherwise, the real
+// value is close to a halfway representation, or halfway between `b` and
+// `b+1`, or `b+h`, and the slow path is required.
 
-/// Determine if the number of errors is tolerable for float precision.
+/// Round a normalized, unbiased bound of the real value to the float.
 #[cfg_attr(not(feature = "compact"), inline(always))]
-fn error_is_accurate<F: RawFloat>(errors: u32, fp: &ExtendedFloat80) -> bool {
-    // Check we can't have a literal 0 denormal float.
-    debug_assert!(fp.exp >= -64, "cannot have a literal 0 float");
-
-    // Determine if extended-precision float is a good approximation.
-    // If the error has affected too many units, the float will be
-    // inaccurate, or if the representation is too close to halfway
-    // that any operations could affect this halfway representation.
-    // See the documentation for dtoa for more information.
-
-    // This is always a valid u32, since `fp.exp >= -64`
-    // will always be positive and the significand size is `{23, 52}`.
-    let mantissa_shift = 64 - F::MANTISSA_SIZE - 1;
-
-    // The unbiased exponent checks is `unbiased_exp <= F::MANTISSA_SIZE
-    // - F::EXPONENT_BIAS -64 + 1`, or `biased_exp <= F::MANTISSA_SIZE - 63`,
-    // or `biased_exp <= mantissa_shift`.
-    let extrabits = match fp.exp <= -mantissa_shift {
-        // Denormal, since shifting to the hidden bit still has a negative exponent.
-        // The unbiased check calculation for bits is `1 - F::EXPONENT_BIAS - unbiased_exp`,
-        // or `1 - biased_exp`.
-        true => 1 - fp.exp,
-        false => 64 - F::MANTISSA_SIZE - 1,
-    };
-
-    // Our logic is as follows: we want to determine if the actual
-    // mantissa and the errors during calculation differ significantly
-    // from the rounding point. The rounding point for round-nearest
-    // is the halfway point, IE, this when the truncated bits start
-    // with b1000..., while the rounding point for the round-toward
-    // is when the truncated bits are equal to 0.
-    // To do so, we can check whether the rounding point +/- the error
-    // are >/< the actual lower n bits.
-    //
-    // For whether we need to use signed or unsigned types for this
-    // analysis, see this example, using u8 rather than u64 to simplify
-    // things.
-    //
-    // # Comparisons
-    //      cmp1 = (halfway - errors) < extra
-    //      cmp1 = extra < (halfway + errors)
-    //
-    // # Large Extrabits, Low Errors
-    //
-    //      extrabits = 8
-    //      halfway          =  0b10000000
-    //      extra            =  0b10000010
-    //      errors           =  0b00000100
-    //      halfway - errors =  0b01111100
-    //      halfway + errors =  0b10000100
-    //
-    //      Unsigned:
-    //          halfway - errors = 124
-    //          halfway + errors = 132
-    //          extra            = 130
-    //          cmp1             = true
-    //          cmp2             = true
-    //      Signed:
-    //          halfway - errors = 124
-    //          halfway + errors = -124
-    //          extra            = -126
-    //          cmp1             = false
-    //          cmp2             = true
-    //
-    // # Conclusion
-    //
-    // Since errors will always be small, and since we want to detect
-    // if the representation is accurate, we need to use an **unsigned**
-    // type for comparisons.
-    let maskbits = extrabits as u64;
-    let errors = errors as u64;
-
-    // Round-to-nearest, need to use the halfway point.
-    if extrabits > 64 {
-        // Underflow, we have a shift larger than the mantissa.
-        // Representation is valid **only** if the value is close enough
-        // overflow to the next bit within errors. If it overflows,
-        // the representation is **not** valid.
-        !fp.mant.overflowing_add(errors).1
-    } else {
-        let mask = lower_n_mask(maskbits);
-        let extra = fp.mant & mask;
-
-        // Round-to-nearest, need to check if we're close to halfway.
-        // IE, b10100 | 100000, where `|` signifies the truncation point.
-        let halfway = lower_n_halfway(maskbits);
-        let cmp1 = halfway.wrapping_sub(errors) < extra;
-        let cmp2 = extra < halfway.wrapping_add(errors);
-
-        // If both comparisons are true, we have significant rounding error,
-        // and the value cannot be exactly represented. Otherwise, the
-        // representation is valid.
-        !(cmp1 && cmp2)
+fn round_bound<F: RawFloat>(mut fp: ExtendedFloat80) -> ExtendedFloat80 {
+    normalize(&mut fp);
+    fp.exp += F::EXPONENT_BIAS;
+    if -fp.exp + 1 >= 65 {
+        // Have more than 64 bits below the minimum exponent, must be 0.
+        return ExtendedFloat80 {
+            mant: 0,
+            exp: 0,
+        };
     }
+
+    shared::round::<F, _>(&mut fp, |f, s| {
+        shared::round_nearest_tie_even(f, s, |is_odd, is_halfway, is_above| {
+            is_above || (is_odd && is_halfway)
+        });
+    });
+    fp
 }
 
 // MATH
@@ -0,0 +1,151 @@
+//! Low-level primitives of the slow path, to correctly round floats.
+//!
+//! A float parsed from many digits may be too close to the halfway point
+//! between two floats to round with extended-precision arithmetic. The slow
+//! path then converts the significant digits to a big integer, and compares
+//! them exactly to the halfway point, `b+h`, where `b` is the float rounded
+//! down and `h` is half a unit in the last place. These are the functions
+//! another parser needs to correctly round its own approximation.
+
+use core::cmp;
+
+use lexical_util::error::Error;
+use lexical_util::format::{is_valid_options_punctuation, NumberFormat, STANDARD};
+use lexical_util::result::Result;
+use lexical_util::trim;
+
+use crate::options::Options;
+use crate::parse::{compare_halfway_complete, parse_bigint_complete, ParseFloat};
+
+const DEFAULT_OPTIONS: Options = Options::new();
+
+/// Compare the value of bytes to the halfway point between a float and the
+/// next float further from zero, using the standard format.
+///
+/// The bytes must be a complete, finite float, and are compared exactly,
+/// with their signs, to the value halfway between `float` and the adjacent
+/// float of a larger magnitude, which has the same sign as `float`. If
+/// `float` is the bytes rounded toward zero, the correctly rounded float is
+/// `float` if this is `Less`, the next float if this is `Greater`, and the
+/// float with an even significand if this is `Equal`.
+///
+/// Returns an error if the bytes are not a finite float, and
+/// [`Error::TooManyDigits`] if the digits do not fit in the big integers,
+/// which only occurs for exponents far outside the range of the float.
+///
+/// # Panics
+///
+/// Panics if `float` is `NaN` or infinite.
+///
+/// # Examples
+///
+/// ```rust
+/// use core::cmp::Ordering;
+///
+/// use lexical_parse_float::compare_halfway;
+///
+/// // The halfway point between 2^53 and 2^53 + 2.
+/// let float = 9007199254740992.0f64;
+/// assert_eq!(compare_halfway(b"9007199254740993", float), Ok(Ordering::Equal));
+/// assert_eq!(compare_halfway(b"9007199254740992.9", float), Ok(Ordering::Less));
+/// assert_eq!(compare_halfway(b"9007199254740993.0000000001", float), Ok(Ordering::Greater));
+/// assert_eq!(compare_halfway(b"-9007199254740993.1", -float), Ok(Ordering::Less));
+/// assert!(compare_halfway(b"NaN", float).is_err());
+/// ```
+#[inline]
+pub fn compare_halfway<F: ParseFloat>(bytes: &[u8], float: F) -> Result<cmp::Ordering> {
+    compare_halfway_complete::<F, STANDARD>(bytes, &DEFAULT_OPTIONS, float)
+}
+
+/// Compare the value of bytes to the halfway point between a float and the
+/// next float further from zero, using a custom format and options.
+///
+/// This is identical to [`compare_halfway`], except the bytes are parsed
+/// like [`FromLexicalWithOptions::from_lexical_with_options`].
+///
+/// # Panics
+///
+/// Panics if `float` is `NaN` or infinite.
+///
+/// [`FromLexicalWithOptions::from_lexical_with_options`]: crate::FromLexicalWithOptions::from_lexical_with_options
+#[inline]
+pub fn compare_halfway_with_options<F: ParseFloat, const FORMAT: u128>(
+    bytes: &[u8],
+    options: &Options,
+    float: F,
+) -> Result<cmp::Ordering> {
+    check_format::<FORMAT>(options)?;
+    trim::parse_complete::<_, _, FORMAT>(bytes, |bytes| {
+        compare_halfway_complete::<F, FORMAT>(bytes, options, float)
+    })
+}
+
+/// Parse the significant digits of a float into a big integer, using the
+/// standard format.
+///
+/// The bytes must be a complete, finite float. The magnitude of the value
+/// is `digits * 10^exponent`, where `digits` is written to `limbs` as
+/// little-endian, 32-bit limbs. Returns the number of limbs written, which
+/// is 0 for zero, and the exponent.
+///
+/// Digits past the most that can affect rounding to `F` are truncated, and
+/// if any were non-zero, a final non-zero digit is added. This keeps the
+/// order relative to any halfway point of `F`, but not the exact value.
+/// For an `f64`, 768 digits and 1 extra digit suffice, which fit in 80
+/// limbs.
+///
+/// Returns an error if the bytes are not a finite float, and
+/// [`Error::TooManyDigits`] if the digits do not fit in `limbs`.
+///
+/// # Examples
+///
+/// ```rust
+/// use lexical_parse_float::parse_bigint;
+///
+/// let mut limbs = [0u32; 80];
+/// assert_eq!(parse_bigint::<f64>(b"-1.25e10", &mut limbs), Ok((1, 8)));
+/// assert_eq!(limbs[0], 125);
+///
+/// assert_eq!(parse_bigint::<f64>(b"18446744073709551617", &mut limbs), Ok((3, 0)));
+/// assert_eq!(limbs[..3], [1, 0, 1]);
+/// assert!(parse_bigint::<f64>(b"18446744073709551617", &mut limbs[..2]).is_err());
+///
+/// assert_eq!(parse_bigint::<f64>(b"0.000", &mut limbs), Ok((0, 0)));
+/// ```
+#[inline]
+pub fn parse_bigint<F: ParseFloat>(bytes: &[u8], limbs: &mut [u32]) -> Result<(usize, i32)> {
+    parse_bigint_complete::<F, STANDARD>(bytes, &DEFAULT_OPTIONS, limbs)
+}
+
+/// Parse the significant digits of a float into a big integer, using a
+/// custom format and options.
+///
+/// This is identical to [`parse_bigint`], except the bytes are parsed like
+/// [`FromLexicalWithOptions::from_lexical_with_options`], and the exponent
+/// is in the radix of the mantissa.
+///
+/// [`FromLexicalWithOptions::from_lexical_with_options`]: crate::FromLexicalWithOptions::from_lexical_with_options
+#[inline]
+pub fn parse_bigint_with_options<F: ParseFloat, const FORMAT: u128>(
+    bytes: &[u8],
+    options: &Options,
+    limbs: &mut [u32],
+) -> Result<(usize, i32)> {
+    check_format::<FORMAT>(options)?;
+    trim::parse_complete::<_, _, FORMAT>(bytes, |bytes| {
+        parse_bigint_complete::<F, FORMAT>(bytes, options, limbs)
+    })
+}
+
+/// Check the format and options are valid together.
+#[inline(always)]
+fn check_format<const FORMAT: u128>(options: &Options) -> Result<()> {
+    let format = NumberFormat::<{ FORMAT }> {};
+    if !format.is_valid() {
+        Err(format.error())
+    } else if !is_valid_options_punctuation(FORMAT, options.exponent(), options.decimal_point()) {
+        Err(Error::InvalidPunctuation)
+    } else {
+        Ok(())
+    }
+}
@@ -4,7 +4,9 @@
 
 #![doc(hidden)]
 
-use core::{cmp, mem, ops, ptr, slice};
+use core::{cmp, ops};
+#[cfg(not(feature = "safe"))]
+use core::{mem, slice};
 
 #[cfg(feature = "radix")]
 use crate::float::ExtendedFloat80;
@@ -18,6 +20,7 @@ use crate::table::get_large_int_power;
 /// # Safety
 ///
 /// Safe if `index < array.len()`.
+#[cfg(not(feature = "safe"))]
 macro_rules! index_unchecked {
     ($x:ident[$i:expr]) => {
         // SAFETY: safe if `index < array.len()`.
@@ -25,6 +28,14 @@ macro_rules! index_unchecked {
     };
 }
 
+/// Index an array with bounds checking.
+#[cfg(feature = "safe")]
+macro_rules! index_unchecked {
+    ($x:ident[$i:expr]) => {
+        $x[$i]
+    };
+}
+
 // BIGINT
 // ------
 
@@ -41,7 +52,9 @@ const BIGINT_BITS: usize = 6000;
 const BIGINT_BITS: usize = 4000;
 
 /// The number of limbs for the bigint.
-const BIGINT_LIMBS: usize = BIGINT_BITS / Limb::BITS as usize;
+///
+/// This is enough to correctly round any float, in any supported radix.
+pub const BIGINT_LIMBS: usize = BIGINT_BITS / Limb::BITS as usize;
 
 /// Storage for a big integer type.
 ///
@@ -52,9 +65,10 @@ const BIGINT_LIMBS: usize = BIGINT_BITS / Limb::BITS as usize;
 ///
 /// This requires us to store the number of significant bits, plus the
 /// number of exponent bits (required) since we scale everything
-/// to the same exponent.
+/// to the same exponent. A smaller capacity than [`BIGINT_LIMBS`] uses
+/// less memory, but operations fail if the result does not fit.
 #[derive(Clone, PartialEq, Eq)]
-pub struct Bigint {
+pub struct SizedBigint<const SIZE: usize> {
     /// Significant digits for the float, stored in a big integer in LE order.
     ///
     /// This is pretty much the same number of digits for any radix, since the
@@ -69,10 +83,13 @@ pub struct Bigint {
     /// for decimal, we need `log2(10**1091) ≅ 3600`, while for base 36
     /// we need `log2(36**1086) ≅ 5600`. Since we use uninitialized data,
     /// we avoid a major performance hit from the large buffer size.
-    pub data: StackVec<BIGINT_LIMBS>,
+    pub data: StackVec<SIZE>,
 }
 
-impl Bigint {
+/// Big integer with enough capacity for any float.
+pub type Bigint = SizedBigint<BIGINT_LIMBS>;
+
+impl<const SIZE: usize> SizedBigint<SIZE> {
     /// Construct a bigfloat representing 0.
     #[inline(always)]
     pub const fn new() -> Self {
@@ -107,7 +124,7 @@ impl Bigint {
     pub fn pow(&mut self, base: u32, exp: u32) -> Option<()> {
         let (odd, shift) = split_radix(base);
         if odd != 0 {
-            pow::<BIGINT_LIMBS>(&mut self.data, odd, exp)?;
+            pow::<SIZE>(&mut self.data, odd, exp)?;
         }
         if shift != 0 {
             shl(&mut self.data, (exp * shift) as usize)?;
@@ -122,13 +139,13 @@ impl Bigint {
     }
 }
 
-impl ops::MulAssign<&Bigint> for Bigint {
-    fn mul_assign(&mut self, rhs: &Bigint) {
+impl<const SIZE: usize> ops::MulAssign<&SizedBigint<SIZE>> for SizedBigint<SIZE> {
+    fn mul_assign(&mut self, rhs: &SizedBigint<SIZE>) {
         self.data *= &rhs.data;
     }
 }
 
-impl Default for Bigint {
+impl<const SIZE: usize> Default for SizedBigint<SIZE> {
     fn default() -> Self {
         Self::new()
     }
@@ -268,7 +285,11 @@ impl Default for Bigfloat {
 #[derive(Clone)]
 pub struct StackVec<const SIZE: usize> {
     /// The raw buffer for the elements.
+    #[cfg(not(feature = "safe"))]
     data: [mem::MaybeUninit<Limb>; SIZE],
+    /// The buffer for the elements, where only the first `length` are used.
+    #[cfg(feature = "safe")]
+    data: [Limb; SIZE],
     /// The number of elements in the array (we never need more than
     /// `u16::MAX`).
     length: u16,
@@ -329,7 +350,10 @@ impl<const SIZE: usize> StackVec<SIZE> {
     pub const fn new() -> Self {
         Self {
             length: 0,
+            #[cfg(not(feature = "safe"))]
             data: [mem::MaybeUninit::uninit(); SIZE],
+            #[cfg(feature = "safe")]
+            data: [0; SIZE],
         }
     }
 
@@ -401,13 +425,17 @@ impl<const SIZE: usize> StackVec<SIZE> {
     #[inline(always)]
     unsafe fn push_unchecked(&mut self, value: Limb) {
         debug_assert!(self.len() < self.capacity(), "cannot exceed our array bounds");
+        let len = self.len();
+        #[cfg(not(feature = "safe"))]
         // SAFETY: safe, capacity is less than the current size.
         unsafe {
-            let len = self.len();
-            let ptr = self.as_mut_ptr().add(len);
-            ptr.write(value);
-            self.length += 1;
+            *self.data.get_unchecked_mut(len) = mem::MaybeUninit::new(value);
         }
+        #[cfg(feature = "safe")]
+        {
+            self.data[len] = value;
+        }
+        self.length += 1;
     }
 
     /// Append an item to the vector.
@@ -431,9 +459,13 @@ impl<const SIZE: usize> StackVec<SIZE> {
     unsafe fn pop_unchecked(&mut self) -> Limb {
         debug_assert!(!self.is_empty(), "cannot pop a value if none exists");
         self.length -= 1;
-        // SAFETY: safe if `self.length > 0`.
+        #[cfg(not(feature = "safe"))]
+        // SAFETY: safe if `self.length > 0`, since the item was initialized.
         // We have a trivial drop and copy, so this is safe.
-        unsafe { ptr::read(self.as_mut_ptr().add(self.len())) }
+        let value = unsafe { self.data.get_unchecked(self.len()).assume_init() };
+        #[cfg(feature = "safe")]
+        let value = self.data[self.len()];
+        value
     }
 
     /// Remove an item from the end of the vector and return it, or None if
@@ -458,13 +490,18 @@ impl<const SIZE: usize> StackVec<SIZE> {
         let index = self.len();
         let new_len = index + slc.len();
         debug_assert!(self.len() + slc.len() <= self.capacity(), "cannot exceed our array bounds");
-        let src = slc.as_ptr();
-        // SAFETY: safe if `self.len() + slc.len() <= self.capacity()`.
-        unsafe {
-            let dst = self.as_mut_ptr().add(index);
-            ptr::copy_nonoverlapping(src, dst, slc.len());
-            self.set_len(new_len);
+        #[cfg(not(feature = "safe"))]
+        {
+            // SAFETY: safe if `self.len() + slc.len() <= self.capacity()`.
+            let dst = unsafe { self.data.get_unchecked_mut(index..new_len) };
+            for (dst, &src) in dst.iter_mut().zip(slc) {
+                *dst = mem::MaybeUninit::new(src);
+            }
         }
+        #[cfg(feature = "safe")]
+        self.data[index..new_len].copy_from_slice(slc);
+        // SAFETY: safe if `self.len() + slc.len() <= self.capacity()`.
+        unsafe { self.set_len(new_len) };
     }
 
     /// Copy elements from a slice and append them to the vector.
@@ -489,6 +526,12 @@ impl<const SIZE: usize> StackVec<SIZE> {
         self.length = len as u16;
     }
 
+    /// Remove all items from the vector.
+    #[inline(always)]
+    pub fn clear(&mut self) {
+        self.length = 0;
+    }
+
     /// Resize the buffer, without bounds checking.
     ///
     /// # Safety
@@ -503,14 +546,14 @@ impl<const SIZE: usize> StackVec<SIZE> {
             // Just, don't set the length until all values have been written,
             // so we don't accidentally read uninitialized memory.
 
-            let count = len - old_len;
-            for index in 0..count {
-                // SAFETY: safe if `len < self.capacity()`.
-                unsafe {
-                    let dst = self.as_mut_ptr().add(old_len + index);
-                    ptr::write(dst, value);
-                }
+            #[cfg(not(feature = "safe"))]
+            {
+                // SAFETY: safe if `len <= self.capacity()`.
+                let dst = unsafe { self.data.get_unchecked_mut(old_len..len) };
+                dst.fill(mem::MaybeUninit::new(value));
             }
+            #[cfg(feature = "safe")]
+            self.data[old_len..len].fill(value);
             self.length = len as u16;
         } else {
             // SAFETY: safe since `len < self.len()`.
@@ -618,16 +661,23 @@ impl<const SIZE: usize> StackVec<SIZE> {
     #[inline(always)]
     pub fn from_u64(x: u64) -> Self {
         let mut vec = Self::new();
-        debug_assert!(2 <= vec.capacity(), "cannot exceed our array bounds");
+        vec.set_u64(x);
+        vec
+    }
+
+    /// Overwrite the vector with a u64 value.
+    #[inline(always)]
+    pub fn set_u64(&mut self, x: u64) {
+        debug_assert!(2 <= self.capacity(), "cannot exceed our array bounds");
         assert!(2 <= SIZE, "cannot exceed our array bounds");
+        self.clear();
         if Limb::BITS == 32 {
-            _ = vec.try_push(x as Limb);
-            _ = vec.try_push((x >> 32) as Limb);
+            _ = self.try_push(x as Limb);
+            _ = self.try_push((x >> 32) as Limb);
         } else {
-            _ = vec.try_push(x as Limb);
+            _ = self.try_push(x as Limb);
         }
-        vec.normalize();
-        vec
+        self.normalize();
     }
 
     // INDEX
@@ -722,12 +772,16 @@ impl<const SIZE: usize> ops::Deref for StackVec<SIZE> {
     #[inline(always)]
     fn deref(&self) -> &[Limb] {
         debug_assert!(self.len() <= self.capacity(), "cannot exceed our array bounds");
+        #[cfg(not(feature = "safe"))]
         // SAFETY: safe since `self.data[..self.len()]` must be initialized
         // and `self.len() <= self.capacity()`.
-        unsafe {
-            let ptr = self.data.as_ptr() as *const Limb;
+        let slc = unsafe {
+            let ptr = self.data.as_ptr().cast::<Limb>();
             slice::from_raw_parts(ptr, self.len())
-        }
+        };
+        #[cfg(feature = "safe")]
+        let slc = &self.data[..self.len()];
+        slc
     }
 }
 
@@ -735,12 +789,17 @@ impl<const SIZE: usize> ops::DerefMut for StackVec<SIZE> {
     #[inline(always)]
     fn deref_mut(&mut self) -> &mut [Limb] {
         debug_assert!(self.len() <= self.capacity(), "cannot exceed our array bounds");
+        let len = self.len();
+        #[cfg(not(feature = "safe"))]
         // SAFETY: safe since `self.data[..self.len()]` must be initialized
         // and `self.len() <= self.capacity()`.
-        unsafe {
-            let ptr = self.data.as_mut_ptr() as *mut Limb;
-            slice::from_raw_parts_mut(ptr, self.len())
-        }
+        let slc = unsafe {
+            let ptr = self.data.as_mut_ptr().cast::<Limb>();
+            slice::from_raw_parts_mut(ptr, len)
+        };
+        #[cfg(feature = "safe")]
+        let slc = &mut self.data[..len];
+        slc
     }
 }
 
@@ -776,9 +835,13 @@ impl<'a, T: 'a> ReverseView<'a, T> {
     pub unsafe fn get_unchecked(&self, index: usize) -> &T {
         debug_assert!(index < self.inner.len(), "cannot exceed our array bounds");
         let len = self.inner.len();
+        #[cfg(not(feature = "safe"))]
         // SAFETY: Safe as long as the index < length, so len - index - 1 >= 0 and <=
         // len.
-        unsafe { self.inner.get_unchecked(len - index - 1) }
+        let value = unsafe { self.inner.get_unchecked(len - index - 1) };
+        #[cfg(feature = "safe")]
+        let value = &self.inner[len - index - 1];
+        value
     }
 
     /// Get a reference to a value.
@@ -1395,17 +1458,14 @@ pub fn shl_limbs<const SIZE: usize>(x: &mut StackVec<SIZE>, n: usize) -> Option<
     } else if !x.is_empty() {
         let len = n + x.len();
         let x_len = x.len();
-        let ptr = x.as_mut_ptr();
-        let src = ptr;
-        // SAFETY: since x is not empty, and `x.len() + n <= x.capacity()`.
-        unsafe {
-            // Move the elements.
-            let dst = ptr.add(n);
-            ptr::copy(src, dst, x_len);
-            // Write our 0s.
-            ptr::write_bytes(ptr, 0, n);
-            x.set_len(len);
-        }
+        // Move the elements, then write our 0s.
+        x.data.copy_within(..x_len, n);
+        #[cfg(not(feature = "safe"))]
+        x.data[..n].fill(mem::MaybeUninit::new(0));
+        #[cfg(feature = "safe")]
+        x.data[..n].fill(0);
+        // SAFETY: since `x.len() + n <= x.capacity()`.
+        unsafe { x.set_len(len) };
         Some(())
     } else {
         Some(())
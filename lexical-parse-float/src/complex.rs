@@ -0,0 +1,125 @@
+//! Parse complex numbers, like `3+4i` or `1.5e3-2.5e-1j`.
+//!
+//! A complex number is a real part, an imaginary part followed by an
+//! imaginary unit, or a real part followed by a signed imaginary part.
+//! Each part is parsed as a float, so the parts have the same syntax and
+//! rounding as [`FromLexical::from_lexical`].
+//!
+//! [`FromLexical::from_lexical`]: crate::FromLexical::from_lexical
+
+use lexical_util::error::Error;
+use lexical_util::format::{is_valid_options_punctuation, NumberFormat, STANDARD};
+use lexical_util::num::Float;
+use lexical_util::result::Result;
+use lexical_util::trim;
+
+use crate::options::Options;
+use crate::parse::ParseFloat;
+
+const DEFAULT_OPTIONS: Options = Options::new();
+
+/// Parse a complete complex number, using the standard format.
+///
+/// Returns `(re, im)`, the real and imaginary parts. The bytes may be a
+/// real number, like `3`, an imaginary number, like `4i`, or both, like
+/// `3+4i`, where the imaginary part must have a sign and be last. The
+/// imaginary unit is `i` or `j`, and without any digits is an imaginary
+/// part of 1, like `3-i` or `j`. Missing parts are positive zero.
+///
+/// # Examples
+///
+/// ```rust
+/// use lexical_parse_float::parse_complex;
+///
+/// assert_eq!(parse_complex::<f64>(b"3+4i"), Ok((3.0, 4.0)));
+/// assert_eq!(parse_complex::<f64>(b"1.5e3-2.5e-1j"), Ok((1500.0, -0.25)));
+/// assert_eq!(parse_complex::<f64>(b"-2.5"), Ok((-2.5, 0.0)));
+/// assert_eq!(parse_complex::<f64>(b"4i"), Ok((0.0, 4.0)));
+/// assert_eq!(parse_complex::<f32>(b"1-i"), Ok((1.0, -1.0)));
+/// ```
+#[inline]
+pub fn parse_complex<F: ParseFloat>(bytes: &[u8]) -> Result<(F, F)> {
+    parse_complex_parts::<F, STANDARD>(bytes, &DEFAULT_OPTIONS)
+}
+
+/// Parse a complete complex number, using a custom format and options.
+///
+/// This is identical to [`parse_complex`], except each part is parsed
+/// like [`FromLexicalWithOptions::from_lexical_with_options`], and the
+/// imaginary units are the [`imaginary_units`](Options::imaginary_units)
+/// of the options.
+///
+/// # Examples
+///
+/// ```rust
+/// use lexical_parse_float::{format, parse_complex_with_options, Options};
+///
+/// const OPTIONS: Options = Options::builder()
+///     .imaginary_units(b"I")
+///     .build_unchecked();
+/// const FORMAT: u128 = format::STANDARD;
+///
+/// let result = parse_complex_with_options::<f64, FORMAT>(b"1+2I", &OPTIONS);
+/// assert_eq!(result, Ok((1.0, 2.0)));
+/// assert!(parse_complex_with_options::<f64, FORMAT>(b"1+2i", &OPTIONS).is_err());
+/// ```
+///
+/// [`FromLexicalWithOptions::from_lexical_with_options`]: crate::FromLexicalWithOptions::from_lexical_with_options
+#[inline]
+pub fn parse_complex_with_options<F: ParseFloat, const FORMAT: u128>(
+    bytes: &[u8],
+    options: &Options,
+) -> Result<(F, F)> {
+    let format = NumberFormat::<{ FORMAT }> {};
+    if !format.is_valid() {
+        return Err(format.error());
+    } else if !is_valid_options_punctuation(FORMAT, options.exponent(), options.decimal_point()) {
+        return Err(Error::InvalidPunctuation);
+    }
+    trim::parse_complete::<_, _, FORMAT>(bytes, |bytes| {
+        parse_complex_parts::<F, FORMAT>(bytes, options)
+    })
+}
+
+/// Get the value of an imaginary unit without digits, and an optional sign.
+#[inline(always)]
+fn unit_value<F: Float>(bytes: &[u8]) -> Option<F> {
+    match bytes {
+        b"" | b"+" => Some(F::ONE),
+        b"-" => Some(-F::ONE),
+        _ => None,
+    }
+}
+
+/// Parse the real and imaginary parts of a complex number.
+#[inline(always)]
+fn parse_complex_parts<F: ParseFloat, const FORMAT: u128>(
+    bytes: &[u8],
+    options: &Options,
+) -> Result<(F, F)> {
+    let body = match bytes.split_last() {
+        Some((unit, body)) if options.imaginary_units().contains(unit) => body,
+        _ => return Ok((F::parse_complete::<FORMAT>(bytes, options)?, F::ZERO)),
+    };
+    if let Some(imag) = unit_value(body) {
+        return Ok((F::ZERO, imag));
+    }
+
+    // The real part ends at the sign of the imaginary part, if any.
+    let (real, count) = F::parse_partial::<FORMAT>(body, options)?;
+    if count == body.len() {
+        return Ok((F::ZERO, real));
+    } else if !matches!(body[count], b'+' | b'-') {
+        return Err(Error::InvalidDigit(count));
+    }
+    let imag = match unit_value(&body[count..]) {
+        Some(imag) => imag,
+        None => F::parse_complete::<FORMAT>(&body[count..], options).map_err(|mut error| {
+            if let Some(index) = error.index_mut() {
+                *index += count;
+            }
+            error
+        })?,
+    };
+    Ok((real, imag))
+}
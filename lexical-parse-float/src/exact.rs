@@ -0,0 +1,112 @@
+//! Parse a float and determine if it was rounded.
+
+use core::cmp;
+
+use lexical_util::error::Error;
+use lexical_util::format::{is_valid_options_punctuation, NumberFormat, STANDARD};
+use lexical_util::result::Result;
+use lexical_util::trim;
+
+use crate::options::Options;
+use crate::parse::ParseFloat;
+
+const DEFAULT_OPTIONS: Options = Options::new();
+
+/// Parse a complete float, and determine if it is exactly the value of the
+/// bytes, using the standard format.
+///
+/// Returns the float, identical to [`FromLexical::from_lexical`], and
+/// `true` if it is exactly the decimal value written, or `false` if it was
+/// rounded. This may be used to decide if a value needs a decimal type to
+/// be represented exactly. Special values, like `NaN`, are always exact,
+/// and values that overflow to infinity or underflow to zero are not.
+///
+/// Checking exactness compares the digits to the float using big integers,
+/// which is slower than parsing for floats that are not small integers.
+///
+/// # Examples
+///
+/// ```rust
+/// use lexical_parse_float::parse_exactness;
+///
+/// assert_eq!(parse_exactness::<f64>(b"1.5"), Ok((1.5, true)));
+/// assert_eq!(parse_exactness::<f64>(b"0.1"), Ok((0.1, false)));
+/// assert_eq!(parse_exactness::<f32>(b"16777216"), Ok((16777216.0, true)));
+/// assert_eq!(parse_exactness::<f32>(b"16777217"), Ok((16777216.0, false)));
+/// ```
+///
+/// [`FromLexical::from_lexical`]: crate::FromLexical::from_lexical
+#[inline]
+pub fn parse_exactness<F: ParseFloat>(bytes: &[u8]) -> Result<(F, bool)> {
+    let float = F::parse_complete::<STANDARD>(bytes, &DEFAULT_OPTIONS)?;
+    let ordering = F::compare_complete::<STANDARD>(bytes, &DEFAULT_OPTIONS, float)?;
+    Ok((float, ordering == cmp::Ordering::Equal))
+}
+
+/// Parse a complete float, and determine if it is exactly the value of the
+/// bytes, using a custom format and options.
+///
+/// This is identical to [`parse_exactness`], except the float is parsed
+/// like [`FromLexicalWithOptions::from_lexical_with_options`]. Floats that
+/// saturate with the [`overflow_mode`](Options::overflow_mode) are not
+/// exact.
+///
+/// [`FromLexicalWithOptions::from_lexical_with_options`]: crate::FromLexicalWithOptions::from_lexical_with_options
+#[inline]
+pub fn parse_exactness_with_options<F: ParseFloat, const FORMAT: u128>(
+    bytes: &[u8],
+    options: &Options,
+) -> Result<(F, bool)> {
+    let format = NumberFormat::<{ FORMAT }> {};
+    if !format.is_valid() {
+        return Err(format.error());
+    } else if !is_valid_options_punctuation(FORMAT, options.exponent(), options.decimal_point()) {
+        return Err(Error::InvalidPunctuation);
+    }
+    trim::parse_complete::<_, _, FORMAT>(bytes, |bytes| {
+        let float = F::parse_complete::<FORMAT>(bytes, options)?;
+        let ordering = F::compare_complete::<FORMAT>(bytes, options, float)?;
+        Ok((float, ordering == cmp::Ordering::Equal))
+    })
+}
+
+/// Determine if bytes are exactly representable as a float, using the
+/// standard format.
+///
+/// Returns `true` if the bytes are a valid float and parsing them does not
+/// round, like [`parse_exactness`], or `false` otherwise, including if the
+/// bytes are not a valid float. This may be used to warn about precision
+/// loss in configuration files.
+///
+/// Small integers are exact without comparing the digits to the float.
+///
+/// # Examples
+///
+/// ```rust
+/// use lexical_parse_float::is_exact;
+///
+/// assert!(is_exact::<f64>(b"0.5"));
+/// assert!(is_exact::<f64>(b"9007199254740992"));
+/// assert!(!is_exact::<f64>(b"9007199254740993"));
+/// assert!(!is_exact::<f64>(b"0.1"));
+/// assert!(!is_exact::<f64>(b"0.1x"));
+/// ```
+#[inline]
+pub fn is_exact<F: ParseFloat>(bytes: &[u8]) -> bool {
+    matches!(parse_exactness::<F>(bytes), Ok((_, true)))
+}
+
+/// Determine if bytes are exactly representable as a float, using a custom
+/// format and options.
+///
+/// This is identical to [`is_exact`], except the float is parsed like
+/// [`FromLexicalWithOptions::from_lexical_with_options`].
+///
+/// [`FromLexicalWithOptions::from_lexical_with_options`]: crate::FromLexicalWithOptions::from_lexical_with_options
+#[inline]
+pub fn is_exact_with_options<F: ParseFloat, const FORMAT: u128>(
+    bytes: &[u8],
+    options: &Options,
+) -> bool {
+    matches!(parse_exactness_with_options::<F, FORMAT>(bytes, options), Ok((_, true)))
+}
@@ -141,9 +141,17 @@ pub trait LemireFloat: RawFloat {
     const MINIMUM_EXPONENT: i32;
 
     /// Smallest decimal exponent for a non-zero value.
+    ///
+    /// A value with at most 19 significant digits and a smaller decimal
+    /// exponent, relative to the digits, is less than half the smallest
+    /// denormal float, so it always rounds to zero.
     const SMALLEST_POWER_OF_TEN: i32;
 
     /// Largest decimal exponent for a non-infinite value.
+    ///
+    /// A value with a larger decimal exponent, relative to the digits, is
+    /// at least `10^(LARGEST_POWER_OF_TEN + 1)`, so it always rounds to
+    /// infinity.
     const LARGEST_POWER_OF_TEN: i32;
 }
 
@@ -168,8 +176,8 @@ impl LemireFloat for f16 {
     const MIN_EXPONENT_ROUND_TO_EVEN: i32 = 0;
     const MAX_EXPONENT_ROUND_TO_EVEN: i32 = 0;
     const MINIMUM_EXPONENT: i32 = 0;
-    const SMALLEST_POWER_OF_TEN: i32 = 0;
-    const LARGEST_POWER_OF_TEN: i32 = 0;
+    const SMALLEST_POWER_OF_TEN: i32 = -26;
+    const LARGEST_POWER_OF_TEN: i32 = 4;
 }
 
 #[cfg(feature = "f16")]
@@ -177,8 +185,8 @@ impl LemireFloat for bf16 {
     const MIN_EXPONENT_ROUND_TO_EVEN: i32 = 0;
     const MAX_EXPONENT_ROUND_TO_EVEN: i32 = 0;
     const MINIMUM_EXPONENT: i32 = 0;
-    const SMALLEST_POWER_OF_TEN: i32 = 0;
-    const LARGEST_POWER_OF_TEN: i32 = 0;
+    const SMALLEST_POWER_OF_TEN: i32 = -59;
+    const LARGEST_POWER_OF_TEN: i32 = 38;
 }
 
 #[inline(always)]
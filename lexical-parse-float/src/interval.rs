@@ -0,0 +1,274 @@
+//! Bound the value of the bytes by floats.
+//!
+//! This contains [`parse_interval`], which finds the native floats
+//! bracketing a decimal value, and [`Interval`], which tracks the bounds of
+//! a value through extended-precision arithmetic.
+
+use core::cmp;
+
+use lexical_util::error::Error;
+use lexical_util::format::{is_valid_options_punctuation, NumberFormat, STANDARD};
+use lexical_util::num::Float;
+use lexical_util::result::Result;
+use lexical_util::trim;
+
+use crate::float::ExtendedFloat80;
+use crate::options::Options;
+use crate::parse::ParseFloat;
+
+const DEFAULT_OPTIONS: Options = Options::new();
+
+/// Parse a complete float, and find the floats bracketing the value of the
+/// bytes, using the standard format.
+///
+/// Returns `(lower, upper)`, the two adjacent floats such that
+/// `lower <= value <= upper`. If the value was rounded, one of the bounds
+/// is the float returned by [`FromLexical::from_lexical`] and the other is
+/// its neighbor on the other side of the value. If the value is exactly
+/// representable, both bounds are the same float. Values that overflow are
+/// bracketed by the largest finite float and infinity, and values that
+/// underflow by zero and the smallest denormal float, both with the sign of
+/// the value. Special values, like `NaN`, are returned as both bounds.
+///
+/// Finding the bounds compares the digits to the float using big integers,
+/// like [`parse_exactness`](crate::parse_exactness).
+///
+/// # Examples
+///
+/// ```rust
+/// use lexical_parse_float::parse_interval;
+///
+/// assert_eq!(parse_interval::<f64>(b"1.5"), Ok((1.5, 1.5)));
+/// assert_eq!(parse_interval::<f64>(b"0.1"), Ok((0.09999999999999999, 0.1)));
+/// assert_eq!(parse_interval::<f64>(b"-0.1"), Ok((-0.1, -0.09999999999999999)));
+/// assert_eq!(parse_interval::<f32>(b"16777217"), Ok((16777216.0, 16777218.0)));
+/// ```
+///
+/// [`FromLexical::from_lexical`]: crate::FromLexical::from_lexical
+#[inline]
+pub fn parse_interval<F: ParseFloat>(bytes: &[u8]) -> Result<(F, F)> {
+    let float = F::parse_complete::<STANDARD>(bytes, &DEFAULT_OPTIONS)?;
+    let ordering = F::compare_complete::<STANDARD>(bytes, &DEFAULT_OPTIONS, float)?;
+    Ok(bracket(float, ordering))
+}
+
+/// Parse a complete float, and find the floats bracketing the value of the
+/// bytes, using a custom format and options.
+///
+/// This is identical to [`parse_interval`], except the float is parsed
+/// like [`FromLexicalWithOptions::from_lexical_with_options`]. Values that
+/// saturate with the [`overflow_mode`](Options::overflow_mode) are
+/// bracketed by the largest finite float and infinity.
+///
+/// [`FromLexicalWithOptions::from_lexical_with_options`]: crate::FromLexicalWithOptions::from_lexical_with_options
+#[inline]
+pub fn parse_interval_with_options<F: ParseFloat, const FORMAT: u128>(
+    bytes: &[u8],
+    options: &Options,
+) -> Result<(F, F)> {
+    let format = NumberFormat::<{ FORMAT }> {};
+    if !format.is_valid() {
+        return Err(format.error());
+    } else if !is_valid_options_punctuation(FORMAT, options.exponent(), options.decimal_point()) {
+        return Err(Error::InvalidPunctuation);
+    }
+    trim::parse_complete::<_, _, FORMAT>(bytes, |bytes| {
+        let float = F::parse_complete::<FORMAT>(bytes, options)?;
+        let ordering = F::compare_complete::<FORMAT>(bytes, options, float)?;
+        Ok(bracket(float, ordering))
+    })
+}
+
+/// Get the bounds from the float and the order of the value relative to it.
+#[inline(always)]
+fn bracket<F: Float>(float: F, ordering: cmp::Ordering) -> (F, F) {
+    match ordering {
+        cmp::Ordering::Less => (float.prev(), float),
+        cmp::Ordering::Equal => (float, float),
+        cmp::Ordering::Greater => (float, float.next()),
+    }
+}
+
+// INTERVAL
+// --------
+
+/// Closed interval of extended-precision floats, `[lower, upper]`.
+///
+/// Each bound is an unbiased [`ExtendedFloat80`], with the value
+/// `mant * 2^exp`, so only positive values can be represented. Operations
+/// round the lower bound down and the upper bound up, so if the operands
+/// contain the real values, the result contains the real result. This
+/// can be used to track the error of an approximation: if both bounds
+/// round to the same native float, so does any value between them.
+///
+/// The Bellerophon algorithm uses this to determine if the
+/// extended-precision float is accurate enough to be correctly rounded,
+/// otherwise the slow path is used.
+///
+/// # Examples
+///
+/// ```rust
+/// use lexical_parse_float::float::ExtendedFloat80;
+/// use lexical_parse_float::Interval;
+///
+/// // An approximation of 1/3, within 1 unit in the last place.
+/// let third = Interval::approximate(ExtendedFloat80 {
+///     mant: 0xAAAA_AAAA_AAAA_AAAB,
+///     exp: -65,
+/// });
+/// let three = Interval::exact(ExtendedFloat80 {
+///     mant: 3,
+///     exp: 0,
+/// });
+/// // The product is bracketed by the floats adjacent to 1.
+/// let product = third.mul(&three);
+/// assert_eq!(product.lower(), ExtendedFloat80 {
+///     mant: u64::MAX,
+///     exp: -64,
+/// });
+/// assert_eq!(product.upper(), ExtendedFloat80 {
+///     mant: (1 << 63) + 1,
+///     exp: -63,
+/// });
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Interval(pub ExtendedFloat80, pub ExtendedFloat80);
+
+impl Interval {
+    /// Create an interval containing only an exact value.
+    #[must_use]
+    #[inline(always)]
+    pub const fn exact(fp: ExtendedFloat80) -> Self {
+        Self(fp, fp)
+    }
+
+    /// Create an interval from an approximation within 1 unit in the last
+    /// place of the real value, like a rounded power from a table.
+    #[must_use]
+    #[inline(always)]
+    pub const fn approximate(fp: ExtendedFloat80) -> Self {
+        let lower = ExtendedFloat80 {
+            mant: fp.mant.saturating_sub(1),
+            exp: fp.exp,
+        };
+        let upper = match fp.mant.checked_add(1) {
+            Some(mant) => ExtendedFloat80 {
+                mant,
+                exp: fp.exp,
+            },
+            None => ExtendedFloat80 {
+                mant: 1 << 63,
+                exp: fp.exp + 1,
+            },
+        };
+        Self(lower, upper)
+    }
+
+    /// Get the lower bound of the interval.
+    #[must_use]
+    #[inline(always)]
+    pub const fn lower(&self) -> ExtendedFloat80 {
+        self.0
+    }
+
+    /// Get the upper bound of the interval.
+    #[must_use]
+    #[inline(always)]
+    pub const fn upper(&self) -> ExtendedFloat80 {
+        self.1
+    }
+
+    /// Add two intervals, as if by `a+b`.
+    ///
+    /// The bounds of the result are normalized.
+    #[must_use]
+    #[inline]
+    pub fn add(&self, other: &Self) -> Self {
+        Self(add::<false>(&self.0, &other.0), add::<true>(&self.1, &other.1))
+    }
+
+    /// Multiply two intervals, as if by `a*b`.
+    ///
+    /// The bounds of the result are normalized.
+    #[must_use]
+    #[inline]
+    pub fn mul(&self, other: &Self) -> Self {
+        Self(mul::<false>(&self.0, &other.0), mul::<true>(&self.1, &other.1))
+    }
+}
+
+/// Add two extended-precision floats, rounding down or up.
+#[inline]
+fn add<const UP: bool>(x: &ExtendedFloat80, y: &ExtendedFloat80) -> ExtendedFloat80 {
+    if x.mant == 0 {
+        return round::<UP>(y.mant as u128, y.exp, false);
+    } else if y.mant == 0 {
+        return round::<UP>(x.mant as u128, x.exp, false);
+    }
+
+    // Align the smaller value to the larger, leaving 1 bit for the carry.
+    let (large, small) = if x.exp >= y.exp {
+        (x, y)
+    } else {
+        (y, x)
+    };
+    let shift = (large.exp - small.exp) as u32;
+    let large_mant = (large.mant as u128) << 63;
+    let small_mant = (small.mant as u128) << 63;
+    let (small_mant, is_truncated) = if shift >= 127 {
+        (0, true)
+    } else {
+        let mask = (1u128 << shift) - 1;
+        (small_mant >> shift, small_mant & mask != 0)
+    };
+    round::<UP>(large_mant + small_mant, large.exp - 63, is_truncated)
+}
+
+/// Multiply two extended-precision floats, rounding down or up.
+#[inline]
+fn mul<const UP: bool>(x: &ExtendedFloat80, y: &ExtendedFloat80) -> ExtendedFloat80 {
+    let product = x.mant as u128 * y.mant as u128;
+    round::<UP>(product, x.exp + y.exp, false)
+}
+
+/// Normalize and round `mant * 2^exp` to 64 bits, rounding down or up.
+///
+/// If `is_truncated`, the real value is above `mant * 2^exp`.
+#[inline]
+fn round<const UP: bool>(mant: u128, exp: i32, is_truncated: bool) -> ExtendedFloat80 {
+    if mant == 0 {
+        // The truncated value is below the last bit.
+        return ExtendedFloat80 {
+            mant: (UP && is_truncated) as u64,
+            exp,
+        };
+    }
+
+    let shift = 64 - mant.leading_zeros() as i32;
+    let (result, exp, is_truncated) = if shift > 0 {
+        let mask = (1u128 << shift) - 1;
+        ((mant >> shift) as u64, exp + shift, is_truncated || mant & mask != 0)
+    } else if is_truncated {
+        // Cannot normalize, since the truncated bits are below the last bit.
+        (mant as u64, exp, is_truncated)
+    } else {
+        ((mant << -shift) as u64, exp + shift, is_truncated)
+    };
+    if UP && is_truncated {
+        match result.checked_add(1) {
+            Some(mant) => ExtendedFloat80 {
+                mant,
+                exp,
+            },
+            None => ExtendedFloat80 {
+                mant: 1 << 63,
+                exp: exp + 1,
+            },
+        }
+    } else {
+        ExtendedFloat80 {
+            mant: result,
+            exp,
+        }
+    }
+}
@@ -30,6 +30,8 @@
 //! * `radix` - Add support for strings of any radix.
 //! * `format` - Add support for parsing custom integer formats.
 //! * `compact` - Reduce code size at the cost of performance.
+//! * `simd` - Use SIMD instructions to parse long decimal strings.
+//! * `safe` - Ensure only memory-safe indexing is used.
 //!
 //! # Note
 //!
@@ -140,10 +142,20 @@ pub mod mask;
 pub mod number;
 pub mod options;
 pub mod parse;
+pub mod rebase;
 pub mod slow;
 pub mod table;
 
 mod api;
+mod bigcomp;
+mod complex;
+mod exact;
+mod interval;
+mod lossy;
+mod parser;
+mod parts;
+mod ratio;
+mod raw;
 mod table_bellerophon_decimal;
 mod table_bellerophon_radix;
 mod table_binary;
@@ -152,6 +164,7 @@ mod table_large;
 mod table_lemire;
 mod table_radix;
 mod table_small;
+mod validate;
 
 #[macro_use(parse_sign)]
 extern crate lexical_parse_integer;
@@ -159,7 +172,7 @@ extern crate lexical_parse_integer;
 // Re-exports
 #[cfg(feature = "f16")]
 pub use lexical_util::bf16::bf16;
-pub use lexical_util::error::Error;
+pub use lexical_util::error::{CharacterClass, Error, ErrorKind};
 #[cfg(feature = "f16")]
 pub use lexical_util::f16::f16;
 pub use lexical_util::format::{self, NumberFormatBuilder};
@@ -167,5 +180,27 @@ pub use lexical_util::options::ParseOptions;
 pub use lexical_util::result::Result;
 
 pub use self::api::{FromLexical, FromLexicalWithOptions};
+pub use self::bigcomp::{
+    compare_halfway,
+    compare_halfway_with_options,
+    parse_bigint,
+    parse_bigint_with_options,
+};
+pub use self::complex::{parse_complex, parse_complex_with_options};
+pub use self::exact::{
+    is_exact,
+    is_exact_with_options,
+    parse_exactness,
+    parse_exactness_with_options,
+};
+pub use self::interval::{parse_interval, parse_interval_with_options, Interval};
+pub use self::lossy::{parse_lossy, parse_partial_lossy};
 #[doc(inline)]
-pub use self::options::{Options, OptionsBuilder};
+pub use self::options::{Options, OptionsBuilder, OverflowMode};
+pub use self::parser::Parser;
+pub use self::parts::{parse_parts, parse_parts_with_options, NumberParts};
+pub use self::ratio::{parse_ratio, parse_ratio_parts, Ratio};
+pub use self::raw::{assemble, parse_raw, RawNumber};
+#[cfg(feature = "radix")]
+pub use self::rebase::{rebase, rebase_buffer_size, rebase_integer};
+pub use self::validate::{is_float, is_float_with_options};
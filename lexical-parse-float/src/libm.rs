@@ -28,12 +28,21 @@
 /// # Safety
 ///
 /// Safe if `index < array.len()`.
+#[cfg(not(feature = "safe"))]
 macro_rules! i {
     ($x:ident, $i:expr) => {
         unsafe { *$x.get_unchecked($i) }
     };
 }
 
+/// Index an array with bounds checking.
+#[cfg(feature = "safe")]
+macro_rules! i {
+    ($x:ident, $i:expr) => {
+        $x[$i]
+    };
+}
+
 pub fn powf(x: f32, y: f32) -> f32 {
     const BP: [f32; 2] = [1.0, 1.5];
     const DP_H: [f32; 2] = [0.0, 5.84960938e-01]; /* 0x3f15c000 */
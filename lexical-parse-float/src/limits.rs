@@ -884,19 +884,25 @@ impl MaxDigits for f64 {
     }
 }
 
+/// emin = -14
+/// p2 = 11
 #[cfg(feature = "f16")]
 impl MaxDigits for f16 {
     #[inline(always)]
-    fn max_digits(_: u32) -> Option<usize> {
-        unimplemented!()
+    fn max_digits(radix: u32) -> Option<usize> {
+        debug_assert_radix(radix);
+        f16_max_digits(radix)
     }
 }
 
+/// emin = -126
+/// p2 = 8
 #[cfg(feature = "f16")]
 impl MaxDigits for bf16 {
     #[inline(always)]
-    fn max_digits(_: u32) -> Option<usize> {
-        unimplemented!()
+    fn max_digits(radix: u32) -> Option<usize> {
+        debug_assert_radix(radix);
+        bf16_max_digits(radix)
     }
 }
 
@@ -930,6 +936,56 @@ impl MaxDigits for bf16 {
 // CONST FN
 // --------
 
+/// Get the maximum number of significant digits as a const fn.
+#[must_use]
+#[inline(always)]
+#[cfg(feature = "f16")]
+pub const fn f16_max_digits(radix: u32) -> Option<usize> {
+    match radix {
+        6 => Some(21),
+        10 => Some(23),
+        12 => Some(23),
+        14 => Some(23),
+        18 => Some(23),
+        20 => Some(23),
+        22 => Some(24),
+        24 => Some(24),
+        26 => Some(24),
+        28 => Some(24),
+        30 => Some(24),
+        34 => Some(24),
+        36 => Some(24),
+        // Powers of two should be unreachable.
+        // Odd numbers will have infinite digits.
+        _ => None,
+    }
+}
+
+/// Get the maximum number of significant digits as a const fn.
+#[must_use]
+#[inline(always)]
+#[cfg(feature = "f16")]
+pub const fn bf16_max_digits(radix: u32) -> Option<usize> {
+    match radix {
+        6 => Some(87),
+        10 => Some(98),
+        12 => Some(101),
+        14 => Some(103),
+        18 => Some(106),
+        20 => Some(107),
+        22 => Some(107),
+        24 => Some(108),
+        26 => Some(109),
+        28 => Some(109),
+        30 => Some(110),
+        34 => Some(111),
+        36 => Some(111),
+        // Powers of two should be unreachable.
+        // Odd numbers will have infinite digits.
+        _ => None,
+    }
+}
+
 /// Get the maximum number of significant digits as a const fn.
 #[must_use]
 #[inline(always)]
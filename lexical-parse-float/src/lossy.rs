@@ -0,0 +1,63 @@
+//! Parse floats without correct rounding.
+
+use lexical_util::format::STANDARD;
+use lexical_util::result::Result;
+
+use crate::options::Options;
+use crate::parse::ParseFloat;
+
+const LOSSY_OPTIONS: Options = Options::builder().lossy(true).build_unchecked();
+
+/// Parse a complete float with the lossy algorithm, using the standard
+/// format.
+///
+/// This accepts the same grammar as [`FromLexical::from_lexical`], but
+/// never uses the slow path with big-integer arithmetic to correctly round
+/// the float. The result is within 1 ULP of the correctly rounded float,
+/// and is rounded down if it cannot be distinguished from a halfway
+/// representation. This is faster for floats with many digits, and may
+/// be used when throughput is more important than exactness, such as for
+/// telemetry.
+///
+/// This is identical to parsing with the [`lossy`](Options::lossy)
+/// option.
+///
+/// # Examples
+///
+/// ```rust
+/// use lexical_parse_float::parse_lossy;
+///
+/// assert_eq!(parse_lossy::<f64>(b"1.5"), Ok(1.5));
+/// assert_eq!(parse_lossy::<f32>(b"-2.5e3"), Ok(-2500.0));
+///
+/// // Slightly above halfway, which rounds up with correct rounding.
+/// let halfway = b"9007199254740993.0000000000000000000001";
+/// assert_eq!(parse_lossy::<f64>(halfway), Ok(9007199254740992.0));
+/// ```
+///
+/// [`FromLexical::from_lexical`]: crate::FromLexical::from_lexical
+#[inline]
+pub fn parse_lossy<F: ParseFloat>(bytes: &[u8]) -> Result<F> {
+    F::parse_complete::<STANDARD>(bytes, &LOSSY_OPTIONS)
+}
+
+/// Parse a float with the lossy algorithm until an invalid digit is found,
+/// using the standard format.
+///
+/// This is identical to [`parse_lossy`], except it returns the float and
+/// the number of bytes processed, like
+/// [`FromLexical::from_lexical_partial`].
+///
+/// # Examples
+///
+/// ```rust
+/// use lexical_parse_float::parse_partial_lossy;
+///
+/// assert_eq!(parse_partial_lossy::<f64>(b"1.5,2.5"), Ok((1.5, 3)));
+/// ```
+///
+/// [`FromLexical::from_lexical_partial`]: crate::FromLexical::from_lexical_partial
+#[inline]
+pub fn parse_partial_lossy<F: ParseFloat>(bytes: &[u8]) -> Result<(F, usize)> {
+    F::parse_partial::<STANDARD>(bytes, &LOSSY_OPTIONS)
+}
@@ -13,9 +13,9 @@ use crate::fpu::set_precision;
 
 /// Representation of a number as the significant digits and exponent.
 ///
-/// This is only used if the exponent base and the significant digit
-/// radix are the same, since we need to be able to move powers in and
-/// out of the exponent.
+/// The exponent is in the exponent base, which may differ from the radix
+/// of the significant digits if both are powers of two, such as for
+/// hexadecimal floats with a binary exponent.
 #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
 pub struct Number<'a> {
     /// The exponent of the float, scaled to the mantissa.
@@ -38,12 +38,9 @@ impl Number<'_> {
     #[inline(always)]
     pub fn is_fast_path<F: RawFloat, const FORMAT: u128>(&self) -> bool {
         let format = NumberFormat::<FORMAT> {};
-        debug_assert!(
-            format.mantissa_radix() == format.exponent_base(),
-            "fast path requires same radix"
-        );
-        F::min_exponent_fast_path(format.radix()) <= self.exponent
-            && self.exponent <= F::max_exponent_disguised_fast_path(format.radix())
+        let base = format.exponent_base();
+        F::min_exponent_fast_path(base) <= self.exponent
+            && self.exponent <= F::max_exponent_disguised_fast_path(base)
             && self.mantissa <= F::MAX_MANTISSA_FAST_PATH
             && !self.many_digits
     }
@@ -63,10 +60,6 @@ impl Number<'_> {
     #[allow(clippy::let_unit_value)] // reason = "intentional ASM drop for X87 FPUs"
     pub fn try_fast_path<F: RawFloat, const FORMAT: u128>(&self) -> Option<F> {
         let format = NumberFormat::<FORMAT> {};
-        debug_assert!(
-            format.mantissa_radix() == format.exponent_base(),
-            "fast path requires same radix"
-        );
         // The fast path crucially depends on arithmetic being rounded to the correct
         // number of bits without any intermediate rounding. On x86 (without SSE
         // or SSE2) this requires the precision of the x87 FPU stack to be
@@ -77,25 +70,25 @@ impl Number<'_> {
         let _cw: () = set_precision::<F>();
 
         if self.is_fast_path::<F, FORMAT>() {
-            let radix = format.radix();
-            let max_exponent = F::max_exponent_fast_path(radix);
+            let base = format.exponent_base();
+            let max_exponent = F::max_exponent_fast_path(base);
             let mut value = if self.exponent <= max_exponent {
                 // normal fast path
                 let value = F::as_cast(self.mantissa);
                 if self.exponent < 0 {
-                    value / F::pow_fast_path((-self.exponent) as usize, radix)
+                    value / F::pow_fast_path((-self.exponent) as usize, base)
                 } else {
-                    value * F::pow_fast_path(self.exponent as usize, radix)
+                    value * F::pow_fast_path(self.exponent as usize, base)
                 }
             } else {
                 // disguised fast path
                 let shift = self.exponent - max_exponent;
-                let int_power = F::int_pow_fast_path(shift as usize, radix);
+                let int_power = F::int_pow_fast_path(shift as usize, base);
                 let mantissa = self.mantissa.checked_mul(int_power)?;
                 if mantissa > F::MAX_MANTISSA_FAST_PATH {
                     return None;
                 }
-                F::as_cast(mantissa) * F::pow_fast_path(max_exponent as usize, radix)
+                F::as_cast(mantissa) * F::pow_fast_path(max_exponent as usize, base)
             };
             if self.is_negative {
                 value = -value;
@@ -113,29 +106,24 @@ impl Number<'_> {
     #[allow(clippy::let_unit_value)] // reason = "intentional ASM drop for X87 FPUs"
     pub fn force_fast_path<F: RawFloat, const FORMAT: u128>(&self) -> F {
         let format = NumberFormat::<FORMAT> {};
-        debug_assert!(
-            format.mantissa_radix() == format.exponent_base(),
-            "fast path requires same radix"
-        );
-
         let _cw = set_precision::<F>();
 
-        let radix = format.radix();
+        let base = format.exponent_base();
         let mut value = F::as_cast(self.mantissa);
-        let max_exponent = F::max_exponent_fast_path(radix);
+        let max_exponent = F::max_exponent_fast_path(base);
         let mut exponent = self.exponent.abs();
         if self.exponent < 0 {
             while exponent > max_exponent {
-                value /= F::pow_fast_path(max_exponent as usize, radix);
+                value /= F::pow_fast_path(max_exponent as usize, base);
                 exponent -= max_exponent;
             }
-            value /= F::pow_fast_path(exponent as usize, radix);
+            value /= F::pow_fast_path(exponent as usize, base);
         } else {
             while exponent > max_exponent {
-                value *= F::pow_fast_path(max_exponent as usize, radix);
+                value *= F::pow_fast_path(max_exponent as usize, base);
                 exponent -= max_exponent;
             }
-            value *= F::pow_fast_path(exponent as usize, radix);
+            value *= F::pow_fast_path(exponent as usize, base);
         }
         if self.is_negative {
             value = -value;
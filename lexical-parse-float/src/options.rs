@@ -2,7 +2,9 @@
 
 #![allow(clippy::must_use_candidate)]
 
-use lexical_util::ascii::{is_valid_ascii, is_valid_letter_slice};
+use core::num;
+
+use lexical_util::ascii::{is_valid_ascii, is_valid_special_slice};
 use lexical_util::error::Error;
 use lexical_util::options::{self, ParseOptions};
 use lexical_util::result::Result;
@@ -11,6 +13,23 @@ use static_assertions::const_assert;
 /// Maximum length for a special string.
 const MAX_SPECIAL_STRING_LENGTH: usize = 50;
 
+/// Enumeration for how to handle floats too large or too small to represent.
+///
+/// Overflow occurs when a float rounds to infinity, and underflow when a
+/// non-zero float rounds to zero. Denormal floats are representable, and
+/// never underflow. Special values, such as `inf`, are not affected.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub enum OverflowMode {
+    /// Round overflow to infinity and underflow to zero, keeping the sign,
+    /// as specified by IEEE-754.
+    Round,
+    /// Clamp overflow to the largest finite float and underflow to the
+    /// smallest positive denormal float, keeping the sign.
+    Saturate,
+    /// Fail with [`Error::Overflow`] or [`Error::Underflow`].
+    Error,
+}
+
 /// Builder for `Options`.
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
 pub struct OptionsBuilder {
@@ -27,6 +46,26 @@ pub struct OptionsBuilder {
     inf_string: Option<&'static [u8]>,
     /// Long string representation of `Infinity`.
     infinity_string: Option<&'static [u8]>,
+    /// How to handle floats too large or too small to represent.
+    overflow_mode: OverflowMode,
+    /// String to designate the exponent component of a float, in addition
+    /// to the exponent character.
+    exponent_string: Option<&'static [u8]>,
+    /// Maximum number of significant digits in the mantissa.
+    max_significant_digits: Option<num::NonZeroUsize>,
+    /// Maximum number of significant digits in the exponent.
+    max_exponent_digits: Option<num::NonZeroUsize>,
+    /// Characters to designate the exponent component of a float, in
+    /// addition to the exponent character.
+    exponent_characters: Option<&'static [u8]>,
+    /// Flush subnormal floats to zero.
+    flush_subnormals: bool,
+    /// Characters to silently skip anywhere in the input.
+    ignored_chars: Option<&'static [u8]>,
+    /// Accept a trailing `%`, which scales the float by 1/100.
+    percent: bool,
+    /// Characters to designate the imaginary part of a complex number.
+    imaginary_units: &'static [u8],
 }
 
 impl OptionsBuilder {
@@ -40,6 +79,15 @@ impl OptionsBuilder {
             nan_string: Some(b"NaN"),
             inf_string: Some(b"inf"),
             infinity_string: Some(b"infinity"),
+            overflow_mode: OverflowMode::Round,
+            exponent_string: None,
+            max_significant_digits: None,
+            max_exponent_digits: None,
+            exponent_characters: None,
+            flush_subnormals: false,
+            ignored_chars: None,
+            percent: false,
+            imaginary_units: b"ij",
         }
     }
 
@@ -81,6 +129,62 @@ impl OptionsBuilder {
         self.infinity_string
     }
 
+    /// Get how to handle floats too large or too small to represent.
+    #[inline(always)]
+    pub const fn get_overflow_mode(&self) -> OverflowMode {
+        self.overflow_mode
+    }
+
+    /// Get the string to designate the exponent component of a float.
+    #[inline(always)]
+    pub const fn get_exponent_string(&self) -> Option<&'static [u8]> {
+        self.exponent_string
+    }
+
+    /// Get the maximum number of significant digits in the mantissa.
+    #[inline(always)]
+    pub const fn get_max_significant_digits(&self) -> Option<num::NonZeroUsize> {
+        self.max_significant_digits
+    }
+
+    /// Get the maximum number of significant digits in the exponent.
+    #[inline(always)]
+    pub const fn get_max_exponent_digits(&self) -> Option<num::NonZeroUsize> {
+        self.max_exponent_digits
+    }
+
+    /// Get the additional characters to designate the exponent component
+    /// of a float.
+    #[inline(always)]
+    pub const fn get_exponent_characters(&self) -> Option<&'static [u8]> {
+        self.exponent_characters
+    }
+
+    /// Get if subnormal floats are flushed to zero.
+    #[inline(always)]
+    pub const fn get_flush_subnormals(&self) -> bool {
+        self.flush_subnormals
+    }
+
+    /// Get the characters to silently skip anywhere in the input.
+    #[inline(always)]
+    pub const fn get_ignored_chars(&self) -> Option<&'static [u8]> {
+        self.ignored_chars
+    }
+
+    /// Get if we accept a trailing percent sign.
+    #[inline(always)]
+    pub const fn get_percent(&self) -> bool {
+        self.percent
+    }
+
+    /// Get the characters to designate the imaginary part of a complex
+    /// number.
+    #[inline(always)]
+    pub const fn get_imaginary_units(&self) -> &'static [u8] {
+        self.imaginary_units
+    }
+
     // SETTERS
 
     /// Set if we disable the use of arbitrary-precision arithmetic.
@@ -131,6 +235,133 @@ impl OptionsBuilder {
         self
     }
 
+    /// Set how to handle floats too large or too small to represent.
+    #[must_use]
+    #[inline(always)]
+    pub const fn overflow_mode(mut self, overflow_mode: OverflowMode) -> Self {
+        self.overflow_mode = overflow_mode;
+        self
+    }
+
+    /// Set the string to designate the exponent component of a float.
+    ///
+    /// This is accepted in addition to the exponent character, for
+    /// scientific notation like `1.23×10^4`, where the string is `×10^`.
+    /// The exponent after the string is parsed like any other exponent.
+    #[must_use]
+    #[inline(always)]
+    pub const fn exponent_string(mut self, exponent_string: Option<&'static [u8]>) -> Self {
+        self.exponent_string = exponent_string;
+        self
+    }
+
+    /// Set the maximum number of significant digits in the mantissa.
+    ///
+    /// Floats with more digits, after any leading zeros, fail with
+    /// [`Error::TooManyDigits`] before any digits are rounded. This bounds
+    /// the work to parse a float from untrusted input, since the slow path
+    /// may otherwise use hundreds of digits to correctly round a float.
+    #[must_use]
+    #[inline(always)]
+    pub const fn max_significant_digits(
+        mut self,
+        max_significant_digits: Option<num::NonZeroUsize>,
+    ) -> Self {
+        self.max_significant_digits = max_significant_digits;
+        self
+    }
+
+    /// Set the maximum number of significant digits in the exponent.
+    ///
+    /// Floats with more exponent digits, after any leading zeros, fail with
+    /// [`Error::TooManyDigits`]. This bounds the magnitude of the exponent,
+    /// so `1e99999999` can be rejected rather than rounded to infinity.
+    #[must_use]
+    #[inline(always)]
+    pub const fn max_exponent_digits(
+        mut self,
+        max_exponent_digits: Option<num::NonZeroUsize>,
+    ) -> Self {
+        self.max_exponent_digits = max_exponent_digits;
+        self
+    }
+
+    /// Set additional characters to designate the exponent component of a
+    /// float.
+    ///
+    /// Any of these characters is accepted in addition to the exponent
+    /// character, so `exponent(b'e')` with `exponent_characters(Some(b"^"))`
+    /// accepts both `1.5e3` and `1.5^3`. Like the exponent character, these
+    /// are case-insensitive unless the format has a case-sensitive exponent.
+    /// The characters should not be valid digits in the radix, or they will
+    /// be parsed as digits.
+    #[must_use]
+    #[inline(always)]
+    pub const fn exponent_characters(mut self, exponent_characters: Option<&'static [u8]>) -> Self {
+        self.exponent_characters = exponent_characters;
+        self
+    }
+
+    /// Set if subnormal floats are flushed to zero.
+    ///
+    /// This mirrors the denormals-are-zero (DAZ) mode of hardware, so a
+    /// parsed float that rounds to a subnormal value is zero, keeping the
+    /// sign. This is applied after the overflow mode, so an underflow
+    /// saturated to the smallest subnormal float is also flushed to zero.
+    #[must_use]
+    #[inline(always)]
+    pub const fn flush_subnormals(mut self, flush_subnormals: bool) -> Self {
+        self.flush_subnormals = flush_subnormals;
+        self
+    }
+
+    /// Set the characters to silently skip anywhere in the input.
+    ///
+    /// This allows parsing human-formatted values, like `$1 234.5` with
+    /// `ignored_chars(Some(b"$ "))`, without cleaning the input first.
+    /// Multi-byte characters are skipped by ignoring each of their bytes,
+    /// so `b"\xC2\xA0"` skips the UTF-8 no-break space. The characters
+    /// must not be digits, letters, signs, or the decimal point.
+    ///
+    /// If the input contains any ignored characters, the remaining bytes are
+    /// copied to a buffer before they are parsed. Without the `std` feature,
+    /// this is a 128-byte buffer on the stack, and longer inputs, after
+    /// removing the ignored characters, fail with [`Error::TooManyDigits`].
+    #[must_use]
+    #[inline(always)]
+    pub const fn ignored_chars(mut self, ignored_chars: Option<&'static [u8]>) -> Self {
+        self.ignored_chars = ignored_chars;
+        self
+    }
+
+    /// Set if we accept a trailing percent sign.
+    ///
+    /// A number followed by `%` is scaled by 1/100, so `7.5%` is parsed
+    /// as `0.075`, and numbers without it are unchanged. The scaling
+    /// shifts the decimal exponent before rounding, so it is exact and
+    /// does not round the float twice. Percentages are only accepted for
+    /// decimal floats, and not for special values.
+    #[must_use]
+    #[inline(always)]
+    pub const fn percent(mut self, percent: bool) -> Self {
+        self.percent = percent;
+        self
+    }
+
+    /// Set the characters to designate the imaginary part of a complex
+    /// number.
+    ///
+    /// Any of the characters may follow the imaginary part, so the
+    /// default of `b"ij"` accepts both `3+4i` and `3+4j`. The characters
+    /// must be printable ASCII, and not digits, signs, the exponent
+    /// character, or the decimal point.
+    #[must_use]
+    #[inline(always)]
+    pub const fn imaginary_units(mut self, imaginary_units: &'static [u8]) -> Self {
+        self.imaginary_units = imaginary_units;
+        self
+    }
+
     // BUILDERS
 
     /// Determine if `nan_str` is valid.
@@ -145,9 +376,7 @@ impl OptionsBuilder {
         let length = nan.len();
         if length == 0 || length > MAX_SPECIAL_STRING_LENGTH {
             false
-        } else if !matches!(nan[0], b'N' | b'n') {
-            false
-        } else if !is_valid_letter_slice(nan) {
+        } else if !is_valid_special_slice(nan, b'N') {
             false
         } else {
             true
@@ -169,12 +398,10 @@ impl OptionsBuilder {
         let infinity = unwrap_str(self.infinity_string);
         if length == 0 || length > MAX_SPECIAL_STRING_LENGTH {
             false
-        } else if !matches!(inf[0], b'I' | b'i') {
+        } else if !is_valid_special_slice(inf, b'I') {
             false
         } else if length > infinity.len() {
             false
-        } else if !is_valid_letter_slice(inf) {
-            false
         } else {
             true
         }
@@ -194,17 +421,121 @@ impl OptionsBuilder {
         let length = infinity.len();
         if length == 0 || length > MAX_SPECIAL_STRING_LENGTH {
             false
-        } else if !matches!(infinity[0], b'I' | b'i') {
+        } else if !is_valid_special_slice(infinity, b'I') {
             false
         } else if length < inf.len() {
             false
-        } else if !is_valid_letter_slice(infinity) {
-            false
         } else {
             true
         }
     }
 
+    /// Determine if `exponent_string` is valid.
+    ///
+    /// The string must not be empty, and must not start with a digit, a
+    /// sign, or the decimal point.
+    #[inline(always)]
+    pub const fn exponent_string_is_valid(&self) -> bool {
+        let exponent = match self.exponent_string {
+            Some(exponent) => exponent,
+            None => return true,
+        };
+        let length = exponent.len();
+        if length == 0 || length > MAX_SPECIAL_STRING_LENGTH {
+            return false;
+        }
+        let first = exponent[0];
+        !first.is_ascii_digit() && first != b'+' && first != b'-' && first != self.decimal_point
+    }
+
+    /// Determine if `exponent_characters` is valid.
+    ///
+    /// The characters must not be empty, and each must be a valid ASCII
+    /// character other than a digit, a sign, or the decimal point.
+    #[inline(always)]
+    pub const fn exponent_characters_is_valid(&self) -> bool {
+        let characters = match self.exponent_characters {
+            Some(characters) => characters,
+            None => return true,
+        };
+        let length = characters.len();
+        if length == 0 || length > MAX_SPECIAL_STRING_LENGTH {
+            return false;
+        }
+        let mut index = 0;
+        while index < length {
+            let c = characters[index];
+            if !is_valid_ascii(c)
+                || c.is_ascii_digit()
+                || c == b'+'
+                || c == b'-'
+                || c == self.decimal_point
+            {
+                return false;
+            }
+            index += 1;
+        }
+        true
+    }
+
+    /// Determine if `ignored_chars` is valid.
+    ///
+    /// The characters must not be empty, and each must not be an ASCII
+    /// letter or digit, a sign, the exponent character, or the decimal
+    /// point.
+    #[inline(always)]
+    pub const fn ignored_chars_is_valid(&self) -> bool {
+        let characters = match self.ignored_chars {
+            Some(characters) => characters,
+            None => return true,
+        };
+        if characters.is_empty() {
+            return false;
+        }
+        let mut index = 0;
+        while index < characters.len() {
+            let c = characters[index];
+            if c.is_ascii_alphanumeric()
+                || c == b'+'
+                || c == b'-'
+                || c == self.exponent
+                || c == self.decimal_point
+            {
+                return false;
+            }
+            index += 1;
+        }
+        true
+    }
+
+    /// Determine if `imaginary_units` is valid.
+    ///
+    /// The characters must not be empty, and each must be printable ASCII,
+    /// and not a digit, a sign, the exponent character, or the decimal
+    /// point.
+    #[inline(always)]
+    pub const fn imaginary_units_is_valid(&self) -> bool {
+        let characters = self.imaginary_units;
+        if characters.is_empty() {
+            return false;
+        }
+        let mut index = 0;
+        while index < characters.len() {
+            let c = characters[index];
+            if !c.is_ascii_graphic()
+                || c.is_ascii_digit()
+                || c == b'+'
+                || c == b'-'
+                || c == self.exponent
+                || c == self.decimal_point
+            {
+                return false;
+            }
+            index += 1;
+        }
+        true
+    }
+
     /// Check if the builder state is valid.
     #[inline(always)]
     #[allow(clippy::if_same_then_else, clippy::needless_bool)] // reason = "more idiomatic"
@@ -219,6 +550,14 @@ impl OptionsBuilder {
             false
         } else if !self.infinity_string_is_valid() {
             false
+        } else if !self.exponent_string_is_valid() {
+            false
+        } else if !self.exponent_characters_is_valid() {
+            false
+        } else if !self.ignored_chars_is_valid() {
+            false
+        } else if !self.imaginary_units_is_valid() {
+            false
         } else {
             true
         }
@@ -241,6 +580,15 @@ impl OptionsBuilder {
             nan_string: self.nan_string,
             inf_string: self.inf_string,
             infinity_string: self.infinity_string,
+            overflow_mode: self.overflow_mode,
+            exponent_string: self.exponent_string,
+            max_significant_digits: self.max_significant_digits,
+            max_exponent_digits: self.max_exponent_digits,
+            exponent_characters: self.exponent_characters,
+            flush_subnormals: self.flush_subnormals,
+            ignored_chars: self.ignored_chars,
+            percent: self.percent,
+            imaginary_units: self.imaginary_units,
         }
     }
 
@@ -261,9 +609,7 @@ impl OptionsBuilder {
 
         if self.nan_string.is_some() {
             let nan = unwrap_str(self.nan_string);
-            if nan.is_empty() || !matches!(nan[0], b'N' | b'n') {
-                return Err(Error::InvalidNanString);
-            } else if !is_valid_letter_slice(nan) {
+            if !is_valid_special_slice(nan, b'N') {
                 return Err(Error::InvalidNanString);
             } else if nan.len() > MAX_SPECIAL_STRING_LENGTH {
                 return Err(Error::NanStringTooLong);
@@ -276,9 +622,7 @@ impl OptionsBuilder {
 
         if self.inf_string.is_some() {
             let inf = unwrap_str(self.inf_string);
-            if inf.is_empty() || !matches!(inf[0], b'I' | b'i') {
-                return Err(Error::InvalidInfString);
-            } else if !is_valid_letter_slice(inf) {
+            if !is_valid_special_slice(inf, b'I') {
                 return Err(Error::InvalidInfString);
             } else if inf.len() > MAX_SPECIAL_STRING_LENGTH {
                 return Err(Error::InfStringTooLong);
@@ -288,9 +632,7 @@ impl OptionsBuilder {
         if self.infinity_string.is_some() {
             let inf = unwrap_str(self.inf_string);
             let infinity = unwrap_str(self.infinity_string);
-            if infinity.is_empty() || !matches!(infinity[0], b'I' | b'i') {
-                return Err(Error::InvalidInfinityString);
-            } else if !is_valid_letter_slice(infinity) {
+            if !is_valid_special_slice(infinity, b'I') {
                 return Err(Error::InvalidInfinityString);
             } else if infinity.len() > MAX_SPECIAL_STRING_LENGTH {
                 return Err(Error::InfinityStringTooLong);
@@ -299,6 +641,14 @@ impl OptionsBuilder {
             }
         }
 
+        if !self.exponent_string_is_valid() || !self.exponent_characters_is_valid() {
+            return Err(Error::InvalidExponentSymbol);
+        }
+
+        if !self.ignored_chars_is_valid() || !self.imaginary_units_is_valid() {
+            return Err(Error::InvalidPunctuation);
+        }
+
         Ok(self.build_unchecked())
     }
 }
@@ -342,6 +692,26 @@ pub struct Options {
     inf_string: Option<&'static [u8]>,
     /// Long string representation of `Infinity`.
     infinity_string: Option<&'static [u8]>,
+    /// How to handle floats too large or too small to represent.
+    overflow_mode: OverflowMode,
+    /// String to designate the exponent component of a float, in addition
+    /// to the exponent character.
+    exponent_string: Option<&'static [u8]>,
+    /// Maximum number of significant digits in the mantissa.
+    max_significant_digits: Option<num::NonZeroUsize>,
+    /// Maximum number of significant digits in the exponent.
+    max_exponent_digits: Option<num::NonZeroUsize>,
+    /// Characters to designate the exponent component of a float, in
+    /// addition to the exponent character.
+    exponent_characters: Option<&'static [u8]>,
+    /// Flush subnormal floats to zero.
+    flush_subnormals: bool,
+    /// Characters to silently skip anywhere in the input.
+    ignored_chars: Option<&'static [u8]>,
+    /// Accept a trailing `%`, which scales the float by 1/100.
+    percent: bool,
+    /// Characters to designate the imaginary part of a complex number.
+    imaginary_units: &'static [u8],
 }
 
 impl Options {
@@ -411,6 +781,62 @@ impl Options {
         self.infinity_string
     }
 
+    /// Get how to handle floats too large or too small to represent.
+    #[inline(always)]
+    pub const fn overflow_mode(&self) -> OverflowMode {
+        self.overflow_mode
+    }
+
+    /// Get the string to designate the exponent component of a float.
+    #[inline(always)]
+    pub const fn exponent_string(&self) -> Option<&'static [u8]> {
+        self.exponent_string
+    }
+
+    /// Get the maximum number of significant digits in the mantissa.
+    #[inline(always)]
+    pub const fn max_significant_digits(&self) -> Option<num::NonZeroUsize> {
+        self.max_significant_digits
+    }
+
+    /// Get the maximum number of significant digits in the exponent.
+    #[inline(always)]
+    pub const fn max_exponent_digits(&self) -> Option<num::NonZeroUsize> {
+        self.max_exponent_digits
+    }
+
+    /// Get the additional characters to designate the exponent component
+    /// of a float.
+    #[inline(always)]
+    pub const fn exponent_characters(&self) -> Option<&'static [u8]> {
+        self.exponent_characters
+    }
+
+    /// Get if subnormal floats are flushed to zero.
+    #[inline(always)]
+    pub const fn flush_subnormals(&self) -> bool {
+        self.flush_subnormals
+    }
+
+    /// Get the characters to silently skip anywhere in the input.
+    #[inline(always)]
+    pub const fn ignored_chars(&self) -> Option<&'static [u8]> {
+        self.ignored_chars
+    }
+
+    /// Get if we accept a trailing percent sign.
+    #[inline(always)]
+    pub const fn percent(&self) -> bool {
+        self.percent
+    }
+
+    /// Get the characters to designate the imaginary part of a complex
+    /// number.
+    #[inline(always)]
+    pub const fn imaginary_units(&self) -> &'static [u8] {
+        self.imaginary_units
+    }
+
     // SETTERS
 
     /// Set if we disable the use of arbitrary-precision arithmetic.
@@ -449,6 +875,65 @@ impl Options {
         self.infinity_string = infinity_string;
     }
 
+    /// Set how to handle floats too large or too small to represent.
+    #[inline(always)]
+    pub fn set_overflow_mode(&mut self, overflow_mode: OverflowMode) {
+        self.overflow_mode = overflow_mode;
+    }
+
+    /// Set the string to designate the exponent component of a float.
+    #[inline(always)]
+    pub fn set_exponent_string(&mut self, exponent_string: Option<&'static [u8]>) {
+        self.exponent_string = exponent_string;
+    }
+
+    /// Set the maximum number of significant digits in the mantissa.
+    #[inline(always)]
+    pub fn set_max_significant_digits(
+        &mut self,
+        max_significant_digits: Option<num::NonZeroUsize>,
+    ) {
+        self.max_significant_digits = max_significant_digits;
+    }
+
+    /// Set the maximum number of significant digits in the exponent.
+    #[inline(always)]
+    pub fn set_max_exponent_digits(&mut self, max_exponent_digits: Option<num::NonZeroUsize>) {
+        self.max_exponent_digits = max_exponent_digits;
+    }
+
+    /// Set the additional characters to designate the exponent component
+    /// of a float.
+    #[inline(always)]
+    pub fn set_exponent_characters(&mut self, exponent_characters: Option<&'static [u8]>) {
+        self.exponent_characters = exponent_characters;
+    }
+
+    /// Set if subnormal floats are flushed to zero.
+    #[inline(always)]
+    pub fn set_flush_subnormals(&mut self, flush_subnormals: bool) {
+        self.flush_subnormals = flush_subnormals;
+    }
+
+    /// Set the characters to silently skip anywhere in the input.
+    #[inline(always)]
+    pub fn set_ignored_chars(&mut self, ignored_chars: Option<&'static [u8]>) {
+        self.ignored_chars = ignored_chars;
+    }
+
+    /// Set if we accept a trailing percent sign.
+    #[inline(always)]
+    pub fn set_percent(&mut self, percent: bool) {
+        self.percent = percent;
+    }
+
+    /// Set the characters to designate the imaginary part of a complex
+    /// number.
+    #[inline(always)]
+    pub fn set_imaginary_units(&mut self, imaginary_units: &'static [u8]) {
+        self.imaginary_units = imaginary_units;
+    }
+
     // BUILDERS
 
     /// Get `OptionsBuilder` as a static function.
@@ -469,6 +954,15 @@ impl Options {
             nan_string: self.nan_string,
             inf_string: self.inf_string,
             infinity_string: self.infinity_string,
+            overflow_mode: self.overflow_mode,
+            exponent_string: self.exponent_string,
+            max_significant_digits: self.max_significant_digits,
+            max_exponent_digits: self.max_exponent_digits,
+            exponent_characters: self.exponent_characters,
+            flush_subnormals: self.flush_subnormals,
+            ignored_chars: self.ignored_chars,
+            percent: self.percent,
+            imaginary_units: self.imaginary_units,
         }
     }
 }
@@ -529,6 +1023,14 @@ pub const CARAT_EXPONENT: Options = Options::builder()
         .build_unchecked();
 const_assert!(CARAT_EXPONENT.is_valid());
 
+/// Numerical format which also accepts scientific notation written as
+/// a multiplication by a power of 10, like `1.23×10^4`.
+#[rustfmt::skip]
+pub const TIMES_TEN_EXPONENT: Options = Options::builder()
+        .exponent_string(Some("×10^".as_bytes()))
+        .build_unchecked();
+const_assert!(TIMES_TEN_EXPONENT.is_valid());
+
 /// Number format for a `Rust` literal floating-point number.
 #[rustfmt::skip]
 pub const RUST_LITERAL: Options = Options::builder()
@@ -1011,15 +1513,28 @@ const_assert!(JSON.is_valid());
 /// Number format for a `TOML` literal floating-point number.
 #[rustfmt::skip]
 pub const TOML: Options = Options::builder()
-        .nan_string(options::TOML)
-        .inf_string(options::TOML)
-        .infinity_string(options::TOML)
+        .nan_string(options::TOML_NAN)
+        .inf_string(options::TOML_INF)
+        .infinity_string(options::TOML_INF)
         .build_unchecked();
 const_assert!(TOML.is_valid());
 
 /// Number format for a `YAML` literal floating-point number.
 #[rustfmt::skip]
-pub const YAML: Options = JSON;
+pub const YAML: Options = Options::builder()
+        .nan_string(options::YAML_NAN)
+        .inf_string(options::YAML_INF)
+        .infinity_string(options::YAML_INF)
+        .build_unchecked();
+const_assert!(YAML.is_valid());
+
+/// Number format for a `JSON5` literal floating-point number.
+#[rustfmt::skip]
+pub const JSON5: Options = Options::builder()
+        .inf_string(options::JSON5_INF)
+        .infinity_string(options::JSON5_INFINITY)
+        .build_unchecked();
+const_assert!(JSON5.is_valid());
 
 /// Number format for an `XML` literal floating-point number.
 #[rustfmt::skip]
@@ -1063,3 +1578,66 @@ pub const MONGODB: Options = Options::builder()
         .infinity_string(options::MONGODB_INFINITY)
         .build_unchecked();
 const_assert!(MONGODB.is_valid());
+
+#[cfg(feature = "proptest")]
+impl proptest::arbitrary::Arbitrary for OverflowMode {
+    type Parameters = ();
+    type Strategy = proptest::sample::Select<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        proptest::sample::select(vec![
+            OverflowMode::Round,
+            OverflowMode::Saturate,
+            OverflowMode::Error,
+        ])
+    }
+}
+
+#[cfg(feature = "proptest")]
+impl proptest::arbitrary::Arbitrary for Options {
+    type Parameters = ();
+    type Strategy = proptest::strategy::BoxedStrategy<Self>;
+
+    /// Generate valid options, with random punctuation and special
+    /// float strings.
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        use proptest::prelude::*;
+        use proptest::sample::select;
+
+        (
+            any::<bool>(),
+            select(vec![b'e', b'^']),
+            select(vec![b'.', b',']),
+            select(vec![Some(b"NaN" as &'static [u8]), Some(b"nan"), None]),
+            select(vec![Some(b"inf" as &'static [u8]), Some(b"Inf"), None]),
+            select(vec![Some(b"infinity" as &'static [u8]), Some(b"Infinity"), None]),
+            any::<OverflowMode>(),
+            select(vec![None, Some("×10^".as_bytes()), Some("·10^".as_bytes())]),
+        )
+            .prop_map(
+                |(
+                    lossy,
+                    exponent,
+                    decimal_point,
+                    nan,
+                    inf,
+                    infinity,
+                    overflow_mode,
+                    exponent_string,
+                )| {
+                    Self::builder()
+                        .lossy(lossy)
+                        .exponent(exponent)
+                        .decimal_point(decimal_point)
+                        .nan_string(nan)
+                        .inf_string(inf)
+                        .infinity_string(infinity)
+                        .overflow_mode(overflow_mode)
+                        .exponent_string(exponent_string)
+                },
+            )
+            .prop_filter("options must be valid", OptionsBuilder::is_valid)
+            .prop_map(|builder| builder.build_unchecked())
+            .boxed()
+    }
+}
@@ -9,30 +9,45 @@
 
 #![doc(hidden)]
 
+use core::cmp;
+
 #[cfg(not(feature = "compact"))]
 use lexical_parse_integer::algorithm;
+#[cfg(all(not(feature = "compact"), feature = "simd"))]
+use lexical_parse_integer::simd;
 #[cfg(feature = "f16")]
 use lexical_util::bf16::bf16;
-use lexical_util::digit::{char_to_digit_const, char_to_valid_digit_const};
+use lexical_util::digit::{char_to_digit_format, char_to_valid_digit_const};
 use lexical_util::error::Error;
 #[cfg(feature = "f16")]
 use lexical_util::f16::f16;
 use lexical_util::format::NumberFormat;
 use lexical_util::iterator::{AsBytes, Bytes, DigitsIter, Iter};
+use lexical_util::num::{Float, Integer};
 use lexical_util::result::Result;
 use lexical_util::step::u64_step;
 
 #[cfg(any(feature = "compact", feature = "radix"))]
 use crate::bellerophon::bellerophon;
+use crate::bigint::{Limb, BIGINT_LIMBS};
 #[cfg(feature = "power-of-two")]
 use crate::binary::{binary, slow_binary};
-use crate::float::{extended_to_float, ExtendedFloat80, LemireFloat};
+use crate::float::{extended_to_float, ExtendedFloat80, LemireFloat, RawFloat};
 #[cfg(not(feature = "compact"))]
 use crate::lemire::lemire;
 use crate::number::Number;
-use crate::options::Options;
+use crate::options::{Options, OverflowMode};
+use crate::parts::NumberParts;
 use crate::shared;
-use crate::slow::slow_radix;
+use crate::slow::{
+    bh,
+    compare_extended,
+    compare_float,
+    parse_mantissa,
+    scientific_exponent,
+    slow_radix,
+    Scratch,
+};
 
 // API
 // ---
@@ -77,14 +92,50 @@ pub trait ParseFloat: LemireFloat {
     #[cfg_attr(not(feature = "compact"), inline(always))]
     fn parse_complete<const FORMAT: u128>(bytes: &[u8], options: &Options) -> Result<Self> {
         check_radix!(FORMAT);
-        parse_complete::<Self, FORMAT>(bytes, options)
+        parse_complete::<Self, FORMAT, BIGINT_LIMBS>(bytes, options, None)
     }
 
     /// Forward partial parser parameters to the backend.
     #[cfg_attr(not(feature = "compact"), inline(always))]
     fn parse_partial<const FORMAT: u128>(bytes: &[u8], options: &Options) -> Result<(Self, usize)> {
         check_radix!(FORMAT);
-        parse_partial::<Self, FORMAT>(bytes, options)
+        parse_partial::<Self, FORMAT, BIGINT_LIMBS>(bytes, options, None)
+    }
+
+    /// Forward complete parser parameters to the backend, reusing the
+    /// storage for the slow path. This fails with [`Error::TooManyDigits`]
+    /// if the storage does not have enough capacity.
+    #[cfg_attr(not(feature = "compact"), inline(always))]
+    fn parse_complete_scratch<const FORMAT: u128, const SIZE: usize>(
+        bytes: &[u8],
+        options: &Options,
+        scratch: &mut Scratch<SIZE>,
+    ) -> Result<Self> {
+        check_radix!(FORMAT);
+        parse_complete::<Self, FORMAT, SIZE>(bytes, options, Some(scratch))
+    }
+
+    /// Forward partial parser parameters to the backend, reusing the
+    /// storage for the slow path. This fails with [`Error::TooManyDigits`]
+    /// if the storage does not have enough capacity.
+    #[cfg_attr(not(feature = "compact"), inline(always))]
+    fn parse_partial_scratch<const FORMAT: u128, const SIZE: usize>(
+        bytes: &[u8],
+        options: &Options,
+        scratch: &mut Scratch<SIZE>,
+    ) -> Result<(Self, usize)> {
+        check_radix!(FORMAT);
+        parse_partial::<Self, FORMAT, SIZE>(bytes, options, Some(scratch))
+    }
+
+    /// Forward comparison parameters to the backend.
+    #[cfg_attr(not(feature = "compact"), inline(always))]
+    fn compare_complete<const FORMAT: u128>(
+        bytes: &[u8],
+        options: &Options,
+        float: Self,
+    ) -> Result<cmp::Ordering> {
+        compare_complete::<Self, FORMAT>(bytes, options, float)
     }
 
     /// Forward complete parser parameters to the backend, using only the fast
@@ -123,15 +174,49 @@ macro_rules! parse_float_as_f32 {
             fn parse_complete<const FORMAT: u128>(bytes: &[u8], options: &Options)
                 -> Result<Self>
             {
-                Ok(Self::from_f32(parse_complete::<f32, FORMAT>(bytes, options)?))
+                let float = parse_complete::<f32, FORMAT, BIGINT_LIMBS>(bytes, options, None)?;
+                check_overflow::<_, FORMAT>(Self::from_f32(float), float == 0.0, options, bytes.len())
             }
 
             #[cfg_attr(not(feature = "compact"), inline(always))]
             fn parse_partial<const FORMAT: u128>(bytes: &[u8], options: &Options)
                 -> Result<(Self, usize)>
             {
-                let (float, count) = parse_partial::<f32, FORMAT>(bytes, options)?;
-                Ok((Self::from_f32(float), count))
+                let (float, count) = parse_partial::<f32, FORMAT, BIGINT_LIMBS>(bytes, options, None)?;
+                Ok((check_overflow::<_, FORMAT>(Self::from_f32(float), float == 0.0, options, count)?, count))
+            }
+
+            #[cfg_attr(not(feature = "compact"), inline(always))]
+            fn parse_complete_scratch<const FORMAT: u128, const SIZE: usize>(
+                bytes: &[u8],
+                options: &Options,
+                scratch: &mut Scratch<SIZE>,
+            ) -> Result<Self>
+            {
+                let float = parse_complete::<f32, FORMAT, SIZE>(bytes, options, Some(scratch))?;
+                check_overflow::<_, FORMAT>(Self::from_f32(float), float == 0.0, options, bytes.len())
+            }
+
+            #[cfg_attr(not(feature = "compact"), inline(always))]
+            fn parse_partial_scratch<const FORMAT: u128, const SIZE: usize>(
+                bytes: &[u8],
+                options: &Options,
+                scratch: &mut Scratch<SIZE>,
+            ) -> Result<(Self, usize)>
+            {
+                let (float, count) = parse_partial::<f32, FORMAT, SIZE>(bytes, options, Some(scratch))?;
+                Ok((check_overflow::<_, FORMAT>(Self::from_f32(float), float == 0.0, options, count)?, count))
+            }
+
+            #[cfg_attr(not(feature = "compact"), inline(always))]
+            fn compare_complete<const FORMAT: u128>(
+                bytes: &[u8],
+                options: &Options,
+                float: Self,
+            ) -> Result<cmp::Ordering>
+            {
+                // The float is exactly representable as an `f32`.
+                compare_complete::<f32, FORMAT>(bytes, options, float.as_f32())
             }
 
             #[cfg_attr(not(feature = "compact"), inline(always))]
@@ -173,6 +258,8 @@ pub fn parse_mantissa_sign<const FORMAT: u128>(byte: &mut Bytes<'_, FORMAT>) ->
         true,
         format.no_positive_mantissa_sign(),
         format.required_mantissa_sign(),
+        format.unicode_signs(),
+        format.parenthesized_negatives(),
         InvalidPositiveSign,
         MissingSign
     )
@@ -187,6 +274,8 @@ pub fn parse_exponent_sign<const FORMAT: u128>(byte: &mut Bytes<'_, FORMAT>) ->
         true,
         format.no_positive_exponent_sign(),
         format.required_exponent_sign(),
+        format.unicode_signs(),
+        false,
         InvalidPositiveExponentSign,
         MissingExponentSign
     )
@@ -223,25 +312,99 @@ macro_rules! parse_number {
     }};
 }
 
-/// Convert extended float to native.
+/// Convert extended float to native, handling overflow and underflow.
 ///
 /// - `type` - The native floating point type.
 /// - `fp` - The extended floating-point representation.
+/// - `is_negative` - If the final value is negative
+/// - `is_zero` - If the parsed digits are all zero
+/// - `format` - The numerical format specification as a packed 128-bit integer
+/// - `options` - The options with the overflow mode
+/// - `index` - The index for overflow or underflow errors
 macro_rules! to_native {
-    ($type:ident, $fp:ident, $is_negative:ident) => {{
+    (
+        $type:ident,
+        $fp:ident,
+        $is_negative:ident,
+        $is_zero:ident,
+        $format:ident,
+        $options:ident,
+        $index:expr
+    ) => {{
         let mut float = extended_to_float::<$type>($fp);
         if $is_negative {
             float = -float;
         }
-        float
+        check_overflow::<_, $format>(float, $is_zero, $options, $index)?
     }};
 }
 
+/// Handle a float that overflowed to infinity or underflowed to zero.
+///
+/// A zero float only underflowed if the parsed digits were not all zero,
+/// which is given by `is_zero`. Otherwise, the float is returned unchanged,
+/// other than the handling of subnormal floats in [`check_subnormal`].
+#[cfg_attr(not(feature = "compact"), inline(always))]
+pub fn check_overflow<F: Float, const FORMAT: u128>(
+    float: F,
+    is_zero: bool,
+    options: &Options,
+    index: usize,
+) -> Result<F> {
+    if float.is_inf() {
+        match options.overflow_mode() {
+            OverflowMode::Round => Ok(float),
+            OverflowMode::Saturate if float.is_sign_negative() => Ok(F::MIN),
+            OverflowMode::Saturate => Ok(F::MAX),
+            OverflowMode::Error => Err(Error::Overflow(index)),
+        }
+    } else if float.to_bits() & !F::SIGN_MASK == F::Unsigned::ZERO && !is_zero {
+        let denormal = F::from_bits(F::Unsigned::ONE);
+        match options.overflow_mode() {
+            OverflowMode::Round => Ok(float),
+            OverflowMode::Saturate if float.is_sign_negative() => {
+                check_subnormal::<_, FORMAT>(-denormal, options, index)
+            },
+            OverflowMode::Saturate => check_subnormal::<_, FORMAT>(denormal, options, index),
+            OverflowMode::Error => Err(Error::Underflow(index)),
+        }
+    } else {
+        check_subnormal::<_, FORMAT>(float, options, index)
+    }
+}
+
+/// Handle a subnormal float.
+///
+/// Subnormal floats are an underflow error if the format does not allow
+/// them, and are flushed to zero, keeping the sign, if enabled.
+#[cfg_attr(not(feature = "compact"), inline(always))]
+fn check_subnormal<F: Float, const FORMAT: u128>(
+    float: F,
+    options: &Options,
+    index: usize,
+) -> Result<F> {
+    if !float.is_denormal() || float == F::ZERO {
+        Ok(float)
+    } else if NumberFormat::<FORMAT>::NO_SUBNORMALS {
+        Err(Error::Underflow(index))
+    } else if options.flush_subnormals() {
+        Ok(F::from_bits(float.to_bits() & F::SIGN_MASK))
+    } else {
+        Ok(float)
+    }
+}
+
 /// Parse a float from bytes using a complete parser.
+///
+/// If `scratch` is provided, it is used as the storage for the slow path,
+/// otherwise new storage is created if the slow path is required. This
+/// fails with [`Error::TooManyDigits`] if the storage does not have enough
+/// capacity to correctly round the float.
 #[allow(clippy::missing_inline_in_public_items)] // reason = "only public for testing"
-pub fn parse_complete<F: LemireFloat, const FORMAT: u128>(
+pub fn parse_complete<F: LemireFloat, const FORMAT: u128, const SIZE: usize>(
     bytes: &[u8],
     options: &Options,
+    scratch: Option<&mut Scratch<SIZE>>,
 ) -> Result<F> {
     let mut byte = bytes.bytes::<{ FORMAT }>();
     let is_negative = parse_mantissa_sign(&mut byte)?;
@@ -260,9 +423,10 @@ pub fn parse_complete<F: LemireFloat, const FORMAT: u128>(
         parse_number!(FORMAT, byte, is_negative, options, parse_complete_number, parse_special);
     // Try the fast-path algorithm.
     if let Some(value) = num.try_fast_path::<_, FORMAT>() {
-        return Ok(value);
+        return check_subnormal::<_, FORMAT>(value, options, bytes.len());
     }
     // Now try the moderate path algorithm.
+    let is_zero = num.mantissa == 0;
     let mut fp = moderate_path::<F, FORMAT>(&num, options.lossy());
 
     // Unable to correctly round the float using the fast or moderate algorithms.
@@ -272,11 +436,15 @@ pub fn parse_complete<F: LemireFloat, const FORMAT: u128>(
         debug_assert!(!options.lossy(), "lossy algorithms never use slow algorithms");
         // Undo the invalid extended float biasing.
         fp.exp -= shared::INVALID_FP;
-        fp = slow_path::<F, FORMAT>(num, fp);
+        let slow = match scratch {
+            Some(scratch) => slow_path::<F, FORMAT, SIZE>(num, fp, scratch),
+            None => slow_path::<F, FORMAT, SIZE>(num, fp, &mut Scratch::new()),
+        };
+        fp = slow.ok_or(Error::TooManyDigits(bytes.len()))?;
     }
 
     // Convert to native float and return result.
-    Ok(to_native!(F, fp, is_negative))
+    Ok(to_native!(F, fp, is_negative, is_zero, FORMAT, options, bytes.len()))
 }
 
 /// Parse a float using only the fast path as a complete parser.
@@ -303,11 +471,273 @@ pub fn fast_path_complete<F: LemireFloat, const FORMAT: u128>(
     Ok(num.force_fast_path::<_, FORMAT>())
 }
 
+/// Check if bytes are a valid float using a complete parser, without
+/// rounding the significant digits to a float.
+///
+/// This parses the sign, digits, exponent, and special values exactly
+/// like [`parse_complete`], but does not check for overflow or underflow,
+/// since that depends on the float type and requires rounding.
+#[allow(clippy::missing_inline_in_public_items)] // reason = "only public for testing"
+pub fn validate_complete<const FORMAT: u128>(bytes: &[u8], options: &Options) -> Result<()> {
+    check_radix!(FORMAT);
+    let mut byte = bytes.bytes::<{ FORMAT }>();
+    let is_negative = parse_mantissa_sign(&mut byte)?;
+    if byte.integer_iter().is_consumed() {
+        if NumberFormat::<FORMAT>::REQUIRED_INTEGER_DIGITS
+            || NumberFormat::<FORMAT>::REQUIRED_MANTISSA_DIGITS
+        {
+            return Err(Error::Empty(byte.cursor()));
+        } else {
+            return Ok(());
+        }
+    }
+
+    match parse_complete_number::<FORMAT>(byte.clone(), is_negative, options) {
+        Ok(_) => Ok(()),
+        // Special values are the same for all float types.
+        Err(_) if parse_special::<f64, FORMAT>(byte, is_negative, options).is_some() => Ok(()),
+        Err(error) => Err(error),
+    }
+}
+
+/// Find the components of a float using a complete parser, without
+/// rounding the significant digits to a float.
+#[allow(clippy::missing_inline_in_public_items)] // reason = "only public for testing"
+pub fn parse_parts_complete<const FORMAT: u128>(
+    bytes: &[u8],
+    options: &Options,
+) -> Result<NumberParts> {
+    check_radix!(FORMAT);
+    let mut byte = bytes.bytes::<{ FORMAT }>();
+    let is_negative = parse_mantissa_sign(&mut byte)?;
+    let sign_end = byte.cursor();
+    let sign = if sign_end != 0 {
+        Some(0..sign_end)
+    } else {
+        None
+    };
+    if byte.integer_iter().is_consumed() {
+        if NumberFormat::<FORMAT>::REQUIRED_INTEGER_DIGITS
+            || NumberFormat::<FORMAT>::REQUIRED_MANTISSA_DIGITS
+        {
+            return Err(Error::Empty(byte.cursor()));
+        } else {
+            return Ok(NumberParts {
+                sign,
+                integer: sign_end..sign_end,
+                fraction: None,
+                exponent: None,
+            });
+        }
+    }
+
+    // The digits are subslices of the input, so get their offsets from
+    // their addresses.
+    let num = parse_complete_number::<FORMAT>(byte, is_negative, options)?;
+    let range = |digits: &[u8]| {
+        let start = digits.as_ptr() as usize - bytes.as_ptr() as usize;
+        start..start + digits.len()
+    };
+    let integer = range(num.integer);
+    let fraction = num.fraction.map(range);
+    let mantissa_end = fraction.as_ref().map_or(integer.end, |fraction| fraction.end);
+
+    // Anything after the mantissa is the exponent, and then the base suffix.
+    let format = NumberFormat::<{ FORMAT }> {};
+    let mut end = bytes.len();
+    let base_suffix = format.base_suffix();
+    if base_suffix != 0 && end > mantissa_end {
+        let last = bytes[end - 1];
+        let is_suffix = if format.case_sensitive_base_suffix() {
+            last == base_suffix
+        } else {
+            last.eq_ignore_ascii_case(&base_suffix)
+        };
+        if is_suffix {
+            end -= 1;
+        }
+    }
+    let exponent = if end > mantissa_end {
+        Some(mantissa_end..end)
+    } else {
+        None
+    };
+
+    Ok(NumberParts {
+        sign,
+        integer,
+        fraction,
+        exponent,
+    })
+}
+
+/// Compare the value of bytes to a float parsed from them using a complete
+/// parser, to determine if and how it was rounded.
+///
+/// The bytes must have been successfully parsed as `float`. Returns
+/// `Ordering::Equal` if the float is exactly the value of the bytes,
+/// including for special values, like `NaN`. Returns
+/// [`Error::TooManyDigits`] if the big integers do not have enough
+/// capacity to compare the digits.
+#[allow(clippy::missing_inline_in_public_items)] // reason = "only public for testing"
+pub fn compare_complete<F: LemireFloat, const FORMAT: u128>(
+    bytes: &[u8],
+    options: &Options,
+    float: F,
+) -> Result<cmp::Ordering> {
+    let mut byte = bytes.bytes::<{ FORMAT }>();
+    let is_negative = parse_mantissa_sign(&mut byte)?;
+    if byte.integer_iter().is_consumed() {
+        return Ok(cmp::Ordering::Equal);
+    }
+    let num = match parse_complete_number::<FORMAT>(byte, is_negative, options) {
+        Ok(num) => num,
+        Err(_) => return Ok(cmp::Ordering::Equal),
+    };
+
+    let is_zero = float.to_bits() & !F::SIGN_MASK == F::Unsigned::ZERO;
+    let ordering = if num.mantissa == 0 && !num.many_digits {
+        // The float is always zero, since zero cannot overflow.
+        cmp::Ordering::Equal
+    } else if !num.many_digits && num.exponent == 0 && num.mantissa <= F::MAX_MANTISSA_FAST_PATH {
+        // Small integers are always exact.
+        cmp::Ordering::Equal
+    } else if is_zero {
+        // The float underflowed.
+        cmp::Ordering::Greater
+    } else if float.is_inf() {
+        // The float overflowed.
+        cmp::Ordering::Less
+    } else {
+        let mut scratch = Scratch::<BIGINT_LIMBS>::new();
+        compare_float::<F, FORMAT, BIGINT_LIMBS>(num, float, &mut scratch)
+            .ok_or(Error::TooManyDigits(bytes.len()))?
+    };
+
+    // The magnitudes are compared, so reverse the order for negative values.
+    if is_negative {
+        Ok(ordering.reverse())
+    } else {
+        Ok(ordering)
+    }
+}
+
+/// Compare the value of bytes to the halfway point between a float and
+/// the next float further from zero, `b+h`, using a complete parser.
+///
+/// Values are compared with their signs, and the float must be finite.
+/// Returns [`Error::TooManyDigits`] if the big integers do not have enough
+/// capacity to compare the digits.
+#[allow(clippy::missing_inline_in_public_items)] // reason = "only public for testing"
+pub fn compare_halfway_complete<F: RawFloat, const FORMAT: u128>(
+    bytes: &[u8],
+    options: &Options,
+    float: F,
+) -> Result<cmp::Ordering> {
+    assert!(!float.is_special(), "the float must be finite");
+
+    let mut byte = bytes.bytes::<{ FORMAT }>();
+    let is_negative = parse_mantissa_sign(&mut byte)?;
+    if byte.integer_iter().is_consumed() {
+        return Err(Error::Empty(byte.cursor()));
+    }
+    let num = parse_complete_number::<FORMAT>(byte, is_negative, options)?;
+
+    // The halfway point is never zero, so zero is only ordered by its sign.
+    let is_halfway_negative = float.is_sign_negative();
+    if num.mantissa == 0 && !num.many_digits {
+        return Ok(if is_halfway_negative {
+            cmp::Ordering::Greater
+        } else {
+            cmp::Ordering::Less
+        });
+    } else if is_negative != is_halfway_negative {
+        return Ok(if is_negative {
+            cmp::Ordering::Less
+        } else {
+            cmp::Ordering::Greater
+        });
+    }
+
+    // The halfway point has 1 more significant bit than the float.
+    let format = NumberFormat::<{ FORMAT }> {};
+    let max_digits = F::max_digits(format.radix()).unwrap_or(usize::MAX);
+    let mut scratch = Scratch::<BIGINT_LIMBS>::new();
+    let ordering =
+        compare_extended::<FORMAT, BIGINT_LIMBS>(num, bh(float), max_digits, &mut scratch)
+            .ok_or(Error::TooManyDigits(bytes.len()))?;
+
+    // The magnitudes are compared, so reverse the order for negative values.
+    if is_negative {
+        Ok(ordering.reverse())
+    } else {
+        Ok(ordering)
+    }
+}
+
+/// Parse the significant digits of bytes into a big integer, using a
+/// complete parser.
+///
+/// Writes the magnitude of the digits to `limbs` as little-endian 32-bit
+/// limbs, and returns the number of limbs and the exponent in the radix
+/// relative to the digits. Digits past the maximum number that can affect
+/// rounding `F` are truncated, with a trailing non-zero digit if any were
+/// non-zero. Returns [`Error::TooManyDigits`] if the limbs do not have
+/// enough capacity.
+#[allow(clippy::cast_possible_wrap)] // reason = "can't wrap in practice: max is ~1000 limbs"
+#[allow(clippy::useless_conversion)] // reason = "the limbs may be 32 or 64 bits"
+#[allow(clippy::missing_inline_in_public_items)] // reason = "only public for testing"
+pub fn parse_bigint_complete<F: RawFloat, const FORMAT: u128>(
+    bytes: &[u8],
+    options: &Options,
+    limbs: &mut [u32],
+) -> Result<(usize, i32)> {
+    let mut byte = bytes.bytes::<{ FORMAT }>();
+    let is_negative = parse_mantissa_sign(&mut byte)?;
+    if byte.integer_iter().is_consumed() {
+        return Err(Error::Empty(byte.cursor()));
+    }
+    let num = parse_complete_number::<FORMAT>(byte, is_negative, options)?;
+    if num.mantissa == 0 && !num.many_digits {
+        return Ok((0, 0));
+    }
+
+    let format = NumberFormat::<{ FORMAT }> {};
+    let max_digits = F::max_digits(format.radix()).unwrap_or(usize::MAX);
+    let sci_exp = scientific_exponent::<FORMAT>(&num);
+    let mut scratch = Scratch::<BIGINT_LIMBS>::new();
+    let digits = parse_mantissa::<FORMAT, BIGINT_LIMBS>(&mut scratch.real, num, max_digits)
+        .ok_or(Error::TooManyDigits(bytes.len()))?;
+
+    // Split the limbs into 32-bit halves, without the leading zeros.
+    let halves = scratch.real.data.iter().flat_map(|&limb| {
+        let limb = u64::from(limb);
+        [limb as u32, (limb >> 32) as u32].into_iter().take(Limb::BITS as usize / 32)
+    });
+    let mut count = 0;
+    for (index, half) in halves.enumerate() {
+        if half != 0 {
+            *limbs.get_mut(index).ok_or(Error::TooManyDigits(bytes.len()))? = half;
+            count = index + 1;
+        } else if let Some(limb) = limbs.get_mut(index) {
+            *limb = 0;
+        }
+    }
+
+    Ok((count, sci_exp + 1 - digits as i32))
+}
+
 /// Parse a float from bytes using a partial parser.
+///
+/// If `scratch` is provided, it is used as the storage for the slow path,
+/// otherwise new storage is created if the slow path is required. This
+/// fails with [`Error::TooManyDigits`] if the storage does not have enough
+/// capacity to correctly round the float.
 #[allow(clippy::missing_inline_in_public_items)] // reason = "only public for testing"
-pub fn parse_partial<F: LemireFloat, const FORMAT: u128>(
+pub fn parse_partial<F: LemireFloat, const FORMAT: u128, const SIZE: usize>(
     bytes: &[u8],
     options: &Options,
+    scratch: Option<&mut Scratch<SIZE>>,
 ) -> Result<(F, usize)> {
     let mut byte = bytes.bytes::<{ FORMAT }>();
     let is_negative = parse_mantissa_sign(&mut byte)?;
@@ -332,9 +762,10 @@ pub fn parse_partial<F: LemireFloat, const FORMAT: u128>(
     );
     // Try the fast-path algorithm.
     if let Some(value) = num.try_fast_path::<_, FORMAT>() {
-        return Ok((value, count));
+        return Ok((check_subnormal::<_, FORMAT>(value, options, count)?, count));
     }
     // Now try the moderate path algorithm.
+    let is_zero = num.mantissa == 0;
     let mut fp = moderate_path::<F, FORMAT>(&num, options.lossy());
 
     // Unable to correctly round the float using the fast or moderate algorithms.
@@ -344,11 +775,15 @@ pub fn parse_partial<F: LemireFloat, const FORMAT: u128>(
         debug_assert!(!options.lossy(), "lossy algorithms never use slow algorithms");
         // Undo the invalid extended float biasing.
         fp.exp -= shared::INVALID_FP;
-        fp = slow_path::<F, FORMAT>(num, fp);
+        let slow = match scratch {
+            Some(scratch) => slow_path::<F, FORMAT, SIZE>(num, fp, scratch),
+            None => slow_path::<F, FORMAT, SIZE>(num, fp, &mut Scratch::new()),
+        };
+        fp = slow.ok_or(Error::TooManyDigits(count))?;
     }
 
     // Convert to native float and return result.
-    Ok((to_native!(F, fp, is_negative), count))
+    Ok((to_native!(F, fp, is_negative, is_zero, FORMAT, options, count), count))
 }
 
 /// Parse a float using only the fast path as a partial parser.
@@ -384,6 +819,37 @@ pub fn fast_path_partial<F: LemireFloat, const FORMAT: u128>(
 // PATHS
 // -----
 
+/// Round a decimal float with an exponent far outside the range of the
+/// float to zero or infinity, without rounding the significant digits.
+///
+/// The significant digits are truncated to at most 19 digits, so the value
+/// is at least `10^q` and less than `2^64 * 10^q`. Any exponent below
+/// [`LemireFloat::SMALLEST_POWER_OF_TEN`] is therefore zero, and any
+/// exponent above [`LemireFloat::LARGEST_POWER_OF_TEN`] is infinite, no
+/// matter how many digits there are.
+#[must_use]
+#[inline(always)]
+pub fn out_of_range_path<F: LemireFloat, const FORMAT: u128>(
+    num: &Number,
+) -> Option<ExtendedFloat80> {
+    let format = NumberFormat::<{ FORMAT }> {};
+    if format.radix() != 10 || num.mantissa == 0 {
+        None
+    } else if num.exponent < F::SMALLEST_POWER_OF_TEN as i64 {
+        Some(ExtendedFloat80 {
+            mant: 0,
+            exp: 0,
+        })
+    } else if num.exponent > F::LARGEST_POWER_OF_TEN as i64 {
+        Some(ExtendedFloat80 {
+            mant: 0,
+            exp: F::INFINITE_POWER,
+        })
+    } else {
+        None
+    }
+}
+
 /// Wrapper for different moderate-path algorithms.
 /// A return exponent of `-1` indicates an invalid value.
 #[must_use]
@@ -392,6 +858,10 @@ pub fn moderate_path<F: LemireFloat, const FORMAT: u128>(
     num: &Number,
     lossy: bool,
 ) -> ExtendedFloat80 {
+    if let Some(fp) = out_of_range_path::<F, FORMAT>(num) {
+        return fp;
+    }
+
     #[cfg(feature = "compact")]
     {
         #[cfg(feature = "power-of-two")]
@@ -449,24 +919,26 @@ pub fn moderate_path<F: LemireFloat, const FORMAT: u128>(
 
 /// Invoke the slow path.
 /// At this point, the float string has already been validated.
+/// Returns `None` if the storage does not have enough capacity.
 #[must_use]
 #[inline(always)]
-pub fn slow_path<F: LemireFloat, const FORMAT: u128>(
+pub fn slow_path<F: LemireFloat, const FORMAT: u128, const SIZE: usize>(
     num: Number,
     fp: ExtendedFloat80,
-) -> ExtendedFloat80 {
+    scratch: &mut Scratch<SIZE>,
+) -> Option<ExtendedFloat80> {
     #[cfg(not(feature = "power-of-two"))]
     {
-        slow_radix::<F, FORMAT>(num, fp)
+        slow_radix::<F, FORMAT, SIZE>(num, fp, scratch)
     }
 
     #[cfg(feature = "power-of-two")]
     {
         let format = NumberFormat::<{ FORMAT }> {};
         if is_power_two!(format.mantissa_radix()) {
-            slow_binary::<F, FORMAT>(num)
+            Some(slow_binary::<F, FORMAT>(num))
         } else {
-            slow_radix::<F, FORMAT>(num, fp)
+            slow_radix::<F, FORMAT, SIZE>(num, fp, scratch)
         }
     }
 }
@@ -586,7 +1058,10 @@ pub fn parse_number<'a, const FORMAT: u128, const IS_PARTIAL: bool>(
     // NOTE: Removing this code leads to ~10% reduction in parsing
     // that triggers the Eisell-Lemire algorithm or the digit comp
     // algorithms, so don't remove the unsafe indexing.
+    #[cfg(not(feature = "safe"))]
     let integer_digits = unsafe { start.as_slice().get_unchecked(..b_digits) };
+    #[cfg(feature = "safe")]
+    let integer_digits = &start.as_slice()[..b_digits];
 
     // Check if integer leading zeros are disabled.
     #[cfg(feature = "format")]
@@ -630,8 +1105,12 @@ pub fn parse_number<'a, const FORMAT: u128, const IS_PARTIAL: bool>(
             b_after_dot <= before.as_slice().len(),
             "digits after dot must be smaller than buffer"
         );
+        #[cfg(not(feature = "safe"))]
         // SAFETY: safe, since `idx_after_dot <= before.as_slice().len()`.
-        fraction_digits = Some(unsafe { before.as_slice().get_unchecked(..b_after_dot) });
+        let digits = unsafe { before.as_slice().get_unchecked(..b_after_dot) };
+        #[cfg(feature = "safe")]
+        let digits = &before.as_slice()[..b_after_dot];
+        fraction_digits = Some(digits);
 
         // Calculate the implicit exponent: the number of digits after the dot.
         implicit_exponent = -(n_after_dot as i64);
@@ -649,8 +1128,18 @@ pub fn parse_number<'a, const FORMAT: u128, const IS_PARTIAL: bool>(
 
     // NOTE: Check if we have our exponent **BEFORE** checking if the
     // mantissa is empty, so we can ensure
-    let has_exponent = byte
-        .first_is(exponent_character, format.case_sensitive_exponent() && cfg!(feature = "format"));
+    let is_cased = format.case_sensitive_exponent() && cfg!(feature = "format");
+    let exponent_length = if byte.first_is(exponent_character, is_cased)
+        || is_exponent_character(byte.first(), options.exponent_characters(), is_cased)
+    {
+        1
+    } else {
+        match options.exponent_string() {
+            Some(string) if byte.as_slice().starts_with(string) => string.len(),
+            _ => 0,
+        }
+    };
+    let has_exponent = exponent_length != 0;
 
     // check to see if we have any invalid leading zeros
     n_digits += n_after_dot;
@@ -667,40 +1156,78 @@ pub fn parse_number<'a, const FORMAT: u128, const IS_PARTIAL: bool>(
         }
     }
 
+    // Check the number of significant digits, only skipping the leading
+    // zeros if there are too many digits.
+    if let Some(max_digits) = options.max_significant_digits() {
+        if n_digits > max_digits.get() {
+            let mut zeros = start.clone();
+            let mut significant = n_digits.saturating_sub(zeros.integer_iter().skip_zeros());
+            if zeros.first_is_cased(decimal_point) {
+                // SAFETY: safe since zeros cannot be empty due to `first_is`
+                unsafe { zeros.step_unchecked() };
+                significant = significant.saturating_sub(zeros.fraction_iter().skip_zeros());
+            }
+            if significant > max_digits.get() {
+                return Err(Error::TooManyDigits(start.cursor()));
+            }
+        }
+    }
+
     // EXPONENT
 
     // Handle scientific notation.
     let mut explicit_exponent = 0_i64;
     if has_exponent {
+        let exponent_character_start = byte.cursor();
         // NOTE: See above for the safety invariant above `required_mantissa_digits`.
         // This is separated for correctness concerns, and therefore the two cannot
         // be on the same line.
-        // SAFETY: byte cannot be empty due to `first_is` from `has_exponent`.`
-        unsafe { byte.step_unchecked() };
+        // SAFETY: byte has at least `exponent_length` items from `has_exponent`.
+        unsafe { byte.step_by_unchecked(exponent_length) };
 
         // Check float format syntax checks.
         #[cfg(feature = "format")]
         {
             // NOTE: We've overstepped for the safety invariant before.
             if format.no_exponent_notation() {
-                return Err(Error::InvalidExponent(byte.cursor() - 1));
+                return Err(Error::InvalidExponent(byte.cursor() - exponent_length));
             }
             // Check if we have no fraction but we required exponent notation.
             if format.no_exponent_without_fraction() && fraction_digits.is_none() {
-                return Err(Error::ExponentWithoutFraction(byte.cursor() - 1));
+                return Err(Error::ExponentWithoutFraction(byte.cursor() - exponent_length));
             }
         }
 
         let is_negative_exponent = parse_exponent_sign(&mut byte)?;
         let before = byte.current_count();
-        parse_digits::<_, _, FORMAT>(byte.exponent_iter(), |digit| {
+        let exponent_start = byte.cursor();
+        let mut exponent_digits = 0_usize;
+        parse_exponent_digits::<_, _, FORMAT>(byte.exponent_iter(), |digit| {
+            if explicit_exponent != 0 || digit != 0 {
+                exponent_digits += 1;
+            }
             if explicit_exponent < 0x10000000 {
-                explicit_exponent *= format.radix() as i64;
+                explicit_exponent *= format.exponent_radix() as i64;
                 explicit_exponent += digit as i64;
             }
         });
-        if format.required_exponent_digits() && byte.current_count() - before == 0 {
-            return Err(Error::EmptyExponent(byte.cursor()));
+        if byte.current_count() - before == 0 {
+            if format.trailing_empty_exponent() {
+                // The exponent is not part of the number, so leave the exponent
+                // character and sign as trailing bytes.
+                // SAFETY: safe since the cursor was previously at the exponent character.
+                unsafe { byte.set_cursor(exponent_character_start) };
+                if format.required_exponent_notation() {
+                    return Err(Error::MissingExponent(byte.cursor()));
+                }
+            } else if format.required_exponent_digits() {
+                return Err(Error::EmptyExponent(byte.cursor()));
+            }
+        }
+        if let Some(max_digits) = options.max_exponent_digits() {
+            if exponent_digits > max_digits.get() {
+                return Err(Error::TooManyDigits(exponent_start));
+            }
         }
         // Handle our sign, and get the explicit part of the exponent.
         explicit_exponent = if is_negative_exponent {
@@ -726,6 +1253,19 @@ pub fn parse_number<'a, const FORMAT: u128, const IS_PARTIAL: bool>(
         }
     }
 
+    // Check to see if we have a percent sign, which scales the float by
+    // 1/100. This is exact, since it only shifts the decimal exponent.
+    if options.percent()
+        && format.mantissa_radix() == 10
+        && format.exponent_base() == 10
+        && byte.first_is_cased(b'%')
+    {
+        // SAFETY: safe since `byte.len() >= 1`.
+        unsafe { byte.step_unchecked() };
+        exponent -= 2;
+        explicit_exponent -= 2;
+    }
+
     // CHECK OVERFLOW
 
     // Get the number of parsed digits (total), and redo if we had overflow.
@@ -853,17 +1393,48 @@ pub fn parse_complete_number<'a, const FORMAT: u128>(
 // DIGITS
 // ------
 
+/// Determine if a byte is one of the additional exponent characters.
+#[inline(always)]
+fn is_exponent_character(c: Option<&u8>, characters: Option<&[u8]>, is_cased: bool) -> bool {
+    match (c, characters) {
+        (Some(c), Some(characters)) if is_cased => characters.contains(c),
+        (Some(c), Some(characters)) => characters.iter().any(|x| c.eq_ignore_ascii_case(x)),
+        _ => false,
+    }
+}
+
 /// Iteratively parse and consume digits from bytes.
 #[inline(always)]
-pub fn parse_digits<'a, Iter, Cb, const FORMAT: u128>(mut iter: Iter, mut cb: Cb)
+pub fn parse_digits<'a, Iter, Cb, const FORMAT: u128>(iter: Iter, cb: Cb)
+where
+    Iter: DigitsIter<'a>,
+    Cb: FnMut(u32),
+{
+    let format = NumberFormat::<{ FORMAT }> {};
+    parse_radix_digits::<_, _, FORMAT>(iter, format.radix(), cb);
+}
+
+/// Iteratively parse and consume exponent digits from bytes, in the
+/// exponent radix.
+#[inline(always)]
+pub fn parse_exponent_digits<'a, Iter, Cb, const FORMAT: u128>(iter: Iter, cb: Cb)
 where
     Iter: DigitsIter<'a>,
     Cb: FnMut(u32),
 {
     let format = NumberFormat::<{ FORMAT }> {};
-    let radix = format.radix();
+    parse_radix_digits::<_, _, FORMAT>(iter, format.exponent_radix(), cb);
+}
+
+/// Iteratively parse and consume digits in a radix from bytes.
+#[inline(always)]
+fn parse_radix_digits<'a, Iter, Cb, const FORMAT: u128>(mut iter: Iter, radix: u32, mut cb: Cb)
+where
+    Iter: DigitsIter<'a>,
+    Cb: FnMut(u32),
+{
     while let Some(&c) = iter.peek() {
-        match char_to_digit_const(c, radix) {
+        match char_to_digit_format::<FORMAT>(c, radix) {
             Some(v) => cb(v),
             None => break,
         }
@@ -886,6 +1457,12 @@ where
     let radix: u64 = format.radix() as u64;
     if can_try_parse_multidigit!(iter, radix) {
         debug_assert!(radix < 16, "radices over 16 will overflow with radix^8");
+        // Try our SIMD, 16-digit at a time optimizations.
+        #[cfg(feature = "simd")]
+        while let Some(v) = simd::try_parse_16digits::<u64, _, FORMAT>(&mut iter) {
+            *mantissa = mantissa.wrapping_mul(simd::CHUNK_POWER).wrapping_add(v);
+        }
+
         let radix8 = format.radix8() as u64;
         // Can do up to 2 iterations without overflowing, however, for large
         // inputs, this is much faster than any other alternative.
@@ -915,6 +1492,16 @@ pub fn parse_u64_digits<'a, Iter, const FORMAT: u128>(
     #[cfg(not(feature = "compact"))]
     if can_try_parse_multidigit!(iter, radix) {
         debug_assert!(radix < 16, "radices over 16 will overflow with radix^8");
+        #[cfg(feature = "simd")]
+        while *step > simd::CHUNK_SIZE {
+            if let Some(v) = simd::try_parse_16digits::<u64, _, FORMAT>(&mut iter) {
+                *mantissa = mantissa.wrapping_mul(simd::CHUNK_POWER).wrapping_add(v);
+                *step -= simd::CHUNK_SIZE;
+            } else {
+                break;
+            }
+        }
+
         let radix8 = format.radix8() as u64;
         while *step > 8 {
             if let Some(v) = algorithm::try_parse_8digits::<u64, _, FORMAT>(&mut iter) {
@@ -0,0 +1,151 @@
+//! Reusable context to parse many floats.
+
+use core::fmt;
+
+use lexical_util::error::Error;
+use lexical_util::format::{is_valid_options_punctuation, NumberFormat, STANDARD};
+use lexical_util::result::Result;
+use lexical_util::trim;
+
+use crate::bigint::BIGINT_LIMBS;
+use crate::options::Options;
+use crate::parse::ParseFloat;
+use crate::slow::Scratch;
+
+const DEFAULT_OPTIONS: Options = Options::new();
+
+/// Reusable context to parse floats.
+///
+/// Floats that cannot be correctly rounded by the fast algorithms use a
+/// slow path with big-integer storage. `Parser` owns this storage, so
+/// parsing many floats in a loop reuses it rather than creating it for
+/// every call. The results are identical to [`FromLexical`] and
+/// [`FromLexicalWithOptions`].
+///
+/// The storage is a fixed-capacity array of `SIZE` limbs for each of two
+/// big integers, and never allocates. The default capacity can correctly
+/// round any float. A smaller capacity reduces the memory used, which
+/// may be useful on targets with small stacks, but parsing a float that
+/// requires the slow path and does not fit fails with
+/// [`Error::TooManyDigits`]. The number of significant digits that fit
+/// depends on the float type, the radix, and the exponent, so test the
+/// inputs you expect when choosing a capacity.
+///
+/// # Examples
+///
+/// ```rust
+/// use lexical_parse_float::Parser;
+///
+/// let mut parser = Parser::new();
+/// let mut sum = 0.0;
+/// for bytes in [&b"1.5"[..], b"2.5", b"9007199254740993"] {
+///     sum += parser.parse::<f64>(bytes).unwrap();
+/// }
+/// assert_eq!(sum, 9007199254740996.0);
+/// assert_eq!(parser.parse_partial::<f32>(b"3.5x"), Ok((3.5, 3)));
+///
+/// // Limit the big integers to 8 limbs each. Floats that do not need the
+/// // slow path are unaffected.
+/// let mut bounded = Parser::<8>::bounded();
+/// assert_eq!(bounded.parse::<f64>(b"1.5"), Ok(1.5));
+/// let halfway = b"1.00000000000000011102230246251565404236316680908203125";
+/// assert_eq!(bounded.parse::<f64>(halfway), Ok(1.0));
+/// let long = [&halfway[..], &[b'0'; 400], b"1"].concat();
+/// assert_eq!(parser.parse::<f64>(&long), Ok(1.0000000000000002));
+/// assert!(bounded.parse::<f64>(&long).unwrap_err().is_too_many_digits());
+/// ```
+///
+/// [`FromLexical`]: crate::FromLexical
+/// [`FromLexicalWithOptions`]: crate::FromLexicalWithOptions
+#[derive(Clone, Default)]
+pub struct Parser<const SIZE: usize = BIGINT_LIMBS> {
+    scratch: Scratch<SIZE>,
+}
+
+impl Parser {
+    /// Create a new parser, with enough capacity to round any float.
+    #[must_use]
+    #[inline(always)]
+    pub const fn new() -> Self {
+        Self::bounded()
+    }
+}
+
+impl<const SIZE: usize> Parser<SIZE> {
+    /// Create a new parser, with a capacity of `SIZE` limbs.
+    #[must_use]
+    #[inline(always)]
+    pub const fn bounded() -> Self {
+        Self {
+            scratch: Scratch::new(),
+        }
+    }
+
+    /// Parse a complete float from bytes, using the standard format.
+    ///
+    /// See [`FromLexical::from_lexical`](crate::FromLexical::from_lexical).
+    #[cfg_attr(not(feature = "compact"), inline)]
+    pub fn parse<F: ParseFloat>(&mut self, bytes: &[u8]) -> Result<F> {
+        F::parse_complete_scratch::<STANDARD, SIZE>(bytes, &DEFAULT_OPTIONS, &mut self.scratch)
+    }
+
+    /// Parse a float from bytes until an invalid digit is found, using the
+    /// standard format.
+    ///
+    /// See [`FromLexical::from_lexical_partial`](crate::FromLexical::from_lexical_partial).
+    #[cfg_attr(not(feature = "compact"), inline)]
+    pub fn parse_partial<F: ParseFloat>(&mut self, bytes: &[u8]) -> Result<(F, usize)> {
+        F::parse_partial_scratch::<STANDARD, SIZE>(bytes, &DEFAULT_OPTIONS, &mut self.scratch)
+    }
+
+    /// Parse a complete float from bytes, using a custom format and options.
+    ///
+    /// See [`FromLexicalWithOptions::from_lexical_with_options`](crate::FromLexicalWithOptions::from_lexical_with_options).
+    #[cfg_attr(not(feature = "compact"), inline)]
+    pub fn parse_with_options<F: ParseFloat, const FORMAT: u128>(
+        &mut self,
+        bytes: &[u8],
+        options: &Options,
+    ) -> Result<F> {
+        check_format::<FORMAT>(options)?;
+        trim::parse_complete::<_, _, FORMAT>(bytes, |bytes| {
+            F::parse_complete_scratch::<FORMAT, SIZE>(bytes, options, &mut self.scratch)
+        })
+    }
+
+    /// Parse a float from bytes until an invalid digit is found, using a
+    /// custom format and options.
+    ///
+    /// See [`FromLexicalWithOptions::from_lexical_partial_with_options`](crate::FromLexicalWithOptions::from_lexical_partial_with_options).
+    #[cfg_attr(not(feature = "compact"), inline)]
+    pub fn parse_partial_with_options<F: ParseFloat, const FORMAT: u128>(
+        &mut self,
+        bytes: &[u8],
+        options: &Options,
+    ) -> Result<(F, usize)> {
+        check_format::<FORMAT>(options)?;
+        trim::parse_partial::<_, _, FORMAT>(bytes, |bytes| {
+            F::parse_partial_scratch::<FORMAT, SIZE>(bytes, options, &mut self.scratch)
+        })
+    }
+}
+
+impl<const SIZE: usize> fmt::Debug for Parser<SIZE> {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Parser").finish_non_exhaustive()
+    }
+}
+
+/// Check the format and options are valid together.
+#[inline(always)]
+fn check_format<const FORMAT: u128>(options: &Options) -> Result<()> {
+    let format = NumberFormat::<{ FORMAT }> {};
+    if !format.is_valid() {
+        Err(format.error())
+    } else if !is_valid_options_punctuation(FORMAT, options.exponent(), options.decimal_point()) {
+        Err(Error::InvalidPunctuation)
+    } else {
+        Ok(())
+    }
+}
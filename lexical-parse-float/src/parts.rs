@@ -0,0 +1,109 @@
+//! Find the components of a number, without parsing a value.
+
+use core::ops::Range;
+
+use lexical_util::error::Error;
+use lexical_util::format::{is_valid_options_punctuation, NumberFormat, STANDARD};
+use lexical_util::result::Result;
+use lexical_util::trim;
+
+use crate::options::Options;
+use crate::parse::parse_parts_complete;
+
+const DEFAULT_OPTIONS: Options = Options::new();
+
+/// Byte ranges of the components of a number.
+///
+/// Each range is relative to the start of the parsed bytes, and may be
+/// used to highlight or replace part of a numeric literal. Digit
+/// separators between the digits of a component are part of its range.
+///
+/// # Examples
+///
+/// ```rust
+/// use lexical_parse_float::parse_parts;
+///
+/// let parts = parse_parts(b"-12.50e+3").unwrap();
+/// assert_eq!(parts.sign, Some(0..1));
+/// assert_eq!(parts.integer, 1..3);
+/// assert_eq!(parts.fraction, Some(4..6));
+/// assert_eq!(parts.exponent, Some(6..9));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct NumberParts {
+    /// The mantissa sign, if present.
+    pub sign: Option<Range<usize>>,
+    /// The integer digits, after any base prefix. This is empty if there
+    /// are no integer digits, like `.5`.
+    pub integer: Range<usize>,
+    /// The fraction digits, if there is a decimal point. This is empty if
+    /// there are no digits after the decimal point, like `1.`, and does not
+    /// include the decimal point.
+    pub fraction: Option<Range<usize>>,
+    /// The exponent, if present, from the exponent character through its
+    /// sign and digits.
+    pub exponent: Option<Range<usize>>,
+}
+
+impl NumberParts {
+    /// Shift all ranges by `offset` bytes.
+    #[inline(always)]
+    const fn shift(self, offset: usize) -> Self {
+        const fn shift_range(range: Range<usize>, offset: usize) -> Range<usize> {
+            range.start + offset..range.end + offset
+        }
+        const fn shift_option(range: Option<Range<usize>>, offset: usize) -> Option<Range<usize>> {
+            match range {
+                Some(range) => Some(shift_range(range, offset)),
+                None => None,
+            }
+        }
+
+        Self {
+            sign: shift_option(self.sign, offset),
+            integer: shift_range(self.integer, offset),
+            fraction: shift_option(self.fraction, offset),
+            exponent: shift_option(self.exponent, offset),
+        }
+    }
+}
+
+/// Find the components of a complete number, using the standard format.
+///
+/// The bytes must be a valid float, like for
+/// [`FromLexical::from_lexical`], except the digits are not rounded to a
+/// float. Special values, like `NaN`, have no components, and return an
+/// error.
+///
+/// [`FromLexical::from_lexical`]: crate::FromLexical::from_lexical
+#[inline]
+pub fn parse_parts(bytes: &[u8]) -> Result<NumberParts> {
+    parse_parts_complete::<STANDARD>(bytes, &DEFAULT_OPTIONS)
+}
+
+/// Find the components of a complete number, using a custom format and
+/// options.
+///
+/// This is identical to [`parse_parts`], except the number is parsed
+/// like [`FromLexicalWithOptions::from_lexical_with_options`]. If the
+/// format skips whitespace around numbers, the ranges are still relative
+/// to the start of `bytes`.
+///
+/// [`FromLexicalWithOptions::from_lexical_with_options`]: crate::FromLexicalWithOptions::from_lexical_with_options
+#[inline]
+pub fn parse_parts_with_options<const FORMAT: u128>(
+    bytes: &[u8],
+    options: &Options,
+) -> Result<NumberParts> {
+    let format = NumberFormat::<{ FORMAT }> {};
+    if !format.is_valid() {
+        return Err(format.error());
+    } else if !is_valid_options_punctuation(FORMAT, options.exponent(), options.decimal_point()) {
+        return Err(Error::InvalidPunctuation);
+    }
+    let start = bytes.as_ptr() as usize;
+    trim::parse_complete::<_, _, FORMAT>(bytes, |trimmed| {
+        let offset = trimmed.as_ptr() as usize - start;
+        parse_parts_complete::<FORMAT>(trimmed, options).map(|parts| parts.shift(offset))
+    })
+}
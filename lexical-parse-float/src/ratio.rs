@@ -0,0 +1,214 @@
+//! Parse fractions and mixed numbers, like `22/7` or `-3 1/2`.
+//!
+//! Fractions are common in measurements and recipes, and cannot be
+//! parsed as decimal floats. The value of the fraction is rounded once,
+//! so it is the nearest float to the exact quotient.
+
+use lexical_util::error::Error;
+use lexical_util::num::AsPrimitive;
+use lexical_util::result::Result;
+
+use crate::float::{extended_to_float, ExtendedFloat80, RawFloat};
+use crate::parse::ParseFloat;
+use crate::shared;
+
+/// Sign, numerator, and denominator of a fraction.
+///
+/// The value is `numerator / denominator`, negated if `is_negative`. Mixed
+/// numbers are converted to improper fractions, and fractions are not
+/// reduced, so `4/8` has a numerator of `4` and a denominator of `8`.
+///
+/// # Examples
+///
+/// ```rust
+/// use lexical_parse_float::{parse_ratio, parse_ratio_parts, Ratio};
+///
+/// let ratio = parse_ratio_parts(b"-3 1/2").unwrap();
+/// assert_eq!(ratio, Ratio {
+///     is_negative: true,
+///     numerator: 7,
+///     denominator: 2,
+/// });
+/// assert_eq!(parse_ratio::<f64>(b"-3 1/2"), Ok(-3.5));
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Ratio {
+    /// If the fraction is negative.
+    pub is_negative: bool,
+    /// The numerator, including the whole part of a mixed number.
+    pub numerator: u64,
+    /// The denominator, which is never zero.
+    pub denominator: u64,
+}
+
+/// Parse the decimal digits starting at `index`, returning the value and
+/// the index after them.
+#[inline(always)]
+fn parse_digits(bytes: &[u8], start: usize) -> Result<(u64, usize)> {
+    let mut value = 0_u64;
+    let mut index = start;
+    while let Some(&c) = bytes.get(index) {
+        if !c.is_ascii_digit() {
+            break;
+        }
+        value = value
+            .checked_mul(10)
+            .and_then(|value| value.checked_add(u64::from(c - b'0')))
+            .ok_or(Error::Overflow(index))?;
+        index += 1;
+    }
+    if index != start {
+        Ok((value, index))
+    } else if index == bytes.len() {
+        Err(Error::Empty(index))
+    } else {
+        Err(Error::InvalidDigit(index))
+    }
+}
+
+/// Parse the denominator starting at `index`, which must end the input.
+#[inline(always)]
+fn parse_denominator(bytes: &[u8], index: usize) -> Result<u64> {
+    let (denominator, end) = parse_digits(bytes, index)?;
+    if end != bytes.len() {
+        Err(Error::InvalidDigit(end))
+    } else if denominator == 0 {
+        Err(Error::ZeroDenominator(index))
+    } else {
+        Ok(denominator)
+    }
+}
+
+/// Parse a complete fraction, mixed number, or integer, without rounding
+/// it to a float.
+///
+/// The bytes must be an optional sign followed by a fraction like `22/7`,
+/// a mixed number like `3 1/2`, with a single space between the whole
+/// number and the fraction, or an integer like `3`, which has a
+/// denominator of `1`. All numbers are decimal, and the numerator and
+/// denominator must fit in a `u64`, including the whole part of a mixed
+/// number, or [`Error::Overflow`] is returned. A zero denominator returns
+/// [`Error::ZeroDenominator`].
+///
+/// # Examples
+///
+/// ```rust
+/// use lexical_parse_float::{parse_ratio_parts, Error, Ratio};
+///
+/// assert_eq!(parse_ratio_parts(b"22/7"), Ok(Ratio {
+///     is_negative: false,
+///     numerator: 22,
+///     denominator: 7,
+/// }));
+/// assert_eq!(parse_ratio_parts(b"1/0"), Err(Error::ZeroDenominator(2)));
+/// assert_eq!(parse_ratio_parts(b"1.5"), Err(Error::InvalidDigit(1)));
+/// ```
+#[inline]
+pub fn parse_ratio_parts(bytes: &[u8]) -> Result<Ratio> {
+    let (is_negative, start) = match bytes.first() {
+        Some(&b'-') => (true, 1),
+        Some(&b'+') => (false, 1),
+        _ => (false, 0),
+    };
+    let (first, index) = parse_digits(bytes, start)?;
+    let (numerator, denominator) = match bytes.get(index) {
+        None => (first, 1),
+        Some(&b'/') => (first, parse_denominator(bytes, index + 1)?),
+        Some(&b' ') => {
+            let (numerator, index) = parse_digits(bytes, index + 1)?;
+            match bytes.get(index) {
+                Some(&b'/') => (),
+                Some(_) => return Err(Error::InvalidDigit(index)),
+                None => return Err(Error::Empty(index)),
+            }
+            let denominator = parse_denominator(bytes, index + 1)?;
+            let numerator = first
+                .checked_mul(denominator)
+                .and_then(|value| value.checked_add(numerator))
+                .ok_or(Error::Overflow(start))?;
+            (numerator, denominator)
+        },
+        Some(_) => return Err(Error::InvalidDigit(index)),
+    };
+
+    Ok(Ratio {
+        is_negative,
+        numerator,
+        denominator,
+    })
+}
+
+/// Parse a complete fraction, mixed number, or integer, and round it to
+/// the nearest float, with ties to even.
+///
+/// The bytes have the same syntax as [`parse_ratio_parts`]. The quotient
+/// is computed exactly before rounding, so `1/3` is the nearest float to
+/// one third, unlike dividing the rounded numerator by the rounded
+/// denominator. Negative fractions with a zero numerator are negative
+/// zero.
+///
+/// # Examples
+///
+/// ```rust
+/// use lexical_parse_float::{parse_ratio, Error};
+///
+/// assert_eq!(parse_ratio::<f64>(b"22/7"), Ok(22.0 / 7.0));
+/// assert_eq!(parse_ratio::<f32>(b"1 3/4"), Ok(1.75));
+/// assert_eq!(parse_ratio::<f64>(b"-5"), Ok(-5.0));
+/// assert_eq!(parse_ratio::<f64>(b"2/"), Err(Error::Empty(2)));
+/// ```
+#[inline]
+pub fn parse_ratio<F: ParseFloat>(bytes: &[u8]) -> Result<F> {
+    let ratio = parse_ratio_parts(bytes)?;
+    let float = ratio_to_float::<F>(ratio.numerator, ratio.denominator);
+    Ok(if ratio.is_negative {
+        -float
+    } else {
+        float
+    })
+}
+
+/// Round the quotient of the numerator and denominator to the nearest
+/// positive float, with ties to even.
+#[allow(clippy::cast_possible_truncation)] // reason = "quotient is shifted to 64 bits"
+#[allow(clippy::cast_possible_wrap)] // reason = "bit lengths are at most 64"
+fn ratio_to_float<F: RawFloat>(numerator: u64, denominator: u64) -> F {
+    debug_assert!(denominator != 0, "denominator must be non-zero");
+    if numerator == 0 {
+        return F::ZERO;
+    }
+
+    // Normalize the numerator to the top bit of a `u128`, and the
+    // denominator to the top bit of a `u64`, so the quotient has 64 or 65
+    // significant bits. The value is `quotient * 2^exp`.
+    let num_shift = numerator.leading_zeros();
+    let den_shift = denominator.leading_zeros();
+    let num = u128::from(numerator) << (64 + num_shift);
+    let den = u128::from(denominator << den_shift);
+    let mut quotient = num / den;
+    let mut is_truncated = num % den != 0;
+    let mut exp = den_shift as i32 - num_shift as i32 - 64;
+    if quotient >> 64 != 0 {
+        is_truncated |= quotient & 1 != 0;
+        quotient >>= 1;
+        exp += 1;
+    }
+
+    // Values below half the smallest denormal float always round to zero.
+    let mut fp = ExtendedFloat80 {
+        mant: quotient as u64,
+        exp: exp + F::EXPONENT_BIAS,
+    };
+    if fp.exp <= -64 {
+        return F::ZERO;
+    }
+
+    // Shift the digits into position and determine if we need to round-up.
+    shared::round::<F, _>(&mut fp, |f, s| {
+        shared::round_nearest_tie_even(f, s, |is_odd, is_halfway, is_above| {
+            is_above || (is_halfway && is_truncated) || (is_odd && is_halfway)
+        });
+    });
+    debug_assert!(fp.mant <= F::MANTISSA_MASK.as_u64(), "mantissa must be rounded");
+    extended_to_float::<F>(fp)
+}
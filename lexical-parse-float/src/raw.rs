@@ -0,0 +1,168 @@
+//! Parse the exact decimal representation of a float, and round it later.
+
+use lexical_util::format::STANDARD;
+use lexical_util::result::Result;
+
+use crate::options::Options;
+use crate::parse::{parse_parts_complete, ParseFloat};
+
+const DEFAULT_OPTIONS: Options = Options::new();
+
+/// Maximum number of bytes to serialize a raw number: a sign, up to 39
+/// mantissa digits, an exponent character, and an `i32` exponent.
+const RAW_BUFFER_SIZE: usize = 1 + 39 + 1 + 11;
+
+/// Decimal representation of a float, before rounding to a binary float.
+///
+/// The value is `mantissa * 10^exponent`, negated if `is_negative`. At least
+/// 38 significant digits fit in the mantissa, and any further digits are
+/// truncated, so the value is only exact if `truncated` is `false`.
+///
+/// # Examples
+///
+/// ```rust
+/// use lexical_parse_float::{assemble, parse_raw, RawNumber};
+///
+/// let raw = parse_raw(b"-1.250e3").unwrap();
+/// assert_eq!(raw, RawNumber {
+///     is_negative: true,
+///     mantissa: 1250,
+///     exponent: 0,
+///     truncated: false,
+/// });
+/// assert_eq!(assemble::<f64>(raw), -1250.0);
+/// assert_eq!(assemble::<f32>(raw), -1250.0);
+/// ```
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct RawNumber {
+    /// If the number is negative.
+    pub is_negative: bool,
+    /// The significant digits, without leading zeros.
+    pub mantissa: u128,
+    /// The decimal exponent, scaled to the mantissa.
+    pub exponent: i32,
+    /// If non-zero significant digits did not fit in the mantissa.
+    pub truncated: bool,
+}
+
+/// Parse the exact decimal representation of a complete float, using the
+/// standard format.
+///
+/// The bytes must be a valid float, like for
+/// [`FromLexical::from_lexical`], except special values, like `NaN`, which
+/// have no decimal representation and return an error. The significant
+/// digits are not rounded, and exponents outside the range of an `i32` are
+/// saturated.
+///
+/// [`FromLexical::from_lexical`]: crate::FromLexical::from_lexical
+#[allow(clippy::cast_possible_truncation)] // reason = "exponent is clamped to i32"
+#[inline]
+pub fn parse_raw(bytes: &[u8]) -> Result<RawNumber> {
+    let parts = parse_parts_complete::<STANDARD>(bytes, &DEFAULT_OPTIONS)?;
+    let is_negative = parts.sign.map_or(false, |sign| bytes[sign.start] == b'-');
+
+    // Digits that do not fit in the mantissa are truncated: integer digits
+    // scale the exponent, and fraction digits are dropped.
+    let mut mantissa = 0_u128;
+    let mut exponent = 0_i64;
+    let mut truncated = false;
+    let mut is_full = false;
+    let mut add_digit = |digit: u8| {
+        let digit = u128::from(digit - b'0');
+        let next = mantissa.checked_mul(10).and_then(|value| value.checked_add(digit));
+        match next {
+            Some(value) if !is_full => {
+                mantissa = value;
+                true
+            },
+            _ => {
+                is_full = true;
+                truncated |= digit != 0;
+                false
+            },
+        }
+    };
+    for &digit in &bytes[parts.integer] {
+        if !add_digit(digit) {
+            exponent += 1;
+        }
+    }
+    for &digit in &bytes[parts.fraction.unwrap_or(0..0)] {
+        if add_digit(digit) {
+            exponent -= 1;
+        }
+    }
+
+    // Add the explicit exponent, after the exponent character.
+    if let Some(range) = parts.exponent {
+        let digits = &bytes[range.start + 1..range.end];
+        let (is_negative_exponent, digits) = match digits {
+            [b'-', rest @ ..] => (true, rest),
+            [b'+', rest @ ..] => (false, rest),
+            _ => (false, digits),
+        };
+        let mut explicit = 0_i64;
+        for &digit in digits {
+            explicit = explicit.saturating_mul(10).saturating_add(i64::from(digit - b'0'));
+        }
+        if is_negative_exponent {
+            explicit = -explicit;
+        }
+        exponent = exponent.saturating_add(explicit);
+    }
+
+    Ok(RawNumber {
+        is_negative,
+        mantissa,
+        exponent: exponent.clamp(i64::from(i32::MIN), i64::from(i32::MAX)) as i32,
+        truncated,
+    })
+}
+
+/// Write the decimal digits of a value, returning the index after them.
+#[inline(always)]
+#[allow(clippy::cast_possible_truncation)] // reason = "a digit is always < 10"
+fn write_digits(buffer: &mut [u8], mut index: usize, mut value: u128) -> usize {
+    let start = index;
+    loop {
+        buffer[index] = b'0' + (value % 10) as u8;
+        index += 1;
+        value /= 10;
+        if value == 0 {
+            break;
+        }
+    }
+    buffer[start..index].reverse();
+    index
+}
+
+/// Round a decimal representation to the nearest float, with ties to even.
+///
+/// For a raw number from [`parse_raw`] that is not truncated, this is
+/// identical to parsing the original bytes. Otherwise, the truncated
+/// decimal value is rounded, which may differ in the last bit. Values that
+/// overflow or underflow round to infinity or zero.
+#[inline]
+pub fn assemble<F: ParseFloat>(raw: RawNumber) -> F {
+    // Serialize the exact decimal value and parse it, so it is rounded by
+    // the same algorithms as the original bytes.
+    let mut buffer = [0u8; RAW_BUFFER_SIZE];
+    let mut index = 0;
+    if raw.is_negative {
+        buffer[index] = b'-';
+        index += 1;
+    }
+    index = write_digits(&mut buffer, index, raw.mantissa);
+    buffer[index] = b'e';
+    index += 1;
+    if raw.exponent < 0 {
+        buffer[index] = b'-';
+        index += 1;
+    }
+    index = write_digits(&mut buffer, index, u128::from(raw.exponent.unsigned_abs()));
+
+    match F::parse_complete::<STANDARD>(&buffer[..index], &DEFAULT_OPTIONS) {
+        Ok(float) => float,
+        Err(_) => unreachable!("a serialized raw number is always a valid float"),
+    }
+}
@@ -0,0 +1,434 @@
+//! Convert numeric strings between radixes using exact arithmetic.
+//!
+//! Converting a string with a fraction from one radix to another by
+//! parsing it to a float and writing the float rounds twice: once when
+//! parsing, and once when writing. [`rebase`] converts the digits directly
+//! using big-integer arithmetic, so the result is the exact value of the
+//! input, correctly rounded to the requested number of fraction digits.
+//! [`rebase_integer`] converts integer strings, and has no limit on the
+//! number of digits if both radixes are powers of two.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use lexical_parse_float::{rebase, rebase_buffer_size};
+//!
+//! let mut buffer = [0u8; 64];
+//! let mut convert = |bytes: &[u8], from, to, fraction_digits| {
+//!     rebase(bytes, from, to, fraction_digits, &mut buffer).map(|x| x.to_vec())
+//! };
+//! assert_eq!(convert(b"3.243F6A", 16, 10, 10), Ok(b"3.1415926218".to_vec()));
+//! assert_eq!(convert(b"-0.1", 10, 2, 8), Ok(b"-0.0001101".to_vec()));
+//! assert_eq!(convert(b"255", 10, 16, 0), Ok(b"FF".to_vec()));
+//! assert!(rebase_buffer_size(8, 16, 10, 10) <= 64);
+//! ```
+
+#![cfg(feature = "radix")]
+
+use core::cmp;
+
+use lexical_util::digit::{
+    char_is_digit_const,
+    char_to_digit_const,
+    char_to_valid_digit_const,
+    digit_to_char_const,
+};
+use lexical_util::error::Error;
+use lexical_util::format::is_valid_radix;
+use lexical_util::result::Result;
+
+use crate::bigint::{compare, small_add, small_mul, Limb, StackVec, Wide, BIGINT_LIMBS};
+use crate::limits::{u32_power_limit, u64_power_limit};
+
+/// Big integer storage for the digits.
+type Storage = StackVec<BIGINT_LIMBS>;
+
+/// Get the number of digits processed at a time, so the radix raised to
+/// the number of digits fits in a limb.
+#[inline(always)]
+const fn limb_step(radix: u32) -> usize {
+    if Limb::BITS == 32 {
+        u32_power_limit(radix) as usize
+    } else {
+        u64_power_limit(radix) as usize
+    }
+}
+
+/// Get the maximum number of integer digits written for `count` digits
+/// in the `from` radix.
+///
+/// The value is less than `2^(count * ceil(log2(from)))`, which has at
+/// most `count * ceil(log2(from)) / floor(log2(to)) + 1` digits in the `to`
+/// radix. Rounding the fraction may carry into a new digit, so add 1.
+#[inline(always)]
+const fn integer_size(count: usize, from: u32, to: u32) -> usize {
+    let bits_per_digit = 32 - from.saturating_sub(1).leading_zeros() as usize;
+    let floor_log2 = if to < 4 {
+        1
+    } else {
+        31 - to.leading_zeros() as usize
+    };
+    count * bits_per_digit / floor_log2 + 2
+}
+
+/// Get the size of the buffer required to convert a string between radixes.
+///
+/// This is an upper bound on the number of bytes written by [`rebase`]
+/// for an input of `len` bytes.
+///
+/// * `len` - The length of the input string.
+/// * `from` - The radix of the input string.
+/// * `to` - The radix of the output string.
+/// * `fraction_digits` - The maximum number of fraction digits to write.
+#[must_use]
+#[inline]
+pub const fn rebase_buffer_size(len: usize, from: u32, to: u32, fraction_digits: usize) -> usize {
+    // Add the sign and the decimal point.
+    integer_size(len, from, to) + fraction_digits + 2
+}
+
+/// Add the digits to `x`, multiplying `scale` by the radix for each digit.
+///
+/// `offset` is the index of the first digit in the input, for errors.
+fn add_digits(
+    x: &mut Storage,
+    mut scale: Option<&mut Storage>,
+    digits: &[u8],
+    radix: u32,
+    offset: usize,
+) -> Result<()> {
+    let step = limb_step(radix);
+    for (chunk_index, chunk) in digits.chunks(step).enumerate() {
+        let index = offset + chunk_index * step;
+        let mut value: Limb = 0;
+        for (digit_index, &c) in chunk.iter().enumerate() {
+            let digit = match char_to_digit_const(c, radix) {
+                Some(digit) => digit,
+                None => return Err(Error::InvalidDigit(index + digit_index)),
+            };
+            value = value * radix as Limb + digit as Limb;
+        }
+        let power = (radix as Limb).pow(chunk.len() as u32);
+        small_mul(x, power).ok_or(Error::TooManyDigits(index))?;
+        small_add(x, value).ok_or(Error::TooManyDigits(index))?;
+        if let Some(scale) = scale.as_mut() {
+            small_mul(scale, power).ok_or(Error::TooManyDigits(index))?;
+        }
+    }
+    Ok(())
+}
+
+/// Divide `x` by a small divisor in place, returning the remainder.
+fn small_divrem(x: &mut Storage, y: Limb) -> Limb {
+    let mut rem: Wide = 0;
+    for xi in x.iter_mut().rev() {
+        let n = (rem << Limb::BITS) | *xi as Wide;
+        *xi = (n / y as Wide) as Limb;
+        rem = n % y as Wide;
+    }
+    x.normalize();
+    rem as Limb
+}
+
+/// Subtract `y` from `x` in place, where `x >= y`.
+fn large_sub(x: &mut Storage, y: &[Limb]) {
+    let mut borrow = false;
+    for (index, xi) in x.iter_mut().enumerate() {
+        let yi = y.get(index).copied().unwrap_or(0);
+        let (value, b1) = xi.overflowing_sub(yi);
+        let (value, b2) = value.overflowing_sub(borrow as Limb);
+        *xi = value;
+        borrow = b1 || b2;
+    }
+    x.normalize();
+}
+
+/// Write the digits of a big integer backwards, ending at `end`, and
+/// return the index of the first digit.
+fn write_integer(x: &mut Storage, radix: u32, buffer: &mut [u8], end: usize) -> usize {
+    // Divide by the largest power of the radix that fits in a limb, and
+    // write the digits of each remainder.
+    let step = limb_step(radix);
+    let power = (radix as Limb).pow(step as u32);
+    let mut index = end;
+    loop {
+        let mut rem = small_divrem(x, power);
+        let is_last = x.is_empty();
+        for _ in 0..step {
+            index -= 1;
+            buffer[index] = digit_to_char_const((rem % radix as Limb) as u32, radix);
+            rem /= radix as Limb;
+            if is_last && rem == 0 {
+                break;
+            }
+        }
+        if is_last {
+            return index;
+        }
+    }
+}
+
+/// Write the digits for two radixes that are powers of two backwards,
+/// ending at `end`, and return the index of the first digit.
+///
+/// This regroups the bits of each digit, without a big integer, so the
+/// number of digits is not limited.
+fn regroup_bits(
+    digits: &[u8],
+    from: u32,
+    to: u32,
+    offset: usize,
+    buffer: &mut [u8],
+    end: usize,
+) -> Result<usize> {
+    if let Some(index) = digits.iter().position(|&c| !char_is_digit_const(c, from)) {
+        return Err(Error::InvalidDigit(offset + index));
+    }
+
+    let from_bits = from.trailing_zeros();
+    let to_bits = to.trailing_zeros();
+    let mut value: u32 = 0;
+    let mut bits = 0;
+    let mut index = end;
+    for &c in digits.iter().rev() {
+        value |= char_to_valid_digit_const(c, from) << bits;
+        bits += from_bits;
+        while bits >= to_bits {
+            index -= 1;
+            buffer[index] = digit_to_char_const(value & (to - 1), to);
+            value >>= to_bits;
+            bits -= to_bits;
+        }
+    }
+    if bits != 0 {
+        index -= 1;
+        buffer[index] = digit_to_char_const(value, to);
+    }
+
+    // Remove the leading zeros, keeping at least one digit.
+    while index + 1 < end && buffer[index] == b'0' {
+        index += 1;
+    }
+    Ok(index)
+}
+
+/// Parse the sign, returning if the number is negative and the index of
+/// the first digit.
+#[inline(always)]
+fn parse_sign(bytes: &[u8]) -> Result<(bool, usize)> {
+    match bytes.first() {
+        Some(&b'-') => Ok((true, 1)),
+        Some(&b'+') => Ok((false, 1)),
+        Some(_) => Ok((false, 0)),
+        None => Err(Error::Empty(0)),
+    }
+}
+
+/// Convert a numeric string from one radix to another.
+///
+/// The input has an optional `+` or `-` sign, followed by integer digits,
+/// and an optional `.` followed by fraction digits. Letters are accepted
+/// in either case. The output has the sign if the result is not zero,
+/// the integer digits, and up to `fraction_digits` fraction digits after
+/// a `.`, using uppercase letters. The fraction is rounded to the nearest
+/// value, with ties to even, and trailing zeros are removed, so the `.`
+/// is only written if the fraction is not zero.
+///
+/// Returns a subslice of the buffer containing the written bytes. Returns
+/// [`Error::InvalidRadix`] if either radix is not valid,
+/// [`Error::InvalidDigit`] if the input contains an invalid digit, and
+/// [`Error::TooManyDigits`] if the input has too many digits to convert.
+///
+/// * `bytes` - The string to convert.
+/// * `from` - The radix of the input string.
+/// * `to` - The radix of the output string.
+/// * `fraction_digits` - The maximum number of fraction digits to write.
+/// * `buffer` - The buffer to write the converted string to.
+///
+/// # Panics
+///
+/// Panics if the buffer is smaller than
+/// [`rebase_buffer_size`] for the length of the input.
+#[inline]
+pub fn rebase<'a>(
+    bytes: &[u8],
+    from: u32,
+    to: u32,
+    fraction_digits: usize,
+    buffer: &'a mut [u8],
+) -> Result<&'a mut [u8]> {
+    if !is_valid_radix(from) || !is_valid_radix(to) {
+        return Err(Error::InvalidRadix);
+    }
+    assert!(
+        buffer.len() >= rebase_buffer_size(bytes.len(), from, to, fraction_digits),
+        "buffer is too small to convert the string"
+    );
+
+    let (is_negative, start) = parse_sign(bytes)?;
+    let digits = &bytes[start..];
+    let (integer, fraction) = match digits.iter().position(|&c| c == b'.') {
+        Some(index) => (&digits[..index], &digits[index + 1..]),
+        None => (digits, &digits[digits.len()..]),
+    };
+    if integer.is_empty() && fraction.is_empty() {
+        return Err(Error::EmptyMantissa(start));
+    }
+
+    // The integer part, and the fraction as `num / den`.
+    let mut int = Storage::new();
+    add_digits(&mut int, None, integer, from, start)?;
+    let mut num = Storage::new();
+    let mut den = Storage::from_u32(1);
+    add_digits(&mut num, Some(&mut den), fraction, from, start + integer.len() + 1)?;
+
+    // Write the fraction digits after the space for the sign, the integer
+    // digits, and the decimal point, and move them into place afterwards.
+    let int_size = integer_size(integer.len(), from, to);
+    let int_end = int_size + 1;
+    let frac_start = int_end + 1;
+    let mut count = 0;
+    while count < fraction_digits && !num.is_empty() {
+        small_mul(&mut num, to as Limb).ok_or(Error::TooManyDigits(bytes.len()))?;
+        let mut digit = 0;
+        while compare(&num, &den) != cmp::Ordering::Less {
+            large_sub(&mut num, &den);
+            digit += 1;
+        }
+        buffer[frac_start + count] = digit_to_char_const(digit, to);
+        count += 1;
+    }
+
+    // Round to the nearest value, with ties to even, by comparing twice the
+    // remainder to the denominator.
+    if !num.is_empty() {
+        small_mul(&mut num, 2).ok_or(Error::TooManyDigits(bytes.len()))?;
+        let round_up = match compare(&num, &den) {
+            cmp::Ordering::Greater => true,
+            cmp::Ordering::Less => false,
+            cmp::Ordering::Equal if count != 0 => {
+                char_to_digit_const(buffer[frac_start + count - 1], to).unwrap_or(0) % 2 == 1
+            },
+            cmp::Ordering::Equal => small_divrem(&mut int.clone(), to as Limb) % 2 == 1,
+        };
+        if round_up {
+            // Carry through the fraction digits, removing any that become
+            // trailing zeros, and then into the integer.
+            let mut carry = true;
+            while carry && count != 0 {
+                let index = frac_start + count - 1;
+                let digit = char_to_digit_const(buffer[index], to).unwrap_or(0) + 1;
+                if digit == to {
+                    count -= 1;
+                } else {
+                    buffer[index] = digit_to_char_const(digit, to);
+                    carry = false;
+                }
+            }
+            if carry {
+                small_add(&mut int, 1).ok_or(Error::TooManyDigits(bytes.len()))?;
+            }
+        }
+    }
+    while count != 0 && buffer[frac_start + count - 1] == b'0' {
+        count -= 1;
+    }
+
+    let is_zero = int.is_empty() && count == 0;
+    let mut index = write_integer(&mut int, to, buffer, int_end);
+    if is_negative && !is_zero {
+        index -= 1;
+        buffer[index] = b'-';
+    }
+
+    let mut len = int_end - index;
+    buffer.copy_within(index..int_end, 0);
+    if count != 0 {
+        buffer[len] = b'.';
+        buffer.copy_within(frac_start..frac_start + count, len + 1);
+        len += count + 1;
+    }
+    Ok(&mut buffer[..len])
+}
+
+/// Convert an integer string from one radix to another.
+///
+/// The input has an optional `+` or `-` sign, followed by the digits.
+/// Letters are accepted in either case. The output has the sign if the
+/// value is not zero, and the digits without leading zeros, using
+/// uppercase letters.
+///
+/// If both radixes are powers of two, such as hexadecimal to base32, the
+/// bits of each digit are regrouped, and the number of digits is not
+/// limited. Otherwise, the digits are converted using a big integer with
+/// the same capacity as the float parser, which fits about 6000 bits, or
+/// 1800 decimal digits, and [`Error::TooManyDigits`] is returned if the
+/// value does not fit.
+///
+/// Returns a subslice of the buffer containing the written bytes. Returns
+/// [`Error::InvalidRadix`] if either radix is not valid, and
+/// [`Error::InvalidDigit`] if the input contains an invalid digit.
+///
+/// * `bytes` - The string to convert.
+/// * `from` - The radix of the input string.
+/// * `to` - The radix of the output string.
+/// * `buffer` - The buffer to write the converted string to.
+///
+/// # Panics
+///
+/// Panics if the buffer is smaller than [`rebase_buffer_size`] for the
+/// length of the input, with no fraction digits.
+///
+/// # Examples
+///
+/// ```rust
+/// use lexical_parse_float::rebase_integer;
+///
+/// let mut buffer = [0u8; 128];
+/// let hex = b"DEADBEEFDEADBEEFDEADBEEFDEADBEEFDEADBEEF";
+/// let result = rebase_integer(hex, 16, 32, &mut buffer).map(|x| x.to_vec());
+/// assert_eq!(result, Ok(b"RQMRTRUULMVEVNLDNRNTTBDUTVFARFNF".to_vec()));
+///
+/// let result = rebase_integer(b"-0000255", 10, 2, &mut buffer).map(|x| x.to_vec());
+/// assert_eq!(result, Ok(b"-11111111".to_vec()));
+/// ```
+#[inline]
+pub fn rebase_integer<'a>(
+    bytes: &[u8],
+    from: u32,
+    to: u32,
+    buffer: &'a mut [u8],
+) -> Result<&'a mut [u8]> {
+    if !is_valid_radix(from) || !is_valid_radix(to) {
+        return Err(Error::InvalidRadix);
+    }
+    assert!(
+        buffer.len() >= rebase_buffer_size(bytes.len(), from, to, 0),
+        "buffer is too small to convert the string"
+    );
+
+    let (is_negative, start) = parse_sign(bytes)?;
+    let digits = &bytes[start..];
+    if digits.is_empty() {
+        return Err(Error::EmptyMantissa(start));
+    }
+
+    // Leave space for the sign at the start of the buffer.
+    let end = integer_size(digits.len(), from, to) + 1;
+    let mut index = if from.is_power_of_two() && to.is_power_of_two() {
+        regroup_bits(digits, from, to, start, buffer, end)?
+    } else {
+        let mut int = Storage::new();
+        add_digits(&mut int, None, digits, from, start)?;
+        write_integer(&mut int, to, buffer, end)
+    };
+    let is_zero = index + 1 == end && buffer[index] == b'0';
+    if is_negative && !is_zero {
+        index -= 1;
+        buffer[index] = b'-';
+    }
+
+    let len = end - index;
+    buffer.copy_within(index..end, 0);
+    Ok(&mut buffer[..len])
+}
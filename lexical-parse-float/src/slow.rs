@@ -19,12 +19,42 @@ use lexical_util::num::{AsPrimitive, Integer};
 
 #[cfg(feature = "radix")]
 use crate::bigint::Bigfloat;
-use crate::bigint::{Bigint, Limb};
+use crate::bigint::{Limb, SizedBigint, BIGINT_LIMBS};
 use crate::float::{extended_to_float, ExtendedFloat80, RawFloat};
 use crate::limits::{u32_power_limit, u64_power_limit};
 use crate::number::Number;
 use crate::shared;
 
+// SCRATCH
+// -------
+
+/// Big-integer storage for the slow path algorithms.
+///
+/// The slow path overwrites these big integers on every use, so the
+/// storage can be reused across parses rather than initialized per call.
+/// Each big integer has a capacity of `SIZE` limbs: the default can
+/// correctly round any float, while a smaller capacity uses less memory
+/// but fails for inputs with too many significant digits.
+#[derive(Clone, Default)]
+pub struct Scratch<const SIZE: usize = BIGINT_LIMBS> {
+    /// Significant digits of the parsed float.
+    pub real: SizedBigint<SIZE>,
+    /// Significant digits of the theoretical halfway representation, `b+h`.
+    pub theor: SizedBigint<SIZE>,
+}
+
+impl<const SIZE: usize> Scratch<SIZE> {
+    /// Create new, empty storage.
+    #[must_use]
+    #[inline(always)]
+    pub const fn new() -> Self {
+        Self {
+            real: SizedBigint::new(),
+            theor: SizedBigint::new(),
+        }
+    }
+}
+
 // ALGORITHM
 // ---------
 
@@ -44,13 +74,17 @@ use crate::shared;
 /// any value before or equal to `16777217.0` must be rounded down
 /// to `16777216.0`. These near-halfway conversions therefore may require
 /// a large number of digits to unambiguously determine how to round.
+///
+/// Returns `None` if the big integers in `scratch` do not have enough
+/// capacity for the significant digits.
 #[must_use]
 #[inline(always)]
 #[allow(clippy::unwrap_used)] // reason = "none is a developer error"
-pub fn slow_radix<F: RawFloat, const FORMAT: u128>(
+pub fn slow_radix<F: RawFloat, const FORMAT: u128, const SIZE: usize>(
     num: Number,
     fp: ExtendedFloat80,
-) -> ExtendedFloat80 {
+    scratch: &mut Scratch<SIZE>,
+) -> Option<ExtendedFloat80> {
     // Ensure our preconditions are valid:
     //  1. The significant digits are not shifted into place.
     debug_assert!(fp.mant & (1 << 63) != 0, "number must be normalized");
@@ -75,10 +109,10 @@ pub fn slow_radix<F: RawFloat, const FORMAT: u128>(
     {
         if let Some(max_digits) = F::max_digits(format.radix()) {
             // Can use our finite number of digit algorithm.
-            digit_comp::<F, FORMAT>(num, fp, sci_exp, max_digits)
+            digit_comp::<F, FORMAT, SIZE>(num, fp, sci_exp, max_digits, scratch)
         } else {
             // Fallback to infinite digits.
-            byte_comp::<F, FORMAT>(num, fp, sci_exp)
+            Some(byte_comp::<F, FORMAT>(num, fp, sci_exp))
         }
     }
 
@@ -86,7 +120,7 @@ pub fn slow_radix<F: RawFloat, const FORMAT: u128>(
     {
         // Can use our finite number of digit algorithm.
         let max_digits = F::max_digits(format.radix()).unwrap();
-        digit_comp::<F, FORMAT>(num, fp, sci_exp, max_digits)
+        digit_comp::<F, FORMAT, SIZE>(num, fp, sci_exp, max_digits, scratch)
     }
 }
 
@@ -96,43 +130,46 @@ pub fn slow_radix<F: RawFloat, const FORMAT: u128>(
 /// is just a multiplication by an exponent power. For a negative
 /// exponent relative to the significant digits, we scale the real
 /// digits to the theoretical digits for `b` and determine if we
-/// need to round-up.
+/// need to round-up. Returns `None` if the big integers in `scratch`
+/// do not have enough capacity.
 #[must_use]
 #[inline(always)]
 #[allow(clippy::cast_possible_wrap)] // reason = "the value range is [-324, 308]"
-pub fn digit_comp<F: RawFloat, const FORMAT: u128>(
+pub fn digit_comp<F: RawFloat, const FORMAT: u128, const SIZE: usize>(
     num: Number,
     fp: ExtendedFloat80,
     sci_exp: i32,
     max_digits: usize,
-) -> ExtendedFloat80 {
-    let (bigmant, digits) = parse_mantissa::<FORMAT>(num, max_digits);
+    scratch: &mut Scratch<SIZE>,
+) -> Option<ExtendedFloat80> {
+    let digits = parse_mantissa::<FORMAT, SIZE>(&mut scratch.real, num, max_digits)?;
     // This can't underflow, since `digits` is at most `max_digits`.
     let exponent = sci_exp + 1 - digits as i32;
     if exponent >= 0 {
-        positive_digit_comp::<F, FORMAT>(bigmant, exponent)
+        positive_digit_comp::<F, FORMAT, SIZE>(&mut scratch.real, exponent)
     } else {
-        negative_digit_comp::<F, FORMAT>(bigmant, fp, exponent)
+        negative_digit_comp::<F, FORMAT, SIZE>(&mut scratch.real, &mut scratch.theor, fp, exponent)
     }
 }
 
 /// Generate the significant digits with a positive exponent relative to
 /// mantissa.
+///
+/// Returns `None` if the big integer does not have enough capacity.
 #[must_use]
-#[allow(clippy::unwrap_used)] // reason = "none is a developer error"
 #[allow(clippy::cast_possible_wrap)] // reason = "can't wrap in practice: max is ~1000 limbs"
 #[allow(clippy::missing_inline_in_public_items)] // reason = "only public for testing"
-pub fn positive_digit_comp<F: RawFloat, const FORMAT: u128>(
-    mut bigmant: Bigint,
+pub fn positive_digit_comp<F: RawFloat, const FORMAT: u128, const SIZE: usize>(
+    bigmant: &mut SizedBigint<SIZE>,
     exponent: i32,
-) -> ExtendedFloat80 {
+) -> Option<ExtendedFloat80> {
     let format = NumberFormat::<{ FORMAT }> {};
 
     // Simple, we just need to multiply by the power of the radix.
     // Now, we can calculate the mantissa and the exponent from this.
     // The binary exponent is the binary exponent for the mantissa
     // shifted to the hidden bit.
-    bigmant.pow(format.radix(), exponent as u32).unwrap();
+    bigmant.pow(format.radix(), exponent as u32)?;
 
     // Get the exact representation of the float from the big integer.
     // hi64 checks **all** the remaining bits after the mantissa,
@@ -150,7 +187,7 @@ pub fn positive_digit_comp<F: RawFloat, const FORMAT: u128>(
             is_above || (is_halfway && is_truncated) || (is_odd && is_halfway)
         });
     });
-    fp
+    Some(fp)
 }
 
 /// Generate the significant digits with a negative exponent relative to
@@ -173,16 +210,17 @@ pub fn positive_digit_comp<F: RawFloat, const FORMAT: u128>(
 /// are all positive.
 ///
 /// This allows us to compare both floats using integers efficiently
-/// without any loss of precision.
+/// without any loss of precision. Returns `None` if the big integers do
+/// not have enough capacity.
 #[allow(clippy::match_bool)] // reason = "simplifies documentation"
-#[allow(clippy::unwrap_used)] // reason = "unwrap panics if a developer error"
 #[allow(clippy::comparison_chain)] // reason = "logically different conditions for algorithm"
 #[allow(clippy::missing_inline_in_public_items)] // reason = "only exposed for unittesting"
-pub fn negative_digit_comp<F: RawFloat, const FORMAT: u128>(
-    bigmant: Bigint,
+pub fn negative_digit_comp<F: RawFloat, const FORMAT: u128, const SIZE: usize>(
+    real_digits: &mut SizedBigint<SIZE>,
+    theor_digits: &mut SizedBigint<SIZE>,
     mut fp: ExtendedFloat80,
     exponent: i32,
-) -> ExtendedFloat80 {
+) -> Option<ExtendedFloat80> {
     // Ensure our preconditions are valid:
     //  1. The significant digits are not shifted into place.
     debug_assert!(fp.mant & (1 << 63) != 0, "the significant digits must be normalized");
@@ -190,8 +228,7 @@ pub fn negative_digit_comp<F: RawFloat, const FORMAT: u128>(
     let format = NumberFormat::<FORMAT> {};
     let radix = format.radix();
 
-    // Get the significant digits and radix exponent for the real digits.
-    let mut real_digits = bigmant;
+    // Get the radix exponent for the real digits.
     let real_exp = exponent;
     debug_assert!(real_exp < 0, "algorithm only works with negative numbers");
 
@@ -202,7 +239,7 @@ pub fn negative_digit_comp<F: RawFloat, const FORMAT: u128>(
 
     // Get the significant digits and the binary exponent for `b+h`.
     let theor = bh(b);
-    let mut theor_digits = Bigint::from_u64(theor.mant);
+    theor_digits.data.set_u64(theor.mant);
     let theor_exp = theor.exp;
 
     // We need to scale the real digits and `b+h` digits to be the same
@@ -227,15 +264,15 @@ pub fn negative_digit_comp<F: RawFloat, const FORMAT: u128>(
     };
 
     if halfradix_exp != 0 {
-        theor_digits.pow(radix / 2, halfradix_exp as u32).unwrap();
+        theor_digits.pow(radix / 2, halfradix_exp as u32)?;
     }
     if radix_exp != 0 {
-        theor_digits.pow(radix, radix_exp as u32).unwrap();
+        theor_digits.pow(radix, radix_exp as u32)?;
     }
     if binary_exp > 0 {
-        theor_digits.pow(2, binary_exp as u32).unwrap();
+        theor_digits.pow(2, binary_exp as u32)?;
     } else if binary_exp < 0 {
-        real_digits.pow(2, (-binary_exp) as u32).unwrap();
+        real_digits.pow(2, (-binary_exp) as u32)?;
     }
 
     // Compare our theoretical and real digits and round nearest, tie even.
@@ -252,7 +289,84 @@ pub fn negative_digit_comp<F: RawFloat, const FORMAT: u128>(
             }
         });
     });
-    fp
+    Some(fp)
+}
+
+/// Compare the significant digits to a float.
+///
+/// Like [`negative_digit_comp`], the digits `m1 * b^N1` and the float
+/// `m2 * 2^N2` are scaled to big integers with only positive exponents,
+/// and then compared. The float must be finite and non-zero, and only the
+/// magnitudes are compared. Returns `None` if the big integers in
+/// `scratch` do not have enough capacity.
+#[must_use]
+#[inline(always)]
+pub fn compare_float<F: RawFloat, const FORMAT: u128, const SIZE: usize>(
+    num: Number,
+    float: F,
+    scratch: &mut Scratch<SIZE>,
+) -> Option<cmp::Ordering> {
+    let format = NumberFormat::<{ FORMAT }> {};
+    let max_digits = F::max_digits(format.radix()).unwrap_or(usize::MAX);
+    compare_extended::<FORMAT, SIZE>(num, b(float), max_digits, scratch)
+}
+
+/// Compare the significant digits to an extended-precision float.
+///
+/// This is [`compare_float`] for any non-zero `m2 * 2^N2`, such as the
+/// halfway point `b+h` from [`bh`]. Digits past `max_digits` are rounded
+/// up to a non-zero digit, so `max_digits` must be enough to represent
+/// the float exactly. Returns `None` if the big integers in `scratch` do
+/// not have enough capacity.
+#[must_use]
+#[allow(clippy::cast_possible_wrap)] // reason = "can't wrap in practice: max is ~1000 limbs"
+#[allow(clippy::missing_inline_in_public_items)] // reason = "only public for testing"
+pub fn compare_extended<const FORMAT: u128, const SIZE: usize>(
+    num: Number,
+    fp: ExtendedFloat80,
+    max_digits: usize,
+    scratch: &mut Scratch<SIZE>,
+) -> Option<cmp::Ordering> {
+    debug_assert!(fp.mant != 0, "the float must be non-zero");
+
+    let format = NumberFormat::<{ FORMAT }> {};
+    let radix = format.radix();
+    let float_bits = fp.exp + (64 - fp.mant.leading_zeros() as i32);
+
+    // The digits are in `[b^N, b^(N+1))` and the float is in
+    // `[2^(B-1), 2^B)`, so quickly order values of a different magnitude
+    // before creating any large powers.
+    let sci_exp = scientific_exponent::<FORMAT>(&num);
+    if sci_exp >= 0 && sci_exp >= float_bits {
+        return Some(cmp::Ordering::Greater);
+    } else if sci_exp < 0 && sci_exp + 2 <= float_bits {
+        return Some(cmp::Ordering::Less);
+    }
+
+    // Digits past `max_digits` are rounded up to a non-zero digit, which
+    // keeps the order since the float does not have that many significant
+    // digits.
+    let digits = parse_mantissa::<FORMAT, SIZE>(&mut scratch.real, num, max_digits)?;
+    let real_exp = sci_exp + 1 - digits as i32;
+    scratch.theor.data.set_u64(fp.mant);
+
+    // Remove a power-of-two from the radix if possible, like for `b+h`.
+    let (radix, radix_exp, binary_exp) = if radix.is_even() {
+        (radix / 2, real_exp, real_exp - fp.exp)
+    } else {
+        (radix, real_exp, -fp.exp)
+    };
+    if radix_exp > 0 {
+        scratch.real.pow(radix, radix_exp as u32)?;
+    } else if radix_exp < 0 {
+        scratch.theor.pow(radix, (-radix_exp) as u32)?;
+    }
+    if binary_exp > 0 {
+        scratch.real.pow(2, binary_exp as u32)?;
+    } else if binary_exp < 0 {
+        scratch.theor.pow(2, (-binary_exp) as u32)?;
+    }
+    Some(scratch.real.data.cmp(&scratch.theor.data))
 }
 
 /// Try to parse 8 digits at a time.
@@ -324,8 +438,8 @@ macro_rules! add_digit {
 macro_rules! add_temporary {
     // Multiply by the small power and add the native value.
     (@mul $result:ident, $power:expr, $value:expr) => {
-        $result.data.mul_small($power).unwrap();
-        $result.data.add_small($value).unwrap();
+        $result.data.mul_small($power)?;
+        $result.data.add_small($value)?;
     };
 
     // Add a temporary where we won't read the counter results internally.
@@ -378,7 +492,7 @@ macro_rules! round_up_nonzero {
                 if value != 0x3030_3030_3030_3030 {
                     // Have non-zero digits, exit early.
                     round_up_truncated!($format, $result, $count);
-                    return ($result, $count);
+                    return Some($count);
                 }
             }
         }
@@ -386,7 +500,7 @@ macro_rules! round_up_nonzero {
         for &digit in iter {
             if digit != b'0' {
                 round_up_truncated!($format, $result, $count);
-                return ($result, $count);
+                return Some($count);
             }
         }
     }};
@@ -394,12 +508,18 @@ macro_rules! round_up_nonzero {
 
 /// Parse the full mantissa into a big integer.
 ///
-/// Returns the parsed mantissa and the number of digits in the mantissa.
-/// The max digits is the maximum number of digits plus one.
+/// Overwrites `result` with the parsed mantissa and returns the number
+/// of digits in the mantissa, or `None` if the big integer does not have
+/// enough capacity. The max digits is the maximum number of digits plus
+/// one.
 #[must_use]
 #[allow(clippy::cognitive_complexity)] // reason = "complexity broken into macros"
 #[allow(clippy::missing_inline_in_public_items)] // reason = "only public for testing"
-pub fn parse_mantissa<const FORMAT: u128>(num: Number, max_digits: usize) -> (Bigint, usize) {
+pub fn parse_mantissa<const FORMAT: u128, const SIZE: usize>(
+    result: &mut SizedBigint<SIZE>,
+    num: Number,
+    max_digits: usize,
+) -> Option<usize> {
     let format = NumberFormat::<FORMAT> {};
     let radix = format.radix();
 
@@ -410,7 +530,7 @@ pub fn parse_mantissa<const FORMAT: u128>(num: Number, max_digits: usize) -> (Bi
     let mut counter: usize = 0;
     let mut count: usize = 0;
     let mut value: Limb = 0;
-    let mut result = Bigint::new();
+    result.data.clear();
 
     // Now use our pre-computed small powers iteratively.
     let step = if Limb::BITS == 32 {
@@ -447,7 +567,7 @@ pub fn parse_mantissa<const FORMAT: u128>(num: Number, max_digits: usize) -> (Bi
                 let mut fraction = fraction.bytes::<FORMAT>();
                 round_up_nonzero!(format, fraction.fraction_iter(), result, count);
             }
-            return (result, count);
+            return Some(count);
         } else {
             // Add our temporary from the loop.
             // SAFETY: safe since `counter <= step`.
@@ -481,7 +601,7 @@ pub fn parse_mantissa<const FORMAT: u128>(num: Number, max_digits: usize) -> (Bi
                 // SAFETY: safe since `counter <= step`.
                 add_temporary!(@end format, result, counter, value);
                 round_up_nonzero!(format, fraction_iter, result, count);
-                return (result, count);
+                return Some(count);
             } else {
                 // Add our temporary from the loop.
                 // SAFETY: safe since `counter <= step`.
@@ -495,7 +615,7 @@ pub fn parse_mantissa<const FORMAT: u128>(num: Number, max_digits: usize) -> (Bi
     // SAFETY: safe since `counter <= step`.
     add_temporary!(@end format, result, counter, value);
 
-    (result, count)
+    Some(count)
 }
 
 /// Compare actual integer digits to the theoretical digits.
@@ -0,0 +1,56 @@
+//! Check if bytes are a valid float, without parsing a value.
+
+use lexical_util::format::{is_valid_options_punctuation, NumberFormat, STANDARD};
+use lexical_util::trim;
+
+use crate::options::Options;
+use crate::parse::validate_complete;
+
+const DEFAULT_OPTIONS: Options = Options::new();
+
+/// Check if bytes are a complete, valid float, using the standard format.
+///
+/// This checks the same grammar as [`FromLexical::from_lexical`], but
+/// skips rounding the digits to a float, which may be slow for floats with
+/// many digits. Since the result does not depend on the float type, floats
+/// that would overflow or underflow are valid.
+///
+/// # Examples
+///
+/// ```rust
+/// use lexical_parse_float::is_float;
+///
+/// assert!(is_float(b"1.5e300"));
+/// assert!(is_float(b"-inf"));
+/// assert!(is_float(b"1e400"));
+/// assert!(!is_float(b"1.5e"));
+/// assert!(!is_float(b""));
+/// ```
+///
+/// [`FromLexical::from_lexical`]: crate::FromLexical::from_lexical
+#[inline]
+pub fn is_float(bytes: &[u8]) -> bool {
+    validate_complete::<STANDARD>(bytes, &DEFAULT_OPTIONS).is_ok()
+}
+
+/// Check if bytes are a complete, valid float, using a custom format and
+/// options.
+///
+/// This checks the same grammar as
+/// [`FromLexicalWithOptions::from_lexical_with_options`], but skips
+/// rounding the digits to a float. Since the result does not depend on the
+/// float type, the [`overflow_mode`](Options::overflow_mode) is ignored.
+/// Returns `false` if the format or options are invalid.
+///
+/// [`FromLexicalWithOptions::from_lexical_with_options`]: crate::FromLexicalWithOptions::from_lexical_with_options
+#[inline]
+pub fn is_float_with_options<const FORMAT: u128>(bytes: &[u8], options: &Options) -> bool {
+    let format = NumberFormat::<{ FORMAT }> {};
+    if !format.is_valid()
+        || !is_valid_options_punctuation(FORMAT, options.exponent(), options.decimal_point())
+    {
+        return false;
+    }
+    trim::parse_complete::<_, _, FORMAT>(bytes, |bytes| validate_complete::<FORMAT>(bytes, options))
+        .is_ok()
+}
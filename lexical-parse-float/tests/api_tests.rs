@@ -1,9 +1,33 @@
 mod util;
 
-#[cfg(feature = "format")]
 use core::num;
 
-use lexical_parse_float::{FromLexical, FromLexicalWithOptions, Options};
+use lexical_parse_float::{
+    assemble,
+    compare_halfway,
+    compare_halfway_with_options,
+    is_exact,
+    is_exact_with_options,
+    is_float,
+    is_float_with_options,
+    parse_bigint,
+    parse_exactness,
+    parse_exactness_with_options,
+    parse_interval,
+    parse_interval_with_options,
+    parse_lossy,
+    parse_partial_lossy,
+    parse_parts,
+    parse_parts_with_options,
+    parse_raw,
+    FromLexical,
+    FromLexicalWithOptions,
+    NumberParts,
+    Options,
+    OverflowMode,
+    Parser,
+    RawNumber,
+};
 #[cfg(feature = "f16")]
 use lexical_util::bf16::bf16;
 use lexical_util::error::Error;
@@ -654,6 +678,55 @@ fn parse_binary_f64_test() {
     assert_eq!(Ok((2.2250738585072014e-308, 170)), parse_binary(b"1111111111111111111111111111111111111111111111111111111111111111111111000000000000000000000000000000000000000000000000000000000000000000000000000000000000000^-10010011011"));
 }
 
+#[test]
+#[cfg(feature = "power-of-two")]
+fn parse_hex_f64_test() {
+    // Hexadecimal digits, with a binary exponent written in decimal.
+    const HEX: u128 = NumberFormatBuilder::new()
+        .mantissa_radix(16)
+        .exponent_base(num::NonZeroU8::new(2))
+        .exponent_radix(num::NonZeroU8::new(10))
+        .build();
+    let options = Options::builder().exponent(b'p').build().unwrap();
+    let parse_hex = |x: &[u8]| f64::from_lexical_with_options::<HEX>(x, &options);
+
+    assert_eq!(parse_hex(b"1p0"), Ok(1.0));
+    assert_eq!(parse_hex(b"1p+1"), Ok(2.0));
+    assert_eq!(parse_hex(b"1.8p3"), Ok(12.0));
+    assert_eq!(parse_hex(b"A.8p10"), Ok(10752.0));
+    assert_eq!(parse_hex(b"-0.8p-1"), Ok(-0.25));
+    assert_eq!(parse_hex(b"10p-4"), Ok(1.0));
+    assert_eq!(parse_hex(b"1p-1074"), Ok(f64::from_bits(1)));
+    assert_eq!(parse_hex(b"1p-1022"), Ok(f64::MIN_POSITIVE));
+    assert_eq!(parse_hex(b"1.fffffffffffffp1023"), Ok(f64::MAX));
+    assert_eq!(parse_hex(b"1p1024"), Ok(f64::INFINITY));
+
+    // Halfway cases, including ones that need the slow path.
+    assert_eq!(parse_hex(b"1.00000000000008p0"), Ok(1.0));
+    assert_eq!(parse_hex(b"1.00000000000018p0"), Ok(1.0000000000000004));
+    assert_eq!(parse_hex(b"1.000000000000080000000000000000001p0"), Ok(1.0000000000000002));
+    assert_eq!(parse_hex(b"1.fffffffffffff8p1023"), Ok(f64::INFINITY));
+
+    // Every normal float, from its exact hexadecimal representation.
+    for bits in [1u64, 0xFFFFFFFFFFFFF, 0x10000000000000, 0x3FB999999999999A, 0x7FEFFFFFFFFFFFFF] {
+        let float = f64::from_bits(bits);
+        let exponent = (bits >> 52) as i32;
+        let mantissa = bits & 0xFFFFFFFFFFFFF;
+        let hex = if exponent == 0 {
+            format!("0.{:013x}p-1022", mantissa)
+        } else {
+            format!("1.{:013x}p{}", mantissa, exponent - 1023)
+        };
+        assert_eq!(parse_hex(hex.as_bytes()), Ok(float));
+    }
+
+    // A binary exponent written in hexadecimal.
+    const HEX_EXP: u128 =
+        NumberFormatBuilder::rebuild(HEX).exponent_radix(num::NonZeroU8::new(16)).build();
+    assert_eq!(f64::from_lexical_with_options::<HEX_EXP>(b"1p10", &options), Ok(65536.0));
+    assert_eq!(f64::from_lexical_with_options::<HEX_EXP>(b"1.8p-a", &options), Ok(0.00146484375));
+}
+
 #[test]
 fn parse_f32_lossy_test() {
     const FORMAT: u128 = STANDARD;
@@ -678,6 +751,304 @@ fn parse_f64_lossy_test() {
     assert_eq!(Ok((1.2345e10, 9)), parse(b"1.2345e10"));
 }
 
+#[test]
+fn parse_f64_overflow_mode_test() {
+    const FORMAT: u128 = STANDARD;
+    let round = Options::new();
+    let saturate = Options::builder().overflow_mode(OverflowMode::Saturate).build().unwrap();
+    let error = Options::builder().overflow_mode(OverflowMode::Error).build().unwrap();
+    let parse = |x, options| f64::from_lexical_with_options::<FORMAT>(x, options);
+    let min_denormal = f64::from_bits(1);
+
+    assert_eq!(parse(b"1e309", &round), Ok(f64::INFINITY));
+    assert_eq!(parse(b"-1e309", &round), Ok(f64::NEG_INFINITY));
+    assert_eq!(parse(b"1e-400", &round), Ok(0.0));
+    assert_eq!(parse(b"1e309", &saturate), Ok(f64::MAX));
+    assert_eq!(parse(b"-1e309", &saturate), Ok(f64::MIN));
+    assert_eq!(parse(b"1e-400", &saturate), Ok(min_denormal));
+    assert_eq!(parse(b"-1e-400", &saturate), Ok(-min_denormal));
+    assert_eq!(parse(b"1e309", &error), Err(Error::Overflow(5)));
+    assert_eq!(parse(b"-1e-400", &error), Err(Error::Underflow(7)));
+
+    // Representable values, zeros, and special values are unchanged.
+    for options in [&round, &saturate, &error] {
+        assert_eq!(parse(b"1.7976931348623157e308", options), Ok(f64::MAX));
+        assert_eq!(parse(b"5e-324", options), Ok(min_denormal));
+        assert_eq!(parse(b"0e-400", options), Ok(0.0));
+        assert_eq!(parse(b"0.000e999", options), Ok(0.0));
+        assert_eq!(parse(b"inf", options), Ok(f64::INFINITY));
+    }
+
+    // The slow path, with more digits than fit in the mantissa.
+    let mut digits = b"0.".to_vec();
+    digits.extend_from_slice(&[b'0'; 400]);
+    digits.extend_from_slice(b"12345678901234567890123");
+    assert_eq!(parse(&digits, &round), Ok(0.0));
+    assert_eq!(parse(&digits, &saturate), Ok(min_denormal));
+    assert_eq!(parse(&digits, &error), Err(Error::Underflow(digits.len())));
+
+    let parse_partial = |x| f64::from_lexical_partial_with_options::<FORMAT>(x, &error);
+    assert_eq!(parse_partial(b"1e400,"), Err(Error::Overflow(5)));
+    assert_eq!(
+        Parser::new().parse_with_options::<f64, FORMAT>(b"1e400", &error),
+        Err(Error::Overflow(5))
+    );
+}
+
+#[test]
+fn parse_f32_overflow_mode_test() {
+    const FORMAT: u128 = STANDARD;
+    let saturate = Options::builder().overflow_mode(OverflowMode::Saturate).build().unwrap();
+    let error = Options::builder().overflow_mode(OverflowMode::Error).build().unwrap();
+    let parse = |x, options| f32::from_lexical_with_options::<FORMAT>(x, options);
+
+    assert_eq!(parse(b"3.5e38", &saturate), Ok(f32::MAX));
+    assert_eq!(parse(b"1e-50", &saturate), Ok(f32::from_bits(1)));
+    assert_eq!(parse(b"3.5e38", &error), Err(Error::Overflow(6)));
+    assert_eq!(parse(b"1e-50", &error), Err(Error::Underflow(5)));
+}
+
+#[test]
+fn parse_ignored_chars_test() {
+    const FORMAT: u128 = STANDARD;
+    let options = Options::builder().ignored_chars(Some("$, \u{a0}".as_bytes())).build().unwrap();
+    let parse = |x: &str| f64::from_lexical_with_options::<FORMAT>(x.as_bytes(), &options);
+
+    assert_eq!(parse("$1,234.5"), Ok(1234.5));
+    assert_eq!(parse("-$ 1 234.5 "), Ok(-1234.5));
+    assert_eq!(parse("1\u{a0}234\u{a0}567.25"), Ok(1234567.25));
+    assert_eq!(parse("1.5e1,0"), Ok(1.5e10));
+    assert_eq!(parse("1.5"), Ok(1.5));
+    assert_eq!(parse("$1,2x3.5"), Err(Error::InvalidDigit(4)));
+    assert_eq!(parse("$ "), Err(Error::Empty(2)));
+
+    let parse_partial = |x: &[u8]| f64::from_lexical_partial_with_options::<FORMAT>(x, &options);
+    assert_eq!(parse_partial(b"$1,234.5 USD"), Ok((1234.5, 9)));
+    assert_eq!(parse_partial(b"1,234.5;"), Ok((1234.5, 7)));
+
+    // Long inputs are only limited by the stack buffer without `std`.
+    let options = Options::builder().ignored_chars(Some(b"_")).build().unwrap();
+    let parse = |x: &str| f64::from_lexical_with_options::<FORMAT>(x.as_bytes(), &options);
+    let mut digits = "0_".repeat(125);
+    digits.push_str("1.5");
+    assert_eq!(parse(&digits), Ok(1.5));
+    let mut digits = "0_".repeat(200);
+    digits.push_str("1.5");
+    #[cfg(feature = "std")]
+    assert_eq!(parse(&digits), Ok(1.5));
+    #[cfg(not(feature = "std"))]
+    assert_eq!(parse(&digits), Err(Error::TooManyDigits(256)));
+
+    // Long inputs round correctly past the stack buffer.
+    #[cfg(feature = "std")]
+    {
+        let options = Options::builder().ignored_chars(Some(b"_")).build().unwrap();
+        let mut digits = String::from("2.4703282292062327208828439643411068618252990130716238221279284125033775363510437593264991818081799618989828234772285886546332835517796989819938739800539093906315035659515570226392290858392449105184435931802849936536152500319370457678249219365623669863658480757001585769269903706311928279558551332927834338409351978015531246597263579574622766465272827220056374006485499977096599470454020828166226237857393450736339007967761930577506740176324673600968951340535537458516661134223766678604162159680461914467291840300530057530849048765391711386591646239524912623653881879636239373280423891018672348497668235089863388587925628302755995657524455507255189313690836254779186948667994968324049705821028513185451396213837722826145437693412532098591327667236328125");
+        digits = digits.replace('0', "_0");
+        let parse = |x: String| f64::from_lexical_with_options::<FORMAT>(x.as_bytes(), &options);
+        assert_eq!(parse(format!("{digits}e-324")), Ok(0.0));
+        assert_eq!(parse(format!("{digits}1e-324")), Ok(5e-324));
+    }
+}
+
+#[test]
+fn parse_percent_test() {
+    const FORMAT: u128 = STANDARD;
+    let options = Options::builder().percent(true).build().unwrap();
+    let parse = |x| f64::from_lexical_with_options::<FORMAT>(x, &options);
+
+    assert_eq!(parse(b"50%"), Ok(0.5));
+    assert_eq!(parse(b"-12.5%"), Ok(-0.125));
+    assert_eq!(parse(b"1e2%"), Ok(1.0));
+    assert_eq!(parse(b"0%"), Ok(0.0));
+    assert_eq!(parse(b"0.5"), Ok(0.5));
+    // The decimal exponent is shifted, so the float is only rounded once.
+    assert_eq!(parse(b"1.1%"), Ok(0.011));
+    assert_eq!(parse(b"0.7%"), Ok(0.007));
+    assert_eq!(parse(b"99.99%"), Ok(0.9999));
+    assert_eq!(parse(b"2.4703282292062328e-322%"), Ok(5e-324));
+    assert_eq!(parse(b"%"), Err(Error::InvalidDigit(0)));
+    assert_eq!(parse(b"5%%"), Err(Error::InvalidDigit(2)));
+    assert_eq!(parse(b"inf%"), Err(Error::InvalidDigit(0)));
+    assert_eq!(f32::from_lexical_with_options::<FORMAT>(b"17.3%", &options), Ok(0.173));
+
+    let parse_partial = |x| f64::from_lexical_partial_with_options::<FORMAT>(x, &options);
+    assert_eq!(parse_partial(b"5% off"), Ok((0.05, 2)));
+    assert_eq!(parse_partial(b"5 %"), Ok((5.0, 1)));
+
+    let default = Options::new();
+    assert_eq!(
+        f64::from_lexical_with_options::<FORMAT>(b"5%", &default),
+        Err(Error::InvalidDigit(1))
+    );
+}
+
+#[test]
+fn parse_flush_subnormals_test() {
+    const FORMAT: u128 = STANDARD;
+    let flush = Options::builder().flush_subnormals(true).build().unwrap();
+    let saturate = Options::builder()
+        .overflow_mode(OverflowMode::Saturate)
+        .flush_subnormals(true)
+        .build()
+        .unwrap();
+    let parse = |x, options| f64::from_lexical_with_options::<FORMAT>(x, options);
+    let is_neg_zero = |x: f64| x == 0.0 && x.is_sign_negative();
+
+    let default = Options::new();
+    assert_eq!(parse(b"5e-324", &default), Ok(f64::from_bits(1)));
+    assert_eq!(parse(b"5e-324", &flush), Ok(0.0));
+    assert_eq!(parse(b"2.2250738585072009e-308", &flush), Ok(0.0));
+    assert_eq!(parse(b"2.2250738585072014e-308", &flush), Ok(f64::MIN_POSITIVE));
+    assert!(is_neg_zero(parse(b"-5e-324", &flush).unwrap()));
+    assert_eq!(parse(b"1e-400", &saturate), Ok(0.0));
+    assert!(is_neg_zero(parse(b"-1e-400", &saturate).unwrap()));
+    assert_eq!(parse(b"1.5", &flush), Ok(1.5));
+
+    let parse_partial = |x| f64::from_lexical_partial_with_options::<FORMAT>(x, &flush);
+    assert_eq!(parse_partial(b"5e-324,"), Ok((0.0, 6)));
+    assert_eq!(f32::from_lexical_with_options::<FORMAT>(b"1e-40", &flush), Ok(0.0));
+}
+
+#[test]
+#[cfg(feature = "format")]
+fn parse_no_subnormals_test() {
+    use lexical_util::format::NumberFormatBuilder;
+
+    const FORMAT: u128 = NumberFormatBuilder::new().no_subnormals(true).build();
+    let options = Options::new();
+    let saturate = Options::builder().overflow_mode(OverflowMode::Saturate).build().unwrap();
+    let parse = |x, options| f64::from_lexical_with_options::<FORMAT>(x, options);
+
+    assert_eq!(parse(b"5e-324", &options), Err(Error::Underflow(6)));
+    assert_eq!(parse(b"-2.2250738585072009e-308", &options), Err(Error::Underflow(24)));
+    assert_eq!(parse(b"2.2250738585072014e-308", &options), Ok(f64::MIN_POSITIVE));
+    assert_eq!(parse(b"0.0", &options), Ok(0.0));
+    assert_eq!(parse(b"1e-400", &options), Ok(0.0));
+    assert_eq!(parse(b"1e-400", &saturate), Err(Error::Underflow(6)));
+    assert_eq!(parse(b"1.5", &options), Ok(1.5));
+
+    // The slow path, with more digits than fit in the mantissa.
+    let mut digits = b"0.".to_vec();
+    digits.extend_from_slice(&[b'0'; 320]);
+    digits.extend_from_slice(b"12345678901234567890123");
+    assert_eq!(parse(&digits, &options), Err(Error::Underflow(digits.len())));
+
+    let parse_partial = |x| f64::from_lexical_partial_with_options::<FORMAT>(x, &options);
+    assert_eq!(parse_partial(b"5e-324,"), Err(Error::Underflow(6)));
+    assert_eq!(
+        f32::from_lexical_with_options::<FORMAT>(b"1e-40", &options),
+        Err(Error::Underflow(5))
+    );
+    assert_eq!(f64::from_lexical_with_options::<STANDARD>(b"5e-324", &options), Ok(5e-324));
+}
+
+#[test]
+fn parse_out_of_range_exponent_test() {
+    const FORMAT: u128 = STANDARD;
+    let error = Options::builder().overflow_mode(OverflowMode::Error).build().unwrap();
+    let parse = |x| f64::from_lexical_with_options::<FORMAT>(x, &error);
+
+    // The boundaries of the cutoffs, compared to the standard library.
+    let mut many_digits = vec![b'1'; 400];
+    many_digits.extend_from_slice(b"e-742");
+    let mut many_zeros = b"0.".to_vec();
+    many_zeros.extend_from_slice(&[b'0'; 400]);
+    many_zeros.extend_from_slice(b"1e400");
+    let cases: [&[u8]; 12] = [
+        b"1e308",
+        b"0.1e309",
+        b"1e309",
+        b"9.9999999999999999999e308",
+        b"18446744073709551615e-342",
+        b"9999999999999999999e-343",
+        b"1e-342",
+        b"1e-343",
+        b"2.4703282292062328e-324",
+        b"2.4703282292062329e-324",
+        &many_digits,
+        &many_zeros,
+    ];
+    for bytes in cases {
+        let expected = core::str::from_utf8(bytes).unwrap().parse::<f64>().unwrap();
+        assert_eq!(f64::from_lexical(bytes), Ok(expected));
+        let expected = core::str::from_utf8(bytes).unwrap().parse::<f32>().unwrap();
+        assert_eq!(f32::from_lexical(bytes), Ok(expected));
+    }
+    let cases: [&[u8]; 6] = [
+        b"1e38",
+        b"1e39",
+        b"18446744073709551615e-65",
+        b"18446744073709551615e-66",
+        b"1e-65",
+        b"1e-66",
+    ];
+    for bytes in cases {
+        let expected = core::str::from_utf8(bytes).unwrap().parse::<f32>().unwrap();
+        assert_eq!(f32::from_lexical(bytes), Ok(expected));
+    }
+
+    // Far outside the range, including with the overflow mode.
+    assert_eq!(f64::from_lexical(b"-1e99999999"), Ok(f64::NEG_INFINITY));
+    assert_eq!(f64::from_lexical(b"1e-99999999"), Ok(0.0));
+    assert_eq!(f32::from_lexical(b"1.5e400"), Ok(f32::INFINITY));
+    assert_eq!(parse(b"1e99999999"), Err(Error::Overflow(10)));
+    assert_eq!(parse(b"-1e-99999999"), Err(Error::Underflow(12)));
+    assert_eq!(parse(b"0e99999999"), Ok(0.0));
+    assert_eq!(parse(&many_zeros), Ok(0.1));
+}
+
+#[test]
+fn parse_max_digits_test() {
+    const FORMAT: u128 = STANDARD;
+    let options = Options::builder()
+        .max_significant_digits(num::NonZeroUsize::new(20))
+        .max_exponent_digits(num::NonZeroUsize::new(3))
+        .build()
+        .unwrap();
+    let parse = |x| f64::from_lexical_with_options::<FORMAT>(x, &options);
+
+    // Leading zeros are not significant, but trailing zeros are.
+    assert_eq!(parse(b"12345678901234567890"), Ok(12345678901234567890.0));
+    assert_eq!(parse(b"1.2345678901234567890"), Ok(1.2345678901234567890));
+    assert_eq!(parse(b"000.00012345678901234567890"), Ok(0.00012345678901234567890));
+    assert_eq!(parse(b"123456789012345678901"), Err(Error::TooManyDigits(0)));
+    assert_eq!(parse(b"-1.00000000000000000000"), Err(Error::TooManyDigits(1)));
+    assert_eq!(parse(b"0.000000000000000000000"), Ok(0.0));
+
+    // Leading zeros in the exponent are not significant either.
+    assert_eq!(parse(b"1e308"), Ok(1e308));
+    assert_eq!(parse(b"1e-0000308"), Ok(1e-308));
+    assert_eq!(parse(b"1e1000"), Err(Error::TooManyDigits(2)));
+    assert_eq!(parse(b"1.5e-99999999"), Err(Error::TooManyDigits(5)));
+    assert_eq!(parse(b"inf"), Ok(f64::INFINITY));
+
+    let mut digits = b"0.".to_vec();
+    digits.extend_from_slice(&[b'1'; 1000]);
+    assert_eq!(parse(&digits), Err(Error::TooManyDigits(0)));
+    assert!(f64::from_lexical(&digits).is_ok());
+    let parse_partial = |x| f64::from_lexical_partial_with_options::<FORMAT>(x, &options);
+    assert_eq!(parse_partial(b"1.5e10,"), Ok((1.5e10, 6)));
+    assert_eq!(parse_partial(b"1.5e1000,"), Err(Error::TooManyDigits(4)));
+}
+
+#[test]
+#[cfg(feature = "f16")]
+fn parse_f16_overflow_mode_test() {
+    // Compare the bits, since the result may be a special value.
+    const FORMAT: u128 = STANDARD;
+    let saturate = Options::builder().overflow_mode(OverflowMode::Saturate).build().unwrap();
+    let error = Options::builder().overflow_mode(OverflowMode::Error).build().unwrap();
+    let parse = |x, options| f16::from_lexical_with_options::<FORMAT>(x, options).map(f16::to_bits);
+
+    assert_eq!(parse(b"1e40", &saturate), Ok(f16::MAX.to_bits()));
+    assert_eq!(parse(b"1e40", &error), Err(Error::Overflow(4)));
+    assert_eq!(parse(b"1e-10", &saturate), Ok(f16::from_bits(1).to_bits()));
+    assert_eq!(parse(b"1e-10", &error), Err(Error::Underflow(5)));
+    let result = bf16::from_lexical_with_options::<FORMAT>(b"-1e-50", &saturate);
+    assert_eq!(result.map(bf16::to_bits), Ok((-bf16::from_bits(1)).to_bits()));
+}
+
 #[test]
 fn f32_lossy_decimal_test() {
     const FORMAT: u128 = STANDARD;
@@ -830,6 +1201,266 @@ fn f64_required_digits_test() {
     assert!(f64::from_lexical_with_options::<FORMAT>(b".0", &options).is_err());
 }
 
+#[test]
+#[cfg(feature = "format")]
+fn f64_required_digits_grammar_test() {
+    let options = Options::new();
+
+    // Strict JSON requires digits on both sides of the decimal point.
+    let parse = |s: &[u8]| f64::from_lexical_with_options::<{ format::JSON }>(s, &options);
+    assert_eq!(parse(b"0.5"), Ok(0.5));
+    assert_eq!(parse(b".5"), Err(Error::EmptyInteger(0)));
+    assert_eq!(parse(b"5."), Err(Error::EmptyFraction(2)));
+    assert_eq!(parse(b"."), Err(Error::EmptyInteger(0)));
+
+    // C accepts digits on either side, but not neither.
+    let parse = |s: &[u8]| f64::from_lexical_with_options::<{ format::C_STRING }>(s, &options);
+    assert_eq!(parse(b".5"), Ok(0.5));
+    assert_eq!(parse(b"5."), Ok(5.0));
+    assert_eq!(parse(b"."), Err(Error::EmptyMantissa(1)));
+
+    // Without required mantissa digits, a bare decimal point is zero.
+    let parse = |s: &[u8]| f64::from_lexical_with_options::<{ format::PERMISSIVE }>(s, &options);
+    assert_eq!(parse(b".5"), Ok(0.5));
+    assert_eq!(parse(b"5."), Ok(5.0));
+    assert_eq!(parse(b"."), Ok(0.0));
+}
+
+#[test]
+#[cfg(feature = "format")]
+fn f64_python_string_test() {
+    // The expected values are from CPython's `float`.
+    let options = Options::new();
+    let parse = |s: &[u8]| f64::from_lexical_with_options::<{ format::PYTHON_STRING }>(s, &options);
+    assert_eq!(parse(b"1.5"), Ok(1.5));
+    assert_eq!(parse(b"  -1.5e+3\n"), Ok(-1500.0));
+    assert_eq!(parse(b"1_000.000_5"), Ok(1000.0005));
+    assert_eq!(parse(b"1e1_0"), Ok(1e10));
+    assert_eq!(parse(b".5"), Ok(0.5));
+    assert_eq!(parse(b"5."), Ok(5.0));
+    assert_eq!(parse(b"007"), Ok(7.0));
+    assert_eq!(parse(b"-Infinity"), Ok(f64::NEG_INFINITY));
+    assert_eq!(parse(b"INF"), Ok(f64::INFINITY));
+    assert!(parse(b"nan").unwrap().is_nan());
+    assert!(parse(b"1__0").is_err());
+    assert!(parse(b"_1").is_err());
+    assert!(parse(b"1_").is_err());
+    assert!(parse(b"1_.5").is_err());
+    assert!(parse(b"1e").is_err());
+    assert!(parse(b".").is_err());
+
+    // Python 3.5 and earlier do not accept underscores.
+    let parse =
+        |s: &[u8]| f64::from_lexical_with_options::<{ format::PYTHON35_STRING }>(s, &options);
+    assert_eq!(parse(b" 1.5 "), Ok(1.5));
+    assert!(parse(b"1_000.5").is_err());
+
+    // `repr` always writes the exponent sign.
+    let parse = |s: &[u8]| f64::from_lexical_with_options::<{ format::PYTHON_REPR }>(s, &options);
+    assert_eq!(parse(b"1e+16"), Ok(1e16));
+    assert_eq!(parse(b"1e-05"), Ok(1e-5));
+    assert!(parse(b"1e16").is_err());
+}
+
+#[test]
+#[cfg(feature = "format")]
+fn f64_go_string_test() {
+    // The expected values are from Go's `strconv.ParseFloat(s, 64)`.
+    let options = Options::new();
+    let parse = |s: &[u8]| f64::from_lexical_with_options::<{ format::GO_STRING }>(s, &options);
+    assert_eq!(parse(b"1.5"), Ok(1.5));
+    assert_eq!(parse(b"+1.5E-3"), Ok(1.5e-3));
+    assert_eq!(parse(b"1_23.50_0_0e+1_2"), Ok(1.235e14));
+    assert_eq!(parse(b".5"), Ok(0.5));
+    assert_eq!(parse(b"5."), Ok(5.0));
+    assert_eq!(parse(b"+Inf"), Ok(f64::INFINITY));
+    assert_eq!(parse(b"-infinity"), Ok(f64::NEG_INFINITY));
+    assert!(parse(b"NaN").unwrap().is_nan());
+    assert!(parse(b" 1.5").is_err());
+    assert!(parse(b"1__23.5").is_err());
+    assert!(parse(b"_123.5").is_err());
+    assert!(parse(b"123_.5").is_err());
+    assert!(parse(b"123.5e+_12").is_err());
+    assert!(parse(b"1e").is_err());
+    assert!(parse(b".").is_err());
+
+    // `FormatFloat` always writes the exponent sign.
+    let parse =
+        |s: &[u8]| f64::from_lexical_with_options::<{ format::GO_FORMAT_FLOAT }>(s, &options);
+    assert_eq!(parse(b"1e+06"), Ok(1e6));
+    assert_eq!(parse(b"1.5e-07"), Ok(1.5e-7));
+    assert!(parse(b"1e6").is_err());
+}
+
+#[test]
+#[cfg(all(feature = "format", feature = "power-of-two"))]
+fn f64_go_hex_string_test() {
+    // The expected values are from Go's `strconv.ParseFloat(s, 64)`.
+    let options = lexical_parse_float::options::HEX_FLOAT;
+    let parse = |s: &[u8]| f64::from_lexical_with_options::<{ format::GO_HEX_STRING }>(s, &options);
+    assert_eq!(parse(b"0x1.8p+1"), Ok(3.0));
+    assert_eq!(parse(b"-0X1P-2"), Ok(-0.25));
+    assert_eq!(parse(b"0x1_0p0"), Ok(16.0));
+    assert_eq!(parse(b"0x.8p1"), Ok(1.0));
+    assert_eq!(parse(b"0x1.fffffffffffffp1023"), Ok(f64::MAX));
+    assert!(parse(b"0x1.8").is_err());
+    assert!(parse(b"0x1__0p0").is_err());
+}
+
+#[test]
+#[cfg(feature = "format")]
+fn f64_java_string_test() {
+    // The expected values are from Java's `Double.parseDouble`.
+    let options = lexical_parse_float::options::JAVA_STRING;
+    let parse = |s: &[u8]| f64::from_lexical_with_options::<{ format::JAVA_STRING }>(s, &options);
+    assert_eq!(parse(b"1.5"), Ok(1.5));
+    assert_eq!(parse(b"  -1.0E-4\n"), Ok(-1e-4));
+    assert_eq!(parse(b".5"), Ok(0.5));
+    assert_eq!(parse(b"5."), Ok(5.0));
+    assert_eq!(parse(b"4.9E-324"), Ok(5e-324));
+    assert_eq!(parse(b"-Infinity"), Ok(f64::NEG_INFINITY));
+    assert!(parse(b"NaN").unwrap().is_nan());
+    assert!(parse(b"infinity").is_err());
+    assert!(parse(b"1_000").is_err());
+    assert!(parse(b"1e").is_err());
+}
+
+#[test]
+#[cfg(all(feature = "format", feature = "power-of-two"))]
+fn f64_java_hex_string_test() {
+    // The expected values are from Java's `Double.parseDouble`.
+    let options = lexical_parse_float::options::HEX_FLOAT;
+    let parse =
+        |s: &[u8]| f64::from_lexical_with_options::<{ format::JAVA_HEX_STRING }>(s, &options);
+    assert_eq!(parse(b"0x1.8p1"), Ok(3.0));
+    assert_eq!(parse(b" -0X1P-2 "), Ok(-0.25));
+    assert!(parse(b"0x1.8").is_err());
+}
+
+#[test]
+#[cfg(feature = "format")]
+fn f64_csharp_string_test() {
+    // The expected values are from C#'s `double.Parse` with the invariant culture.
+    let options = lexical_parse_float::options::CSHARP_STRING;
+    let parse = |s: &[u8]| f64::from_lexical_with_options::<{ format::CSHARP_STRING }>(s, &options);
+    assert_eq!(parse(b"1.5"), Ok(1.5));
+    assert_eq!(parse(b" +1E-05 "), Ok(1e-5));
+    assert_eq!(parse(b"1,234,567.5"), Ok(1234567.5));
+    assert_eq!(parse(b"1,,2,"), Ok(12.0));
+    assert_eq!(parse(b".5"), Ok(0.5));
+    assert_eq!(parse(b"-Infinity"), Ok(f64::NEG_INFINITY));
+    assert!(parse(b"NaN").unwrap().is_nan());
+    assert!(parse(b",1").is_err());
+    assert!(parse(b"1.5,0").is_err());
+    assert!(parse(b"1e").is_err());
+
+    // `ToString("R")` always writes the exponent sign.
+    let parse =
+        |s: &[u8]| f64::from_lexical_with_options::<{ format::CSHARP_ROUND_TRIP }>(s, &options);
+    assert_eq!(parse(b"1E+15"), Ok(1e15));
+    assert_eq!(parse(b"1.5E-07"), Ok(1.5e-7));
+    assert!(parse(b"1E15").is_err());
+}
+
+#[test]
+#[cfg(feature = "format")]
+fn f64_postgresql_string_test() {
+    // The expected values are from PostgreSQL's `float8` input function.
+    let options = Options::new();
+    let parse =
+        |s: &[u8]| f64::from_lexical_with_options::<{ format::POSTGRESQL_STRING }>(s, &options);
+    assert_eq!(parse(b"1.5"), Ok(1.5));
+    assert_eq!(parse(b"  +1.5e3  "), Ok(1500.0));
+    assert_eq!(parse(b".5"), Ok(0.5));
+    assert_eq!(parse(b"-Infinity"), Ok(f64::NEG_INFINITY));
+    assert_eq!(parse(b"+inf"), Ok(f64::INFINITY));
+    assert_eq!(parse(b"INFINITY"), Ok(f64::INFINITY));
+    assert!(parse(b"nan").unwrap().is_nan());
+    assert!(parse(b"1.5abc").is_err());
+    assert!(parse(b"1e").is_err());
+    assert!(parse(b"").is_err());
+}
+
+#[test]
+#[cfg(feature = "format")]
+fn f64_sqlite_string_test() {
+    // The expected values are from SQLite's `CAST(x AS REAL)`.
+    let options = Options::new();
+    let parse =
+        |s: &[u8]| f64::from_lexical_partial_with_options::<{ format::SQLITE_STRING }>(s, &options);
+    assert_eq!(parse(b"1.5"), Ok((1.5, 3)));
+    assert_eq!(parse(b"  +1.5e3  "), Ok((1500.0, 10)));
+    assert_eq!(parse(b"12abc"), Ok((12.0, 2)));
+    assert_eq!(parse(b"1e"), Ok((1.0, 1)));
+    assert_eq!(parse(b"1e+x"), Ok((1.0, 1)));
+    assert_eq!(parse(b"5."), Ok((5.0, 2)));
+    assert_eq!(parse(b"1e999"), Ok((f64::INFINITY, 5)));
+    assert!(parse(b"Infinity").is_err());
+    assert!(parse(b"abc").is_err());
+}
+
+#[test]
+#[cfg(feature = "format")]
+fn f64_toml_test() {
+    let options = lexical_parse_float::options::TOML;
+    let parse = |s: &[u8]| f64::from_lexical_with_options::<{ format::TOML }>(s, &options);
+    assert_eq!(parse(b"+1.0"), Ok(1.0));
+    assert_eq!(parse(b"-0.01"), Ok(-0.01));
+    assert_eq!(parse(b"5e+22"), Ok(5e22));
+    assert_eq!(parse(b"1e06"), Ok(1e6));
+    assert_eq!(parse(b"6.626e-34"), Ok(6.626e-34));
+    assert_eq!(parse(b"224_617.445_991_228"), Ok(224617.445991228));
+    assert_eq!(parse(b"inf"), Ok(f64::INFINITY));
+    assert_eq!(parse(b"-inf"), Ok(f64::NEG_INFINITY));
+    assert!(parse(b"nan").unwrap().is_nan());
+    assert!(parse(b"+nan").unwrap().is_nan());
+    assert!(parse(b".7").is_err());
+    assert!(parse(b"7.").is_err());
+    assert!(parse(b"3.e+20").is_err());
+    assert!(parse(b"01.5").is_err());
+    assert!(parse(b"1__0.0").is_err());
+    assert!(parse(b"Inf").is_err());
+    assert!(parse(b"NaN").is_err());
+}
+
+#[test]
+#[cfg(feature = "format")]
+fn f64_yaml_test() {
+    let options = lexical_parse_float::options::YAML;
+    let parse = |s: &[u8]| f64::from_lexical_with_options::<{ format::YAML }>(s, &options);
+    assert_eq!(parse(b"1.5"), Ok(1.5));
+    assert_eq!(parse(b"+12e03"), Ok(12e3));
+    assert_eq!(parse(b".5"), Ok(0.5));
+    assert_eq!(parse(b"5."), Ok(5.0));
+    assert_eq!(parse(b"01.5"), Ok(1.5));
+    assert_eq!(parse(b".inf"), Ok(f64::INFINITY));
+    assert_eq!(parse(b"-.Inf"), Ok(f64::NEG_INFINITY));
+    assert_eq!(parse(b"+.INF"), Ok(f64::INFINITY));
+    assert!(parse(b".nan").unwrap().is_nan());
+    assert!(parse(b".NaN").unwrap().is_nan());
+    assert!(parse(b"inf").is_err());
+    assert!(parse(b"nan").is_err());
+    assert!(parse(b"1_000.0").is_err());
+    assert!(parse(b".").is_err());
+}
+
+#[test]
+#[cfg(feature = "format")]
+fn f64_json5_test() {
+    let options = lexical_parse_float::options::JSON5;
+    let parse = |s: &[u8]| f64::from_lexical_with_options::<{ format::JSON5 }>(s, &options);
+    assert_eq!(parse(b"+1.5"), Ok(1.5));
+    assert_eq!(parse(b".5"), Ok(0.5));
+    assert_eq!(parse(b"5."), Ok(5.0));
+    assert_eq!(parse(b"-0.5e-3"), Ok(-0.5e-3));
+    assert_eq!(parse(b"Infinity"), Ok(f64::INFINITY));
+    assert_eq!(parse(b"-Infinity"), Ok(f64::NEG_INFINITY));
+    assert!(parse(b"NaN").unwrap().is_nan());
+    assert!(parse(b"01.5").is_err());
+    assert!(parse(b"infinity").is_err());
+    assert!(parse(b"1e").is_err());
+}
+
 #[test]
 #[cfg(feature = "format")]
 fn f64_no_positive_mantissa_sign_test() {
@@ -1216,6 +1847,37 @@ fn base_prefix_and_suffix_test() {
     assert!(f64::from_lexical_with_options::<FORMAT>(b"+0x3.0e+300h ", &options).is_err());
 }
 
+#[test]
+#[cfg(all(feature = "power-of-two", feature = "format"))]
+fn digit_case_test() {
+    const HEX: u128 = NumberFormatBuilder::new()
+        .mantissa_radix(16)
+        .exponent_base(num::NonZeroU8::new(2))
+        .exponent_radix(num::NonZeroU8::new(10))
+        .base_prefix(num::NonZeroU8::new(b'x'))
+        .build();
+    const LOWER: u128 = NumberFormatBuilder::rebuild(HEX)
+        .case_sensitive_base_prefix(true)
+        .no_uppercase_digits(true)
+        .build();
+    const UPPER: u128 = NumberFormatBuilder::rebuild(HEX).no_lowercase_digits(true).build();
+    let options = lexical_parse_float::options::HEX_FLOAT;
+    assert_eq!(f64::from_lexical_with_options::<LOWER>(b"0x1.ap1", &options), Ok(3.25));
+    assert_eq!(
+        f64::from_lexical_with_options::<LOWER>(b"0x1.Ap1", &options),
+        Err(Error::InvalidDigit(4))
+    );
+    assert!(f64::from_lexical_with_options::<LOWER>(b"0X1.ap1", &options).is_err());
+    assert_eq!(f64::from_lexical_partial_with_options::<LOWER>(b"0xaB", &options), Ok((10.0, 3)));
+
+    assert_eq!(f64::from_lexical_with_options::<UPPER>(b"0X1.Ap1", &options), Ok(3.25));
+    assert_eq!(f64::from_lexical_with_options::<UPPER>(b"0x1.Ap1", &options), Ok(3.25));
+    assert_eq!(
+        f64::from_lexical_with_options::<UPPER>(b"0x1.ap1", &options),
+        Err(Error::InvalidDigit(4))
+    );
+}
+
 #[test]
 #[cfg(feature = "format")]
 fn issue66_test() {
@@ -1244,6 +1906,507 @@ fn issue68_test() {
     assert_eq!(f64::INFINITY, f64::from_lexical_with_options::<FORMAT>(hex, &options).unwrap());
 }
 
+#[test]
+#[cfg(feature = "format")]
+fn f64_skip_whitespace_test() {
+    let options = Options::new();
+    const FORMAT: u128 = NumberFormatBuilder::new().skip_whitespace(true).build();
+    assert_eq!(f64::from_lexical_with_options::<FORMAT>(b" \t-1.5e3\n", &options), Ok(-1500.0));
+    assert_eq!(
+        f64::from_lexical_with_options::<FORMAT>(b" 1.5 e3", &options),
+        Err(Error::InvalidDigit(4))
+    );
+    assert_eq!(
+        f64::from_lexical_with_options::<FORMAT>(b"  1.5e", &options),
+        Err(Error::EmptyExponent(6))
+    );
+    assert_eq!(f64::from_lexical_partial_with_options::<FORMAT>(b" 1.5 ,", &options), Ok((1.5, 5)));
+
+    let mut parser = Parser::new();
+    assert_eq!(parser.parse_with_options::<f64, FORMAT>(b" 2.5 ", &options), Ok(2.5));
+    assert_eq!(parser.parse_partial_with_options::<f64, FORMAT>(b" 2.5 ;", &options), Ok((2.5, 5)));
+}
+
+#[test]
+#[cfg(feature = "format")]
+fn f64_unicode_signs_test() {
+    let options = Options::new();
+    const FORMAT: u128 = NumberFormatBuilder::new().unicode_signs(true).build();
+    let parse = |s: &str| f64::from_lexical_with_options::<FORMAT>(s.as_bytes(), &options);
+    assert_eq!(parse("\u{2212}1.5"), Ok(-1.5));
+    assert_eq!(parse("\u{FF0D}1.5e\u{2212}2"), Ok(-0.015));
+    assert_eq!(parse("\u{FF0B}1.5e\u{FF0B}2"), Ok(150.0));
+    assert_eq!(parse("1.5e-2"), Ok(0.015));
+    assert_eq!(parse("\u{2212}inf"), Ok(f64::NEG_INFINITY));
+    assert_eq!(parse("1.5e\u{2212}"), Err(Error::EmptyExponent(7)));
+    assert_eq!(
+        f64::from_lexical_with_options::<STANDARD>("\u{2212}1.5".as_bytes(), &options),
+        Err(Error::InvalidDigit(0))
+    );
+}
+
+#[test]
+#[cfg(feature = "format")]
+fn f64_parenthesized_negatives_test() {
+    let options = Options::new();
+    const FORMAT: u128 = NumberFormatBuilder::new().parenthesized_negatives(true).build();
+    let parse = |s: &[u8]| f64::from_lexical_with_options::<FORMAT>(s, &options);
+    assert_eq!(parse(b"(123.45)"), Ok(-123.45));
+    assert_eq!(parse(b"-123.45"), Ok(-123.45));
+    assert_eq!(parse(b"(1.5e-3)"), Ok(-0.0015));
+    assert_eq!(parse(b"(inf)"), Ok(f64::NEG_INFINITY));
+    assert_eq!(parse(b"(1.5"), Err(Error::InvalidDigit(4)));
+    assert_eq!(parse(b"1.5e(3)"), Err(Error::EmptyExponent(4)));
+    assert_eq!(
+        f64::from_lexical_partial_with_options::<FORMAT>(b"(1.5) EUR", &options),
+        Ok((-1.5, 5))
+    );
+}
+
+#[test]
+#[cfg(feature = "format")]
+fn f64_trailing_empty_exponent_test() {
+    let options = Options::new();
+    const FORMAT: u128 = NumberFormatBuilder::new().trailing_empty_exponent(true).build();
+    let parse = |s: &[u8]| f64::from_lexical_with_options::<FORMAT>(s, &options);
+    let parse_partial = |s: &[u8]| f64::from_lexical_partial_with_options::<FORMAT>(s, &options);
+    assert_eq!(parse_partial(b"1e"), Ok((1.0, 1)));
+    assert_eq!(parse_partial(b"1e+"), Ok((1.0, 1)));
+    assert_eq!(parse_partial(b"-1.5E-x"), Ok((-1.5, 4)));
+    assert_eq!(parse_partial(b"1e5"), Ok((100000.0, 3)));
+    assert_eq!(parse(b"1e-5"), Ok(0.00001));
+    assert_eq!(parse(b"1e"), Err(Error::InvalidDigit(1)));
+    assert_eq!(parse(b"1e+"), Err(Error::InvalidDigit(1)));
+
+    // Empty exponents are errors by default, like JSON.
+    let parse_partial = |s: &[u8]| f64::from_lexical_partial(s);
+    assert_eq!(parse_partial(b"1e"), Err(Error::EmptyExponent(2)));
+    assert_eq!(parse_partial(b"1e+"), Err(Error::EmptyExponent(3)));
+
+    // Or, the exponent character and sign are part of the number.
+    const OPTIONAL: u128 = NumberFormatBuilder::new().required_exponent_digits(false).build();
+    let parse_partial = |s: &[u8]| f64::from_lexical_partial_with_options::<OPTIONAL>(s, &options);
+    assert_eq!(parse_partial(b"1e"), Ok((1.0, 2)));
+    assert_eq!(parse_partial(b"1e+"), Ok((1.0, 3)));
+
+    const REQUIRED: u128 = rebuild(FORMAT).required_exponent_notation(true).build();
+    let parse_partial = |s: &[u8]| f64::from_lexical_partial_with_options::<REQUIRED>(s, &options);
+    assert_eq!(parse_partial(b"1e3"), Ok((1000.0, 3)));
+    assert_eq!(parse_partial(b"1e+"), Err(Error::MissingExponent(1)));
+}
+
+#[test]
+fn f64_exponent_string_test() {
+    let options = Options::builder().exponent_string(Some("\u{d7}10^".as_bytes())).build().unwrap();
+    let parse = |s: &str| f64::from_lexical_with_options::<STANDARD>(s.as_bytes(), &options);
+    assert_eq!(parse("1.23\u{d7}10^4"), Ok(12300.0));
+    assert_eq!(parse("1.23\u{d7}10^-2"), Ok(0.0123));
+    assert_eq!(parse("-5\u{d7}10^+3"), Ok(-5000.0));
+    assert_eq!(parse("1.23e4"), Ok(12300.0));
+    assert_eq!(parse("1.23\u{d7}10^"), Err(Error::EmptyExponent(9)));
+    assert_eq!(parse("1.23\u{d7}10"), Err(Error::InvalidDigit(4)));
+    assert_eq!(
+        f64::from_lexical_partial_with_options::<STANDARD>("2\u{d7}10 apples".as_bytes(), &options),
+        Ok((2.0, 1))
+    );
+
+    let options = Options::builder().exponent_string(Some("\u{b7}10^".as_bytes())).build().unwrap();
+    let parse = |s: &str| f64::from_lexical_with_options::<STANDARD>(s.as_bytes(), &options);
+    assert_eq!(parse("1.5\u{b7}10^3"), Ok(1500.0));
+    assert_eq!(parse("1.5\u{d7}10^3"), Err(Error::InvalidDigit(3)));
+}
+
+#[test]
+fn f64_exponent_characters_test() {
+    let options = Options::builder().exponent_characters(Some(b"^")).build().unwrap();
+    let parse = |s: &[u8]| f64::from_lexical_with_options::<STANDARD>(s, &options);
+    assert_eq!(parse(b"1.5e3"), Ok(1500.0));
+    assert_eq!(parse(b"1.5E3"), Ok(1500.0));
+    assert_eq!(parse(b"1.5^3"), Ok(1500.0));
+    assert_eq!(parse(b"-2^-2"), Ok(-0.02));
+    assert_eq!(parse(b"1.5^"), Err(Error::EmptyExponent(4)));
+    assert_eq!(parse(b"1.5p3"), Err(Error::InvalidDigit(3)));
+    assert_eq!(parse(b"1.5^e3"), Err(Error::EmptyExponent(4)));
+
+    // Only accept `p`, in either case.
+    let options =
+        Options::builder().exponent(b'p').exponent_characters(Some(b"p")).build().unwrap();
+    let parse = |s: &[u8]| f64::from_lexical_with_options::<STANDARD>(s, &options);
+    assert_eq!(parse(b"1.5p3"), Ok(1500.0));
+    assert_eq!(parse(b"1.5P3"), Ok(1500.0));
+    assert_eq!(parse(b"1.5e3"), Err(Error::InvalidDigit(3)));
+
+    // Several characters, with a case-sensitive exponent.
+    #[cfg(feature = "format")]
+    {
+        const FORMAT: u128 = NumberFormatBuilder::new().case_sensitive_exponent(true).build();
+        let options = Options::builder().exponent_characters(Some(b"^D")).build().unwrap();
+        let parse = |s: &[u8]| f64::from_lexical_with_options::<FORMAT>(s, &options);
+        assert_eq!(parse(b"1.5e3"), Ok(1500.0));
+        assert_eq!(parse(b"1.5^3"), Ok(1500.0));
+        assert_eq!(parse(b"1.5D3"), Ok(1500.0));
+        assert_eq!(parse(b"1.5E3"), Err(Error::InvalidDigit(3)));
+        assert_eq!(parse(b"1.5d3"), Err(Error::InvalidDigit(3)));
+    }
+}
+
+#[test]
+fn parser_test() {
+    // Halfway cases, which require the slow path, interleaved with simple floats.
+    let strings: [&[u8]; 6] = [
+        b"9007199254740993",
+        b"1.5",
+        b"9007199254740993.0000000000000000000000000000001",
+        b"1.00000000000000011102230246251565404236316680908203125",
+        b"2.5e-3",
+        b"1.00000000000000011102230246251565404236316680908203125000000001",
+    ];
+    let mut parser = Parser::new();
+    for _ in 0..2 {
+        for &string in strings.iter() {
+            let expected = f64::from_lexical(string);
+            assert_eq!(parser.parse::<f64>(string), expected);
+            assert_eq!(parser.parse::<f32>(string), f32::from_lexical(string));
+            let expected = expected.map(|value| (value, string.len()));
+            assert_eq!(parser.parse_partial::<f64>(string), expected);
+        }
+    }
+    assert_eq!(parser.parse::<f64>(b"9007199254740993"), Ok(9007199254740992.0));
+    assert_eq!(
+        parser.parse::<f64>(b"9007199254740993.0000000000000000000000000000001"),
+        Ok(9007199254740994.0)
+    );
+    assert_eq!(parser.parse_partial::<f64>(b"1.5e"), Err(Error::EmptyExponent(4)));
+
+    let options = Options::builder().decimal_point(b',').build().unwrap();
+    assert_eq!(parser.parse_with_options::<f64, STANDARD>(b"2,5", &options), Ok(2.5));
+    assert_eq!(parser.parse_partial_with_options::<f64, STANDARD>(b"2,5;", &options), Ok((2.5, 3)));
+}
+
+#[test]
+fn parser_bounded_test() {
+    let halfway = b"1.00000000000000011102230246251565404236316680908203125";
+    let long = [&halfway[..], &[b'0'; 400], b"1"].concat();
+    let mut parser = Parser::<8>::bounded();
+    assert_eq!(parser.parse::<f64>(b"1.5"), Ok(1.5));
+    assert_eq!(parser.parse::<f64>(halfway), Ok(1.0));
+    assert_eq!(parser.parse::<f64>(&long), Err(Error::TooManyDigits(long.len())));
+    let partial = [&long[..], b"x"].concat();
+    assert_eq!(parser.parse_partial::<f64>(&partial), Err(Error::TooManyDigits(long.len())));
+
+    // The storage is still usable after an error.
+    assert_eq!(parser.parse::<f64>(halfway), Ok(1.0));
+    assert_eq!(Parser::new().parse::<f64>(&long), Ok(1.0000000000000002));
+}
+
+#[test]
+fn is_float_test() {
+    assert!(is_float(b"1.5"));
+    assert!(is_float(b"-1e-400"));
+    assert!(is_float(b"1e400"));
+    assert!(is_float(b"NaN"));
+    assert!(is_float(b"-Infinity"));
+    assert!(is_float(b"1.00000000000000011102230246251565404236316680908203125"));
+    assert!(!is_float(b""));
+    assert!(!is_float(b"-"));
+    assert!(!is_float(b"1.5e"));
+    assert!(!is_float(b"1.5 "));
+    assert!(!is_float(b"nanx"));
+
+    let options = Options::builder().decimal_point(b',').build().unwrap();
+    assert!(is_float_with_options::<STANDARD>(b"2,5", &options));
+    assert!(!is_float_with_options::<STANDARD>(b"2.5", &options));
+    let options = Options::builder().overflow_mode(OverflowMode::Error).build().unwrap();
+    assert!(is_float_with_options::<STANDARD>(b"1e400", &options));
+}
+
+#[test]
+#[cfg(feature = "format")]
+fn is_float_format_test() {
+    const FORMAT: u128 = format::JSON;
+    let options = Options::new();
+    assert!(is_float_with_options::<FORMAT>(b"1.5", &options));
+    assert!(!is_float_with_options::<FORMAT>(b".5", &options));
+    assert!(!is_float_with_options::<FORMAT>(b"NaN", &options));
+    assert!(is_float_with_options::<{ format::STANDARD | format::SKIP_WHITESPACE }>(
+        b" 1 ", &options
+    ));
+}
+
+#[test]
+fn parse_parts_test() {
+    let parts = |sign, integer, fraction, exponent| NumberParts {
+        sign,
+        integer,
+        fraction,
+        exponent,
+    };
+    assert_eq!(parse_parts(b"1"), Ok(parts(None, 0..1, None, None)));
+    assert_eq!(parse_parts(b"+12.5"), Ok(parts(Some(0..1), 1..3, Some(4..5), None)));
+    assert_eq!(parse_parts(b"-.5e-10"), Ok(parts(Some(0..1), 1..1, Some(2..3), Some(3..7))));
+    assert_eq!(parse_parts(b"1.E3"), Ok(parts(None, 0..1, Some(2..2), Some(2..4))));
+    assert_eq!(parse_parts(b"123e4"), Ok(parts(None, 0..3, None, Some(3..5))));
+    let long = b"1.00000000000000011102230246251565404236316680908203125e1";
+    assert_eq!(parse_parts(long), Ok(parts(None, 0..1, Some(2..55), Some(55..57))));
+    assert_eq!(parse_parts(b""), Err(Error::Empty(0)));
+    assert_eq!(parse_parts(b"1.5e"), Err(Error::EmptyExponent(4)));
+    assert!(parse_parts(b"NaN").is_err());
+
+    let options = Options::builder().exponent_string(Some("×10^".as_bytes())).build().unwrap();
+    let result = parse_parts_with_options::<STANDARD>("1.5×10^3".as_bytes(), &options);
+    assert_eq!(result, Ok(parts(None, 0..1, Some(2..3), Some(3..9))));
+}
+
+#[test]
+#[cfg(feature = "format")]
+fn parse_parts_format_test() {
+    let parts = |sign, integer, fraction, exponent| NumberParts {
+        sign,
+        integer,
+        fraction,
+        exponent,
+    };
+    let options = Options::new();
+    const SKIP: u128 = STANDARD | format::SKIP_WHITESPACE | format::PARENTHESIZED_NEGATIVES;
+    let result = parse_parts_with_options::<SKIP>(b" (1.5) ", &options);
+    assert_eq!(result, Ok(parts(Some(1..2), 2..3, Some(4..5), None)));
+
+    const SEPARATOR: u128 = NumberFormatBuilder::new()
+        .digit_separator(num::NonZeroU8::new(b'_'))
+        .internal_digit_separator(true)
+        .build_strict();
+    let result = parse_parts_with_options::<SEPARATOR>(b"1_000.2_5e1_0", &options);
+    assert_eq!(result, Ok(parts(None, 0..5, Some(6..9), Some(9..13))));
+}
+
+#[test]
+fn parse_raw_test() {
+    let raw = |is_negative, mantissa, exponent, truncated| RawNumber {
+        is_negative,
+        mantissa,
+        exponent,
+        truncated,
+    };
+    assert_eq!(parse_raw(b"0"), Ok(raw(false, 0, 0, false)));
+    assert_eq!(parse_raw(b"-0.0"), Ok(raw(true, 0, -1, false)));
+    assert_eq!(parse_raw(b"+12.50"), Ok(raw(false, 1250, -2, false)));
+    assert_eq!(parse_raw(b"0.001e-5"), Ok(raw(false, 1, -8, false)));
+    assert_eq!(parse_raw(b"1e2147483648"), Ok(raw(false, 1, i32::MAX, false)));
+    assert_eq!(parse_raw(b"1e-9999999999"), Ok(raw(false, 1, i32::MIN, false)));
+    assert_eq!(
+        parse_raw(b"340282366920938463463374607431768211455"),
+        Ok(raw(false, u128::MAX, 0, false))
+    );
+    assert_eq!(
+        parse_raw(b"340282366920938463463374607431768211456"),
+        Ok(raw(false, 34028236692093846346337460743176821145, 1, true))
+    );
+    assert_eq!(
+        parse_raw(b"3402823669209384634633746074317682114560.00"),
+        Ok(raw(false, 34028236692093846346337460743176821145, 2, true))
+    );
+    assert_eq!(
+        parse_raw(b"1.000000000000000000000000000000000000000"),
+        Ok(raw(false, 100000000000000000000000000000000000000, -38, false))
+    );
+    assert_eq!(parse_raw(b"1.5e"), Err(Error::EmptyExponent(4)));
+    assert!(parse_raw(b"inf").is_err());
+
+    assert_eq!(assemble::<f64>(raw(false, 0, 0, false)), 0.0);
+    assert!(assemble::<f64>(raw(true, 0, 0, false)).is_sign_negative());
+    assert_eq!(assemble::<f64>(raw(false, 15, -1, false)), 1.5);
+    assert_eq!(assemble::<f64>(raw(true, 1, i32::MAX, false)), f64::NEG_INFINITY);
+    assert_eq!(assemble::<f64>(raw(false, 1, i32::MIN, false)), 0.0);
+    assert_eq!(assemble::<f32>(raw(false, u128::MAX, 0, false)), f32::INFINITY);
+    let halfway = b"9007199254740993";
+    assert_eq!(assemble::<f64>(parse_raw(halfway).unwrap()), 9007199254740992.0);
+}
+
+#[test]
+fn parse_exactness_test() {
+    assert_eq!(parse_exactness::<f64>(b"0"), Ok((0.0, true)));
+    assert_eq!(parse_exactness::<f64>(b"-0.000e5"), Ok((-0.0, true)));
+    assert_eq!(parse_exactness::<f64>(b"1.5"), Ok((1.5, true)));
+    assert_eq!(parse_exactness::<f64>(b"0.1"), Ok((0.1, false)));
+    assert_eq!(parse_exactness::<f64>(b"1e22"), Ok((1e22, true)));
+    assert_eq!(parse_exactness::<f64>(b"1e23"), Ok((1e23, false)));
+    assert_eq!(parse_exactness::<f64>(b"9007199254740992"), Ok((9007199254740992.0, true)));
+    assert_eq!(parse_exactness::<f64>(b"9007199254740993"), Ok((9007199254740992.0, false)));
+    assert_eq!(parse_exactness::<f64>(b"0.0000152587890625"), Ok((0.0000152587890625, true)));
+    assert_eq!(parse_exactness::<f64>(b"1e400"), Ok((f64::INFINITY, false)));
+    assert_eq!(parse_exactness::<f64>(b"1e-400"), Ok((0.0, false)));
+    assert_eq!(parse_exactness::<f64>(b"inf"), Ok((f64::INFINITY, true)));
+    assert_eq!(parse_exactness::<f32>(b"0.1"), Ok((0.1, false)));
+    assert_eq!(
+        parse_exactness::<f32>(b"340282346638528859811704183484516925440"),
+        Ok((f32::MAX, true))
+    );
+    assert_eq!(parse_exactness::<f64>(b"1.5x"), Err(Error::InvalidDigit(3)));
+
+    // The smallest denormal float has 751 significant digits.
+    let denormal = format!("{:.1074}", f64::from_bits(1));
+    assert_eq!(parse_exactness::<f64>(denormal.as_bytes()), Ok((f64::from_bits(1), true)));
+    let denormal = format!("{}1", denormal);
+    assert_eq!(parse_exactness::<f64>(denormal.as_bytes()), Ok((f64::from_bits(1), false)));
+
+    let options = Options::builder().overflow_mode(OverflowMode::Saturate).build().unwrap();
+    let result = parse_exactness_with_options::<f64, STANDARD>(b"1e400", &options);
+    assert_eq!(result, Ok((f64::MAX, false)));
+    let result = parse_exactness_with_options::<f64, STANDARD>(b"1e-400", &options);
+    assert_eq!(result, Ok((f64::from_bits(1), false)));
+    let max = format!("{:.0}", f64::MAX);
+    let result = parse_exactness_with_options::<f64, STANDARD>(max.as_bytes(), &options);
+    assert_eq!(result, Ok((f64::MAX, true)));
+}
+
+#[test]
+#[cfg(feature = "power-of-two")]
+fn parse_exactness_radix_test() {
+    const BINARY: u128 = NumberFormatBuilder::from_radix(2);
+    let options = Options::new();
+    let result = parse_exactness_with_options::<f64, BINARY>(b"1.1e-1", &options);
+    assert_eq!(result, Ok((0.75, true)));
+    let digits = [&b"1."[..], &[b'0'; 60], b"1"].concat();
+    let result = parse_exactness_with_options::<f64, BINARY>(&digits, &options);
+    assert_eq!(result, Ok((1.0, false)));
+
+    #[cfg(feature = "radix")]
+    {
+        const BASE3: u128 = NumberFormatBuilder::from_radix(3);
+        let result = parse_exactness_with_options::<f64, BASE3>(b"12.0", &options);
+        assert_eq!(result, Ok((5.0, true)));
+        let result = parse_exactness_with_options::<f64, BASE3>(b"0.1", &options);
+        assert_eq!(result, Ok((1.0 / 3.0, false)));
+    }
+}
+
+#[test]
+fn is_exact_test() {
+    assert!(is_exact::<f64>(b"0"));
+    assert!(is_exact::<f64>(b"-1.25e2"));
+    assert!(is_exact::<f64>(b"9007199254740992"));
+    assert!(!is_exact::<f64>(b"9007199254740993"));
+    assert!(is_exact::<f64>(b"9007199254740994"));
+    assert!(!is_exact::<f64>(b"0.1"));
+    assert!(!is_exact::<f64>(b"1e400"));
+    assert!(!is_exact::<f64>(b"1e-400"));
+    assert!(is_exact::<f64>(b"inf"));
+    assert!(!is_exact::<f64>(b""));
+    assert!(!is_exact::<f64>(b"1.5x"));
+    assert!(is_exact::<f32>(b"16777216"));
+    assert!(!is_exact::<f32>(b"16777217"));
+    assert!(is_exact::<f32>(b"0.1000000014901161193847656250"));
+    assert!(is_exact::<f64>(b"0.1000000014901161193847656250"));
+    assert!(!is_exact::<f32>(b"0.1000000014901161193847656251"));
+
+    let options = Options::builder().overflow_mode(OverflowMode::Saturate).build().unwrap();
+    assert!(!is_exact_with_options::<f64, STANDARD>(b"1e400", &options));
+    assert!(is_exact_with_options::<f64, STANDARD>(b"1.5", &options));
+}
+
+#[test]
+fn parse_interval_test() {
+    assert_eq!(parse_interval::<f64>(b"1.5"), Ok((1.5, 1.5)));
+    assert_eq!(parse_interval::<f64>(b"-0"), Ok((-0.0, -0.0)));
+    assert_eq!(parse_interval::<f64>(b"0.1"), Ok((0.09999999999999999, 0.1)));
+    assert_eq!(parse_interval::<f64>(b"0.3"), Ok((0.3, 0.30000000000000004)));
+    assert_eq!(parse_interval::<f64>(b"-0.1"), Ok((-0.1, -0.09999999999999999)));
+    assert_eq!(parse_interval::<f64>(b"-0.3"), Ok((-0.30000000000000004, -0.3)));
+    assert_eq!(parse_interval::<f64>(b"1e23"), Ok((1e23, 1.0000000000000001e23)));
+    assert_eq!(
+        parse_interval::<f64>(b"9007199254740993"),
+        Ok((9007199254740992.0, 9007199254740994.0))
+    );
+    assert_eq!(parse_interval::<f32>(b"16777217"), Ok((16777216.0, 16777218.0)));
+    assert_eq!(parse_interval::<f64>(b"1e400"), Ok((f64::MAX, f64::INFINITY)));
+    assert_eq!(parse_interval::<f64>(b"-1e400"), Ok((f64::NEG_INFINITY, f64::MIN)));
+    assert_eq!(parse_interval::<f64>(b"1e-400"), Ok((0.0, f64::from_bits(1))));
+    assert_eq!(parse_interval::<f64>(b"-1e-400"), Ok((-f64::from_bits(1), -0.0)));
+    assert_eq!(parse_interval::<f64>(b"inf"), Ok((f64::INFINITY, f64::INFINITY)));
+    let (lower, upper) = parse_interval::<f64>(b"NaN").unwrap();
+    assert!(lower.is_nan() && upper.is_nan());
+    assert_eq!(parse_interval::<f64>(b"1.5x"), Err(Error::InvalidDigit(3)));
+
+    let options = Options::builder().overflow_mode(OverflowMode::Saturate).build().unwrap();
+    let result = parse_interval_with_options::<f64, STANDARD>(b"1e400", &options);
+    assert_eq!(result, Ok((f64::MAX, f64::INFINITY)));
+    let result = parse_interval_with_options::<f64, STANDARD>(b"1e-400", &options);
+    assert_eq!(result, Ok((0.0, f64::from_bits(1))));
+}
+
+#[test]
+fn parse_lossy_test() {
+    assert_eq!(parse_lossy::<f64>(b"1.5"), Ok(1.5));
+    assert_eq!(parse_lossy::<f64>(b"-0.1"), Ok(-0.1));
+    assert_eq!(parse_lossy::<f32>(b"1e40"), Ok(f32::INFINITY));
+    assert_eq!(parse_lossy::<f64>(b"9007199254740993"), Ok(9007199254740992.0));
+    assert_eq!(parse_lossy::<f64>(b"1.5x"), Err(Error::InvalidDigit(3)));
+    assert_eq!(parse_partial_lossy::<f64>(b"1.5x"), Ok((1.5, 3)));
+    assert!(parse_lossy::<f64>(b"NaN").unwrap().is_nan());
+
+    // Above halfway, but the lossy algorithm cannot tell.
+    let above = b"9007199254740993.0000000000000000000001";
+    assert_eq!(parse_lossy::<f64>(above), Ok(9007199254740992.0));
+    assert_eq!(f64::from_lexical(above), Ok(9007199254740994.0));
+}
+
+#[test]
+fn compare_halfway_test() {
+    use core::cmp::Ordering;
+
+    let denormal = b"2.4703282292062327208828439643411068618252990130716238221279284125033775363510437593264991818081799618989828234772285886546332835517796989819938739800539093906315035659515570226392290858392449105184435931802849936536152500319370457678249219365623669863658480757001585769269903706311928279558551332927834338409351978015531246597263579574622766465272827220056374006485499977096599470454020828166226237857393450736339007967761930577506740176324673600968951340535537458516661134223766678604162159680461914467291840300530057530849048765391711386591646239524912623653881879636239373280423891018672348497668235089863388587925628302755995657524455507255189313690836254779186948667994968324049705821028513185451396213837722826145437693412532098591327667236328125e-324";
+    assert_eq!(compare_halfway(denormal, 0.0f64), Ok(Ordering::Equal));
+    assert_eq!(compare_halfway(b"2.4703282292062327e-324", 0.0f64), Ok(Ordering::Less));
+    assert_eq!(compare_halfway(b"2.4703282292062328e-324", 0.0f64), Ok(Ordering::Greater));
+    assert_eq!(compare_halfway(b"0", 0.0f64), Ok(Ordering::Less));
+    assert_eq!(compare_halfway(b"-0", -0.0f64), Ok(Ordering::Greater));
+    assert_eq!(compare_halfway(b"1", -1.0f64), Ok(Ordering::Greater));
+    assert_eq!(compare_halfway(b"-1", 1.0f64), Ok(Ordering::Less));
+    assert_eq!(compare_halfway(b"1e400", f64::MAX), Ok(Ordering::Greater));
+    assert_eq!(compare_halfway(b"1e-400", 0.0f64), Ok(Ordering::Less));
+    assert_eq!(compare_halfway(b"16777217", 16777216.0f32), Ok(Ordering::Equal));
+    assert_eq!(compare_halfway(b"16777217e0", 16777218.0f32), Ok(Ordering::Less));
+    assert_eq!(compare_halfway(b"1.5x", 1.5f64), Err(Error::InvalidDigit(3)));
+    assert!(compare_halfway(b"inf", 1.5f64).is_err());
+    assert!(compare_halfway(b"", 1.5f64).is_err());
+
+    let options = Options::builder().decimal_point(b',').build().unwrap();
+    let result = compare_halfway_with_options::<f32, STANDARD>(b"16777217,0", &options, 16777216.0);
+    assert_eq!(result, Ok(Ordering::Equal));
+}
+
+#[test]
+#[should_panic]
+fn compare_halfway_special_test() {
+    _ = compare_halfway(b"1", f64::INFINITY);
+}
+
+#[test]
+fn parse_bigint_test() {
+    let mut limbs = [0u32; 80];
+    assert_eq!(parse_bigint::<f64>(b"1", &mut limbs), Ok((1, 0)));
+    assert_eq!(limbs[0], 1);
+    assert_eq!(parse_bigint::<f64>(b"-0.5", &mut limbs), Ok((1, -1)));
+    assert_eq!(limbs[0], 5);
+    assert_eq!(parse_bigint::<f64>(b"4294967296", &mut limbs), Ok((2, 0)));
+    assert_eq!(limbs[..2], [0, 1]);
+    assert_eq!(parse_bigint::<f64>(b"0e10", &mut limbs), Ok((0, 0)));
+    assert_eq!(parse_bigint::<f64>(b"1.5x", &mut limbs), Err(Error::InvalidDigit(3)));
+    assert!(parse_bigint::<f64>(b"NaN", &mut limbs).is_err());
+
+    // Digits past the maximum are truncated, with a non-zero digit.
+    let long = format!("1{}1", "0".repeat(800));
+    let (count, exponent) = parse_bigint::<f64>(long.as_bytes(), &mut limbs).unwrap();
+    assert_eq!(exponent, 801 - 769);
+    assert!(count <= 80);
+    assert_eq!(limbs[0] % 10, 1);
+    let (_, exponent) = parse_bigint::<f32>(long.as_bytes(), &mut limbs).unwrap();
+    assert_eq!(exponent, 801 - 114);
+    assert!(parse_bigint::<f64>(long.as_bytes(), &mut limbs[..10])
+        .unwrap_err()
+        .is_too_many_digits());
+}
+
 fn float_equal<F: Float>(x: F, y: F) -> bool {
     if x.is_nan() {
         y.is_nan()
@@ -1341,6 +2504,60 @@ default_quickcheck! {
 proptest! {
     #![proptest_config(default_proptest_config())]
 
+    #[test]
+    fn f64_parse_raw_proptest(i in r"[+-]?[0-9]{1,40}\.[0-9]{0,40}(e[+-]?[0-9]{1,3})?") {
+        let raw = parse_raw(i.as_bytes()).unwrap();
+        if !raw.truncated {
+            prop_assert_eq!(assemble::<f64>(raw), f64::from_lexical(i.as_bytes()).unwrap());
+        }
+    }
+
+    #[test]
+    fn f64_parse_exactness_proptest(i in f64::MIN_POSITIVE..f64::MAX) {
+        let exact = format!("{:.1100}", i);
+        prop_assert_eq!(parse_exactness::<f64>(exact.as_bytes()), Ok((i, true)));
+        let inexact = format!("{}1", exact);
+        prop_assert_eq!(parse_exactness::<f64>(inexact.as_bytes()), Ok((i, false)));
+    }
+
+    #[test]
+    fn f64_parse_interval_proptest(i in f64::MIN_POSITIVE..f64::MAX) {
+        let exact = format!("{:.1100}", i);
+        prop_assert_eq!(parse_interval::<f64>(exact.as_bytes()), Ok((i, i)));
+        let above = format!("{}1", exact);
+        let next = f64::from_bits(i.to_bits() + 1);
+        prop_assert_eq!(parse_interval::<f64>(above.as_bytes()), Ok((i, next)));
+        let below = format!("-{}", above);
+        prop_assert_eq!(parse_interval::<f64>(below.as_bytes()), Ok((-next, -i)));
+    }
+
+    #[test]
+    fn f64_parse_lossy_proptest(i in r"[0-9]{1,30}\.[0-9]{0,40}(e[+-]?[0-9]{1,3})?") {
+        let lossy = parse_lossy::<f64>(i.as_bytes()).unwrap();
+        let expected = f64::from_lexical(i.as_bytes()).unwrap();
+        prop_assert!(lossy.to_bits().abs_diff(expected.to_bits()) <= 1);
+    }
+
+    #[test]
+    fn f32_compare_halfway_proptest(i in 0f32..f32::MAX) {
+        use core::cmp::Ordering;
+
+        let next = f32::from_bits(i.to_bits() + 1);
+        let halfway = format!("{:.200}", (i as f64 + next as f64) / 2.0);
+        prop_assert_eq!(compare_halfway(halfway.as_bytes(), i), Ok(Ordering::Equal));
+        let above = format!("{}1", halfway);
+        prop_assert_eq!(compare_halfway(above.as_bytes(), i), Ok(Ordering::Greater));
+        let below = format!("-{}", above);
+        prop_assert_eq!(compare_halfway(below.as_bytes(), -i), Ok(Ordering::Less));
+        let exact = format!("{:.200}", i as f64);
+        prop_assert_eq!(compare_halfway(exact.as_bytes(), i), Ok(Ordering::Less));
+    }
+
+    #[test]
+    fn f64_is_float_proptest(i in r"[+-]?[0-9]{0,3}\.?[0-9]{0,3}(e[+-]?[0-9]{0,3})?[a-z]?") {
+        prop_assert_eq!(is_float(i.as_bytes()), f64::from_lexical(i.as_bytes()).is_ok());
+    }
+
     #[test]
     fn f32_invalid_proptest(i in r"[+-]?[0-9]{2}[^\deE]?\.[^\deE]?[0-9]{2}[^\deE]?e[+-]?[0-9]+[^\deE]") {
         let res = f32::from_lexical(i.as_bytes());
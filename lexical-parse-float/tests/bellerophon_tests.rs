@@ -86,13 +86,7 @@ fn halfway_round_up_test() {
 
     // Add a 0 but say we're truncated.
     bellerophon_test::<f64, { STANDARD }>(9007199254740994000, -3, true, 1, 1076);
-    bellerophon_test::<f64, { STANDARD }>(
-        9007199254740994990,
-        -3,
-        true,
-        9223372036854778869,
-        1065 + INVALID_FP,
-    );
+    bellerophon_test::<f64, { STANDARD }>(9007199254740994990, -3, true, 1, 1076);
     bellerophon_test::<f64, { STANDARD }>(
         9007199254740995000,
         -3,
@@ -100,13 +94,7 @@ fn halfway_round_up_test() {
         9223372036854778879,
         1065 + INVALID_FP,
     );
-    bellerophon_test::<f64, { STANDARD }>(
-        9007199254740995010,
-        -3,
-        true,
-        9223372036854778890,
-        1065 + INVALID_FP,
-    );
+    bellerophon_test::<f64, { STANDARD }>(9007199254740995010, -3, true, 2, 1076);
     bellerophon_test::<f64, { STANDARD }>(9007199254740995050, -3, true, 2, 1076);
     bellerophon_test::<f64, { STANDARD }>(9007199254740996000, -3, true, 2, 1076);
 }
@@ -118,13 +106,7 @@ fn extremes_test() {
     bellerophon_test::<f64, { STANDARD }>(5, -324, false, 1, 0);
     bellerophon_test::<f64, { STANDARD }>(10, -324, false, 2, 0);
     // This is very close to 2.4703282292062327206e-342.
-    bellerophon_test::<f64, { STANDARD }>(
-        2470328229206232720,
-        -342,
-        false,
-        18446744073709551608,
-        -64 + INVALID_FP,
-    );
+    bellerophon_test::<f64, { STANDARD }>(2470328229206232720, -342, false, 0, 0);
     bellerophon_test::<f64, { STANDARD }>(
         2470328229206232721,
         -342,
@@ -132,13 +114,7 @@ fn extremes_test() {
         9223372036854775808,
         -32831,
     );
-    bellerophon_test::<f64, { STANDARD }>(
-        2470328229206232725,
-        -342,
-        false,
-        9223372036854775824,
-        -32831,
-    );
+    bellerophon_test::<f64, { STANDARD }>(2470328229206232725, -342, false, 1, 0);
     bellerophon_test::<f64, { STANDARD }>(2470328229206232726, -342, false, 1, 0);
     bellerophon_test::<f64, { STANDARD }>(2470328229206232730, -342, false, 1, 0);
     // Check very close to literal infinity.
@@ -149,20 +125,8 @@ fn extremes_test() {
     bellerophon_test::<f64, { STANDARD }>(17976931348623156, 292, false, 4503599627370494, 2046);
     bellerophon_test::<f64, { STANDARD }>(1797693134862315605, 290, false, 4503599627370494, 2046);
     bellerophon_test::<f64, { STANDARD }>(1797693134862315607, 290, false, 4503599627370494, 2046);
-    bellerophon_test::<f64, { STANDARD }>(
-        1797693134862315608,
-        290,
-        false,
-        18446744073709548540,
-        -30733,
-    );
-    bellerophon_test::<f64, { STANDARD }>(
-        1797693134862315609,
-        290,
-        false,
-        18446744073709548550,
-        -30733,
-    );
+    bellerophon_test::<f64, { STANDARD }>(1797693134862315608, 290, false, 4503599627370494, 2046);
+    bellerophon_test::<f64, { STANDARD }>(1797693134862315609, 290, false, 4503599627370495, 2046);
     bellerophon_test::<f64, { STANDARD }>(179769313486231561, 291, false, 4503599627370495, 2046);
     bellerophon_test::<f64, { STANDARD }>(17976931348623157, 292, false, 4503599627370495, 2046);
 
@@ -190,13 +154,7 @@ fn extremes_test() {
     bellerophon_test::<f64, { STANDARD }>(222507385850720062, -325, false, 4503599627370494, 0);
     bellerophon_test::<f64, { STANDARD }>(222507385850720063, -325, false, 4503599627370494, 0);
     bellerophon_test::<f64, { STANDARD }>(222507385850720064, -325, false, 4503599627370494, 0);
-    bellerophon_test::<f64, { STANDARD }>(
-        2225073858507200641,
-        -326,
-        false,
-        18446744073709545462,
-        -32779,
-    );
+    bellerophon_test::<f64, { STANDARD }>(2225073858507200641, -326, false, 4503599627370494, 0);
     bellerophon_test::<f64, { STANDARD }>(
         2225073858507200642,
         -326,
@@ -0,0 +1,74 @@
+use lexical_parse_float::{format, parse_complex, parse_complex_with_options, Error, Options};
+
+#[test]
+fn parse_complex_test() {
+    assert_eq!(parse_complex::<f64>(b"3+4i"), Ok((3.0, 4.0)));
+    assert_eq!(parse_complex::<f64>(b"3-4j"), Ok((3.0, -4.0)));
+    assert_eq!(parse_complex::<f64>(b"-3+4i"), Ok((-3.0, 4.0)));
+    assert_eq!(parse_complex::<f64>(b"1.5e3-2.5e-1j"), Ok((1500.0, -0.25)));
+    assert_eq!(parse_complex::<f64>(b"1e+3+1e-3i"), Ok((1000.0, 0.001)));
+    assert_eq!(parse_complex::<f32>(b".5+.25i"), Ok((0.5, 0.25)));
+
+    // Missing parts are zero.
+    assert_eq!(parse_complex::<f64>(b"3"), Ok((3.0, 0.0)));
+    assert_eq!(parse_complex::<f64>(b"-2.5"), Ok((-2.5, 0.0)));
+    assert_eq!(parse_complex::<f64>(b"4i"), Ok((0.0, 4.0)));
+    assert_eq!(parse_complex::<f64>(b"-4.5e1j"), Ok((0.0, -45.0)));
+
+    // Imaginary units without digits are 1.
+    assert_eq!(parse_complex::<f64>(b"i"), Ok((0.0, 1.0)));
+    assert_eq!(parse_complex::<f64>(b"+j"), Ok((0.0, 1.0)));
+    assert_eq!(parse_complex::<f64>(b"-i"), Ok((0.0, -1.0)));
+    assert_eq!(parse_complex::<f64>(b"3+i"), Ok((3.0, 1.0)));
+    assert_eq!(parse_complex::<f64>(b"3-j"), Ok((3.0, -1.0)));
+
+    // Special values.
+    assert_eq!(parse_complex::<f64>(b"inf"), Ok((f64::INFINITY, 0.0)));
+    assert_eq!(parse_complex::<f64>(b"1+infi"), Ok((1.0, f64::INFINITY)));
+    assert_eq!(parse_complex::<f64>(b"-inf-infj"), Ok((f64::NEG_INFINITY, f64::NEG_INFINITY)));
+    let (re, im) = parse_complex::<f64>(b"NaN+NaNi").unwrap();
+    assert!(re.is_nan() && im.is_nan());
+}
+
+#[test]
+fn parse_complex_error_test() {
+    assert_eq!(parse_complex::<f64>(b""), Err(Error::Empty(0)));
+    assert_eq!(parse_complex::<f64>(b"3+"), Err(Error::InvalidDigit(1)));
+    assert_eq!(parse_complex::<f64>(b"3+4"), Err(Error::InvalidDigit(1)));
+    assert_eq!(parse_complex::<f64>(b"3*4i"), Err(Error::InvalidDigit(1)));
+    assert_eq!(parse_complex::<f64>(b"3+4ii"), Err(Error::InvalidDigit(3)));
+    assert_eq!(parse_complex::<f64>(b"3+4.5.6i"), Err(Error::InvalidDigit(5)));
+    assert_eq!(parse_complex::<f64>(b"3 + 4i"), Err(Error::InvalidDigit(1)));
+    assert_eq!(parse_complex::<f64>(b"4k"), Err(Error::InvalidDigit(1)));
+    assert_eq!(parse_complex::<f64>(b"3++i"), Err(Error::InvalidDigit(2)));
+}
+
+#[test]
+fn parse_complex_with_options_test() {
+    const FORMAT: u128 = format::STANDARD;
+    let options = Options::builder().imaginary_units(b"IJ").build().unwrap();
+    let parse = |x| parse_complex_with_options::<f64, FORMAT>(x, &options);
+    assert_eq!(parse(b"3+4I"), Ok((3.0, 4.0)));
+    assert_eq!(parse(b"3-4J"), Ok((3.0, -4.0)));
+    assert_eq!(parse(b"-J"), Ok((0.0, -1.0)));
+    assert_eq!(parse(b"3+4i"), Err(Error::InvalidDigit(1)));
+
+    let options = Options::builder().decimal_point(b',').build().unwrap();
+    let parse = |x| parse_complex_with_options::<f64, FORMAT>(x, &options);
+    assert_eq!(parse(b"1,5-2,5j"), Ok((1.5, -2.5)));
+
+    let options = Options::builder().percent(true).build().unwrap();
+    let parse = |x| parse_complex_with_options::<f64, FORMAT>(x, &options);
+    assert_eq!(parse(b"50%+25%i"), Ok((0.5, 0.25)));
+}
+
+#[test]
+#[cfg(feature = "format")]
+fn parse_complex_format_test() {
+    const FORMAT: u128 = format::NumberFormatBuilder::new().required_exponent_sign(true).build();
+    let options = Options::new();
+    let parse = |x| parse_complex_with_options::<f64, FORMAT>(x, &options);
+    assert_eq!(parse(b"1e+2-1e-2i"), Ok((100.0, -0.01)));
+    assert_eq!(parse(b"1e2-1e-2i"), Err(Error::MissingExponentSign(2)));
+    assert_eq!(parse(b"1e+2-1e2i"), Err(Error::MissingExponentSign(7)));
+}
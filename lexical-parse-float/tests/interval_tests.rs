@@ -0,0 +1,61 @@
+use lexical_parse_float::float::ExtendedFloat80;
+use lexical_parse_float::Interval;
+
+const fn fp(mant: u64, exp: i32) -> ExtendedFloat80 {
+    ExtendedFloat80 {
+        mant,
+        exp,
+    }
+}
+
+#[test]
+fn approximate_test() {
+    assert_eq!(Interval::approximate(fp(5, 0)), Interval(fp(4, 0), fp(6, 0)));
+    assert_eq!(
+        Interval::approximate(fp(u64::MAX, 0)),
+        Interval(fp(u64::MAX - 1, 0), fp(1 << 63, 1))
+    );
+}
+
+#[test]
+fn add_test() {
+    // Exact values are normalized.
+    let x = Interval::exact(fp(1, 0));
+    let y = Interval::exact(fp(1, 1));
+    assert_eq!(x.add(&y), Interval::exact(fp(3 << 62, -62)));
+
+    // Carry to the next bit.
+    let x = Interval::exact(fp(u64::MAX, 0));
+    assert_eq!(x.add(&x), Interval::exact(fp(u64::MAX, 1)));
+    let y = Interval::exact(fp(1, 0));
+    assert_eq!(x.add(&y), Interval::exact(fp(1 << 63, 1)));
+
+    // Truncated bits round the upper bound up.
+    let x = Interval::exact(fp(1 << 63, 0));
+    let y = Interval::exact(fp(1, -1));
+    assert_eq!(x.add(&y), Interval(fp(1 << 63, 0), fp((1 << 63) + 1, 0)));
+    let y = Interval::exact(fp(1, -200));
+    assert_eq!(x.add(&y), Interval(fp(1 << 63, 0), fp((1 << 63) + 1, 0)));
+
+    // Adding zero.
+    let zero = Interval::exact(fp(0, 0));
+    assert_eq!(zero.add(&Interval::exact(fp(1, 0))), Interval::exact(fp(1 << 63, -63)));
+}
+
+#[test]
+fn mul_test() {
+    let x = Interval::exact(fp(3, 0));
+    assert_eq!(x.mul(&x), Interval::exact(fp(9 << 60, -60)));
+
+    let x = Interval::exact(fp(u64::MAX, 0));
+    let y = Interval::exact(fp(3, 0));
+    let product = x.mul(&y);
+    assert_eq!(product.lower(), fp(0xBFFF_FFFF_FFFF_FFFF, 2));
+    assert_eq!(product.upper(), fp(0xC000_0000_0000_0000, 2));
+
+    // The bounds contain the real product.
+    let x = Interval(fp(u64::MAX - 1, -64), fp(u64::MAX, -64));
+    let product = x.mul(&x);
+    assert_eq!(product.lower(), fp(u64::MAX - 3, -64));
+    assert_eq!(product.upper(), fp(u64::MAX, -64));
+}
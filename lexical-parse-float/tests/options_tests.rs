@@ -1,4 +1,8 @@
-use lexical_parse_float::options::{Options, OptionsBuilder};
+#![allow(clippy::disallowed_macros)]
+
+use core::num;
+
+use lexical_parse_float::options::{Options, OptionsBuilder, OverflowMode};
 
 #[test]
 fn invalid_exponent_test() {
@@ -13,6 +17,46 @@ fn invalid_exponent_test() {
     assert!(builder.build().is_ok());
 }
 
+#[test]
+fn invalid_exponent_string_test() {
+    let mut builder = OptionsBuilder::default();
+    builder = builder.exponent_string(Some(b""));
+    assert!(!builder.is_valid());
+    builder = builder.exponent_string(Some(b"10^"));
+    assert!(!builder.is_valid());
+    builder = builder.exponent_string(Some(b"-10^"));
+    assert!(!builder.is_valid());
+    builder = builder.exponent_string(Some(b".10^"));
+    assert!(!builder.is_valid());
+    assert!(builder.build().is_err());
+    builder = builder.exponent_string(Some("\u{d7}10^".as_bytes()));
+    assert!(builder.is_valid());
+    assert!(builder.build().is_ok());
+    builder = builder.exponent_string(None);
+    assert!(builder.is_valid());
+}
+
+#[test]
+fn invalid_exponent_characters_test() {
+    let mut builder = OptionsBuilder::default();
+    builder = builder.exponent_characters(Some(b""));
+    assert!(!builder.is_valid());
+    builder = builder.exponent_characters(Some(b"^1"));
+    assert!(!builder.is_valid());
+    builder = builder.exponent_characters(Some(b"^+"));
+    assert!(!builder.is_valid());
+    builder = builder.exponent_characters(Some(b"^."));
+    assert!(!builder.is_valid());
+    builder = builder.exponent_characters(Some(b"^\x00"));
+    assert!(!builder.is_valid());
+    assert!(builder.build().is_err());
+    builder = builder.exponent_characters(Some(b"^p"));
+    assert!(builder.is_valid());
+    assert!(builder.build().is_ok());
+    builder = builder.exponent_characters(None);
+    assert!(builder.is_valid());
+}
+
 #[test]
 fn invalid_decimal_point_test() {
     let mut builder = OptionsBuilder::default();
@@ -36,6 +80,13 @@ fn invalid_nan_test() {
     builder = builder.nan_string(Some(b"na00n"));
     assert!(!builder.is_valid());
     assert!(builder.build().is_err());
+    builder = builder.nan_string(Some(b"."));
+    assert!(!builder.is_valid());
+    builder = builder.nan_string(Some(b"..nan"));
+    assert!(!builder.is_valid());
+    builder = builder.nan_string(Some(b".nan"));
+    assert!(builder.is_valid());
+    assert!(builder.build().is_ok());
     builder = builder.nan_string(Some(b"nan"));
     assert!(builder.is_valid());
     assert!(builder.build().is_ok());
@@ -53,6 +104,13 @@ fn invalid_inf_test() {
     builder = builder.inf_string(Some(b"in00f"));
     assert!(!builder.is_valid());
     assert!(builder.build().is_err());
+    builder = builder.inf_string(Some(b"."));
+    assert!(!builder.is_valid());
+    builder = builder.inf_string(Some(b"..inf"));
+    assert!(!builder.is_valid());
+    builder = builder.inf_string(Some(b".inf"));
+    assert!(builder.is_valid());
+    assert!(builder.build().is_ok());
     builder = builder.inf_string(Some(b"i"));
     assert!(builder.is_valid());
     builder = builder.inf_string(Some(b"inf"));
@@ -88,6 +146,52 @@ fn invalid_infinity_test() {
     assert!(builder.is_valid());
 }
 
+#[test]
+fn invalid_ignored_chars_test() {
+    let mut builder = OptionsBuilder::default();
+    builder = builder.ignored_chars(Some(b""));
+    assert!(!builder.is_valid());
+    builder = builder.ignored_chars(Some(b"$1"));
+    assert!(!builder.is_valid());
+    builder = builder.ignored_chars(Some(b"$+"));
+    assert!(!builder.is_valid());
+    builder = builder.ignored_chars(Some(b"$."));
+    assert!(!builder.is_valid());
+    builder = builder.ignored_chars(Some(b"$n"));
+    assert!(!builder.is_valid());
+    assert!(builder.build().is_err());
+    builder = builder.ignored_chars(Some(b"$^")).exponent(b'^');
+    assert!(!builder.is_valid());
+    builder = builder.ignored_chars(Some(b"$,")).decimal_point(b',');
+    assert!(!builder.is_valid());
+    builder = builder.ignored_chars(Some(b"$ ")).decimal_point(b'.');
+    assert!(builder.is_valid());
+    assert!(builder.build().is_ok());
+    builder = builder.ignored_chars(None);
+    assert!(builder.is_valid());
+}
+
+#[test]
+fn invalid_imaginary_units_test() {
+    let mut builder = OptionsBuilder::default();
+    builder = builder.imaginary_units(b"");
+    assert!(!builder.is_valid());
+    builder = builder.imaginary_units(b"i1");
+    assert!(!builder.is_valid());
+    builder = builder.imaginary_units(b"i-");
+    assert!(!builder.is_valid());
+    builder = builder.imaginary_units(b"i ");
+    assert!(!builder.is_valid());
+    builder = builder.imaginary_units(b"ie");
+    assert!(!builder.is_valid());
+    assert!(builder.build().is_err());
+    builder = builder.imaginary_units(b"i,").decimal_point(b',');
+    assert!(!builder.is_valid());
+    builder = builder.imaginary_units(b"iIjJ").decimal_point(b'.');
+    assert!(builder.is_valid());
+    assert!(builder.build().is_ok());
+}
+
 #[test]
 fn builder_test() {
     let mut builder = OptionsBuilder::default();
@@ -97,14 +201,32 @@ fn builder_test() {
     builder = builder.decimal_point(b',');
     builder = builder.nan_string(Some(b"nan"));
     builder = builder.inf_string(Some(b"Infinity"));
+    builder = builder.exponent_string(Some(b"*10^"));
     builder = builder.infinity_string(Some(b"Infiniiiiiity"));
+    builder = builder.overflow_mode(OverflowMode::Saturate);
+    builder = builder.max_significant_digits(num::NonZeroUsize::new(100));
+    builder = builder.max_exponent_digits(num::NonZeroUsize::new(4));
+    builder = builder.exponent_characters(Some(b"pq"));
+    builder = builder.flush_subnormals(true);
+    builder = builder.ignored_chars(Some(b"$ "));
+    builder = builder.percent(true);
+    builder = builder.imaginary_units(b"j");
 
     assert_eq!(builder.get_lossy(), true);
     assert_eq!(builder.get_exponent(), b'^');
     assert_eq!(builder.get_decimal_point(), b',');
     assert_eq!(builder.get_nan_string(), Some("nan".as_bytes()));
     assert_eq!(builder.get_inf_string(), Some("Infinity".as_bytes()));
+    assert_eq!(builder.get_exponent_string(), Some("*10^".as_bytes()));
     assert_eq!(builder.get_infinity_string(), Some("Infiniiiiiity".as_bytes()));
+    assert_eq!(builder.get_overflow_mode(), OverflowMode::Saturate);
+    assert_eq!(builder.get_max_significant_digits(), num::NonZeroUsize::new(100));
+    assert_eq!(builder.get_max_exponent_digits(), num::NonZeroUsize::new(4));
+    assert_eq!(builder.get_exponent_characters(), Some("pq".as_bytes()));
+    assert!(builder.get_flush_subnormals());
+    assert_eq!(builder.get_ignored_chars(), Some("$ ".as_bytes()));
+    assert!(builder.get_percent());
+    assert_eq!(builder.get_imaginary_units(), b"j");
 
     assert!(builder.is_valid());
     assert_eq!(builder.build(), Ok(builder.build_unchecked()));
@@ -119,16 +241,42 @@ fn options_test() {
     opts.set_decimal_point(b',');
     opts.set_nan_string(Some(b"nan"));
     opts.set_inf_string(Some(b"Infinity"));
+    opts.set_exponent_string(Some(b"*10^"));
     opts.set_infinity_string(Some(b"Infiniiiiiity"));
+    opts.set_overflow_mode(OverflowMode::Error);
+    opts.set_max_significant_digits(num::NonZeroUsize::new(100));
+    opts.set_max_exponent_digits(num::NonZeroUsize::new(4));
+    opts.set_exponent_characters(Some(b"pq"));
+    opts.set_flush_subnormals(true);
+    opts.set_ignored_chars(Some(b"$ "));
+    opts.set_percent(true);
+    opts.set_imaginary_units(b"j");
 
     assert_eq!(opts.lossy(), true);
     assert_eq!(opts.exponent(), b'^');
     assert_eq!(opts.decimal_point(), b',');
     assert_eq!(opts.nan_string(), Some("nan".as_bytes()));
     assert_eq!(opts.inf_string(), Some("Infinity".as_bytes()));
+    assert_eq!(opts.exponent_string(), Some("*10^".as_bytes()));
     assert_eq!(opts.infinity_string(), Some("Infiniiiiiity".as_bytes()));
+    assert_eq!(opts.overflow_mode(), OverflowMode::Error);
+    assert_eq!(opts.max_significant_digits(), num::NonZeroUsize::new(100));
+    assert_eq!(opts.max_exponent_digits(), num::NonZeroUsize::new(4));
+    assert_eq!(opts.exponent_characters(), Some("pq".as_bytes()));
+    assert!(opts.flush_subnormals());
+    assert_eq!(opts.ignored_chars(), Some("$ ".as_bytes()));
+    assert!(opts.percent());
+    assert_eq!(opts.imaginary_units(), b"j");
     assert!(opts.is_valid());
 
     assert_eq!(Options::builder(), OptionsBuilder::new());
     assert_eq!(opts.rebuild().build(), Ok(opts));
 }
+
+#[cfg(feature = "proptest")]
+proptest::proptest! {
+    #[test]
+    fn arbitrary_options_proptest(options in proptest::arbitrary::any::<Options>()) {
+        proptest::prop_assert!(options.is_valid());
+    }
+}
@@ -1,3 +1,4 @@
+use lexical_parse_float::bigint::BIGINT_LIMBS;
 use lexical_parse_float::options::Options;
 use lexical_parse_float::parse;
 use lexical_util::format::STANDARD;
@@ -9,15 +10,15 @@ fn parse_complete_test() {
     const FORMAT: u128 = STANDARD;
     let options = Options::new();
     let string = b"1.2345e10";
-    let result = parse::parse_complete::<f64, FORMAT>(string, &options);
+    let result = parse::parse_complete::<f64, FORMAT, BIGINT_LIMBS>(string, &options, None);
     assert_eq!(result, Ok(1.2345e10));
 
     let string = b"1.2345e";
-    let result = parse::parse_complete::<f64, FORMAT>(string, &options);
+    let result = parse::parse_complete::<f64, FORMAT, BIGINT_LIMBS>(string, &options, None);
     assert!(result.is_err());
 
     let string = b"1.2345 ";
-    let result = parse::parse_complete::<f64, FORMAT>(string, &options);
+    let result = parse::parse_complete::<f64, FORMAT, BIGINT_LIMBS>(string, &options, None);
     assert!(result.is_err());
 }
 
@@ -43,15 +44,15 @@ fn parse_partial_test() {
     const FORMAT: u128 = STANDARD;
     let options = Options::new();
     let string = b"1.2345e10";
-    let result = parse::parse_partial::<f64, FORMAT>(string, &options);
+    let result = parse::parse_partial::<f64, FORMAT, BIGINT_LIMBS>(string, &options, None);
     assert_eq!(result, Ok((1.2345e10, 9)));
 
     let string = b"1.2345e";
-    let result = parse::parse_partial::<f64, FORMAT>(string, &options);
+    let result = parse::parse_partial::<f64, FORMAT, BIGINT_LIMBS>(string, &options, None);
     assert!(result.is_err());
 
     let string = b"1.2345 ";
-    let result = parse::parse_partial::<f64, FORMAT>(string, &options);
+    let result = parse::parse_partial::<f64, FORMAT, BIGINT_LIMBS>(string, &options, None);
     assert_eq!(result, Ok((1.2345, 6)));
 }
 
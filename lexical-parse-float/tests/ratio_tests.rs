@@ -0,0 +1,127 @@
+#![allow(clippy::disallowed_macros)]
+
+mod util;
+
+use lexical_parse_float::{parse_ratio, parse_ratio_parts, Error, Ratio};
+#[cfg(feature = "f16")]
+use lexical_util::f16::f16;
+
+const fn ratio(is_negative: bool, numerator: u64, denominator: u64) -> Ratio {
+    Ratio {
+        is_negative,
+        numerator,
+        denominator,
+    }
+}
+
+#[test]
+fn parse_ratio_parts_test() {
+    assert_eq!(parse_ratio_parts(b"22/7"), Ok(ratio(false, 22, 7)));
+    assert_eq!(parse_ratio_parts(b"+22/7"), Ok(ratio(false, 22, 7)));
+    assert_eq!(parse_ratio_parts(b"-1/2"), Ok(ratio(true, 1, 2)));
+    assert_eq!(parse_ratio_parts(b"4/8"), Ok(ratio(false, 4, 8)));
+    assert_eq!(parse_ratio_parts(b"0/5"), Ok(ratio(false, 0, 5)));
+    assert_eq!(parse_ratio_parts(b"3 1/2"), Ok(ratio(false, 7, 2)));
+    assert_eq!(parse_ratio_parts(b"-3 1/2"), Ok(ratio(true, 7, 2)));
+    assert_eq!(parse_ratio_parts(b"1 5/4"), Ok(ratio(false, 9, 4)));
+    assert_eq!(parse_ratio_parts(b"12"), Ok(ratio(false, 12, 1)));
+    assert_eq!(parse_ratio_parts(b"-0"), Ok(ratio(true, 0, 1)));
+    assert_eq!(
+        parse_ratio_parts(b"18446744073709551615/18446744073709551615"),
+        Ok(ratio(false, u64::MAX, u64::MAX))
+    );
+}
+
+#[test]
+fn parse_ratio_parts_error_test() {
+    assert_eq!(parse_ratio_parts(b""), Err(Error::Empty(0)));
+    assert_eq!(parse_ratio_parts(b"-"), Err(Error::Empty(1)));
+    assert_eq!(parse_ratio_parts(b"/2"), Err(Error::InvalidDigit(0)));
+    assert_eq!(parse_ratio_parts(b"1/"), Err(Error::Empty(2)));
+    assert_eq!(parse_ratio_parts(b"1/-2"), Err(Error::InvalidDigit(2)));
+    assert_eq!(parse_ratio_parts(b"1/2/3"), Err(Error::InvalidDigit(3)));
+    assert_eq!(parse_ratio_parts(b"1/2 "), Err(Error::InvalidDigit(3)));
+    assert_eq!(parse_ratio_parts(b"1.5"), Err(Error::InvalidDigit(1)));
+    assert_eq!(parse_ratio_parts(b"1/0"), Err(Error::ZeroDenominator(2)));
+    assert_eq!(parse_ratio_parts(b"3 1/00"), Err(Error::ZeroDenominator(4)));
+    assert_eq!(parse_ratio_parts(b"3 "), Err(Error::Empty(2)));
+    assert_eq!(parse_ratio_parts(b"3 1"), Err(Error::Empty(3)));
+    assert_eq!(parse_ratio_parts(b"3  1/2"), Err(Error::InvalidDigit(2)));
+    assert_eq!(parse_ratio_parts(b"3 1 2"), Err(Error::InvalidDigit(3)));
+    assert_eq!(parse_ratio_parts(b"3 -1/2"), Err(Error::InvalidDigit(2)));
+    assert_eq!(parse_ratio_parts(b"18446744073709551616"), Err(Error::Overflow(19)));
+    assert_eq!(parse_ratio_parts(b"1/18446744073709551616"), Err(Error::Overflow(21)));
+    assert_eq!(parse_ratio_parts(b"-9223372036854775808 1/2"), Err(Error::Overflow(1)));
+    assert!(Error::ZeroDenominator(2).is_zero_denominator());
+}
+
+#[test]
+fn parse_ratio_test() {
+    assert_eq!(parse_ratio::<f64>(b"22/7"), Ok(22.0 / 7.0));
+    assert_eq!(parse_ratio::<f64>(b"1/3"), Ok(1.0 / 3.0));
+    assert_eq!(parse_ratio::<f64>(b"-3 1/2"), Ok(-3.5));
+    assert_eq!(parse_ratio::<f64>(b"1 1/8"), Ok(1.125));
+    assert_eq!(parse_ratio::<f64>(b"7"), Ok(7.0));
+    assert_eq!(parse_ratio::<f32>(b"1/10"), Ok(0.1));
+    assert_eq!(parse_ratio::<f32>(b"2/3"), Ok(2.0 / 3.0));
+    assert_eq!(parse_ratio::<f32>(b"-3 1/2"), Ok(-3.5));
+
+    // Zeros keep the sign.
+    assert_eq!(parse_ratio::<f64>(b"0/3").map(f64::to_bits), Ok(0.0f64.to_bits()));
+    assert_eq!(parse_ratio::<f64>(b"-0/3").map(f64::to_bits), Ok((-0.0f64).to_bits()));
+
+    // The quotient is rounded once, with ties to even.
+    assert_eq!(parse_ratio::<f64>(b"18446744073709551615"), Ok(18446744073709551616.0));
+    assert_eq!(parse_ratio::<f64>(b"1/18446744073709551615"), Ok(5.421010862427522e-20));
+    assert_eq!(parse_ratio::<f64>(b"9007199254740993/1"), Ok(9007199254740992.0));
+    assert_eq!(parse_ratio::<f64>(b"9007199254740995/1"), Ok(9007199254740996.0));
+    assert_eq!(parse_ratio::<f64>(b"9007199254740993/9007199254740992"), Ok(1.0));
+    assert_eq!(parse_ratio::<f64>(b"9007199254740995/9007199254740992"), Ok(1.0000000000000004));
+    assert_eq!(parse_ratio::<f32>(b"16777217/16777216"), Ok(1.0));
+    assert_eq!(parse_ratio::<f32>(b"16777217/33554432"), Ok(0.5));
+    assert_eq!(parse_ratio::<f32>(b"1 1/16777216"), Ok(1.0));
+    assert_eq!(parse_ratio::<f32>(b"1 3/16777216"), Ok(1.0000002));
+
+    assert_eq!(parse_ratio::<f64>(b"1/0"), Err(Error::ZeroDenominator(2)));
+    assert_eq!(parse_ratio::<f32>(b"1e5"), Err(Error::InvalidDigit(1)));
+}
+
+#[test]
+#[cfg(feature = "f16")]
+fn parse_ratio_f16_test() {
+    // Compare the bits, since the result may be a special value.
+    let parse = |x| parse_ratio::<f16>(x).map(f16::to_bits);
+
+    assert_eq!(parse(b"1 1/2"), Ok(f16::from_f32(1.5).to_bits()));
+    assert_eq!(parse(b"65504"), Ok(f16::MAX.to_bits()));
+    assert_eq!(parse(b"65519"), Ok(f16::MAX.to_bits()));
+    assert_eq!(parse(b"65520"), Ok(f16::INFINITY.to_bits()));
+    assert_eq!(parse(b"-18446744073709551615"), Ok(f16::NEG_INFINITY.to_bits()));
+    // The smallest denormal float is `2^-24`.
+    assert_eq!(parse(b"1/16777216"), Ok(1));
+    assert_eq!(parse(b"3/33554432"), Ok(2));
+    assert_eq!(parse(b"1/33554432"), Ok(0));
+    assert_eq!(parse(b"1/33554431"), Ok(1));
+    assert_eq!(parse(b"1/18446744073709551615"), Ok(0));
+    assert_eq!(parse(b"-1/18446744073709551615"), Ok((-f16::ZERO).to_bits()));
+}
+
+default_quickcheck! {
+    fn f32_ratio_quickcheck(numerator: u64, denominator: u64) -> bool {
+        // The `f64` quotient of 53-bit integers is correctly rounded, and
+        // so is converting it to `f32`, since it has over twice the bits.
+        let numerator = numerator >> 11;
+        let denominator = (denominator >> 11).max(1);
+        let expected = (numerator as f64 / denominator as f64) as f32;
+        let string = format!("{}/{}", numerator, denominator);
+        parse_ratio::<f32>(string.as_bytes()) == Ok(expected)
+    }
+
+    fn f64_ratio_quickcheck(numerator: u64, denominator: u64) -> bool {
+        let numerator = numerator >> 11;
+        let denominator = (denominator >> 11).max(1);
+        let expected = numerator as f64 / denominator as f64;
+        let string = format!("{}/{}", numerator, denominator);
+        parse_ratio::<f64>(string.as_bytes()) == Ok(expected)
+    }
+}
@@ -0,0 +1,174 @@
+#![cfg(feature = "radix")]
+#![allow(clippy::disallowed_macros)]
+
+mod util;
+
+use lexical_parse_float::{rebase, rebase_buffer_size, rebase_integer};
+use lexical_util::error::Error;
+use proptest::prelude::*;
+use util::default_proptest_config;
+
+fn convert(bytes: &[u8], from: u32, to: u32, fraction_digits: usize) -> Result<String, Error> {
+    let mut buffer = vec![0u8; rebase_buffer_size(bytes.len(), from, to, fraction_digits)];
+    let result = rebase(bytes, from, to, fraction_digits, &mut buffer)?;
+    Ok(String::from_utf8(result.to_vec()).unwrap())
+}
+
+fn convert_integer(bytes: &[u8], from: u32, to: u32) -> Result<String, Error> {
+    let mut buffer = vec![0u8; rebase_buffer_size(bytes.len(), from, to, 0)];
+    let result = rebase_integer(bytes, from, to, &mut buffer)?;
+    Ok(String::from_utf8(result.to_vec()).unwrap())
+}
+
+fn to_radix(mut value: u128, radix: u32) -> String {
+    let mut digits = Vec::new();
+    loop {
+        digits.push(char::from_digit((value % radix as u128) as u32, radix).unwrap());
+        value /= radix as u128;
+        if value == 0 {
+            break;
+        }
+    }
+    digits.iter().rev().collect::<String>().to_uppercase()
+}
+
+#[test]
+fn rebase_integer_test() {
+    assert_eq!(convert(b"0", 10, 2, 0), Ok("0".into()));
+    assert_eq!(convert(b"255", 10, 16, 0), Ok("FF".into()));
+    assert_eq!(convert(b"ff", 16, 10, 0), Ok("255".into()));
+    assert_eq!(convert(b"-Z", 36, 10, 4), Ok("-35".into()));
+    assert_eq!(convert(b"+0010", 2, 3, 0), Ok("2".into()));
+    assert_eq!(convert(b"-0", 10, 10, 0), Ok("0".into()));
+    assert_eq!(convert(b"12.", 10, 10, 5), Ok("12".into()));
+    assert_eq!(
+        convert(b"340282366920938463463374607431768211456", 10, 16, 0),
+        Ok("100000000000000000000000000000000".into())
+    );
+}
+
+#[test]
+fn rebase_fraction_test() {
+    assert_eq!(convert(b"3.243F6A", 16, 10, 10), Ok("3.1415926218".into()));
+    assert_eq!(convert(b"3.243F6A", 16, 10, 30), Ok("3.14159262180328369140625".into()));
+    assert_eq!(convert(b".5", 10, 2, 10), Ok("0.1".into()));
+    assert_eq!(convert(b"0.1", 10, 2, 8), Ok("0.0001101".into()));
+    assert_eq!(convert(b"0.1", 10, 3, 4), Ok("0.0022".into()));
+    assert_eq!(convert(b"0.000", 10, 16, 4), Ok("0".into()));
+    assert_eq!(convert(b"-0.0001", 10, 10, 2), Ok("0".into()));
+}
+
+#[test]
+fn rebase_round_test() {
+    // Round up, carrying into the integer.
+    assert_eq!(convert(b"0.FF", 16, 10, 1), Ok("1".into()));
+    assert_eq!(convert(b"9.96", 10, 10, 1), Ok("10".into()));
+    assert_eq!(convert(b"-1.99", 10, 10, 1), Ok("-2".into()));
+    // Round down.
+    assert_eq!(convert(b"1.94", 10, 10, 1), Ok("1.9".into()));
+    // Ties to even.
+    assert_eq!(convert(b"1.25", 10, 10, 1), Ok("1.2".into()));
+    assert_eq!(convert(b"1.35", 10, 10, 1), Ok("1.4".into()));
+    assert_eq!(convert(b"0.5", 10, 10, 0), Ok("0".into()));
+    assert_eq!(convert(b"1.5", 10, 10, 0), Ok("2".into()));
+    assert_eq!(convert(b"2.5", 10, 10, 0), Ok("2".into()));
+    assert_eq!(convert(b"1.1", 2, 3, 0), Ok("2".into()));
+    assert_eq!(convert(b"10.1", 2, 3, 0), Ok("2".into()));
+}
+
+#[test]
+fn rebase_error_test() {
+    assert_eq!(convert(b"", 10, 16, 0), Err(Error::Empty(0)));
+    assert_eq!(convert(b"-", 10, 16, 0), Err(Error::EmptyMantissa(1)));
+    assert_eq!(convert(b".", 10, 16, 0), Err(Error::EmptyMantissa(0)));
+    assert_eq!(convert(b"12A", 10, 16, 0), Err(Error::InvalidDigit(2)));
+    assert_eq!(convert(b"-1.2.3", 10, 16, 0), Err(Error::InvalidDigit(4)));
+    assert_eq!(convert(b"1e5", 10, 16, 0), Err(Error::InvalidDigit(1)));
+    assert_eq!(convert(b"1", 1, 16, 0), Err(Error::InvalidRadix));
+    assert_eq!(convert(b"1", 10, 37, 0), Err(Error::InvalidRadix));
+    let result = convert(&[b'9'; 4000], 10, 16, 0);
+    assert!(matches!(result, Err(Error::TooManyDigits(_))), "{result:?}");
+}
+
+#[test]
+#[should_panic]
+fn rebase_buffer_test() {
+    let mut buffer = [0u8; 8];
+    _ = rebase(b"1.5", 10, 2, 8, &mut buffer);
+}
+
+#[test]
+fn rebase_integer_digits_test() {
+    assert_eq!(convert_integer(b"0", 10, 2), Ok("0".into()));
+    assert_eq!(convert_integer(b"-000", 16, 2), Ok("0".into()));
+    assert_eq!(convert_integer(b"-000", 10, 3), Ok("0".into()));
+    assert_eq!(convert_integer(b"+00ff", 16, 10), Ok("255".into()));
+    assert_eq!(convert_integer(b"00ff", 16, 8), Ok("377".into()));
+    assert_eq!(convert_integer(b"-11111", 2, 32), Ok("-V".into()));
+    assert_eq!(convert_integer(b"100000", 2, 32), Ok("10".into()));
+    assert_eq!(convert_integer(b"7777", 8, 16), Ok("FFF".into()));
+
+    // Power-of-two radixes have no limit on the number of digits.
+    let hex = "F".repeat(10000);
+    let binary = convert_integer(hex.as_bytes(), 16, 2).unwrap();
+    assert_eq!(binary, "1".repeat(40000));
+    assert_eq!(convert_integer(binary.as_bytes(), 2, 16), Ok(hex));
+
+    let decimal = "9".repeat(500);
+    let hex = convert_integer(decimal.as_bytes(), 10, 16).unwrap();
+    assert_eq!(convert_integer(hex.as_bytes(), 16, 10), Ok(decimal));
+}
+
+#[test]
+fn rebase_integer_error_test() {
+    assert_eq!(convert_integer(b"", 10, 16), Err(Error::Empty(0)));
+    assert_eq!(convert_integer(b"+", 10, 16), Err(Error::EmptyMantissa(1)));
+    assert_eq!(convert_integer(b"1.5", 10, 16), Err(Error::InvalidDigit(1)));
+    assert_eq!(convert_integer(b"-12G", 16, 2), Err(Error::InvalidDigit(3)));
+    assert_eq!(convert_integer(b"102", 2, 10), Err(Error::InvalidDigit(2)));
+    assert_eq!(convert_integer(b"1", 10, 0), Err(Error::InvalidRadix));
+    let result = convert_integer(&[b'9'; 4000], 10, 16);
+    assert!(matches!(result, Err(Error::TooManyDigits(_))), "{result:?}");
+}
+
+proptest! {
+    #![proptest_config(default_proptest_config())]
+
+    #[test]
+    fn rebase_integer_proptest(value: u128, is_negative: bool, from in 2u32..=36, to in 2u32..=36) {
+        let sign = if is_negative && value != 0 { "-" } else { "" };
+        let input = format!("{sign}{}", to_radix(value, from));
+        let expected = format!("{sign}{}", to_radix(value, to));
+        prop_assert_eq!(convert(input.as_bytes(), from, to, 0), Ok(expected));
+    }
+
+    #[test]
+    fn rebase_fraction_proptest(value: u64, bits in 1usize..=52) {
+        // Binary fractions with `n` bits have exactly `n` decimal digits.
+        let value = value & ((1 << bits) - 1);
+        let input = format!("0.{:0bits$b}", value, bits = bits);
+        let result = convert(input.as_bytes(), 2, 10, bits).unwrap();
+        let expected = value as f64 / (1u64 << bits) as f64;
+        prop_assert_eq!(result.parse::<f64>().unwrap(), expected);
+        let back = convert(result.as_bytes(), 10, 2, bits).unwrap();
+        prop_assert_eq!(convert(input.as_bytes(), 2, 2, bits).unwrap(), back);
+    }
+
+    #[test]
+    fn rebase_integer_digits_proptest(value: u128, is_negative: bool, from in 2u32..=36, to in 2u32..=36) {
+        let sign = if is_negative && value != 0 { "-" } else { "" };
+        let input = format!("{sign}{}", to_radix(value, from));
+        let expected = format!("{sign}{}", to_radix(value, to));
+        prop_assert_eq!(convert_integer(input.as_bytes(), from, to), Ok(expected));
+    }
+
+    #[test]
+    fn rebase_long_integer_proptest(digits in "[1-9a-f][0-9a-f]{0,300}") {
+        // Compare converting with the big integer and regrouping the bits.
+        let hex = digits.to_uppercase();
+        let decimal = convert_integer(hex.as_bytes(), 16, 10).unwrap();
+        let binary = convert_integer(hex.as_bytes(), 16, 2).unwrap();
+        prop_assert_eq!(convert_integer(decimal.as_bytes(), 10, 2), Ok(binary.clone()));
+        prop_assert_eq!(convert_integer(binary.as_bytes(), 2, 16), Ok(hex));
+    }
+}
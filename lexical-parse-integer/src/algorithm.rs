@@ -32,13 +32,15 @@
 
 #![doc(hidden)]
 
-use lexical_util::digit::char_to_digit_const;
+use lexical_util::digit::char_to_digit_format;
 use lexical_util::error::Error;
 use lexical_util::format::NumberFormat;
 use lexical_util::iterator::{AsBytes, Bytes, DigitsIter, Iter};
 use lexical_util::num::{as_cast, Integer};
 use lexical_util::result::Result;
 
+#[cfg(feature = "simd")]
+use crate::simd::{self, try_parse_16digits};
 use crate::Options;
 
 // HELPERS
@@ -181,6 +183,7 @@ macro_rules! fmt_invalid_digit {
 /// 4. Handles if the sign is required, but missing.
 /// 5. Handles if the iterator is empty, before or after parsing the sign.
 /// 6. Handles if the iterator has invalid, leading zeros.
+/// 7. Handles Unicode signs and parenthesized negatives, if enabled.
 ///
 /// Returns if the value is negative, or any values detected when
 /// validating the input.
@@ -191,27 +194,47 @@ macro_rules! parse_sign {
         $is_signed:expr,
         $no_positive:expr,
         $required:expr,
+        $unicode:expr,
+        $parenthesis:expr,
         $invalid_positive:ident,
         $missing:ident
-    ) => {
-        // NOTE: `read_if` optimizes poorly since we then match after
-        match $byte.integer_iter().first() {
-            Some(&b'+') if !$no_positive => {
-                // SAFETY: We have at least 1 item left since we peaked a value
-                unsafe { $byte.step_unchecked() };
+    ) => {{
+        let extended_sign = if $unicode || $parenthesis {
+            lexical_util::sign::extended_sign($byte.as_slice(), $unicode, $parenthesis)
+        } else {
+            None
+        };
+        match extended_sign {
+            Some((false, length)) if !$no_positive => {
+                // SAFETY: We have at least `length` items left since we matched the sign
+                unsafe { $byte.step_by_unchecked(length) };
                 Ok(false)
             },
-            Some(&b'+') if $no_positive => Err(Error::$invalid_positive($byte.cursor())),
-            Some(&b'-') if $is_signed => {
-                // SAFETY: We have at least 1 item left since we peaked a value
-                unsafe { $byte.step_unchecked() };
+            Some((false, _)) => Err(Error::$invalid_positive($byte.cursor())),
+            Some((true, length)) if $is_signed => {
+                // SAFETY: We have at least `length` items left since we matched the sign
+                unsafe { $byte.step_by_unchecked(length) };
                 Ok(true)
             },
-            Some(_) if $required => Err(Error::$missing($byte.cursor())),
-            _ if $required => Err(Error::$missing($byte.cursor())),
-            _ => Ok(false),
+            // NOTE: `read_if` optimizes poorly since we then match after
+            _ => match $byte.integer_iter().first() {
+                Some(&b'+') if !$no_positive => {
+                    // SAFETY: We have at least 1 item left since we peaked a value
+                    unsafe { $byte.step_unchecked() };
+                    Ok(false)
+                },
+                Some(&b'+') if $no_positive => Err(Error::$invalid_positive($byte.cursor())),
+                Some(&b'-') if $is_signed => {
+                    // SAFETY: We have at least 1 item left since we peaked a value
+                    unsafe { $byte.step_unchecked() };
+                    Ok(true)
+                },
+                Some(_) if $required => Err(Error::$missing($byte.cursor())),
+                _ if $required => Err(Error::$missing($byte.cursor())),
+                _ => Ok(false),
+            },
         }
-    };
+    }};
 }
 
 /// Parse the sign from the leading digits.
@@ -223,6 +246,8 @@ pub fn parse_sign<T: Integer, const FORMAT: u128>(byte: &mut Bytes<'_, FORMAT>)
         T::IS_SIGNED,
         format.no_positive_mantissa_sign(),
         format.required_mantissa_sign(),
+        format.unicode_signs(),
+        format.parenthesized_negatives(),
         InvalidPositiveSign,
         MissingSign
     )
@@ -302,7 +327,21 @@ where
 /// Determine if 8 bytes, read raw from bytes, are 8 digits for the radix.
 /// See `is_4digits` for the algorithm description.
 #[cfg_attr(not(feature = "compact"), inline(always))]
+#[allow(clippy::needless_return)] // reason="required based on target pointer width"
 pub fn is_8digits<const FORMAT: u128>(v: u64) -> bool {
+    // 64-bit arithmetic is emulated on 32-bit targets, so check each
+    // half using 32-bit arithmetic.
+    #[cfg(not(all(target_pointer_width = "64", not(target_arch = "sparc"))))]
+    return is_4digits::<FORMAT>(v as u32) & is_4digits::<FORMAT>((v >> 32) as u32);
+
+    #[cfg(all(target_pointer_width = "64", not(target_arch = "sparc")))]
+    return is_8digits_u64::<FORMAT>(v);
+}
+
+/// Determine if 8 bytes are 8 digits for the radix, using 64-bit arithmetic.
+#[cfg_attr(not(feature = "compact"), inline(always))]
+#[cfg_attr(not(all(target_pointer_width = "64", not(target_arch = "sparc"))), allow(dead_code))]
+fn is_8digits_u64<const FORMAT: u128>(v: u64) -> bool {
     let radix = NumberFormat::<{ FORMAT }>::MANTISSA_RADIX;
     debug_assert!(radix <= 10);
 
@@ -322,7 +361,27 @@ pub fn is_8digits<const FORMAT: u128>(v: u64) -> bool {
 /// Credit for this goes to @aqrit, which further optimizes the
 /// optimization described by Johnny Lee above.
 #[cfg_attr(not(feature = "compact"), inline(always))]
-pub fn parse_8digits<const FORMAT: u128>(mut v: u64) -> u64 {
+#[allow(clippy::needless_return)] // reason="required based on target pointer width"
+pub fn parse_8digits<const FORMAT: u128>(v: u64) -> u64 {
+    // 64-bit multiplication is emulated on 32-bit targets, so parse each
+    // half using 32-bit arithmetic. The first digits are in the low bytes.
+    #[cfg(not(all(target_pointer_width = "64", not(target_arch = "sparc"))))]
+    {
+        let radix = NumberFormat::<{ FORMAT }>::MANTISSA_RADIX;
+        let radix4 = radix * radix * radix * radix;
+        let hi = parse_4digits::<FORMAT>(v as u32);
+        let lo = parse_4digits::<FORMAT>((v >> 32) as u32);
+        return (hi * radix4 + lo) as u64;
+    }
+
+    #[cfg(all(target_pointer_width = "64", not(target_arch = "sparc")))]
+    return parse_8digits_u64::<FORMAT>(v);
+}
+
+/// Parse 8 bytes read from bytes into 8 digits, using 64-bit arithmetic.
+#[cfg_attr(not(feature = "compact"), inline(always))]
+#[cfg_attr(not(all(target_pointer_width = "64", not(target_arch = "sparc"))), allow(dead_code))]
+fn parse_8digits_u64<const FORMAT: u128>(mut v: u64) -> u64 {
     let radix = NumberFormat::<{ FORMAT }>::MANTISSA_RADIX as u64;
     debug_assert!(radix <= 10);
 
@@ -408,7 +467,7 @@ macro_rules! parse_1digit_unchecked {
         // an unchecked loop.
         let radix = NumberFormat::<FORMAT>::MANTISSA_RADIX;
         while let Some(&c) = $iter.next() {
-            let digit = match char_to_digit_const(c, radix) {
+            let digit = match char_to_digit_format::<FORMAT>(c, radix) {
                 Some(v) => v,
                 None => fmt_invalid_digit!($value, $iter, c, $start_index, $invalid_digit, $is_end),
             };
@@ -446,7 +505,7 @@ macro_rules! parse_1digit_checked {
         // an unchecked loop.
         let radix = NumberFormat::<FORMAT>::MANTISSA_RADIX;
         while let Some(&c) = $iter.next() {
-            let digit = match char_to_digit_const(c, radix) {
+            let digit = match char_to_digit_format::<FORMAT>(c, radix) {
                 Some(v) => v,
                 None => fmt_invalid_digit!($value, $iter, c, $start_index, $invalid_digit, true),
             };
@@ -497,6 +556,15 @@ macro_rules! parse_digits_unchecked {
         // matter.
         let format = NumberFormat::<FORMAT> {};
         if use_multi && T::BITS >= 64 && $iter.buffer_length() >= 8 {
+            // Try our SIMD, 16-digit at a time optimizations.
+            #[cfg(feature = "simd")]
+            if $iter.buffer_length() >= simd::CHUNK_SIZE {
+                let chunk_power = T::as_cast(simd::CHUNK_POWER);
+                while let Some(value) = try_parse_16digits::<T, _, FORMAT>(&mut $iter) {
+                    $value = $value.wrapping_mul(chunk_power).$add_op(value);
+                }
+            }
+
             // Try our fast, 8-digit at a time optimizations.
             let radix8 = T::from_u32(format.radix8());
             while let Some(value) = try_parse_8digits::<T, _, FORMAT>(&mut $iter) {
@@ -660,7 +728,7 @@ macro_rules! algorithm {
                 into_error!(InvalidLeadingZeros, index);
             }
             // NOTE: Zeros has to be 0 here, so our index == 1 or 2 (depending on sign)
-            match iter.peek().map(|&c| char_to_digit_const(c, format.radix())) {
+            match iter.peek().map(|&c| char_to_digit_format::<FORMAT>(c, format.radix())) {
                 // Valid digit, we have an invalid value.
                 Some(Some(_)) => into_error!(InvalidLeadingZeros, index),
                 // Have a non-digit character that follows.
@@ -685,7 +753,7 @@ macro_rules! algorithm {
     let mut value = T::ZERO;
     if cannot_overflow && is_negative {
         parse_digits_unchecked!(value, iter, wrapping_sub, start_index, $invalid_digit, $no_multi_digit, true);
-    } if cannot_overflow {
+    } else if cannot_overflow {
         parse_digits_unchecked!(value, iter, wrapping_add, start_index, $invalid_digit, $no_multi_digit, true);
     } else if is_negative {
         parse_digits_checked!(value, iter, checked_sub, wrapping_sub, start_index, $invalid_digit, Underflow, $no_multi_digit, overflow_digits);
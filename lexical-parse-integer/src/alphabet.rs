@@ -0,0 +1,105 @@
+//! String-to-integer conversion using a custom digit alphabet.
+//!
+//! These routines are not optimized: each loop parses a single digit,
+//! since the radix is only known at runtime and may be larger than 36.
+//! Digit separators and base prefixes from the number format are not
+//! supported, however, the sign rules still apply.
+
+#![doc(hidden)]
+
+use lexical_util::alphabet::DigitAlphabet;
+use lexical_util::error::Error;
+use lexical_util::iterator::{AsBytes, Iter};
+use lexical_util::num::Integer;
+use lexical_util::result::Result;
+
+use crate::algorithm::parse_sign;
+
+/// Convert a single character to a digit, or return from the parser.
+macro_rules! parse_digit {
+    ($alphabet:ident, $c:ident, $index:ident, $value:ident, $is_partial:ident) => {
+        match $alphabet.char_to_digit($c) {
+            Some(digit) => T::from_u32(digit),
+            None if $is_partial => return Ok(($value, $index)),
+            None => return Err(Error::InvalidDigit($index)),
+        }
+    };
+}
+
+/// Parse the digits using the alphabet, returning the value and the
+/// number of bytes processed.
+///
+/// The partial parser stops at the first invalid digit, while the
+/// complete parser returns an error.
+#[inline]
+fn parse_digits<T, const FORMAT: u128>(
+    bytes: &[u8],
+    alphabet: &DigitAlphabet,
+    is_partial: bool,
+) -> Result<(T, usize)>
+where
+    T: Integer,
+{
+    let mut byte = bytes.bytes::<FORMAT>();
+    let is_negative = parse_sign::<T, FORMAT>(&mut byte)?;
+    let start = byte.cursor();
+    if start == bytes.len() {
+        return Err(Error::Empty(start));
+    }
+
+    // Parse the digits that cannot overflow with wrapping arithmetic,
+    // and only check for overflow on the remaining digits.
+    let radix = T::from_u32(alphabet.radix());
+    let overflow_digits = T::overflow_digits(alphabet.radix());
+    let mut value = T::ZERO;
+    let mut iter = bytes.iter().enumerate().skip(start);
+    for (index, &c) in iter.by_ref().take(overflow_digits) {
+        let digit = parse_digit!(alphabet, c, index, value, is_partial);
+        value = value.wrapping_mul(radix);
+        value = if is_negative {
+            value.wrapping_sub(digit)
+        } else {
+            value.wrapping_add(digit)
+        };
+    }
+    for (index, &c) in iter {
+        let digit = parse_digit!(alphabet, c, index, value, is_partial);
+        value = if is_negative {
+            value
+                .checked_mul(radix)
+                .and_then(|x| x.checked_sub(digit))
+                .ok_or(Error::Underflow(index))?
+        } else {
+            value
+                .checked_mul(radix)
+                .and_then(|x| x.checked_add(digit))
+                .ok_or(Error::Overflow(index))?
+        };
+    }
+
+    Ok((value, bytes.len()))
+}
+
+/// Algorithm for the complete parser.
+#[inline]
+pub fn algorithm_complete<T, const FORMAT: u128>(
+    bytes: &[u8],
+    alphabet: &DigitAlphabet,
+) -> Result<T>
+where
+    T: Integer,
+{
+    parse_digits::<T, FORMAT>(bytes, alphabet, false).map(|(value, _)| value)
+}
+
+/// Algorithm for the partial parser.
+#[inline]
+pub fn algorithm_partial<T, const FORMAT: u128>(
+    bytes: &[u8],
+    alphabet: &DigitAlphabet,
+) -> Result<(T, usize)>
+where
+    T: Integer,
+{
+    parse_digits::<T, FORMAT>(bytes, alphabet, true)
+}
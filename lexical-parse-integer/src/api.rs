@@ -3,7 +3,7 @@
 #![doc(hidden)]
 
 use lexical_util::format::{NumberFormat, STANDARD};
-use lexical_util::{from_lexical, from_lexical_with_options};
+use lexical_util::{from_lexical, from_lexical_with_options, ignore, trim};
 
 use crate::options::{Options, STANDARD as DEFAULT_OPTIONS};
 use crate::parse::ParseInteger;
@@ -45,7 +45,11 @@ macro_rules! integer_from_lexical {
                 if !format.is_valid() {
                     return Err(format.error());
                 }
-                Self::parse_complete::<FORMAT>(bytes, options)
+                ignore::parse_complete(bytes, options.get_ignored_chars(), |bytes| {
+                    trim::parse_complete::<_, _, FORMAT>(bytes, |bytes| {
+                        Self::parse_complete::<FORMAT>(bytes, options)
+                    })
+                })
             }
 
             #[cfg_attr(not(feature = "compact"), inline)]
@@ -58,7 +62,11 @@ macro_rules! integer_from_lexical {
                 if !format.is_valid() {
                     return Err(format.error());
                 }
-                Self::parse_partial::<FORMAT>(bytes, options)
+                ignore::parse_partial(bytes, options.get_ignored_chars(), |bytes| {
+                    trim::parse_partial::<_, _, FORMAT>(bytes, |bytes| {
+                        Self::parse_partial::<FORMAT>(bytes, options)
+                    })
+                })
             }
         }
     )*)
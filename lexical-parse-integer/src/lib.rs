@@ -25,9 +25,12 @@
 //! * `radix` - Add support for strings of any radix.
 //! * `format` - Add support for parsing custom integer formats.
 //! * `compact` - Reduce code size at the cost of performance.
+//! * `simd` - Use SIMD instructions to parse long decimal strings.
+//! * `unicode` - Add support for parsing Unicode decimal digits.
 //! * `safe` - Ensure only memory-safe indexing is used.
 //!
-//! `safe` is a no-op, since all parsers are memory-safe by default.
+//! With `safe`, all reads from the input are bounds-checked, and the SIMD
+//! back-ends are replaced with the scalar fallback.
 //!
 //! # Note
 //!
@@ -89,13 +92,18 @@
 )]
 
 pub mod algorithm;
+pub mod alphabet;
 pub mod options;
 pub mod parse;
+pub mod parse_const;
+pub mod simd;
+pub mod unicode;
 
 mod api;
+mod validate;
 
 // Re-exports
-pub use lexical_util::error::Error;
+pub use lexical_util::error::{CharacterClass, Error, ErrorKind};
 pub use lexical_util::format::{self, NumberFormatBuilder};
 pub use lexical_util::options::ParseOptions;
 pub use lexical_util::result::Result;
@@ -103,3 +111,6 @@ pub use lexical_util::result::Result;
 pub use self::api::{FromLexical, FromLexicalWithOptions};
 #[doc(inline)]
 pub use self::options::{Options, OptionsBuilder};
+#[cfg(feature = "unicode")]
+pub use self::unicode::FromLexicalUnicode;
+pub use self::validate::{is_integer, is_integer_with_options};
@@ -1,5 +1,7 @@
 //! Configuration options for parsing integers.
 
+use lexical_util::alphabet::DigitAlphabet;
+use lexical_util::error::Error;
 use lexical_util::options::ParseOptions;
 use lexical_util::result::Result;
 use static_assertions::const_assert;
@@ -15,6 +17,11 @@ pub struct OptionsBuilder {
     /// increased branching can decrease performance for simple
     /// strings by 5-20%. Choose based on your inputs.
     no_multi_digit: bool,
+    /// Custom digit alphabet, which overrides the radix from the format.
+    /// If not set, the case-insensitive `0-9A-Z` digits are used.
+    alphabet: Option<DigitAlphabet>,
+    /// Characters to silently skip anywhere in the input.
+    ignored_chars: Option<&'static [u8]>,
 }
 
 impl OptionsBuilder {
@@ -23,6 +30,8 @@ impl OptionsBuilder {
     pub const fn new() -> Self {
         Self {
             no_multi_digit: true,
+            alphabet: None,
+            ignored_chars: None,
         }
     }
 
@@ -34,6 +43,18 @@ impl OptionsBuilder {
         self.no_multi_digit
     }
 
+    /// Get the custom digit alphabet.
+    #[inline(always)]
+    pub const fn get_alphabet(&self) -> Option<DigitAlphabet> {
+        self.alphabet
+    }
+
+    /// Get the characters to silently skip anywhere in the input.
+    #[inline(always)]
+    pub const fn get_ignored_chars(&self) -> Option<&'static [u8]> {
+        self.ignored_chars
+    }
+
     // SETTERS
 
     /// Set if we disable the use of multi-digit optimizations.
@@ -43,12 +64,71 @@ impl OptionsBuilder {
         self
     }
 
+    /// Set the custom digit alphabet.
+    #[inline(always)]
+    pub const fn alphabet(mut self, alphabet: Option<DigitAlphabet>) -> Self {
+        self.alphabet = alphabet;
+        self
+    }
+
+    /// Set the characters to silently skip anywhere in the input.
+    ///
+    /// This allows parsing human-formatted values, like `$1 234` with
+    /// `ignored_chars(Some(b"$ "))`, without cleaning the input first.
+    /// Multi-byte characters are skipped by ignoring each of their bytes,
+    /// so `b"\xC2\xA0"` skips the UTF-8 no-break space. The characters
+    /// must not be digits, letters, or signs.
+    ///
+    /// If the input contains any ignored characters, the remaining bytes are
+    /// copied to a buffer before they are parsed. Without the `std` feature,
+    /// this is a 128-byte buffer on the stack, and longer inputs, after
+    /// removing the ignored characters, fail with [`Error::TooManyDigits`].
+    #[inline(always)]
+    pub const fn ignored_chars(mut self, ignored_chars: Option<&'static [u8]>) -> Self {
+        self.ignored_chars = ignored_chars;
+        self
+    }
+
     // BUILDERS
 
+    /// Determine if `ignored_chars` is valid.
+    ///
+    /// The characters must not be empty, and each must not be an ASCII
+    /// letter or digit, a sign, or a digit in the custom alphabet.
+    #[inline(always)]
+    pub const fn ignored_chars_is_valid(&self) -> bool {
+        let characters = match self.ignored_chars {
+            Some(characters) => characters,
+            None => return true,
+        };
+        if characters.is_empty() {
+            return false;
+        }
+        let mut index = 0;
+        while index < characters.len() {
+            let c = characters[index];
+            if c.is_ascii_alphanumeric() || c == b'+' || c == b'-' {
+                return false;
+            }
+            if let Some(alphabet) = self.alphabet {
+                if alphabet.char_to_digit(c).is_some() {
+                    return false;
+                }
+            }
+            index += 1;
+        }
+        true
+    }
+
     /// Check if the builder state is valid.
     #[inline(always)]
     pub const fn is_valid(&self) -> bool {
-        true
+        if let Some(alphabet) = self.alphabet {
+            if !alphabet.is_valid() {
+                return false;
+            }
+        }
+        self.ignored_chars_is_valid()
     }
 
     /// Build the Options struct with bounds validation.
@@ -56,12 +136,22 @@ impl OptionsBuilder {
     pub const fn build_unchecked(&self) -> Options {
         Options {
             no_multi_digit: self.no_multi_digit,
+            alphabet: self.alphabet,
+            ignored_chars: self.ignored_chars,
         }
     }
 
     /// Build the Options struct.
     #[inline(always)]
     pub const fn build(&self) -> Result<Options> {
+        if let Some(alphabet) = self.alphabet {
+            if !alphabet.is_valid() {
+                return Err(Error::InvalidDigitAlphabet);
+            }
+        }
+        if !self.ignored_chars_is_valid() {
+            return Err(Error::InvalidPunctuation);
+        }
         Ok(self.build_unchecked())
     }
 }
@@ -73,7 +163,14 @@ impl Default for OptionsBuilder {
     }
 }
 
-/// Immutable options to customize writing integers.
+/// Immutable options to customize parsing integers.
+///
+/// A custom digit alphabet, such as base58 or base62, overrides the
+/// radix from the number format: the radix is the number of digits in
+/// the alphabet, and the digits are case-sensitive.
+///
+/// Ignored characters are skipped anywhere in the input, including
+/// between the sign and the digits.
 ///
 /// # Examples
 ///
@@ -96,6 +193,11 @@ pub struct Options {
     /// increased branching can decrease performance for simple
     /// strings by 5-20%. Choose based on your inputs.
     no_multi_digit: bool,
+    /// Custom digit alphabet, which overrides the radix from the format.
+    /// If not set, the case-insensitive `0-9A-Z` digits are used.
+    alphabet: Option<DigitAlphabet>,
+    /// Characters to silently skip anywhere in the input.
+    ignored_chars: Option<&'static [u8]>,
 }
 
 impl Options {
@@ -120,6 +222,18 @@ impl Options {
         self.no_multi_digit
     }
 
+    /// Get the custom digit alphabet.
+    #[inline(always)]
+    pub const fn get_alphabet(&self) -> Option<DigitAlphabet> {
+        self.alphabet
+    }
+
+    /// Get the characters to silently skip anywhere in the input.
+    #[inline(always)]
+    pub const fn get_ignored_chars(&self) -> Option<&'static [u8]> {
+        self.ignored_chars
+    }
+
     // SETTERS
 
     /// Set if we disable the use of multi-digit optimizations.
@@ -128,6 +242,18 @@ impl Options {
         self.no_multi_digit = no_multi_digit;
     }
 
+    /// Set the custom digit alphabet.
+    #[inline(always)]
+    pub fn alphabet(&mut self, alphabet: Option<DigitAlphabet>) {
+        self.alphabet = alphabet;
+    }
+
+    /// Set the characters to silently skip anywhere in the input.
+    #[inline(always)]
+    pub fn ignored_chars(&mut self, ignored_chars: Option<&'static [u8]>) {
+        self.ignored_chars = ignored_chars;
+    }
+
     // BUILDERS
 
     /// Get `OptionsBuilder` as a static function.
@@ -141,6 +267,8 @@ impl Options {
     pub const fn rebuild(&self) -> OptionsBuilder {
         OptionsBuilder {
             no_multi_digit: self.no_multi_digit,
+            alphabet: self.alphabet,
+            ignored_chars: self.ignored_chars,
         }
     }
 }
@@ -180,3 +308,20 @@ pub const LARGE_NUMBERS: Options = Options::builder()
         .no_multi_digit(false)
         .build_unchecked();
 const_assert!(LARGE_NUMBERS.is_valid());
+
+#[cfg(feature = "proptest")]
+impl proptest::arbitrary::Arbitrary for Options {
+    type Parameters = ();
+    type Strategy = proptest::strategy::BoxedStrategy<Self>;
+
+    /// Generate valid options, optionally with a custom digit alphabet.
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        use proptest::prelude::*;
+
+        (any::<bool>(), proptest::option::of(any::<DigitAlphabet>()))
+            .prop_map(|(no_multi_digit, alphabet)| {
+                Self::builder().no_multi_digit(no_multi_digit).alphabet(alphabet).build_unchecked()
+            })
+            .boxed()
+    }
+}
@@ -7,6 +7,7 @@ use lexical_util::num::Integer;
 use lexical_util::result::Result;
 
 use crate::algorithm::{algorithm_complete, algorithm_partial};
+use crate::alphabet;
 use crate::Options;
 
 /// Parse integer trait, implemented in terms of the optimized back-end.
@@ -14,12 +15,18 @@ pub trait ParseInteger: Integer {
     /// Forward complete parser parameters to the backend.
     #[cfg_attr(not(feature = "compact"), inline(always))]
     fn parse_complete<const FORMAT: u128>(bytes: &[u8], options: &Options) -> Result<Self> {
+        if let Some(alphabet) = options.get_alphabet() {
+            return alphabet::algorithm_complete::<_, { FORMAT }>(bytes, &alphabet);
+        }
         algorithm_complete::<_, { FORMAT }>(bytes, options)
     }
 
     /// Forward partial parser parameters to the backend.
     #[cfg_attr(not(feature = "compact"), inline(always))]
     fn parse_partial<const FORMAT: u128>(bytes: &[u8], options: &Options) -> Result<(Self, usize)> {
+        if let Some(alphabet) = options.get_alphabet() {
+            return alphabet::algorithm_partial::<_, { FORMAT }>(bytes, &alphabet);
+        }
         algorithm_partial::<_, { FORMAT }>(bytes, options)
     }
 }
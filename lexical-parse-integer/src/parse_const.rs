@@ -0,0 +1,127 @@
+//! Parse integers in `const` contexts.
+//!
+//! Each function is named after the integer type, and parses the entire
+//! string as a decimal integer, with an optional `+` or `-` sign, using
+//! the standard format. The results and errors are identical to
+//! [`FromLexical::from_lexical`], but the functions may be evaluated at
+//! compile time. These routines are not optimized, and parse a single
+//! digit at a time, so prefer [`FromLexical`] at runtime.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use lexical_parse_integer::parse_const;
+//!
+//! const PORT: u16 = match parse_const::u16(b"8080") {
+//!     Ok(value) => value,
+//!     Err(_) => panic!("invalid port"),
+//! };
+//! assert_eq!(PORT, 8080);
+//! assert!(parse_const::i8(b"-129").is_err());
+//! ```
+//!
+//! [`FromLexical`]: crate::FromLexical
+//! [`FromLexical::from_lexical`]: crate::FromLexical::from_lexical
+
+use lexical_util::error::Error;
+use lexical_util::result::Result;
+
+/// Convert an ASCII byte to a decimal digit.
+#[inline(always)]
+const fn to_digit(c: u8) -> Option<u8> {
+    match c {
+        b'0'..=b'9' => Some(c - b'0'),
+        _ => None,
+    }
+}
+
+macro_rules! parse_unsigned {
+    ($($t:ident)*) => ($(
+        #[doc = concat!("Parse a `", stringify!($t), "` from a decimal string in a `const` context.")]
+        ///
+        /// # Errors
+        ///
+        /// Returns an error if the string is empty, contains invalid
+        /// digits, or the value overflows.
+        #[inline]
+        pub const fn $t(bytes: &[u8]) -> Result<$t> {
+            let mut index = match bytes {
+                [] => return Err(Error::Empty(0)),
+                [b'+'] => return Err(Error::Empty(1)),
+                [b'+', ..] => 1,
+                _ => 0,
+            };
+            let mut value: $t = 0;
+            while index < bytes.len() {
+                let digit = match to_digit(bytes[index]) {
+                    Some(digit) => digit as $t,
+                    None => return Err(Error::InvalidDigit(index)),
+                };
+                value = match value.checked_mul(10) {
+                    Some(value) => value,
+                    None => return Err(Error::Overflow(index)),
+                };
+                value = match value.checked_add(digit) {
+                    Some(value) => value,
+                    None => return Err(Error::Overflow(index)),
+                };
+                index += 1;
+            }
+            Ok(value)
+        }
+    )*);
+}
+
+parse_unsigned! { u8 u16 u32 u64 u128 usize }
+
+macro_rules! parse_signed {
+    ($($t:ident)*) => ($(
+        #[doc = concat!("Parse an `", stringify!($t), "` from a decimal string in a `const` context.")]
+        ///
+        /// # Errors
+        ///
+        /// Returns an error if the string is empty, contains invalid
+        /// digits, or the value overflows or underflows.
+        #[inline]
+        pub const fn $t(bytes: &[u8]) -> Result<$t> {
+            let (is_negative, mut index) = match bytes {
+                [] => return Err(Error::Empty(0)),
+                [b'+' | b'-'] => return Err(Error::Empty(1)),
+                [b'+', ..] => (false, 1),
+                [b'-', ..] => (true, 1),
+                _ => (false, 0),
+            };
+            // Accumulate negative values by subtraction, so the minimum
+            // value does not overflow.
+            let mut value: $t = 0;
+            while index < bytes.len() {
+                let digit = match to_digit(bytes[index]) {
+                    Some(digit) => digit as $t,
+                    None => return Err(Error::InvalidDigit(index)),
+                };
+                let error = if is_negative {
+                    Error::Underflow(index)
+                } else {
+                    Error::Overflow(index)
+                };
+                value = match value.checked_mul(10) {
+                    Some(value) => value,
+                    None => return Err(error),
+                };
+                let next = if is_negative {
+                    value.checked_sub(digit)
+                } else {
+                    value.checked_add(digit)
+                };
+                value = match next {
+                    Some(value) => value,
+                    None => return Err(error),
+                };
+                index += 1;
+            }
+            Ok(value)
+        }
+    )*);
+}
+
+parse_signed! { i8 i16 i32 i64 i128 isize }
@@ -0,0 +1,187 @@
+//! SIMD-accelerated validation and conversion of decimal digits.
+//!
+//! This parses 16 decimal digits at a time, which is the largest chunk
+//! that fits in a 64-bit integer, so wider vectors (such as AVX2) provide
+//! no benefit. The following back-ends are used:
+//!
+//! - SSE4.1, on x86 and `x86_64`. If the target does not enable SSE4.1 at
+//!   compile-time, it is detected at runtime with the `std` feature.
+//! - NEON, on `aarch64`.
+//! - A scalar fallback, which parses 2 chunks of 8 digits using SWAR.
+//!
+//! These are only used for decimal strings without digit separators.
+//! With the `safe` feature, only the scalar fallback is used.
+
+#![cfg(feature = "simd")]
+#![doc(hidden)]
+
+use lexical_util::format::{NumberFormat, STANDARD};
+use lexical_util::iterator::DigitsIter;
+use lexical_util::num::Integer;
+
+use crate::algorithm::{is_8digits, parse_8digits};
+
+/// The number of digits parsed in a single chunk.
+pub const CHUNK_SIZE: usize = 16;
+
+/// The value of `10^16`, to scale the value by a single chunk.
+pub const CHUNK_POWER: u64 = 10_000_000_000_000_000;
+
+/// Parse 16 decimal digits, or return `None` if any byte is not a digit.
+#[inline(always)]
+pub fn parse_16digits(bytes: &[u8; CHUNK_SIZE]) -> Option<u64> {
+    #[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), not(feature = "safe")))]
+    if x86::is_supported() {
+        // SAFETY: safe since the required target features are supported.
+        return unsafe { x86::parse_16digits(bytes) };
+    }
+
+    #[cfg(all(target_arch = "aarch64", target_feature = "neon", not(feature = "safe")))]
+    // SAFETY: safe since NEON is enabled at compile-time.
+    return unsafe { neon::parse_16digits(bytes) };
+
+    #[cfg(not(all(target_arch = "aarch64", target_feature = "neon", not(feature = "safe"))))]
+    parse_16digits_scalar(bytes)
+}
+
+/// Parse 16 decimal digits without SIMD instructions.
+#[inline(always)]
+pub fn parse_16digits_scalar(bytes: &[u8; CHUNK_SIZE]) -> Option<u64> {
+    let mut hi = [0u8; 8];
+    let mut lo = [0u8; 8];
+    hi.copy_from_slice(&bytes[..8]);
+    lo.copy_from_slice(&bytes[8..]);
+    let hi = u64::from_le_bytes(hi);
+    let lo = u64::from_le_bytes(lo);
+    if is_8digits::<STANDARD>(hi) && is_8digits::<STANDARD>(lo) {
+        Some(parse_8digits::<STANDARD>(hi) * 100_000_000 + parse_8digits::<STANDARD>(lo))
+    } else {
+        None
+    }
+}
+
+/// Use a fast-path optimization, where we attempt to parse 16 digits at a
+/// time using SIMD instructions.
+#[inline(always)]
+pub fn try_parse_16digits<'a, T, Iter, const FORMAT: u128>(iter: &mut Iter) -> Option<T>
+where
+    T: Integer,
+    Iter: DigitsIter<'a>,
+{
+    // The SIMD routines only support contiguous, decimal digits.
+    if NumberFormat::<{ FORMAT }>::MANTISSA_RADIX != 10 || !Iter::IS_CONTIGUOUS {
+        return None;
+    }
+
+    let bytes = iter.as_slice().get(..CHUNK_SIZE)?.try_into().ok()?;
+    let value = parse_16digits(bytes)?;
+    // SAFETY: safe since we have at least 16 bytes in the buffer.
+    unsafe { iter.step_by_unchecked(CHUNK_SIZE) };
+    Some(T::as_cast(value))
+}
+
+#[cfg(all(any(target_arch = "x86", target_arch = "x86_64"), not(feature = "safe")))]
+mod x86 {
+    #[cfg(target_arch = "x86")]
+    use core::arch::x86::*;
+    #[cfg(target_arch = "x86_64")]
+    use core::arch::x86_64::*;
+
+    use super::CHUNK_SIZE;
+
+    /// Determine if the CPU supports the required target features.
+    #[inline(always)]
+    pub fn is_supported() -> bool {
+        #[cfg(all(target_feature = "ssse3", target_feature = "sse4.1"))]
+        return true;
+
+        #[cfg(all(
+            not(all(target_feature = "ssse3", target_feature = "sse4.1")),
+            feature = "std"
+        ))]
+        return std::is_x86_feature_detected!("ssse3") && std::is_x86_feature_detected!("sse4.1");
+
+        #[cfg(all(
+            not(all(target_feature = "ssse3", target_feature = "sse4.1")),
+            not(feature = "std")
+        ))]
+        return false;
+    }
+
+    /// Parse 16 decimal digits, or return `None` if any byte is not a digit.
+    ///
+    /// # Safety
+    ///
+    /// Safe as long as the CPU supports SSSE3 and SSE4.1.
+    #[target_feature(enable = "sse2,ssse3,sse4.1")]
+    pub unsafe fn parse_16digits(bytes: &[u8; CHUNK_SIZE]) -> Option<u64> {
+        // SAFETY: safe since the buffer is 16 bytes, the load is unaligned,
+        // and the caller guarantees the target features are supported.
+        unsafe {
+            let chunk = _mm_loadu_si128(bytes.as_ptr().cast());
+
+            // Normalize our digits, and validate they're all in the range `[0, 9]`.
+            let digits = _mm_sub_epi8(chunk, _mm_set1_epi8(b'0' as i8));
+            let nine = _mm_set1_epi8(9);
+            let is_valid = _mm_cmpeq_epi8(_mm_max_epu8(digits, nine), nine);
+            if _mm_movemask_epi8(is_valid) != 0xFFFF {
+                return None;
+            }
+
+            // Combine adjacent digits into 2, 4, then 8-digit values.
+            let mul_10 = _mm_setr_epi8(10, 1, 10, 1, 10, 1, 10, 1, 10, 1, 10, 1, 10, 1, 10, 1);
+            let v2 = _mm_maddubs_epi16(digits, mul_10);
+            let mul_100 = _mm_setr_epi16(100, 1, 100, 1, 100, 1, 100, 1);
+            let v4 = _mm_madd_epi16(v2, mul_100);
+            let v4 = _mm_packus_epi32(v4, v4);
+            let mul_10000 = _mm_setr_epi16(10000, 1, 10000, 1, 10000, 1, 10000, 1);
+            let v8 = _mm_madd_epi16(v4, mul_10000);
+
+            let first = _mm_cvtsi128_si32(v8) as u32 as u64;
+            let second = _mm_extract_epi32::<1>(v8) as u32 as u64;
+            Some(first * 100_000_000 + second)
+        }
+    }
+}
+
+#[cfg(all(target_arch = "aarch64", target_feature = "neon", not(feature = "safe")))]
+mod neon {
+    use core::arch::aarch64::*;
+
+    use super::CHUNK_SIZE;
+
+    /// Parse 16 decimal digits, or return `None` if any byte is not a digit.
+    ///
+    /// # Safety
+    ///
+    /// Safe as long as the CPU supports NEON.
+    #[inline(always)]
+    pub unsafe fn parse_16digits(bytes: &[u8; CHUNK_SIZE]) -> Option<u64> {
+        // SAFETY: safe since the buffer is 16 bytes, and NEON is enabled.
+        unsafe {
+            let chunk = vld1q_u8(bytes.as_ptr());
+
+            // Normalize our digits, and validate they're all in the range `[0, 9]`.
+            let digits = vsubq_u8(chunk, vdupq_n_u8(b'0'));
+            if vmaxvq_u8(digits) > 9 {
+                return None;
+            }
+
+            // Combine adjacent digits into 2, 4, then 8-digit values. The
+            // lanes are little-endian, so the leading digits are in the low half.
+            let v = vreinterpretq_u16_u8(digits);
+            let v2 = vmlaq_n_u16(vshrq_n_u16::<8>(v), vandq_u16(v, vdupq_n_u16(0xFF)), 10);
+            let v = vreinterpretq_u32_u16(v2);
+            let v4 = vmlaq_n_u32(vshrq_n_u32::<16>(v), vandq_u32(v, vdupq_n_u32(0xFFFF)), 100);
+            // NEON has no 64-bit multiply-accumulate, but the products fit in 32 bits.
+            let v = vreinterpretq_u64_u32(v4);
+            let leading = vmovn_u64(v);
+            let trailing = vshrn_n_u64::<32>(v);
+            let v8 = vmovl_u32(vmla_n_u32(trailing, leading, 10000));
+
+            let first = vgetq_lane_u64::<0>(v8);
+            let second = vgetq_lane_u64::<1>(v8);
+            Some(first * 100_000_000 + second)
+        }
+    }
+}
@@ -0,0 +1,175 @@
+//! Parse integers from strings containing Unicode decimal digits.
+//!
+//! Any decimal digit (general category `Nd`) is accepted, such as the
+//! Arabic-Indic `٠١٢`, Devanagari `०१२`, or fullwidth `０１２` digits,
+//! and digits from different scripts may be mixed. ASCII digits are
+//! converted with [`char::to_digit`], so they are always accepted.
+//!
+//! These routines are not optimized, and only support the standard,
+//! decimal format: an optional `+` or `-` sign followed by digits.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use lexical_parse_integer::FromLexicalUnicode;
+//!
+//! assert_eq!(u32::from_lexical_str("٤٢"), Ok(42));
+//! assert_eq!(i32::from_lexical_str("-१२३"), Ok(-123));
+//! assert_eq!(u8::from_lexical_chars(&['１', '２']), Ok(12));
+//! ```
+
+#![cfg(feature = "unicode")]
+
+use lexical_util::error::Error;
+use lexical_util::num::Integer;
+use lexical_util::result::Result;
+
+/// Code point of the zero for each run of Unicode decimal digits.
+///
+/// Unicode guarantees decimal digits are encoded in contiguous runs
+/// from 0 to 9. This table is current as of Unicode 15.0.
+const DECIMAL_ZEROS: [u32; 68] = [
+    0x30, 0x660, 0x6F0, 0x7C0, 0x966, 0x9E6, 0xA66, 0xAE6, 0xB66, 0xBE6, 0xC66, 0xCE6, 0xD66,
+    0xDE6, 0xE50, 0xED0, 0xF20, 0x1040, 0x1090, 0x17E0, 0x1810, 0x1946, 0x19D0, 0x1A80, 0x1A90,
+    0x1B50, 0x1BB0, 0x1C40, 0x1C50, 0xA620, 0xA8D0, 0xA900, 0xA9D0, 0xA9F0, 0xAA50, 0xABF0, 0xFF10,
+    0x104A0, 0x10D30, 0x11066, 0x110F0, 0x11136, 0x111D0, 0x112F0, 0x11450, 0x114D0, 0x11650,
+    0x116C0, 0x11730, 0x118E0, 0x11950, 0x11C50, 0x11D50, 0x11DA0, 0x11F50, 0x16A60, 0x16AC0,
+    0x16B50, 0x1D7CE, 0x1D7D8, 0x1D7E2, 0x1D7EC, 0x1D7F6, 0x1E140, 0x1E2F0, 0x1E4F0, 0x1E950,
+    0x1FBF0,
+];
+
+/// Convert a Unicode decimal digit to its value.
+#[inline]
+pub fn unicode_to_digit(c: char) -> Option<u32> {
+    if let Some(digit) = c.to_digit(10) {
+        return Some(digit);
+    }
+    let c = c as u32;
+    let index = match DECIMAL_ZEROS.binary_search(&c) {
+        Ok(_) => return Some(0),
+        Err(0) => return None,
+        Err(index) => index - 1,
+    };
+    let digit = c - DECIMAL_ZEROS[index];
+    if digit < 10 {
+        Some(digit)
+    } else {
+        None
+    }
+}
+
+/// Convert a single character to a digit, or return from the parser.
+macro_rules! parse_digit {
+    ($c:ident, $index:ident, $value:ident, $is_partial:ident) => {
+        match unicode_to_digit($c) {
+            Some(digit) => T::from_u32(digit),
+            None if $is_partial => return Ok(($value, $index)),
+            None => return Err(Error::InvalidDigit($index)),
+        }
+    };
+}
+
+/// Parse the sign and digits from an iterator of indexes and characters.
+///
+/// Returns the value and the index after the last digit processed, where
+/// `length` is the index after the last character.
+fn parse_chars<T, Iter>(mut iter: Iter, length: usize, is_partial: bool) -> Result<(T, usize)>
+where
+    T: Integer,
+    Iter: Iterator<Item = (usize, char)> + Clone,
+{
+    let mut is_negative = false;
+    let mut peek = iter.clone();
+    match peek.next() {
+        Some((_, '+')) => iter = peek,
+        Some((_, '-')) if T::IS_SIGNED => {
+            is_negative = true;
+            iter = peek;
+        },
+        _ => (),
+    }
+
+    if iter.clone().next().is_none() {
+        return Err(Error::Empty(length));
+    }
+
+    // Parse the digits that cannot overflow with wrapping arithmetic,
+    // and only check for overflow on the remaining digits.
+    let radix = T::from_u32(10);
+    let mut value = T::ZERO;
+    for (index, c) in iter.by_ref().take(T::overflow_digits(10)) {
+        let digit = parse_digit!(c, index, value, is_partial);
+        value = value.wrapping_mul(radix);
+        value = if is_negative {
+            value.wrapping_sub(digit)
+        } else {
+            value.wrapping_add(digit)
+        };
+    }
+    for (index, c) in iter {
+        let digit = parse_digit!(c, index, value, is_partial);
+        value = if is_negative {
+            value
+                .checked_mul(radix)
+                .and_then(|x| x.checked_sub(digit))
+                .ok_or(Error::Underflow(index))?
+        } else {
+            value
+                .checked_mul(radix)
+                .and_then(|x| x.checked_add(digit))
+                .ok_or(Error::Overflow(index))?
+        };
+    }
+
+    Ok((value, length))
+}
+
+/// Trait for integers that can be parsed from Unicode decimal digits.
+///
+/// Error indexes and the number of processed elements are in characters
+/// for the `chars` parsers, and in bytes for the `str` parsers.
+pub trait FromLexicalUnicode: Integer {
+    /// Checked parser for a slice of characters.
+    ///
+    /// This method parses the entire string, returning an error if
+    /// any invalid digits are found during parsing.
+    #[inline]
+    fn from_lexical_chars(chars: &[char]) -> Result<Self> {
+        parse_chars(chars.iter().copied().enumerate(), chars.len(), false).map(|(value, _)| value)
+    }
+
+    /// Checked parser for a slice of characters, stopping at the first
+    /// invalid digit.
+    ///
+    /// Returns the value and the number of characters processed.
+    #[inline]
+    fn from_lexical_chars_partial(chars: &[char]) -> Result<(Self, usize)> {
+        parse_chars(chars.iter().copied().enumerate(), chars.len(), true)
+    }
+
+    /// Checked parser for a string.
+    ///
+    /// This method parses the entire string, returning an error if
+    /// any invalid digits are found during parsing.
+    #[inline]
+    fn from_lexical_str(string: &str) -> Result<Self> {
+        parse_chars(string.char_indices(), string.len(), false).map(|(value, _)| value)
+    }
+
+    /// Checked parser for a string, stopping at the first invalid digit.
+    ///
+    /// Returns the value and the number of bytes processed.
+    #[inline]
+    fn from_lexical_str_partial(string: &str) -> Result<(Self, usize)> {
+        parse_chars(string.char_indices(), string.len(), true)
+    }
+}
+
+macro_rules! from_lexical_unicode_impl {
+    ($($t:ty)*) => ($(
+        impl FromLexicalUnicode for $t {}
+    )*)
+}
+
+from_lexical_unicode_impl! { u8 u16 u32 u64 u128 usize }
+from_lexical_unicode_impl! { i8 i16 i32 i64 i128 isize }
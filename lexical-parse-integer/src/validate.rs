@@ -0,0 +1,39 @@
+//! Check if bytes are a valid integer, without using the value.
+
+use crate::api::{FromLexical, FromLexicalWithOptions};
+
+/// Check if bytes are a complete, valid integer of type `T`, using the
+/// standard format.
+///
+/// This checks the same grammar as [`FromLexical::from_lexical`],
+/// including that the integer does not overflow `T`, which is cheap since
+/// the digits are accumulated anyway.
+///
+/// # Examples
+///
+/// ```rust
+/// use lexical_parse_integer::is_integer;
+///
+/// assert!(is_integer::<u8>(b"255"));
+/// assert!(!is_integer::<u8>(b"256"));
+/// assert!(is_integer::<i64>(b"-12"));
+/// assert!(!is_integer::<i64>(b"1.0"));
+/// ```
+#[inline]
+pub fn is_integer<T: FromLexical>(bytes: &[u8]) -> bool {
+    T::from_lexical(bytes).is_ok()
+}
+
+/// Check if bytes are a complete, valid integer of type `T`, using a
+/// custom format and options.
+///
+/// This checks the same grammar as
+/// [`FromLexicalWithOptions::from_lexical_with_options`], including
+/// overflow. Returns `false` if the format is invalid.
+#[inline]
+pub fn is_integer_with_options<T: FromLexicalWithOptions, const FORMAT: u128>(
+    bytes: &[u8],
+    options: &T::Options,
+) -> bool {
+    T::from_lexical_with_options::<FORMAT>(bytes, options).is_ok()
+}
@@ -1,6 +1,13 @@
 mod util;
 
-use lexical_parse_integer::{FromLexical, FromLexicalWithOptions, Options};
+use lexical_parse_integer::{
+    is_integer,
+    is_integer_with_options,
+    FromLexical,
+    FromLexicalWithOptions,
+    Options,
+};
+use lexical_util::alphabet;
 use lexical_util::error::Error;
 #[cfg(feature = "format")]
 use lexical_util::format::NumberFormatBuilder;
@@ -154,6 +161,92 @@ fn options_test() {
     assert_eq!(Ok(0), i128::from_lexical_with_options::<STANDARD>(b"0", &options));
 }
 
+#[test]
+fn alphabet_test() {
+    let options = Options::builder().alphabet(Some(alphabet::BASE58)).build().unwrap();
+    assert_eq!(Ok(0), u8::from_lexical_with_options::<STANDARD>(b"1", &options));
+    assert_eq!(Ok(58), u32::from_lexical_with_options::<STANDARD>(b"21", &options));
+    assert_eq!(Ok(-58), i32::from_lexical_with_options::<STANDARD>(b"-21", &options));
+    assert_eq!(Ok(u64::MAX), u64::from_lexical_with_options::<STANDARD>(b"jpXCZedGfVQ", &options));
+    assert_eq!(
+        Err(Error::InvalidDigit(1)),
+        u32::from_lexical_with_options::<STANDARD>(b"20", &options)
+    );
+    assert_eq!(Err(Error::Empty(1)), i32::from_lexical_with_options::<STANDARD>(b"-", &options));
+    assert_eq!(Err(Error::Overflow(1)), u8::from_lexical_with_options::<STANDARD>(b"5R", &options));
+    assert_eq!(
+        Err(Error::Overflow(8)),
+        u8::from_lexical_with_options::<STANDARD>(b"11111115R", &options)
+    );
+    assert_eq!(Ok(255), u8::from_lexical_with_options::<STANDARD>(b"111111115Q", &options));
+    assert_eq!(
+        Err(Error::Underflow(2)),
+        i8::from_lexical_with_options::<STANDARD>(b"-3J", &options)
+    );
+    assert_eq!(Ok((58, 2)), u32::from_lexical_partial_with_options::<STANDARD>(b"21l", &options));
+
+    let options = Options::builder().alphabet(Some(alphabet::BASE62)).build().unwrap();
+    assert_eq!(Ok(u64::MAX), u64::from_lexical_with_options::<STANDARD>(b"LygHa16AHYF", &options));
+    assert_eq!(Ok(i32::MIN), i32::from_lexical_with_options::<STANDARD>(b"-2LKcb2", &options));
+
+    let options = Options::builder().alphabet(Some(alphabet::BASE32)).build().unwrap();
+    assert_eq!(Ok(0xFFFFF), u32::from_lexical_with_options::<STANDARD>(b"7777", &options));
+    assert_eq!(
+        Err(Error::InvalidDigit(0)),
+        u32::from_lexical_with_options::<STANDARD>(b"a777", &options)
+    );
+}
+
+#[test]
+fn ignored_chars_test() {
+    let options = Options::builder().ignored_chars(Some(b"$, ")).build().unwrap();
+    assert_eq!(Ok(1234567), u32::from_lexical_with_options::<STANDARD>(b"$1,234,567", &options));
+    assert_eq!(Ok(-1234), i32::from_lexical_with_options::<STANDARD>(b"-$ 1 234 ", &options));
+    assert_eq!(Ok(12), u8::from_lexical_with_options::<STANDARD>(b"12", &options));
+    assert_eq!(
+        Err(Error::InvalidDigit(4)),
+        u32::from_lexical_with_options::<STANDARD>(b"$1,2x3", &options)
+    );
+    assert_eq!(
+        Err(Error::Overflow(4)),
+        u8::from_lexical_with_options::<STANDARD>(b"$2,56", &options)
+    );
+    assert_eq!(Err(Error::Empty(2)), u32::from_lexical_with_options::<STANDARD>(b", ", &options));
+    assert_eq!(
+        Ok((1234, 6)),
+        u32::from_lexical_partial_with_options::<STANDARD>(b"1,234 USD", &options)
+    );
+    assert_eq!(Ok((12, 4)), u32::from_lexical_partial_with_options::<STANDARD>(b"1,2,", &options));
+
+    // Multi-byte characters are skipped by ignoring each of their bytes.
+    let options = Options::builder().ignored_chars(Some("\u{a0}".as_bytes())).build().unwrap();
+    assert_eq!(
+        Ok(1234567),
+        u64::from_lexical_with_options::<STANDARD>("1\u{a0}234\u{a0}567".as_bytes(), &options)
+    );
+
+    // Long inputs are only limited by the stack buffer without `std`.
+    let options = Options::builder().ignored_chars(Some(b"_")).build().unwrap();
+    let mut digits = "0_".repeat(127);
+    digits.push('1');
+    assert_eq!(Ok(1), u128::from_lexical_with_options::<STANDARD>(digits.as_bytes(), &options));
+    let mut digits = "0_".repeat(200);
+    digits.push('1');
+    #[cfg(feature = "std")]
+    {
+        assert_eq!(Ok(1), u128::from_lexical_with_options::<STANDARD>(digits.as_bytes(), &options));
+        assert_eq!(
+            Err(Error::InvalidDigit(401)),
+            u128::from_lexical_with_options::<STANDARD>(format!("{digits}x").as_bytes(), &options)
+        );
+    }
+    #[cfg(not(feature = "std"))]
+    assert_eq!(
+        Err(Error::TooManyDigits(256)),
+        u128::from_lexical_with_options::<STANDARD>(digits.as_bytes(), &options)
+    );
+}
+
 #[test]
 #[cfg(feature = "power-of-two")]
 fn i32_binary_test() {
@@ -222,6 +315,118 @@ fn i32_no_leading_zeros_test() {
     assert!(i32::from_lexical_with_options::<FORMAT>(b"010", &options).is_err());
 }
 
+#[test]
+#[cfg(feature = "format")]
+fn i32_skip_whitespace_test() {
+    let options = Options::new();
+    const BOTH: u128 = NumberFormatBuilder::new().skip_whitespace(true).build();
+    assert_eq!(i32::from_lexical_with_options::<BOTH>(b" \t-12\r\n", &options), Ok(-12));
+    assert_eq!(i32::from_lexical_with_options::<BOTH>(b"12", &options), Ok(12));
+    assert_eq!(
+        i32::from_lexical_with_options::<BOTH>(b"  1 2", &options),
+        Err(Error::InvalidDigit(3))
+    );
+    assert_eq!(i32::from_lexical_with_options::<BOTH>(b"   ", &options), Err(Error::Empty(3)));
+    assert_eq!(i32::from_lexical_partial_with_options::<BOTH>(b" 12  x", &options), Ok((12, 5)));
+
+    const LEADING: u128 = NumberFormatBuilder::new().skip_leading_whitespace(true).build();
+    assert_eq!(i32::from_lexical_with_options::<LEADING>(b"  12", &options), Ok(12));
+    assert_eq!(
+        i32::from_lexical_with_options::<LEADING>(b"  12 ", &options),
+        Err(Error::InvalidDigit(4))
+    );
+    assert_eq!(i32::from_lexical_partial_with_options::<LEADING>(b" 12 ", &options), Ok((12, 3)));
+
+    const TRAILING: u128 = NumberFormatBuilder::new().skip_trailing_whitespace(true).build();
+    assert_eq!(i32::from_lexical_with_options::<TRAILING>(b"12\n", &options), Ok(12));
+    assert_eq!(
+        i32::from_lexical_with_options::<TRAILING>(b" 12", &options),
+        Err(Error::InvalidDigit(0))
+    );
+}
+
+#[test]
+#[cfg(feature = "format")]
+fn i32_unicode_signs_test() {
+    let options = Options::new();
+    const FORMAT: u128 = NumberFormatBuilder::new().unicode_signs(true).build();
+    assert_eq!(
+        i32::from_lexical_with_options::<FORMAT>("\u{2212}12".as_bytes(), &options),
+        Ok(-12)
+    );
+    assert_eq!(
+        i32::from_lexical_with_options::<FORMAT>("\u{FF0D}12".as_bytes(), &options),
+        Ok(-12)
+    );
+    assert_eq!(i32::from_lexical_with_options::<FORMAT>("\u{FF0B}12".as_bytes(), &options), Ok(12));
+    assert_eq!(i32::from_lexical_with_options::<FORMAT>(b"-12", &options), Ok(-12));
+    assert_eq!(
+        i32::from_lexical_with_options::<FORMAT>("\u{2212}2147483648".as_bytes(), &options),
+        Ok(i32::MIN)
+    );
+    assert_eq!(
+        i32::from_lexical_with_options::<FORMAT>("\u{2212}".as_bytes(), &options),
+        Err(Error::Empty(3))
+    );
+    assert_eq!(
+        i32::from_lexical_partial_with_options::<FORMAT>("\u{2212}12 ".as_bytes(), &options),
+        Ok((-12, 5))
+    );
+    assert_eq!(
+        u32::from_lexical_with_options::<FORMAT>("\u{2212}12".as_bytes(), &options),
+        Err(Error::InvalidDigit(0))
+    );
+    assert_eq!(
+        i32::from_lexical_with_options::<STANDARD>("\u{2212}12".as_bytes(), &options),
+        Err(Error::InvalidDigit(0))
+    );
+
+    const NO_POSITIVE: u128 =
+        NumberFormatBuilder::new().unicode_signs(true).no_positive_mantissa_sign(true).build();
+    assert_eq!(
+        i32::from_lexical_with_options::<NO_POSITIVE>("\u{FF0B}12".as_bytes(), &options),
+        Err(Error::InvalidPositiveSign(0))
+    );
+}
+
+#[test]
+#[cfg(feature = "format")]
+fn i32_parenthesized_negatives_test() {
+    let options = Options::new();
+    const FORMAT: u128 = NumberFormatBuilder::new().parenthesized_negatives(true).build();
+    assert_eq!(i32::from_lexical_with_options::<FORMAT>(b"(12)", &options), Ok(-12));
+    assert_eq!(i32::from_lexical_with_options::<FORMAT>(b"-12", &options), Ok(-12));
+    assert_eq!(i32::from_lexical_with_options::<FORMAT>(b"12", &options), Ok(12));
+    assert_eq!(i32::from_lexical_with_options::<FORMAT>(b"(2147483648)", &options), Ok(i32::MIN));
+    assert_eq!(
+        i32::from_lexical_with_options::<FORMAT>(b"(12", &options),
+        Err(Error::InvalidDigit(3))
+    );
+    assert_eq!(
+        i32::from_lexical_with_options::<FORMAT>(b"12)", &options),
+        Err(Error::InvalidDigit(2))
+    );
+    assert_eq!(
+        i32::from_lexical_with_options::<FORMAT>(b"(-12)", &options),
+        Err(Error::InvalidDigit(1))
+    );
+    assert_eq!(i32::from_lexical_with_options::<FORMAT>(b"()", &options), Err(Error::Empty(1)));
+    assert_eq!(
+        u32::from_lexical_with_options::<FORMAT>(b"(12)", &options),
+        Err(Error::InvalidDigit(0))
+    );
+    assert_eq!(i32::from_lexical_partial_with_options::<FORMAT>(b"(12),", &options), Ok((-12, 4)));
+    assert_eq!(
+        i32::from_lexical_partial_with_options::<FORMAT>(b"(12,", &options),
+        Err(Error::InvalidDigit(3))
+    );
+
+    const SKIP: u128 =
+        NumberFormatBuilder::new().parenthesized_negatives(true).skip_whitespace(true).build();
+    assert_eq!(i32::from_lexical_with_options::<SKIP>(b" (12) ", &options), Ok(-12));
+    assert_eq!(i32::from_lexical_partial_with_options::<SKIP>(b" (12) ;", &options), Ok((-12, 6)));
+}
+
 #[test]
 #[cfg(feature = "format")]
 fn i32_integer_internal_digit_separator_test() {
@@ -315,6 +520,19 @@ fn base_prefix_test() {
     assert!(i32::from_lexical_partial_with_options::<FORMAT>(b"-0x012h ", &options).is_ok());
 }
 
+#[test]
+#[cfg(all(feature = "power-of-two", feature = "format"))]
+fn json5_hex_test() {
+    use lexical_util::format::JSON5_HEX;
+
+    let options = Options::new();
+    assert_eq!(i32::from_lexical_with_options::<JSON5_HEX>(b"0xFF", &options), Ok(255));
+    assert_eq!(i32::from_lexical_with_options::<JSON5_HEX>(b"+0XdecaF", &options), Ok(0xdecaf));
+    assert_eq!(i32::from_lexical_with_options::<JSON5_HEX>(b"-0x10", &options), Ok(-16));
+    assert!(i32::from_lexical_with_options::<JSON5_HEX>(b"0x", &options).is_err());
+    assert!(i32::from_lexical_with_options::<JSON5_HEX>(b"0xG", &options).is_err());
+}
+
 #[test]
 #[cfg(all(feature = "power-of-two", feature = "format"))]
 fn base_suffix_test() {
@@ -359,6 +577,41 @@ fn base_prefix_and_suffix_test() {
     assert!(i32::from_lexical_with_options::<FORMAT>(b"+0x", &options).is_err());
 }
 
+#[test]
+#[cfg(all(feature = "power-of-two", feature = "format"))]
+fn digit_case_test() {
+    use core::num;
+
+    const HEX: u128 = NumberFormatBuilder::new()
+        .mantissa_radix(16)
+        .base_prefix(num::NonZeroU8::new(b'x'))
+        .build();
+    const LOWER: u128 = NumberFormatBuilder::rebuild(HEX).no_uppercase_digits(true).build();
+    const UPPER: u128 = NumberFormatBuilder::rebuild(HEX)
+        .case_sensitive_base_prefix(true)
+        .no_lowercase_digits(true)
+        .build();
+    let options = Options::new();
+    assert_eq!(u32::from_lexical_with_options::<HEX>(b"0X1aBc", &options), Ok(0x1abc));
+    assert_eq!(u32::from_lexical_with_options::<LOWER>(b"0X1abc", &options), Ok(0x1abc));
+    assert_eq!(
+        u32::from_lexical_with_options::<LOWER>(b"0x1aBc", &options),
+        Err(Error::InvalidDigit(4))
+    );
+    assert_eq!(u32::from_lexical_partial_with_options::<LOWER>(b"1aBc", &options), Ok((0x1a, 2)));
+    assert_eq!(
+        u64::from_lexical_with_options::<LOWER>(b"ffffffffffffffff", &options),
+        Ok(u64::MAX)
+    );
+
+    assert_eq!(u32::from_lexical_with_options::<UPPER>(b"0x1ABC", &options), Ok(0x1abc));
+    assert!(u32::from_lexical_with_options::<UPPER>(b"0X1ABC", &options).is_err());
+    assert_eq!(
+        i32::from_lexical_with_options::<UPPER>(b"-0x1Abc", &options),
+        Err(Error::InvalidDigit(5))
+    );
+}
+
 macro_rules! is_error {
     ($result:expr, $check:ident) => {{
         let result = $result;
@@ -402,6 +655,23 @@ macro_rules! is_invalid_digit_match {
     }};
 }
 
+#[test]
+fn is_integer_test() {
+    assert!(is_integer::<u8>(b"0"));
+    assert!(is_integer::<u8>(b"255"));
+    assert!(!is_integer::<u8>(b"256"));
+    assert!(!is_integer::<u8>(b"-1"));
+    assert!(is_integer::<i8>(b"-128"));
+    assert!(!is_integer::<i8>(b"-129"));
+    assert!(!is_integer::<i32>(b""));
+    assert!(!is_integer::<i32>(b"1.0"));
+    assert!(!is_integer::<i32>(b"1 "));
+
+    let options = Options::new();
+    assert!(is_integer_with_options::<u16, STANDARD>(b"65535", &options));
+    assert!(!is_integer_with_options::<u16, STANDARD>(b"65536", &options));
+}
+
 proptest! {
     #![proptest_config(default_proptest_config())]
 
@@ -1,4 +1,7 @@
+#![allow(clippy::disallowed_macros)]
+
 use lexical_parse_integer::options::{Options, OptionsBuilder};
+use lexical_util::alphabet::{self, alphabet_inverse, DigitAlphabet};
 
 #[test]
 fn options_tests() {
@@ -15,3 +18,55 @@ fn options_tests() {
     assert!(OptionsBuilder::default().is_valid());
     assert_eq!(options.rebuild(), Options::builder());
 }
+
+#[test]
+fn alphabet_tests() {
+    let builder = OptionsBuilder::new().alphabet(Some(alphabet::BASE62));
+    assert!(builder.is_valid());
+    assert_eq!(builder.get_alphabet(), Some(alphabet::BASE62));
+
+    let mut options = builder.build().unwrap();
+    assert_eq!(options.get_alphabet(), Some(alphabet::BASE62));
+    options.alphabet(None);
+    assert_eq!(options.get_alphabet(), None);
+
+    const DIGITS: &[u8] = b"0-1";
+    const INVERSE: [u8; 256] = alphabet_inverse(DIGITS);
+    let builder = OptionsBuilder::new().alphabet(Some(DigitAlphabet::new(DIGITS, &INVERSE)));
+    assert!(!builder.is_valid());
+    assert!(builder.build().is_err());
+}
+
+#[test]
+fn ignored_chars_tests() {
+    let builder = OptionsBuilder::new().ignored_chars(Some(b"$ "));
+    assert!(builder.is_valid());
+    assert_eq!(builder.get_ignored_chars(), Some(b"$ " as &[u8]));
+
+    let mut options = builder.build().unwrap();
+    assert_eq!(options.get_ignored_chars(), Some(b"$ " as &[u8]));
+    options.ignored_chars(None);
+    assert_eq!(options.get_ignored_chars(), None);
+
+    for invalid in [b"" as &[u8], b"$1", b"$a", b"$+", b"$-"] {
+        let builder = OptionsBuilder::new().ignored_chars(Some(invalid));
+        assert!(!builder.is_valid());
+        assert!(builder.build().is_err());
+    }
+
+    const DIGITS: &[u8] = b"0123456789!@";
+    const INVERSE: [u8; 256] = alphabet_inverse(DIGITS);
+    let builder = OptionsBuilder::new()
+        .alphabet(Some(DigitAlphabet::new(DIGITS, &INVERSE)))
+        .ignored_chars(Some(b"@"));
+    assert!(!builder.is_valid());
+    assert!(builder.build().is_err());
+}
+
+#[cfg(feature = "proptest")]
+proptest::proptest! {
+    #[test]
+    fn arbitrary_options_proptest(options in proptest::arbitrary::any::<Options>()) {
+        proptest::prop_assert!(options.is_valid());
+    }
+}
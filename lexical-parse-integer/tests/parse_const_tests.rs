@@ -0,0 +1,73 @@
+#![allow(clippy::disallowed_macros)]
+
+mod util;
+
+use lexical_parse_integer::{parse_const, FromLexical};
+use lexical_util::error::Error;
+use proptest::prelude::*;
+
+use crate::util::default_proptest_config;
+
+const ANSWER: u32 = match parse_const::u32(b"42") {
+    Ok(value) => value,
+    Err(_) => panic!("invalid integer"),
+};
+
+#[test]
+fn const_test() {
+    assert_eq!(ANSWER, 42);
+}
+
+#[test]
+fn unsigned_test() {
+    assert_eq!(parse_const::u8(b"0"), Ok(0));
+    assert_eq!(parse_const::u8(b"+255"), Ok(255));
+    assert_eq!(parse_const::u8(b"00012"), Ok(12));
+    assert_eq!(parse_const::u8(b"256"), Err(Error::Overflow(2)));
+    assert_eq!(parse_const::u8(b"255x"), Err(Error::InvalidDigit(3)));
+    assert_eq!(parse_const::u8(b""), Err(Error::Empty(0)));
+    assert_eq!(parse_const::u8(b"+"), Err(Error::Empty(1)));
+    assert_eq!(parse_const::u8(b"-"), Err(Error::InvalidDigit(0)));
+    assert_eq!(parse_const::u8(b"-1"), Err(Error::InvalidDigit(0)));
+    assert_eq!(parse_const::u64(b"18446744073709551615"), Ok(u64::MAX));
+    assert_eq!(parse_const::u64(b"18446744073709551616"), Err(Error::Overflow(19)));
+    assert_eq!(parse_const::u128(b"340282366920938463463374607431768211455"), Ok(u128::MAX));
+}
+
+#[test]
+fn signed_test() {
+    assert_eq!(parse_const::i8(b"-128"), Ok(i8::MIN));
+    assert_eq!(parse_const::i8(b"+127"), Ok(i8::MAX));
+    assert_eq!(parse_const::i8(b"-0"), Ok(0));
+    assert_eq!(parse_const::i8(b"128"), Err(Error::Overflow(2)));
+    assert_eq!(parse_const::i8(b"-129"), Err(Error::Underflow(3)));
+    assert_eq!(parse_const::i8(b"-"), Err(Error::Empty(1)));
+    assert_eq!(parse_const::i8(b"-x"), Err(Error::InvalidDigit(1)));
+    assert_eq!(parse_const::i8(b"+-1"), Err(Error::InvalidDigit(1)));
+    assert_eq!(parse_const::i64(b"-9223372036854775808"), Ok(i64::MIN));
+    assert_eq!(parse_const::i128(b"-170141183460469231731687303715884105728"), Ok(i128::MIN));
+}
+
+proptest! {
+    #![proptest_config(default_proptest_config())]
+
+    #[test]
+    fn u64_proptest(i in r"[+-]?[0-9]{0,25}[x]?[0-9]{0,2}") {
+        prop_assert_eq!(parse_const::u64(i.as_bytes()), u64::from_lexical(i.as_bytes()));
+    }
+
+    #[test]
+    fn i8_proptest(i in r"[+-]?[0-9]{0,5}[x]?[0-9]{0,2}") {
+        prop_assert_eq!(parse_const::i8(i.as_bytes()), i8::from_lexical(i.as_bytes()));
+    }
+
+    #[test]
+    fn i128_proptest(i in r"[+-]?[0-9]{0,45}") {
+        prop_assert_eq!(parse_const::i128(i.as_bytes()), i128::from_lexical(i.as_bytes()));
+    }
+
+    #[test]
+    fn u16_bytes_proptest(i: Vec<u8>) {
+        prop_assert_eq!(parse_const::u16(&i), u16::from_lexical(&i));
+    }
+}
@@ -0,0 +1,71 @@
+#![allow(clippy::disallowed_macros)]
+#![cfg(feature = "simd")]
+
+mod util;
+
+use lexical_parse_integer::options::LARGE_NUMBERS;
+use lexical_parse_integer::simd;
+use lexical_parse_integer::FromLexicalWithOptions;
+use lexical_util::error::Error;
+use lexical_util::format::STANDARD;
+use proptest::prelude::*;
+
+use crate::util::default_proptest_config;
+
+fn parse_16digits(bytes: &[u8; 16], expected: Option<u64>) {
+    assert_eq!(simd::parse_16digits(bytes), expected);
+    assert_eq!(simd::parse_16digits_scalar(bytes), expected);
+}
+
+#[test]
+fn parse_16digits_test() {
+    parse_16digits(b"0000000000000000", Some(0));
+    parse_16digits(b"1234567890123456", Some(1234567890123456));
+    parse_16digits(b"9999999999999999", Some(9999999999999999));
+    parse_16digits(b"0000000000000001", Some(1));
+    parse_16digits(b"1000000000000000", Some(1000000000000000));
+
+    // Every position must be validated.
+    for index in 0..16 {
+        for &c in b"/:a \x00\xFF" {
+            let mut bytes = *b"1234567890123456";
+            bytes[index] = c;
+            parse_16digits(&bytes, None);
+        }
+    }
+}
+
+#[test]
+fn large_numbers_test() {
+    let options = LARGE_NUMBERS;
+    let parse_u64 = |x: &[u8]| u64::from_lexical_with_options::<STANDARD>(x, &options);
+    let parse_i64 = |x: &[u8]| i64::from_lexical_with_options::<STANDARD>(x, &options);
+    let parse_u128 = |x: &[u8]| u128::from_lexical_with_options::<STANDARD>(x, &options);
+
+    assert_eq!(parse_u64(b"18446744073709551615"), Ok(u64::MAX));
+    assert_eq!(parse_u64(b"18446744073709551616"), Err(Error::Overflow(19)));
+    assert_eq!(parse_u64(b"1234567890123456"), Ok(1234567890123456));
+    assert_eq!(parse_u64(b"123456789012345a"), Err(Error::InvalidDigit(15)));
+    assert_eq!(parse_i64(b"-9223372036854775808"), Ok(i64::MIN));
+    assert_eq!(parse_i64(b"-9223372036854775809"), Err(Error::Underflow(19)));
+    assert_eq!(parse_u128(b"340282366920938463463374607431768211455"), Ok(u128::MAX));
+    assert_eq!(parse_u128(b"0000000000000000000000000000000001"), Ok(1));
+}
+
+proptest! {
+    #![proptest_config(default_proptest_config())]
+
+    #[test]
+    fn u64_large_numbers_proptest(i in r"[+]?[0-9]{2,30}") {
+        let options = LARGE_NUMBERS;
+        let result = u64::from_lexical_with_options::<STANDARD>(i.as_bytes(), &options);
+        prop_assert_eq!(result.ok(), i.parse::<u64>().ok());
+    }
+
+    #[test]
+    fn i128_large_numbers_proptest(i in r"[+-]?[0-9]{2,45}") {
+        let options = LARGE_NUMBERS;
+        let result = i128::from_lexical_with_options::<STANDARD>(i.as_bytes(), &options);
+        prop_assert_eq!(result.ok(), i.parse::<i128>().ok());
+    }
+}
@@ -0,0 +1,62 @@
+#![cfg(feature = "unicode")]
+
+use lexical_parse_integer::unicode::unicode_to_digit;
+use lexical_parse_integer::FromLexicalUnicode;
+use lexical_util::error::Error;
+
+#[test]
+fn unicode_to_digit_test() {
+    assert_eq!(unicode_to_digit('0'), Some(0));
+    assert_eq!(unicode_to_digit('9'), Some(9));
+    assert_eq!(unicode_to_digit('a'), None);
+    assert_eq!(unicode_to_digit('/'), None);
+    assert_eq!(unicode_to_digit('\u{0660}'), Some(0));
+    assert_eq!(unicode_to_digit('\u{0669}'), Some(9));
+    assert_eq!(unicode_to_digit('\u{066A}'), None);
+    assert_eq!(unicode_to_digit('\u{065F}'), None);
+    assert_eq!(unicode_to_digit('\u{096F}'), Some(9));
+    assert_eq!(unicode_to_digit('\u{FF15}'), Some(5));
+    assert_eq!(unicode_to_digit('\u{1D7CE}'), Some(0));
+    assert_eq!(unicode_to_digit('\u{1D7FF}'), Some(9));
+    assert_eq!(unicode_to_digit('\u{1FBF9}'), Some(9));
+    assert_eq!(unicode_to_digit('\u{1FBFA}'), None);
+    // Numeric, but not decimal digits.
+    assert_eq!(unicode_to_digit('\u{00B2}'), None);
+    assert_eq!(unicode_to_digit('\u{2167}'), None);
+}
+
+#[test]
+fn from_lexical_str_test() {
+    assert_eq!(u32::from_lexical_str("٠١٢"), Ok(12));
+    assert_eq!(u32::from_lexical_str("42"), Ok(42));
+    assert_eq!(u32::from_lexical_str("+४२"), Ok(42));
+    assert_eq!(i32::from_lexical_str("-١٢٣"), Ok(-123));
+    assert_eq!(u16::from_lexical_str("1٢३"), Ok(123));
+    assert_eq!(i8::from_lexical_str("-١٢٨"), Ok(-128));
+    assert_eq!(u8::from_lexical_str("٠٠٠٠٠٠٠٠٢٥٥"), Ok(255));
+    assert_eq!(i16::from_lexical_str("-٠٠٠٠٣٢٧٦٨"), Ok(i16::MIN));
+
+    // Indexes are in bytes.
+    assert_eq!(u32::from_lexical_str(""), Err(Error::Empty(0)));
+    assert_eq!(i32::from_lexical_str("-"), Err(Error::Empty(1)));
+    assert_eq!(u32::from_lexical_str("-١"), Err(Error::InvalidDigit(0)));
+    assert_eq!(u32::from_lexical_str("١x"), Err(Error::InvalidDigit(2)));
+    assert_eq!(u8::from_lexical_str("٢٥٦"), Err(Error::Overflow(4)));
+    assert_eq!(i8::from_lexical_str("-١٢٩"), Err(Error::Underflow(5)));
+
+    assert_eq!(u32::from_lexical_str_partial("١٢x"), Ok((12, 4)));
+    assert_eq!(u32::from_lexical_str_partial("x"), Ok((0, 0)));
+}
+
+#[test]
+fn from_lexical_chars_test() {
+    assert_eq!(u32::from_lexical_chars(&['٠', '١', '٢']), Ok(12));
+    assert_eq!(i64::from_lexical_chars(&['-', '１', '０']), Ok(-10));
+
+    // Indexes are in characters.
+    assert_eq!(u32::from_lexical_chars(&[]), Err(Error::Empty(0)));
+    assert_eq!(u32::from_lexical_chars(&['١', 'x']), Err(Error::InvalidDigit(1)));
+    assert_eq!(u8::from_lexical_chars(&['٢', '٥', '٦']), Err(Error::Overflow(2)));
+
+    assert_eq!(u32::from_lexical_chars_partial(&['١', '٢', 'x']), Ok((12, 2)));
+}
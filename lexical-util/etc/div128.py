@@ -128,13 +128,12 @@ def print_moderate(radix, divisor, factor, factor_shr, suffix):
     print('')
 
 
-def print_slow(radix, divisor, suffix):
-    '''Print the function for the slow division algorithm.'''
+def print_wide(radix, divisor, factor, factor_shr, suffix):
+    '''Print the function for the division algorithm with a 129-bit multiplier.'''
 
-    ctlz = 66 - len(bin(divisor))
     print('#[inline(always)]')
     print(f'fn u128_divrem_{radix}{suffix}(n: u128) -> (u128, u64) {{')
-    print(f'    slow_u128_divrem(n, {divisor}, {ctlz})')
+    print(f'    wide_u128_divrem(n, {divisor}, {factor - 2**128}, {factor_shr})')
     print('}')
     print('')
 
@@ -153,8 +152,9 @@ def print_radix(radix, digits=None):
     factor, factor_shr, _ = choose_multiplier(divisor, 128)
 
     if factor >= 2**128:
-        # Cannot fit in a u128, must revert to the slow algorithm.
-        print_slow(radix, divisor, suffix)
+        # Cannot fit in a u128, must use the implicit high bit.
+        assert factor < 2**129 and factor_shr >= 1
+        print_wide(radix, divisor, factor, factor_shr, suffix)
     elif fast_shr != 0:
         print_fast(radix, divisor, fast_shr, factor, factor_shr, suffix)
     else:
@@ -203,7 +203,10 @@ def udiv128(n, divisor=10**19):
 
     factor, factor_shr, _ = choose_multiplier(divisor, 128)
     shr = fast_shift(divisor)
-    if n < (1 << (64 + shr)):
+    if factor >= 2**128:
+        high = u128_mulhi(n, factor - 2**128)
+        quotient = (high + ((n - high) >> 1)) >> (factor_shr - 1)
+    elif n < (1 << (64 + shr)):
         quotient = (n >> shr) // (divisor >> shr)
     else:
         quotient = u128_mulhi(n, factor) >> factor_shr
@@ -1,5 +1,8 @@
 //! Simple, shared algorithms for slices and iterators.
 
+#[cfg(feature = "write")]
+use core::mem::MaybeUninit;
+
 use crate::num::Integer;
 
 /// Copy bytes from source to destination.
@@ -15,6 +18,22 @@ pub fn copy_to_dst<T: Copy, Bytes: AsRef<[T]>>(dst: &mut [T], src: Bytes) -> usi
     src.len()
 }
 
+/// Initialize up to `count` bytes of an uninitialized buffer with zeros.
+///
+/// Returns the initialized prefix, which is shorter than `count` if the
+/// buffer is too small. Only the prefix is initialized, so the cost does
+/// not depend on the length of the buffer.
+#[inline(always)]
+#[cfg(feature = "write")]
+pub fn init_prefix(bytes: &mut [MaybeUninit<u8>], count: usize) -> &mut [u8] {
+    let count = count.min(bytes.len());
+    let prefix = &mut bytes[..count];
+    prefix.fill(MaybeUninit::new(0));
+    // SAFETY: safe since every byte in the prefix was initialized, and
+    // `MaybeUninit<u8>` has the same layout as `u8`.
+    unsafe { &mut *(prefix as *mut [MaybeUninit<u8>] as *mut [u8]) }
+}
+
 /// Count the number of trailing characters equal to a given value.
 #[inline(always)]
 #[cfg(feature = "write")]
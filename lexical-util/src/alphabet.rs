@@ -0,0 +1,184 @@
+//! Custom digit alphabets for integer conversions.
+//!
+//! By default, digits are written and parsed using the `0-9A-Z` mapping,
+//! case-insensitive when parsing. A [`DigitAlphabet`] replaces that mapping
+//! with a user-supplied table of digits, and its inverse, so integers can
+//! be converted to and from encodings like base58, base62, or RFC 4648
+//! base32. The radix is the number of digits in the alphabet, and is not
+//! limited to 36.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use lexical_util::alphabet::{alphabet_inverse, DigitAlphabet};
+//!
+//! const DIGITS: &[u8] = b"01234567";
+//! const INVERSE: [u8; 256] = alphabet_inverse(DIGITS);
+//! const OCTAL: DigitAlphabet = DigitAlphabet::new(DIGITS, &INVERSE);
+//!
+//! assert!(OCTAL.is_valid());
+//! assert_eq!(OCTAL.radix(), 8);
+//! assert_eq!(OCTAL.char_to_digit(b'7'), Some(7));
+//! assert_eq!(OCTAL.char_to_digit(b'8'), None);
+//! ```
+
+#![cfg(feature = "integers")]
+
+/// Marker in the inverse table for a character that is not a digit.
+pub const INVALID_DIGIT: u8 = 0xFF;
+
+/// Minimum number of digits in an alphabet.
+pub const MIN_ALPHABET_LENGTH: usize = 2;
+
+/// Maximum number of digits in an alphabet.
+///
+/// This is the number of printable ASCII characters, excluding
+/// the space and the `+` and `-` signs.
+pub const MAX_ALPHABET_LENGTH: usize = 92;
+
+/// Create the inverse of a digit table, mapping characters to digits.
+///
+/// Characters not present in the table are mapped to [`INVALID_DIGIT`].
+/// If a character is repeated, the last occurrence is used, however,
+/// such an alphabet is invalid.
+pub const fn alphabet_inverse(digits: &[u8]) -> [u8; 256] {
+    let mut inverse = [INVALID_DIGIT; 256];
+    let mut index = 0;
+    while index < digits.len() && index < INVALID_DIGIT as usize {
+        inverse[digits[index] as usize] = index as u8;
+        index += 1;
+    }
+    inverse
+}
+
+/// A table of digits, and its inverse, for custom integer encodings.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct DigitAlphabet {
+    /// Characters for each digit, in ascending order.
+    digits: &'static [u8],
+    /// Digit for each character, or [`INVALID_DIGIT`].
+    inverse: &'static [u8; 256],
+}
+
+impl DigitAlphabet {
+    /// Create a new alphabet from a digit table and its inverse.
+    ///
+    /// The inverse can be created with [`alphabet_inverse`].
+    #[inline(always)]
+    pub const fn new(digits: &'static [u8], inverse: &'static [u8; 256]) -> Self {
+        Self {
+            digits,
+            inverse,
+        }
+    }
+
+    /// Get the characters for each digit.
+    #[inline(always)]
+    pub const fn digits(&self) -> &'static [u8] {
+        self.digits
+    }
+
+    /// Get the digit for each character.
+    #[inline(always)]
+    pub const fn inverse(&self) -> &'static [u8; 256] {
+        self.inverse
+    }
+
+    /// Get the radix of the alphabet.
+    #[inline(always)]
+    pub const fn radix(&self) -> u32 {
+        self.digits.len() as u32
+    }
+
+    /// Check if the alphabet is valid.
+    ///
+    /// A valid alphabet has between [`MIN_ALPHABET_LENGTH`] and
+    /// [`MAX_ALPHABET_LENGTH`] unique, printable ASCII digits that are
+    /// not signs, and an inverse that exactly maps each digit back to
+    /// its index.
+    pub const fn is_valid(&self) -> bool {
+        let length = self.digits.len();
+        if length < MIN_ALPHABET_LENGTH || length > MAX_ALPHABET_LENGTH {
+            return false;
+        }
+
+        let mut index = 0;
+        while index < length {
+            let c = self.digits[index];
+            let is_digit = c > b' ' && c < 0x7F && c != b'+' && c != b'-';
+            if !is_digit || self.inverse[c as usize] as usize != index {
+                return false;
+            }
+            index += 1;
+        }
+
+        // Every other character must be invalid, and that also
+        // guarantees the digits are unique.
+        let mut count = 0;
+        let mut c = 0;
+        while c < 256 {
+            if self.inverse[c] != INVALID_DIGIT {
+                count += 1;
+            }
+            c += 1;
+        }
+        count == length
+    }
+
+    /// Convert a digit to a character.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the digit is not smaller than the radix.
+    #[inline(always)]
+    pub const fn digit_to_char(&self, digit: u32) -> u8 {
+        self.digits[digit as usize]
+    }
+
+    /// Convert a character to a digit, if it is a valid digit.
+    #[inline(always)]
+    pub const fn char_to_digit(&self, c: u8) -> Option<u32> {
+        match self.inverse[c as usize] {
+            INVALID_DIGIT => None,
+            digit => Some(digit as u32),
+        }
+    }
+}
+
+// PRE-DEFINED ALPHABETS
+// ---------------------
+
+const BASE32_DIGITS: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ234567";
+const BASE32_INVERSE: [u8; 256] = alphabet_inverse(BASE32_DIGITS);
+
+/// The RFC 4648 base32 alphabet, `A-Z` followed by `2-7`.
+pub const BASE32: DigitAlphabet = DigitAlphabet::new(BASE32_DIGITS, &BASE32_INVERSE);
+
+const BASE32_HEX_DIGITS: &[u8] = b"0123456789ABCDEFGHIJKLMNOPQRSTUV";
+const BASE32_HEX_INVERSE: [u8; 256] = alphabet_inverse(BASE32_HEX_DIGITS);
+
+/// The RFC 4648 base32 alphabet with the extended hex ordering, `0-9A-V`.
+pub const BASE32_HEX: DigitAlphabet = DigitAlphabet::new(BASE32_HEX_DIGITS, &BASE32_HEX_INVERSE);
+
+const BASE58_DIGITS: &[u8] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+const BASE58_INVERSE: [u8; 256] = alphabet_inverse(BASE58_DIGITS);
+
+/// The Bitcoin base58 alphabet, which excludes `0`, `O`, `I` and `l`.
+pub const BASE58: DigitAlphabet = DigitAlphabet::new(BASE58_DIGITS, &BASE58_INVERSE);
+
+const BASE62_DIGITS: &[u8] = b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz";
+const BASE62_INVERSE: [u8; 256] = alphabet_inverse(BASE62_DIGITS);
+
+/// The base62 alphabet, `0-9A-Za-z`.
+pub const BASE62: DigitAlphabet = DigitAlphabet::new(BASE62_DIGITS, &BASE62_INVERSE);
+
+#[cfg(feature = "proptest")]
+impl proptest::arbitrary::Arbitrary for DigitAlphabet {
+    type Parameters = ();
+    type Strategy = proptest::sample::Select<Self>;
+
+    /// Select one of the predefined digit alphabets.
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        proptest::sample::select(vec![BASE32, BASE32_HEX, BASE58, BASE62])
+    }
+}
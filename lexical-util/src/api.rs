@@ -141,6 +141,33 @@ macro_rules! to_lexical {
             ///
             /// [`FORMATTED_SIZE_DECIMAL`]: lexical_util::constants::FormattedSize::FORMATTED_SIZE_DECIMAL
             fn to_lexical<'a>(self, bytes: &'a mut [u8]) -> &'a mut [u8];
+
+            /// Serializer for a number-to-string conversion to an
+            /// uninitialized buffer.
+            ///
+            /// Returns the initialized prefix of the buffer containing the
+            /// written bytes. At most [`FORMATTED_SIZE_DECIMAL`] bytes are
+            /// initialized, so this can write to the spare capacity of a
+            /// vector without zeroing it first.
+            ///
+            /// * `value`   - Number to serialize.
+            /// * `bytes`   - Buffer to write number to.
+            ///
+            /// # Panics
+            ///
+            /// Panics if the buffer is not of sufficient size, like
+            /// [`to_lexical`](Self::to_lexical).
+            ///
+            /// [`FORMATTED_SIZE_DECIMAL`]: lexical_util::constants::FormattedSize::FORMATTED_SIZE_DECIMAL
+            #[inline]
+            fn to_lexical_uninit<'a>(
+                self,
+                bytes: &'a mut [core::mem::MaybeUninit<u8>],
+            ) -> &'a mut [u8] {
+                let count =
+                    <Self as lexical_util::constants::FormattedSize>::FORMATTED_SIZE_DECIMAL;
+                self.to_lexical(lexical_util::algorithm::init_prefix(bytes, count))
+            }
         }
     };
 }
@@ -219,6 +246,38 @@ macro_rules! to_lexical_with_options {
                 bytes: &'a mut [u8],
                 options: &Self::Options,
             ) -> &'a mut [u8];
+
+            /// Serializer for a number-to-string conversion to an
+            /// uninitialized buffer.
+            ///
+            /// Returns the initialized prefix of the buffer containing the
+            /// written bytes. At most [`WriteOptions::buffer_size`] bytes
+            /// are initialized, so this can write to the spare capacity of
+            /// a vector without zeroing it first.
+            ///
+            /// * `FORMAT`  - Flags and characters designating the number grammar.
+            /// * `value`   - Number to serialize.
+            /// * `bytes`   - Buffer to write number to.
+            /// * `options` - Options for number formatting.
+            ///
+            /// # Panics
+            ///
+            /// Panics if the buffer is not of sufficient size, or the
+            /// number format is invalid, like
+            /// [`to_lexical_with_options`](Self::to_lexical_with_options).
+            ///
+            /// [`WriteOptions::buffer_size`]: lexical_util::options::WriteOptions::buffer_size
+            #[inline]
+            fn to_lexical_with_options_uninit<'a, const FORMAT: u128>(
+                self,
+                bytes: &'a mut [core::mem::MaybeUninit<u8>],
+                options: &Self::Options,
+            ) -> &'a mut [u8] {
+                let count =
+                    lexical_util::options::WriteOptions::buffer_size::<Self, FORMAT>(options);
+                let bytes = lexical_util::algorithm::init_prefix(bytes, count);
+                self.to_lexical_with_options::<FORMAT>(bytes, options)
+            }
         }
     };
 }
@@ -43,3 +43,22 @@ pub const fn is_valid_letter_slice(slc: &[u8]) -> bool {
     }
     true
 }
+
+/// Determine if a slice is a valid string for a special value, like `NaN`.
+///
+/// The string must be ASCII letters starting with `first`, in either case,
+/// optionally after a `.`, like the `.nan` and `.inf` strings in `YAML`.
+pub const fn is_valid_special_slice(slc: &[u8], first: u8) -> bool {
+    let start = (!slc.is_empty() && slc[0] == b'.') as usize;
+    if slc.len() <= start || !slc[start].eq_ignore_ascii_case(&first) {
+        return false;
+    }
+    let mut index = start;
+    while index < slc.len() {
+        if !is_valid_letter(slc[index]) {
+            return false;
+        }
+        index += 1;
+    }
+    true
+}
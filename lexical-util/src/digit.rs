@@ -3,6 +3,9 @@
 //! This both contains routines to convert to and from digits,
 //! as well as iterate over digits while skipping digit separators.
 
+#[cfg(feature = "parse")]
+use crate::format::NumberFormat;
+
 // CONST FNS
 // ---------
 
@@ -43,6 +46,26 @@ pub const fn char_to_digit_const(c: u8, radix: u32) -> Option<u32> {
     }
 }
 
+/// Convert a character to a digit with a radix and number format known at
+/// compile time.
+///
+/// This rejects letters with a case that is not allowed by the format,
+/// like uppercase letters with [`NO_UPPERCASE_DIGITS`].
+///
+/// [`NO_UPPERCASE_DIGITS`]: crate::format::NO_UPPERCASE_DIGITS
+#[inline(always)]
+#[cfg(feature = "parse")]
+pub const fn char_to_digit_format<const FORMAT: u128>(c: u8, radix: u32) -> Option<u32> {
+    let format = NumberFormat::<FORMAT> {};
+    if (format.no_uppercase_digits() && c.is_ascii_uppercase())
+        || (format.no_lowercase_digits() && c.is_ascii_lowercase())
+    {
+        None
+    } else {
+        char_to_digit_const(c, radix)
+    }
+}
+
 /// Determine if a character is a digit with a radix known at compile time.
 #[inline(always)]
 pub const fn char_is_digit_const(c: u8, radix: u32) -> bool {
@@ -33,18 +33,40 @@
 //! dual license (a BSD-like license), while the rest of the module is
 //! subject to an MIT/Apache-2.0 dual-license.
 //!
+//! # Public API
+//!
+//! [`u128_divrem`] and [`u128_divisor`] are stable, and may be used to
+//! format 128-bit integers in any supported radix: each call splits off
+//! the lowest [`u64_step`] digits as a `u64`, which can be written with
+//! native 64-bit arithmetic. The remaining functions are the building
+//! blocks for each radix, and require constants generated for the divisor.
+//!
+//! ```rust
+//! use lexical_util::div128::{u128_divisor, u128_divrem};
+//! use lexical_util::step::u64_step;
+//!
+//! const DIVISOR: u128 = u128_divisor(10);
+//! assert_eq!(DIVISOR, 10u128.pow(u64_step(10) as u32));
+//!
+//! let n = 123456789012345678901234567890u128;
+//! let (hi, lo) = u128_divrem(n, 10);
+//! assert_eq!((hi, lo), (n / DIVISOR, (n % DIVISOR) as u64));
+//! assert_eq!((hi, lo), (12345678901, 2345678901234567890));
+//! ```
+//!
 //! # Generation
 //!
 //! See [`etc/div128.py`] for the script to generate the divisors and the
 //! constants, and the division algorithm.
 //!
 //! [`etc/div128.py`]: https://github.com/Alexhuszagh/rust-lexical/blob/main/lexical-util/etc/div128.py
+//! [`u64_step`]: crate::step::u64_step
 
-#![cfg(not(feature = "compact"))]
 #![cfg(feature = "write")]
 
 use crate::assert::debug_assert_radix;
 use crate::mul::mulhi;
+use crate::step::u64_step;
 
 /// Calculate a div/remainder algorithm optimized for power-of-two radixes.
 ///
@@ -90,6 +112,23 @@ pub fn moderate_u128_divrem(n: u128, d: u64, factor: u128, factor_shr: u32) -> (
     (quot, rem)
 }
 
+/// Fast division/remainder algorithm for u128, where the multiplier does not
+/// fit in a u128.
+///
+/// The multiplier is `2^128 + factor`, which needs 129 bits. The high bit is
+/// handled by adding the dividend to the high product, using the approach in
+/// section 4 of the paper to avoid overflow, so `factor_shr` must be at
+/// least 1.
+#[inline(always)]
+#[allow(clippy::many_single_char_names)] // reason="mathematical names"
+pub fn wide_u128_divrem(n: u128, d: u64, factor: u128, factor_shr: u32) -> (u128, u64) {
+    debug_assert!(factor_shr >= 1);
+    let t = mulhi::<u128, u64>(n, factor);
+    let quot = (t + ((n - t) >> 1)) >> (factor_shr - 1);
+    let rem = (n - quot * d as u128) as u64;
+    (quot, rem)
+}
+
 /// Optimized fallback division/remainder algorithm for u128.
 ///
 /// This is because the code generation for u128 divrem is very inefficient
@@ -97,8 +136,8 @@ pub fn moderate_u128_divrem(n: u128, d: u64, factor: u128, factor_shr: u32) -> (
 /// time.
 ///
 /// This is still a fair bit slower than the optimized algorithms described
-/// in the above paper, but this is a suitable fallback when we cannot use
-/// the faster algorithm.
+/// in the above paper, but this is a suitable fallback for divisors without
+/// precomputed constants.
 #[cfg_attr(not(feature = "compact"), inline(always))]
 #[allow(clippy::many_single_char_names)] // reason="mathematical names"
 pub fn slow_u128_divrem(n: u128, d: u64, d_ctlz: u32) -> (u128, u64) {
@@ -153,9 +192,16 @@ pub fn slow_u128_divrem(n: u128, d: u64, d_ctlz: u32) -> (u128, u64) {
 /// 3. Cases of 2. with a power-of-two divisor.
 /// 4. Fallback cases.
 ///
-/// This returns the quotient and the remainder.
-/// For the number of digits processed, see
-/// [`min_step`](crate::step::min_step).
+/// This returns the quotient and the remainder of dividing `n` by
+/// [`u128_divisor(radix)`](u128_divisor), so the remainder contains
+/// the lowest [`u64_step(radix)`](crate::step::u64_step) digits of `n`.
+///
+/// # Panics
+///
+/// Panics if the radix is not supported by the enabled features: 10,
+/// and with `power-of-two`, 2, 4, 8, 16 and 32, and with `radix`, any
+/// radix from 2 to 36. This is only checked in debug builds when only
+/// decimal is enabled.
 #[inline(always)]
 #[allow(clippy::needless_return)] // reason="required based on radix configuration"
 pub fn u128_divrem(n: u128, radix: u32) -> (u128, u64) {
@@ -224,6 +270,18 @@ pub fn u128_divrem(n: u128, radix: u32) -> (u128, u64) {
     }
 }
 
+/// Get the divisor used by [`u128_divrem`] for the radix.
+///
+/// This is `radix` to the power of [`u64_step(radix)`](crate::step::u64_step),
+/// the largest power of the radix where the remainder always fits in a
+/// `u64`. For power-of-two radixes this may be `2^64`, so it is returned
+/// as a `u128`. This may be evaluated at compile time to create a constant
+/// for each radix.
+#[inline(always)]
+pub const fn u128_divisor(radix: u32) -> u128 {
+    (radix as u128).pow(u64_step(radix) as u32)
+}
+
 // AUTO-GENERATED
 // These functions were auto-generated by `etc/div128.py`.
 // Do not edit them unless there is a good reason to.
@@ -241,7 +299,7 @@ const fn u128_divrem_2(n: u128) -> (u128, u64) {
 #[inline(always)]
 #[cfg_attr(not(feature = "radix"), allow(dead_code))]
 fn u128_divrem_3(n: u128) -> (u128, u64) {
-    slow_u128_divrem(n, 12157665459056928801, 0)
+    wide_u128_divrem(n, 12157665459056928801, 176025780932440297617072543449198556497, 64)
 }
 
 #[inline(always)]
@@ -284,7 +342,7 @@ const fn u128_divrem_8(n: u128) -> (u128, u64) {
 #[inline(always)]
 #[cfg_attr(not(feature = "radix"), allow(dead_code))]
 fn u128_divrem_9(n: u128) -> (u128, u64) {
-    slow_u128_divrem(n, 12157665459056928801, 0)
+    wide_u128_divrem(n, 12157665459056928801, 176025780932440297617072543449198556497, 64)
 }
 
 #[inline(always)]
@@ -302,13 +360,13 @@ fn u128_divrem_10(n: u128) -> (u128, u64) {
 #[inline(always)]
 #[cfg_attr(not(feature = "radix"), allow(dead_code))]
 fn u128_divrem_11(n: u128) -> (u128, u64) {
-    slow_u128_divrem(n, 5559917313492231481, 1)
+    wide_u128_divrem(n, 5559917313492231481, 224213594210898911930242503374116915501, 63)
 }
 
 #[inline(always)]
 #[cfg_attr(not(feature = "radix"), allow(dead_code))]
 fn u128_divrem_12(n: u128) -> (u128, u64) {
-    slow_u128_divrem(n, 2218611106740436992, 3)
+    wide_u128_divrem(n, 2218611106740436992, 13379306609632472813553825920014412237, 61)
 }
 
 #[inline(always)]
@@ -389,7 +447,7 @@ fn u128_divrem_21(n: u128) -> (u128, u64) {
 #[inline(always)]
 #[cfg_attr(not(feature = "radix"), allow(dead_code))]
 fn u128_divrem_22(n: u128) -> (u128, u64) {
-    slow_u128_divrem(n, 6221821273427820544, 1)
+    wide_u128_divrem(n, 6221821273427820544, 164160099322422804428345858834648057085, 63)
 }
 
 #[inline(always)]
@@ -433,7 +491,7 @@ fn u128_divrem_26(n: u128) -> (u128, u64) {
 #[inline(always)]
 #[cfg_attr(not(feature = "radix"), allow(dead_code))]
 fn u128_divrem_27(n: u128) -> (u128, u64) {
-    slow_u128_divrem(n, 4052555153018976267, 2)
+    wide_u128_divrem(n, 4052555153018976267, 46948743969095607346960755728956864509, 62)
 }
 
 #[inline(always)]
@@ -458,7 +516,7 @@ fn u128_divrem_29(n: u128) -> (u128, u64) {
 #[inline(always)]
 #[cfg_attr(not(feature = "radix"), allow(dead_code))]
 fn u128_divrem_30(n: u128) -> (u128, u64) {
-    slow_u128_divrem(n, 15943230000000000000, 0)
+    wide_u128_divrem(n, 15943230000000000000, 53433444453963658869163367822158779255, 64)
 }
 
 #[inline(always)]
@@ -476,7 +534,7 @@ const fn u128_divrem_32(n: u128) -> (u128, u64) {
 #[inline(always)]
 #[cfg_attr(not(feature = "radix"), allow(dead_code))]
 fn u128_divrem_33(n: u128) -> (u128, u64) {
-    slow_u128_divrem(n, 1667889514952984961, 3)
+    wide_u128_divrem(n, 1667889514952984961, 130155099043617786708183346525672342339, 61)
 }
 
 #[inline(always)]
@@ -52,6 +52,13 @@ pub enum Error {
     InvalidPositiveSign(usize),
     /// Invalid negative sign for an unsigned type was found.
     InvalidNegativeSign(usize),
+    /// Too many significant digits to correctly round with the big-integer
+    /// capacity, or more digits than allowed by the options.
+    TooManyDigits(usize),
+    /// The parsed value was outside the allowed range.
+    OutOfRange(usize),
+    /// The denominator of a fraction was zero.
+    ZeroDenominator(usize),
 
     // NUMBER FORMAT ERRORS
     /// Invalid radix for the mantissa (significant) digits.
@@ -90,15 +97,18 @@ pub enum Error {
     InvalidFlags,
 
     // OPTION ERRORS
-    /// Invalid NaN string: must start with an `n` character.
+    /// Invalid NaN string: must start with an `n` character, optionally after a
+    /// `.`.
     InvalidNanString,
     /// NaN string is too long.
     NanStringTooLong,
-    /// Invalid short infinity string: must start with an `i` character.
+    /// Invalid short infinity string: must start with an `i` character,
+    /// optionally after a `.`.
     InvalidInfString,
     /// Short infinity string is too long.
     InfStringTooLong,
-    /// Invalid long infinity string: must start with an `i` character.
+    /// Invalid long infinity string: must start with an `i` character,
+    /// optionally after a `.`.
     InvalidInfinityString,
     /// Long infinity string is too long.
     InfinityStringTooLong,
@@ -114,12 +124,180 @@ pub enum Error {
     InvalidNegativeExponentBreak,
     /// Invalid positive exponent break: break is below 0.
     InvalidPositiveExponentBreak,
+    /// Invalid pad character: must be ASCII.
+    InvalidPadCharacter,
+    /// Invalid digit alphabet: digits are not unique, printable ASCII.
+    InvalidDigitAlphabet,
+    /// Invalid printf conversion specification.
+    InvalidPrintfSpec,
 
     // NOT AN ERROR
     /// An error did not actually occur, and the result was successful.
     Success,
 }
 
+/// The type of an error, without any associated data.
+///
+/// This has a variant for each variant of [`Error`], and is useful to
+/// match on the failure type while reporting the index separately.
+#[non_exhaustive]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum ErrorKind {
+    // PARSE ERRORS
+    /// Integral overflow occurred during numeric parsing.
+    Overflow,
+    /// Integral underflow occurred during numeric parsing.
+    Underflow,
+    /// Invalid digit found before string termination.
+    InvalidDigit,
+    /// Empty byte array found.
+    Empty,
+    /// Empty mantissa found.
+    EmptyMantissa,
+    /// Empty exponent found.
+    EmptyExponent,
+    /// Empty integer found.
+    EmptyInteger,
+    /// Empty fraction found.
+    EmptyFraction,
+    /// Invalid positive mantissa sign was found.
+    InvalidPositiveMantissaSign,
+    /// Mantissa sign was required, but not found.
+    MissingMantissaSign,
+    /// Exponent was present but not allowed.
+    InvalidExponent,
+    /// Invalid positive exponent sign was found.
+    InvalidPositiveExponentSign,
+    /// Exponent sign was required, but not found.
+    MissingExponentSign,
+    /// Exponent was present without fraction component.
+    ExponentWithoutFraction,
+    /// Integer or integer component of float had invalid leading zeros.
+    InvalidLeadingZeros,
+    /// No exponent with required exponent notation.
+    MissingExponent,
+    /// Integral sign was required, but not found.
+    MissingSign,
+    /// Invalid positive sign for an integer was found.
+    InvalidPositiveSign,
+    /// Invalid negative sign for an unsigned type was found.
+    InvalidNegativeSign,
+    /// Too many significant digits to correctly round with the big-integer
+    /// capacity, or more digits than allowed by the options.
+    TooManyDigits,
+    /// The parsed value was outside the allowed range.
+    OutOfRange,
+    /// The denominator of a fraction was zero.
+    ZeroDenominator,
+
+    // NUMBER FORMAT ERRORS
+    /// Invalid radix for the mantissa (significant) digits.
+    InvalidMantissaRadix,
+    /// Invalid base for the exponent.
+    InvalidExponentBase,
+    /// Invalid radix for the exponent digits.
+    InvalidExponentRadix,
+    /// Invalid digit separator character.
+    InvalidDigitSeparator,
+    /// Invalid decimal point character.
+    InvalidDecimalPoint,
+    /// Invalid symbol to represent exponent notation.
+    InvalidExponentSymbol,
+    /// Invalid character for a base prefix.
+    InvalidBasePrefix,
+    /// Invalid character for a base suffix.
+    InvalidBaseSuffix,
+    /// Invalid punctuation characters: multiple symbols overlap.
+    InvalidPunctuation,
+    /// Optional exponent flags were set while disabling exponent notation.
+    InvalidExponentFlags,
+    /// Set no positive mantissa sign while requiring mantissa signs.
+    InvalidMantissaSign,
+    /// Set no positive exponent sign while requiring exponent signs.
+    InvalidExponentSign,
+    /// Set optional special float flags while disable special floats.
+    InvalidSpecial,
+    /// Invalid consecutive integer digit separator.
+    InvalidConsecutiveIntegerDigitSeparator,
+    /// Invalid consecutive fraction digit separator.
+    InvalidConsecutiveFractionDigitSeparator,
+    /// Invalid consecutive exponent digit separator.
+    InvalidConsecutiveExponentDigitSeparator,
+    /// Invalid flags were set without the format feature.
+    InvalidFlags,
+
+    // OPTION ERRORS
+    /// Invalid NaN string: must start with an `n` character, optionally after a
+    /// `.`.
+    InvalidNanString,
+    /// NaN string is too long.
+    NanStringTooLong,
+    /// Invalid short infinity string: must start with an `i` character,
+    /// optionally after a `.`.
+    InvalidInfString,
+    /// Short infinity string is too long.
+    InfStringTooLong,
+    /// Invalid long infinity string: must start with an `i` character,
+    /// optionally after a `.`.
+    InvalidInfinityString,
+    /// Long infinity string is too long.
+    InfinityStringTooLong,
+    /// Long infinity string is too short: it must be as long as short infinity.
+    InfinityStringTooShort,
+    /// Invalid float parsing algorithm.
+    InvalidFloatParseAlgorithm,
+    /// Invalid radix for the significant digits.
+    InvalidRadix,
+    /// Invalid precision flags for writing floats.
+    InvalidFloatPrecision,
+    /// Invalid negative exponent break: break is above 0.
+    InvalidNegativeExponentBreak,
+    /// Invalid positive exponent break: break is below 0.
+    InvalidPositiveExponentBreak,
+    /// Invalid pad character: must be ASCII.
+    InvalidPadCharacter,
+    /// Invalid digit alphabet: digits are not unique, printable ASCII.
+    InvalidDigitAlphabet,
+    /// Invalid printf conversion specification.
+    InvalidPrintfSpec,
+
+    // NOT AN ERROR
+    /// An error did not actually occur, and the result was successful.
+    Success,
+}
+
+/// The class of characters expected where a parse error occurred.
+#[non_exhaustive]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Ord, PartialOrd, Hash)]
+pub enum CharacterClass {
+    /// A digit in the radix of the number.
+    Digit,
+    /// A `+` or `-` sign.
+    Sign,
+    /// The exponent character, like `e`.
+    Exponent,
+    /// The decimal point, like `.`.
+    DecimalPoint,
+}
+
+impl CharacterClass {
+    /// Get a description of the character class.
+    pub const fn description(&self) -> &'static str {
+        match self {
+            Self::Digit => "a digit",
+            Self::Sign => "a `+/-` sign",
+            Self::Exponent => "an exponent",
+            Self::DecimalPoint => "a decimal point",
+        }
+    }
+}
+
+impl fmt::Display for CharacterClass {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str(self.description())
+    }
+}
+
 // Ensure we don't have extra padding on the structure.
 const_assert!(mem::size_of::<Error>() <= 2 * mem::size_of::<usize>());
 
@@ -138,6 +316,80 @@ macro_rules! is_error_type {
 }
 
 impl Error {
+    /// Get the type of the error, without the index.
+    pub const fn kind(&self) -> ErrorKind {
+        match self {
+            // PARSE ERRORS
+            Self::Overflow(_) => ErrorKind::Overflow,
+            Self::Underflow(_) => ErrorKind::Underflow,
+            Self::InvalidDigit(_) => ErrorKind::InvalidDigit,
+            Self::Empty(_) => ErrorKind::Empty,
+            Self::EmptyMantissa(_) => ErrorKind::EmptyMantissa,
+            Self::EmptyExponent(_) => ErrorKind::EmptyExponent,
+            Self::EmptyInteger(_) => ErrorKind::EmptyInteger,
+            Self::EmptyFraction(_) => ErrorKind::EmptyFraction,
+            Self::InvalidPositiveMantissaSign(_) => ErrorKind::InvalidPositiveMantissaSign,
+            Self::MissingMantissaSign(_) => ErrorKind::MissingMantissaSign,
+            Self::InvalidExponent(_) => ErrorKind::InvalidExponent,
+            Self::InvalidPositiveExponentSign(_) => ErrorKind::InvalidPositiveExponentSign,
+            Self::MissingExponentSign(_) => ErrorKind::MissingExponentSign,
+            Self::ExponentWithoutFraction(_) => ErrorKind::ExponentWithoutFraction,
+            Self::InvalidLeadingZeros(_) => ErrorKind::InvalidLeadingZeros,
+            Self::MissingExponent(_) => ErrorKind::MissingExponent,
+            Self::MissingSign(_) => ErrorKind::MissingSign,
+            Self::InvalidPositiveSign(_) => ErrorKind::InvalidPositiveSign,
+            Self::InvalidNegativeSign(_) => ErrorKind::InvalidNegativeSign,
+            Self::TooManyDigits(_) => ErrorKind::TooManyDigits,
+            Self::OutOfRange(_) => ErrorKind::OutOfRange,
+            Self::ZeroDenominator(_) => ErrorKind::ZeroDenominator,
+
+            // NUMBER FORMAT ERRORS
+            Self::InvalidMantissaRadix => ErrorKind::InvalidMantissaRadix,
+            Self::InvalidExponentBase => ErrorKind::InvalidExponentBase,
+            Self::InvalidExponentRadix => ErrorKind::InvalidExponentRadix,
+            Self::InvalidDigitSeparator => ErrorKind::InvalidDigitSeparator,
+            Self::InvalidDecimalPoint => ErrorKind::InvalidDecimalPoint,
+            Self::InvalidExponentSymbol => ErrorKind::InvalidExponentSymbol,
+            Self::InvalidBasePrefix => ErrorKind::InvalidBasePrefix,
+            Self::InvalidBaseSuffix => ErrorKind::InvalidBaseSuffix,
+            Self::InvalidPunctuation => ErrorKind::InvalidPunctuation,
+            Self::InvalidExponentFlags => ErrorKind::InvalidExponentFlags,
+            Self::InvalidMantissaSign => ErrorKind::InvalidMantissaSign,
+            Self::InvalidExponentSign => ErrorKind::InvalidExponentSign,
+            Self::InvalidSpecial => ErrorKind::InvalidSpecial,
+            Self::InvalidConsecutiveIntegerDigitSeparator => {
+                ErrorKind::InvalidConsecutiveIntegerDigitSeparator
+            },
+            Self::InvalidConsecutiveFractionDigitSeparator => {
+                ErrorKind::InvalidConsecutiveFractionDigitSeparator
+            },
+            Self::InvalidConsecutiveExponentDigitSeparator => {
+                ErrorKind::InvalidConsecutiveExponentDigitSeparator
+            },
+            Self::InvalidFlags => ErrorKind::InvalidFlags,
+
+            // OPTION ERRORS
+            Self::InvalidNanString => ErrorKind::InvalidNanString,
+            Self::NanStringTooLong => ErrorKind::NanStringTooLong,
+            Self::InvalidInfString => ErrorKind::InvalidInfString,
+            Self::InfStringTooLong => ErrorKind::InfStringTooLong,
+            Self::InvalidInfinityString => ErrorKind::InvalidInfinityString,
+            Self::InfinityStringTooLong => ErrorKind::InfinityStringTooLong,
+            Self::InfinityStringTooShort => ErrorKind::InfinityStringTooShort,
+            Self::InvalidFloatParseAlgorithm => ErrorKind::InvalidFloatParseAlgorithm,
+            Self::InvalidRadix => ErrorKind::InvalidRadix,
+            Self::InvalidFloatPrecision => ErrorKind::InvalidFloatPrecision,
+            Self::InvalidNegativeExponentBreak => ErrorKind::InvalidNegativeExponentBreak,
+            Self::InvalidPositiveExponentBreak => ErrorKind::InvalidPositiveExponentBreak,
+            Self::InvalidPadCharacter => ErrorKind::InvalidPadCharacter,
+            Self::InvalidDigitAlphabet => ErrorKind::InvalidDigitAlphabet,
+            Self::InvalidPrintfSpec => ErrorKind::InvalidPrintfSpec,
+
+            // NOT AN ERROR
+            Self::Success => ErrorKind::Success,
+        }
+    }
+
     /// Get the index for the parsing error.
     pub fn index(&self) -> Option<&usize> {
         match self {
@@ -161,6 +413,9 @@ impl Error {
             Self::MissingSign(index) => Some(index),
             Self::InvalidPositiveSign(index) => Some(index),
             Self::InvalidNegativeSign(index) => Some(index),
+            Self::TooManyDigits(index) => Some(index),
+            Self::OutOfRange(index) => Some(index),
+            Self::ZeroDenominator(index) => Some(index),
 
             // NUMBER FORMAT ERRORS
             Self::InvalidMantissaRadix => None,
@@ -194,12 +449,47 @@ impl Error {
             Self::InvalidFloatPrecision => None,
             Self::InvalidNegativeExponentBreak => None,
             Self::InvalidPositiveExponentBreak => None,
+            Self::InvalidPadCharacter => None,
+            Self::InvalidDigitAlphabet => None,
+            Self::InvalidPrintfSpec => None,
 
             // NOT AN ERROR
             Self::Success => None,
         }
     }
 
+    /// Get a mutable reference to the index for the parsing error.
+    ///
+    /// This is useful to make the index relative to a larger input, after
+    /// parsing a subslice of it.
+    pub fn index_mut(&mut self) -> Option<&mut usize> {
+        match self {
+            Self::Overflow(index)
+            | Self::Underflow(index)
+            | Self::InvalidDigit(index)
+            | Self::Empty(index)
+            | Self::EmptyMantissa(index)
+            | Self::EmptyExponent(index)
+            | Self::EmptyInteger(index)
+            | Self::EmptyFraction(index)
+            | Self::InvalidPositiveMantissaSign(index)
+            | Self::MissingMantissaSign(index)
+            | Self::InvalidExponent(index)
+            | Self::InvalidPositiveExponentSign(index)
+            | Self::MissingExponentSign(index)
+            | Self::ExponentWithoutFraction(index)
+            | Self::InvalidLeadingZeros(index)
+            | Self::MissingExponent(index)
+            | Self::MissingSign(index)
+            | Self::InvalidPositiveSign(index)
+            | Self::InvalidNegativeSign(index)
+            | Self::TooManyDigits(index)
+            | Self::OutOfRange(index)
+            | Self::ZeroDenominator(index) => Some(index),
+            _ => None,
+        }
+    }
+
     is_error_type!(is_overflow, Overflow(_));
     is_error_type!(is_underflow, Underflow(_));
     is_error_type!(is_invalid_digit, InvalidDigit(_));
@@ -219,6 +509,9 @@ impl Error {
     is_error_type!(is_missing_sign, MissingSign(_));
     is_error_type!(is_invalid_positive_sign, InvalidPositiveSign(_));
     is_error_type!(is_invalid_negative_sign, InvalidNegativeSign(_));
+    is_error_type!(is_too_many_digits, TooManyDigits(_));
+    is_error_type!(is_out_of_range, OutOfRange(_));
+    is_error_type!(is_zero_denominator, ZeroDenominator(_));
     is_error_type!(is_invalid_mantissa_radix, InvalidMantissaRadix);
     is_error_type!(is_invalid_exponent_base, InvalidExponentBase);
     is_error_type!(is_invalid_exponent_radix, InvalidExponentRadix);
@@ -257,89 +550,210 @@ impl Error {
     is_error_type!(is_invalid_float_precision, InvalidFloatPrecision);
     is_error_type!(is_invalid_negative_exponent_break, InvalidNegativeExponentBreak);
     is_error_type!(is_invalid_positive_exponent_break, InvalidPositiveExponentBreak);
+    is_error_type!(is_invalid_pad_character, InvalidPadCharacter);
+    is_error_type!(is_invalid_digit_alphabet, InvalidDigitAlphabet);
+    is_error_type!(is_invalid_printf_spec, InvalidPrintfSpec);
     is_error_type!(is_success, Success);
 }
 
 /// Add an error message for parsing errors.
 macro_rules! write_parse_error {
-    ($formatter:ident, $message:literal, $index:ident) => {
-        write!($formatter, "lexical parse error: {} at index {}", $message, $index)
+    ($formatter:ident, $message:ident, $index:ident, $expected:expr) => {
+        match $expected {
+            Some(expected) => write!(
+                $formatter,
+                "lexical parse error: '{}' at index {}, expected {}",
+                $message, $index, expected
+            ),
+            None => write!($formatter, "lexical parse error: '{}' at index {}", $message, $index),
+        }
     };
 }
 
 /// Add an error message for number format errors.
 macro_rules! format_message {
-    ($formatter:ident, $message:literal) => {
-        write!($formatter, "lexical number format error: {}", $message)
+    ($formatter:ident, $message:ident) => {
+        write!($formatter, "lexical number format error: '{}'", $message)
     };
 }
 
 /// Add an error message for options errors.
 macro_rules! options_message {
-    ($formatter:ident, $message:literal) => {
-        write!($formatter, "lexical options error: {}", $message)
+    ($formatter:ident, $message:ident) => {
+        write!($formatter, "lexical options error: '{}'", $message)
     };
 }
 
+impl ErrorKind {
+    /// Get a description of the error.
+    pub const fn description(&self) -> &'static str {
+        match self {
+            // PARSE ERRORS
+            Self::Overflow => "numeric overflow occurred",
+            Self::Underflow => "numeric underflow occurred",
+            Self::InvalidDigit => "invalid digit found",
+            Self::Empty => "the string to parse was empty",
+            Self::EmptyMantissa => "no significant digits found",
+            Self::EmptyExponent => "exponent notation found without an exponent",
+            Self::EmptyInteger => "invalid float with no integer digits",
+            Self::EmptyFraction => "invalid float with no fraction digits",
+            Self::InvalidPositiveMantissaSign => "invalid `+` sign before significant digits",
+            Self::MissingMantissaSign => "missing required `+/-` sign for significant digits",
+            Self::InvalidExponent => "exponent found but not allowed",
+            Self::InvalidPositiveExponentSign => "invalid `+` sign in exponent",
+            Self::MissingExponentSign => "missing required `+/-` sign for exponent",
+            Self::ExponentWithoutFraction => "invalid float containing exponent without fraction",
+            Self::InvalidLeadingZeros => "invalid number with leading zeros before digits",
+            Self::MissingExponent => "missing required exponent",
+            Self::MissingSign => "missing required `+/-` sign for integer",
+            Self::InvalidPositiveSign => "invalid `+` sign for an integer was found",
+            Self::InvalidNegativeSign => "invalid `-` sign for an unsigned type was found",
+            Self::TooManyDigits => "too many significant digits to parse",
+            Self::OutOfRange => "parsed value is outside the allowed range",
+            Self::ZeroDenominator => "denominator of a fraction is zero",
+
+            // NUMBER FORMAT ERRORS
+            Self::InvalidMantissaRadix => "invalid radix for mantissa digits",
+            Self::InvalidExponentBase => "invalid exponent base",
+            Self::InvalidExponentRadix => "invalid radix for exponent digits",
+            Self::InvalidDigitSeparator => "invalid digit separator: must be ASCII and not a digit or a `+/-` sign",
+            Self::InvalidDecimalPoint => "invalid decimal point: must be ASCII and not a digit or a `+/-` sign",
+            Self::InvalidExponentSymbol => "invalid exponent symbol: must be ASCII and not a digit or a `+/-` sign",
+            Self::InvalidBasePrefix => "invalid base prefix character",
+            Self::InvalidBaseSuffix => "invalid base suffix character",
+            Self::InvalidPunctuation => "invalid punctuation: multiple characters overlap",
+            Self::InvalidExponentFlags => "exponent flags set while disabling exponent notation",
+            Self::InvalidMantissaSign => "disabled the `+` sign while requiring a sign for significant digits",
+            Self::InvalidExponentSign => "disabled the `+` sign while requiring a sign for exponent digits",
+            Self::InvalidSpecial => "special flags set while disabling special floats",
+            Self::InvalidConsecutiveIntegerDigitSeparator => "enabled consecutive digit separators in the integer without setting a valid location",
+            Self::InvalidConsecutiveFractionDigitSeparator => "enabled consecutive digit separators in the fraction without setting a valid location",
+            Self::InvalidConsecutiveExponentDigitSeparator => "enabled consecutive digit separators in the exponent without setting a valid location",
+            Self::InvalidFlags => "invalid flags enabled without the format feature",
+
+            // OPTION ERRORS
+            Self::InvalidNanString => "NaN string must started with `n`",
+            Self::NanStringTooLong => "NaN string is too long",
+            Self::InvalidInfString => "short infinity string must started with `i`",
+            Self::InfStringTooLong => "short infinity string is too long",
+            Self::InvalidInfinityString => "long infinity string must started with `i`",
+            Self::InfinityStringTooLong => "long infinity string is too long",
+            Self::InfinityStringTooShort => "long infinity string is too short",
+            Self::InvalidFloatParseAlgorithm => "invalid combination of float parse algorithms",
+            Self::InvalidRadix => "invalid radix for significant digits",
+            Self::InvalidFloatPrecision => "invalid float precision: min digits is larger than max digits",
+            Self::InvalidNegativeExponentBreak => "invalid negative exponent break: value is above 0",
+            Self::InvalidPositiveExponentBreak => "invalid positive exponent break: value is below 0",
+            Self::InvalidPadCharacter => "invalid pad character: must be ASCII",
+            Self::InvalidDigitAlphabet => "invalid digit alphabet: digits are not unique, printable ASCII",
+            Self::InvalidPrintfSpec => "invalid printf conversion specification",
+
+            // NOT AN ERROR
+            Self::Success => "not actually an error",
+        }
+    }
+
+    /// Get the class of characters expected where a parse error occurred.
+    ///
+    /// Returns `None` for errors that are not caused by an unexpected
+    /// character, like overflow, and for number format and options errors.
+    pub const fn expected(&self) -> Option<CharacterClass> {
+        match self {
+            Self::InvalidDigit
+            | Self::Empty
+            | Self::EmptyMantissa
+            | Self::EmptyExponent
+            | Self::EmptyInteger
+            | Self::EmptyFraction
+            | Self::InvalidPositiveMantissaSign
+            | Self::InvalidPositiveExponentSign
+            | Self::InvalidPositiveSign
+            | Self::InvalidNegativeSign => Some(CharacterClass::Digit),
+            Self::MissingMantissaSign | Self::MissingExponentSign | Self::MissingSign => {
+                Some(CharacterClass::Sign)
+            },
+            Self::MissingExponent => Some(CharacterClass::Exponent),
+            Self::ExponentWithoutFraction => Some(CharacterClass::DecimalPoint),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for ErrorKind {
+    fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str(self.description())
+    }
+}
+
 impl fmt::Display for Error {
     fn fmt(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let kind = self.kind();
+        let description = kind.description();
         match self {
             // PARSE ERRORS
-            Self::Overflow(index) => write_parse_error!(formatter, "'numeric overflow occurred'", index),
-            Self::Underflow(index) => write_parse_error!(formatter, "'numeric underflow occurred'", index),
-            Self::InvalidDigit(index) => write_parse_error!(formatter, "'invalid digit found'", index),
-            Self::Empty(index) => write_parse_error!(formatter, "'the string to parse was empty'", index),
-            Self::EmptyMantissa(index) => write_parse_error!(formatter, "'no significant digits found'", index),
-            Self::EmptyExponent(index) => write_parse_error!(formatter, "'exponent notation found without an exponent'", index),
-            Self::EmptyInteger(index) => write_parse_error!(formatter, "'invalid float with no integer digits'", index),
-            Self::EmptyFraction(index) => write_parse_error!(formatter, "'invalid float with no fraction digits'", index),
-            Self::InvalidPositiveMantissaSign(index) => write_parse_error!(formatter, "'invalid `+` sign before significant digits'", index),
-            Self::MissingMantissaSign(index) => write_parse_error!(formatter, "'missing required `+/-` sign for significant digits'", index),
-            Self::InvalidExponent(index) => write_parse_error!(formatter, "'exponent found but not allowed'", index),
-            Self::InvalidPositiveExponentSign(index) => write_parse_error!(formatter, "'invalid `+` sign in exponent'", index),
-            Self::MissingExponentSign(index) => write_parse_error!(formatter, "'missing required `+/-` sign for exponent'", index),
-            Self::ExponentWithoutFraction(index) => write_parse_error!(formatter,  "'invalid float containing exponent without fraction'", index),
-            Self::InvalidLeadingZeros(index) => write_parse_error!(formatter, "'invalid number with leading zeros before digits'", index),
-            Self::MissingExponent(index) => write_parse_error!(formatter, "'missing required exponent'", index),
-            Self::MissingSign(index) => write_parse_error!(formatter, "'missing required `+/-` sign for integer'", index),
-            Self::InvalidPositiveSign(index) => write_parse_error!(formatter, "'invalid `+` sign for an integer was found'", index),
-            Self::InvalidNegativeSign(index) => write_parse_error!(formatter, "'invalid `-` sign for an unsigned type was found'", index),
+            Self::Overflow(index)
+            | Self::Underflow(index)
+            | Self::InvalidDigit(index)
+            | Self::Empty(index)
+            | Self::EmptyMantissa(index)
+            | Self::EmptyExponent(index)
+            | Self::EmptyInteger(index)
+            | Self::EmptyFraction(index)
+            | Self::InvalidPositiveMantissaSign(index)
+            | Self::MissingMantissaSign(index)
+            | Self::InvalidExponent(index)
+            | Self::InvalidPositiveExponentSign(index)
+            | Self::MissingExponentSign(index)
+            | Self::ExponentWithoutFraction(index)
+            | Self::InvalidLeadingZeros(index)
+            | Self::MissingExponent(index)
+            | Self::MissingSign(index)
+            | Self::InvalidPositiveSign(index)
+            | Self::InvalidNegativeSign(index)
+            | Self::TooManyDigits(index)
+            | Self::OutOfRange(index)
+            | Self::ZeroDenominator(index) => {
+                write_parse_error!(formatter, description, index, kind.expected())
+            },
 
             // NUMBER FORMAT ERRORS
-            Self::InvalidMantissaRadix => format_message!(formatter, "'invalid radix for mantissa digits'"),
-            Self::InvalidExponentBase => format_message!(formatter, "'invalid exponent base'"),
-            Self::InvalidExponentRadix => format_message!(formatter, "'invalid radix for exponent digits'"),
-            Self::InvalidDigitSeparator => format_message!(formatter, "'invalid digit separator: must be ASCII and not a digit or a `+/-` sign'"),
-            Self::InvalidDecimalPoint => format_message!(formatter, "'invalid decimal point: must be ASCII and not a digit or a `+/-` sign'"),
-            Self::InvalidExponentSymbol => format_message!(formatter, "'invalid exponent symbol: must be ASCII and not a digit or a `+/-` sign'"),
-            Self::InvalidBasePrefix => format_message!(formatter, "'invalid base prefix character'"),
-            Self::InvalidBaseSuffix => format_message!(formatter, "'invalid base suffix character'"),
-            Self::InvalidPunctuation => format_message!(formatter, "'invalid punctuation: multiple characters overlap'"),
-            Self::InvalidExponentFlags => format_message!(formatter, "'exponent flags set while disabling exponent notation'"),
-            Self::InvalidMantissaSign => format_message!(formatter, "'disabled the `+` sign while requiring a sign for significant digits'"),
-            Self::InvalidExponentSign => format_message!(formatter, "'disabled the `+` sign while requiring a sign for exponent digits'"),
-            Self::InvalidSpecial => format_message!(formatter, "'special flags set while disabling special floats'"),
-            Self::InvalidConsecutiveIntegerDigitSeparator => format_message!(formatter, "'enabled consecutive digit separators in the integer without setting a valid location'"),
-            Self::InvalidConsecutiveFractionDigitSeparator => format_message!(formatter, "'enabled consecutive digit separators in the fraction without setting a valid location'"),
-            Self::InvalidConsecutiveExponentDigitSeparator => format_message!(formatter, "'enabled consecutive digit separators in the exponent without setting a valid location'"),
-            Self::InvalidFlags => format_message!(formatter, "'invalid flags enabled without the format feature'"),
+            Self::InvalidMantissaRadix
+            | Self::InvalidExponentBase
+            | Self::InvalidExponentRadix
+            | Self::InvalidDigitSeparator
+            | Self::InvalidDecimalPoint
+            | Self::InvalidExponentSymbol
+            | Self::InvalidBasePrefix
+            | Self::InvalidBaseSuffix
+            | Self::InvalidPunctuation
+            | Self::InvalidExponentFlags
+            | Self::InvalidMantissaSign
+            | Self::InvalidExponentSign
+            | Self::InvalidSpecial
+            | Self::InvalidConsecutiveIntegerDigitSeparator
+            | Self::InvalidConsecutiveFractionDigitSeparator
+            | Self::InvalidConsecutiveExponentDigitSeparator
+            | Self::InvalidFlags => format_message!(formatter, description),
 
             // OPTION ERRORS
-            Self::InvalidNanString => options_message!(formatter, "'NaN string must started with `n`'"),
-            Self::NanStringTooLong => options_message!(formatter, "'NaN string is too long'"),
-            Self::InvalidInfString => options_message!(formatter, "'short infinity string must started with `i`'"),
-            Self::InfStringTooLong => options_message!(formatter, "'short infinity string is too long'"),
-            Self::InvalidInfinityString => options_message!(formatter, "'long infinity string must started with `i`'"),
-            Self::InfinityStringTooLong => options_message!(formatter, "'long infinity string is too long'"),
-            Self::InfinityStringTooShort => options_message!(formatter, "'long infinity string is too short'"),
-            Self::InvalidFloatParseAlgorithm => options_message!(formatter, "'invalid combination of float parse algorithms'"),
-            Self::InvalidRadix => options_message!(formatter, "'invalid radix for significant digits'"),
-            Self::InvalidFloatPrecision => options_message!(formatter, "'invalid float precision: min digits is larger than max digits'"),
-            Self::InvalidNegativeExponentBreak => options_message!(formatter, "'invalid negative exponent break: value is above 0'"),
-            Self::InvalidPositiveExponentBreak => options_message!(formatter, "'invalid positive exponent break: value is below 0'"),
+            Self::InvalidNanString
+            | Self::NanStringTooLong
+            | Self::InvalidInfString
+            | Self::InfStringTooLong
+            | Self::InvalidInfinityString
+            | Self::InfinityStringTooLong
+            | Self::InfinityStringTooShort
+            | Self::InvalidFloatParseAlgorithm
+            | Self::InvalidRadix
+            | Self::InvalidFloatPrecision
+            | Self::InvalidNegativeExponentBreak
+            | Self::InvalidPositiveExponentBreak
+            | Self::InvalidPadCharacter
+            | Self::InvalidDigitAlphabet
+            | Self::InvalidPrintfSpec => options_message!(formatter, description),
 
             // NOT AN ERROR
-            Self::Success => write!(formatter, "'not actually an error'"),
+            Self::Success => write!(formatter, "'{}'", description),
         }
     }
 }
@@ -710,6 +710,62 @@ macro_rules! from_flag {
     }};
 }
 
+/// Get the error type from a packed number format.
+///
+/// This is identical to [`NumberFormat::error`], except the format does
+/// not need to be a const generic, so it can validate formats as they
+/// are built.
+#[allow(clippy::if_same_then_else)] // reason="all are different logic conditions"
+pub(crate) const fn format_error_impl(format: u128) -> Error {
+    if !flags::is_valid_radix(flags::mantissa_radix(format)) {
+        Error::InvalidMantissaRadix
+    } else if !flags::is_valid_radix(flags::exponent_base(format)) {
+        Error::InvalidExponentBase
+    } else if !flags::is_valid_radix(flags::exponent_radix(format)) {
+        Error::InvalidExponentRadix
+    } else if !flags::is_valid_digit_separator(format) {
+        Error::InvalidDigitSeparator
+    } else if format & flags::DIGIT_SEPARATOR_FLAG_MASK != 0 && flags::digit_separator(format) == 0
+    {
+        // Digit separator flags without a digit separator character.
+        Error::InvalidDigitSeparator
+    } else if !flags::is_valid_base_prefix(format) {
+        Error::InvalidBasePrefix
+    } else if !flags::is_valid_base_suffix(format) {
+        Error::InvalidBaseSuffix
+    } else if !flags::is_valid_punctuation(format) {
+        Error::InvalidPunctuation
+    } else if !flags::is_valid_exponent_flags(format) {
+        Error::InvalidExponentFlags
+    } else if format & flags::NO_POSITIVE_MANTISSA_SIGN != 0
+        && format & flags::REQUIRED_MANTISSA_SIGN != 0
+    {
+        Error::InvalidMantissaSign
+    } else if format & flags::NO_POSITIVE_EXPONENT_SIGN != 0
+        && format & flags::REQUIRED_EXPONENT_SIGN != 0
+    {
+        Error::InvalidExponentSign
+    } else if format & flags::NO_SPECIAL != 0 && format & flags::CASE_SENSITIVE_SPECIAL != 0 {
+        Error::InvalidSpecial
+    } else if format & flags::NO_SPECIAL != 0 && format & flags::SPECIAL_DIGIT_SEPARATOR != 0 {
+        Error::InvalidSpecial
+    } else if format & flags::INTEGER_DIGIT_SEPARATOR_FLAG_MASK
+        == flags::INTEGER_CONSECUTIVE_DIGIT_SEPARATOR
+    {
+        Error::InvalidConsecutiveIntegerDigitSeparator
+    } else if format & flags::FRACTION_DIGIT_SEPARATOR_FLAG_MASK
+        == flags::FRACTION_CONSECUTIVE_DIGIT_SEPARATOR
+    {
+        Error::InvalidConsecutiveFractionDigitSeparator
+    } else if format & flags::EXPONENT_DIGIT_SEPARATOR_FLAG_MASK
+        == flags::EXPONENT_CONSECUTIVE_DIGIT_SEPARATOR
+    {
+        Error::InvalidConsecutiveExponentDigitSeparator
+    } else {
+        Error::Success
+    }
+}
+
 /// Wrapper for the 128-bit packed struct.
 ///
 /// See `NumberFormatBuilder` for the `FORMAT` fields
@@ -734,41 +790,8 @@ impl<const FORMAT: u128> NumberFormat<FORMAT> {
     }
 
     /// Get the error type from the format.
-    #[allow(clippy::if_same_then_else)] // reason="all are different logic conditions"
     pub const fn error(&self) -> Error {
-        if !flags::is_valid_radix(self.mantissa_radix()) {
-            Error::InvalidMantissaRadix
-        } else if !flags::is_valid_radix(self.exponent_base()) {
-            Error::InvalidExponentBase
-        } else if !flags::is_valid_radix(self.exponent_radix()) {
-            Error::InvalidExponentRadix
-        } else if !flags::is_valid_digit_separator(FORMAT) {
-            Error::InvalidDigitSeparator
-        } else if !flags::is_valid_base_prefix(FORMAT) {
-            Error::InvalidBasePrefix
-        } else if !flags::is_valid_base_suffix(FORMAT) {
-            Error::InvalidBaseSuffix
-        } else if !flags::is_valid_punctuation(FORMAT) {
-            Error::InvalidPunctuation
-        } else if !flags::is_valid_exponent_flags(FORMAT) {
-            Error::InvalidExponentFlags
-        } else if self.no_positive_mantissa_sign() && self.required_mantissa_sign() {
-            Error::InvalidMantissaSign
-        } else if self.no_positive_exponent_sign() && self.required_exponent_sign() {
-            Error::InvalidExponentSign
-        } else if self.no_special() && self.case_sensitive_special() {
-            Error::InvalidSpecial
-        } else if self.no_special() && self.special_digit_separator() {
-            Error::InvalidSpecial
-        } else if self.integer_digit_separator_flags() == flags::INTEGER_CONSECUTIVE_DIGIT_SEPARATOR {
-            Error::InvalidConsecutiveIntegerDigitSeparator
-        } else if self.fraction_digit_separator_flags() == flags::FRACTION_CONSECUTIVE_DIGIT_SEPARATOR {
-            Error::InvalidConsecutiveFractionDigitSeparator
-        } else if self.exponent_digit_separator_flags() == flags::EXPONENT_CONSECUTIVE_DIGIT_SEPARATOR {
-            Error::InvalidConsecutiveExponentDigitSeparator
-        } else {
-            Error::Success
-        }
+        format_error_impl(FORMAT)
     }
 
     // NON-DIGIT SEPARATOR FLAGS & MASKS
@@ -944,6 +967,78 @@ impl<const FORMAT: u128> NumberFormat<FORMAT> {
         Self::CASE_SENSITIVE_BASE_SUFFIX
     }
 
+    /// If ASCII whitespace before the number is skipped.
+    pub const SKIP_LEADING_WHITESPACE: bool = from_flag!(FORMAT, SKIP_LEADING_WHITESPACE);
+
+    /// Get if ASCII whitespace before the number is skipped.
+    #[inline(always)]
+    pub const fn skip_leading_whitespace(&self) -> bool {
+        Self::SKIP_LEADING_WHITESPACE
+    }
+
+    /// If ASCII whitespace after the number is skipped.
+    pub const SKIP_TRAILING_WHITESPACE: bool = from_flag!(FORMAT, SKIP_TRAILING_WHITESPACE);
+
+    /// Get if ASCII whitespace after the number is skipped.
+    #[inline(always)]
+    pub const fn skip_trailing_whitespace(&self) -> bool {
+        Self::SKIP_TRAILING_WHITESPACE
+    }
+
+    /// If Unicode minus and plus signs are accepted.
+    pub const UNICODE_SIGNS: bool = from_flag!(FORMAT, UNICODE_SIGNS);
+
+    /// Get if Unicode minus and plus signs are accepted.
+    #[inline(always)]
+    pub const fn unicode_signs(&self) -> bool {
+        Self::UNICODE_SIGNS
+    }
+
+    /// If negative numbers are enclosed in parentheses.
+    pub const PARENTHESIZED_NEGATIVES: bool = from_flag!(FORMAT, PARENTHESIZED_NEGATIVES);
+
+    /// Get if negative numbers are enclosed in parentheses.
+    #[inline(always)]
+    pub const fn parenthesized_negatives(&self) -> bool {
+        Self::PARENTHESIZED_NEGATIVES
+    }
+
+    /// If an exponent without digits is left as trailing bytes.
+    pub const TRAILING_EMPTY_EXPONENT: bool = from_flag!(FORMAT, TRAILING_EMPTY_EXPONENT);
+
+    /// Get if an exponent without digits is left as trailing bytes.
+    #[inline(always)]
+    pub const fn trailing_empty_exponent(&self) -> bool {
+        Self::TRAILING_EMPTY_EXPONENT
+    }
+
+    /// If uppercase letters are not allowed as digits.
+    pub const NO_UPPERCASE_DIGITS: bool = from_flag!(FORMAT, NO_UPPERCASE_DIGITS);
+
+    /// Get if uppercase letters are not allowed as digits.
+    #[inline(always)]
+    pub const fn no_uppercase_digits(&self) -> bool {
+        Self::NO_UPPERCASE_DIGITS
+    }
+
+    /// If lowercase letters are not allowed as digits.
+    pub const NO_LOWERCASE_DIGITS: bool = from_flag!(FORMAT, NO_LOWERCASE_DIGITS);
+
+    /// Get if lowercase letters are not allowed as digits.
+    #[inline(always)]
+    pub const fn no_lowercase_digits(&self) -> bool {
+        Self::NO_LOWERCASE_DIGITS
+    }
+
+    /// If subnormal floats are not allowed.
+    pub const NO_SUBNORMALS: bool = from_flag!(FORMAT, NO_SUBNORMALS);
+
+    /// Get if subnormal floats are not allowed.
+    #[inline(always)]
+    pub const fn no_subnormals(&self) -> bool {
+        Self::NO_SUBNORMALS
+    }
+
     // DIGIT SEPARATOR FLAGS & MASKS
 
     // If digit separators are allowed between integer digits.
@@ -1402,11 +1497,44 @@ pub const PYTHON_STRING: u128 = PYTHON3_STRING;
 /// Number format for a `Python3` literal floating-point number.
 pub const PYTHON3_LITERAL: u128 = PYTHON36_LITERAL;
 
-// PYTHON3 STRING [0134567MN]
 /// Number format to parse a `Python3` float from string.
+pub const PYTHON3_STRING: u128 = PYTHON36_STRING;
+
+// PYTHON3.6+ STRING [0134567MNQR-_]
+/// Number format to parse a `Python3.6` or higher float from string.
+///
+/// This matches `float`, which skips whitespace and accepts single
+/// underscores between digits, from PEP 515.
 #[rustfmt::skip]
-pub const PYTHON3_STRING: u128 = NumberFormatBuilder::new().build();
-const_assert!(NumberFormat::<{ PYTHON3_STRING }> {}.is_valid());
+pub const PYTHON36_STRING: u128 = NumberFormatBuilder::new()
+    .digit_separator(num::NonZeroU8::new(b'_'))
+    .internal_digit_separator(true)
+    .skip_whitespace(true)
+    .build();
+
+const_assert!(NumberFormat::<{ PYTHON36_STRING }> {}.is_valid());
+
+// PYTHON3.5- STRING [0134567MNQR]
+/// Number format to parse a `Python3.5` or lower float from string.
+#[rustfmt::skip]
+pub const PYTHON35_STRING: u128 = NumberFormatBuilder::new()
+    .skip_whitespace(true)
+    .build();
+
+const_assert!(NumberFormat::<{ PYTHON35_STRING }> {}.is_valid());
+
+// PYTHON REPR [0134567MNQR-_]
+/// Number format to write a `Python` float like `repr`.
+///
+/// This always writes the exponent sign, like `1e+16`, and otherwise
+/// parses like [`PYTHON36_STRING`]. Use it with the `PYTHON_REPR` write
+/// float options.
+#[rustfmt::skip]
+pub const PYTHON_REPR: u128 = NumberFormatBuilder::rebuild(PYTHON36_STRING)
+    .required_exponent_sign(true)
+    .build();
+
+const_assert!(NumberFormat::<{ PYTHON_REPR }> {}.is_valid());
 
 // PYTHON3.6+ LITERAL [013456N-_]
 /// Number format for a `Python3.6` or higher literal floating-point number.
@@ -1915,15 +2043,54 @@ pub const GO_LITERAL: u128 = NumberFormatBuilder::new()
 
 const_assert!(NumberFormat::<{ GO_LITERAL }> {}.is_valid());
 
-// GO STRING [134567MN]
+// GO STRING [0134567MN-_]
 /// Number format to parse a `Golang` float from string.
+///
+/// This matches decimal floats for `strconv.ParseFloat`, which accepts
+/// single underscores between digits. `ParseFloat` also accepts
+/// hexadecimal floats with a `0x` prefix: parse those with
+/// `GO_HEX_STRING` and the `HEX_FLOAT` parse float options.
 #[rustfmt::skip]
 pub const GO_STRING: u128 = NumberFormatBuilder::new()
-    .required_fraction_digits(true)
+    .digit_separator(num::NonZeroU8::new(b'_'))
+    .internal_digit_separator(true)
     .build();
 
 const_assert!(NumberFormat::<{ GO_STRING }> {}.is_valid());
 
+// GO HEX STRING [0134567MN-_]
+/// Number format to parse a `Golang` hexadecimal float from string.
+///
+/// This matches hexadecimal floats for `strconv.ParseFloat`, like
+/// `0x1.8p+1`, which require a binary exponent.
+#[rustfmt::skip]
+#[cfg(feature = "power-of-two")]
+pub const GO_HEX_STRING: u128 = NumberFormatBuilder::new()
+    .digit_separator(num::NonZeroU8::new(b'_'))
+    .internal_digit_separator(true)
+    .required_exponent_notation(true)
+    .base_prefix(num::NonZeroU8::new(b'x'))
+    .mantissa_radix(16)
+    .exponent_base(num::NonZeroU8::new(2))
+    .exponent_radix(num::NonZeroU8::new(10))
+    .build();
+
+#[cfg(feature = "power-of-two")]
+const_assert!(NumberFormat::<{ GO_HEX_STRING }> {}.is_valid());
+
+// GO FORMAT FLOAT [0134567MN-_]
+/// Number format to write a `Golang` float like `strconv.FormatFloat`.
+///
+/// This always writes the exponent sign, like `1e+06`, and otherwise
+/// parses like [`GO_STRING`]. Use it with the `GO_FORMAT_FLOAT` write
+/// float options.
+#[rustfmt::skip]
+pub const GO_FORMAT_FLOAT: u128 = NumberFormatBuilder::rebuild(GO_STRING)
+    .required_exponent_sign(true)
+    .build();
+
+const_assert!(NumberFormat::<{ GO_FORMAT_FLOAT }> {}.is_valid());
+
 // HASKELL LITERAL [456MN]
 /// Number format for a `Haskell` literal floating-point number.
 #[rustfmt::skip]
@@ -1966,6 +2133,22 @@ pub const JAVASCRIPT_STRING: u128 = NumberFormatBuilder::new()
 
 const_assert!(NumberFormat::<{ JAVASCRIPT_STRING }> {}.is_valid());
 
+// JAVASCRIPT TO STRING [012345678MN]
+/// Number format to write a `Javascript` float like `Number.prototype.toString`.
+///
+/// This always writes the exponent sign, like `1e+21`, and lowercase
+/// digits, and otherwise parses like [`JAVASCRIPT_STRING`]. Use it with
+/// the `JAVASCRIPT_TO_STRING` write float options. For `toString(radix)`,
+/// rebuild it with the radix as the mantissa radix and exponent base, and
+/// without exponent notation.
+#[rustfmt::skip]
+pub const JAVASCRIPT_TO_STRING: u128 = NumberFormatBuilder::rebuild(JAVASCRIPT_STRING)
+    .required_exponent_sign(true)
+    .no_uppercase_digits(true)
+    .build();
+
+const_assert!(NumberFormat::<{ JAVASCRIPT_TO_STRING }> {}.is_valid());
+
 // PERL LITERAL [0134569ABDEFGHIJKMN-_]
 /// Number format for a `Perl` literal floating-point number.
 #[rustfmt::skip]
@@ -2016,15 +2199,40 @@ pub const JAVA_LITERAL: u128 = NumberFormatBuilder::new()
 
 const_assert!(NumberFormat::<{ JAVA_LITERAL }> {}.is_valid());
 
-// JAVA STRING [01345678MN]
+// JAVA STRING [01345678MNQR]
 /// Number format to parse a `Java` float from string.
+///
+/// This matches decimal floats for `Double.parseDouble`, which skips
+/// whitespace. `parseDouble` also accepts hexadecimal floats with a
+/// `0x` prefix: parse those with `JAVA_HEX_STRING` and the `HEX_FLOAT`
+/// parse float options. The `f` and `d` type suffixes are not accepted.
 #[rustfmt::skip]
 pub const JAVA_STRING: u128 = NumberFormatBuilder::new()
     .case_sensitive_special(true)
+    .skip_whitespace(true)
     .build();
 
 const_assert!(NumberFormat::<{ JAVA_STRING }> {}.is_valid());
 
+// JAVA HEX STRING [0134567MNQR]
+/// Number format to parse a `Java` hexadecimal float from string.
+///
+/// This matches hexadecimal floats for `Double.parseDouble`, like
+/// `0x1.8p1`, which require a binary exponent.
+#[rustfmt::skip]
+#[cfg(feature = "power-of-two")]
+pub const JAVA_HEX_STRING: u128 = NumberFormatBuilder::new()
+    .required_exponent_notation(true)
+    .skip_whitespace(true)
+    .base_prefix(num::NonZeroU8::new(b'x'))
+    .mantissa_radix(16)
+    .exponent_base(num::NonZeroU8::new(2))
+    .exponent_radix(num::NonZeroU8::new(10))
+    .build();
+
+#[cfg(feature = "power-of-two")]
+const_assert!(NumberFormat::<{ JAVA_HEX_STRING }> {}.is_valid());
+
 // R LITERAL [01345678MN]
 /// Number format for a `R` literal floating-point number.
 #[rustfmt::skip]
@@ -2127,15 +2335,36 @@ pub const CSHARP7_LITERAL: u128 = NumberFormatBuilder::new()
 
 const_assert!(NumberFormat::<{ CSHARP7_LITERAL }> {}.is_valid());
 
-// CSHARP7 STRING [0134568MN]
+// CSHARP7 STRING [01345689FIMNQR-,]
 /// Number format to parse a `C#7` float from string.
+///
+/// This matches `double.Parse` with the invariant culture, which skips
+/// whitespace and accepts `,` thousands separators in the integer digits.
 #[rustfmt::skip]
 pub const CSHARP7_STRING: u128 = NumberFormatBuilder::new()
+    .digit_separator(num::NonZeroU8::new(b','))
+    .integer_internal_digit_separator(true)
+    .integer_trailing_digit_separator(true)
+    .integer_consecutive_digit_separator(true)
     .case_sensitive_special(true)
+    .skip_whitespace(true)
     .build();
 
 const_assert!(NumberFormat::<{ CSHARP7_STRING }> {}.is_valid());
 
+// CSHARP ROUND TRIP [01345689FIMNQR-,]
+/// Number format to write a `C#` float like `double.ToString("R")`.
+///
+/// This always writes the exponent sign, like `1E+15`, and otherwise
+/// parses like [`CSHARP_STRING`]. Use it with the `CSHARP_ROUND_TRIP`
+/// write float options.
+#[rustfmt::skip]
+pub const CSHARP_ROUND_TRIP: u128 = NumberFormatBuilder::rebuild(CSHARP7_STRING)
+    .required_exponent_sign(true)
+    .build();
+
+const_assert!(NumberFormat::<{ CSHARP_ROUND_TRIP }> {}.is_valid());
+
 // CSHARP6 LITERAL [03456MN]
 /// Number format for a `C#6` literal floating-point number.
 #[rustfmt::skip]
@@ -2146,11 +2375,16 @@ pub const CSHARP6_LITERAL: u128 = NumberFormatBuilder::new()
 
 const_assert!(NumberFormat::<{ CSHARP6_LITERAL }> {}.is_valid());
 
-// CSHARP6 STRING [0134568MN]
+// CSHARP6 STRING [01345689FIMNQR-,]
 /// Number format to parse a `C#6` float from string.
 #[rustfmt::skip]
 pub const CSHARP6_STRING: u128 = NumberFormatBuilder::new()
+    .digit_separator(num::NonZeroU8::new(b','))
+    .integer_internal_digit_separator(true)
+    .integer_trailing_digit_separator(true)
+    .integer_consecutive_digit_separator(true)
     .case_sensitive_special(true)
+    .skip_whitespace(true)
     .build();
 
 const_assert!(NumberFormat::<{ CSHARP6_STRING }> {}.is_valid());
@@ -2165,11 +2399,16 @@ pub const CSHARP5_LITERAL: u128 = NumberFormatBuilder::new()
 
 const_assert!(NumberFormat::<{ CSHARP5_LITERAL }> {}.is_valid());
 
-// CSHARP5 STRING [0134568MN]
+// CSHARP5 STRING [01345689FIMNQR-,]
 /// Number format to parse a `C#5` float from string.
 #[rustfmt::skip]
 pub const CSHARP5_STRING: u128 = NumberFormatBuilder::new()
+    .digit_separator(num::NonZeroU8::new(b','))
+    .integer_internal_digit_separator(true)
+    .integer_trailing_digit_separator(true)
+    .integer_consecutive_digit_separator(true)
     .case_sensitive_special(true)
+    .skip_whitespace(true)
     .build();
 
 const_assert!(NumberFormat::<{ CSHARP5_STRING }> {}.is_valid());
@@ -2184,11 +2423,16 @@ pub const CSHARP4_LITERAL: u128 = NumberFormatBuilder::new()
 
 const_assert!(NumberFormat::<{ CSHARP4_LITERAL }> {}.is_valid());
 
-// CSHARP4 STRING [0134568MN]
+// CSHARP4 STRING [01345689FIMNQR-,]
 /// Number format to parse a `C#4` float from string.
 #[rustfmt::skip]
 pub const CSHARP4_STRING: u128 = NumberFormatBuilder::new()
+    .digit_separator(num::NonZeroU8::new(b','))
+    .integer_internal_digit_separator(true)
+    .integer_trailing_digit_separator(true)
+    .integer_consecutive_digit_separator(true)
     .case_sensitive_special(true)
+    .skip_whitespace(true)
     .build();
 
 const_assert!(NumberFormat::<{ CSHARP4_STRING }> {}.is_valid());
@@ -2203,11 +2447,16 @@ pub const CSHARP3_LITERAL: u128 = NumberFormatBuilder::new()
 
 const_assert!(NumberFormat::<{ CSHARP3_LITERAL }> {}.is_valid());
 
-// CSHARP3 STRING [0134568MN]
+// CSHARP3 STRING [01345689FIMNQR-,]
 /// Number format to parse a `C#3` float from string.
 #[rustfmt::skip]
 pub const CSHARP3_STRING: u128 = NumberFormatBuilder::new()
+    .digit_separator(num::NonZeroU8::new(b','))
+    .integer_internal_digit_separator(true)
+    .integer_trailing_digit_separator(true)
+    .integer_consecutive_digit_separator(true)
     .case_sensitive_special(true)
+    .skip_whitespace(true)
     .build();
 
 const_assert!(NumberFormat::<{ CSHARP3_STRING }> {}.is_valid());
@@ -2222,11 +2471,16 @@ pub const CSHARP2_LITERAL: u128 = NumberFormatBuilder::new()
 
 const_assert!(NumberFormat::<{ CSHARP2_LITERAL }> {}.is_valid());
 
-// CSHARP2 STRING [0134568MN]
+// CSHARP2 STRING [01345689FIMNQR-,]
 /// Number format to parse a `C#2` float from string.
 #[rustfmt::skip]
 pub const CSHARP2_STRING: u128 = NumberFormatBuilder::new()
+    .digit_separator(num::NonZeroU8::new(b','))
+    .integer_internal_digit_separator(true)
+    .integer_trailing_digit_separator(true)
+    .integer_consecutive_digit_separator(true)
     .case_sensitive_special(true)
+    .skip_whitespace(true)
     .build();
 
 const_assert!(NumberFormat::<{ CSHARP2_STRING }> {}.is_valid());
@@ -2241,11 +2495,16 @@ pub const CSHARP1_LITERAL: u128 = NumberFormatBuilder::new()
 
 const_assert!(NumberFormat::<{ CSHARP1_LITERAL }> {}.is_valid());
 
-// CSHARP1 STRING [0134568MN]
+// CSHARP1 STRING [01345689FIMNQR-,]
 /// Number format to parse a `C#1` float from string.
 #[rustfmt::skip]
 pub const CSHARP1_STRING: u128 = NumberFormatBuilder::new()
+    .digit_separator(num::NonZeroU8::new(b','))
+    .integer_internal_digit_separator(true)
+    .integer_trailing_digit_separator(true)
+    .integer_consecutive_digit_separator(true)
     .case_sensitive_special(true)
+    .skip_whitespace(true)
     .build();
 
 const_assert!(NumberFormat::<{ CSHARP1_STRING }> {}.is_valid());
@@ -2727,13 +2986,17 @@ pub const JSON: u128 = NumberFormatBuilder::new()
 
 const_assert!(NumberFormat::<{ JSON }> {}.is_valid());
 
-// TOML [34569AB]
+// TOML [345679AB-_]
 /// Number format for a `TOML` literal floating-point number.
+///
+/// This requires digits before and after the decimal point, accepts
+/// single underscores between digits, and rejects leading zeros. Use it
+/// with the `TOML` options, for the lowercase `inf` and `nan`.
 #[rustfmt::skip]
 pub const TOML: u128 = NumberFormatBuilder::new()
     .digit_separator(num::NonZeroU8::new(b'_'))
-    .required_digits(false)
-    .no_special(true)
+    .required_digits(true)
+    .case_sensitive_special(true)
     .no_integer_leading_zeros(true)
     .no_float_leading_zeros(true)
     .internal_digit_separator(true)
@@ -2741,9 +3004,42 @@ pub const TOML: u128 = NumberFormatBuilder::new()
 
 const_assert!(NumberFormat::<{ TOML }> {}.is_valid());
 
-// YAML (defined in-terms of JSON schema).
+// YAML [0134567]
 /// Number format for a `YAML` literal floating-point number.
-pub const YAML: u128 = JSON;
+///
+/// This matches the `YAML` 1.2 core schema, which accepts `.5`, `5.`, and
+/// leading zeros. Use it with the `YAML` options, for `.inf` and `.nan`.
+#[rustfmt::skip]
+pub const YAML: u128 = NumberFormatBuilder::new().build();
+
+const_assert!(NumberFormat::<{ YAML }> {}.is_valid());
+
+// JSON5 [01345678]
+/// Number format for a `JSON5` literal floating-point number.
+///
+/// This accepts a leading `+`, `.5`, and `5.`, but rejects leading zeros.
+/// Use it with the `JSON5` options, for `Infinity` and `NaN`. Parse
+/// hexadecimal integers, like `0xFF`, with `JSON5_HEX`.
+#[rustfmt::skip]
+pub const JSON5: u128 = NumberFormatBuilder::new()
+    .case_sensitive_special(true)
+    .no_integer_leading_zeros(true)
+    .no_float_leading_zeros(true)
+    .build();
+
+const_assert!(NumberFormat::<{ JSON5 }> {}.is_valid());
+
+// JSON5 HEX
+/// Number format for a `JSON5` literal hexadecimal integer, like `0xFF`.
+#[rustfmt::skip]
+#[cfg(feature = "power-of-two")]
+pub const JSON5_HEX: u128 = NumberFormatBuilder::new()
+    .mantissa_radix(16)
+    .base_prefix(num::NonZeroU8::new(b'x'))
+    .build();
+
+#[cfg(feature = "power-of-two")]
+const_assert!(NumberFormat::<{ JSON5_HEX }> {}.is_valid());
 
 // XML [01234578MN]
 /// Number format for a `XML` literal floating-point number.
@@ -2764,6 +3060,22 @@ pub const SQLITE: u128 = NumberFormatBuilder::new()
 
 const_assert!(NumberFormat::<{ SQLITE }> {}.is_valid());
 
+// SQLITE STRING [013456MNQRU]
+/// Number format to parse a `SQLite` float from a text value.
+///
+/// This matches `CAST(x AS REAL)`, which skips whitespace and converts
+/// the longest numeric prefix, ignoring any trailing bytes like the `e`
+/// in `1e`: use it with the partial parsers. `SQLite` has no special
+/// values, so `Infinity` is not a number.
+#[rustfmt::skip]
+pub const SQLITE_STRING: u128 = NumberFormatBuilder::new()
+    .no_special(true)
+    .skip_whitespace(true)
+    .trailing_empty_exponent(true)
+    .build();
+
+const_assert!(NumberFormat::<{ SQLITE_STRING }> {}.is_valid());
+
 // POSTGRESQL [013456MN]
 /// Number format for a `PostgreSQL` literal floating-point number.
 #[rustfmt::skip]
@@ -2773,6 +3085,19 @@ pub const POSTGRESQL: u128 = NumberFormatBuilder::new()
 
 const_assert!(NumberFormat::<{ POSTGRESQL }> {}.is_valid());
 
+// POSTGRESQL STRING [0134567MNQR]
+/// Number format to parse a `PostgreSQL` float from a text value.
+///
+/// This matches the `float8` input function, which skips whitespace,
+/// accepts case-insensitive `NaN`, `Infinity`, and `inf`, and rejects
+/// any other trailing bytes.
+#[rustfmt::skip]
+pub const POSTGRESQL_STRING: u128 = NumberFormatBuilder::new()
+    .skip_whitespace(true)
+    .build();
+
+const_assert!(NumberFormat::<{ POSTGRESQL_STRING }> {}.is_valid());
+
 // MYSQL [013456MN]
 /// Number format for a `MySQL` literal floating-point number.
 #[rustfmt::skip]
@@ -17,7 +17,10 @@
 #![cfg_attr(feature = "format", doc = " - [`PYTHON3_LITERAL`]")]
 #![cfg_attr(feature = "format", doc = " - [`PYTHON3_STRING`]")]
 #![cfg_attr(feature = "format", doc = " - [`PYTHON36_LITERAL`]")]
+#![cfg_attr(feature = "format", doc = " - [`PYTHON36_STRING`]")]
 #![cfg_attr(feature = "format", doc = " - [`PYTHON35_LITERAL`]")]
+#![cfg_attr(feature = "format", doc = " - [`PYTHON35_STRING`]")]
+#![cfg_attr(feature = "format", doc = " - [`PYTHON_REPR`]")]
 #![cfg_attr(feature = "format", doc = " - [`PYTHON2_LITERAL`]")]
 #![cfg_attr(feature = "format", doc = " - [`PYTHON2_STRING`]")]
 #![cfg_attr(feature = "format", doc = " - [`CXX_LITERAL`]")]
@@ -71,16 +74,20 @@
 #![cfg_attr(feature = "format", doc = " - [`SWIFT_STRING`]")]
 #![cfg_attr(feature = "format", doc = " - [`GO_LITERAL`]")]
 #![cfg_attr(feature = "format", doc = " - [`GO_STRING`]")]
+#![cfg_attr(all(feature = "format", feature = "power-of-two"), doc = " - [`GO_HEX_STRING`]")]
+#![cfg_attr(feature = "format", doc = " - [`GO_FORMAT_FLOAT`]")]
 #![cfg_attr(feature = "format", doc = " - [`HASKELL_LITERAL`]")]
 #![cfg_attr(feature = "format", doc = " - [`HASKELL_STRING`]")]
 #![cfg_attr(feature = "format", doc = " - [`JAVASCRIPT_LITERAL`]")]
 #![cfg_attr(feature = "format", doc = " - [`JAVASCRIPT_STRING`]")]
+#![cfg_attr(feature = "format", doc = " - [`JAVASCRIPT_TO_STRING`]")]
 #![cfg_attr(feature = "format", doc = " - [`PERL_LITERAL`]")]
 #![cfg_attr(feature = "format", doc = " - [`PERL_STRING`]")]
 #![cfg_attr(feature = "format", doc = " - [`PHP_LITERAL`]")]
 #![cfg_attr(feature = "format", doc = " - [`PHP_STRING`]")]
 #![cfg_attr(feature = "format", doc = " - [`JAVA_LITERAL`]")]
 #![cfg_attr(feature = "format", doc = " - [`JAVA_STRING`]")]
+#![cfg_attr(all(feature = "format", feature = "power-of-two"), doc = " - [`JAVA_HEX_STRING`]")]
 #![cfg_attr(feature = "format", doc = " - [`R_LITERAL`]")]
 #![cfg_attr(feature = "format", doc = " - [`R_STRING`]")]
 #![cfg_attr(feature = "format", doc = " - [`KOTLIN_LITERAL`]")]
@@ -91,6 +98,7 @@
 #![cfg_attr(all(feature = "format", feature = "power-of-two"), doc = " - [`JULIA_HEX_STRING`]")]
 #![cfg_attr(feature = "format", doc = " - [`CSHARP_LITERAL`]")]
 #![cfg_attr(feature = "format", doc = " - [`CSHARP_STRING`]")]
+#![cfg_attr(feature = "format", doc = " - [`CSHARP_ROUND_TRIP`]")]
 #![cfg_attr(feature = "format", doc = " - [`CSHARP7_LITERAL`]")]
 #![cfg_attr(feature = "format", doc = " - [`CSHARP7_STRING`]")]
 #![cfg_attr(feature = "format", doc = " - [`CSHARP6_LITERAL`]")]
@@ -150,9 +158,13 @@
 #![cfg_attr(feature = "format", doc = " - [`JSON`]")]
 #![cfg_attr(feature = "format", doc = " - [`TOML`]")]
 #![cfg_attr(feature = "format", doc = " - [`YAML`]")]
+#![cfg_attr(feature = "format", doc = " - [`JSON5`]")]
+#![cfg_attr(all(feature = "format", feature = "power-of-two"), doc = " - [`JSON5_HEX`]")]
 #![cfg_attr(feature = "format", doc = " - [`XML`]")]
 #![cfg_attr(feature = "format", doc = " - [`SQLITE`]")]
+#![cfg_attr(feature = "format", doc = " - [`SQLITE_STRING`]")]
 #![cfg_attr(feature = "format", doc = " - [`POSTGRESQL`]")]
+#![cfg_attr(feature = "format", doc = " - [`POSTGRESQL_STRING`]")]
 #![cfg_attr(feature = "format", doc = " - [`MYSQL`]")]
 #![cfg_attr(feature = "format", doc = " - [`MONGODB`]")]
 //!
@@ -179,6 +191,15 @@
 //! - [`CASE_SENSITIVE_EXPONENT`]
 //! - [`CASE_SENSITIVE_BASE_PREFIX`]
 //! - [`CASE_SENSITIVE_BASE_SUFFIX`]
+//! - [`SKIP_LEADING_WHITESPACE`]
+//! - [`SKIP_TRAILING_WHITESPACE`]
+//! - [`SKIP_WHITESPACE`]
+//! - [`UNICODE_SIGNS`]
+//! - [`PARENTHESIZED_NEGATIVES`]
+//! - [`TRAILING_EMPTY_EXPONENT`]
+//! - [`NO_UPPERCASE_DIGITS`]
+//! - [`NO_LOWERCASE_DIGITS`]
+//! - [`NO_SUBNORMALS`]
 //!
 //! # Digit Separator Flags
 //!
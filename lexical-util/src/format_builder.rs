@@ -4,7 +4,12 @@ use core::{mem, num};
 
 use static_assertions::const_assert;
 
+use crate::error::Error;
+#[cfg(feature = "format")]
+use crate::feature_format::format_error_impl;
 use crate::format_flags as flags;
+#[cfg(not(feature = "format"))]
+use crate::not_feature_format::format_error_impl;
 
 /// Type with the exact same size as a `u8`.
 pub type OptionU8 = Option<num::NonZeroU8>;
@@ -95,6 +100,22 @@ const fn unwrap_or_zero(option: OptionU8) -> u8 {
 ///   case-sensitive.
 /// * `case_sensitive_base_suffix`              - If base suffixes are
 ///   case-sensitive.
+/// * `skip_leading_whitespace`                 - If ASCII whitespace before the
+///   number is skipped.
+/// * `skip_trailing_whitespace`                - If ASCII whitespace after the
+///   number is skipped.
+/// * `unicode_signs`                           - If Unicode minus and plus
+///   signs are accepted.
+/// * `parenthesized_negatives`                 - If negative numbers are
+///   enclosed in parentheses.
+/// * `trailing_empty_exponent`                 - If an exponent without digits
+///   is left as trailing bytes.
+/// * `no_uppercase_digits`                     - If uppercase letters are not
+///   allowed as digits.
+/// * `no_lowercase_digits`                     - If lowercase letters are not
+///   allowed as digits.
+/// * `no_subnormals`                           - If subnormal floats are not
+///   allowed.
 /// * `integer_internal_digit_separator`        - If digit separators are
 ///   allowed between integer digits.
 /// * `fraction_internal_digit_separator`       - If digit separators are
@@ -181,6 +202,14 @@ const fn unwrap_or_zero(option: OptionU8) -> u8 {
 /// * `case_sensitive_exponent`
 /// * `case_sensitive_base_prefix`
 /// * `case_sensitive_base_suffix`
+/// * `skip_leading_whitespace`
+/// * `skip_trailing_whitespace`
+/// * `unicode_signs`
+/// * `parenthesized_negatives`
+/// * `trailing_empty_exponent`
+/// * `no_uppercase_digits`
+/// * `no_lowercase_digits`
+/// * `no_subnormals`
 /// * `integer_internal_digit_separator`
 /// * `fraction_internal_digit_separator`
 /// * `exponent_internal_digit_separator`
@@ -193,6 +222,7 @@ const fn unwrap_or_zero(option: OptionU8) -> u8 {
 /// * `integer_consecutive_digit_separator`
 /// * `fraction_consecutive_digit_separator`
 /// * `special_digit_separator`
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct NumberFormatBuilder {
     digit_separator: OptionU8,
     base_prefix: OptionU8,
@@ -218,6 +248,14 @@ pub struct NumberFormatBuilder {
     case_sensitive_exponent: bool,
     case_sensitive_base_prefix: bool,
     case_sensitive_base_suffix: bool,
+    skip_leading_whitespace: bool,
+    skip_trailing_whitespace: bool,
+    unicode_signs: bool,
+    parenthesized_negatives: bool,
+    trailing_empty_exponent: bool,
+    no_uppercase_digits: bool,
+    no_lowercase_digits: bool,
+    no_subnormals: bool,
     integer_internal_digit_separator: bool,
     fraction_internal_digit_separator: bool,
     exponent_internal_digit_separator: bool,
@@ -264,6 +302,14 @@ impl NumberFormatBuilder {
             case_sensitive_exponent: false,
             case_sensitive_base_prefix: false,
             case_sensitive_base_suffix: false,
+            skip_leading_whitespace: false,
+            skip_trailing_whitespace: false,
+            unicode_signs: false,
+            parenthesized_negatives: false,
+            trailing_empty_exponent: false,
+            no_uppercase_digits: false,
+            no_lowercase_digits: false,
+            no_subnormals: false,
             integer_internal_digit_separator: false,
             fraction_internal_digit_separator: false,
             exponent_internal_digit_separator: false,
@@ -463,6 +509,54 @@ impl NumberFormatBuilder {
         self.case_sensitive_base_suffix
     }
 
+    /// Get if ASCII whitespace before the number is skipped.
+    #[inline(always)]
+    pub const fn get_skip_leading_whitespace(&self) -> bool {
+        self.skip_leading_whitespace
+    }
+
+    /// Get if ASCII whitespace after the number is skipped.
+    #[inline(always)]
+    pub const fn get_skip_trailing_whitespace(&self) -> bool {
+        self.skip_trailing_whitespace
+    }
+
+    /// Get if Unicode minus and plus signs are accepted.
+    #[inline(always)]
+    pub const fn get_unicode_signs(&self) -> bool {
+        self.unicode_signs
+    }
+
+    /// Get if negative numbers are enclosed in parentheses.
+    #[inline(always)]
+    pub const fn get_parenthesized_negatives(&self) -> bool {
+        self.parenthesized_negatives
+    }
+
+    /// Get if an exponent without digits is left as trailing bytes.
+    #[inline(always)]
+    pub const fn get_trailing_empty_exponent(&self) -> bool {
+        self.trailing_empty_exponent
+    }
+
+    /// Get if uppercase letters are not allowed as digits.
+    #[inline(always)]
+    pub const fn get_no_uppercase_digits(&self) -> bool {
+        self.no_uppercase_digits
+    }
+
+    /// Get if lowercase letters are not allowed as digits.
+    #[inline(always)]
+    pub const fn get_no_lowercase_digits(&self) -> bool {
+        self.no_lowercase_digits
+    }
+
+    /// Get if subnormal floats are not allowed.
+    #[inline(always)]
+    pub const fn get_no_subnormals(&self) -> bool {
+        self.no_subnormals
+    }
+
     /// Get if digit separators are allowed between integer digits.
     ///
     /// This will not consider an input of only the digit separator
@@ -783,6 +877,94 @@ impl NumberFormatBuilder {
         self
     }
 
+    /// Set if ASCII whitespace before the number is skipped.
+    #[inline(always)]
+    #[cfg(feature = "format")]
+    pub const fn skip_leading_whitespace(mut self, flag: bool) -> Self {
+        self.skip_leading_whitespace = flag;
+        self
+    }
+
+    /// Set if ASCII whitespace after the number is skipped.
+    #[inline(always)]
+    #[cfg(feature = "format")]
+    pub const fn skip_trailing_whitespace(mut self, flag: bool) -> Self {
+        self.skip_trailing_whitespace = flag;
+        self
+    }
+
+    /// Set if ASCII whitespace before and after the number is skipped.
+    #[inline(always)]
+    #[cfg(feature = "format")]
+    pub const fn skip_whitespace(mut self, flag: bool) -> Self {
+        self = self.skip_leading_whitespace(flag);
+        self = self.skip_trailing_whitespace(flag);
+        self
+    }
+
+    /// Set if Unicode minus and plus signs are accepted.
+    ///
+    /// This accepts U+2212 MINUS SIGN, U+FF0D FULLWIDTH HYPHEN-MINUS, and
+    /// U+FF0B FULLWIDTH PLUS SIGN in addition to `-` and `+`.
+    #[inline(always)]
+    #[cfg(feature = "format")]
+    pub const fn unicode_signs(mut self, flag: bool) -> Self {
+        self.unicode_signs = flag;
+        self
+    }
+
+    /// Set if negative numbers are enclosed in parentheses.
+    ///
+    /// This is the accounting style, where `(123.45)` is `-123.45`.
+    #[inline(always)]
+    #[cfg(feature = "format")]
+    pub const fn parenthesized_negatives(mut self, flag: bool) -> Self {
+        self.parenthesized_negatives = flag;
+        self
+    }
+
+    /// Set if an exponent without digits is left as trailing bytes.
+    ///
+    /// With this, `1e+` is `1.0` followed by the trailing bytes `e+`, like
+    /// C's `strtod`, rather than an empty exponent.
+    #[inline(always)]
+    #[cfg(feature = "format")]
+    pub const fn trailing_empty_exponent(mut self, flag: bool) -> Self {
+        self.trailing_empty_exponent = flag;
+        self
+    }
+
+    /// Set if uppercase letters are not allowed as digits.
+    ///
+    /// With a hexadecimal radix, this rejects `0x1A` but accepts `0x1a`.
+    #[inline(always)]
+    #[cfg(feature = "format")]
+    pub const fn no_uppercase_digits(mut self, flag: bool) -> Self {
+        self.no_uppercase_digits = flag;
+        self
+    }
+
+    /// Set if lowercase letters are not allowed as digits.
+    ///
+    /// With a hexadecimal radix, this rejects `0x1a` but accepts `0x1A`.
+    #[inline(always)]
+    #[cfg(feature = "format")]
+    pub const fn no_lowercase_digits(mut self, flag: bool) -> Self {
+        self.no_lowercase_digits = flag;
+        self
+    }
+
+    /// Set if subnormal floats are not allowed.
+    ///
+    /// Parsing a float that rounds to a subnormal value, like `5e-324`
+    /// for an `f64`, fails with an underflow error.
+    #[inline(always)]
+    #[cfg(feature = "format")]
+    pub const fn no_subnormals(mut self, flag: bool) -> Self {
+        self.no_subnormals = flag;
+        self
+    }
+
     /// Set if digit separators are allowed between integer digits.
     ///
     /// This will not consider an input of only the digit separator
@@ -1018,7 +1200,9 @@ impl NumberFormatBuilder {
     /// NOTE: This function will never fail, due to issues with panicking
     /// (and therefore unwrapping Errors/Options) in const fns. It is
     /// therefore up to you to ensure the format is valid, called via the
-    /// `is_valid` function on `NumberFormat`.
+    /// `is_valid` function on `NumberFormat`, or to use [`build_strict`].
+    ///
+    /// [`build_strict`]: Self::build_strict
     #[inline(always)]
     pub const fn build(&self) -> u128 {
         let mut format: u128 = 0;
@@ -1042,6 +1226,14 @@ impl NumberFormatBuilder {
             self.case_sensitive_exponent, CASE_SENSITIVE_EXPONENT ;
             self.case_sensitive_base_prefix, CASE_SENSITIVE_BASE_PREFIX ;
             self.case_sensitive_base_suffix, CASE_SENSITIVE_BASE_SUFFIX ;
+            self.skip_leading_whitespace, SKIP_LEADING_WHITESPACE ;
+            self.skip_trailing_whitespace, SKIP_TRAILING_WHITESPACE ;
+            self.unicode_signs, UNICODE_SIGNS ;
+            self.parenthesized_negatives, PARENTHESIZED_NEGATIVES ;
+            self.trailing_empty_exponent, TRAILING_EMPTY_EXPONENT ;
+            self.no_uppercase_digits, NO_UPPERCASE_DIGITS ;
+            self.no_lowercase_digits, NO_LOWERCASE_DIGITS ;
+            self.no_subnormals, NO_SUBNORMALS ;
             self.integer_internal_digit_separator, INTEGER_INTERNAL_DIGIT_SEPARATOR ;
             self.fraction_internal_digit_separator, FRACTION_INTERNAL_DIGIT_SEPARATOR ;
             self.exponent_internal_digit_separator, EXPONENT_INTERNAL_DIGIT_SEPARATOR ;
@@ -1069,6 +1261,48 @@ impl NumberFormatBuilder {
         format
     }
 
+    /// Get the error type from the builder options.
+    ///
+    /// This is identical to [`NumberFormat::error`] for the built format.
+    ///
+    /// [`NumberFormat::error`]: crate::format::NumberFormat::error
+    #[inline(always)]
+    pub const fn error(&self) -> Error {
+        format_error_impl(self.build())
+    }
+
+    /// Determine if the builder options create a valid format.
+    #[inline(always)]
+    pub const fn is_valid(&self) -> bool {
+        self.error().is_success()
+    }
+
+    /// Create 128-bit, packed number format struct from builder options,
+    /// panicking if the format is invalid.
+    ///
+    /// When evaluated in a `const` item, an invalid format, such as digit
+    /// separator flags without a digit separator character, is a compile
+    /// error rather than a runtime error, so the format can be safely
+    /// used as a const generic parameter:
+    ///
+    /// ```rust
+    /// use lexical_util::format::NumberFormatBuilder;
+    ///
+    /// const FORMAT: u128 = NumberFormatBuilder::new().build_strict();
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if the format is invalid.
+    #[inline(always)]
+    pub const fn build_strict(&self) -> u128 {
+        let error = self.error();
+        if !error.is_success() {
+            panic!("{}", error.kind().description());
+        }
+        self.build()
+    }
+
     /// Re-create builder from format.
     #[inline(always)]
     pub const fn rebuild(format: u128) -> Self {
@@ -1097,6 +1331,14 @@ impl NumberFormatBuilder {
             case_sensitive_exponent: has_flag!(format, CASE_SENSITIVE_EXPONENT),
             case_sensitive_base_prefix: has_flag!(format, CASE_SENSITIVE_BASE_PREFIX),
             case_sensitive_base_suffix: has_flag!(format, CASE_SENSITIVE_BASE_SUFFIX),
+            skip_leading_whitespace: has_flag!(format, SKIP_LEADING_WHITESPACE),
+            skip_trailing_whitespace: has_flag!(format, SKIP_TRAILING_WHITESPACE),
+            unicode_signs: has_flag!(format, UNICODE_SIGNS),
+            parenthesized_negatives: has_flag!(format, PARENTHESIZED_NEGATIVES),
+            trailing_empty_exponent: has_flag!(format, TRAILING_EMPTY_EXPONENT),
+            no_uppercase_digits: has_flag!(format, NO_UPPERCASE_DIGITS),
+            no_lowercase_digits: has_flag!(format, NO_LOWERCASE_DIGITS),
+            no_subnormals: has_flag!(format, NO_SUBNORMALS),
             integer_internal_digit_separator: has_flag!(format, INTEGER_INTERNAL_DIGIT_SEPARATOR),
             fraction_internal_digit_separator: has_flag!(format, FRACTION_INTERNAL_DIGIT_SEPARATOR),
             exponent_internal_digit_separator: has_flag!(format, EXPONENT_INTERNAL_DIGIT_SEPARATOR),
@@ -1129,3 +1371,53 @@ impl Default for NumberFormatBuilder {
         Self::new()
     }
 }
+
+#[cfg(feature = "proptest")]
+impl proptest::arbitrary::Arbitrary for NumberFormatBuilder {
+    type Parameters = ();
+    type Strategy = proptest::strategy::BoxedStrategy<Self>;
+
+    /// Generate valid number formats, with a random radix and, if the
+    /// `format` feature is enabled, a predefined language or data format.
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        use proptest::prelude::*;
+
+        #[cfg(feature = "radix")]
+        let radix = 2u8..=36;
+        #[cfg(all(feature = "power-of-two", not(feature = "radix")))]
+        let radix = proptest::sample::select(vec![2u8, 4, 8, 10, 16, 32]);
+        #[cfg(not(feature = "power-of-two"))]
+        let radix = Just(10u8);
+
+        let from_radix = radix.prop_map(|radix| {
+            let mut builder = Self::new();
+            builder.mantissa_radix = radix;
+            builder.exponent_base = num::NonZeroU8::new(radix);
+            builder.exponent_radix = num::NonZeroU8::new(radix);
+            builder
+        });
+
+        #[cfg(feature = "format")]
+        {
+            use crate::format;
+
+            let predefined = proptest::sample::select(vec![
+                format::RUST_LITERAL,
+                format::PYTHON_LITERAL,
+                format::C_LITERAL,
+                format::JAVASCRIPT_LITERAL,
+                format::JSON,
+                format::TOML,
+                format::XML,
+                format::SQLITE,
+                format::PERMISSIVE,
+                format::IGNORE,
+            ])
+            .prop_map(Self::rebuild);
+            prop_oneof![from_radix, predefined].boxed()
+        }
+
+        #[cfg(not(feature = "format"))]
+        from_radix.boxed()
+    }
+}
@@ -25,7 +25,7 @@
 //!
 //! 16  17  18  19  20  21  22  23  24  25  26  27  28  29  30  31  32
 //! +---+---+---+---+---+---+---+---+---+---+---+---+---+---+---+---+
-//! |e/P|e/S|                                                       |
+//! |e/P|e/S|W/L|W/T|U/S|P/N|e/T|D/U|D/L|S/N|                       |
 //! +---+---+---+---+---+---+---+---+---+---+---+---+---+---+---+---+
 //!
 //! 32  33  34  35  36  37  38  39  40  41 42  43  44  45  46  47   48
@@ -58,6 +58,14 @@
 //!         e/C = Case-sensitive exponent character.
 //!         e/P = Case-sensitive base prefix.
 //!         e/S = Case-sensitive base suffix.
+//!         W/L = Skip leading whitespace.
+//!         W/T = Skip trailing whitespace.
+//!         U/S = Unicode signs.
+//!         P/N = Parenthesized negative numbers.
+//!         e/T = Empty exponent as trailing bytes.
+//!         D/U = No uppercase digits.
+//!         D/L = No lowercase digits.
+//!         S/N = No subnormal floats.
 //!
 //!     Digit Separator Flags:
 //!         I/I = Integer internal digit separator.
@@ -153,6 +161,13 @@
 //! P: '3.0E7'      // Case-insensitive exponent character.
 //! P: '0x3.0'      // Case-insensitive base prefix.
 //! P: '3.0H'       // Case-insensitive base postfix.
+//! Q: ' 3.0'       // Leading whitespace.
+//! R: '3.0 '       // Trailing whitespace.
+//! S: '−3.0'       // Unicode minus sign.
+//! T: '(3.0)'      // Parenthesized negative number.
+//! U: '3.0e+'      // Empty exponent as trailing bytes.
+//! V: '0x1A'       // Uppercase digits.
+//! W: '0x1a'       // Lowercase digits.
 //! ```
 //!
 //! Currently Supported Programming and Data Languages:
@@ -337,6 +352,70 @@ pub const CASE_SENSITIVE_BASE_PREFIX: u128 = 1 << 16;
 /// Base suffixes are case-sensitive.
 pub const CASE_SENSITIVE_BASE_SUFFIX: u128 = 1 << 17;
 
+/// ASCII whitespace before the number is skipped.
+///
+/// Any sign, base prefix, and digits follow the whitespace. Error indexes
+/// and the number of processed bytes include the skipped whitespace.
+pub const SKIP_LEADING_WHITESPACE: u128 = 1 << 18;
+
+/// ASCII whitespace after the number is skipped.
+///
+/// For partial parsers, the whitespace is included in the number of
+/// processed bytes.
+pub const SKIP_TRAILING_WHITESPACE: u128 = 1 << 19;
+
+/// ASCII whitespace before and after the number is skipped.
+pub const SKIP_WHITESPACE: u128 = SKIP_LEADING_WHITESPACE | SKIP_TRAILING_WHITESPACE;
+
+/// Unicode minus and plus signs are accepted in addition to `-` and `+`.
+///
+/// This accepts U+2212 MINUS SIGN, U+FF0D FULLWIDTH HYPHEN-MINUS, and
+/// U+FF0B FULLWIDTH PLUS SIGN, encoded as UTF-8, for both the mantissa
+/// and exponent signs. Each sign is 3 bytes long.
+pub const UNICODE_SIGNS: u128 = 1 << 20;
+
+/// Negative numbers are enclosed in parentheses, rather than using `-`.
+///
+/// This is the accounting style used in financial exports, where
+/// `(123.45)` is `-123.45`. When parsing, `-` is still accepted, but
+/// an opening parenthesis must be closed immediately after the number.
+/// When writing, negative numbers are enclosed in parentheses, and a
+/// minimum width includes both parentheses.
+pub const PARENTHESIZED_NEGATIVES: u128 = 1 << 21;
+
+/// An exponent without digits is not part of the number.
+///
+/// The exponent character and any exponent sign, like `e` or `e+` in
+/// `1e+`, are left as trailing bytes, like C's `strtod`, so a partial
+/// parser returns `1.0` and the number of bytes before the exponent
+/// character, and a complete parser fails with an invalid digit. This
+/// takes precedence over [`REQUIRED_EXPONENT_DIGITS`], which otherwise
+/// fails with an empty exponent, like JSON.
+pub const TRAILING_EMPTY_EXPONENT: u128 = 1 << 22;
+
+/// Uppercase letters are not allowed as digits.
+///
+/// This only applies to radixes above 10 when parsing, so with a
+/// hexadecimal radix, `0x1a` is valid but `0x1A` is not. To also require
+/// a lowercase base prefix, use [`CASE_SENSITIVE_BASE_PREFIX`]. Floats
+/// are written with lowercase digits.
+pub const NO_UPPERCASE_DIGITS: u128 = 1 << 23;
+
+/// Lowercase letters are not allowed as digits.
+///
+/// This only applies to radixes above 10 when parsing, so with a
+/// hexadecimal radix, `0x1A` is valid but `0x1a` is not. To also require
+/// an uppercase base prefix, use [`CASE_SENSITIVE_BASE_PREFIX`].
+pub const NO_LOWERCASE_DIGITS: u128 = 1 << 24;
+
+/// Subnormal floats are not allowed.
+///
+/// Parsing a float that rounds to a subnormal value fails with an
+/// underflow error, rather than producing a denormal. Zero is still
+/// valid, and values that round to zero are handled by the overflow
+/// mode of the parse options.
+pub const NO_SUBNORMALS: u128 = 1 << 25;
+
 // Non-digit separator flags.
 const_assert!(REQUIRED_INTEGER_DIGITS == 1);
 check_subsequent_flags!(REQUIRED_INTEGER_DIGITS, REQUIRED_FRACTION_DIGITS);
@@ -357,6 +436,14 @@ check_subsequent_flags!(NO_FLOAT_LEADING_ZEROS, REQUIRED_EXPONENT_NOTATION);
 check_subsequent_flags!(REQUIRED_EXPONENT_NOTATION, CASE_SENSITIVE_EXPONENT);
 check_subsequent_flags!(CASE_SENSITIVE_EXPONENT, CASE_SENSITIVE_BASE_PREFIX);
 check_subsequent_flags!(CASE_SENSITIVE_BASE_PREFIX, CASE_SENSITIVE_BASE_SUFFIX);
+check_subsequent_flags!(CASE_SENSITIVE_BASE_SUFFIX, SKIP_LEADING_WHITESPACE);
+check_subsequent_flags!(SKIP_LEADING_WHITESPACE, SKIP_TRAILING_WHITESPACE);
+check_subsequent_flags!(SKIP_TRAILING_WHITESPACE, UNICODE_SIGNS);
+check_subsequent_flags!(UNICODE_SIGNS, PARENTHESIZED_NEGATIVES);
+check_subsequent_flags!(PARENTHESIZED_NEGATIVES, TRAILING_EMPTY_EXPONENT);
+check_subsequent_flags!(TRAILING_EMPTY_EXPONENT, NO_UPPERCASE_DIGITS);
+check_subsequent_flags!(NO_UPPERCASE_DIGITS, NO_LOWERCASE_DIGITS);
+check_subsequent_flags!(NO_LOWERCASE_DIGITS, NO_SUBNORMALS);
 
 // DIGIT SEPARATOR FLAGS & MASKS
 // -----------------------------
@@ -523,6 +610,13 @@ pub const FLAG_MASK: u128 =
     CASE_SENSITIVE_EXPONENT |
     CASE_SENSITIVE_BASE_PREFIX |
     CASE_SENSITIVE_BASE_SUFFIX |
+    SKIP_WHITESPACE |
+    UNICODE_SIGNS |
+    PARENTHESIZED_NEGATIVES |
+    TRAILING_EMPTY_EXPONENT |
+    NO_UPPERCASE_DIGITS |
+    NO_LOWERCASE_DIGITS |
+    NO_SUBNORMALS |
     INTERNAL_DIGIT_SEPARATOR |
     LEADING_DIGIT_SEPARATOR |
     TRAILING_DIGIT_SEPARATOR |
@@ -0,0 +1,104 @@
+//! Skip ignored characters anywhere in a number, as enabled by the
+//! parse options.
+//!
+//! The ignored characters are removed from the input before it is
+//! parsed, so the parsers only see the remaining bytes. These wrappers
+//! make error indexes and the number of processed bytes relative to the
+//! original input. If no characters are ignored, or the input contains
+//! none of them, the input is parsed unchanged.
+//!
+//! The remaining bytes are copied to a buffer on the stack. Without the
+//! `std` feature, inputs longer than the buffer after removing the
+//! ignored characters fail with [`Error::TooManyDigits`].
+
+#[cfg(feature = "std")]
+use std::vec::Vec;
+
+use crate::error::Error;
+use crate::result::Result;
+
+/// Number of bytes of the stack buffer for the remaining bytes.
+const STACK_BUFFER_SIZE: usize = 128;
+
+/// Get the index in the original input of the byte at `index` after
+/// removing the ignored characters.
+///
+/// If `index` is past the remaining bytes, this is the length of the
+/// input, so trailing ignored characters are included.
+#[inline(always)]
+pub fn original_index(bytes: &[u8], ignored: &[u8], index: usize) -> usize {
+    bytes
+        .iter()
+        .enumerate()
+        .filter(|(_, c)| !ignored.contains(c))
+        .nth(index)
+        .map_or(bytes.len(), |(original, _)| original)
+}
+
+/// Shift the index of an error to the index in the original input.
+#[inline(always)]
+fn shift_error(mut error: Error, bytes: &[u8], ignored: &[u8]) -> Error {
+    if let Some(index) = error.index_mut() {
+        *index = original_index(bytes, ignored, *index);
+    }
+    error
+}
+
+/// Remove the ignored characters and parse the remaining bytes.
+///
+/// The error indexes and processed bytes are relative to the remaining
+/// bytes.
+#[inline(always)]
+fn parse_remaining<T, F>(bytes: &[u8], ignored: &[u8], parse: F) -> Result<T>
+where
+    F: FnOnce(&[u8]) -> Result<T>,
+{
+    let mut buffer = [0u8; STACK_BUFFER_SIZE];
+    let mut length = 0;
+    for &c in bytes.iter().filter(|c| !ignored.contains(c)) {
+        if length == STACK_BUFFER_SIZE {
+            #[cfg(feature = "std")]
+            {
+                let vec: Vec<u8> = bytes.iter().copied().filter(|c| !ignored.contains(c)).collect();
+                return parse(&vec);
+            }
+            #[cfg(not(feature = "std"))]
+            return Err(Error::TooManyDigits(length));
+        }
+        buffer[length] = c;
+        length += 1;
+    }
+    parse(&buffer[..length])
+}
+
+/// Parse a complete number, skipping the ignored characters.
+#[inline(always)]
+pub fn parse_complete<T, F>(bytes: &[u8], ignored: Option<&[u8]>, parse: F) -> Result<T>
+where
+    F: FnOnce(&[u8]) -> Result<T>,
+{
+    let ignored = match ignored {
+        Some(ignored) if bytes.iter().any(|c| ignored.contains(c)) => ignored,
+        _ => return parse(bytes),
+    };
+    parse_remaining(bytes, ignored, parse).map_err(|error| shift_error(error, bytes, ignored))
+}
+
+/// Parse a number until an invalid digit is found, skipping the ignored
+/// characters.
+///
+/// Ignored characters after the last processed byte are included in the
+/// number of processed bytes.
+#[inline(always)]
+pub fn parse_partial<T, F>(bytes: &[u8], ignored: Option<&[u8]>, parse: F) -> Result<(T, usize)>
+where
+    F: FnOnce(&[u8]) -> Result<(T, usize)>,
+{
+    let ignored = match ignored {
+        Some(ignored) if bytes.iter().any(|c| ignored.contains(c)) => ignored,
+        _ => return parse(bytes),
+    };
+    let (value, count) = parse_remaining(bytes, ignored, parse)
+        .map_err(|error| shift_error(error, bytes, ignored))?;
+    Ok((value, original_index(bytes, ignored, count)))
+}
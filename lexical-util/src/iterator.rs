@@ -53,8 +53,12 @@ pub unsafe trait Iter<'a> {
     #[inline(always)]
     fn as_slice(&self) -> &'a [u8] {
         debug_assert!(self.cursor() <= self.buffer_length());
+        #[cfg(not(feature = "safe"))]
         // SAFETY: safe since index must be in range.
-        unsafe { self.get_buffer().get_unchecked(self.cursor()..) }
+        let slc = unsafe { self.get_buffer().get_unchecked(self.cursor()..) };
+        #[cfg(feature = "safe")]
+        let slc = &self.get_buffer()[self.cursor()..];
+        slc
     }
 
     /// Get a slice to the full underlying contiguous buffer,
@@ -237,9 +241,17 @@ pub unsafe trait Iter<'a> {
     #[inline(always)]
     fn peek_u32(&self) -> Option<u32> {
         if Self::IS_CONTIGUOUS && self.as_slice().len() >= mem::size_of::<u32>() {
+            #[cfg(not(feature = "safe"))]
             // SAFETY: safe since we've guaranteed the buffer is greater than
             // the number of elements read. u32 is valid for all bit patterns
-            unsafe { Some(self.peek_many_unchecked()) }
+            let value = unsafe { self.peek_many_unchecked() };
+            #[cfg(feature = "safe")]
+            let value = {
+                let mut bytes = [0u8; mem::size_of::<u32>()];
+                bytes.copy_from_slice(&self.as_slice()[..mem::size_of::<u32>()]);
+                u32::from_ne_bytes(bytes)
+            };
+            Some(value)
         } else {
             None
         }
@@ -251,9 +263,17 @@ pub unsafe trait Iter<'a> {
     #[inline(always)]
     fn peek_u64(&self) -> Option<u64> {
         if Self::IS_CONTIGUOUS && self.as_slice().len() >= mem::size_of::<u64>() {
+            #[cfg(not(feature = "safe"))]
             // SAFETY: safe since we've guaranteed the buffer is greater than
             // the number of elements read. u64 is valid for all bit patterns
-            unsafe { Some(self.peek_many_unchecked()) }
+            let value = unsafe { self.peek_many_unchecked() };
+            #[cfg(feature = "safe")]
+            let value = {
+                let mut bytes = [0u8; mem::size_of::<u64>()];
+                bytes.copy_from_slice(&self.as_slice()[..mem::size_of::<u64>()]);
+                u64::from_ne_bytes(bytes)
+            };
+            Some(value)
         } else {
             None
         }
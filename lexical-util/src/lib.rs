@@ -15,12 +15,15 @@
 //! * `parse-integers` - Add support for parsing integers.
 //! * `parse-floats` - Add support for parsing floats.
 //! * `compact` - Reduce code size at the cost of performance.
+//! * `safe` - Ensure only memory-safe indexing is used.
 //!
 //! # Note
 //!
 //! None of this is considered a public API: any of the implementation
 //! details may change release-to-release without major or minor version
-//! changes. Use internal implementation details at your own risk.
+//! changes. Use internal implementation details at your own risk. The
+//! exceptions are `div128::u128_divrem` and `div128::u128_divisor`, which
+//! are stable and documented for formatting 128-bit integers.
 //!
 //! lexical-util mainly exists as an implementation detail for
 //! lexical-core, although its API is stable. If you would like to use
@@ -153,6 +156,7 @@ compile_error!(
 );
 
 pub mod algorithm;
+pub mod alphabet;
 pub mod ascii;
 pub mod assert;
 pub mod bf16;
@@ -162,13 +166,16 @@ pub mod div128;
 pub mod error;
 pub mod extended_float;
 pub mod f16;
+pub mod ignore;
 pub mod format;
 pub mod iterator;
 pub mod mul;
 pub mod num;
 pub mod options;
 pub mod result;
+pub mod sign;
 pub mod step;
+pub mod trim;
 
 mod api;
 mod feature_format;
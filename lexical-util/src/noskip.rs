@@ -5,6 +5,7 @@
 
 #![cfg(all(feature = "parse", not(feature = "format")))]
 
+#[cfg(not(feature = "safe"))]
 use core::{mem, ptr};
 
 use crate::digit::char_is_digit_const;
@@ -144,6 +145,7 @@ unsafe impl<'a, const __: u128> Iter<'a> for Bytes<'a, __> {
         self.index += count;
     }
 
+    #[cfg(not(feature = "safe"))]
     #[inline(always)]
     #[allow(clippy::assertions_on_constants)] // reason="ensuring safety invariants are valid"
     unsafe fn peek_many_unchecked<V>(&self) -> V {
@@ -229,6 +231,7 @@ unsafe impl<'a: 'b, 'b, const __: u128> Iter<'a> for DigitsIterator<'a, 'b, __>
         unsafe { self.byte.step_by_unchecked(count) }
     }
 
+    #[cfg(not(feature = "safe"))]
     #[inline(always)]
     unsafe fn peek_many_unchecked<V>(&self) -> V {
         debug_assert!(self.as_slice().len() >= mem::size_of::<V>());
@@ -9,6 +9,34 @@ use crate::error::Error;
 use crate::format_builder::NumberFormatBuilder;
 use crate::format_flags as flags;
 
+/// Get the error type from a packed number format.
+///
+/// This is identical to [`NumberFormat::error`], except the format does
+/// not need to be a const generic, so it can validate formats as they
+/// are built.
+pub(crate) const fn format_error_impl(format: u128) -> Error {
+    let valid_flags = flags::REQUIRED_EXPONENT_DIGITS | flags::REQUIRED_MANTISSA_DIGITS;
+    if !flags::is_valid_radix(flags::mantissa_radix(format)) {
+        Error::InvalidMantissaRadix
+    } else if !flags::is_valid_radix(flags::exponent_base(format)) {
+        Error::InvalidExponentBase
+    } else if !flags::is_valid_radix(flags::exponent_radix(format)) {
+        Error::InvalidExponentRadix
+    } else if !flags::is_valid_digit_separator(format) {
+        Error::InvalidDigitSeparator
+    } else if !flags::is_valid_base_prefix(format) {
+        Error::InvalidBasePrefix
+    } else if !flags::is_valid_base_suffix(format) {
+        Error::InvalidBaseSuffix
+    } else if !flags::is_valid_punctuation(format) {
+        Error::InvalidPunctuation
+    } else if format & flags::FLAG_MASK != valid_flags {
+        Error::InvalidFlags
+    } else {
+        Error::Success
+    }
+}
+
 /// Wrapper for the 128-bit packed struct.
 ///
 /// The following values are explicitly set, and therefore not configurable:
@@ -31,28 +59,36 @@ use crate::format_flags as flags;
 ///     17. case_sensitive_exponent
 ///     18. case_sensitive_base_prefix
 ///     19. case_sensitive_base_suffix
-///     20. integer_internal_digit_separator
-///     21. fraction_internal_digit_separator
-///     22. exponent_internal_digit_separator
-///     23. internal_digit_separator
-///     24. integer_leading_digit_separator
-///     25. fraction_leading_digit_separator
-///     26. exponent_leading_digit_separator
-///     27. leading_digit_separator
-///     28. integer_trailing_digit_separator
-///     29. fraction_trailing_digit_separator
-///     30. exponent_trailing_digit_separator
-///     31. trailing_digit_separator
-///     32. integer_consecutive_digit_separator
-///     33. fraction_consecutive_digit_separator
-///     34. exponent_consecutive_digit_separator
-///     35. consecutive_digit_separator
-///     36. special_digit_separator
-///     37. digit_separator
-///     38. base_prefix
-///     39. base_suffix
-///     40. exponent_base
-///     41. exponent_radix
+///     20. skip_leading_whitespace
+///     21. skip_trailing_whitespace
+///     22. unicode_signs
+///     23. parenthesized_negatives
+///     24. trailing_empty_exponent
+///     25. no_uppercase_digits
+///     26. no_lowercase_digits
+///     27. no_subnormals
+///     28. integer_internal_digit_separator
+///     29. fraction_internal_digit_separator
+///     30. exponent_internal_digit_separator
+///     31. internal_digit_separator
+///     32. integer_leading_digit_separator
+///     33. fraction_leading_digit_separator
+///     34. exponent_leading_digit_separator
+///     35. leading_digit_separator
+///     36. integer_trailing_digit_separator
+///     37. fraction_trailing_digit_separator
+///     38. exponent_trailing_digit_separator
+///     39. trailing_digit_separator
+///     40. integer_consecutive_digit_separator
+///     41. fraction_consecutive_digit_separator
+///     42. exponent_consecutive_digit_separator
+///     43. consecutive_digit_separator
+///     44. special_digit_separator
+///     45. digit_separator
+///     46. base_prefix
+///     47. base_suffix
+///     48. exponent_base
+///     49. exponent_radix
 ///
 /// See `NumberFormatBuilder` for the `FORMAT` fields
 /// for the packed struct.
@@ -76,26 +112,7 @@ impl<const FORMAT: u128> NumberFormat<FORMAT> {
 
     /// Get the error type from the format.
     pub const fn error(&self) -> Error {
-        let valid_flags = flags::REQUIRED_EXPONENT_DIGITS | flags::REQUIRED_MANTISSA_DIGITS;
-        if !flags::is_valid_radix(self.mantissa_radix()) {
-            Error::InvalidMantissaRadix
-        } else if !flags::is_valid_radix(self.exponent_base()) {
-            Error::InvalidExponentBase
-        } else if !flags::is_valid_radix(self.exponent_radix()) {
-            Error::InvalidExponentRadix
-        } else if !flags::is_valid_digit_separator(FORMAT) {
-            Error::InvalidDigitSeparator
-        } else if !flags::is_valid_base_prefix(FORMAT) {
-            Error::InvalidBasePrefix
-        } else if !flags::is_valid_base_suffix(FORMAT) {
-            Error::InvalidBaseSuffix
-        } else if !flags::is_valid_punctuation(FORMAT) {
-            Error::InvalidPunctuation
-        } else if self.flags() != valid_flags {
-            Error::InvalidFlags
-        } else {
-            Error::Success
-        }
+        format_error_impl(FORMAT)
     }
 
     // NON-DIGIT SEPARATOR FLAGS & MASKS
@@ -271,6 +288,78 @@ impl<const FORMAT: u128> NumberFormat<FORMAT> {
         Self::CASE_SENSITIVE_BASE_SUFFIX
     }
 
+    /// If ASCII whitespace before the number is skipped.
+    pub const SKIP_LEADING_WHITESPACE: bool = false;
+
+    /// Get if ASCII whitespace before the number is skipped.
+    #[inline(always)]
+    pub const fn skip_leading_whitespace(&self) -> bool {
+        Self::SKIP_LEADING_WHITESPACE
+    }
+
+    /// If ASCII whitespace after the number is skipped.
+    pub const SKIP_TRAILING_WHITESPACE: bool = false;
+
+    /// Get if ASCII whitespace after the number is skipped.
+    #[inline(always)]
+    pub const fn skip_trailing_whitespace(&self) -> bool {
+        Self::SKIP_TRAILING_WHITESPACE
+    }
+
+    /// If Unicode minus and plus signs are accepted.
+    pub const UNICODE_SIGNS: bool = false;
+
+    /// Get if Unicode minus and plus signs are accepted.
+    #[inline(always)]
+    pub const fn unicode_signs(&self) -> bool {
+        Self::UNICODE_SIGNS
+    }
+
+    /// If negative numbers are enclosed in parentheses.
+    pub const PARENTHESIZED_NEGATIVES: bool = false;
+
+    /// Get if negative numbers are enclosed in parentheses.
+    #[inline(always)]
+    pub const fn parenthesized_negatives(&self) -> bool {
+        Self::PARENTHESIZED_NEGATIVES
+    }
+
+    /// If an exponent without digits is left as trailing bytes.
+    pub const TRAILING_EMPTY_EXPONENT: bool = false;
+
+    /// Get if an exponent without digits is left as trailing bytes.
+    #[inline(always)]
+    pub const fn trailing_empty_exponent(&self) -> bool {
+        Self::TRAILING_EMPTY_EXPONENT
+    }
+
+    /// If uppercase letters are not allowed as digits.
+    pub const NO_UPPERCASE_DIGITS: bool = false;
+
+    /// Get if uppercase letters are not allowed as digits.
+    #[inline(always)]
+    pub const fn no_uppercase_digits(&self) -> bool {
+        Self::NO_UPPERCASE_DIGITS
+    }
+
+    /// If lowercase letters are not allowed as digits.
+    pub const NO_LOWERCASE_DIGITS: bool = false;
+
+    /// Get if lowercase letters are not allowed as digits.
+    #[inline(always)]
+    pub const fn no_lowercase_digits(&self) -> bool {
+        Self::NO_LOWERCASE_DIGITS
+    }
+
+    /// If subnormal floats are not allowed.
+    pub const NO_SUBNORMALS: bool = false;
+
+    /// Get if subnormal floats are not allowed.
+    #[inline(always)]
+    pub const fn no_subnormals(&self) -> bool {
+        Self::NO_SUBNORMALS
+    }
+
     // DIGIT SEPARATOR FLAGS & MASKS
 
     // If digit separators are allowed between integer digits.
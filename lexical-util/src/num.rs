@@ -1163,7 +1163,7 @@ impl Float for f64 {
 /// # Safety
 ///
 /// Safe as long as `e` is properly initialized.
-#[cfg(all(not(feature = "std"), feature = "floats"))]
+#[cfg(all(not(feature = "std"), feature = "floats", not(feature = "safe")))]
 macro_rules! volatile {
 ($e:expr) => {
     // SAFETY: safe as long as `$e` has been properly initialized.
@@ -1173,6 +1173,15 @@ macro_rules! volatile {
 };
 }
 
+/// Evaluate `e`, which may be optimized out since it only raises
+/// floating-point exceptions.
+#[cfg(all(not(feature = "std"), feature = "floats", feature = "safe"))]
+macro_rules! volatile {
+($e:expr) => {
+    _ = $e;
+};
+}
+
 /// Floor (f64)
 ///
 /// Finds the nearest integer less than or equal to `x`.
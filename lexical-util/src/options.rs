@@ -23,6 +23,26 @@ pub trait ParseOptions: Default {
     fn is_valid(&self) -> bool;
 }
 
+// TYPES
+// -----
+
+/// Alignment of a number written with a minimum width.
+///
+/// Like `printf`, right alignment with the `0` pad character places the
+/// zeros between the sign and the digits, as in `-0042`. With any other
+/// alignment or pad character, the padding is placed around the number.
+#[cfg(feature = "write")]
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Alignment {
+    /// Pad before the number, like `  -42`.
+    Right,
+    /// Pad after the number, like `-42  `.
+    Left,
+    /// Pad on both sides of the number, with any odd padding character
+    /// after it, like ` -42  `.
+    Center,
+}
+
 // PRE-DEFINED CONSTANTS
 // ---------------------
 
@@ -46,6 +66,8 @@ literal!(RUST_LITERAL, None);
 // RUST_STRING
 literal!(PYTHON_LITERAL, None);
 // PYTHON_STRING
+literal!(PYTHON_REPR_NAN, b"nan");
+literal!(PYTHON_REPR_INF, b"inf");
 literal!(CXX_LITERAL_NAN, b"NAN");
 literal!(CXX_LITERAL_INF, b"INFINITY");
 literal!(CXX_LITERAL_INFINITY, b"INFINITY");
@@ -62,6 +84,7 @@ literal!(SWIFT_LITERAL, None);
 // SWIFT_STRING
 literal!(GO_LITERAL, None);
 // GO_STRING
+literal!(GO_FORMAT_FLOAT_INF, b"Inf");
 literal!(HASKELL_LITERAL, None);
 literal!(HASKELL_STRING_INF, b"Infinity");
 literal!(HASKELL_STRING_INFINITY, b"Infinity");
@@ -138,6 +161,12 @@ literal!(SAGE_LITERAL_INFINITY, b"Infinity");
 literal!(JSON, None);
 literal!(TOML, None);
 literal!(YAML, None);
+literal!(TOML_NAN, b"nan");
+literal!(TOML_INF, b"inf");
+literal!(YAML_NAN, b".nan");
+literal!(YAML_INF, b".inf");
+literal!(JSON5_INF, b"Infinity");
+literal!(JSON5_INFINITY, b"Infinity");
 literal!(XML_INF, None);
 literal!(XML_INFINITY, None);
 literal!(SQLITE, None);
@@ -0,0 +1,44 @@
+//! Signs accepted in addition to `-` and `+`.
+//!
+//! These are only used if the number format enables
+//! [`UNICODE_SIGNS`](crate::format::UNICODE_SIGNS) or
+//! [`PARENTHESIZED_NEGATIVES`](crate::format::PARENTHESIZED_NEGATIVES).
+
+/// U+2212 MINUS SIGN, encoded as UTF-8.
+pub const MINUS_SIGN: [u8; 3] = [0xE2, 0x88, 0x92];
+
+/// U+FF0D FULLWIDTH HYPHEN-MINUS, encoded as UTF-8.
+pub const FULLWIDTH_HYPHEN_MINUS: [u8; 3] = [0xEF, 0xBC, 0x8D];
+
+/// U+FF0B FULLWIDTH PLUS SIGN, encoded as UTF-8.
+pub const FULLWIDTH_PLUS_SIGN: [u8; 3] = [0xEF, 0xBC, 0x8B];
+
+/// The length, in bytes, of each Unicode sign.
+pub const UNICODE_SIGN_LENGTH: usize = 3;
+
+/// Get if the bytes start with a Unicode sign, and if it is negative.
+///
+/// Returns `Some(true)` for a minus sign, `Some(false)` for a plus sign,
+/// and `None` otherwise. The sign is [`UNICODE_SIGN_LENGTH`] bytes long.
+#[inline(always)]
+pub fn unicode_sign(bytes: &[u8]) -> Option<bool> {
+    match bytes.get(..UNICODE_SIGN_LENGTH) {
+        Some(sign) if sign == MINUS_SIGN || sign == FULLWIDTH_HYPHEN_MINUS => Some(true),
+        Some(sign) if sign == FULLWIDTH_PLUS_SIGN => Some(false),
+        _ => None,
+    }
+}
+
+/// Get if the bytes start with an extended sign, if it is negative, and
+/// the length of the sign in bytes.
+///
+/// An opening parenthesis is a negative sign if `parenthesis` is set,
+/// and Unicode signs are accepted if `unicode` is set.
+#[inline(always)]
+pub fn extended_sign(bytes: &[u8], unicode: bool, parenthesis: bool) -> Option<(bool, usize)> {
+    match bytes.first() {
+        Some(&b'(') if parenthesis => Some((true, 1)),
+        _ if unicode => unicode_sign(bytes).map(|is_negative| (is_negative, UNICODE_SIGN_LENGTH)),
+        _ => None,
+    }
+}
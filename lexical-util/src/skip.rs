@@ -41,6 +41,7 @@
 
 #![cfg(all(feature = "format", feature = "parse"))]
 
+#[cfg(not(feature = "safe"))]
 use core::{mem, ptr};
 
 use crate::digit::char_is_digit_const;
@@ -1161,6 +1162,7 @@ impl<'a, const FORMAT: u128> Bytes<'a, FORMAT> {
     /// # Safety
     ///
     /// Safe if the buffer has at least `size_of::<V>` elements.
+    #[cfg(not(feature = "safe"))]
     #[inline(always)]
     unsafe fn peek_many_unchecked_impl<V>(&self, is_contiguous: bool) -> V {
         // NOTE: THIS IS NOT a duplicate calling `peek_many_unchecked` from a digits
@@ -1223,6 +1225,7 @@ unsafe impl<'a, const FORMAT: u128> Iter<'a> for Bytes<'a, FORMAT> {
         unsafe { self.step_by_unchecked_impl(count, Self::IS_CONTIGUOUS) }
     }
 
+    #[cfg(not(feature = "safe"))]
     #[inline(always)]
     unsafe fn peek_many_unchecked<V>(&self) -> V {
         // SAFETY: Safe if the buffer has at least `size_of::<V>` elements.
@@ -1386,6 +1389,7 @@ macro_rules! skip_iterator_iter_base {
             unsafe { self.byte.step_by_unchecked_impl(count, Self::IS_CONTIGUOUS) }
         }
 
+        #[cfg(not(feature = "safe"))]
         #[inline(always)]
         unsafe fn peek_many_unchecked<V>(&self) -> V {
             // SAFETY: Safe if the buffer has at least `size_of::<V>` elements.
@@ -167,6 +167,14 @@ pub const fn u64_step(radix: u32) -> usize {
     min_step(radix, 64, false)
 }
 
+/// Calculate the number of digits that can be processed without overflowing a
+/// u32. Helper function since this is used for 64-bit division on 32-bit
+/// targets.
+#[inline(always)]
+pub const fn u32_step(radix: u32) -> usize {
+    min_step(radix, 32, false)
+}
+
 // AUTO-GENERATED
 // These functions were auto-generated by `etc/step.py`.
 // Do not edit them unless there is a good reason to.
@@ -0,0 +1,106 @@
+//! Trim whitespace and parentheses around numbers, as enabled by the
+//! number format.
+//!
+//! The parsers only see the bytes between the skipped whitespace, so
+//! these wrappers make error indexes and the number of processed bytes
+//! relative to the original input. For parenthesized negatives, the
+//! opening parenthesis is parsed as the sign, and the closing
+//! parenthesis is checked here. If the format does not skip whitespace
+//! or allow parentheses, the input is parsed unchanged.
+
+use crate::error::Error;
+use crate::format::NumberFormat;
+use crate::result::Result;
+
+/// Get the number of leading ASCII whitespace characters.
+#[inline(always)]
+pub fn leading_whitespace(bytes: &[u8]) -> usize {
+    bytes.iter().take_while(|c| c.is_ascii_whitespace()).count()
+}
+
+/// Get the number of trailing ASCII whitespace characters.
+#[inline(always)]
+pub fn trailing_whitespace(bytes: &[u8]) -> usize {
+    bytes.iter().rev().take_while(|c| c.is_ascii_whitespace()).count()
+}
+
+/// Shift the index of an error by the number of skipped bytes.
+#[inline(always)]
+fn shift_error(mut error: Error, start: usize) -> Error {
+    if let Some(index) = error.index_mut() {
+        *index += start;
+    }
+    error
+}
+
+/// Parse a complete number, trimming whitespace and parentheses around it
+/// if the format allows it.
+#[inline(always)]
+pub fn parse_complete<T, F, const FORMAT: u128>(bytes: &[u8], parse: F) -> Result<T>
+where
+    F: FnOnce(&[u8]) -> Result<T>,
+{
+    let format = NumberFormat::<FORMAT> {};
+    if !format.skip_leading_whitespace()
+        && !format.skip_trailing_whitespace()
+        && !format.parenthesized_negatives()
+    {
+        return parse(bytes);
+    }
+
+    let start = if format.skip_leading_whitespace() {
+        leading_whitespace(bytes)
+    } else {
+        0
+    };
+    let bytes = &bytes[start..];
+    let mut end = if format.skip_trailing_whitespace() {
+        bytes.len() - trailing_whitespace(bytes)
+    } else {
+        bytes.len()
+    };
+    if format.parenthesized_negatives() && bytes.first() == Some(&b'(') {
+        if end < 2 || bytes[end - 1] != b')' {
+            return Err(Error::InvalidDigit(start + end));
+        }
+        end -= 1;
+    }
+    parse(&bytes[..end]).map_err(|error| shift_error(error, start))
+}
+
+/// Parse a number until an invalid digit is found, trimming whitespace
+/// and parentheses around it if the format allows it.
+///
+/// Skipped trailing whitespace and the closing parenthesis are included
+/// in the number of processed bytes.
+#[inline(always)]
+pub fn parse_partial<T, F, const FORMAT: u128>(bytes: &[u8], parse: F) -> Result<(T, usize)>
+where
+    F: FnOnce(&[u8]) -> Result<(T, usize)>,
+{
+    let format = NumberFormat::<FORMAT> {};
+    if !format.skip_leading_whitespace()
+        && !format.skip_trailing_whitespace()
+        && !format.parenthesized_negatives()
+    {
+        return parse(bytes);
+    }
+
+    let start = if format.skip_leading_whitespace() {
+        leading_whitespace(bytes)
+    } else {
+        0
+    };
+    let (value, count) = parse(&bytes[start..]).map_err(|error| shift_error(error, start))?;
+    let mut count = start + count;
+    if format.parenthesized_negatives() && bytes.get(start) == Some(&b'(') {
+        if bytes.get(count) != Some(&b')') {
+            return Err(Error::InvalidDigit(count));
+        }
+        count += 1;
+    }
+    if format.skip_trailing_whitespace() {
+        count += leading_whitespace(&bytes[count..]);
+    }
+    Ok((value, count))
+}
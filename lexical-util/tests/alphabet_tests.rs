@@ -0,0 +1,85 @@
+#![cfg(feature = "integers")]
+
+use lexical_util::alphabet::{self, alphabet_inverse, DigitAlphabet};
+
+const OCTAL_DIGITS: &[u8] = b"01234567";
+const OCTAL_INVERSE: [u8; 256] = alphabet_inverse(OCTAL_DIGITS);
+const OCTAL: DigitAlphabet = DigitAlphabet::new(OCTAL_DIGITS, &OCTAL_INVERSE);
+
+const DUPLICATE_DIGITS: &[u8] = b"0120";
+const DUPLICATE_INVERSE: [u8; 256] = alphabet_inverse(DUPLICATE_DIGITS);
+
+const SIGN_DIGITS: &[u8] = b"01+";
+const SIGN_INVERSE: [u8; 256] = alphabet_inverse(SIGN_DIGITS);
+
+const SHORT_DIGITS: &[u8] = b"0";
+const SHORT_INVERSE: [u8; 256] = alphabet_inverse(SHORT_DIGITS);
+
+#[test]
+fn is_valid_test() {
+    assert!(OCTAL.is_valid());
+    assert!(alphabet::BASE32.is_valid());
+    assert!(alphabet::BASE32_HEX.is_valid());
+    assert!(alphabet::BASE58.is_valid());
+    assert!(alphabet::BASE62.is_valid());
+
+    assert!(!DigitAlphabet::new(DUPLICATE_DIGITS, &DUPLICATE_INVERSE).is_valid());
+    assert!(!DigitAlphabet::new(SIGN_DIGITS, &SIGN_INVERSE).is_valid());
+    assert!(!DigitAlphabet::new(SHORT_DIGITS, &SHORT_INVERSE).is_valid());
+    // Mismatched inverse.
+    assert!(!DigitAlphabet::new(OCTAL_DIGITS, &SHORT_INVERSE).is_valid());
+    assert!(!DigitAlphabet::new(SHORT_DIGITS, &OCTAL_INVERSE).is_valid());
+}
+
+#[test]
+fn radix_test() {
+    assert_eq!(OCTAL.radix(), 8);
+    assert_eq!(alphabet::BASE32.radix(), 32);
+    assert_eq!(alphabet::BASE32_HEX.radix(), 32);
+    assert_eq!(alphabet::BASE58.radix(), 58);
+    assert_eq!(alphabet::BASE62.radix(), 62);
+}
+
+#[test]
+fn digit_to_char_test() {
+    assert_eq!(OCTAL.digit_to_char(0), b'0');
+    assert_eq!(OCTAL.digit_to_char(7), b'7');
+    assert_eq!(alphabet::BASE32.digit_to_char(0), b'A');
+    assert_eq!(alphabet::BASE32.digit_to_char(26), b'2');
+    assert_eq!(alphabet::BASE58.digit_to_char(0), b'1');
+    assert_eq!(alphabet::BASE58.digit_to_char(57), b'z');
+    assert_eq!(alphabet::BASE62.digit_to_char(36), b'a');
+}
+
+#[test]
+fn char_to_digit_test() {
+    assert_eq!(OCTAL.char_to_digit(b'0'), Some(0));
+    assert_eq!(OCTAL.char_to_digit(b'7'), Some(7));
+    assert_eq!(OCTAL.char_to_digit(b'8'), None);
+    assert_eq!(alphabet::BASE32.char_to_digit(b'A'), Some(0));
+    assert_eq!(alphabet::BASE32.char_to_digit(b'a'), None);
+    assert_eq!(alphabet::BASE32.char_to_digit(b'1'), None);
+    assert_eq!(alphabet::BASE58.char_to_digit(b'0'), None);
+    assert_eq!(alphabet::BASE58.char_to_digit(b'l'), None);
+    assert_eq!(alphabet::BASE58.char_to_digit(b'z'), Some(57));
+    assert_eq!(alphabet::BASE62.char_to_digit(b'a'), Some(36));
+    assert_eq!(alphabet::BASE62.char_to_digit(b'-'), None);
+}
+
+#[test]
+fn roundtrip_test() {
+    for alphabet in [OCTAL, alphabet::BASE32, alphabet::BASE58, alphabet::BASE62] {
+        for digit in 0..alphabet.radix() {
+            let c = alphabet.digit_to_char(digit);
+            assert_eq!(alphabet.char_to_digit(c), Some(digit));
+        }
+    }
+}
+
+#[cfg(feature = "proptest")]
+proptest::proptest! {
+    #[test]
+    fn arbitrary_alphabet_proptest(alphabet in proptest::arbitrary::any::<DigitAlphabet>()) {
+        proptest::prop_assert!(alphabet.is_valid());
+    }
+}
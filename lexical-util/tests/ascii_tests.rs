@@ -49,3 +49,17 @@ fn is_valid_letter_slice_test() {
     assert_eq!(ascii::is_valid_letter_slice(b" 09a"), false);
     assert_eq!(ascii::is_valid_letter_slice(b"aZAz"), true);
 }
+
+#[test]
+fn is_valid_special_slice_test() {
+    assert!(ascii::is_valid_special_slice(b"NaN", b'N'));
+    assert!(ascii::is_valid_special_slice(b"nan", b'N'));
+    assert!(ascii::is_valid_special_slice(b".nan", b'N'));
+    assert!(ascii::is_valid_special_slice(b".Inf", b'I'));
+    assert!(!ascii::is_valid_special_slice(b"", b'N'));
+    assert!(!ascii::is_valid_special_slice(b".", b'N'));
+    assert!(!ascii::is_valid_special_slice(b"inf", b'N'));
+    assert!(!ascii::is_valid_special_slice(b"..nan", b'N'));
+    assert!(!ascii::is_valid_special_slice(b"na.n", b'N'));
+    assert!(!ascii::is_valid_special_slice(b"na0n", b'N'));
+}
@@ -1,14 +1,38 @@
-#![cfg(not(feature = "compact"))]
 #![cfg(feature = "write")]
 
 mod util;
 
-use lexical_util::div128::u128_divrem;
+use lexical_util::div128::{u128_divisor, u128_divrem};
 use lexical_util::step::u64_step;
 use proptest::{prop_assert_eq, proptest};
 
 use crate::util::default_proptest_config;
 
+#[test]
+fn u128_divisor_test() {
+    assert_eq!(u128_divisor(10), 10000000000000000000);
+    if cfg!(feature = "power-of-two") {
+        assert_eq!(u128_divisor(2), 1 << 64);
+        assert_eq!(u128_divisor(8), 1 << 63);
+        assert_eq!(u128_divisor(32), 1 << 60);
+    }
+    if cfg!(feature = "radix") {
+        assert_eq!(u128_divisor(3), 12157665459056928801);
+        assert_eq!(u128_divisor(36), 4738381338321616896);
+    }
+}
+
+#[test]
+#[cfg(feature = "radix")]
+fn u128_divrem_radix_test() {
+    for radix in 2u32..=36 {
+        let d = u128_divisor(radix);
+        for i in [0, 1, d - 1, d, d + 1, u128::MAX / d, u128::MAX / d * d, u128::MAX] {
+            assert_eq!(u128_divrem(i, radix), (i / d, (i % d) as u64), "radix {radix}, i {i}");
+        }
+    }
+}
+
 proptest! {
     #![proptest_config(default_proptest_config())]
 
@@ -19,6 +43,7 @@ proptest! {
         let d = 10u128.pow(step as u32);
         let expected = (i / d, (i % d) as u64);
         prop_assert_eq!((hi, lo), expected);
+        prop_assert_eq!(u128_divisor(10), d);
     }
 
     #[test]
@@ -30,5 +55,6 @@ proptest! {
         let d = (radix as u128).pow(step as u32);
         let expected = (i / d, (i % d) as u64);
         prop_assert_eq!((hi, lo), expected);
+        prop_assert_eq!(u128_divisor(radix), d);
     }
 }
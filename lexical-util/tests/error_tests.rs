@@ -0,0 +1,77 @@
+use lexical_util::error::{CharacterClass, Error, ErrorKind};
+
+#[test]
+fn kind_test() {
+    assert_eq!(Error::Overflow(3).kind(), ErrorKind::Overflow);
+    assert_eq!(Error::InvalidDigit(0).kind(), ErrorKind::InvalidDigit);
+    assert_eq!(Error::EmptyExponent(5).kind(), ErrorKind::EmptyExponent);
+    assert_eq!(Error::InvalidMantissaRadix.kind(), ErrorKind::InvalidMantissaRadix);
+    assert_eq!(Error::InvalidDigitAlphabet.kind(), ErrorKind::InvalidDigitAlphabet);
+    assert_eq!(Error::Success.kind(), ErrorKind::Success);
+
+    // The kind does not depend on the index.
+    assert_eq!(Error::Empty(0).kind(), Error::Empty(10).kind());
+    assert_ne!(Error::Empty(0).kind(), Error::EmptyMantissa(0).kind());
+}
+
+#[test]
+fn index_test() {
+    assert_eq!(Error::Underflow(7).index(), Some(&7));
+    assert_eq!(Error::InvalidPunctuation.index(), None);
+}
+
+#[test]
+fn expected_test() {
+    assert_eq!(Error::InvalidDigit(2).kind().expected(), Some(CharacterClass::Digit));
+    assert_eq!(Error::EmptyExponent(3).kind().expected(), Some(CharacterClass::Digit));
+    assert_eq!(Error::MissingSign(0).kind().expected(), Some(CharacterClass::Sign));
+    assert_eq!(Error::MissingExponentSign(2).kind().expected(), Some(CharacterClass::Sign));
+    assert_eq!(Error::MissingExponent(3).kind().expected(), Some(CharacterClass::Exponent));
+    assert_eq!(
+        Error::ExponentWithoutFraction(1).kind().expected(),
+        Some(CharacterClass::DecimalPoint)
+    );
+    assert_eq!(Error::Overflow(20).kind().expected(), None);
+    assert_eq!(Error::InvalidPunctuation.kind().expected(), None);
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn display_test() {
+    assert_eq!(
+        Error::InvalidDigit(2).to_string(),
+        "lexical parse error: 'invalid digit found' at index 2, expected a digit"
+    );
+    assert_eq!(
+        Error::MissingExponentSign(2).to_string(),
+        "lexical parse error: 'missing required `+/-` sign for exponent' at index 2, expected a `+/-` sign"
+    );
+    assert_eq!(
+        Error::MissingExponent(3).to_string(),
+        "lexical parse error: 'missing required exponent' at index 3, expected an exponent"
+    );
+    assert_eq!(
+        Error::InvalidPunctuation.to_string(),
+        "lexical number format error: 'invalid punctuation: multiple characters overlap'"
+    );
+    assert_eq!(
+        Error::InvalidRadix.to_string(),
+        "lexical options error: 'invalid radix for significant digits'"
+    );
+    assert_eq!(Error::Success.to_string(), "'not actually an error'");
+    assert_eq!(ErrorKind::InvalidDigit.to_string(), "invalid digit found");
+    assert_eq!(CharacterClass::DecimalPoint.to_string(), "a decimal point");
+}
+
+#[test]
+#[cfg(feature = "std")]
+fn std_error_test() {
+    fn parse() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        Err(Error::Overflow(20))?;
+        Ok(())
+    }
+
+    let error = parse().unwrap_err();
+    assert_eq!(error.downcast_ref::<Error>(), Some(&Error::Overflow(20)));
+    assert_eq!(error.to_string(), "lexical parse error: 'numeric overflow occurred' at index 20");
+}
@@ -117,6 +117,14 @@ fn flags_test() {
     test_flag!(fraction_consecutive_digit_separator, FRACTION_CONSECUTIVE_DIGIT_SEPARATOR);
     test_flag!(exponent_consecutive_digit_separator, EXPONENT_CONSECUTIVE_DIGIT_SEPARATOR);
     test_flag!(special_digit_separator, SPECIAL_DIGIT_SEPARATOR);
+    test_flag!(skip_leading_whitespace, SKIP_LEADING_WHITESPACE);
+    test_flag!(skip_trailing_whitespace, SKIP_TRAILING_WHITESPACE);
+    test_flag!(unicode_signs, UNICODE_SIGNS);
+    test_flag!(parenthesized_negatives, PARENTHESIZED_NEGATIVES);
+    test_flag!(trailing_empty_exponent, TRAILING_EMPTY_EXPONENT);
+    test_flag!(no_uppercase_digits, NO_UPPERCASE_DIGITS);
+    test_flag!(no_lowercase_digits, NO_LOWERCASE_DIGITS);
+    test_flag!(no_subnormals, NO_SUBNORMALS);
 }
 
 #[test]
@@ -129,7 +137,10 @@ fn constants_test() {
     let _: u128 = format::PYTHON3_LITERAL;
     let _: u128 = format::PYTHON3_STRING;
     let _: u128 = format::PYTHON36_LITERAL;
+    let _: u128 = format::PYTHON36_STRING;
     let _: u128 = format::PYTHON35_LITERAL;
+    let _: u128 = format::PYTHON35_STRING;
+    let _: u128 = format::PYTHON_REPR;
     let _: u128 = format::PYTHON2_LITERAL;
     let _: u128 = format::PYTHON2_STRING;
     let _: u128 = format::CXX17_LITERAL;
@@ -158,10 +169,12 @@ fn constants_test() {
     let _: u128 = format::SWIFT_STRING;
     let _: u128 = format::GO_LITERAL;
     let _: u128 = format::GO_STRING;
+    let _: u128 = format::GO_FORMAT_FLOAT;
     let _: u128 = format::HASKELL_LITERAL;
     let _: u128 = format::HASKELL_STRING;
     let _: u128 = format::JAVASCRIPT_LITERAL;
     let _: u128 = format::JAVASCRIPT_STRING;
+    let _: u128 = format::JAVASCRIPT_TO_STRING;
     let _: u128 = format::PERL_LITERAL;
     let _: u128 = format::PERL_STRING;
     let _: u128 = format::PHP_LITERAL;
@@ -176,6 +189,7 @@ fn constants_test() {
     let _: u128 = format::JULIA_STRING;
     let _: u128 = format::CSHARP7_LITERAL;
     let _: u128 = format::CSHARP7_STRING;
+    let _: u128 = format::CSHARP_ROUND_TRIP;
     let _: u128 = format::CSHARP6_LITERAL;
     let _: u128 = format::CSHARP6_STRING;
     let _: u128 = format::CSHARP5_LITERAL;
@@ -233,9 +247,12 @@ fn constants_test() {
     let _: u128 = format::JSON;
     let _: u128 = format::TOML;
     let _: u128 = format::YAML;
+    let _: u128 = format::JSON5;
     let _: u128 = format::XML;
     let _: u128 = format::SQLITE;
+    let _: u128 = format::SQLITE_STRING;
     let _: u128 = format::POSTGRESQL;
+    let _: u128 = format::POSTGRESQL_STRING;
     let _: u128 = format::MYSQL;
     let _: u128 = format::MONGODB;
 }
@@ -1,3 +1,6 @@
+#![allow(clippy::disallowed_macros)]
+
+use lexical_util::error::Error;
 use lexical_util::format::{NumberFormat, NumberFormatBuilder};
 
 #[test]
@@ -58,3 +61,58 @@ fn from_radix_test() {
     assert_eq!(format.exponent_base(), 32);
     assert_eq!(format.exponent_radix(), 32);
 }
+
+#[test]
+fn build_strict_test() {
+    const FORMAT: u128 = NumberFormatBuilder::new().build_strict();
+    assert_eq!(FORMAT, NumberFormatBuilder::new().build());
+    assert!(NumberFormatBuilder::new().is_valid());
+    assert_eq!(NumberFormatBuilder::new().error(), Error::Success);
+}
+
+#[test]
+#[cfg(feature = "format")]
+fn missing_digit_separator_test() {
+    use core::num::NonZeroU8;
+
+    let builder = NumberFormatBuilder::new().integer_internal_digit_separator(true);
+    assert!(!builder.is_valid());
+    assert_eq!(builder.error(), Error::InvalidDigitSeparator);
+
+    let builder = builder.digit_separator(NonZeroU8::new(b'_'));
+    assert!(builder.is_valid());
+    assert_eq!(builder.build_strict(), builder.build());
+}
+
+#[test]
+#[cfg(feature = "format")]
+fn skip_whitespace_test() {
+    use lexical_util::format::{SKIP_LEADING_WHITESPACE, SKIP_WHITESPACE};
+
+    let format = NumberFormatBuilder::new().skip_whitespace(true).build_strict();
+    assert_eq!(format & SKIP_WHITESPACE, SKIP_WHITESPACE);
+    let builder = NumberFormatBuilder::rebuild(format);
+    assert!(builder.get_skip_leading_whitespace());
+    assert!(builder.get_skip_trailing_whitespace());
+
+    let builder = builder.skip_trailing_whitespace(false);
+    assert!(builder.get_skip_leading_whitespace());
+    assert!(!builder.get_skip_trailing_whitespace());
+    assert_eq!(builder.build_strict() & SKIP_WHITESPACE, SKIP_LEADING_WHITESPACE);
+}
+
+#[test]
+#[should_panic]
+#[cfg(feature = "format")]
+fn build_strict_invalid_test() {
+    NumberFormatBuilder::new().integer_internal_digit_separator(true).build_strict();
+}
+
+#[cfg(feature = "proptest")]
+proptest::proptest! {
+    #[test]
+    fn arbitrary_builder_proptest(builder in proptest::arbitrary::any::<NumberFormatBuilder>()) {
+        let format = builder.build();
+        proptest::prop_assert_eq!(NumberFormatBuilder::rebuild(format), builder);
+    }
+}
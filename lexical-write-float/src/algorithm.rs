@@ -35,7 +35,7 @@ use lexical_util::num::{AsPrimitive, Float};
 use lexical_write_integer::decimal::{Decimal, DecimalCount};
 
 use crate::float::{ExtendedFloat80, RawFloat};
-use crate::options::{Options, RoundMode};
+use crate::options::{DigitRounding, Options, RoundMode};
 use crate::shared;
 use crate::table::*;
 
@@ -49,8 +49,12 @@ pub fn write_float<F: RawFloat, const FORMAT: u128>(
     debug_assert!(!float.is_special());
     debug_assert!(float >= F::ZERO);
 
-    let fp = to_decimal(float);
+    let mut fp = to_decimal_with_rounding(float, options.digit_rounding());
     let digit_count = F::digit_count(fp.mant);
+    if options.percent() && fp.mant != 0 {
+        // Scale percentages exactly by shifting the decimal exponent.
+        fp.exp += 2;
+    }
     let sci_exp = fp.exp + digit_count as i32 - 1;
 
     // Note that for performance reasons, we write the significant digits
@@ -71,6 +75,20 @@ pub fn write_float<F: RawFloat, const FORMAT: u128>(
     )
 }
 
+/// Get the number of significant digits and the scientific exponent of the
+/// shortest decimal representation of a float.
+///
+/// The float must be finite and positive.
+#[inline(always)]
+pub fn shortest_digits<F: RawFloat>(float: F) -> (usize, i32) {
+    debug_assert!(!float.is_special());
+    debug_assert!(float > F::ZERO);
+
+    let fp = to_decimal(float);
+    let digit_count = F::digit_count(fp.mant);
+    (digit_count, fp.exp + digit_count as i32 - 1)
+}
+
 /// Write float to string in scientific notation.
 #[inline]
 pub fn write_float_scientific<F: DragonboxFloat, const FORMAT: u128>(
@@ -102,7 +120,10 @@ pub fn write_float_scientific<F: DragonboxFloat, const FORMAT: u128>(
     let mut cursor: usize;
     bytes[0] = bytes[1];
     bytes[1] = decimal_point;
-    if !format.no_exponent_without_fraction() && digit_count == 1 && options.trim_floats() {
+    if !format.no_exponent_without_fraction()
+        && digit_count == 1
+        && (options.trim_floats() || options.trim_scientific_floats())
+    {
         cursor = 1;
     } else if digit_count < exact_count {
         // Adjust the number of digits written, by appending zeros.
@@ -119,7 +140,7 @@ pub fn write_float_scientific<F: DragonboxFloat, const FORMAT: u128>(
 
     // Now, write our scientific notation.
     // Won't panic since bytes must be large enough to store all digits.
-    shared::write_exponent::<FORMAT>(bytes, &mut cursor, sci_exp, options.exponent());
+    shared::write_exponent::<FORMAT>(bytes, &mut cursor, sci_exp, options);
 
     cursor
 }
@@ -286,6 +307,17 @@ pub fn write_float_positive_exponent<F: DragonboxFloat, const FORMAT: u128>(
 /// short-circuit anyway.
 #[inline(always)]
 pub fn to_decimal<F: RawFloat>(float: F) -> ExtendedFloat80 {
+    to_decimal_with_rounding(float, DigitRounding::HalfEven)
+}
+
+/// Get an extended representation of the decimal float, breaking ties
+/// between the two nearest representations with the rounding mode.
+///
+/// This is identical to [`to_decimal`], other than the rounding mode, which
+/// only matters if the float is exactly halfway between two representations
+/// of the shortest length.
+#[inline(always)]
+pub fn to_decimal_with_rounding<F: RawFloat>(float: F, rounding: DigitRounding) -> ExtendedFloat80 {
     let bits = float.to_bits();
     let mantissa_bits = bits & F::MANTISSA_MASK;
 
@@ -350,29 +382,29 @@ pub fn to_decimal<F: RawFloat>(float: F) -> ExtendedFloat80 {
     //    - `13.9999999999999982236431606 => (13999999999999982, -15)` for binary64.
 
     if mantissa_bits.as_u64() == 0 {
-        compute_round_short(float)
+        compute_round_short(float, rounding)
     } else {
-        compute_round(float)
+        compute_round(float, rounding)
     }
 }
 
-/// Compute for a simple case when rounding nearest, tie-even.
+/// Compute for a simple case when rounding nearest.
 #[inline(always)]
-pub fn compute_round_short<F: RawFloat>(float: F) -> ExtendedFloat80 {
-    compute_nearest_shorter(float)
+pub fn compute_round_short<F: RawFloat>(float: F, rounding: DigitRounding) -> ExtendedFloat80 {
+    compute_nearest_shorter(float, rounding)
 }
 
-/// Compute for a non-simple case when rounding nearest, tie-even.
+/// Compute for a non-simple case when rounding nearest.
 #[inline(always)]
-pub fn compute_round<F: RawFloat>(float: F) -> ExtendedFloat80 {
-    compute_nearest_normal(float)
+pub fn compute_round<F: RawFloat>(float: F, rounding: DigitRounding) -> ExtendedFloat80 {
+    compute_nearest_normal(float, rounding)
 }
 
 /// Compute the interval `I = [m−w,m+w]` if even, otherwise, `(m−w,m+w)`.
 /// This is the simple case for a finite number where only the hidden bit is
 /// set.
 #[inline]
-pub fn compute_nearest_shorter<F: RawFloat>(float: F) -> ExtendedFloat80 {
+pub fn compute_nearest_shorter<F: RawFloat>(float: F, rounding: DigitRounding) -> ExtendedFloat80 {
     // Compute `k` and `beta`.
     let exponent = float.exponent();
     let minus_k = floor_log10_pow2_minus_log10_4_over_3(exponent);
@@ -421,7 +453,7 @@ pub fn compute_nearest_shorter<F: RawFloat>(float: F) -> ExtendedFloat80 {
     let lower_threshold: i32 = -floor_log5_pow2_minus_log5_3(bits + 4) - 2 - bits;
     let upper_threshold: i32 = -floor_log5_pow2(bits + 2) - 2 - bits;
 
-    let round_down = RoundMode::Round.prefer_round_down(significand);
+    let round_down = rounding.prefer_round_down(significand);
     if round_down && exponent >= lower_threshold && exponent <= upper_threshold {
         significand -= 1;
     } else if significand < xi {
@@ -440,7 +472,7 @@ pub fn compute_nearest_shorter<F: RawFloat>(float: F) -> ExtendedFloat80 {
 /// This is the normal case for a finite number with non-zero significant
 /// digits.
 #[allow(clippy::comparison_chain)] // reason="logical approach for algorithm"
-pub fn compute_nearest_normal<F: RawFloat>(float: F) -> ExtendedFloat80 {
+pub fn compute_nearest_normal<F: RawFloat>(float: F, rounding: DigitRounding) -> ExtendedFloat80 {
     let mantissa = float.mantissa().as_u64();
     let exponent = float.exponent();
     let is_even = mantissa % 2 == 0;
@@ -555,7 +587,7 @@ pub fn compute_nearest_normal<F: RawFloat>(float: F) -> ExtendedFloat80 {
             // parity. Also, `zi` and `r` should have the same parity since the divisor is
             // an even number.
             let (yi_parity, is_y_integer) = F::compute_mul_parity(two_fc, &pow5, beta);
-            let round_down = RoundMode::Round.prefer_round_down(significand);
+            let round_down = rounding.prefer_round_down(significand);
 
             if yi_parity != approx_y_parity || (is_y_integer && round_down) {
                 // If `z^(f) >= epsilon^(f)`, we might have a tie
@@ -986,6 +1018,23 @@ impl RoundMode {
     }
 }
 
+impl DigitRounding {
+    /// Determine if we should round down at a tie.
+    ///
+    /// The shortest representation is always the nearest, so the directed
+    /// rounding modes break ties to even, like [`DigitRounding::HalfEven`].
+    #[inline(always)]
+    pub const fn prefer_round_down(&self, significand: u64) -> bool {
+        match self {
+            DigitRounding::HalfEven | DigitRounding::TowardZero | DigitRounding::AwayFromZero => {
+                significand % 2 != 0
+            },
+            DigitRounding::HalfDown => true,
+            DigitRounding::HalfUp => false,
+        }
+    }
+}
+
 // INTERVAL TYPE
 // -------------
 
@@ -1149,7 +1198,11 @@ pub trait DragonboxFloat: Float {
     /// Or, `ceil((MANTISSA_SIZE + 1) / log2(10)) + 1`.
     const DECIMAL_DIGITS: usize;
     const FC_PM_HALF_LOWER: i32 = -(Self::KAPPA as i32) - floor_log5_pow2(Self::KAPPA as i32);
-    const DIV_BY_5_THRESHOLD: i32 = floor_log2_pow10(Self::KAPPA as i32 + 1);
+    /// Maximum power of 5 that can divide the endpoints, like `2 * fc - 1`.
+    const MAX_POWER_OF_FACTOR_OF_5: i32 = floor_log5_pow2(Self::MANTISSA_SIZE + 2);
+    /// Maximum exponent where the left endpoint may be an integer.
+    const DIV_BY_5_THRESHOLD: i32 =
+        floor_log2_pow10(Self::MAX_POWER_OF_FACTOR_OF_5 + Self::KAPPA as i32 + 1);
 
     type Power;
 
@@ -1262,7 +1315,7 @@ impl DragonboxFloat for f32 {
 
         let r = umul96_lower64(two_f, *pow5);
         let parity = (r >> (64 - beta)) & 1;
-        let is_integer = r >> (32 - beta);
+        let is_integer = (r >> (32 - beta)) as u32;
         (parity != 0, is_integer == 0)
     }
 
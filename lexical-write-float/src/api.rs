@@ -56,3 +56,35 @@ float_to_lexical! {
     f16 ;
     bf16 ;
 }
+
+// SHORTEST DIGITS
+
+/// Get the number of significant digits required to round-trip a float,
+/// and the exponent of the shortest representation.
+///
+/// Returns `(digit_count, exponent)`, where `digit_count` is the number of
+/// significant decimal digits in the shortest representation that parses
+/// to the same float, and `exponent` is the decimal exponent of its first
+/// digit, as if written in scientific notation. These are the digits
+/// written by [`ToLexical`] with the default options, so they may be used
+/// to choose column widths before formatting. The sign is ignored, and
+/// zero has a single digit and an exponent of 0.
+///
+/// Returns `None` for special values, like `NaN` or infinity.
+///
+/// # Examples
+///
+/// ```rust
+/// use lexical_write_float::shortest_digits;
+///
+/// assert_eq!(shortest_digits(1.5f64), Some((2, 0)));
+/// assert_eq!(shortest_digits(-1234.5f64), Some((5, 3)));
+/// assert_eq!(shortest_digits(0.1f64), Some((1, -1)));
+/// assert_eq!(shortest_digits(0.1f32 as f64), Some((17, -1)));
+/// assert_eq!(shortest_digits(1e300f64), Some((1, 300)));
+/// assert_eq!(shortest_digits(f64::NAN), None);
+/// ```
+#[inline]
+pub fn shortest_digits<F: WriteFloat>(float: F) -> Option<(usize, i32)> {
+    float.shortest_digits()
+}
@@ -13,7 +13,7 @@ use lexical_util::format::NumberFormat;
 use lexical_util::num::{as_cast, Float, Integer, UnsignedInteger};
 use lexical_write_integer::write::WriteInteger;
 
-use crate::options::{Options, RoundMode};
+use crate::options::{DigitRounding, Options};
 use crate::shared;
 
 /// Optimized float-to-string algorithm for power of 2 radixes.
@@ -147,7 +147,10 @@ where
     let exact_count = shared::min_exact_digits(digit_count, options);
 
     // Write any trailing digits to the output.
-    if !format.no_exponent_without_fraction() && cursor == 2 && options.trim_floats() {
+    if !format.no_exponent_without_fraction()
+        && cursor == 2
+        && (options.trim_floats() || options.trim_scientific_floats())
+    {
         // Need to trim floats from trailing zeros, and we have only a decimal.
         cursor -= 1;
     } else if exact_count < 2 {
@@ -165,7 +168,7 @@ where
 
     // Now, write our scientific notation.
     let scaled_sci_exp = scale_sci_exp(sci_exp, bits_per_digit);
-    shared::write_exponent::<FORMAT>(bytes, &mut cursor, scaled_sci_exp, options.exponent());
+    shared::write_exponent::<FORMAT>(bytes, &mut cursor, scaled_sci_exp, options);
 
     cursor
 }
@@ -739,23 +742,27 @@ where
         let shr = (mantissa_bits - max_bits) as i32;
         shifted_mantissa = mantissa >> shr;
 
-        // We need to round-nearest, tie-even, so we need to handle
-        // the truncation **here**. If the representation is above
-        // halfway at all, we need to round up, even if 1 bit.
-        if options.round_mode() == RoundMode::Round {
+        // We need to handle the truncation **here**, comparing the
+        // truncated bits to halfway. If the representation is above
+        // halfway at all, it's above halfway, even if by 1 bit.
+        if options.digit_rounding() != DigitRounding::TowardZero {
             let mask = (M::ONE << shr) - M::ONE;
             let halfway = M::ONE << (shr - 1);
-            let above_halfway = (mantissa & mask) > halfway;
-            let is_halfway = (mantissa & mask) == halfway;
+            let truncated = mantissa & mask;
             let is_odd = shifted_mantissa & M::ONE == M::ONE;
+            let is_round_up = options.digit_rounding().is_round_up(
+                is_odd,
+                truncated.cmp(&halfway),
+                truncated == M::ZERO,
+            );
 
             // Round-up and calculate if we carry over 1-bit.
             // The built-in ctlz is very fast, so use that.
             // Add 1 to the mantissa bits if we carry.
             let initial_bits = shifted_mantissa.leading_zeros();
-            shifted_mantissa += as_cast((above_halfway || (is_odd & is_halfway)) as u32);
+            shifted_mantissa += as_cast(is_round_up as u32);
             let final_bits = shifted_mantissa.leading_zeros();
-            mantissa_bits += (final_bits - initial_bits) as usize;
+            mantissa_bits += (initial_bits - final_bits) as usize;
         }
     }
 
@@ -22,7 +22,7 @@
 #![cfg(feature = "compact")]
 #![doc(hidden)]
 
-use lexical_util::algorithm::{copy_to_dst, rtrim_char_count};
+use lexical_util::algorithm::rtrim_char_count;
 #[cfg(feature = "f16")]
 use lexical_util::bf16::bf16;
 use lexical_util::digit::digit_to_char_const;
@@ -34,6 +34,11 @@ use lexical_util::num::{AsPrimitive, Float};
 use crate::float::{ExtendedFloat80, RawFloat};
 use crate::options::Options;
 use crate::shared;
+pub use crate::shared::{
+    write_float_negative_exponent,
+    write_float_positive_exponent,
+    write_float_scientific,
+};
 use crate::table::GRISU_POWERS_OF_TEN;
 
 /// Compact float-to-string algorithm for decimal strings.
@@ -70,7 +75,11 @@ pub fn write_float<F: RawFloat, const FORMAT: u128>(
         (end, k + start as i32 - end as i32, carried)
     };
 
-    let sci_exp = kappa + digit_count as i32 - 1 + carried as i32;
+    let mut sci_exp = kappa + digit_count as i32 - 1 + carried as i32;
+    if options.percent() && float != F::ZERO {
+        // Scale percentages exactly by shifting the decimal exponent.
+        sci_exp += 2;
+    }
     write_float!(
         float,
         FORMAT,
@@ -84,176 +93,18 @@ pub fn write_float<F: RawFloat, const FORMAT: u128>(
     )
 }
 
-/// Write float to string in scientific notation.
-#[allow(clippy::comparison_chain)] // reason="logical approach for the algorithm"
-pub fn write_float_scientific<const FORMAT: u128>(
-    bytes: &mut [u8],
-    digits: &mut [u8],
-    digit_count: usize,
-    sci_exp: i32,
-    options: &Options,
-) -> usize {
-    debug_assert!(rtrim_char_count(&digits[..digit_count], b'0') == 0 || digit_count == 1);
-    debug_assert!(digit_count <= 20);
-
-    // Config options
-    let format = NumberFormat::<{ FORMAT }> {};
-    assert!(format.is_valid());
-    let decimal_point = options.decimal_point();
-
-    // Determine the exact number of digits to write.
-    let exact_count = shared::min_exact_digits(digit_count, options);
-
-    // Write our significant digits
-    let mut cursor: usize;
-    bytes[0] = digits[0];
-    bytes[1] = decimal_point;
-    if !format.no_exponent_without_fraction() && digit_count == 1 && options.trim_floats() {
-        // No more digits and need to trim floats.
-        cursor = 1;
-    } else if digit_count < exact_count {
-        // Write our significant digits.
-        let src = &digits[1..digit_count];
-        let dst = &mut bytes[2..digit_count + 1];
-        copy_to_dst(dst, src);
-        cursor = digit_count + 1;
-
-        // Adjust the number of digits written, by appending zeros.
-        let zeros = exact_count - digit_count;
-        bytes[cursor..cursor + zeros].fill(b'0');
-        cursor += zeros;
-    } else if digit_count == 1 {
-        // Write a single, trailing 0.
-        bytes[2] = b'0';
-        cursor = 3;
-    } else {
-        // Write our significant digits.
-        let src = &digits[1..digit_count];
-        let dst = &mut bytes[2..digit_count + 1];
-        copy_to_dst(dst, src);
-        cursor = digit_count + 1;
-    }
-
-    // Now, write our scientific notation.
-    shared::write_exponent::<FORMAT>(bytes, &mut cursor, sci_exp, options.exponent());
-
-    cursor
-}
-
-/// Write negative float to string without scientific notation.
-///
-/// Has a negative exponent (shift right) and no scientific notation.
-#[allow(clippy::comparison_chain)] // reason="logical approach for the algorithm"
-pub fn write_float_negative_exponent<const FORMAT: u128>(
-    bytes: &mut [u8],
-    digits: &mut [u8],
-    digit_count: usize,
-    sci_exp: i32,
-    options: &Options,
-) -> usize {
-    debug_assert!(rtrim_char_count(&digits[..digit_count], b'0') == 0);
-    debug_assert!(digit_count <= 20);
-    debug_assert!(sci_exp < 0);
-
-    // Config options
-    let decimal_point = options.decimal_point();
-    let sci_exp = sci_exp.wrapping_neg() as usize;
-
-    // Write our 0 digits. Note that we cannot have carried, since we previously
-    // adjusted for carrying and rounding before.
-    bytes[0] = b'0';
-    bytes[1] = decimal_point;
-    bytes[2..sci_exp + 1].fill(b'0');
-    let mut cursor = sci_exp + 1;
-
-    // Write out significant digits.
-    let src = &digits[..digit_count];
-    let dst = &mut bytes[cursor..cursor + digit_count];
-    copy_to_dst(dst, src);
-    cursor += digit_count;
-
-    // Determine the exact number of digits to write.
-    let exact_count = shared::min_exact_digits(digit_count, options);
-
-    // Adjust the number of digits written, based on the exact number of digits.
-    if digit_count < exact_count {
-        let zeros = exact_count - digit_count;
-        bytes[cursor..cursor + zeros].fill(b'0');
-        cursor += zeros;
-    }
-
-    cursor
-}
-
-/// Write positive float to string without scientific notation.
+/// Get the number of significant digits and the scientific exponent of the
+/// shortest decimal representation of a float.
 ///
-/// Has a positive exponent (shift left) and no scientific notation.
-pub fn write_float_positive_exponent<const FORMAT: u128>(
-    bytes: &mut [u8],
-    digits: &mut [u8],
-    mut digit_count: usize,
-    sci_exp: i32,
-    options: &Options,
-) -> usize {
-    debug_assert!(rtrim_char_count(&digits[..digit_count], b'0') == 0 || digit_count == 1);
-    debug_assert!(digit_count <= 20);
-    debug_assert!(sci_exp >= 0);
-
-    // Config options
-    let decimal_point = options.decimal_point();
-
-    // Now need to write our significant digits.
-    let leading_digits = sci_exp as usize + 1;
-    let mut cursor: usize;
-    let mut trimmed = false;
-    if leading_digits >= digit_count {
-        // We have more leading digits than digits we wrote: can write
-        // any additional digits, and then just write the remaining ones.
-        let src = &digits[..digit_count];
-        let dst = &mut bytes[..digit_count];
-        copy_to_dst(dst, src);
-        bytes[digit_count..leading_digits].fill(b'0');
-        cursor = leading_digits;
-        digit_count = leading_digits;
-        // Only write decimal point if we're not trimming floats.
-        if !options.trim_floats() {
-            bytes[cursor] = decimal_point;
-            cursor += 1;
-            bytes[cursor] = b'0';
-            cursor += 1;
-            digit_count += 1;
-        } else {
-            trimmed = true;
-        }
-    } else {
-        // We have less leading digits than digits we wrote.
-
-        // Write the digits before the decimal point.
-        let src = &digits[..leading_digits];
-        let dst = &mut bytes[..leading_digits];
-        copy_to_dst(dst, src);
-        bytes[leading_digits] = decimal_point;
-
-        // Write the digits after the decimal point.
-        let src = &digits[leading_digits..digit_count];
-        let dst = &mut bytes[leading_digits + 1..digit_count + 1];
-        copy_to_dst(dst, src);
-
-        cursor = digit_count + 1;
-    }
-
-    // Determine the exact number of digits to write.
-    let exact_count = shared::min_exact_digits(digit_count, options);
-
-    // Change the number of digits written, if we need to add more or trim digits.
-    if !trimmed && exact_count > digit_count {
-        // Check if we need to write more trailing digits.
-        let zeros = exact_count - digit_count;
-        bytes[cursor..cursor + zeros].fill(b'0');
-        cursor += zeros;
-    }
+/// The float must be finite and positive.
+pub fn shortest_digits<F: RawFloat>(float: F) -> (usize, i32) {
+    debug_assert!(!float.is_special());
+    debug_assert!(float > F::ZERO);
 
-    cursor
+    let mut digits: [u8; 32] = [0u8; 32];
+    let (digit_count, k) = grisu(float, &mut digits);
+    let sci_exp = k + digit_count as i32 - 1;
+    (digit_count - rtrim_char_count(&digits[..digit_count], b'0'), sci_exp)
 }
 
 // ALGORITHM
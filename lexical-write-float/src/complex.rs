@@ -0,0 +1,111 @@
+//! Write complex numbers, like `3+4i`.
+//!
+//! The real part is written first, followed by the imaginary part with an
+//! explicit sign and the imaginary unit, so the output can be parsed by
+//! `parse_complex`. Each part is written like [`ToLexicalWithOptions`].
+
+#![doc(hidden)]
+
+use lexical_util::format::{NumberFormat, STANDARD};
+
+use crate::api::ToLexicalWithOptions;
+use crate::options::Options;
+
+const DEFAULT_OPTIONS: Options = Options::new();
+
+/// Write a complex number to a buffer, using the standard format.
+///
+/// Returns a subslice of the input buffer containing the written bytes,
+/// starting from the same address in memory as the input slice. The real
+/// and imaginary parts are both written, with the imaginary unit `i`, so
+/// `(3.0, 0.0)` is written as `3.0+0.0i`.
+///
+/// # Panics
+///
+/// Panics if the buffer is not large enough to hold both parts. A buffer
+/// of twice [`FORMATTED_SIZE_DECIMAL`] bytes, plus 2 bytes for the sign and
+/// the imaginary unit, is always large enough.
+///
+/// # Examples
+///
+/// ```rust
+/// use lexical_write_float::write_complex;
+///
+/// let mut buffer = [0u8; 128];
+/// assert_eq!(write_complex(3.0f64, 4.0, &mut buffer), b"3.0+4.0i");
+/// assert_eq!(write_complex(1500.0f64, -0.25, &mut buffer), b"1500.0-0.25i");
+/// assert_eq!(write_complex(-0.5f32, f32::NAN, &mut buffer), b"-0.5+NaNi");
+/// ```
+///
+/// [`FORMATTED_SIZE_DECIMAL`]: lexical_util::constants::FormattedSize::FORMATTED_SIZE_DECIMAL
+#[inline]
+pub fn write_complex<F>(re: F, im: F, bytes: &mut [u8]) -> &mut [u8]
+where
+    F: ToLexicalWithOptions<Options = Options>,
+{
+    write_complex_with_options::<F, STANDARD>(re, im, bytes, &DEFAULT_OPTIONS)
+}
+
+/// Write a complex number to a buffer, using a custom format and options.
+///
+/// This is identical to [`write_complex`], except each part is written
+/// like [`ToLexicalWithOptions::to_lexical_with_options`], and the
+/// imaginary unit is the [`imaginary_unit`](Options::imaginary_unit) of
+/// the options.
+///
+/// # Panics
+///
+/// Panics if the buffer is smaller than twice
+/// [`buffer_size_const`](Options::buffer_size_const), plus 2 bytes for the
+/// sign and the imaginary unit, or for any reason
+/// [`ToLexicalWithOptions::to_lexical_with_options`] panics. Since the
+/// imaginary part must start with its sign, this also panics if the options
+/// have a [`min_width`](Options::min_width), or if the format writes
+/// negative numbers in parentheses.
+///
+/// # Examples
+///
+/// ```rust
+/// use lexical_write_float::{format, write_complex_with_options, Options};
+///
+/// const OPTIONS: Options = Options::builder()
+///     .imaginary_unit(b'j')
+///     .trim_floats(true)
+///     .build_unchecked();
+/// const FORMAT: u128 = format::STANDARD;
+///
+/// let mut buffer = [0u8; 128];
+/// let result = write_complex_with_options::<_, FORMAT>(1.0f64, -2.0, &mut buffer, &OPTIONS);
+/// assert_eq!(result, b"1-2j");
+/// ```
+#[inline]
+pub fn write_complex_with_options<'a, F, const FORMAT: u128>(
+    re: F,
+    im: F,
+    bytes: &'a mut [u8],
+    options: &Options,
+) -> &'a mut [u8]
+where
+    F: ToLexicalWithOptions<Options = Options>,
+{
+    let size = options.buffer_size_const::<F, FORMAT>();
+    assert!(bytes.len() >= 2 * size + 2);
+    let format = NumberFormat::<FORMAT> {};
+    assert!(options.min_width().is_none());
+    assert!(!(cfg!(feature = "format") && format.parenthesized_negatives()));
+    let index = re.to_lexical_with_options::<FORMAT>(bytes, options).len();
+
+    // Write the imaginary part after room for the sign, and move it back
+    // if it was written with its own sign.
+    let start = index + 1;
+    let count = im.to_lexical_with_options::<FORMAT>(&mut bytes[start..], options).len();
+    let index = if matches!(bytes[start], b'+' | b'-') {
+        bytes.copy_within(start..start + count, index);
+        index + count
+    } else {
+        bytes[index] = b'+';
+        start + count
+    };
+    bytes[index] = options.imaginary_unit();
+    &mut bytes[..index + 1]
+}
@@ -119,6 +119,8 @@ pub mod table;
 pub mod write;
 
 mod api;
+mod complex;
+mod printf;
 mod table_dragonbox;
 mod table_grisu;
 
@@ -129,8 +131,26 @@ pub use lexical_util::constants::{FormattedSize, BUFFER_SIZE};
 #[cfg(feature = "f16")]
 pub use lexical_util::f16::f16;
 pub use lexical_util::format::{self, NumberFormatBuilder};
-pub use lexical_util::options::WriteOptions;
+pub use lexical_util::options::{Alignment, WriteOptions};
 
-pub use self::api::{ToLexical, ToLexicalWithOptions};
+pub use self::api::{shortest_digits, ToLexical, ToLexicalWithOptions};
+pub use self::complex::{write_complex, write_complex_with_options};
 #[doc(inline)]
-pub use self::options::{Options, OptionsBuilder, RoundMode};
+pub use self::options::{
+    Algorithm,
+    DigitRounding,
+    NegativeZero,
+    Options,
+    OptionsBuilder,
+    RoundMode,
+};
+pub use self::printf::{
+    exponential_buffer_size,
+    fixed_buffer_size,
+    write_exponential,
+    write_exponential_with_rounding,
+    write_fixed,
+    write_fixed_with_rounding,
+    write_printf,
+    PrintfSpec,
+};
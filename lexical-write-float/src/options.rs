@@ -2,14 +2,16 @@
 
 use core::{mem, num};
 
-use lexical_util::ascii::{is_valid_ascii, is_valid_letter_slice};
+use lexical_util::ascii::{is_valid_ascii, is_valid_special_slice};
 use lexical_util::constants::FormattedSize;
 use lexical_util::error::Error;
 use lexical_util::format::NumberFormat;
-use lexical_util::options::{self, WriteOptions};
+use lexical_util::options::{self, Alignment, WriteOptions};
 use lexical_util::result::Result;
 use static_assertions::const_assert;
 
+use crate::printf::MAX_EXACT_DIGITS;
+
 /// Type with the exact same size as a `usize`.
 pub type OptionUsize = Option<num::NonZeroUsize>;
 
@@ -30,6 +32,95 @@ pub enum RoundMode {
     Truncate,
 }
 
+/// Enumeration for how to round the digits of floats with precision control.
+///
+/// This is a superset of [`RoundMode`]: [`DigitRounding::HalfEven`] is
+/// [`RoundMode::Round`], and [`DigitRounding::TowardZero`] is
+/// [`RoundMode::Truncate`]. The digits are rounded by magnitude, before the
+/// sign is written, so rounding up is away from zero and rounding down is
+/// toward zero.
+#[non_exhaustive]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum DigitRounding {
+    /// Round to the nearest, with ties to even (half-even).
+    HalfEven,
+    /// Round to the nearest, with ties away from zero (half-up), like
+    /// most financial reports.
+    ///
+    /// This also breaks ties between the two nearest shortest
+    /// representations away from zero, like `core::fmt`.
+    HalfUp,
+    /// Round to the nearest, with ties toward zero (half-down).
+    ///
+    /// This also breaks ties between the two nearest shortest
+    /// representations toward zero.
+    HalfDown,
+    /// Truncate the digits, rounding toward zero.
+    TowardZero,
+    /// Round away from zero if any truncated digits are not zero.
+    AwayFromZero,
+}
+
+impl DigitRounding {
+    /// Get the equivalent rounding mode for a [`RoundMode`].
+    #[inline(always)]
+    pub const fn from_round_mode(round_mode: RoundMode) -> Self {
+        match round_mode {
+            RoundMode::Round => Self::HalfEven,
+            RoundMode::Truncate => Self::TowardZero,
+        }
+    }
+
+    /// Get the closest [`RoundMode`].
+    ///
+    /// This is [`RoundMode::Truncate`] for [`DigitRounding::TowardZero`],
+    /// and [`RoundMode::Round`] otherwise.
+    #[inline(always)]
+    pub const fn to_round_mode(self) -> RoundMode {
+        match self {
+            Self::TowardZero => RoundMode::Truncate,
+            Self::HalfEven | Self::HalfUp | Self::HalfDown | Self::AwayFromZero => RoundMode::Round,
+        }
+    }
+}
+
+/// Enumeration for the algorithm used to generate the digits of floats.
+///
+/// This is selected at runtime, so a single build can write floats with
+/// the fastest algorithm in some places, and with the exact digits in
+/// others.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Algorithm {
+    /// Write the shortest representation that round-trips, or round it to
+    /// the maximum number of significant digits.
+    Shortest,
+    /// Round the exact value of the float to the maximum number of
+    /// significant digits, like the `%.*e` conversion of `printf`.
+    ///
+    /// Rounding the shortest representation may differ in the last digit,
+    /// since it rounds twice. This only affects decimal floats with a
+    /// maximum number of significant digits, and is otherwise identical
+    /// to [`Algorithm::Shortest`].
+    Exact,
+    /// Use the faster, naive algorithm for radixes that are not powers of
+    /// two, which may write incorrect digits.
+    ///
+    /// This only affects radixes that are not powers of two, and is
+    /// otherwise identical to [`Algorithm::Shortest`].
+    Lossy,
+}
+
+/// Enumeration for how to write negative zero.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum NegativeZero {
+    /// Write the sign, like `-0.0`, so the sign round-trips.
+    Signed,
+    /// Write negative zero like positive zero, as `0.0`.
+    Unsigned,
+    /// Write the sign and zero without a fraction, as `-0`.
+    Integer,
+}
+
 /// Maximum length for a special string.
 const MAX_SPECIAL_STRING_LENGTH: usize = 50;
 const_assert!(MAX_SPECIAL_STRING_LENGTH < f32::FORMATTED_SIZE_DECIMAL);
@@ -56,9 +147,11 @@ pub struct OptionsBuilder {
     /// radix. If not provided, use the algorithm's default.
     negative_exponent_break: OptionI32,
     /// Rounding mode for writing digits with precision control.
-    round_mode: RoundMode,
+    digit_rounding: DigitRounding,
     /// Trim the trailing ".0" from integral float strings.
     trim_floats: bool,
+    /// Algorithm used to generate the digits.
+    algorithm: Algorithm,
     /// Character to designate the exponent component of a float.
     exponent: u8,
     /// Character to separate the integer from the fraction components.
@@ -67,6 +160,30 @@ pub struct OptionsBuilder {
     nan_string: Option<&'static [u8]>,
     /// String representation of `Infinity`.
     inf_string: Option<&'static [u8]>,
+    /// String to designate the exponent component of a float, used
+    /// instead of the exponent character.
+    exponent_string: Option<&'static [u8]>,
+    /// Minimum number of digits to write in the exponent.
+    min_exponent_digits: OptionUsize,
+    /// Trim the trailing ".0" from integral floats in scientific notation.
+    trim_scientific_floats: bool,
+    /// Write a `+` sign before positive infinity.
+    positive_inf_sign: bool,
+    /// How to write negative zero.
+    negative_zero: NegativeZero,
+    /// Write subnormal floats as zero.
+    flush_subnormals: bool,
+    /// Write the float as a percentage, scaled by 100 with a trailing `%`.
+    percent: bool,
+    /// Minimum number of characters to write, including the sign.
+    /// If not set, no padding is written.
+    min_width: OptionUsize,
+    /// Character used to pad the output to the minimum width.
+    pad_char: u8,
+    /// Alignment of the float within the minimum width.
+    alignment: Alignment,
+    /// Character to designate the imaginary part of a complex number.
+    imaginary_unit: u8,
 }
 
 impl OptionsBuilder {
@@ -79,12 +196,24 @@ impl OptionsBuilder {
             min_significant_digits: None,
             positive_exponent_break: None,
             negative_exponent_break: None,
-            round_mode: RoundMode::Round,
+            digit_rounding: DigitRounding::HalfEven,
             trim_floats: false,
+            algorithm: Algorithm::Shortest,
             exponent: b'e',
             decimal_point: b'.',
             nan_string: Some(b"NaN"),
             inf_string: Some(b"inf"),
+            exponent_string: None,
+            min_exponent_digits: None,
+            trim_scientific_floats: false,
+            positive_inf_sign: false,
+            negative_zero: NegativeZero::Signed,
+            flush_subnormals: false,
+            percent: false,
+            min_width: None,
+            pad_char: b'0',
+            alignment: Alignment::Right,
+            imaginary_unit: b'i',
         }
     }
 
@@ -115,9 +244,18 @@ impl OptionsBuilder {
     }
 
     /// Get the rounding mode for writing digits with precision control.
+    ///
+    /// This is [`RoundMode::Truncate`] if the digits are rounded toward
+    /// zero, and [`RoundMode::Round`] otherwise.
     #[inline(always)]
     pub const fn get_round_mode(&self) -> RoundMode {
-        self.round_mode
+        self.digit_rounding.to_round_mode()
+    }
+
+    /// Get the rounding mode for writing digits with precision control.
+    #[inline(always)]
+    pub const fn get_digit_rounding(&self) -> DigitRounding {
+        self.digit_rounding
     }
 
     /// Get if we should trim a trailing `".0"` from floats.
@@ -126,6 +264,18 @@ impl OptionsBuilder {
         self.trim_floats
     }
 
+    /// Get if we should use the naive algorithm for non-power-of-two radixes.
+    #[inline(always)]
+    pub const fn get_lossy(&self) -> bool {
+        matches!(self.algorithm, Algorithm::Lossy)
+    }
+
+    /// Get the algorithm used to generate the digits.
+    #[inline(always)]
+    pub const fn get_algorithm(&self) -> Algorithm {
+        self.algorithm
+    }
+
     /// Get the character to designate the exponent component of a float.
     #[inline(always)]
     pub const fn get_exponent(&self) -> u8 {
@@ -150,6 +300,74 @@ impl OptionsBuilder {
         self.inf_string
     }
 
+    /// Get the string to designate the exponent component of a float.
+    #[inline(always)]
+    pub const fn get_exponent_string(&self) -> Option<&'static [u8]> {
+        self.exponent_string
+    }
+
+    /// Get the minimum number of digits to write in the exponent.
+    #[inline(always)]
+    pub const fn get_min_exponent_digits(&self) -> OptionUsize {
+        self.min_exponent_digits
+    }
+
+    /// Get if we should trim a trailing `".0"` from floats in scientific
+    /// notation.
+    #[inline(always)]
+    pub const fn get_trim_scientific_floats(&self) -> bool {
+        self.trim_scientific_floats
+    }
+
+    /// Get if we should write a `+` sign before positive infinity.
+    #[inline(always)]
+    pub const fn get_positive_inf_sign(&self) -> bool {
+        self.positive_inf_sign
+    }
+
+    /// Get how to write negative zero.
+    #[inline(always)]
+    pub const fn get_negative_zero(&self) -> NegativeZero {
+        self.negative_zero
+    }
+
+    /// Get if we should write subnormal floats as zero.
+    #[inline(always)]
+    pub const fn get_flush_subnormals(&self) -> bool {
+        self.flush_subnormals
+    }
+
+    /// Get if we should write the float as a percentage.
+    #[inline(always)]
+    pub const fn get_percent(&self) -> bool {
+        self.percent
+    }
+
+    /// Get the minimum number of characters to write.
+    #[inline(always)]
+    pub const fn get_min_width(&self) -> OptionUsize {
+        self.min_width
+    }
+
+    /// Get the character used to pad the output to the minimum width.
+    #[inline(always)]
+    pub const fn get_pad_char(&self) -> u8 {
+        self.pad_char
+    }
+
+    /// Get the alignment of the float within the minimum width.
+    #[inline(always)]
+    pub const fn get_alignment(&self) -> Alignment {
+        self.alignment
+    }
+
+    /// Get the character to designate the imaginary part of a complex
+    /// number.
+    #[inline(always)]
+    pub const fn get_imaginary_unit(&self) -> u8 {
+        self.imaginary_unit
+    }
+
     // SETTERS
 
     /// Set the maximum number of significant digits to write.
@@ -181,9 +399,19 @@ impl OptionsBuilder {
     }
 
     /// Set the rounding mode for writing digits with precision control.
+    ///
+    /// This is identical to [`OptionsBuilder::digit_rounding`] with the
+    /// equivalent [`DigitRounding`].
     #[inline(always)]
     pub const fn round_mode(mut self, round_mode: RoundMode) -> Self {
-        self.round_mode = round_mode;
+        self.digit_rounding = DigitRounding::from_round_mode(round_mode);
+        self
+    }
+
+    /// Set the rounding mode for writing digits with precision control.
+    #[inline(always)]
+    pub const fn digit_rounding(mut self, digit_rounding: DigitRounding) -> Self {
+        self.digit_rounding = digit_rounding;
         self
     }
 
@@ -194,6 +422,27 @@ impl OptionsBuilder {
         self
     }
 
+    /// Set if we should use the naive algorithm for non-power-of-two radixes.
+    ///
+    /// The naive algorithm is faster, but accumulates rounding error for
+    /// each digit, so it may write incorrect digits. This has no effect
+    /// for decimal or power-of-two radixes.
+    ///
+    /// This is a shortcut for [`Algorithm::Lossy`]: disabling it restores
+    /// [`Algorithm::Shortest`] if the lossy algorithm was selected.
+    #[inline(always)]
+    pub const fn lossy(mut self, lossy: bool) -> Self {
+        self.algorithm = lossy_algorithm(self.algorithm, lossy);
+        self
+    }
+
+    /// Set the algorithm used to generate the digits.
+    #[inline(always)]
+    pub const fn algorithm(mut self, algorithm: Algorithm) -> Self {
+        self.algorithm = algorithm;
+        self
+    }
+
     /// Set the character to designate the exponent component of a float.
     #[inline(always)]
     pub const fn exponent(mut self, exponent: u8) -> Self {
@@ -236,6 +485,129 @@ impl OptionsBuilder {
         self
     }
 
+    /// Set the string to designate the exponent component of a float.
+    ///
+    /// This is written instead of the exponent character, for scientific
+    /// notation like `1.23×10^4`, where the string is `×10^`.
+    #[inline(always)]
+    pub const fn exponent_string(mut self, exponent_string: Option<&'static [u8]>) -> Self {
+        self.exponent_string = exponent_string;
+        self
+    }
+
+    /// Set the minimum number of digits to write in the exponent.
+    ///
+    /// The exponent is padded with leading zeros, after any sign, so a
+    /// minimum of 2 writes `1e-05` rather than `1e-5`, like C's `printf`.
+    #[inline(always)]
+    pub const fn min_exponent_digits(mut self, min_exponent_digits: OptionUsize) -> Self {
+        self.min_exponent_digits = min_exponent_digits;
+        self
+    }
+
+    /// Set if we should trim a trailing `".0"` from floats in scientific
+    /// notation.
+    ///
+    /// This trims `1.0e16` to `1e16`, like [`trim_floats`], but keeps the
+    /// trailing `".0"` in `1.0`, like Python's `repr`.
+    ///
+    /// [`trim_floats`]: Self::trim_floats
+    #[inline(always)]
+    pub const fn trim_scientific_floats(mut self, trim_scientific_floats: bool) -> Self {
+        self.trim_scientific_floats = trim_scientific_floats;
+        self
+    }
+
+    /// Set if we should write a `+` sign before positive infinity.
+    ///
+    /// This writes positive infinity as `+Inf`, like Go's `FormatFloat`,
+    /// if the infinity string is `Inf`. Finite floats are unaffected.
+    #[inline(always)]
+    pub const fn positive_inf_sign(mut self, positive_inf_sign: bool) -> Self {
+        self.positive_inf_sign = positive_inf_sign;
+        self
+    }
+
+    /// Set how to write negative zero.
+    ///
+    /// JSON round-trippers need the sign, as in `-0.0`, while user
+    /// interfaces may prefer `0.0`. This applies to all radixes and
+    /// number formats, and is independent of [`trim_floats`].
+    ///
+    /// [`trim_floats`]: Self::trim_floats
+    #[inline(always)]
+    pub const fn negative_zero(mut self, negative_zero: NegativeZero) -> Self {
+        self.negative_zero = negative_zero;
+        self
+    }
+
+    /// Set if we should write subnormal floats as zero.
+    ///
+    /// This mirrors the flush-to-zero (FTZ) mode of hardware, so subnormal
+    /// floats are written like zero with the same sign, including the
+    /// [`negative_zero`] handling for negative subnormal floats.
+    ///
+    /// [`negative_zero`]: Self::negative_zero
+    #[inline(always)]
+    pub const fn flush_subnormals(mut self, flush_subnormals: bool) -> Self {
+        self.flush_subnormals = flush_subnormals;
+        self
+    }
+
+    /// Set if we should write the float as a percentage.
+    ///
+    /// Finite floats are scaled by 100 and written with a trailing `%`,
+    /// so `0.075` is written as `7.5%`. The scaling shifts the decimal
+    /// exponent of the shortest or rounded digits, so it is exact and
+    /// does not round the float. Percentages are only written for
+    /// decimal floats: other radixes and special values are unchanged.
+    #[inline(always)]
+    pub const fn percent(mut self, percent: bool) -> Self {
+        self.percent = percent;
+        self
+    }
+
+    /// Set the minimum number of characters to write.
+    ///
+    /// The width includes the sign and any parentheses, and the float is
+    /// padded in the same buffer, so fixed-width columns need no second
+    /// pass over the output.
+    #[inline(always)]
+    pub const fn min_width(mut self, min_width: OptionUsize) -> Self {
+        self.min_width = min_width;
+        self
+    }
+
+    /// Set the character used to pad the output to the minimum width.
+    ///
+    /// Like `printf`, right-aligned floats padded with `0` place the zeros
+    /// after the sign, as in `-001.5`, while `NaN` and infinity are padded
+    /// with spaces.
+    #[inline(always)]
+    pub const fn pad_char(mut self, pad_char: u8) -> Self {
+        self.pad_char = pad_char;
+        self
+    }
+
+    /// Set the alignment of the float within the minimum width.
+    #[inline(always)]
+    pub const fn alignment(mut self, alignment: Alignment) -> Self {
+        self.alignment = alignment;
+        self
+    }
+
+    /// Set the character to designate the imaginary part of a complex
+    /// number.
+    ///
+    /// This is written after the imaginary part, like `3+4i`, and is
+    /// commonly `i` or `j`. It must be printable ASCII, and not a digit,
+    /// a sign, the exponent character, or the decimal point.
+    #[inline(always)]
+    pub const fn imaginary_unit(mut self, imaginary_unit: u8) -> Self {
+        self.imaginary_unit = imaginary_unit;
+        self
+    }
+
     // BUILDERS
 
     /// Determine if `nan_str` is valid.
@@ -250,9 +622,7 @@ impl OptionsBuilder {
         let length = nan.len();
         if length == 0 || length > MAX_SPECIAL_STRING_LENGTH {
             false
-        } else if !matches!(nan[0], b'N' | b'n') {
-            false
-        } else if !is_valid_letter_slice(nan) {
+        } else if !is_valid_special_slice(nan, b'N') {
             false
         } else {
             true
@@ -271,15 +641,43 @@ impl OptionsBuilder {
         let length = inf.len();
         if length == 0 || length > MAX_SPECIAL_STRING_LENGTH {
             false
-        } else if !matches!(inf[0], b'I' | b'i') {
-            false
-        } else if !is_valid_letter_slice(inf) {
+        } else if !is_valid_special_slice(inf, b'I') {
             false
         } else {
             true
         }
     }
 
+    /// Determine if `exponent_string` is valid.
+    ///
+    /// The string must not be empty, and must not start with a digit, a
+    /// sign, or the decimal point.
+    #[inline(always)]
+    pub const fn exponent_string_is_valid(&self) -> bool {
+        let exponent = match self.exponent_string {
+            Some(exponent) => exponent,
+            None => return true,
+        };
+        let length = exponent.len();
+        if length == 0 || length > MAX_SPECIAL_STRING_LENGTH {
+            return false;
+        }
+        let first = exponent[0];
+        !first.is_ascii_digit() && first != b'+' && first != b'-' && first != self.decimal_point
+    }
+
+    /// Determine if `imaginary_unit` is valid.
+    #[inline(always)]
+    pub const fn imaginary_unit_is_valid(&self) -> bool {
+        let unit = self.imaginary_unit;
+        unit.is_ascii_graphic()
+            && !unit.is_ascii_digit()
+            && unit != b'+'
+            && unit != b'-'
+            && unit != self.exponent
+            && unit != self.decimal_point
+    }
+
     /// Check if the builder state is valid.
     #[inline(always)]
     #[allow(clippy::if_same_then_else, clippy::needless_bool)] // reason="more logical"
@@ -292,6 +690,12 @@ impl OptionsBuilder {
             false
         } else if !self.inf_str_is_valid() {
             false
+        } else if !self.exponent_string_is_valid() {
+            false
+        } else if !is_valid_ascii(self.pad_char) {
+            false
+        } else if !self.imaginary_unit_is_valid() {
+            false
         } else {
             true
         }
@@ -312,12 +716,24 @@ impl OptionsBuilder {
             min_significant_digits: self.min_significant_digits,
             positive_exponent_break: self.positive_exponent_break,
             negative_exponent_break: self.negative_exponent_break,
-            round_mode: self.round_mode,
+            digit_rounding: self.digit_rounding,
             trim_floats: self.trim_floats,
+            algorithm: self.algorithm,
             exponent: self.exponent,
             decimal_point: self.decimal_point,
             nan_string: self.nan_string,
             inf_string: self.inf_string,
+            exponent_string: self.exponent_string,
+            min_exponent_digits: self.min_exponent_digits,
+            trim_scientific_floats: self.trim_scientific_floats,
+            positive_inf_sign: self.positive_inf_sign,
+            negative_zero: self.negative_zero,
+            flush_subnormals: self.flush_subnormals,
+            percent: self.percent,
+            min_width: self.min_width,
+            pad_char: self.pad_char,
+            alignment: self.alignment,
+            imaginary_unit: self.imaginary_unit,
         }
     }
 
@@ -327,9 +743,7 @@ impl OptionsBuilder {
     pub const fn build(&self) -> Result<Options> {
         if self.nan_string.is_some() {
             let nan = unwrap_str(self.nan_string);
-            if nan.is_empty() || !matches!(nan[0], b'N' | b'n') {
-                return Err(Error::InvalidNanString);
-            } else if !is_valid_letter_slice(nan) {
+            if !is_valid_special_slice(nan, b'N') {
                 return Err(Error::InvalidNanString);
             } else if nan.len() > MAX_SPECIAL_STRING_LENGTH {
                 return Err(Error::NanStringTooLong);
@@ -338,9 +752,7 @@ impl OptionsBuilder {
 
         if self.inf_string.is_some() {
             let inf = unwrap_str(self.inf_string);
-            if inf.is_empty() || !matches!(inf[0], b'I' | b'i') {
-                return Err(Error::InvalidInfString);
-            } else if !is_valid_letter_slice(inf) {
+            if !is_valid_special_slice(inf, b'I') {
                 return Err(Error::InvalidInfString);
             } else if inf.len() > MAX_SPECIAL_STRING_LENGTH {
                 return Err(Error::InfStringTooLong);
@@ -355,10 +767,14 @@ impl OptionsBuilder {
             Err(Error::InvalidNegativeExponentBreak)
         } else if unwrap_or_zero_i32(self.positive_exponent_break) < 0 {
             Err(Error::InvalidPositiveExponentBreak)
-        } else if !is_valid_ascii(self.exponent) {
+        } else if !is_valid_ascii(self.exponent) || !self.exponent_string_is_valid() {
             Err(Error::InvalidExponentSymbol)
         } else if !is_valid_ascii(self.decimal_point) {
             Err(Error::InvalidDecimalPoint)
+        } else if !is_valid_ascii(self.pad_char) {
+            Err(Error::InvalidPadCharacter)
+        } else if !self.imaginary_unit_is_valid() {
+            Err(Error::InvalidPunctuation)
         } else {
             Ok(self.build_unchecked())
         }
@@ -406,9 +822,11 @@ pub struct Options {
     /// radix. If not provided, use the algorithm's default.
     negative_exponent_break: OptionI32,
     /// Rounding mode for writing digits with precision control.
-    round_mode: RoundMode,
+    digit_rounding: DigitRounding,
     /// Trim the trailing ".0" from integral float strings.
     trim_floats: bool,
+    /// Algorithm used to generate the digits.
+    algorithm: Algorithm,
     /// Character to designate the exponent component of a float.
     exponent: u8,
     /// Character to separate the integer from the fraction components.
@@ -417,6 +835,30 @@ pub struct Options {
     nan_string: Option<&'static [u8]>,
     /// String representation of `Infinity`.
     inf_string: Option<&'static [u8]>,
+    /// String to designate the exponent component of a float, used
+    /// instead of the exponent character.
+    exponent_string: Option<&'static [u8]>,
+    /// Minimum number of digits to write in the exponent.
+    min_exponent_digits: OptionUsize,
+    /// Trim the trailing ".0" from integral floats in scientific notation.
+    trim_scientific_floats: bool,
+    /// Write a `+` sign before positive infinity.
+    positive_inf_sign: bool,
+    /// How to write negative zero.
+    negative_zero: NegativeZero,
+    /// Write subnormal floats as zero.
+    flush_subnormals: bool,
+    /// Write the float as a percentage, scaled by 100 with a trailing `%`.
+    percent: bool,
+    /// Minimum number of characters to write, including the sign.
+    /// If not set, no padding is written.
+    min_width: OptionUsize,
+    /// Character used to pad the output to the minimum width.
+    pad_char: u8,
+    /// Alignment of the float within the minimum width.
+    alignment: Alignment,
+    /// Character to designate the imaginary part of a complex number.
+    imaginary_unit: u8,
 }
 
 impl Options {
@@ -450,6 +892,126 @@ impl Options {
         self.rebuild().is_valid()
     }
 
+    /// Get an upper bound on the buffer size, usable in const contexts.
+    ///
+    /// This is the same as [`WriteOptions::buffer_size`], and can be used
+    /// to declare exactly-sized arrays for a given format and options.
+    ///
+    /// ```rust
+    /// use lexical_write_float::{format, Options, ToLexicalWithOptions};
+    ///
+    /// const OPTIONS: Options = Options::new();
+    /// const SIZE: usize = OPTIONS.buffer_size_const::<f64, { format::STANDARD }>();
+    /// let mut buffer = [0u8; SIZE];
+    /// let bytes = 1.5f64.to_lexical_with_options::<{ format::STANDARD }>(&mut buffer, &OPTIONS);
+    /// assert_eq!(bytes, b"1.5");
+    /// ```
+    #[inline(always)]
+    #[allow(clippy::extra_unused_type_parameters)] // reason = "match the signature of `WriteOptions::buffer_size`"
+    pub const fn buffer_size_const<T: FormattedSize, const FORMAT: u128>(&self) -> usize {
+        let format = NumberFormat::<{ FORMAT }> {};
+
+        // At least 2 for the decimal point and sign.
+        let mut count: usize = 2;
+
+        // First need to calculate maximum number of digits from leading or
+        // trailing zeros, IE, the exponent break.
+        if !format.no_exponent_notation() {
+            let min_exp = match self.negative_exponent_break {
+                Some(x) => x.get(),
+                None => -5,
+            };
+            let max_exp = match self.positive_exponent_break {
+                Some(x) => x.get(),
+                None => 9,
+            };
+            let min_exp = min_exp.abs();
+            let exp = if min_exp > max_exp {
+                min_exp
+            } else {
+                max_exp
+            } as usize;
+            if cfg!(feature = "power-of-two") && exp < 13 {
+                // 11 for the exponent digits in binary, 1 for the sign, 1 for the symbol
+                count += 13;
+            } else if exp < 5 {
+                // 3 for the exponent digits in decimal, 1 for the sign, 1 for the symbol
+                count += 5;
+            } else {
+                // More leading or trailing zeros than the exponent digits.
+                count += exp;
+            }
+        } else if cfg!(feature = "power-of-two") {
+            // Min is 2^-1075.
+            count += 1075;
+        } else {
+            // Min is 10^-324.
+            count += 324;
+        }
+
+        // Now add the number of significant digits.
+        let radix = format.radix();
+        let is_exact = radix == 10 && matches!(self.algorithm, Algorithm::Exact);
+        let formatted_digits = if is_exact {
+            // Every digit of the exact value may be written.
+            MAX_EXACT_DIGITS
+        } else if radix == 10 {
+            // Really should be 18, but add some extra to be cautious.
+            28
+        } else {
+            //  BINARY:
+            //      53 significant mantissa bits for binary, add a few extra.
+            //  RADIX:
+            //      Our limit is `delta`. The maximum relative delta is 2.22e-16,
+            //      around 1. If we have values below 1, our delta is smaller, but
+            //      the max fraction is also a lot smaller. Above, and our fraction
+            //      must be < 1.0, so our delta is less significant. Therefore,
+            //      if our fraction is just less than 1, for a float near 2.0,
+            //      we can do at **maximum** 33 digits (for base 3). Let's just
+            //      assume it's a lot higher, and go with 64.
+            64
+        };
+        let digits = match self.max_significant_digits {
+            Some(max_digits) if max_digits.get() < formatted_digits => max_digits.get(),
+            _ => formatted_digits,
+        };
+        let digits = match self.min_significant_digits {
+            Some(min_digits) if min_digits.get() > digits => min_digits.get(),
+            _ => digits,
+        };
+        count += digits;
+
+        // The exact digits may fill the digit count, so reserve space for
+        // the exponent digits, which are written with up to 10 bytes.
+        if is_exact {
+            count += 10;
+        }
+
+        // Negative values may be enclosed in parentheses.
+        count += format.parenthesized_negatives() as usize;
+
+        // Percentages have 2 more integer digits and the percent sign.
+        if self.percent {
+            count += 3;
+        }
+
+        // The exponent string replaces the exponent character.
+        if let Some(exponent) = self.exponent_string {
+            count += exponent.len() - 1;
+        }
+
+        // The exponent may be padded with leading zeros.
+        if let Some(min_digits) = self.min_exponent_digits {
+            count += min_digits.get();
+        }
+
+        // The float is padded in place to the minimum width.
+        match self.min_width {
+            Some(min_width) if min_width.get() > count => min_width.get(),
+            _ => count,
+        }
+    }
+
     /// Get the maximum number of significant digits to write.
     #[inline(always)]
     pub const fn max_significant_digits(&self) -> OptionUsize {
@@ -475,9 +1037,18 @@ impl Options {
     }
 
     /// Get the rounding mode for writing digits with precision control.
+    ///
+    /// This is [`RoundMode::Truncate`] if the digits are rounded toward
+    /// zero, and [`RoundMode::Round`] otherwise.
     #[inline(always)]
     pub const fn round_mode(&self) -> RoundMode {
-        self.round_mode
+        self.digit_rounding.to_round_mode()
+    }
+
+    /// Get the rounding mode for writing digits with precision control.
+    #[inline(always)]
+    pub const fn digit_rounding(&self) -> DigitRounding {
+        self.digit_rounding
     }
 
     /// Get if we should trim a trailing `".0"` from floats.
@@ -486,6 +1057,18 @@ impl Options {
         self.trim_floats
     }
 
+    /// Get if we should use the naive algorithm for non-power-of-two radixes.
+    #[inline(always)]
+    pub const fn lossy(&self) -> bool {
+        matches!(self.algorithm, Algorithm::Lossy)
+    }
+
+    /// Get the algorithm used to generate the digits.
+    #[inline(always)]
+    pub const fn algorithm(&self) -> Algorithm {
+        self.algorithm
+    }
+
     /// Get the character to designate the exponent component of a float.
     #[inline(always)]
     pub const fn exponent(&self) -> u8 {
@@ -510,6 +1093,74 @@ impl Options {
         self.inf_string
     }
 
+    /// Get the string to designate the exponent component of a float.
+    #[inline(always)]
+    pub const fn exponent_string(&self) -> Option<&'static [u8]> {
+        self.exponent_string
+    }
+
+    /// Get the minimum number of digits to write in the exponent.
+    #[inline(always)]
+    pub const fn min_exponent_digits(&self) -> OptionUsize {
+        self.min_exponent_digits
+    }
+
+    /// Get if we should trim a trailing `".0"` from floats in scientific
+    /// notation.
+    #[inline(always)]
+    pub const fn trim_scientific_floats(&self) -> bool {
+        self.trim_scientific_floats
+    }
+
+    /// Get if we should write a `+` sign before positive infinity.
+    #[inline(always)]
+    pub const fn positive_inf_sign(&self) -> bool {
+        self.positive_inf_sign
+    }
+
+    /// Get how to write negative zero.
+    #[inline(always)]
+    pub const fn negative_zero(&self) -> NegativeZero {
+        self.negative_zero
+    }
+
+    /// Get if we should write subnormal floats as zero.
+    #[inline(always)]
+    pub const fn flush_subnormals(&self) -> bool {
+        self.flush_subnormals
+    }
+
+    /// Get if we should write the float as a percentage.
+    #[inline(always)]
+    pub const fn percent(&self) -> bool {
+        self.percent
+    }
+
+    /// Get the minimum number of characters to write.
+    #[inline(always)]
+    pub const fn min_width(&self) -> OptionUsize {
+        self.min_width
+    }
+
+    /// Get the character used to pad the output to the minimum width.
+    #[inline(always)]
+    pub const fn pad_char(&self) -> u8 {
+        self.pad_char
+    }
+
+    /// Get the alignment of the float within the minimum width.
+    #[inline(always)]
+    pub const fn alignment(&self) -> Alignment {
+        self.alignment
+    }
+
+    /// Get the character to designate the imaginary part of a complex
+    /// number.
+    #[inline(always)]
+    pub const fn imaginary_unit(&self) -> u8 {
+        self.imaginary_unit
+    }
+
     // SETTERS
 
     /// Set the maximum number of significant digits to write.
@@ -537,9 +1188,18 @@ impl Options {
     }
 
     /// Set the rounding mode for writing digits with precision control.
+    ///
+    /// This is identical to [`Options::set_digit_rounding`] with the
+    /// equivalent [`DigitRounding`].
     #[inline(always)]
     pub fn set_round_mode(&mut self, round_mode: RoundMode) {
-        self.round_mode = round_mode;
+        self.digit_rounding = DigitRounding::from_round_mode(round_mode);
+    }
+
+    /// Set the rounding mode for writing digits with precision control.
+    #[inline(always)]
+    pub fn set_digit_rounding(&mut self, digit_rounding: DigitRounding) {
+        self.digit_rounding = digit_rounding;
     }
 
     /// Set if we should trim a trailing `".0"` from floats.
@@ -548,6 +1208,18 @@ impl Options {
         self.trim_floats = trim_floats;
     }
 
+    /// Set if we should use the naive algorithm for non-power-of-two radixes.
+    #[inline(always)]
+    pub fn set_lossy(&mut self, lossy: bool) {
+        self.algorithm = lossy_algorithm(self.algorithm, lossy);
+    }
+
+    /// Set the algorithm used to generate the digits.
+    #[inline(always)]
+    pub fn set_algorithm(&mut self, algorithm: Algorithm) {
+        self.algorithm = algorithm;
+    }
+
     /// Set the character to designate the exponent component of a float.
     ///
     /// # Safety
@@ -596,6 +1268,84 @@ impl Options {
         self.inf_string = inf_string;
     }
 
+    /// Set the string to designate the exponent component of a float.
+    #[inline(always)]
+    pub fn set_exponent_string(&mut self, exponent_string: Option<&'static [u8]>) {
+        self.exponent_string = exponent_string;
+    }
+
+    /// Set the minimum number of digits to write in the exponent.
+    #[inline(always)]
+    pub fn set_min_exponent_digits(&mut self, min_exponent_digits: OptionUsize) {
+        self.min_exponent_digits = min_exponent_digits;
+    }
+
+    /// Set if we should trim a trailing `".0"` from floats in scientific
+    /// notation.
+    #[inline(always)]
+    pub fn set_trim_scientific_floats(&mut self, trim_scientific_floats: bool) {
+        self.trim_scientific_floats = trim_scientific_floats;
+    }
+
+    /// Set if we should write a `+` sign before positive infinity.
+    #[inline(always)]
+    pub fn set_positive_inf_sign(&mut self, positive_inf_sign: bool) {
+        self.positive_inf_sign = positive_inf_sign;
+    }
+
+    /// Set how to write negative zero.
+    #[inline(always)]
+    pub fn set_negative_zero(&mut self, negative_zero: NegativeZero) {
+        self.negative_zero = negative_zero;
+    }
+
+    /// Set if we should write subnormal floats as zero.
+    #[inline(always)]
+    pub fn set_flush_subnormals(&mut self, flush_subnormals: bool) {
+        self.flush_subnormals = flush_subnormals;
+    }
+
+    /// Set if we should write the float as a percentage.
+    #[inline(always)]
+    pub fn set_percent(&mut self, percent: bool) {
+        self.percent = percent;
+    }
+
+    /// Set the minimum number of characters to write.
+    #[inline(always)]
+    pub fn set_min_width(&mut self, min_width: OptionUsize) {
+        self.min_width = min_width;
+    }
+
+    /// Set the character used to pad the output to the minimum width.
+    ///
+    /// # Safety
+    ///
+    /// Always safe, but may produce invalid output if the pad character
+    /// is not a valid ASCII character.
+    #[inline(always)]
+    pub fn set_pad_char(&mut self, pad_char: u8) {
+        self.pad_char = pad_char;
+    }
+
+    /// Set the alignment of the float within the minimum width.
+    #[inline(always)]
+    pub fn set_alignment(&mut self, alignment: Alignment) {
+        self.alignment = alignment;
+    }
+
+    /// Set the character to designate the imaginary part of a complex
+    /// number.
+    ///
+    /// # Safety
+    ///
+    /// Always safe, but may produce output that cannot be parsed if the
+    /// imaginary unit is not a valid ASCII character.
+    #[inline(always)]
+    pub fn set_imaginary_unit(&mut self, imaginary_unit: u8) {
+        self.imaginary_unit = imaginary_unit;
+    }
+
     // BUILDERS
 
     /// Get `WriteFloatOptionsBuilder` as a static function.
@@ -612,12 +1362,24 @@ impl Options {
             min_significant_digits: self.min_significant_digits,
             positive_exponent_break: self.positive_exponent_break,
             negative_exponent_break: self.negative_exponent_break,
-            round_mode: self.round_mode,
+            digit_rounding: self.digit_rounding,
             trim_floats: self.trim_floats,
+            algorithm: self.algorithm,
             exponent: self.exponent,
             decimal_point: self.decimal_point,
             nan_string: self.nan_string,
             inf_string: self.inf_string,
+            exponent_string: self.exponent_string,
+            min_exponent_digits: self.min_exponent_digits,
+            trim_scientific_floats: self.trim_scientific_floats,
+            positive_inf_sign: self.positive_inf_sign,
+            negative_zero: self.negative_zero,
+            flush_subnormals: self.flush_subnormals,
+            percent: self.percent,
+            min_width: self.min_width,
+            pad_char: self.pad_char,
+            alignment: self.alignment,
+            imaginary_unit: self.imaginary_unit,
         }
     }
 }
@@ -637,66 +1399,7 @@ impl WriteOptions for Options {
 
     #[inline(always)]
     fn buffer_size<T: FormattedSize, const FORMAT: u128>(&self) -> usize {
-        let format = NumberFormat::<{ FORMAT }> {};
-
-        // At least 2 for the decimal point and sign.
-        let mut count: usize = 2;
-
-        // First need to calculate maximum number of digits from leading or
-        // trailing zeros, IE, the exponent break.
-        if !format.no_exponent_notation() {
-            let min_exp = self.negative_exponent_break().map_or(-5, |x| x.get());
-            let max_exp = self.positive_exponent_break().map_or(9, |x| x.get());
-            let exp = min_exp.abs().max(max_exp) as usize;
-            if cfg!(feature = "power-of-two") && exp < 13 {
-                // 11 for the exponent digits in binary, 1 for the sign, 1 for the symbol
-                count += 13;
-            } else if exp < 5 {
-                // 3 for the exponent digits in decimal, 1 for the sign, 1 for the symbol
-                count += 5;
-            } else {
-                // More leading or trailing zeros than the exponent digits.
-                count += exp;
-            }
-        } else if cfg!(feature = "power-of-two") {
-            // Min is 2^-1075.
-            count += 1075;
-        } else {
-            // Min is 10^-324.
-            count += 324;
-        }
-
-        // Now add the number of significant digits.
-        let radix = format.radix();
-        let formatted_digits = if radix == 10 {
-            // Really should be 18, but add some extra to be cautious.
-            28
-        } else {
-            //  BINARY:
-            //      53 significant mantissa bits for binary, add a few extra.
-            //  RADIX:
-            //      Our limit is `delta`. The maximum relative delta is 2.22e-16,
-            //      around 1. If we have values below 1, our delta is smaller, but
-            //      the max fraction is also a lot smaller. Above, and our fraction
-            //      must be < 1.0, so our delta is less significant. Therefore,
-            //      if our fraction is just less than 1, for a float near 2.0,
-            //      we can do at **maximum** 33 digits (for base 3). Let's just
-            //      assume it's a lot higher, and go with 64.
-            64
-        };
-        let digits = if let Some(max_digits) = self.max_significant_digits() {
-            formatted_digits.min(max_digits.get())
-        } else {
-            formatted_digits
-        };
-        let digits = if let Some(min_digits) = self.min_significant_digits() {
-            digits.max(min_digits.get())
-        } else {
-            formatted_digits
-        };
-        count += digits;
-
-        count
+        self.buffer_size_const::<T, FORMAT>()
     }
 }
 
@@ -726,6 +1429,16 @@ const fn unwrap_or_max_usize(option: OptionUsize) -> usize {
     }
 }
 
+/// Get the algorithm after enabling or disabling the lossy algorithm.
+#[inline(always)]
+const fn lossy_algorithm(algorithm: Algorithm, lossy: bool) -> Algorithm {
+    match (algorithm, lossy) {
+        (_, true) => Algorithm::Lossy,
+        (Algorithm::Lossy, false) => Algorithm::Shortest,
+        (algorithm, false) => algorithm,
+    }
+}
+
 /// Unwrap `Option` as a const fn.
 #[inline(always)]
 const fn unwrap_str(option: Option<&'static [u8]>) -> &'static [u8] {
@@ -768,6 +1481,14 @@ pub const CARAT_EXPONENT: Options = Options::builder()
         .build_unchecked();
 const_assert!(CARAT_EXPONENT.is_valid());
 
+/// Numerical format which writes scientific notation as a multiplication
+/// by a power of 10, like `1.23×10^4`.
+#[rustfmt::skip]
+pub const TIMES_TEN_EXPONENT: Options = Options::builder()
+        .exponent_string(Some("×10^".as_bytes()))
+        .build_unchecked();
+const_assert!(TIMES_TEN_EXPONENT.is_valid());
+
 /// Number format for a `Rust` literal floating-point number.
 #[rustfmt::skip]
 pub const RUST_LITERAL: Options = Options::builder()
@@ -776,6 +1497,40 @@ pub const RUST_LITERAL: Options = Options::builder()
         .build_unchecked();
 const_assert!(RUST_LITERAL.is_valid());
 
+/// Number format to write a `Rust` float like `Display`.
+///
+/// This writes the shortest representation without scientific notation,
+/// like `1`, `-0`, and `0.00001`, and breaks ties between two shortest
+/// representations away from zero, so the output is identical to `{}`.
+/// Use it with the `STANDARD` number format, and a buffer of
+/// `buffer_size_const` bytes, since every leading and trailing zero is
+/// written. This requires the default algorithm, without the `compact`
+/// feature.
+#[rustfmt::skip]
+pub const RUST_DISPLAY: Options = Options::builder()
+        .positive_exponent_break(num::NonZeroI32::new(308))
+        .negative_exponent_break(num::NonZeroI32::new(-324))
+        .digit_rounding(DigitRounding::HalfUp)
+        .trim_floats(true)
+        .build_unchecked();
+const_assert!(RUST_DISPLAY.is_valid());
+
+/// Number format to write a `Rust` float like `Debug`.
+///
+/// This writes the shortest representation, with scientific notation
+/// below `1e-4` or from `1e16`, like `1e-5` and `1.5e16`, and breaks ties
+/// between two shortest representations away from zero, so the output is
+/// identical to `{:?}`. Use it with the `STANDARD` number format. This
+/// requires the default algorithm, without the `compact` feature.
+#[rustfmt::skip]
+pub const RUST_DEBUG: Options = Options::builder()
+        .positive_exponent_break(num::NonZeroI32::new(15))
+        .negative_exponent_break(num::NonZeroI32::new(-4))
+        .trim_scientific_floats(true)
+        .digit_rounding(DigitRounding::HalfUp)
+        .build_unchecked();
+const_assert!(RUST_DEBUG.is_valid());
+
 /// Number format for a `Python` literal floating-point number.
 #[rustfmt::skip]
 pub const PYTHON_LITERAL: Options = Options::builder()
@@ -784,6 +1539,22 @@ pub const PYTHON_LITERAL: Options = Options::builder()
         .build_unchecked();
 const_assert!(PYTHON_LITERAL.is_valid());
 
+/// Number format to write a `Python` float like `repr`.
+///
+/// This writes the shortest representation, with scientific notation
+/// below `1e-4` or from `1e16`, like `1e-05` and `1.5e+16`. Use it with
+/// the `PYTHON_REPR` number format, which writes the exponent sign.
+#[rustfmt::skip]
+pub const PYTHON_REPR: Options = Options::builder()
+        .positive_exponent_break(num::NonZeroI32::new(15))
+        .negative_exponent_break(num::NonZeroI32::new(-4))
+        .min_exponent_digits(num::NonZeroUsize::new(2))
+        .trim_scientific_floats(true)
+        .nan_string(options::PYTHON_REPR_NAN)
+        .inf_string(options::PYTHON_REPR_INF)
+        .build_unchecked();
+const_assert!(PYTHON_REPR.is_valid());
+
 /// Number format for a `C++` literal floating-point number.
 #[rustfmt::skip]
 pub const CXX_LITERAL: Options = Options::builder()
@@ -834,6 +1605,23 @@ pub const GO_LITERAL: Options = Options::builder()
         .build_unchecked();
 const_assert!(GO_LITERAL.is_valid());
 
+/// Number format to write a `Go` float like `strconv.FormatFloat(f, 'g', -1, 64)`.
+///
+/// This writes the shortest representation, with scientific notation
+/// below `1e-4` or from `1e6`, like `1e-05` and `1.5e+06`, and without
+/// a trailing `".0"`. Use it with the `GO_FORMAT_FLOAT` number format,
+/// which writes the exponent sign.
+#[rustfmt::skip]
+pub const GO_FORMAT_FLOAT: Options = Options::builder()
+        .positive_exponent_break(num::NonZeroI32::new(5))
+        .negative_exponent_break(num::NonZeroI32::new(-4))
+        .min_exponent_digits(num::NonZeroUsize::new(2))
+        .trim_floats(true)
+        .inf_string(options::GO_FORMAT_FLOAT_INF)
+        .positive_inf_sign(true)
+        .build_unchecked();
+const_assert!(GO_FORMAT_FLOAT.is_valid());
+
 /// Number format for a `Haskell` literal floating-point number.
 #[rustfmt::skip]
 pub const HASKELL_LITERAL: Options = Options::builder()
@@ -863,6 +1651,27 @@ pub const JAVASCRIPT_STRING: Options = Options::builder()
         .build_unchecked();
 const_assert!(JAVASCRIPT_STRING.is_valid());
 
+/// Number format to write a `Javascript` float like `Number.prototype.toString`.
+///
+/// This writes the shortest representation, with scientific notation
+/// below `1e-6` or from `1e21`, like `1e-7` and `1.5e+21`, without a
+/// trailing `".0"`, and writes negative zero as `0`. Use it with the
+/// `JAVASCRIPT_TO_STRING` number format.
+///
+/// For `toString(radix)`, use the number format with the radix and without
+/// exponent notation. Radixes that are not powers of two use the lossy
+/// algorithm, which writes the same digits as V8.
+#[rustfmt::skip]
+pub const JAVASCRIPT_TO_STRING: Options = Options::builder()
+        .positive_exponent_break(num::NonZeroI32::new(20))
+        .negative_exponent_break(num::NonZeroI32::new(-6))
+        .trim_floats(true)
+        .lossy(true)
+        .negative_zero(NegativeZero::Unsigned)
+        .inf_string(options::JAVASCRIPT_INF)
+        .build_unchecked();
+const_assert!(JAVASCRIPT_TO_STRING.is_valid());
+
 /// Number format for a `Perl` literal floating-point number.
 #[rustfmt::skip]
 pub const PERL_LITERAL: Options = Options::builder()
@@ -894,6 +1703,24 @@ pub const JAVA_STRING: Options = Options::builder()
         .build_unchecked();
 const_assert!(JAVA_STRING.is_valid());
 
+/// Number format to write a `Java` float like `Double.toString`.
+///
+/// This writes the shortest representation, like Java 19 and later, with
+/// scientific notation below `1e-3` or from `1e7`, like `1.0E-4` and
+/// `1.5E7`. Use it with the `JAVA_STRING` number format.
+///
+/// Java always writes at least 2 significant digits, picking the closest, so
+/// the few subnormal floats where 2 digits are not precise enough differ,
+/// like `4.9E-324` from Java, and `5.0E-324` from lexical.
+#[rustfmt::skip]
+pub const JAVA_TO_STRING: Options = Options::builder()
+        .positive_exponent_break(num::NonZeroI32::new(6))
+        .negative_exponent_break(num::NonZeroI32::new(-3))
+        .exponent(b'E')
+        .inf_string(options::JAVA_STRING_INF)
+        .build_unchecked();
+const_assert!(JAVA_TO_STRING.is_valid());
+
 /// Number format for an `R` literal floating-point number.
 #[rustfmt::skip]
 pub const R_LITERAL: Options = Options::builder()
@@ -938,6 +1765,23 @@ pub const CSHARP_STRING: Options = Options::builder()
         .build_unchecked();
 const_assert!(CSHARP_STRING.is_valid());
 
+/// Number format to write a `C#` float like `double.ToString("R")`.
+///
+/// This writes the shortest representation, like .NET Core 3.0 and later,
+/// with scientific notation below `1e-4` or from `1e15`, like `1E-05` and
+/// `1.5E+15`, and without a trailing `".0"`. Use it with the
+/// `CSHARP_ROUND_TRIP` number format, which writes the exponent sign.
+#[rustfmt::skip]
+pub const CSHARP_ROUND_TRIP: Options = Options::builder()
+        .positive_exponent_break(num::NonZeroI32::new(14))
+        .negative_exponent_break(num::NonZeroI32::new(-4))
+        .exponent(b'E')
+        .min_exponent_digits(num::NonZeroUsize::new(2))
+        .trim_floats(true)
+        .inf_string(options::CSHARP_STRING_INF)
+        .build_unchecked();
+const_assert!(CSHARP_ROUND_TRIP.is_valid());
+
 /// Number format for a `Kawa` literal floating-point number.
 #[rustfmt::skip]
 pub const KAWA_LITERAL: Options = Options::builder()
@@ -1197,14 +2041,25 @@ const_assert!(JSON.is_valid());
 /// Number format for a `TOML` literal floating-point number.
 #[rustfmt::skip]
 pub const TOML: Options = Options::builder()
-        .nan_string(options::TOML)
-        .inf_string(options::TOML)
+        .nan_string(options::TOML_NAN)
+        .inf_string(options::TOML_INF)
         .build_unchecked();
 const_assert!(TOML.is_valid());
 
 /// Number format for a `YAML` literal floating-point number.
 #[rustfmt::skip]
-pub const YAML: Options = JSON;
+pub const YAML: Options = Options::builder()
+        .nan_string(options::YAML_NAN)
+        .inf_string(options::YAML_INF)
+        .build_unchecked();
+const_assert!(YAML.is_valid());
+
+/// Number format for a `JSON5` literal floating-point number.
+#[rustfmt::skip]
+pub const JSON5: Options = Options::builder()
+        .inf_string(options::JSON5_INF)
+        .build_unchecked();
+const_assert!(JSON5.is_valid());
 
 /// Number format for an `XML` literal floating-point number.
 #[rustfmt::skip]
@@ -1243,3 +2098,74 @@ pub const MONGODB: Options = Options::builder()
         .inf_string(options::MONGODB_INF)
         .build_unchecked();
 const_assert!(MONGODB.is_valid());
+
+#[cfg(feature = "proptest")]
+impl proptest::arbitrary::Arbitrary for RoundMode {
+    type Parameters = ();
+    type Strategy = proptest::sample::Select<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        proptest::sample::select(vec![RoundMode::Round, RoundMode::Truncate])
+    }
+}
+
+#[cfg(feature = "proptest")]
+impl proptest::arbitrary::Arbitrary for DigitRounding {
+    type Parameters = ();
+    type Strategy = proptest::sample::Select<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        proptest::sample::select(vec![
+            DigitRounding::HalfEven,
+            DigitRounding::HalfUp,
+            DigitRounding::HalfDown,
+            DigitRounding::TowardZero,
+            DigitRounding::AwayFromZero,
+        ])
+    }
+}
+
+#[cfg(feature = "proptest")]
+impl proptest::arbitrary::Arbitrary for Options {
+    type Parameters = ();
+    type Strategy = proptest::strategy::BoxedStrategy<Self>;
+
+    /// Generate valid options, with random precision control, exponent
+    /// breaks, punctuation, and special float strings.
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        use proptest::option::of;
+        use proptest::prelude::*;
+        use proptest::sample::select;
+
+        let precision = (of(1usize..=40), of(1usize..=40), any::<DigitRounding>(), any::<bool>());
+        let exponent_break = (of(1i32..=50), of(-50i32..=-1));
+        let punctuation = (
+            select(vec![b'e', b'^']),
+            select(vec![b'.', b',']),
+            select(vec![Some(b"NaN" as &'static [u8]), Some(b"nan"), None]),
+            select(vec![Some(b"inf" as &'static [u8]), Some(b"Infinity"), None]),
+            select(vec![None, Some("×10^".as_bytes()), Some("·10^".as_bytes())]),
+        );
+        (precision, exponent_break, punctuation)
+            .prop_map(|(precision, exponent_break, punctuation)| {
+                let (max_digits, min_digits, digit_rounding, trim_floats) = precision;
+                let (positive_break, negative_break) = exponent_break;
+                let (exponent, decimal_point, nan, inf, exponent_string) = punctuation;
+                Self::builder()
+                    .max_significant_digits(max_digits.and_then(num::NonZeroUsize::new))
+                    .min_significant_digits(min_digits.and_then(num::NonZeroUsize::new))
+                    .digit_rounding(digit_rounding)
+                    .trim_floats(trim_floats)
+                    .positive_exponent_break(positive_break.and_then(num::NonZeroI32::new))
+                    .negative_exponent_break(negative_break.and_then(num::NonZeroI32::new))
+                    .exponent(exponent)
+                    .decimal_point(decimal_point)
+                    .nan_string(nan)
+                    .inf_string(inf)
+                    .exponent_string(exponent_string)
+            })
+            .prop_filter("options must be valid", OptionsBuilder::is_valid)
+            .prop_map(|builder| builder.build_unchecked())
+            .boxed()
+    }
+}
@@ -0,0 +1,1150 @@
+//! Printf-style fixed and exponential formatting with exact rounding.
+//!
+//! Unlike the shortest representation, which writes the fewest digits that
+//! round-trip, these write a requested number of digits of the exact value
+//! of the float, like the `%.*f` and `%.*e` conversions of `printf`. Every
+//! finite float is a dyadic rational, `m * 2^e`, so its decimal expansion is
+//! finite, and all digits can be generated exactly.
+//!
+//! Like the `d2fixed` and `d2exp` algorithms of Ryū printf, digits are
+//! generated in blocks of 9, which fit in a 32-bit integer. The integer
+//! digits are extracted by dividing by `10^9`, and the fraction digits by
+//! multiplying the fraction bits by `10^9` and taking the carry out of the
+//! binary point. Rather than using large pre-computed tables, the bits are
+//! stored in a small big integer, so the precision is unbounded, and digits
+//! past the exact expansion are zeros without any further arithmetic.
+//!
+//! Digits are rounded to the nearest, tie-even, or using another
+//! [`DigitRounding`], using the exact value, so there is no double rounding.
+
+#![doc(hidden)]
+
+use core::cmp::Ordering;
+
+use lexical_util::algorithm::rtrim_char_count;
+use lexical_util::error::Error;
+use lexical_util::format::NumberFormat;
+use lexical_util::num::Float;
+use lexical_util::result::Result;
+
+use crate::options::{DigitRounding, Options};
+use crate::shared::{
+    write_float_negative_exponent,
+    write_float_positive_exponent,
+    write_float_scientific,
+};
+
+/// Number of decimal digits in each block.
+const BLOCK_DIGITS: usize = 9;
+
+/// `10^BLOCK_DIGITS`, which fits in a 32-bit limb.
+const BLOCK_POWER: u64 = 1_000_000_000;
+
+/// Number of 32-bit limbs to store the integer or fraction bits of an `f64`.
+///
+/// The integer is at most 1024 bits, and the fraction at most 1074.
+const LIMBS: usize = 34;
+
+/// Maximum number of integer digits of an `f64`, rounded up to a block.
+const INTEGER_SIZE: usize = 315;
+
+/// Maximum number of integer digits of an `f64`.
+const MAX_INTEGER_DIGITS: usize = 309;
+
+/// Number of bytes for the exponent, as `e+308`.
+const EXPONENT_SIZE: usize = 5;
+
+/// Maximum number of significant digits in the exact value of an `f64`.
+///
+/// Any further digits are zeros.
+pub const MAX_EXACT_DIGITS: usize = 767;
+
+/// Get the size of a buffer required to write any float with
+/// [`write_fixed`].
+///
+/// This includes the sign, every integer digit of the largest float,
+/// the decimal point, and `precision` fraction digits.
+#[must_use]
+#[inline(always)]
+pub const fn fixed_buffer_size(precision: usize) -> usize {
+    1 + MAX_INTEGER_DIGITS + 1 + precision
+}
+
+/// Get the size of a buffer required to write any float with
+/// [`write_exponential`].
+///
+/// This includes the sign, the leading digit, the decimal point,
+/// `precision` fraction digits, and the exponent.
+#[must_use]
+#[inline(always)]
+pub const fn exponential_buffer_size(precision: usize) -> usize {
+    1 + 1 + 1 + precision + EXPONENT_SIZE
+}
+
+/// Write a float with a fixed number of digits after the decimal point.
+///
+/// This is like the `%.*f` conversion of `printf`: the digits are exact,
+/// and rounded to the nearest, tie-even, at the last digit. If the
+/// precision is 0, no decimal point is written. Negative zero is written
+/// with a sign, and special values are written as `NaN`, `inf`, and
+/// `-inf`. The float is widened to an `f64`, which is exact.
+///
+/// Returns the subslice of the buffer containing the written bytes.
+///
+/// * `float`       - Number to serialize.
+/// * `precision`   - Number of digits after the decimal point.
+/// * `bytes`       - Buffer to write the number to.
+///
+/// # Panics
+///
+/// Panics if the buffer is not large enough to hold the number. A buffer of
+/// [`fixed_buffer_size`] bytes is always large enough.
+///
+/// # Examples
+///
+/// ```rust
+/// use lexical_write_float::{fixed_buffer_size, write_fixed};
+///
+/// let mut buffer = [0u8; 64];
+/// assert_eq!(write_fixed(1.5f64, 3, &mut buffer), b"1.500");
+/// assert_eq!(write_fixed(0.1f64, 20, &mut buffer), b"0.10000000000000000555");
+/// assert_eq!(write_fixed(2.5f64, 0, &mut buffer), b"2");
+/// assert_eq!(write_fixed(-9.995f32, 2, &mut buffer), b"-9.99");
+/// assert_eq!(write_fixed(9.995f64, 2, &mut buffer), b"9.99");
+/// assert_eq!(write_fixed(999.9f64, 0, &mut buffer), b"1000");
+///
+/// // Every integer digit is written.
+/// let mut buffer = vec![0u8; fixed_buffer_size(0)];
+/// assert_eq!(write_fixed(1e23f64, 0, &mut buffer), b"99999999999999991611392");
+/// ```
+#[inline]
+pub fn write_fixed<F: Float>(float: F, precision: usize, bytes: &mut [u8]) -> &mut [u8] {
+    write_fixed_with_rounding(float, precision, DigitRounding::HalfEven, bytes)
+}
+
+/// Write a float with a fixed number of digits after the decimal point,
+/// rounded at the last digit with the rounding mode.
+///
+/// This is identical to [`write_fixed`], other than the rounding of the
+/// exact digits, which is by magnitude, so [`DigitRounding::HalfUp`] rounds
+/// `-2.5` to `-3`.
+///
+/// # Panics
+///
+/// Panics if the buffer is not large enough to hold the number. A buffer of
+/// [`fixed_buffer_size`] bytes is always large enough.
+///
+/// # Examples
+///
+/// ```rust
+/// use lexical_write_float::{write_fixed_with_rounding, DigitRounding};
+///
+/// let mut buffer = [0u8; 64];
+/// let mut write = |x: f64, mode| write_fixed_with_rounding(x, 0, mode, &mut buffer).to_vec();
+/// assert_eq!(write(2.5, DigitRounding::HalfEven), b"2");
+/// assert_eq!(write(2.5, DigitRounding::HalfUp), b"3");
+/// assert_eq!(write(-2.5, DigitRounding::HalfUp), b"-3");
+/// assert_eq!(write(3.5, DigitRounding::HalfDown), b"3");
+/// assert_eq!(write(2.9, DigitRounding::TowardZero), b"2");
+/// assert_eq!(write(2.1, DigitRounding::AwayFromZero), b"3");
+/// ```
+#[inline]
+pub fn write_fixed_with_rounding<F: Float>(
+    float: F,
+    precision: usize,
+    rounding: DigitRounding,
+    bytes: &mut [u8],
+) -> &mut [u8] {
+    let float = float.as_f64();
+    let count = if let Some(count) = write_special(float, bytes) {
+        count
+    } else if float.is_sign_negative() {
+        bytes[0] = b'-';
+        1 + write_fixed_positive(-float, precision, rounding, &mut bytes[1..])
+    } else {
+        write_fixed_positive(float, precision, rounding, bytes)
+    };
+    &mut bytes[..count]
+}
+
+/// Write a float in scientific notation with a fixed number of digits after
+/// the decimal point.
+///
+/// This is like the `%.*e` conversion of `printf`: the digits are exact,
+/// and rounded to the nearest, tie-even, at the last digit. The exponent
+/// is written with a sign and at least 2 digits, as `e+05`. If the
+/// precision is 0, no decimal point is written. Negative zero is written
+/// with a sign, and special values are written as `NaN`, `inf`, and
+/// `-inf`. The float is widened to an `f64`, which is exact.
+///
+/// Returns the subslice of the buffer containing the written bytes.
+///
+/// * `float`       - Number to serialize.
+/// * `precision`   - Number of digits after the decimal point.
+/// * `bytes`       - Buffer to write the number to.
+///
+/// # Panics
+///
+/// Panics if the buffer is not large enough to hold the number. A buffer of
+/// [`exponential_buffer_size`] bytes is always large enough.
+///
+/// # Examples
+///
+/// ```rust
+/// use lexical_write_float::write_exponential;
+///
+/// let mut buffer = [0u8; 64];
+/// assert_eq!(write_exponential(1234.5f64, 3, &mut buffer), b"1.234e+03");
+/// assert_eq!(write_exponential(1234.5f64, 0, &mut buffer), b"1e+03");
+/// assert_eq!(write_exponential(0.1f64, 20, &mut buffer), b"1.00000000000000005551e-01");
+/// assert_eq!(write_exponential(-0.0f64, 2, &mut buffer), b"-0.00e+00");
+/// assert_eq!(write_exponential(9.9999e99f64, 2, &mut buffer), b"1.00e+100");
+/// assert_eq!(write_exponential(5e-324f64, 1, &mut buffer), b"4.9e-324");
+/// ```
+#[inline]
+pub fn write_exponential<F: Float>(float: F, precision: usize, bytes: &mut [u8]) -> &mut [u8] {
+    write_exponential_with_rounding(float, precision, DigitRounding::HalfEven, bytes)
+}
+
+/// Write a float in scientific notation with a fixed number of digits after
+/// the decimal point, rounded at the last digit with the rounding mode.
+///
+/// This is identical to [`write_exponential`], other than the rounding of
+/// the exact digits, which is by magnitude, so [`DigitRounding::HalfUp`] rounds
+/// `-1.25e+00` to `-1.3e+00`.
+///
+/// # Panics
+///
+/// Panics if the buffer is not large enough to hold the number. A buffer of
+/// [`exponential_buffer_size`] bytes is always large enough.
+///
+/// # Examples
+///
+/// ```rust
+/// use lexical_write_float::{write_exponential_with_rounding, DigitRounding};
+///
+/// let mut buffer = [0u8; 64];
+/// let mut write =
+///     |x: f64, mode| write_exponential_with_rounding(x, 1, mode, &mut buffer).to_vec();
+/// assert_eq!(write(1.25, DigitRounding::HalfEven), b"1.2e+00");
+/// assert_eq!(write(-1.25, DigitRounding::HalfUp), b"-1.3e+00");
+/// assert_eq!(write(1.35, DigitRounding::TowardZero), b"1.3e+00");
+/// assert_eq!(write(9.91, DigitRounding::AwayFromZero), b"1.0e+01");
+/// ```
+#[inline]
+pub fn write_exponential_with_rounding<F: Float>(
+    float: F,
+    precision: usize,
+    rounding: DigitRounding,
+    bytes: &mut [u8],
+) -> &mut [u8] {
+    let float = float.as_f64();
+    let count = if let Some(count) = write_special(float, bytes) {
+        count
+    } else if float.is_sign_negative() {
+        bytes[0] = b'-';
+        1 + write_exponential_positive(-float, precision, rounding, &mut bytes[1..])
+    } else {
+        write_exponential_positive(float, precision, rounding, bytes)
+    };
+    &mut bytes[..count]
+}
+
+/// Write a float with a `printf` conversion specification.
+///
+/// This parses the specification, like `%-+12.4e`, with
+/// [`PrintfSpec::parse`], and writes the float like `snprintf` would. The
+/// digits of `%f`, `%e`, and `%g` are exact, and rounded to the nearest,
+/// tie-even.
+///
+/// Returns the subslice of the buffer containing the written bytes.
+///
+/// * `spec`        - Conversion specification, starting with `%`.
+/// * `float`       - Number to serialize.
+/// * `bytes`       - Buffer to write the number to.
+///
+/// # Errors
+///
+/// Returns [`Error::InvalidPrintfSpec`] if the specification is not a
+/// single, supported conversion.
+///
+/// # Panics
+///
+/// Panics if the buffer is not large enough to hold the number. A buffer of
+/// [`PrintfSpec::buffer_size`] bytes is always large enough.
+///
+/// # Examples
+///
+/// ```rust
+/// use lexical_write_float::write_printf;
+///
+/// let mut buffer = [0u8; 64];
+/// assert_eq!(write_printf(b"%.3f", 1.5f64, &mut buffer).unwrap(), b"1.500");
+/// assert_eq!(write_printf(b"%+010.2e", 1234.5f64, &mut buffer).unwrap(), b"+01.23e+03");
+/// assert!(write_printf(b"%-8g|", 0.0001f64, &mut buffer).is_err());
+/// assert_eq!(write_printf(b"%-8g", 0.0001f64, &mut buffer).unwrap(), b"0.0001  ");
+/// assert_eq!(write_printf(b"%G", 1e-5f64, &mut buffer).unwrap(), b"1E-05");
+/// assert_eq!(write_printf(b"%a", 1.0f64, &mut buffer).unwrap(), b"0x1p+0");
+/// assert_eq!(write_printf(b"%5.1F", f64::INFINITY, &mut buffer).unwrap(), b"  INF");
+/// ```
+#[inline]
+pub fn write_printf<'a, F: Float>(
+    spec: &[u8],
+    float: F,
+    bytes: &'a mut [u8],
+) -> Result<&'a mut [u8]> {
+    Ok(PrintfSpec::parse(spec)?.write(float, bytes))
+}
+
+/// Write a float with the exact digits, rounded to the maximum number of
+/// significant digits in the options.
+///
+/// This is the decimal backend for [`Algorithm::Exact`], and assumes the
+/// float is finite and non-negative, and the maximum number of significant
+/// digits is set.
+///
+/// [`Algorithm::Exact`]: crate::options::Algorithm::Exact
+pub fn write_float<F: Float, const FORMAT: u128>(
+    float: F,
+    bytes: &mut [u8],
+    options: &Options,
+) -> usize {
+    let format = NumberFormat::<{ FORMAT }> {};
+    assert!(format.is_valid());
+    debug_assert!(!float.is_special());
+    debug_assert!(float >= F::ZERO);
+
+    let max_digits = options.max_significant_digits().map_or(MAX_EXACT_DIGITS, |x| x.get());
+    let mut digits = [b'0'; MAX_EXACT_DIGITS];
+    let (digit_count, sci_exp) = if float == F::ZERO {
+        (1, 0)
+    } else {
+        let (digit_count, sci_exp) =
+            exact_digits(float.as_f64(), &mut digits[..max_digits.min(MAX_EXACT_DIGITS)], options);
+        // Scale percentages exactly by shifting the decimal exponent.
+        (digit_count, sci_exp + 2 * options.percent() as i32)
+    };
+
+    write_float!(
+        float,
+        FORMAT,
+        sci_exp,
+        options,
+        write_float_scientific,
+        write_float_positive_exponent,
+        write_float_negative_exponent,
+        bytes => bytes,
+        args => &mut digits, digit_count, sci_exp, options,
+    )
+}
+
+/// Write the significant digits of a finite, positive float, rounded at the
+/// end of the buffer.
+///
+/// Returns the number of digits without trailing zeros, and the scientific
+/// exponent.
+fn exact_digits(float: f64, digits: &mut [u8], options: &Options) -> (usize, i32) {
+    let mut generator = Digits::new(float);
+    let mut sci_exp = if generator.integer_count() != 0 {
+        generator.integer_count() as i32 - 1
+    } else {
+        -(generator.skip_zeros() as i32) - 1
+    };
+    generator.write(digits);
+
+    // Round the digits, which may carry to the next power of 10.
+    let is_odd = (digits[digits.len() - 1] - b'0') % 2 == 1;
+    if generator.is_round_up(is_odd, options.digit_rounding()) && round_up(digits) {
+        digits[0] = b'1';
+        sci_exp += 1;
+    }
+
+    let digit_count = digits.len() - rtrim_char_count(digits, b'0');
+    (digit_count, sci_exp)
+}
+
+/// Write a special value, returning the number of bytes written.
+#[inline(always)]
+fn write_special(float: f64, bytes: &mut [u8]) -> Option<usize> {
+    let special: &[u8] = if float.is_nan() {
+        b"NaN"
+    } else if float == f64::INFINITY {
+        b"inf"
+    } else if float == f64::NEG_INFINITY {
+        b"-inf"
+    } else {
+        return None;
+    };
+    bytes[..special.len()].copy_from_slice(special);
+    Some(special.len())
+}
+
+/// Write a finite, positive float with fixed notation.
+fn write_fixed_positive(
+    float: f64,
+    precision: usize,
+    rounding: DigitRounding,
+    bytes: &mut [u8],
+) -> usize {
+    let mut digits = Digits::new(float);
+    let integer_count = digits.integer_count().max(1);
+    let mut count = integer_count + (precision != 0) as usize + precision;
+    assert!(bytes.len() >= count);
+
+    // Write the integer digits, or a 0 if the float is less than 1.
+    if digits.integer_count() == 0 {
+        bytes[0] = b'0';
+    } else {
+        digits.write(&mut bytes[..integer_count]);
+    }
+    if precision != 0 {
+        bytes[integer_count] = b'.';
+        digits.write(&mut bytes[integer_count + 1..count]);
+    }
+
+    // Round the digits, which may carry to a new integer digit.
+    let is_odd = (bytes[count - 1] - b'0') % 2 == 1;
+    if digits.is_round_up(is_odd, rounding) && round_up(&mut bytes[..count]) {
+        assert!(bytes.len() > count);
+        bytes.copy_within(0..count, 1);
+        bytes[0] = b'1';
+        count += 1;
+    }
+
+    count
+}
+
+/// Write a finite, positive float with exponential notation.
+fn write_exponential_positive(
+    float: f64,
+    precision: usize,
+    rounding: DigitRounding,
+    bytes: &mut [u8],
+) -> usize {
+    let mut digits = Digits::new(float);
+    let mut exponent = if digits.integer_count() != 0 {
+        digits.integer_count() as i32 - 1
+    } else if float == 0.0 {
+        0
+    } else {
+        // Skip the leading zeros of the fraction.
+        -(digits.skip_zeros() as i32) - 1
+    };
+    let count = 1 + (precision != 0) as usize + precision;
+    assert!(bytes.len() >= count);
+
+    // Write the significant digits, with a decimal point after the first.
+    digits.write(&mut bytes[..1]);
+    if precision != 0 {
+        bytes[1] = b'.';
+        digits.write(&mut bytes[2..count]);
+    }
+
+    // Round the digits, which may carry to the next power of 10.
+    let is_odd = (bytes[count - 1] - b'0') % 2 == 1;
+    if digits.is_round_up(is_odd, rounding) && round_up(&mut bytes[..count]) {
+        bytes[0] = b'1';
+        exponent += 1;
+    }
+
+    count + write_exponent(exponent, &mut bytes[count..])
+}
+
+/// Get the number of bytes to write an exponent.
+#[inline(always)]
+const fn exponent_size(exponent: i32) -> usize {
+    if exponent.unsigned_abs() >= 100 {
+        5
+    } else {
+        4
+    }
+}
+
+/// Write the exponent, with a sign and at least 2 digits.
+#[inline(always)]
+fn write_exponent(exponent: i32, bytes: &mut [u8]) -> usize {
+    let size = exponent_size(exponent);
+    assert!(bytes.len() >= size);
+    bytes[0] = b'e';
+    bytes[1] = if exponent < 0 {
+        b'-'
+    } else {
+        b'+'
+    };
+    let mut value = exponent.unsigned_abs();
+    for byte in bytes[2..size].iter_mut().rev() {
+        *byte = b'0' + (value % 10) as u8;
+        value /= 10;
+    }
+    size
+}
+
+/// Add 1 to the last digit, skipping the decimal point.
+///
+/// Returns if all digits were 9, and carried past the first digit.
+#[inline(always)]
+fn round_up(bytes: &mut [u8]) -> bool {
+    for byte in bytes.iter_mut().rev() {
+        match *byte {
+            b'.' => (),
+            b'9' => *byte = b'0',
+            _ => {
+                *byte += 1;
+                return false;
+            },
+        }
+    }
+    true
+}
+
+// PRINTF
+
+/// A `printf` conversion specification for a float, like `%08.3f`.
+///
+/// This supports the `%f`, `%F`, `%e`, `%E`, `%g`, `%G`, `%a`, and `%A`
+/// conversions, with the `-`, `+`, space, `#`, and `0` flags, a minimum
+/// field width, and a precision. The `l` and `L` length modifiers are
+/// accepted and ignored, since the float is always widened to an `f64`.
+/// A `*` width or precision is not supported.
+///
+/// Like glibc, `%a` without a precision writes the exact value, and with a
+/// precision rounds to the nearest, tie-even, which may carry to a leading
+/// digit of `2`. Subnormal floats are written with a leading `0` and an
+/// exponent of `-1022`.
+///
+/// # Examples
+///
+/// ```rust
+/// use lexical_write_float::PrintfSpec;
+///
+/// let spec = PrintfSpec::parse(b"%#.0e").unwrap();
+/// let mut buffer = vec![0u8; spec.buffer_size()];
+/// assert_eq!(spec.write(3.0f64, &mut buffer), b"3.e+00");
+/// ```
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub struct PrintfSpec {
+    /// Left-justify within the field width, from the `-` flag.
+    left_justify: bool,
+    /// Sign for non-negative values, from the `+` or space flags.
+    positive_sign: Option<u8>,
+    /// Use the alternate form, from the `#` flag.
+    alternate: bool,
+    /// Pad with leading zeros, from the `0` flag.
+    zero_pad: bool,
+    /// Minimum field width.
+    width: usize,
+    /// Precision, or `None` for the default.
+    precision: Option<usize>,
+    /// Conversion character.
+    conversion: u8,
+}
+
+impl PrintfSpec {
+    /// Parse a conversion specification, starting with `%`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Error::InvalidPrintfSpec`] if the specification is not a
+    /// single, supported conversion.
+    pub fn parse(spec: &[u8]) -> Result<Self> {
+        if spec.first() != Some(&b'%') {
+            return Err(Error::InvalidPrintfSpec);
+        }
+        let mut result = Self {
+            left_justify: false,
+            positive_sign: None,
+            alternate: false,
+            zero_pad: false,
+            width: 0,
+            precision: None,
+            conversion: b'f',
+        };
+
+        // Parse the flags, where `+` overrides a space.
+        let mut index = 1;
+        while let Some(&flag) = spec.get(index) {
+            match flag {
+                b'-' => result.left_justify = true,
+                b'+' => result.positive_sign = Some(b'+'),
+                b' ' if result.positive_sign.is_none() => result.positive_sign = Some(b' '),
+                b' ' => (),
+                b'#' => result.alternate = true,
+                b'0' => result.zero_pad = true,
+                _ => break,
+            }
+            index += 1;
+        }
+
+        // Parse the width, precision, and length modifier.
+        let (width, count) = parse_decimal(&spec[index..]).ok_or(Error::InvalidPrintfSpec)?;
+        result.width = width;
+        index += count;
+        if spec.get(index) == Some(&b'.') {
+            index += 1;
+            let (precision, count) =
+                parse_decimal(&spec[index..]).ok_or(Error::InvalidPrintfSpec)?;
+            result.precision = Some(precision);
+            index += count;
+        }
+        if matches!(spec.get(index), Some(b'l' | b'L')) {
+            index += 1;
+        }
+
+        match spec.get(index) {
+            Some(&conversion @ (b'f' | b'F' | b'e' | b'E' | b'g' | b'G' | b'a' | b'A'))
+                if index + 1 == spec.len() =>
+            {
+                result.conversion = conversion;
+                Ok(result)
+            },
+            _ => Err(Error::InvalidPrintfSpec),
+        }
+    }
+
+    /// Get the minimum field width.
+    #[inline(always)]
+    pub const fn width(&self) -> usize {
+        self.width
+    }
+
+    /// Get the precision, or `None` for the default.
+    #[inline(always)]
+    pub const fn precision(&self) -> Option<usize> {
+        self.precision
+    }
+
+    /// Get the conversion character, such as `b'e'`.
+    #[inline(always)]
+    pub const fn conversion(&self) -> u8 {
+        self.conversion
+    }
+
+    /// Get the size of a buffer required to write any float.
+    #[must_use]
+    pub const fn buffer_size(&self) -> usize {
+        let size = match self.conversion.to_ascii_lowercase() {
+            // Includes the decimal point of the alternate form.
+            b'f' => fixed_buffer_size(self.default_precision()) + 1,
+            b'e' => exponential_buffer_size(self.default_precision()) + 1,
+            // The fixed notation has at most `precision + 3` fraction
+            // digits, and the exponential notation `precision - 1`.
+            b'g' => exponential_buffer_size(self.default_precision()),
+            // The sign, `0x`, the leading digit, the decimal point, the
+            // digits, and the exponent, as `p-1074`.
+            _ => {
+                let digits = match self.precision {
+                    Some(precision) if precision > HEX_DIGITS => precision,
+                    _ => HEX_DIGITS,
+                };
+                1 + 2 + 1 + 1 + digits + 6
+            },
+        };
+        if size > self.width {
+            size
+        } else {
+            self.width
+        }
+    }
+
+    /// Write a float with the conversion.
+    ///
+    /// Returns the subslice of the buffer containing the written bytes.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the buffer is not large enough to hold the number. A
+    /// buffer of [`buffer_size`] bytes is always large enough.
+    ///
+    /// [`buffer_size`]: Self::buffer_size
+    pub fn write<'a, F: Float>(&self, float: F, bytes: &'a mut [u8]) -> &'a mut [u8] {
+        let float = float.as_f64();
+        let mut count = 0;
+        let value = if float.is_sign_negative() {
+            bytes[0] = b'-';
+            count += 1;
+            -float
+        } else {
+            if let Some(sign) = self.positive_sign {
+                bytes[0] = sign;
+                count += 1;
+            }
+            float
+        };
+
+        // Zeros are padded after the sign and the `0x` prefix.
+        let is_hex = self.conversion.eq_ignore_ascii_case(&b'a');
+        let prefix = if is_hex && value.is_finite() {
+            count + 2
+        } else {
+            count
+        };
+        let precision = self.default_precision();
+        let alternate = self.alternate;
+        let digits = &mut bytes[count..];
+        count += if value.is_nan() {
+            digits[..3].copy_from_slice(b"nan");
+            3
+        } else if value.is_infinite() {
+            digits[..3].copy_from_slice(b"inf");
+            3
+        } else {
+            match self.conversion.to_ascii_lowercase() {
+                b'f' => {
+                    let count =
+                        write_fixed_positive(value, precision, DigitRounding::HalfEven, digits);
+                    if alternate && precision == 0 {
+                        digits[count] = b'.';
+                        count + 1
+                    } else {
+                        count
+                    }
+                },
+                b'e' => {
+                    let count = write_exponential_positive(
+                        value,
+                        precision,
+                        DigitRounding::HalfEven,
+                        digits,
+                    );
+                    if alternate && precision == 0 {
+                        digits.copy_within(1..count, 2);
+                        digits[1] = b'.';
+                        count + 1
+                    } else {
+                        count
+                    }
+                },
+                b'g' => write_general_positive(value, precision, alternate, digits),
+                _ => write_hex_positive(value, self.precision, alternate, digits),
+            }
+        };
+        if self.conversion.is_ascii_uppercase() {
+            bytes[..count].make_ascii_uppercase();
+        }
+
+        // Pad to the field width.
+        if count < self.width {
+            let pad = self.width - count;
+            if self.left_justify {
+                bytes[count..self.width].fill(b' ');
+            } else if self.zero_pad && value.is_finite() {
+                bytes.copy_within(prefix..count, prefix + pad);
+                bytes[prefix..prefix + pad].fill(b'0');
+            } else {
+                bytes.copy_within(0..count, pad);
+                bytes[..pad].fill(b' ');
+            }
+            count = self.width;
+        }
+
+        &mut bytes[..count]
+    }
+
+    /// Get the precision, with the default of 6 for `%f`, `%e`, and `%g`.
+    #[inline(always)]
+    const fn default_precision(&self) -> usize {
+        match self.precision {
+            Some(precision) => precision,
+            None => 6,
+        }
+    }
+}
+
+/// Number of hexadecimal digits in the fraction of an `f64`.
+const HEX_DIGITS: usize = 13;
+
+/// Parse a decimal integer, returning the value and the number of digits.
+///
+/// Returns `None` if the value overflows.
+#[inline(always)]
+fn parse_decimal(bytes: &[u8]) -> Option<(usize, usize)> {
+    let mut value: usize = 0;
+    let mut count = 0;
+    while let Some(&digit @ b'0'..=b'9') = bytes.get(count) {
+        value = value.checked_mul(10)?.checked_add((digit - b'0') as usize)?;
+        count += 1;
+    }
+    Some((value, count))
+}
+
+/// Write a finite, positive float like `%g`.
+///
+/// This uses exponential notation if the exponent is below -4, or at least
+/// the precision, and fixed notation otherwise, with `precision`
+/// significant digits. Unless the alternate form is used, trailing zeros
+/// and the decimal point are removed.
+fn write_general_positive(
+    float: f64,
+    precision: usize,
+    alternate: bool,
+    bytes: &mut [u8],
+) -> usize {
+    // Get the exponent after rounding, from the exponential notation,
+    // which has 2 or 3 digits, as `e+05` or `e-324`.
+    let precision = precision.max(1);
+    let mut count =
+        write_exponential_positive(float, precision - 1, DigitRounding::HalfEven, bytes);
+    let mut end = if bytes[count - 5] == b'e' {
+        count - 5
+    } else {
+        count - 4
+    };
+    let exponent = read_exponent(&bytes[end..count]);
+    if exponent >= -4 && exponent < precision as i32 {
+        let precision = (precision as i32 - 1 - exponent) as usize;
+        count = write_fixed_positive(float, precision, DigitRounding::HalfEven, bytes);
+        end = count;
+    }
+
+    // The mantissa is `bytes[..end]`, followed by any exponent.
+    let has_point = bytes[..end].contains(&b'.');
+    if alternate && !has_point {
+        bytes.copy_within(end..count, end + 1);
+        bytes[end] = b'.';
+        count + 1
+    } else if !alternate && has_point {
+        let mut trimmed = end - rtrim_char_count(&bytes[..end], b'0');
+        if bytes[trimmed - 1] == b'.' {
+            trimmed -= 1;
+        }
+        bytes.copy_within(end..count, trimmed);
+        count - (end - trimmed)
+    } else {
+        count
+    }
+}
+
+/// Read an exponent written by [`write_exponent`].
+#[inline(always)]
+fn read_exponent(bytes: &[u8]) -> i32 {
+    let value = bytes[2..].iter().fold(0, |value, &digit| value * 10 + (digit - b'0') as i32);
+    if bytes[1] == b'-' {
+        -value
+    } else {
+        value
+    }
+}
+
+/// Write a finite, positive float like `%a`.
+///
+/// Without a precision, this writes the exact value without trailing zeros.
+fn write_hex_positive(
+    float: f64,
+    precision: Option<usize>,
+    alternate: bool,
+    bytes: &mut [u8],
+) -> usize {
+    // Get the float as `leading.fraction * 2^exp`.
+    let bits = float.to_bits();
+    let biased = ((bits >> 52) & 0x7FF) as i32;
+    let fraction = bits & f64::MANTISSA_MASK;
+    let (leading, exponent) = if biased != 0 {
+        (1, biased - 1023)
+    } else if fraction == 0 {
+        (0, 0)
+    } else {
+        (0, -1022)
+    };
+
+    // Round the fraction to the precision, which may carry to the leading
+    // digit.
+    let (leading, fraction, digit_count, zero_count) = match precision {
+        Some(precision) if precision < HEX_DIGITS => {
+            let shift = 4 * (HEX_DIGITS - precision);
+            let mut value = (((leading as u64) << 52) | fraction) >> shift;
+            let rem = fraction & ((1 << shift) - 1);
+            let halfway = 1 << (shift - 1);
+            if rem > halfway || (rem == halfway && value & 1 == 1) {
+                value += 1;
+            }
+            let digits = 4 * precision;
+            ((value >> digits) as u8, value & ((1 << digits) - 1), precision, 0)
+        },
+        Some(precision) => (leading, fraction, HEX_DIGITS, precision - HEX_DIGITS),
+        None if fraction == 0 => (leading, 0, 0, 0),
+        None => {
+            let zeros = fraction.trailing_zeros() as usize / 4;
+            (leading, fraction >> (4 * zeros), HEX_DIGITS - zeros, 0)
+        },
+    };
+
+    // Write the significant digits, with a decimal point after the first.
+    const DIGITS: &[u8; 16] = b"0123456789abcdef";
+    let mut count = 3;
+    bytes[..3].copy_from_slice(&[b'0', b'x', DIGITS[leading as usize]]);
+    if digit_count + zero_count != 0 || alternate {
+        bytes[count] = b'.';
+        count += 1;
+    }
+    for (index, byte) in bytes[count..count + digit_count].iter_mut().enumerate() {
+        let shift = 4 * (digit_count - 1 - index);
+        *byte = DIGITS[((fraction >> shift) & 0xF) as usize];
+    }
+    count += digit_count;
+    bytes[count..count + zero_count].fill(b'0');
+    count += zero_count;
+
+    // Write the binary exponent, with a sign and at least 1 digit.
+    bytes[count] = b'p';
+    bytes[count + 1] = if exponent < 0 {
+        b'-'
+    } else {
+        b'+'
+    };
+    count += 2;
+    let mut value = exponent.unsigned_abs();
+    let size = if value >= 1000 {
+        4
+    } else if value >= 100 {
+        3
+    } else if value >= 10 {
+        2
+    } else {
+        1
+    };
+    for byte in bytes[count..count + size].iter_mut().rev() {
+        *byte = b'0' + (value % 10) as u8;
+        value /= 10;
+    }
+    count + size
+}
+
+// DIGITS
+
+/// Generator of the exact decimal digits of a finite, positive `f64`.
+///
+/// Yields the integer digits, without leading zeros, followed by the
+/// fraction digits, and then zeros indefinitely.
+struct Digits {
+    /// Integer digits, right-aligned in the buffer.
+    integer: [u8; INTEGER_SIZE],
+    /// Index of the next integer digit.
+    integer_index: usize,
+    /// Current block of fraction digits.
+    block: [u8; BLOCK_DIGITS],
+    /// Index of the next digit in the block.
+    block_index: usize,
+    /// Fraction bits, little-endian, with the binary point after the last
+    /// limb.
+    fraction: [u32; LIMBS],
+    /// Index of the lowest non-zero limb of the fraction.
+    fraction_start: usize,
+    /// Number of limbs of the fraction.
+    fraction_len: usize,
+}
+
+impl Digits {
+    /// Split the float into integer and fraction parts.
+    fn new(float: f64) -> Self {
+        debug_assert!(float.is_finite() && float >= 0.0);
+
+        // Get the float as `mant * 2^exp`.
+        let bits = float.to_bits();
+        let biased = ((bits >> 52) & 0x7FF) as i32;
+        let (mant, exp) = if biased == 0 {
+            (bits & f64::MANTISSA_MASK, -1074)
+        } else {
+            ((bits & f64::MANTISSA_MASK) | f64::HIDDEN_BIT_MASK, biased - 1075)
+        };
+
+        let mut digits = Self {
+            integer: [b'0'; INTEGER_SIZE],
+            integer_index: INTEGER_SIZE,
+            block: [b'0'; BLOCK_DIGITS],
+            block_index: BLOCK_DIGITS,
+            fraction: [0; LIMBS],
+            fraction_start: 0,
+            fraction_len: 0,
+        };
+
+        // Extract the integer bits, and write the integer digits.
+        let mut integer = [0u32; LIMBS];
+        let integer_len = if exp >= 0 {
+            let shift = exp as usize;
+            shl_into(&mut integer, mant, shift % 32, shift / 32)
+        } else if exp > -64 {
+            shl_into(&mut integer, mant >> -exp, 0, 0)
+        } else {
+            0
+        };
+        digits.write_integer(&mut integer[..integer_len]);
+
+        // Extract the fraction bits, aligned so the binary point is at a
+        // limb boundary.
+        if exp < 0 {
+            let bits = -exp as usize;
+            let fraction = if bits < 64 {
+                mant & ((1 << bits) - 1)
+            } else {
+                mant
+            };
+            let len = (bits + 31) / 32;
+            shl_into(&mut digits.fraction, fraction, len * 32 - bits, 0);
+            digits.fraction_len = len;
+            digits.trim_fraction();
+        }
+
+        digits
+    }
+
+    /// Convert the integer to decimal, as blocks written from the end.
+    fn write_integer(&mut self, integer: &mut [u32]) {
+        let mut len = integer.len();
+        let mut end = INTEGER_SIZE;
+        while len != 0 {
+            // Divide by `10^9`, from the highest limb.
+            let mut rem = 0u64;
+            for limb in integer[..len].iter_mut().rev() {
+                let value = (rem << 32) | *limb as u64;
+                *limb = (value / BLOCK_POWER) as u32;
+                rem = value % BLOCK_POWER;
+            }
+            while len != 0 && integer[len - 1] == 0 {
+                len -= 1;
+            }
+            write_block(&mut self.integer[end - BLOCK_DIGITS..end], rem as u32);
+            end -= BLOCK_DIGITS;
+        }
+
+        // Skip the leading zeros of the highest block.
+        let zeros = self.integer[end..].iter().take_while(|&&digit| digit == b'0').count();
+        self.integer_index = end + zeros;
+    }
+
+    /// Get the number of integer digits, or 0 if the float is less than 1.
+    #[inline(always)]
+    fn integer_count(&self) -> usize {
+        INTEGER_SIZE - self.integer_index
+    }
+
+    /// Remove the trailing zero limbs of the fraction.
+    #[inline(always)]
+    fn trim_fraction(&mut self) {
+        while self.fraction_start < self.fraction_len && self.fraction[self.fraction_start] == 0 {
+            self.fraction_start += 1;
+        }
+    }
+
+    /// Check if the fraction bits are zero.
+    #[inline(always)]
+    fn is_fraction_zero(&self) -> bool {
+        self.fraction_start == self.fraction_len
+    }
+
+    /// Generate the next block of fraction digits.
+    fn next_block(&mut self) {
+        // Multiply by `10^9`, and the carry is the next block.
+        let mut carry = 0u64;
+        for limb in self.fraction[self.fraction_start..self.fraction_len].iter_mut() {
+            let value = *limb as u64 * BLOCK_POWER + carry;
+            *limb = value as u32;
+            carry = value >> 32;
+        }
+        self.trim_fraction();
+        write_block(&mut self.block, carry as u32);
+        self.block_index = 0;
+    }
+
+    /// Check if all remaining digits are zero.
+    #[inline(always)]
+    fn is_zero(&self) -> bool {
+        self.integer[self.integer_index..].iter().all(|&digit| digit == b'0')
+            && self.block[self.block_index..].iter().all(|&digit| digit == b'0')
+            && self.is_fraction_zero()
+    }
+
+    /// Get the next digit.
+    #[inline(always)]
+    fn next_digit(&mut self) -> u8 {
+        if self.integer_index < INTEGER_SIZE {
+            self.integer_index += 1;
+            self.integer[self.integer_index - 1]
+        } else {
+            if self.block_index == BLOCK_DIGITS {
+                self.next_block();
+            }
+            self.block_index += 1;
+            self.block[self.block_index - 1]
+        }
+    }
+
+    /// Write the next digits to the buffer.
+    fn write(&mut self, bytes: &mut [u8]) {
+        for (index, byte) in bytes.iter_mut().enumerate() {
+            if self.integer_index == INTEGER_SIZE
+                && self.block_index == BLOCK_DIGITS
+                && self.is_fraction_zero()
+            {
+                // All remaining digits are zero, so avoid generating them.
+                bytes[index..].fill(b'0');
+                return;
+            }
+            *byte = self.next_digit();
+        }
+    }
+
+    /// Skip leading zero digits, returning the number skipped.
+    ///
+    /// The remaining digits must not all be zero.
+    fn skip_zeros(&mut self) -> usize {
+        debug_assert!(!self.is_zero());
+        let mut count = 0;
+        loop {
+            if self.integer_index == INTEGER_SIZE && self.block_index == BLOCK_DIGITS {
+                self.next_block();
+            }
+            let digit = if self.integer_index < INTEGER_SIZE {
+                self.integer[self.integer_index]
+            } else {
+                self.block[self.block_index]
+            };
+            if digit != b'0' {
+                return count;
+            }
+            self.next_digit();
+            count += 1;
+        }
+    }
+
+    /// Determine if the remaining digits round the last digit up, with the
+    /// rounding mode.
+    #[inline(always)]
+    fn is_round_up(&mut self, is_odd: bool, rounding: DigitRounding) -> bool {
+        if self.is_zero() {
+            return false;
+        }
+        let halfway = match self.next_digit() {
+            b'6'..=b'9' => Ordering::Greater,
+            b'5' if !self.is_zero() => Ordering::Greater,
+            b'5' => Ordering::Equal,
+            _ => Ordering::Less,
+        };
+        rounding.is_round_up(is_odd, halfway, false)
+    }
+}
+
+/// Store `value << (32 * limb_shift + bit_shift)` into limbs, returning the
+/// number of limbs used.
+#[inline(always)]
+fn shl_into(limbs: &mut [u32], value: u64, bit_shift: usize, limb_shift: usize) -> usize {
+    debug_assert!(bit_shift < 32);
+    let value = (value as u128) << bit_shift;
+    let mut len = 0;
+    for index in 0..3 {
+        let limb = (value >> (32 * index)) as u32;
+        if limb != 0 {
+            limbs[limb_shift + index] = limb;
+            len = limb_shift + index + 1;
+        }
+    }
+    len
+}
+
+/// Write a block of 9 digits, with leading zeros.
+#[inline(always)]
+fn write_block(bytes: &mut [u8], mut value: u32) {
+    for byte in bytes.iter_mut().rev() {
+        *byte = b'0' + (value % 10) as u8;
+        value /= 10;
+    }
+}
@@ -1,6 +1,8 @@
-//! Adaptation of the V8 ftoa algorithm with a custom radix.
+//! Float-to-string algorithms with a custom radix.
 //!
-//! This algorithm is adapted from the V8 codebase,
+//! By default, this writes the shortest, correctly rounded digits using
+//! the free-format algorithm from Steele and White. The naive algorithm,
+//! used when [`Options::lossy`] is set, is adapted from the V8 codebase,
 //! and may be found [here](https://github.com/v8/v8).
 //!
 //! # Unsupported Features
@@ -12,20 +14,34 @@
 #![cfg(feature = "radix")]
 #![doc(hidden)]
 
-use lexical_util::algorithm::{copy_to_dst, ltrim_char_count, rtrim_char_count};
+use core::cmp::Ordering;
+
+use lexical_util::algorithm::{copy_to_dst, rtrim_char_count};
 use lexical_util::constants::{FormattedSize, BUFFER_SIZE};
-use lexical_util::digit::{char_to_digit_const, digit_to_char_const};
+use lexical_util::digit::{char_to_valid_digit_const, digit_to_char_const};
 use lexical_util::format::NumberFormat;
-use lexical_util::num::Float;
+use lexical_util::num::{AsPrimitive, Float};
 use lexical_write_integer::write::WriteInteger;
 
-use crate::options::{Options, RoundMode};
+use crate::options::{DigitRounding, Options};
 use crate::shared;
 
 // ALGORITHM
 // ---------
 
-/// Naive float-to-string algorithm for generic radixes.
+/// Size of the buffer for the significant digits.
+///
+/// The significant digits are written without any leading or trailing
+/// zeros, which are implied by the exponent. Both algorithms write fewer
+/// than 40 significant digits for an `f64` in radix 3, the smallest
+/// supported radix, and fewer for larger radixes or smaller floats.
+pub const DIGITS_SIZE: usize = 64;
+
+/// Float-to-string algorithm for generic radixes.
+///
+/// By default, this writes the shortest digits that correctly roundtrip,
+/// using big-integer arithmetic. If [`Options::lossy`] is set, this uses
+/// the faster, naive algorithm, which may write incorrect digits.
 ///
 /// This assumes the float is:
 ///     1). Non-special (NaN or Infinite).
@@ -34,7 +50,6 @@ use crate::shared;
 /// # Panics
 ///
 /// Panics if exponent notation is used.
-#[allow(clippy::collapsible_if)] // reason="conditions are different logical concepts"
 pub fn write_float<F: Float, const FORMAT: u128>(
     float: F,
     bytes: &mut [u8],
@@ -55,17 +70,214 @@ where
     // Validate our options: we don't support different exponent bases here.
     debug_assert!(format.mantissa_radix() == format.exponent_base());
 
-    // Temporary buffer for the result. We start with the decimal point in the
-    // middle and write to the left for the integer part and to the right for the
-    // fractional part. 1024 characters for the exponent and 52 for the mantissa
-    // either way, with additional space for sign, decimal point and string
-    // termination should be sufficient.
-    const SIZE: usize = 2200;
-    let mut buffer = [0u8; SIZE];
-    let initial_cursor: usize = SIZE / 2;
-    let mut integer_cursor = initial_cursor;
-    let mut fraction_cursor = initial_cursor;
-    let base = F::as_cast(format.radix());
+    // Write the significant digits, and get the scientific exponent of the
+    // first digit. We can't use a naive float log algorithm for the exponent,
+    // since rounding issues can cause major issues. For example,
+    // `12157665459056928801f64` is `3^40`, but glibc gives us
+    // `(f.ln() / 3.0.ln())` of `39.999`, while Android, and MUSL libm, and
+    // openlibm give us `40.0`, the correct answer. Both algorithms calculate
+    // the exponent exactly, from the digits they write.
+    let mut digits = [0u8; DIGITS_SIZE];
+    let (digit_count, sci_exp) = if float == F::ZERO {
+        digits[0] = b'0';
+        (1, 0)
+    } else if options.lossy() {
+        write_digits_naive::<F, FORMAT>(float, &mut digits)
+    } else {
+        write_digits_exact::<F, FORMAT>(float, &mut digits)
+    };
+    // The first digit is only zero if the float is zero.
+    let digit_count = digit_count - rtrim_char_count(&digits[1..digit_count], b'0');
+
+    write_float!(
+        float,
+        FORMAT,
+        sci_exp,
+        options,
+        write_float_scientific,
+        write_float_positive_exponent,
+        write_float_negative_exponent,
+        bytes => bytes,
+        args => &mut digits, digit_count, sci_exp, options,
+    )
+}
+
+/// Write the shortest, correctly rounded digits of the float to the buffer.
+///
+/// This is an implementation of the free-format algorithm from Steele and
+/// White, as described in "Printing Floating-Point Numbers Quickly and
+/// Accurately" by Burger and Dybvig, using exact big-integer arithmetic.
+/// The written digits are the shortest that parse back to the same float,
+/// with round-nearest, tie-even.
+///
+/// The float must be positive. Returns the number of significant digits
+/// written, and the scientific exponent of the first digit.
+pub fn write_digits_exact<F: Float, const FORMAT: u128>(
+    float: F,
+    digits: &mut [u8],
+) -> (usize, i32) {
+    let format = NumberFormat::<{ FORMAT }> {};
+    let radix = format.radix();
+    debug_assert!(float > F::ZERO);
+
+    // Scale the float and the halfway points to its neighbors so the value
+    // is `r / s`, and the halfway points are `(r - m_minus) / s` and
+    // `(r + m_plus) / s`. The gap below is half as large if the float is an
+    // exact power of two, excluding the smallest normal float.
+    let mantissa = float.mantissa().as_u64();
+    let exponent = float.exponent();
+    let is_even = mantissa & 1 == 0;
+    let unequal = float.mantissa() == F::HIDDEN_BIT_MASK && exponent > F::DENORMAL_EXPONENT;
+    let (mut r, mut s, mut m_plus, mut m_minus) = if exponent >= 0 {
+        let exponent = exponent as u32;
+        if unequal {
+            let r = Bigint::from_u64_shl(mantissa, exponent + 2);
+            (r, Bigint::from_u64(4), Bigint::from_pow2(exponent + 1), Bigint::from_pow2(exponent))
+        } else {
+            let r = Bigint::from_u64_shl(mantissa, exponent + 1);
+            (r, Bigint::from_u64(2), Bigint::from_pow2(exponent), Bigint::from_pow2(exponent))
+        }
+    } else {
+        let exponent = exponent.unsigned_abs();
+        if unequal {
+            let r = Bigint::from_u64_shl(mantissa, 2);
+            (r, Bigint::from_pow2(exponent + 2), Bigint::from_u64(2), Bigint::from_u64(1))
+        } else {
+            let r = Bigint::from_u64_shl(mantissa, 1);
+            (r, Bigint::from_pow2(exponent + 1), Bigint::from_u64(1), Bigint::from_u64(1))
+        }
+    };
+
+    // The halfway points round to the float if the mantissa is even, so the
+    // boundaries are inclusive.
+    let is_high = |r: &Bigint, m_plus: &Bigint, s: &Bigint| {
+        let mut high = r.clone();
+        high.add(m_plus);
+        match high.compare(s) {
+            Ordering::Greater => true,
+            Ordering::Equal => is_even,
+            Ordering::Less => false,
+        }
+    };
+
+    // Find the exponent `k`, so the value is `0.d1d2d3... * radix^k`. Scale
+    // by the largest power of the radix that fits in a limb first, so we
+    // need fewer big-integer multiplications, then by the radix.
+    let (step, step_power) = {
+        let mut step = 0;
+        let mut power: u32 = 1;
+        while let Some(next) = power.checked_mul(radix) {
+            power = next;
+            step += 1;
+        }
+        (step, power)
+    };
+    let mut k: i32 = 0;
+    loop {
+        let mut scaled = s.clone();
+        scaled.mul_small(step_power);
+        if is_high(&r, &m_plus, &scaled) {
+            s = scaled;
+            k += step;
+        } else {
+            break;
+        }
+    }
+    while is_high(&r, &m_plus, &s) {
+        s.mul_small(radix);
+        k += 1;
+    }
+    loop {
+        let mut scaled = r.clone();
+        let mut scaled_plus = m_plus.clone();
+        scaled.mul_small(step_power);
+        scaled_plus.mul_small(step_power);
+        if is_high(&scaled, &scaled_plus, &s) {
+            break;
+        }
+        r = scaled;
+        m_plus = scaled_plus;
+        m_minus.mul_small(step_power);
+        k -= step;
+    }
+    loop {
+        let mut scaled = r.clone();
+        let mut scaled_plus = m_plus.clone();
+        scaled.mul_small(radix);
+        scaled_plus.mul_small(radix);
+        if is_high(&scaled, &scaled_plus, &s) {
+            break;
+        }
+        r = scaled;
+        m_plus = scaled_plus;
+        m_minus.mul_small(radix);
+        k -= 1;
+    }
+
+    // Generate the digits, stopping when the remainder is within either
+    // halfway point.
+    let mut cursor = 0;
+    loop {
+        r.mul_small(radix);
+        m_plus.mul_small(radix);
+        m_minus.mul_small(radix);
+        let mut digit = 0;
+        while r.compare(&s) != Ordering::Less {
+            r.sub(&s);
+            digit += 1;
+        }
+
+        let low = match r.compare(&m_minus) {
+            Ordering::Less => true,
+            Ordering::Equal => is_even,
+            Ordering::Greater => false,
+        };
+        let high = is_high(&r, &m_plus, &s);
+        if !low && !high {
+            digits[cursor] = digit_to_char_const(digit, radix);
+            cursor += 1;
+            continue;
+        }
+
+        // Round the last digit to the nearest, tie-even.
+        let round_up = if low && high {
+            let mut double = r.clone();
+            double.add(&r);
+            match double.compare(&s) {
+                Ordering::Greater => true,
+                Ordering::Equal => digit & 1 == 1,
+                Ordering::Less => false,
+            }
+        } else {
+            high
+        };
+        let digit = digit + round_up as u32;
+        debug_assert!(digit < radix);
+        digits[cursor] = digit_to_char_const(digit, radix);
+        cursor += 1;
+        break;
+    }
+
+    (cursor, k - 1)
+}
+
+/// Write the digits of the float to the buffer using the naive algorithm.
+///
+/// This accumulates rounding error for each digit, and may write incorrect
+/// digits for long expansions. The digits are identical to V8's
+/// `DoubleToRadixCString`, used by `Number.prototype.toString(radix)`.
+///
+/// The float must be positive. Returns the number of significant digits
+/// written, and the scientific exponent of the first digit.
+#[allow(clippy::collapsible_if)] // reason="conditions are different logical concepts"
+pub fn write_digits_naive<F: Float, const FORMAT: u128>(
+    float: F,
+    digits: &mut [u8],
+) -> (usize, i32) {
+    let format = NumberFormat::<{ FORMAT }> {};
+    let radix = format.radix();
+    let base = F::as_cast(radix);
+    debug_assert!(float > F::ZERO);
 
     // Split the float into an integer part and a fractional part.
     let mut integer = float.floor();
@@ -83,19 +295,23 @@ where
     delta = F::ZERO.next_positive().max_finite(delta);
     debug_assert!(delta > F::ZERO);
 
-    // Write our fraction digits.
-    // Won't panic since we have 1100 digits, which is enough for any float f64 or
-    // smaller.
-    if fraction > delta {
+    // Write our fraction digits. If we have no integer digits, skip any
+    // leading zeros, and only count them for the exponent.
+    let mut count = 0;
+    let mut leading_zeros = 0;
+    if fraction >= delta {
         loop {
             // Shift up by one digit.
             fraction *= base;
             delta *= base;
             // Write digit.
             let digit = fraction.as_u32();
-            let c = digit_to_char_const(digit, format.radix());
-            buffer[fraction_cursor] = c;
-            fraction_cursor += 1;
+            if digit == 0 && count == 0 && integer == F::ZERO {
+                leading_zeros += 1;
+            } else {
+                digits[count] = digit_to_char_const(digit, radix);
+                count += 1;
+            }
             // Calculate remainder.
             fraction -= F::as_cast(digit);
             // Round to even.
@@ -103,19 +319,24 @@ where
                 if fraction + delta > F::ONE {
                     // We need to back trace already written digits in case of carry-over.
                     loop {
-                        fraction_cursor -= 1;
-                        if fraction_cursor == initial_cursor - 1 {
-                            // Carry over to the integer part.
-                            integer += F::ONE;
+                        if count == 0 {
+                            if leading_zeros == 0 {
+                                // Carry over to the integer part.
+                                integer += F::ONE;
+                            } else {
+                                // Carry over to the last leading zero.
+                                leading_zeros -= 1;
+                                digits[0] = b'1';
+                                count = 1;
+                            }
                             break;
                         }
                         // Reconstruct digit.
-                        let c = buffer[fraction_cursor];
-                        if let Some(digit) = char_to_digit_const(c, format.radix()) {
-                            let idx = digit + 1;
-                            let c = digit_to_char_const(idx, format.radix());
-                            buffer[fraction_cursor] = c;
-                            fraction_cursor += 1;
+                        count -= 1;
+                        let digit = char_to_valid_digit_const(digits[count], radix);
+                        if digit + 1 < radix {
+                            digits[count] = digit_to_char_const(digit + 1, radix);
+                            count += 1;
                             break;
                         }
                     }
@@ -123,73 +344,58 @@ where
                 }
             }
 
-            if delta >= fraction {
+            if fraction < delta {
                 break;
             }
         }
     }
 
-    // Compute integer digits. Fill unrepresented digits with zero.
-    // Won't panic we have 1100 digits, which is enough for any float f64 or
-    // smaller. We do this first, so we can do extended precision control later.
+    if integer == F::ZERO {
+        return (count, -leading_zeros - 1);
+    }
+
+    // Compute integer digits. Skip unrepresented digits, which are zero,
+    // until the integer is exact, so we only write the significant digits.
+    let mut trailing_zeros = 0;
     while (integer / base).exponent() > 0 {
         integer /= base;
-        integer_cursor -= 1;
-        buffer[integer_cursor] = b'0';
+        trailing_zeros += 1;
     }
+    debug_assert!(trailing_zeros == 0 || count == 0);
 
+    // Write the integer digits in reverse after the fraction digits, then
+    // move them to the front.
+    let fraction_count = count;
     loop {
         let remainder = integer % base;
-        integer_cursor -= 1;
-        let idx = remainder.as_u32();
-        let c = digit_to_char_const(idx, format.radix());
-        buffer[integer_cursor] = c;
+        digits[count] = digit_to_char_const(remainder.as_u32(), radix);
+        count += 1;
         integer = (integer - remainder) / base;
 
         if integer <= F::ZERO {
             break;
         }
     }
+    digits[fraction_count..count].reverse();
+    digits[..count].rotate_left(fraction_count);
+    let sci_exp = (count - fraction_count + trailing_zeros) as i32 - 1;
 
-    // Get our exponent.
-    // We can't use a naive float log algorithm, since rounding issues can
-    // cause major issues. For example, `12157665459056928801f64` is `3^40`,
-    // but glibc gives us `(f.ln() / 3.0.ln())` of `39.999`, while Android, and
-    // MUSL libm, and openlibm give us `40.0`, the correct answer. This of
-    // course means we have off-by-1 errors, so the correct way is to trim
-    // leading zeros, and then calculate the exponent as the offset.
-    let digits = &buffer[integer_cursor..fraction_cursor];
-    let zero_count = ltrim_char_count(digits, b'0');
-    let sci_exp: i32 = initial_cursor as i32 - integer_cursor as i32 - zero_count as i32 - 1;
-    write_float!(
-        float,
-        FORMAT,
-        sci_exp,
-        options,
-        write_float_scientific,
-        write_float_nonscientific,
-        write_float_nonscientific,
-        bytes => bytes,
-        args => sci_exp, &mut buffer, initial_cursor,
-                integer_cursor, fraction_cursor, options,
-    )
+    (count, sci_exp)
 }
 
 /// Write float to string in scientific notation.
 ///
 /// # Preconditions
 ///
-/// The mantissa must be truncated and rounded, prior to calling this,
-/// based on the number of maximum digits. In addition, `exponent_base`
-/// and `mantissa_radix` in `FORMAT` must be identical.
+/// The digits must not have any leading or trailing zeros, unless the
+/// float is zero. In addition, `exponent_base` and `mantissa_radix` in
+/// `FORMAT` must be identical.
 #[cfg_attr(not(feature = "compact"), inline(always))]
 pub fn write_float_scientific<const FORMAT: u128>(
     bytes: &mut [u8],
+    digits: &mut [u8],
+    digit_count: usize,
     sci_exp: i32,
-    buffer: &mut [u8],
-    initial_cursor: usize,
-    integer_cursor: usize,
-    fraction_cursor: usize,
     options: &Options,
 ) -> usize {
     // PRECONDITIONS
@@ -201,24 +407,16 @@ pub fn write_float_scientific<const FORMAT: u128>(
     let decimal_point = options.decimal_point();
 
     // Round and truncate the number of significant digits.
-    let start: usize = if sci_exp <= 0 {
-        ((initial_cursor as i32) - sci_exp - 1) as usize
-    } else {
-        integer_cursor
-    };
-    let end = fraction_cursor.min(start + MAX_DIGIT_LENGTH + 1);
     let (mut digit_count, carried) =
-        truncate_and_round(buffer, start, end, format.radix(), options);
-    let digits = &buffer[start..start + digit_count];
+        truncate_and_round(digits, digit_count, format.radix(), options);
+    let digits = &digits[..digit_count];
     // If we carried, just adjust the exponent since we will always have a
     // `digit_count == 1`. This means we don't have to worry about any other
     // digits.
     let sci_exp = sci_exp + carried as i32;
 
     // Non-exponent portion.
-    // Get as many digits as possible, up to `MAX_DIGIT_LENGTH+1`
-    // since we are ignoring the digit for the first digit,
-    // or the number of written digits.
+    // Write the first digit, the decimal point, and the remaining digits.
     bytes[0] = digits[0];
     bytes[1] = decimal_point;
     let src = &digits[1..digit_count];
@@ -234,7 +432,10 @@ pub fn write_float_scientific<const FORMAT: u128>(
 
     // Write any trailing digits to the output.
     // Won't panic since bytes cannot be empty.
-    if !format.no_exponent_without_fraction() && cursor == 2 && options.trim_floats() {
+    if !format.no_exponent_without_fraction()
+        && cursor == 2
+        && (options.trim_floats() || options.trim_scientific_floats())
+    {
         // Need to trim floats from trailing zeros, and we have only a decimal.
         cursor -= 1;
     } else if exact_count < 2 {
@@ -251,23 +452,24 @@ pub fn write_float_scientific<const FORMAT: u128>(
     }
 
     // Now, write our scientific notation.
-    shared::write_exponent::<FORMAT>(bytes, &mut cursor, sci_exp, options.exponent());
+    shared::write_exponent::<FORMAT>(bytes, &mut cursor, sci_exp, options);
 
     cursor
 }
 
-/// Write float to string without scientific notation.
+/// Write negative float to string without scientific notation.
+///
+/// Has a negative exponent (shift right) and no scientific notation.
 #[cfg_attr(not(feature = "compact"), inline(always))]
-pub fn write_float_nonscientific<const FORMAT: u128>(
+pub fn write_float_negative_exponent<const FORMAT: u128>(
     bytes: &mut [u8],
-    _: i32,
-    buffer: &mut [u8],
-    initial_cursor: usize,
-    integer_cursor: usize,
-    fraction_cursor: usize,
+    digits: &mut [u8],
+    digit_count: usize,
+    sci_exp: i32,
     options: &Options,
 ) -> usize {
     // PRECONDITIONS
+    debug_assert!(sci_exp < 0);
     debug_assert!(bytes.len() >= BUFFER_SIZE);
 
     // Config options.
@@ -276,113 +478,134 @@ pub fn write_float_nonscientific<const FORMAT: u128>(
     let decimal_point = options.decimal_point();
 
     // Round and truncate the number of significant digits.
-    let mut start = integer_cursor;
-    let end = fraction_cursor.min(start + MAX_DIGIT_LENGTH + 1);
-    let (mut digit_count, carried) =
-        truncate_and_round(buffer, start, end, format.radix(), options);
-
-    // Adjust the buffer if we carried.
-    // Note that we can **only** carry if it overflowed through the integer
-    // component, since we always write at least 1 integer digit.
-    if carried {
-        debug_assert!(digit_count == 1);
-        start -= 1;
-        buffer[start] = b'1';
+    let (digit_count, carried) = truncate_and_round(digits, digit_count, format.radix(), options);
+    let sci_exp = sci_exp + carried as i32;
+    if sci_exp == 0 {
+        // Rounded-up to 1, so we have no leading zeros.
+        return write_float_positive_exponent::<FORMAT>(bytes, digits, digit_count, 0, options);
+    }
+
+    // Write our 0 digits.
+    let zero_digits = sci_exp.wrapping_neg() as usize - 1;
+    bytes[0] = b'0';
+    bytes[1] = decimal_point;
+    bytes[2..zero_digits + 2].fill(b'0');
+    let mut cursor = zero_digits + 2;
+
+    // Write the significant digits, without trailing zeros.
+    let zeros = rtrim_char_count(&digits[..digit_count], b'0');
+    let src = &digits[..digit_count - zeros];
+    let dst = &mut bytes[cursor..cursor + src.len()];
+    copy_to_dst(dst, src);
+    cursor += src.len();
+
+    // Determine if we need to add more trailing zeros. The leading zeros,
+    // including the integer digit, count towards the written digits.
+    let digit_count = zero_digits + 1 + src.len();
+    let exact_count = shared::min_exact_digits(digit_count, options);
+
+    // Write any trailing digits to the output.
+    if exact_count > digit_count {
+        let zeros = exact_count - digit_count;
+        bytes[cursor..cursor + zeros].fill(b'0');
+        cursor += zeros;
     }
 
-    let digits = &buffer[start..start + digit_count];
+    cursor
+}
+
+/// Write positive float to string without scientific notation.
+///
+/// Has a positive exponent (shift left) and no scientific notation.
+#[cfg_attr(not(feature = "compact"), inline(always))]
+pub fn write_float_positive_exponent<const FORMAT: u128>(
+    bytes: &mut [u8],
+    digits: &mut [u8],
+    digit_count: usize,
+    sci_exp: i32,
+    options: &Options,
+) -> usize {
+    // PRECONDITIONS
+    debug_assert!(sci_exp >= 0);
+    debug_assert!(bytes.len() >= BUFFER_SIZE);
+
+    // Config options.
+    let format = NumberFormat::<{ FORMAT }> {};
+    assert!(format.is_valid());
+    let decimal_point = options.decimal_point();
 
-    // Write the integer component.
-    let integer_length = initial_cursor - start;
-    let integer_count = digit_count.min(integer_length);
-    let src = &digits[..integer_count];
-    let dst = &mut bytes[..integer_count];
+    // Round and truncate the number of significant digits. If we carried,
+    // we have 1 digit, and need to add an additional leading digit.
+    let (digit_count, carried) = truncate_and_round(digits, digit_count, format.radix(), options);
+    let leading_digits = sci_exp as usize + 1 + carried as usize;
+
+    // Write the significant digits, without trailing zeros. We can only
+    // have trailing zeros if the float is zero.
+    let zeros = rtrim_char_count(&digits[1..digit_count], b'0');
+    let mut digit_count = digit_count - zeros;
+    let src = &digits[..digit_count];
+    let dst = &mut bytes[..digit_count];
     copy_to_dst(dst, src);
-    if integer_count < integer_length {
+
+    // Now need to write our decimal point and add any additional significant
+    // digits.
+    let mut cursor: usize;
+    let mut trimmed = false;
+    if leading_digits >= digit_count {
         // We have more leading digits than digits we wrote: can write
         // any additional digits, and then just write the remaining zeros.
-        bytes[integer_count..integer_length].fill(b'0');
-    }
-    let mut cursor = integer_length;
-    bytes[cursor] = decimal_point;
-    cursor += 1;
-
-    // Write the fraction component.
-    // We've only consumed `integer_count` digits, since this input
-    // may have been truncated.
-    // Won't panic since `integer_count < digits.len()` since `digit_count <
-    // digits.len()`.
-    let digits = &digits[integer_count..];
-    let fraction_count = digit_count.saturating_sub(integer_length);
-    if fraction_count > 0 {
-        // Need to write additional fraction digits.
-        let src = &digits[..fraction_count];
-        let end = cursor + fraction_count;
-        let dst = &mut bytes[cursor..end];
-        copy_to_dst(dst, src);
-        let zeros = rtrim_char_count(&bytes[cursor..end], b'0');
-        cursor += fraction_count - zeros;
-    } else if options.trim_floats() {
-        // Remove the decimal point, went too far.
-        cursor -= 1;
+        bytes[digit_count..leading_digits].fill(b'0');
+        cursor = leading_digits;
+        digit_count = leading_digits;
+        // Only write decimal point if we're not trimming floats.
+        if !options.trim_floats() {
+            bytes[cursor] = decimal_point;
+            cursor += 1;
+            bytes[cursor] = b'0';
+            cursor += 1;
+            digit_count += 1;
+        } else {
+            trimmed = true;
+        }
     } else {
-        bytes[cursor] = b'0';
-        cursor += 1;
-        digit_count += 1;
+        // We have less leading digits than digits we wrote: find the
+        // decimal point index, shift all digits right by 1, then write it.
+        let shifted = digit_count - leading_digits;
+        let buf = &mut bytes[leading_digits..digit_count + 1];
+        for i in (0..shifted).rev() {
+            buf[i + 1] = buf[i];
+        }
+        bytes[leading_digits] = decimal_point;
+        cursor = digit_count + 1;
     }
 
-    // Determine if we need to add more trailing zeros.
+    // Determine if we need to add more trailing zeros after a decimal point.
     let exact_count = shared::min_exact_digits(digit_count, options);
 
-    // Write any trailing digits to the output.
-    // Won't panic since bytes cannot be empty.
-    if (fraction_count > 0 || !options.trim_floats()) && exact_count > digit_count {
-        // NOTE: Neither `exact_count >= digit_count >= 2`.
-        // We need to write `exact_count - (cursor - 1)` digits, since
-        // cursor includes the decimal point.
-        let digits_end = cursor + exact_count - digit_count;
-        bytes[cursor..digits_end].fill(b'0');
-        cursor = digits_end;
+    // Change the number of digits written, if we need to add more or trim digits.
+    if !trimmed && exact_count > digit_count {
+        // Check if we need to write more trailing digits.
+        let zeros = exact_count - digit_count;
+        bytes[cursor..cursor + zeros].fill(b'0');
+        cursor += zeros;
     }
 
     cursor
 }
 
-// Store the first digit and up to `BUFFER_SIZE - 20` digits
-// that occur from left-to-right in the decimal representation.
-// For example, for the number 123.45, store the first digit `1`
-// and `2345` as the remaining values. Then, decide on-the-fly
-// if we need scientific or regular formatting.
-//
-//   BUFFER_SIZE
-// - 1      # first digit
-// - 1      # period
-// - 1      # +/- sign
-// - 2      # e and +/- sign
-// - 9      # max exp is 308, in radix2 is 9
-// - 1      # null terminator
-// = 15 characters of formatting required
-// Just pad it a bit, we don't want memory corruption.
-const MAX_NONDIGIT_LENGTH: usize = 25;
-const MAX_DIGIT_LENGTH: usize = BUFFER_SIZE - MAX_NONDIGIT_LENGTH;
-
 /// Round mantissa to the nearest value, returning only the number
 /// of significant digits. Returns the number of digits of the mantissa,
 /// and if the rounding did a full carry.
 #[cfg_attr(not(feature = "compact"), inline(always))]
-#[allow(clippy::comparison_chain)] // reason="conditions are different logical concepts"
 pub fn truncate_and_round(
-    buffer: &mut [u8],
-    start: usize,
-    end: usize,
+    digits: &mut [u8],
+    digit_count: usize,
     radix: u32,
     options: &Options,
 ) -> (usize, bool) {
-    debug_assert!(end >= start);
-    debug_assert!(end <= buffer.len());
+    debug_assert!(digit_count <= digits.len());
 
     // Get the number of max digits, and then calculate if we need to round.
-    let digit_count = end - start;
     let max_digits = if let Some(digits) = options.max_significant_digits() {
         digits.get()
     } else {
@@ -392,54 +615,131 @@ pub fn truncate_and_round(
     if max_digits >= digit_count {
         return (digit_count, false);
     }
-    if options.round_mode() == RoundMode::Truncate {
+    if options.digit_rounding() == DigitRounding::TowardZero {
         // Don't round input, just shorten number of digits emitted.
         return (max_digits, false);
     }
 
-    // Need to add the number of leading zeros to the digits `digit_count`.
-    let max_digits = {
-        let digits = &mut buffer[start..start + max_digits];
-        max_digits + ltrim_char_count(digits, b'0')
+    // We need to handle the truncation **here**, comparing the truncated
+    // digits to halfway. If the representation is above halfway at all,
+    // it's above halfway, even if by 1 bit.
+    let last = char_to_valid_digit_const(digits[max_digits - 1], radix);
+    let truncated = &digits[max_digits..digit_count];
+    let halfway_char = digit_to_char_const(radix / 2, radix);
+    let is_exact = truncated.iter().all(|&x| x == b'0');
+    let halfway = if radix % 2 == 0 {
+        // Even radix, our halfway point `$c00000.....`.
+        match truncated[0].cmp(&halfway_char) {
+            Ordering::Equal if truncated[1..].iter().any(|&x| x != b'0') => Ordering::Greater,
+            order => order,
+        }
+    } else {
+        // Odd radix, our halfway point is `$c$c$c$c$c$c....`. Cannot have
+        // halfway points, so all halfway digits are below halfway.
+        truncated
+            .iter()
+            .map(|c| c.cmp(&halfway_char))
+            .find(|&order| order != Ordering::Equal)
+            .unwrap_or(Ordering::Less)
     };
-
-    // We need to round-nearest, tie-even, so we need to handle
-    // the truncation **here**. If the representation is above
-    // halfway at all, we need to round up, even if 1 bit.
-    let last = buffer[start + max_digits - 1];
-    let first = buffer[start + max_digits];
-    let halfway = digit_to_char_const(radix / 2, radix);
-    let rem = radix % 2;
-    if first < halfway {
-        // Just truncate, going to round-down anyway.
-        (max_digits, false)
-    } else if first > halfway {
-        // Round-up always.
-        let digits = &mut buffer[start..start + max_digits];
+    if options.digit_rounding().is_round_up(last % 2 == 1, halfway, is_exact) {
+        let digits = &mut digits[..max_digits];
         shared::round_up(digits, max_digits, radix)
-    } else if rem == 0 {
-        // Even radix, our halfway point `$c00000.....`.
-        let truncated = &buffer[start + max_digits + 1..end];
-        if truncated.iter().all(|&x| x == b'0') && last & 1 == 0 {
-            // At an exact halfway point, and even, round-down.
-            (max_digits, false)
+    } else {
+        (max_digits, false)
+    }
+}
+
+// BIGINT
+// ------
+
+/// Number of 32-bit limbs in a big integer.
+///
+/// The largest values are the scaled `f64` denominators, which are at most
+/// `2^1076` multiplied by the radix, plus a few bits for the boundaries.
+const BIGINT_LIMBS: usize = 36;
+
+/// Fixed-width, unsigned big integer for exact digit generation.
+#[derive(Clone)]
+struct Bigint {
+    /// Little-endian limbs.
+    limbs: [u32; BIGINT_LIMBS],
+}
+
+impl Bigint {
+    /// Create a big integer from a 64-bit value.
+    #[inline(always)]
+    fn from_u64(value: u64) -> Self {
+        Self::from_u64_shl(value, 0)
+    }
+
+    /// Create a big integer from a power of two.
+    #[inline(always)]
+    fn from_pow2(exponent: u32) -> Self {
+        Self::from_u64_shl(1, exponent)
+    }
+
+    /// Create a big integer from a 64-bit value shifted left by `shift` bits.
+    fn from_u64_shl(value: u64, shift: u32) -> Self {
+        let mut limbs = [0u32; BIGINT_LIMBS];
+        let index = (shift / 32) as usize;
+        let bits = shift % 32;
+        // Split the shifted value over 3 limbs: the value has at most 64 bits.
+        let lo = (value << bits) as u32;
+        let mid = ((value << bits) >> 32) as u32;
+        let hi = if bits == 0 {
+            0
         } else {
-            // Above halfway or at halfway and even, round-up
-            let digits = &mut buffer[start..start + max_digits];
-            shared::round_up(digits, max_digits, radix)
+            (value >> (64 - bits)) as u32
+        };
+        limbs[index] = lo;
+        limbs[index + 1] = mid;
+        debug_assert!(hi == 0 || index + 2 < BIGINT_LIMBS);
+        if index + 2 < BIGINT_LIMBS {
+            limbs[index + 2] = hi;
         }
-    } else {
-        // Odd radix, our halfway point is `$c$c$c$c$c$c....`. Cannot halfway points.
-        let truncated = &buffer[start + max_digits + 1..end];
-        for &c in truncated.iter() {
-            if c < halfway {
-                return (max_digits, false);
-            } else if c > halfway {
-                // Above halfway
-                let digits = &mut buffer[start..start + max_digits];
-                return shared::round_up(digits, max_digits, radix);
-            }
+        Self {
+            limbs,
         }
-        (max_digits, false)
+    }
+
+    /// Multiply in-place by a small value.
+    fn mul_small(&mut self, value: u32) {
+        let mut carry: u64 = 0;
+        for limb in self.limbs.iter_mut() {
+            let product = (*limb as u64) * (value as u64) + carry;
+            *limb = product as u32;
+            carry = product >> 32;
+        }
+        debug_assert!(carry == 0);
+    }
+
+    /// Add a big integer in-place.
+    fn add(&mut self, other: &Self) {
+        let mut carry = false;
+        for (x, &y) in self.limbs.iter_mut().zip(other.limbs.iter()) {
+            let (sum, overflow1) = x.overflowing_add(y);
+            let (sum, overflow2) = sum.overflowing_add(carry as u32);
+            *x = sum;
+            carry = overflow1 || overflow2;
+        }
+        debug_assert!(!carry);
+    }
+
+    /// Subtract a smaller or equal big integer in-place.
+    fn sub(&mut self, other: &Self) {
+        let mut borrow = false;
+        for (x, &y) in self.limbs.iter_mut().zip(other.limbs.iter()) {
+            let (diff, overflow1) = x.overflowing_sub(y);
+            let (diff, overflow2) = diff.overflowing_sub(borrow as u32);
+            *x = diff;
+            borrow = overflow1 || overflow2;
+        }
+        debug_assert!(!borrow);
+    }
+
+    /// Compare two big integers.
+    fn compare(&self, other: &Self) -> Ordering {
+        self.limbs.iter().rev().cmp(other.limbs.iter().rev())
     }
 }
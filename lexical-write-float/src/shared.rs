@@ -1,10 +1,13 @@
 //! Shared utilities for writing floats.
 
+use core::cmp::Ordering;
+
+use lexical_util::algorithm::{copy_to_dst, rtrim_char_count};
 use lexical_util::digit::{char_to_valid_digit_const, digit_to_char_const};
 use lexical_util::format::NumberFormat;
 use lexical_write_integer::write::WriteInteger;
 
-use crate::options::{Options, RoundMode};
+use crate::options::{DigitRounding, Options};
 
 /// Get the exact number of digits from a minimum bound.
 #[inline(always)]
@@ -46,6 +49,26 @@ pub fn round_up(digits: &mut [u8], count: usize, radix: u32) -> (usize, bool) {
     (1, true)
 }
 
+impl DigitRounding {
+    /// Determine if the truncated digits round the last kept digit up.
+    ///
+    /// `is_odd` is if the last kept digit is odd, `halfway` compares the
+    /// truncated digits to half of the last kept digit, and `is_exact` is
+    /// if the truncated digits are all zero.
+    #[inline(always)]
+    pub fn is_round_up(self, is_odd: bool, halfway: Ordering, is_exact: bool) -> bool {
+        match self {
+            DigitRounding::HalfEven => {
+                halfway == Ordering::Greater || (halfway == Ordering::Equal && is_odd)
+            },
+            DigitRounding::HalfUp => halfway != Ordering::Less,
+            DigitRounding::HalfDown => halfway == Ordering::Greater,
+            DigitRounding::TowardZero => false,
+            DigitRounding::AwayFromZero => !is_exact,
+        }
+    }
+}
+
 /// Round the number of digits based on the maximum digits, for decimal digits.
 ///
 /// `digits` is a mutable buffer of the current digits, `digit_count` is the
@@ -53,7 +76,6 @@ pub fn round_up(digits: &mut [u8], count: usize, radix: u32) -> (usize, bool) {
 /// relative to the digits. Returns the digit count, resulting exp, and if
 /// the input carried to the next digit.
 #[cfg_attr(not(feature = "compact"), inline(always))]
-#[allow(clippy::comparison_chain)] // reason="conditions are different logical concepts"
 pub fn truncate_and_round_decimal(
     digits: &mut [u8],
     digit_count: usize,
@@ -71,40 +93,32 @@ pub fn truncate_and_round_decimal(
     }
 
     // Check if we're truncating, if so, shorten the digits in the input.
-    if options.round_mode() == RoundMode::Truncate {
+    if options.digit_rounding() == DigitRounding::TowardZero {
         // Don't round input, just shorten number of digits emitted.
         return (max_digits, false);
     }
 
-    // We need to round-nearest, tie-even, so we need to handle
-    // the truncation **here**. If the representation is above
-    // halfway at all, we need to round up, even if 1 digit.
+    // We need to handle the truncation **here**, comparing the truncated
+    // digits to halfway. If the representation is above halfway at all,
+    // it's above halfway, even if by 1 digit.
 
     // Get the last non-truncated digit, and the remaining ones.
     // Won't panic if `digit_count < digits.len()`, since `max_digits <
     // digit_count`.
-    let truncated = digits[max_digits];
-    let (digits, carried) = if truncated < b'5' {
-        // Just truncate, going to round-down anyway.
-        (max_digits, false)
-    } else if truncated > b'5' {
-        // Round-up always.
+    let truncated = &digits[max_digits..digit_count];
+    let is_odd = digits[max_digits - 1] % 2 == 1;
+    let is_exact = truncated.iter().all(|&x| x == b'0');
+    let halfway = match truncated[0].cmp(&b'5') {
+        Ordering::Equal if truncated[1..].iter().any(|&x| x != b'0') => Ordering::Greater,
+        order => order,
+    };
+    if options.digit_rounding().is_round_up(is_odd, halfway, is_exact) {
+        // Won't panic `digit_count <= digits.len()`, because `max_digits <
+        // digit_count`.
         round_up(digits, max_digits, 10)
     } else {
-        // Have a near-halfway case, resolve it.
-        let to_round = &digits[max_digits - 1..digit_count];
-        let is_odd = to_round[0] % 2 == 1;
-        let is_above = to_round[2..].iter().any(|&x| x != b'0');
-        if is_odd || is_above {
-            // Won't panic `digit_count <= digits.len()`, because `max_digits <
-            // digit_count`.
-            round_up(digits, max_digits, 10)
-        } else {
-            (max_digits, false)
-        }
-    };
-
-    (digits, carried)
+        (max_digits, false)
+    }
 }
 
 /// Write the sign for the exponent.
@@ -129,17 +143,207 @@ pub fn write_exponent_sign<const FORMAT: u128>(
 }
 
 /// Write the symbol, sign, and digits for the exponent.
+///
+/// The exponent string is written instead of the exponent character, if
+/// one is set.
 #[cfg_attr(not(feature = "compact"), inline(always))]
 pub fn write_exponent<const FORMAT: u128>(
     bytes: &mut [u8],
     cursor: &mut usize,
     exp: i32,
-    exponent_character: u8,
+    options: &Options,
 ) {
-    bytes[*cursor] = exponent_character;
-    *cursor += 1;
+    if let Some(exponent) = options.exponent_string() {
+        bytes[*cursor..*cursor + exponent.len()].copy_from_slice(exponent);
+        *cursor += exponent.len();
+    } else {
+        bytes[*cursor] = options.exponent();
+        *cursor += 1;
+    }
     let positive_exp: u32 = write_exponent_sign::<FORMAT>(bytes, cursor, exp);
-    *cursor += positive_exp.write_exponent_signed::<FORMAT>(&mut bytes[*cursor..]);
+    let count = positive_exp.write_exponent_signed::<FORMAT>(&mut bytes[*cursor..]);
+    let min_digits = options.min_exponent_digits().map_or(0, |x| x.get());
+    if count < min_digits {
+        // Shift the digits and pad with leading zeros.
+        let zeros = min_digits - count;
+        bytes.copy_within(*cursor..*cursor + count, *cursor + zeros);
+        bytes[*cursor..*cursor + zeros].fill(b'0');
+        *cursor += min_digits;
+    } else {
+        *cursor += count;
+    }
+}
+
+/// Write a buffer of decimal digits to string in scientific notation.
+#[allow(clippy::comparison_chain)] // reason="logical approach for the algorithm"
+pub fn write_float_scientific<const FORMAT: u128>(
+    bytes: &mut [u8],
+    digits: &mut [u8],
+    digit_count: usize,
+    sci_exp: i32,
+    options: &Options,
+) -> usize {
+    debug_assert!(rtrim_char_count(&digits[..digit_count], b'0') == 0 || digit_count == 1);
+
+    // Config options
+    let format = NumberFormat::<{ FORMAT }> {};
+    assert!(format.is_valid());
+    let decimal_point = options.decimal_point();
+
+    // Determine the exact number of digits to write.
+    let exact_count = min_exact_digits(digit_count, options);
+
+    // Write our significant digits
+    let mut cursor: usize;
+    bytes[0] = digits[0];
+    bytes[1] = decimal_point;
+    if !format.no_exponent_without_fraction()
+        && digit_count == 1
+        && (options.trim_floats() || options.trim_scientific_floats())
+    {
+        // No more digits and need to trim floats.
+        cursor = 1;
+    } else if digit_count < exact_count {
+        // Write our significant digits.
+        let src = &digits[1..digit_count];
+        let dst = &mut bytes[2..digit_count + 1];
+        copy_to_dst(dst, src);
+        cursor = digit_count + 1;
+
+        // Adjust the number of digits written, by appending zeros.
+        let zeros = exact_count - digit_count;
+        bytes[cursor..cursor + zeros].fill(b'0');
+        cursor += zeros;
+    } else if digit_count == 1 {
+        // Write a single, trailing 0.
+        bytes[2] = b'0';
+        cursor = 3;
+    } else {
+        // Write our significant digits.
+        let src = &digits[1..digit_count];
+        let dst = &mut bytes[2..digit_count + 1];
+        copy_to_dst(dst, src);
+        cursor = digit_count + 1;
+    }
+
+    // Now, write our scientific notation.
+    write_exponent::<FORMAT>(bytes, &mut cursor, sci_exp, options);
+
+    cursor
+}
+
+/// Write a buffer of decimal digits to string without scientific notation.
+///
+/// Has a negative exponent (shift right) and no scientific notation.
+#[allow(clippy::comparison_chain)] // reason="logical approach for the algorithm"
+pub fn write_float_negative_exponent<const FORMAT: u128>(
+    bytes: &mut [u8],
+    digits: &mut [u8],
+    digit_count: usize,
+    sci_exp: i32,
+    options: &Options,
+) -> usize {
+    debug_assert!(rtrim_char_count(&digits[..digit_count], b'0') == 0);
+    debug_assert!(sci_exp < 0);
+
+    // Config options
+    let decimal_point = options.decimal_point();
+    let sci_exp = sci_exp.wrapping_neg() as usize;
+
+    // Write our 0 digits. Note that we cannot have carried, since we previously
+    // adjusted for carrying and rounding before.
+    bytes[0] = b'0';
+    bytes[1] = decimal_point;
+    bytes[2..sci_exp + 1].fill(b'0');
+    let mut cursor = sci_exp + 1;
+
+    // Write out significant digits.
+    let src = &digits[..digit_count];
+    let dst = &mut bytes[cursor..cursor + digit_count];
+    copy_to_dst(dst, src);
+    cursor += digit_count;
+
+    // Determine the exact number of digits to write.
+    let exact_count = min_exact_digits(digit_count, options);
+
+    // Adjust the number of digits written, based on the exact number of digits.
+    if digit_count < exact_count {
+        let zeros = exact_count - digit_count;
+        bytes[cursor..cursor + zeros].fill(b'0');
+        cursor += zeros;
+    }
+
+    cursor
+}
+
+/// Write a buffer of decimal digits to string without scientific notation.
+///
+/// Has a positive exponent (shift left) and no scientific notation.
+pub fn write_float_positive_exponent<const FORMAT: u128>(
+    bytes: &mut [u8],
+    digits: &mut [u8],
+    mut digit_count: usize,
+    sci_exp: i32,
+    options: &Options,
+) -> usize {
+    debug_assert!(rtrim_char_count(&digits[..digit_count], b'0') == 0 || digit_count == 1);
+    debug_assert!(sci_exp >= 0);
+
+    // Config options
+    let decimal_point = options.decimal_point();
+
+    // Now need to write our significant digits.
+    let leading_digits = sci_exp as usize + 1;
+    let mut cursor: usize;
+    let mut trimmed = false;
+    if leading_digits >= digit_count {
+        // We have more leading digits than digits we wrote: can write
+        // any additional digits, and then just write the remaining ones.
+        let src = &digits[..digit_count];
+        let dst = &mut bytes[..digit_count];
+        copy_to_dst(dst, src);
+        bytes[digit_count..leading_digits].fill(b'0');
+        cursor = leading_digits;
+        digit_count = leading_digits;
+        // Only write decimal point if we're not trimming floats.
+        if !options.trim_floats() {
+            bytes[cursor] = decimal_point;
+            cursor += 1;
+            bytes[cursor] = b'0';
+            cursor += 1;
+            digit_count += 1;
+        } else {
+            trimmed = true;
+        }
+    } else {
+        // We have less leading digits than digits we wrote.
+
+        // Write the digits before the decimal point.
+        let src = &digits[..leading_digits];
+        let dst = &mut bytes[..leading_digits];
+        copy_to_dst(dst, src);
+        bytes[leading_digits] = decimal_point;
+
+        // Write the digits after the decimal point.
+        let src = &digits[leading_digits..digit_count];
+        let dst = &mut bytes[leading_digits + 1..digit_count + 1];
+        copy_to_dst(dst, src);
+
+        cursor = digit_count + 1;
+    }
+
+    // Determine the exact number of digits to write.
+    let exact_count = min_exact_digits(digit_count, options);
+
+    // Change the number of digits written, if we need to add more or trim digits.
+    if !trimmed && exact_count > digit_count {
+        // Check if we need to write more trailing digits.
+        let zeros = exact_count - digit_count;
+        bytes[cursor..cursor + zeros].fill(b'0');
+        cursor += zeros;
+    }
+
+    cursor
 }
 
 /// Detect the notation to use for the float formatter and call the appropriate
@@ -7,21 +7,28 @@ use lexical_util::bf16::bf16;
 #[cfg(feature = "f16")]
 use lexical_util::f16::f16;
 use lexical_util::format::NumberFormat;
-use lexical_util::options::WriteOptions;
+use lexical_util::options::{Alignment, WriteOptions};
 use lexical_util::{algorithm::copy_to_dst, constants::FormattedSize};
 use lexical_write_integer::write::WriteInteger;
 
 /// Select the back-end.
 #[cfg(not(feature = "compact"))]
-use crate::algorithm::write_float as write_float_decimal;
+use crate::algorithm::{
+    shortest_digits as shortest_digits_decimal,
+    write_float as write_float_decimal,
+};
 #[cfg(feature = "power-of-two")]
 use crate::binary;
 #[cfg(feature = "compact")]
-use crate::compact::write_float as write_float_decimal;
+use crate::compact::{
+    shortest_digits as shortest_digits_decimal,
+    write_float as write_float_decimal,
+};
 use crate::float::RawFloat;
 #[cfg(feature = "power-of-two")]
 use crate::hex;
-use crate::options::Options;
+use crate::options::{Algorithm, NegativeZero, Options};
+use crate::printf;
 #[cfg(feature = "radix")]
 use crate::radix;
 
@@ -58,6 +65,70 @@ fn write_inf(bytes: &mut [u8], options: &Options, count: usize) -> usize {
         )
 }
 
+/// Write a decimal float with the algorithm selected in the options.
+#[cfg_attr(not(feature = "compact"), inline(always))]
+fn write_decimal<F: RawFloat, const FORMAT: u128>(
+    float: F,
+    bytes: &mut [u8],
+    options: &Options,
+) -> usize {
+    if options.algorithm() == Algorithm::Exact && options.max_significant_digits().is_some() {
+        printf::write_float::<_, FORMAT>(float, bytes, options)
+    } else {
+        write_float_decimal::<_, FORMAT>(float, bytes, options)
+    }
+}
+
+/// Convert the uppercase digits written in a radix above 10 to lowercase.
+///
+/// Only letters that are valid digits in the radix are converted, so the
+/// exponent character is unchanged.
+#[cfg(feature = "power-of-two")]
+fn lowercase_digits(bytes: &mut [u8], radix: u32) {
+    for byte in bytes.iter_mut() {
+        if byte.is_ascii_uppercase() && (*byte - b'A') as u32 + 10 < radix {
+            *byte = byte.to_ascii_lowercase();
+        }
+    }
+}
+
+/// Pad the written float to the minimum width.
+///
+/// `sign` is the number of bytes in the sign, after which zeros are placed
+/// for right alignment. Returns the number of bytes written, including the
+/// padding.
+#[cfg_attr(not(feature = "compact"), inline(always))]
+fn write_padding(
+    bytes: &mut [u8],
+    count: usize,
+    sign: usize,
+    is_special: bool,
+    options: &Options,
+) -> usize {
+    let min_width = options.min_width().map_or(0, |x| x.get());
+    if count >= min_width {
+        return count;
+    }
+    let padding = min_width - count;
+    let pad_char = options.pad_char();
+    let (before, pad_char) = match options.alignment() {
+        Alignment::Right if pad_char == b'0' && !is_special => {
+            bytes.copy_within(sign..count, sign + padding);
+            bytes[sign..sign + padding].fill(b'0');
+            return min_width;
+        },
+        // Like `printf`, special values are not padded with zeros.
+        Alignment::Right if pad_char == b'0' => (padding, b' '),
+        Alignment::Right => (padding, pad_char),
+        Alignment::Left => (0, pad_char),
+        Alignment::Center => (padding / 2, pad_char),
+    };
+    bytes.copy_within(..count, before);
+    bytes[..before].fill(pad_char);
+    bytes[before + count..min_width].fill(pad_char);
+    min_width
+}
+
 /// Check if a buffer is sufficiently large.
 #[inline(always)]
 fn check_buffer<T, const FORMAT: u128>(len: usize, options: &Options) -> bool
@@ -86,12 +157,12 @@ pub trait WriteFloat: RawFloat + FormattedSize {
     /// [`FORMATTED_SIZE`]: lexical_util::constants::FormattedSize::FORMATTED_SIZE
     /// [`FORMATTED_SIZE_DECIMAL`]: lexical_util::constants::FormattedSize::FORMATTED_SIZE_DECIMAL
     #[cfg_attr(not(feature = "compact"), inline(always))]
-    fn write_float<const FORMAT: u128>(self, bytes: &mut [u8], options: &Options) -> usize
+    fn write_float<const FORMAT: u128>(self, buffer: &mut [u8], options: &Options) -> usize
     where
         Self::Unsigned: FormattedSize + WriteInteger,
     {
         // Validate our format options.
-        assert!(check_buffer::<Self, { FORMAT }>(bytes.len(), options));
+        assert!(check_buffer::<Self, { FORMAT }>(buffer.len(), options));
         let format = NumberFormat::<FORMAT> {};
         assert!(format.is_valid());
         // Avoid any false assumptions for 128-bit floats.
@@ -108,25 +179,55 @@ pub trait WriteFloat: RawFloat + FormattedSize {
             }
         }
 
-        let (float, count, bytes) = if self.needs_negative_sign() {
-            bytes[0] = b'-';
-            (-self, 1, &mut bytes[1..])
-        } else if cfg!(feature = "format") && format.required_mantissa_sign() {
-            bytes[0] = b'+';
-            (self, 1, &mut bytes[1..])
+        // Subnormal floats may be flushed to zero, keeping the sign.
+        let value = if options.flush_subnormals() && self.is_denormal() {
+            Self::from_bits(self.to_bits() & Self::SIGN_MASK)
+        } else {
+            self
+        };
+
+        // Negative zero may be written like positive zero, or as an integer.
+        let negative_zero = if value == Self::ZERO && value.is_sign_negative() {
+            options.negative_zero()
+        } else {
+            NegativeZero::Signed
+        };
+        let value = if negative_zero == NegativeZero::Unsigned {
+            Self::ZERO
+        } else {
+            value
+        };
+
+        let is_parenthesized = cfg!(feature = "format")
+            && format.parenthesized_negatives()
+            && value.needs_negative_sign();
+        let (float, count, bytes) = if is_parenthesized {
+            buffer[0] = b'(';
+            (-value, 1, &mut buffer[1..])
+        } else if value.needs_negative_sign() {
+            buffer[0] = b'-';
+            (-value, 1, &mut buffer[1..])
+        } else if (cfg!(feature = "format") && format.required_mantissa_sign())
+            || (options.positive_inf_sign() && self.is_inf())
+        {
+            buffer[0] = b'+';
+            (value, 1, &mut buffer[1..])
         } else {
-            (self, 0, bytes)
+            (value, 0, &mut *buffer)
         };
 
         // Handle special values.
-        if !self.is_special() {
+        let len = if negative_zero == NegativeZero::Integer {
+            bytes[0] = b'0';
+            count + 1
+        } else if !self.is_special() {
             #[cfg(all(feature = "power-of-two", not(feature = "radix")))]
             {
                 let radix = format.radix();
                 let exponent_base = format.exponent_base();
                 count
                     + if radix == 10 {
-                        write_float_decimal::<_, FORMAT>(float, bytes, options)
+                        write_decimal::<_, FORMAT>(float, bytes, options)
                     } else if radix != exponent_base {
                         hex::write_float::<_, FORMAT>(float, bytes, options)
                     } else {
@@ -140,7 +241,7 @@ pub trait WriteFloat: RawFloat + FormattedSize {
                 let exponent_base = format.exponent_base();
                 count
                     + if radix == 10 {
-                        write_float_decimal::<_, FORMAT>(float, bytes, options)
+                        write_decimal::<_, FORMAT>(float, bytes, options)
                     } else if radix != exponent_base {
                         hex::write_float::<_, FORMAT>(float, bytes, options)
                     } else if matches!(radix, 2 | 4 | 8 | 16 | 32) {
@@ -152,12 +253,54 @@ pub trait WriteFloat: RawFloat + FormattedSize {
 
             #[cfg(not(feature = "power-of-two"))]
             {
-                count + write_float_decimal::<_, FORMAT>(float, bytes, options)
+                count + write_decimal::<_, FORMAT>(float, bytes, options)
             }
         } else if self.is_nan() {
             write_nan(bytes, options, count)
         } else {
             write_inf(bytes, options, count)
+        };
+
+        // Digits are written in uppercase, unless uppercase is not allowed.
+        #[cfg(feature = "power-of-two")]
+        if format.no_uppercase_digits() && !self.is_special() {
+            lowercase_digits(&mut bytes[..len - count], format.radix());
+        }
+
+        // Percentages are scaled by the decimal algorithms, and suffixed here.
+        let len = if options.percent() && format.radix() == 10 && !self.is_special() {
+            bytes[len - count] = b'%';
+            len + 1
+        } else {
+            len
+        };
+
+        let len = if is_parenthesized {
+            // `bytes` starts after the opening parenthesis.
+            bytes[len - count] = b')';
+            len + 1
+        } else {
+            len
+        };
+
+        write_padding(buffer, len, count, self.is_special(), options)
+    }
+
+    /// Get the number of significant digits and the scientific exponent of
+    /// the shortest decimal representation of the float.
+    ///
+    /// Returns `None` for special values, like `NaN`. Zero has a single
+    /// significant digit and an exponent of 0.
+    #[cfg_attr(not(feature = "compact"), inline(always))]
+    fn shortest_digits(self) -> Option<(usize, i32)> {
+        if self.is_special() {
+            None
+        } else if self == Self::ZERO {
+            Some((1, 0))
+        } else if self.is_sign_negative() {
+            Some(shortest_digits_decimal(-self))
+        } else {
+            Some(shortest_digits_decimal(self))
         }
     }
 }
@@ -179,6 +322,11 @@ macro_rules! write_float_as_f32 {
             {
                 self.as_f32().write_float::<FORMAT>(bytes, options)
             }
+
+            #[inline(always)]
+            fn shortest_digits(self) -> Option<(usize, i32)> {
+                self.as_f32().shortest_digits()
+            }
         }
     )*)
 }
@@ -9,7 +9,7 @@ use lexical_util::format::NumberFormatBuilder;
 use lexical_util::num::Float;
 use lexical_write_float::algorithm::DragonboxFloat;
 use lexical_write_float::float::{ExtendedFloat80, RawFloat};
-use lexical_write_float::{algorithm, Options, RoundMode};
+use lexical_write_float::{algorithm, DigitRounding, Options, RoundMode};
 use proptest::prelude::*;
 
 use crate::util::default_proptest_config;
@@ -154,6 +154,7 @@ fn to_decimal_test() {
     assert_eq!(to_decimal_f32(2147483600.0), (21474836, 2));
     assert_eq!(to_decimal_f32(2762159900.0), (27621599, 2));
     assert_eq!(to_decimal_f32(77371252000000000000000000.0), (77371252, 18));
+    assert_eq!(to_decimal_f32(f32::from_bits(0x4c000014)), (3355451, 1));
 
     assert_eq!(to_decimal_f64(0.0), (0, 0));
     assert_eq!(to_decimal_f64(0.5), (5, -1));
@@ -162,10 +163,24 @@ fn to_decimal_test() {
     assert_eq!(to_decimal_f64(1.23456), (123456, -5));
     assert_eq!(to_decimal_f64(2.2250738585072014e-308), (22250738585072014, -324));
     assert_eq!(to_decimal_f64(1.7976931348623157e+308), (17976931348623157, 292));
+    assert_eq!(to_decimal_f64(f64::from_bits(0x440b0a984d5fb93e)), (6235319190006528, 4));
+}
+
+#[test]
+fn to_decimal_with_rounding_test() {
+    // Ties between the two nearest shortest representations.
+    let tie = 29376553785613.3125f64;
+    let decimal = |mode| {
+        let fp = algorithm::to_decimal_with_rounding(tie, mode);
+        (fp.mant, fp.exp)
+    };
+    assert_eq!(decimal(DigitRounding::HalfEven), (29376553785613312, -3));
+    assert_eq!(decimal(DigitRounding::HalfUp), (29376553785613313, -3));
+    assert_eq!(decimal(DigitRounding::HalfDown), (29376553785613312, -3));
 }
 
 fn compute_nearest_shorter(float: f64) -> (u64, i32) {
-    let fp = algorithm::compute_nearest_shorter(float);
+    let fp = algorithm::compute_nearest_shorter(float, DigitRounding::HalfEven);
     (fp.mant, fp.exp)
 }
 
@@ -177,7 +192,7 @@ fn compute_nearest_shorter_test() {
 }
 
 fn compute_nearest_normal(float: f64) -> (u64, i32) {
-    let fp = algorithm::compute_nearest_normal(float);
+    let fp = algorithm::compute_nearest_normal(float, DigitRounding::HalfEven);
     (fp.mant, fp.exp)
 }
 
@@ -721,6 +736,23 @@ fn f64_test() {
     write_float::<_, DECIMAL>(1.2345678901234567890e2f64, &round, "123.5");
     write_float::<_, DECIMAL>(1.2345678901234567890e3f64, &truncate, "1234.0");
     write_float::<_, DECIMAL>(1.2345678901234567890e3f64, &round, "1235.0");
+
+    let options = |rounding| {
+        Options::builder()
+            .max_significant_digits(num::NonZeroUsize::new(2))
+            .digit_rounding(rounding)
+            .build()
+            .unwrap()
+    };
+    write_float::<_, DECIMAL>(1.25f64, &options(DigitRounding::HalfEven), "1.2");
+    write_float::<_, DECIMAL>(1.35f64, &options(DigitRounding::HalfEven), "1.4");
+    write_float::<_, DECIMAL>(1.25f64, &options(DigitRounding::HalfUp), "1.3");
+    write_float::<_, DECIMAL>(1.24f64, &options(DigitRounding::HalfUp), "1.2");
+    write_float::<_, DECIMAL>(1.35f64, &options(DigitRounding::HalfDown), "1.3");
+    write_float::<_, DECIMAL>(1.351f64, &options(DigitRounding::HalfDown), "1.4");
+    write_float::<_, DECIMAL>(1.21f64, &options(DigitRounding::AwayFromZero), "1.3");
+    write_float::<_, DECIMAL>(1.2f64, &options(DigitRounding::AwayFromZero), "1.2");
+    write_float::<_, DECIMAL>(9.91f64, &options(DigitRounding::AwayFromZero), "10.0");
 }
 
 #[test]
@@ -6,7 +6,7 @@ use lexical_util::constants::BUFFER_SIZE;
 #[cfg(feature = "f16")]
 use lexical_util::f16::f16;
 use lexical_util::format::STANDARD;
-use lexical_write_float::{Options, ToLexical, ToLexicalWithOptions};
+use lexical_write_float::{shortest_digits, Options, ToLexical, ToLexicalWithOptions};
 use proptest::prelude::*;
 
 use crate::util::default_proptest_config;
@@ -52,6 +52,621 @@ fn special_test() {
     assert_eq!(actual, "Infinity");
 }
 
+#[test]
+#[cfg(feature = "format")]
+fn parenthesized_negatives_test() {
+    use lexical_util::format::NumberFormatBuilder;
+
+    const FORMAT: u128 = NumberFormatBuilder::new().parenthesized_negatives(true).build();
+    let mut buffer = [b'\x00'; BUFFER_SIZE];
+    let options = Options::new();
+    assert_eq!(b"(123.45)", (-123.45f64).to_lexical_with_options::<FORMAT>(&mut buffer, &options));
+    assert_eq!(b"123.45", 123.45f64.to_lexical_with_options::<FORMAT>(&mut buffer, &options));
+    assert_eq!(b"(0.0)", (-0.0f32).to_lexical_with_options::<FORMAT>(&mut buffer, &options));
+    assert_eq!(
+        b"(inf)",
+        f64::NEG_INFINITY.to_lexical_with_options::<FORMAT>(&mut buffer, &options)
+    );
+    assert_eq!(b"NaN", (-f64::NAN).to_lexical_with_options::<FORMAT>(&mut buffer, &options));
+    assert_eq!(
+        b"(1.7976931348623157e308)",
+        f64::MIN.to_lexical_with_options::<FORMAT>(&mut buffer, &options)
+    );
+}
+
+#[test]
+fn exponent_string_test() {
+    let mut buffer = [b'\x00'; BUFFER_SIZE];
+    let options = Options::builder().exponent_string(Some("\u{d7}10^".as_bytes())).build().unwrap();
+    let bytes = 1.23e40f64.to_lexical_with_options::<{ STANDARD }>(&mut buffer, &options);
+    assert_eq!(bytes, "1.23\u{d7}10^40".as_bytes());
+    let bytes = (-1.5e-7f64).to_lexical_with_options::<{ STANDARD }>(&mut buffer, &options);
+    assert_eq!(bytes, "-1.5\u{d7}10^-7".as_bytes());
+    let bytes = 123.5f32.to_lexical_with_options::<{ STANDARD }>(&mut buffer, &options);
+    assert_eq!(bytes, b"123.5");
+}
+
+#[test]
+fn min_exponent_digits_test() {
+    let mut buffer = [b'\x00'; BUFFER_SIZE];
+    let options =
+        Options::builder().min_exponent_digits(core::num::NonZeroUsize::new(3)).build().unwrap();
+    let mut write =
+        |x: f64| x.to_lexical_with_options::<{ STANDARD }>(&mut buffer, &options).to_vec();
+    assert_eq!(write(1.5e10), b"1.5e010");
+    assert_eq!(write(-1.5e-7), b"-1.5e-007");
+    assert_eq!(write(1e100), b"1.0e100");
+    assert_eq!(write(5e-324), b"5.0e-324");
+    assert_eq!(write(123.5), b"123.5");
+
+    let options = Options::builder().trim_scientific_floats(true).build().unwrap();
+    let mut write =
+        |x: f64| x.to_lexical_with_options::<{ STANDARD }>(&mut buffer, &options).to_vec();
+    assert_eq!(write(1e10), b"1e10");
+    assert_eq!(write(1.5e10), b"1.5e10");
+    assert_eq!(write(1.0), b"1.0");
+}
+
+#[test]
+#[cfg(feature = "format")]
+fn python_repr_test() {
+    use lexical_util::format::PYTHON_REPR;
+    use lexical_write_float::options;
+
+    // The expected strings are from CPython's `repr`.
+    let mut buffer = [b'\x00'; BUFFER_SIZE];
+    let mut repr = |x: f64| {
+        let bytes = x.to_lexical_with_options::<PYTHON_REPR>(&mut buffer, &options::PYTHON_REPR);
+        String::from_utf8(bytes.to_vec()).unwrap()
+    };
+    assert_eq!(repr(0.0), "0.0");
+    assert_eq!(repr(-0.0), "-0.0");
+    assert_eq!(repr(1.0), "1.0");
+    assert_eq!(repr(100.0), "100.0");
+    assert_eq!(repr(0.1), "0.1");
+    assert_eq!(repr(0.3), "0.3");
+    assert_eq!(repr(0.0001), "0.0001");
+    assert_eq!(repr(0.00001), "1e-05");
+    assert_eq!(repr(-1.5e-7), "-1.5e-07");
+    assert_eq!(repr(1e15), "1000000000000000.0");
+    assert_eq!(repr(9999999999999998.0), "9999999999999998.0");
+    assert_eq!(repr(1e16), "1e+16");
+    assert_eq!(repr(1.5e16), "1.5e+16");
+    assert_eq!(repr(123456789012345678.0), "1.2345678901234568e+17");
+    assert_eq!(repr(1e22), "1e+22");
+    assert_eq!(repr(1e100), "1e+100");
+    assert_eq!(repr(5e-324), "5e-324");
+    assert_eq!(repr(f64::MAX), "1.7976931348623157e+308");
+    assert_eq!(repr(f64::NAN), "nan");
+    assert_eq!(repr(f64::INFINITY), "inf");
+    assert_eq!(repr(f64::NEG_INFINITY), "-inf");
+}
+
+#[test]
+#[cfg(not(feature = "compact"))]
+fn rust_display_test() {
+    use lexical_write_float::options;
+
+    let mut buffer = [b'\x00'; 1200];
+    let mut display = |x: f64| {
+        let bytes = x.to_lexical_with_options::<STANDARD>(&mut buffer, &options::RUST_DISPLAY);
+        String::from_utf8(bytes.to_vec()).unwrap()
+    };
+    for x in [
+        0.0,
+        -0.0,
+        1.0,
+        -1.5,
+        0.1,
+        1e-7,
+        1e16,
+        1e300,
+        5e-324,
+        f64::MAX,
+        29376553785613.312,
+        6.235319190006528e19,
+        f64::NAN,
+        f64::INFINITY,
+        f64::NEG_INFINITY,
+    ] {
+        assert_eq!(display(x), format!("{}", x));
+    }
+    assert_eq!(display(1.0), "1");
+    assert_eq!(display(-0.0), "-0");
+}
+
+#[test]
+#[cfg(not(feature = "compact"))]
+fn rust_debug_test() {
+    use lexical_write_float::options;
+
+    let mut buffer = [b'\x00'; BUFFER_SIZE];
+    let mut debug = |x: f64| {
+        let bytes = x.to_lexical_with_options::<STANDARD>(&mut buffer, &options::RUST_DEBUG);
+        String::from_utf8(bytes.to_vec()).unwrap()
+    };
+    for x in [
+        0.0,
+        -0.0,
+        1.0,
+        0.1,
+        1e-4,
+        9.9e-5,
+        1e15,
+        9999999999999998.0,
+        1e16,
+        1.5e16,
+        5e-324,
+        f64::MAX,
+        29376553785613.312,
+        6.235319190006528e19,
+        f64::NAN,
+        f64::INFINITY,
+        f64::NEG_INFINITY,
+    ] {
+        assert_eq!(debug(x), format!("{:?}", x));
+    }
+    assert_eq!(debug(29376553785613.312), "29376553785613.313");
+    assert_eq!(debug(1e16), "1e16");
+
+    let mut buffer = [b'\x00'; BUFFER_SIZE];
+    let bytes = 33554512f32.to_lexical_with_options::<STANDARD>(&mut buffer, &options::RUST_DEBUG);
+    assert_eq!(bytes, b"33554510.0");
+    assert_eq!(bytes, format!("{:?}", 33554512f32).as_bytes());
+}
+
+#[test]
+#[cfg(feature = "format")]
+fn python_repr_vectors_test() {
+    use lexical_util::format::PYTHON_REPR;
+    use lexical_write_float::options;
+
+    // The float bits and `repr` strings are from CPython 3.11, including
+    // random floats and floats with 17 significant digits.
+    const VECTORS: [(u64, &str); 41] = [
+        (4808357600546065744, "29376553785613.312"),
+        (3084222511168427998, "1.638551892745897e-102"),
+        (7397706334419951340, "3.5260745179036124e+186"),
+        (6719862254377265222, "1.690682042681112e+141"),
+        (5371859350563631921, "1.3412233422335789e+51"),
+        (5272725906644733682, "3.1762341575197853e+44"),
+        (6909586248405575308, "8.288363103828399e+153"),
+        (787285163965626425, "4.828289399584541e-256"),
+        (3559671772389927439, "1.0185192938604461e-70"),
+        (2061058536994637403, "6.8186314746461605e-171"),
+        (7228475446481985717, "1.65252280133841e+175"),
+        (2077597355179837726, "8.73938168753363e-170"),
+        (3616681530350531434, "6.318241260550083e-67"),
+        (2128387076368588429, "2.166413090186392e-166"),
+        (1669581555276905636, "4.6061623467931414e-197"),
+        (3130193710686040368, "1.9067330805489054e-99"),
+        (2925990084069315302, "4.4188605945935536e-113"),
+        (6055054807920691537, "6.374864519054927e+96"),
+        (9088196257305399207, "3.323262907666285e+299"),
+        (7957880025243697048, "9.293762848029198e+223"),
+        (4942288023161295980, "2.6603763796991757e+22"),
+        (4838244611324475417, "2941105577966604.5"),
+        (4845998625102860996, "9258051359155592.0"),
+        (4844791804531590593, "7925804735677889.0"),
+        (4839346511678462254, "3492055754960023.0"),
+        (4840160960279337744, "3899280055397768.0"),
+        (4844327457241953558, "7461457446040854.0"),
+        (4833953536979597359, "1523684109606411.8"),
+        (4838692906469862170, "3165253150659981.0"),
+        (4846369599423283200, "1e+16"),
+        (4846369599423283199, "9999999999999998.0"),
+        (4547007122018943789, "0.0001"),
+        (4547007122018943788, "9.999999999999999e-05"),
+        (4683220299150161609, "123456.789"),
+        (4845873199050653697, "9007199254740994.0"),
+        (4599075939470750516, "0.30000000000000004"),
+        (4599676419421066581, "0.3333333333333333"),
+        (4604180019048437077, "0.6666666666666666"),
+        (1, "5e-324"),
+        (4503599627370496, "2.2250738585072014e-308"),
+        (9218868437227405311, "1.7976931348623157e+308"),
+    ];
+    let mut buffer = [b'\x00'; BUFFER_SIZE];
+    for (bits, expected) in VECTORS {
+        let f = f64::from_bits(bits);
+        let bytes = f.to_lexical_with_options::<PYTHON_REPR>(&mut buffer, &options::PYTHON_REPR);
+        assert_eq!(bytes, expected.as_bytes(), "bits={}", bits);
+    }
+}
+
+#[test]
+#[cfg(feature = "format")]
+fn go_format_float_test() {
+    use lexical_util::format::GO_FORMAT_FLOAT;
+    use lexical_write_float::options;
+
+    // The expected strings are from Go's `strconv.FormatFloat(x, 'g', -1, 64)`.
+    let mut buffer = [b'\x00'; BUFFER_SIZE];
+    let mut format = |x: f64| {
+        let bytes =
+            x.to_lexical_with_options::<GO_FORMAT_FLOAT>(&mut buffer, &options::GO_FORMAT_FLOAT);
+        String::from_utf8(bytes.to_vec()).unwrap()
+    };
+    assert_eq!(format(0.0), "0");
+    assert_eq!(format(-0.0), "-0");
+    assert_eq!(format(1.0), "1");
+    assert_eq!(format(-2.5), "-2.5");
+    assert_eq!(format(0.1), "0.1");
+    assert_eq!(format(0.0001), "0.0001");
+    assert_eq!(format(0.00001), "1e-05");
+    assert_eq!(format(1.5e-7), "1.5e-07");
+    assert_eq!(format(123456.0), "123456");
+    assert_eq!(format(999999.0), "999999");
+    assert_eq!(format(1e6), "1e+06");
+    assert_eq!(format(1234567.0), "1.234567e+06");
+    assert_eq!(format(1e21), "1e+21");
+    assert_eq!(format(1e100), "1e+100");
+    assert_eq!(format(5e-324), "5e-324");
+    assert_eq!(format(f64::MAX), "1.7976931348623157e+308");
+    assert_eq!(format(f64::NAN), "NaN");
+    assert_eq!(format(f64::INFINITY), "+Inf");
+    assert_eq!(format(f64::NEG_INFINITY), "-Inf");
+
+    let mut buffer = [b'\x00'; BUFFER_SIZE];
+    let bytes =
+        1e6f32.to_lexical_with_options::<GO_FORMAT_FLOAT>(&mut buffer, &options::GO_FORMAT_FLOAT);
+    assert_eq!(bytes, b"1e+06");
+}
+
+#[test]
+#[cfg(feature = "format")]
+fn javascript_to_string_test() {
+    use lexical_util::format::JAVASCRIPT_TO_STRING;
+    use lexical_write_float::options;
+
+    // The expected strings are from V8's `Number.prototype.toString`.
+    let mut buffer = [b'\x00'; BUFFER_SIZE];
+    let mut format = |x: f64| {
+        let bytes = x.to_lexical_with_options::<JAVASCRIPT_TO_STRING>(
+            &mut buffer,
+            &options::JAVASCRIPT_TO_STRING,
+        );
+        String::from_utf8(bytes.to_vec()).unwrap()
+    };
+    assert_eq!(format(0.0), "0");
+    assert_eq!(format(-0.0), "0");
+    assert_eq!(format(1.0), "1");
+    assert_eq!(format(-1.5), "-1.5");
+    assert_eq!(format(0.1), "0.1");
+    assert_eq!(format(100.0), "100");
+    assert_eq!(format(123.456), "123.456");
+    assert_eq!(format(1e-6), "0.000001");
+    assert_eq!(format(1e-7), "1e-7");
+    assert_eq!(format(1.5e-7), "1.5e-7");
+    assert_eq!(format(1e20), "100000000000000000000");
+    assert_eq!(format(1e21), "1e+21");
+    assert_eq!(format(1.5e21), "1.5e+21");
+    assert_eq!(format(5e-324), "5e-324");
+    assert_eq!(format(f64::MAX), "1.7976931348623157e+308");
+    assert_eq!(format(f64::NAN), "NaN");
+    assert_eq!(format(f64::INFINITY), "Infinity");
+    assert_eq!(format(f64::NEG_INFINITY), "-Infinity");
+}
+
+#[test]
+#[cfg(all(feature = "format", feature = "radix"))]
+fn javascript_to_string_radix_test() {
+    use lexical_util::format::{NumberFormatBuilder, JAVASCRIPT_TO_STRING};
+    use lexical_write_float::options;
+
+    const fn radix(radix: u8) -> u128 {
+        NumberFormatBuilder::rebuild(JAVASCRIPT_TO_STRING)
+            .radix(radix)
+            .exponent_base(core::num::NonZeroU8::new(radix))
+            .exponent_radix(core::num::NonZeroU8::new(radix))
+            .no_exponent_notation(true)
+            .build()
+    }
+    const BASE2: u128 = radix(2);
+    const BASE3: u128 = radix(3);
+    const BASE7: u128 = radix(7);
+    const BASE12: u128 = radix(12);
+    const BASE16: u128 = radix(16);
+    const BASE36: u128 = radix(36);
+
+    // The expected strings are from V8's `Number.prototype.toString(radix)`.
+    let options = &options::JAVASCRIPT_TO_STRING;
+    let mut buffer = [b'\x00'; 1200];
+    let format = |bytes: &[u8]| String::from_utf8(bytes.to_vec()).unwrap();
+    assert_eq!(
+        format(0.1.to_lexical_with_options::<BASE2>(&mut buffer, options)),
+        "0.0001100110011001100110011001100110011001100110011001101"
+    );
+    assert_eq!(
+        format(0.1.to_lexical_with_options::<BASE3>(&mut buffer, options)),
+        "0.0022002200220022002200220022002201"
+    );
+    assert_eq!(format((1.0 / 3.0).to_lexical_with_options::<BASE3>(&mut buffer, options)), "0.1");
+    assert_eq!(
+        format(5e-324.to_lexical_with_options::<BASE3>(&mut buffer, options)),
+        format!("0.{}1", "0".repeat(676))
+    );
+    assert_eq!(
+        format(123.456.to_lexical_with_options::<BASE7>(&mut buffer, options)),
+        "234.312256641535441"
+    );
+    assert_eq!(
+        format(1e-7.to_lexical_with_options::<BASE12>(&mut buffer, options)),
+        "0.00000036bb8a065560637"
+    );
+    assert_eq!(format(255.5.to_lexical_with_options::<BASE16>(&mut buffer, options)), "ff.8");
+    assert_eq!(format((-255.5).to_lexical_with_options::<BASE16>(&mut buffer, options)), "-ff.8");
+    assert_eq!(format(0.5.to_lexical_with_options::<BASE36>(&mut buffer, options)), "0.i");
+    assert_eq!(
+        format(1e21.to_lexical_with_options::<BASE36>(&mut buffer, options)),
+        "5v1j4f4ds7c000"
+    );
+}
+
+#[test]
+fn java_to_string_test() {
+    use lexical_write_float::options;
+
+    // The expected strings are from Java's `Double.toString`.
+    let mut buffer = [b'\x00'; BUFFER_SIZE];
+    let mut format = |x: f64| {
+        let bytes = x.to_lexical_with_options::<STANDARD>(&mut buffer, &options::JAVA_TO_STRING);
+        String::from_utf8(bytes.to_vec()).unwrap()
+    };
+    assert_eq!(format(0.0), "0.0");
+    assert_eq!(format(-0.0), "-0.0");
+    assert_eq!(format(1.0), "1.0");
+    assert_eq!(format(0.1), "0.1");
+    assert_eq!(format(0.001), "0.001");
+    assert_eq!(format(0.0001), "1.0E-4");
+    assert_eq!(format(-1.5e-5), "-1.5E-5");
+    assert_eq!(format(9999999.0), "9999999.0");
+    assert_eq!(format(1e7), "1.0E7");
+    assert_eq!(format(1.5e7), "1.5E7");
+    assert_eq!(format(1e21), "1.0E21");
+    assert_eq!(format(1e-300), "1.0E-300");
+    assert_eq!(format(f64::MAX), "1.7976931348623157E308");
+    assert_eq!(format(f64::NAN), "NaN");
+    assert_eq!(format(f64::INFINITY), "Infinity");
+    assert_eq!(format(f64::NEG_INFINITY), "-Infinity");
+}
+
+#[test]
+#[cfg(feature = "format")]
+fn csharp_round_trip_test() {
+    use lexical_util::format::CSHARP_ROUND_TRIP;
+    use lexical_write_float::options;
+
+    // The expected strings are from C#'s `double.ToString("R")`.
+    let mut buffer = [b'\x00'; BUFFER_SIZE];
+    let mut format = |x: f64| {
+        let bytes = x
+            .to_lexical_with_options::<CSHARP_ROUND_TRIP>(&mut buffer, &options::CSHARP_ROUND_TRIP);
+        String::from_utf8(bytes.to_vec()).unwrap()
+    };
+    assert_eq!(format(0.0), "0");
+    assert_eq!(format(-0.0), "-0");
+    assert_eq!(format(1.0), "1");
+    assert_eq!(format(0.1), "0.1");
+    assert_eq!(format(0.0001), "0.0001");
+    assert_eq!(format(0.00001), "1E-05");
+    assert_eq!(format(-1.5e-7), "-1.5E-07");
+    assert_eq!(format(1e14), "100000000000000");
+    assert_eq!(format(1e15), "1E+15");
+    assert_eq!(format(123456789012345680.0), "1.2345678901234568E+17");
+    assert_eq!(format(5e-324), "5E-324");
+    assert_eq!(format(f64::MAX), "1.7976931348623157E+308");
+    assert_eq!(format(f64::NAN), "NaN");
+    assert_eq!(format(f64::INFINITY), "Infinity");
+    assert_eq!(format(f64::NEG_INFINITY), "-Infinity");
+}
+
+#[test]
+#[cfg(feature = "format")]
+fn config_file_specials_test() {
+    use lexical_util::format::{JSON5, TOML, YAML};
+    use lexical_write_float::options;
+
+    let mut buffer = [b'\x00'; BUFFER_SIZE];
+    assert_eq!(f64::NAN.to_lexical_with_options::<TOML>(&mut buffer, &options::TOML), b"nan");
+    assert_eq!(
+        f64::NEG_INFINITY.to_lexical_with_options::<TOML>(&mut buffer, &options::TOML),
+        b"-inf"
+    );
+    assert_eq!(f64::NAN.to_lexical_with_options::<YAML>(&mut buffer, &options::YAML), b".nan");
+    assert_eq!(f64::INFINITY.to_lexical_with_options::<YAML>(&mut buffer, &options::YAML), b".inf");
+    assert_eq!(
+        f64::NEG_INFINITY.to_lexical_with_options::<YAML>(&mut buffer, &options::YAML),
+        b"-.inf"
+    );
+    assert_eq!(
+        f64::INFINITY.to_lexical_with_options::<JSON5>(&mut buffer, &options::JSON5),
+        b"Infinity"
+    );
+    assert_eq!(1.5f64.to_lexical_with_options::<JSON5>(&mut buffer, &options::JSON5), b"1.5");
+}
+
+#[test]
+fn positive_inf_sign_test() {
+    let mut buffer = [b'\x00'; BUFFER_SIZE];
+    let options = Options::builder().positive_inf_sign(true).build().unwrap();
+    let mut write =
+        |x: f64| x.to_lexical_with_options::<{ STANDARD }>(&mut buffer, &options).to_vec();
+    assert_eq!(write(f64::INFINITY), b"+inf");
+    assert_eq!(write(f64::NEG_INFINITY), b"-inf");
+    assert_eq!(write(f64::NAN), b"NaN");
+    assert_eq!(write(1.5), b"1.5");
+}
+
+#[test]
+fn negative_zero_test() {
+    use lexical_write_float::NegativeZero;
+
+    let mut buffer = [b'\x00'; BUFFER_SIZE];
+    let mut write = |x: f64, negative_zero: NegativeZero, trim_floats: bool| {
+        let options = Options::builder()
+            .negative_zero(negative_zero)
+            .trim_floats(trim_floats)
+            .build()
+            .unwrap();
+        x.to_lexical_with_options::<{ STANDARD }>(&mut buffer, &options).to_vec()
+    };
+    assert_eq!(write(-0.0, NegativeZero::Signed, false), b"-0.0");
+    assert_eq!(write(-0.0, NegativeZero::Signed, true), b"-0");
+    assert_eq!(write(-0.0, NegativeZero::Unsigned, false), b"0.0");
+    assert_eq!(write(-0.0, NegativeZero::Unsigned, true), b"0");
+    assert_eq!(write(-0.0, NegativeZero::Integer, false), b"-0");
+    assert_eq!(write(0.0, NegativeZero::Integer, false), b"0.0");
+    assert_eq!(write(-1.5, NegativeZero::Unsigned, false), b"-1.5");
+    assert_eq!(write(-1e-300, NegativeZero::Integer, false), b"-1.0e-300");
+
+    let options = Options::builder().negative_zero(NegativeZero::Unsigned).build().unwrap();
+    assert_eq!((-0.0f32).to_lexical_with_options::<{ STANDARD }>(&mut buffer, &options), b"0.0");
+}
+
+#[test]
+fn flush_subnormals_test() {
+    use lexical_write_float::NegativeZero;
+
+    let mut buffer = [b'\x00'; BUFFER_SIZE];
+    let mut write = |x: f64, negative_zero: NegativeZero| {
+        let options =
+            Options::builder().flush_subnormals(true).negative_zero(negative_zero).build().unwrap();
+        x.to_lexical_with_options::<{ STANDARD }>(&mut buffer, &options).to_vec()
+    };
+    assert_eq!(write(5e-324, NegativeZero::Signed), b"0.0");
+    assert_eq!(write(-5e-324, NegativeZero::Signed), b"-0.0");
+    assert_eq!(write(-5e-324, NegativeZero::Unsigned), b"0.0");
+    assert_eq!(write(-5e-324, NegativeZero::Integer), b"-0");
+    assert_eq!(write(f64::MIN_POSITIVE, NegativeZero::Signed), b"2.2250738585072014e-308");
+    assert_eq!(write(1.5, NegativeZero::Signed), b"1.5");
+
+    let options = Options::builder().flush_subnormals(true).build().unwrap();
+    let x = f32::from_bits(1);
+    assert_eq!(x.to_lexical_with_options::<{ STANDARD }>(&mut buffer, &options), b"0.0");
+}
+
+#[test]
+fn percent_test() {
+    use core::num;
+
+    use lexical_write_float::Algorithm;
+
+    let mut buffer = [b'\x00'; BUFFER_SIZE];
+    let options = Options::builder().percent(true).build().unwrap();
+    let mut write =
+        |x: f64| x.to_lexical_with_options::<{ STANDARD }>(&mut buffer, &options).to_vec();
+    assert_eq!(write(0.075), b"7.5%");
+    assert_eq!(write(0.07), b"7.0%");
+    assert_eq!(write(0.011), b"1.1%");
+    assert_eq!(write(1.0), b"100.0%");
+    assert_eq!(write(-0.5), b"-50.0%");
+    assert_eq!(write(0.0), b"0.0%");
+    assert_eq!(write(1e-10), b"1.0e-8%");
+    assert_eq!(write(1e8), b"1.0e10%");
+    assert_eq!(write(f64::NAN), b"NaN");
+    assert_eq!(write(f64::NEG_INFINITY), b"-inf");
+    assert_eq!(write(5e-324), b"5.0e-322%");
+    assert_eq!(0.173f32.to_lexical_with_options::<{ STANDARD }>(&mut buffer, &options), b"17.3%");
+
+    let options = Options::builder()
+        .percent(true)
+        .max_significant_digits(num::NonZeroUsize::new(3))
+        .build()
+        .unwrap();
+    assert_eq!(
+        0.123456f64.to_lexical_with_options::<{ STANDARD }>(&mut buffer, &options),
+        b"12.3%"
+    );
+    let options = options.rebuild().algorithm(Algorithm::Exact).build().unwrap();
+    assert_eq!(
+        0.123456f64.to_lexical_with_options::<{ STANDARD }>(&mut buffer, &options),
+        b"12.3%"
+    );
+
+    let options =
+        Options::builder().percent(true).min_width(num::NonZeroUsize::new(8)).build().unwrap();
+    assert_eq!(
+        (-0.5f64).to_lexical_with_options::<{ STANDARD }>(&mut buffer, &options),
+        b"-0050.0%"
+    );
+}
+
+#[test]
+#[cfg(feature = "format")]
+fn negative_zero_format_test() {
+    use lexical_util::format::NumberFormatBuilder;
+    use lexical_write_float::NegativeZero;
+
+    const SIGNED: u128 = NumberFormatBuilder::new().required_mantissa_sign(true).build();
+    const PARENTHESIZED: u128 = NumberFormatBuilder::new().parenthesized_negatives(true).build();
+    let mut buffer = [b'\x00'; BUFFER_SIZE];
+    let unsigned = Options::builder().negative_zero(NegativeZero::Unsigned).build().unwrap();
+    let integer = Options::builder().negative_zero(NegativeZero::Integer).build().unwrap();
+    assert_eq!((-0.0f64).to_lexical_with_options::<SIGNED>(&mut buffer, &unsigned), b"+0.0");
+    assert_eq!((-0.0f64).to_lexical_with_options::<PARENTHESIZED>(&mut buffer, &unsigned), b"0.0");
+    assert_eq!((-0.0f64).to_lexical_with_options::<PARENTHESIZED>(&mut buffer, &integer), b"(0)");
+}
+
+#[test]
+fn min_width_test() {
+    use core::num::NonZeroUsize;
+
+    use lexical_write_float::Alignment;
+
+    let mut buffer = [b'\x00'; BUFFER_SIZE];
+    let mut write = |x: f64, pad_char: u8, alignment: Alignment| {
+        let options = Options::builder()
+            .min_width(NonZeroUsize::new(8))
+            .pad_char(pad_char)
+            .alignment(alignment)
+            .build()
+            .unwrap();
+        x.to_lexical_with_options::<{ STANDARD }>(&mut buffer, &options).to_vec()
+    };
+    assert_eq!(write(-1.5, b'0', Alignment::Right), b"-00001.5");
+    assert_eq!(write(1.5, b'0', Alignment::Right), b"000001.5");
+    assert_eq!(write(-1.5, b' ', Alignment::Right), b"    -1.5");
+    assert_eq!(write(-1.5, b' ', Alignment::Left), b"-1.5    ");
+    assert_eq!(write(-1.5, b'*', Alignment::Center), b"**-1.5**");
+    assert_eq!(write(1.5, b'*', Alignment::Center), b"**1.5***");
+    assert_eq!(write(1.5, b'0', Alignment::Left), b"1.500000");
+    assert_eq!(write(1.2345678e-300, b'0', Alignment::Right), b"1.2345678e-300");
+    // Like `printf`, special values are padded with spaces instead of zeros.
+    assert_eq!(write(f64::NAN, b'0', Alignment::Right), b"     NaN");
+    assert_eq!(write(f64::NEG_INFINITY, b'0', Alignment::Right), b"    -inf");
+    assert_eq!(write(f64::NAN, b'_', Alignment::Left), b"NaN_____");
+
+    let options = Options::builder().min_width(NonZeroUsize::new(6)).build().unwrap();
+    let size = Options::new().buffer_size_const::<f32, { STANDARD }>();
+    assert_eq!(options.buffer_size_const::<f32, { STANDARD }>(), size);
+    assert_eq!((-1.5f32).to_lexical_with_options::<{ STANDARD }>(&mut buffer, &options), b"-001.5");
+}
+
+#[test]
+#[cfg(feature = "format")]
+fn min_width_format_test() {
+    use core::num::NonZeroUsize;
+
+    use lexical_util::format::NumberFormatBuilder;
+
+    const SIGNED: u128 = NumberFormatBuilder::new().required_mantissa_sign(true).build();
+    const PARENTHESIZED: u128 = NumberFormatBuilder::new().parenthesized_negatives(true).build();
+    let mut buffer = [b'\x00'; BUFFER_SIZE];
+    let options = Options::builder().min_width(NonZeroUsize::new(8)).build().unwrap();
+    assert_eq!(1.5f64.to_lexical_with_options::<SIGNED>(&mut buffer, &options), b"+00001.5");
+    assert_eq!(
+        (-1.5f64).to_lexical_with_options::<PARENTHESIZED>(&mut buffer, &options),
+        b"(0001.5)"
+    );
+    let options =
+        Options::builder().min_width(NonZeroUsize::new(8)).pad_char(b' ').build().unwrap();
+    assert_eq!(
+        (-1.5f64).to_lexical_with_options::<PARENTHESIZED>(&mut buffer, &options),
+        b"   (1.5)"
+    );
+}
+
 #[test]
 #[should_panic]
 fn invalid_nan_test() {
@@ -88,6 +703,23 @@ fn hex_test() {
     assert_eq!(result, b"3.039^12");
 }
 
+#[test]
+fn shortest_digits_test() {
+    assert_eq!(shortest_digits(0.0f64), Some((1, 0)));
+    assert_eq!(shortest_digits(-0.0f64), Some((1, 0)));
+    assert_eq!(shortest_digits(1.0f64), Some((1, 0)));
+    assert_eq!(shortest_digits(100.0f64), Some((1, 2)));
+    assert_eq!(shortest_digits(123.456f64), Some((6, 2)));
+    assert_eq!(shortest_digits(-0.00123f64), Some((3, -3)));
+    assert_eq!(shortest_digits(0.1f32), Some((1, -1)));
+    assert_eq!(shortest_digits(0.1f32 as f64), Some((17, -1)));
+    assert_eq!(shortest_digits(f64::MAX), Some((17, 308)));
+    assert_eq!(shortest_digits(f64::from_bits(1)), Some((1, -324)));
+    assert_eq!(shortest_digits(f32::MAX), Some((8, 38)));
+    assert_eq!(shortest_digits(f32::INFINITY), None);
+    assert_eq!(shortest_digits(f64::NAN), None);
+}
+
 default_quickcheck! {
     fn f32_quickcheck(f: f32) -> bool {
         let mut buffer = [b'\x00'; BUFFER_SIZE];
@@ -115,6 +747,50 @@ default_quickcheck! {
 proptest! {
     #![proptest_config(default_proptest_config())]
 
+    #[test]
+    #[cfg(not(feature = "compact"))]
+    fn f64_rust_display_proptest(bits in u64::MIN..u64::MAX) {
+        use lexical_write_float::options;
+
+        let f = f64::from_bits(bits);
+        let mut buffer = [b'\x00'; 1200];
+        let bytes = f.to_lexical_with_options::<STANDARD>(&mut buffer, &options::RUST_DISPLAY);
+        prop_assert_eq!(std::str::from_utf8(bytes).unwrap(), format!("{}", f));
+    }
+
+    #[test]
+    #[cfg(not(feature = "compact"))]
+    fn f64_rust_debug_proptest(bits in u64::MIN..u64::MAX) {
+        use lexical_write_float::options;
+
+        let f = f64::from_bits(bits);
+        let mut buffer = [b'\x00'; BUFFER_SIZE];
+        let bytes = f.to_lexical_with_options::<STANDARD>(&mut buffer, &options::RUST_DEBUG);
+        prop_assert_eq!(std::str::from_utf8(bytes).unwrap(), format!("{:?}", f));
+    }
+
+    #[test]
+    #[cfg(not(feature = "compact"))]
+    fn f32_rust_display_proptest(bits in u32::MIN..u32::MAX) {
+        use lexical_write_float::options;
+
+        let f = f32::from_bits(bits);
+        let mut buffer = [b'\x00'; 1200];
+        let bytes = f.to_lexical_with_options::<STANDARD>(&mut buffer, &options::RUST_DISPLAY);
+        prop_assert_eq!(std::str::from_utf8(bytes).unwrap(), format!("{}", f));
+    }
+
+    #[test]
+    #[cfg(not(feature = "compact"))]
+    fn f32_rust_debug_proptest(bits in u32::MIN..u32::MAX) {
+        use lexical_write_float::options;
+
+        let f = f32::from_bits(bits);
+        let mut buffer = [b'\x00'; BUFFER_SIZE];
+        let bytes = f.to_lexical_with_options::<STANDARD>(&mut buffer, &options::RUST_DEBUG);
+        prop_assert_eq!(std::str::from_utf8(bytes).unwrap(), format!("{:?}", f));
+    }
+
     #[test]
     fn f32_proptest(f in f32::MIN..f32::MAX) {
         let mut buffer = [b'\x00'; BUFFER_SIZE];
@@ -139,6 +815,17 @@ proptest! {
         }
     }
 
+    #[test]
+    #[cfg(not(feature = "compact"))]
+    fn f64_shortest_digits_proptest(f in f64::MIN..f64::MAX) {
+        let expected = format!("{:e}", f);
+        let (mantissa, exponent) = expected.split_once('e').unwrap();
+        let digits = mantissa.bytes().filter(u8::is_ascii_digit).count();
+        let exponent = exponent.parse::<i32>().unwrap();
+        let expected = if f == 0.0 { (1, 0) } else { (digits, exponent) };
+        prop_assert_eq!(shortest_digits(f), Some(expected));
+    }
+
     #[test]
     #[cfg(feature = "f16")]
     fn f16_proptest(bits in u16::MIN..u16::MAX) {
@@ -8,7 +8,7 @@ use core::num;
 use lexical_util::constants::{FormattedSize, BUFFER_SIZE};
 use lexical_util::format::NumberFormatBuilder;
 use lexical_util::num::{Float, Integer};
-use lexical_write_float::options::RoundMode;
+use lexical_write_float::options::{DigitRounding, RoundMode};
 use lexical_write_float::{binary, Options};
 use lexical_write_integer::write::WriteInteger;
 use parse_radix::{parse_f32, parse_f64};
@@ -1085,6 +1085,29 @@ fn write_float_test() {
         .unwrap();
     write_float::<_, BINARY>(1.2345678901234567890e2f64, &truncate, "1111011");
     write_float::<_, BINARY>(1.2345678901234567890e2f64, &round, "1111011.1");
+
+    // Rounding up carries into a new bit.
+    let round = Options::builder()
+        .max_significant_digits(num::NonZeroUsize::new(2))
+        .round_mode(RoundMode::Round)
+        .build()
+        .unwrap();
+    write_float::<_, BINARY>(7.0f64, &round, "1000.0");
+    write_float::<_, BINARY>(15.0f64, &round, "10000.0");
+
+    let options = |rounding| {
+        Options::builder()
+            .max_significant_digits(num::NonZeroUsize::new(2))
+            .digit_rounding(rounding)
+            .build()
+            .unwrap()
+    };
+    write_float::<_, BINARY>(5.0f64, &options(DigitRounding::HalfEven), "100.0");
+    write_float::<_, BINARY>(5.0f64, &options(DigitRounding::HalfUp), "110.0");
+    write_float::<_, BINARY>(7.0f64, &options(DigitRounding::HalfDown), "110.0");
+    write_float::<_, BINARY>(7.0f64, &options(DigitRounding::HalfEven), "1000.0");
+    write_float::<_, BINARY>(4.5f64, &options(DigitRounding::AwayFromZero), "110.0");
+    write_float::<_, BINARY>(4.5f64, &options(DigitRounding::HalfUp), "100.0");
 }
 
 default_quickcheck! {
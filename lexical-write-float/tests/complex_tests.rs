@@ -0,0 +1,66 @@
+use core::num;
+
+use lexical_util::constants::BUFFER_SIZE;
+use lexical_util::format::STANDARD;
+use lexical_write_float::{write_complex, write_complex_with_options, Alignment, Options};
+
+#[test]
+fn write_complex_test() {
+    let mut buffer = [0u8; 2 * BUFFER_SIZE + 2];
+    assert_eq!(write_complex(3.0f64, 4.0, &mut buffer), b"3.0+4.0i");
+    assert_eq!(write_complex(3.0f64, -4.0, &mut buffer), b"3.0-4.0i");
+    assert_eq!(write_complex(-1.5e300f64, 2.5e-10, &mut buffer), b"-1.5e300+2.5e-10i");
+    assert_eq!(write_complex(0.0f64, 0.0, &mut buffer), b"0.0+0.0i");
+    assert_eq!(write_complex(0.0f64, -0.0, &mut buffer), b"0.0-0.0i");
+    assert_eq!(write_complex(1.0f32, f32::INFINITY, &mut buffer), b"1.0+infi");
+    assert_eq!(write_complex(1.0f32, f32::NEG_INFINITY, &mut buffer), b"1.0-infi");
+    assert_eq!(write_complex(f64::NAN, f64::NAN, &mut buffer), b"NaN+NaNi");
+}
+
+#[test]
+fn write_complex_with_options_test() {
+    let mut buffer = [0u8; 2 * BUFFER_SIZE + 2];
+    let options = Options::builder().imaginary_unit(b'j').trim_floats(true).build().unwrap();
+    assert_eq!(
+        write_complex_with_options::<f64, STANDARD>(3.0, 4.0, &mut buffer, &options),
+        b"3+4j"
+    );
+    assert_eq!(
+        write_complex_with_options::<f64, STANDARD>(-0.5, -2.0, &mut buffer, &options),
+        b"-0.5-2j"
+    );
+}
+
+#[test]
+#[should_panic]
+fn write_complex_min_width_test() {
+    // The imaginary sign would be written after the padding, like `  1.5+ -2.0i`.
+    let mut buffer = [0u8; 2 * BUFFER_SIZE + 2];
+    let options = Options::builder()
+        .min_width(num::NonZeroUsize::new(5))
+        .pad_char(b' ')
+        .alignment(Alignment::Right)
+        .build()
+        .unwrap();
+    write_complex_with_options::<f64, STANDARD>(1.5, -2.0, &mut buffer, &options);
+}
+
+#[test]
+#[should_panic]
+#[cfg(feature = "format")]
+fn write_complex_parenthesized_negatives_test() {
+    use lexical_util::format::NumberFormatBuilder;
+
+    // The imaginary sign would be written in parentheses, like `+(2.0)i`.
+    const FORMAT: u128 = NumberFormatBuilder::new().parenthesized_negatives(true).build();
+    let mut buffer = [0u8; 2 * BUFFER_SIZE + 2];
+    write_complex_with_options::<f64, FORMAT>(1.5, -2.0, &mut buffer, &Options::new());
+}
+
+#[test]
+#[should_panic]
+fn write_complex_buffer_test() {
+    const SIZE: usize = Options::new().buffer_size_const::<f64, STANDARD>();
+    let mut buffer = [0u8; 2 * SIZE + 1];
+    write_complex(1.0f64, 1.0, &mut buffer);
+}
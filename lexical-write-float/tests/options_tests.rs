@@ -1,6 +1,9 @@
+#![allow(clippy::disallowed_macros)]
+
 use core::num;
 
 use lexical_write_float::options::{self, Options, OptionsBuilder};
+use lexical_write_float::Alignment;
 
 #[test]
 fn invalid_exponent_test() {
@@ -15,6 +18,25 @@ fn invalid_exponent_test() {
     assert!(builder.build().is_ok());
 }
 
+#[test]
+fn invalid_exponent_string_test() {
+    let mut builder = OptionsBuilder::default();
+    builder = builder.exponent_string(Some(b""));
+    assert!(!builder.is_valid());
+    builder = builder.exponent_string(Some(b"10^"));
+    assert!(!builder.is_valid());
+    builder = builder.exponent_string(Some(b"-10^"));
+    assert!(!builder.is_valid());
+    builder = builder.exponent_string(Some(b".10^"));
+    assert!(!builder.is_valid());
+    assert!(builder.build().is_err());
+    builder = builder.exponent_string(Some("\u{d7}10^".as_bytes()));
+    assert!(builder.is_valid());
+    assert!(builder.build().is_ok());
+    builder = builder.exponent_string(None);
+    assert!(builder.is_valid());
+}
+
 #[test]
 fn invalid_decimal_point_test() {
     let mut builder = OptionsBuilder::default();
@@ -38,6 +60,13 @@ fn invalid_nan_test() {
     builder = builder.nan_string(Some(b"na00n"));
     assert!(!builder.is_valid());
     assert!(builder.build().is_err());
+    builder = builder.nan_string(Some(b"."));
+    assert!(!builder.is_valid());
+    builder = builder.nan_string(Some(b"..nan"));
+    assert!(!builder.is_valid());
+    builder = builder.nan_string(Some(b".nan"));
+    assert!(builder.is_valid());
+    assert!(builder.build().is_ok());
     builder = builder.nan_string(Some(b"nan"));
     assert!(builder.is_valid());
     assert!(builder.build().is_ok());
@@ -55,6 +84,13 @@ fn invalid_inf_test() {
     builder = builder.inf_string(Some(b"in00f"));
     assert!(!builder.is_valid());
     assert!(builder.build().is_err());
+    builder = builder.inf_string(Some(b"."));
+    assert!(!builder.is_valid());
+    builder = builder.inf_string(Some(b"..inf"));
+    assert!(!builder.is_valid());
+    builder = builder.inf_string(Some(b".inf"));
+    assert!(builder.is_valid());
+    assert!(builder.build().is_ok());
     builder = builder.inf_string(Some(b"inf"));
     assert!(builder.is_valid());
     assert!(builder.build().is_ok());
@@ -62,6 +98,36 @@ fn invalid_inf_test() {
     assert!(builder.is_valid());
 }
 
+#[test]
+fn invalid_pad_char_test() {
+    let mut builder = OptionsBuilder::default();
+    builder = builder.pad_char(b'\x00');
+    assert!(!builder.is_valid());
+    builder = builder.pad_char(b'\x80');
+    assert!(!builder.is_valid());
+    assert!(builder.build().is_err());
+    builder = builder.pad_char(b' ');
+    assert!(builder.is_valid());
+    assert!(builder.build().is_ok());
+}
+
+#[test]
+fn invalid_imaginary_unit_test() {
+    let mut builder = OptionsBuilder::default();
+    builder = builder.imaginary_unit(b' ');
+    assert!(!builder.is_valid());
+    builder = builder.imaginary_unit(b'1');
+    assert!(!builder.is_valid());
+    builder = builder.imaginary_unit(b'+');
+    assert!(!builder.is_valid());
+    builder = builder.imaginary_unit(b'e');
+    assert!(!builder.is_valid());
+    assert!(builder.build().is_err());
+    builder = builder.imaginary_unit(b'J');
+    assert!(builder.is_valid());
+    assert!(builder.build().is_ok());
+}
+
 #[test]
 fn builder_test() {
     let mut builder = OptionsBuilder::default();
@@ -72,10 +138,22 @@ fn builder_test() {
     builder = builder.negative_exponent_break(num::NonZeroI32::new(-9));
     builder = builder.round_mode(options::RoundMode::Truncate);
     builder = builder.trim_floats(true);
+    builder = builder.lossy(true);
     builder = builder.exponent(b'^');
     builder = builder.decimal_point(b',');
     builder = builder.nan_string(Some(b"nan"));
     builder = builder.inf_string(Some(b"Infinity"));
+    builder = builder.exponent_string(Some(b"*10^"));
+    builder = builder.min_exponent_digits(num::NonZeroUsize::new(2));
+    builder = builder.trim_scientific_floats(true);
+    builder = builder.positive_inf_sign(true);
+    builder = builder.negative_zero(options::NegativeZero::Integer);
+    builder = builder.flush_subnormals(true);
+    builder = builder.min_width(num::NonZeroUsize::new(12));
+    builder = builder.pad_char(b' ');
+    builder = builder.alignment(Alignment::Center);
+    builder = builder.percent(true);
+    builder = builder.imaginary_unit(b'j');
 
     assert_eq!(builder.get_max_significant_digits().unwrap().get(), 10);
     assert_eq!(builder.get_min_significant_digits().unwrap().get(), 5);
@@ -83,15 +161,71 @@ fn builder_test() {
     assert_eq!(builder.get_negative_exponent_break().unwrap().get(), -9);
     assert_eq!(builder.get_round_mode(), options::RoundMode::Truncate);
     assert_eq!(builder.get_trim_floats(), true);
+    assert!(builder.get_lossy());
+    assert_eq!(builder.get_algorithm(), options::Algorithm::Lossy);
     assert_eq!(builder.get_exponent(), b'^');
     assert_eq!(builder.get_decimal_point(), b',');
     assert_eq!(builder.get_nan_string(), Some("nan".as_bytes()));
     assert_eq!(builder.get_inf_string(), Some("Infinity".as_bytes()));
+    assert_eq!(builder.get_exponent_string(), Some("*10^".as_bytes()));
+    assert_eq!(builder.get_min_exponent_digits().unwrap().get(), 2);
+    assert!(builder.get_trim_scientific_floats());
+    assert!(builder.get_positive_inf_sign());
+    assert_eq!(builder.get_negative_zero(), options::NegativeZero::Integer);
+    assert!(builder.get_flush_subnormals());
+    assert_eq!(builder.get_min_width().unwrap().get(), 12);
+    assert_eq!(builder.get_pad_char(), b' ');
+    assert_eq!(builder.get_alignment(), Alignment::Center);
+    assert!(builder.get_percent());
+    assert_eq!(builder.get_imaginary_unit(), b'j');
 
     assert!(builder.is_valid());
     assert_eq!(builder.build(), Ok(builder.build_unchecked()));
 }
 
+#[test]
+fn algorithm_test() {
+    let builder = OptionsBuilder::new().algorithm(options::Algorithm::Exact);
+    assert_eq!(builder.get_algorithm(), options::Algorithm::Exact);
+    assert!(!builder.get_lossy());
+    let builder = builder.lossy(false);
+    assert_eq!(builder.get_algorithm(), options::Algorithm::Exact);
+    let builder = builder.lossy(true);
+    assert_eq!(builder.get_algorithm(), options::Algorithm::Lossy);
+    let builder = builder.lossy(false);
+    assert_eq!(builder.get_algorithm(), options::Algorithm::Shortest);
+
+    let mut opts = Options::new();
+    assert_eq!(opts.algorithm(), options::Algorithm::Shortest);
+    opts.set_algorithm(options::Algorithm::Exact);
+    assert_eq!(opts.algorithm(), options::Algorithm::Exact);
+    opts.set_lossy(true);
+    assert_eq!(opts.algorithm(), options::Algorithm::Lossy);
+    opts.set_lossy(false);
+    assert_eq!(opts.algorithm(), options::Algorithm::Shortest);
+}
+
+#[test]
+fn digit_rounding_test() {
+    let builder = OptionsBuilder::new();
+    assert_eq!(builder.get_digit_rounding(), options::DigitRounding::HalfEven);
+    assert_eq!(builder.get_round_mode(), options::RoundMode::Round);
+    let builder = builder.round_mode(options::RoundMode::Truncate);
+    assert_eq!(builder.get_digit_rounding(), options::DigitRounding::TowardZero);
+    let builder = builder.digit_rounding(options::DigitRounding::HalfUp);
+    assert_eq!(builder.get_digit_rounding(), options::DigitRounding::HalfUp);
+    assert_eq!(builder.get_round_mode(), options::RoundMode::Round);
+
+    let mut opts = Options::new();
+    assert_eq!(opts.digit_rounding(), options::DigitRounding::HalfEven);
+    opts.set_digit_rounding(options::DigitRounding::TowardZero);
+    assert_eq!(opts.round_mode(), options::RoundMode::Truncate);
+    opts.set_digit_rounding(options::DigitRounding::AwayFromZero);
+    assert_eq!(opts.round_mode(), options::RoundMode::Round);
+    opts.set_round_mode(options::RoundMode::Round);
+    assert_eq!(opts.digit_rounding(), options::DigitRounding::HalfEven);
+}
+
 #[test]
 fn options_test() {
     let mut opts = Options::new();
@@ -102,10 +236,19 @@ fn options_test() {
     opts.set_negative_exponent_break(num::NonZeroI32::new(-9));
     opts.set_round_mode(options::RoundMode::Truncate);
     opts.set_trim_floats(true);
+    opts.set_lossy(true);
     opts.set_exponent(b'^');
     opts.set_decimal_point(b',');
     opts.set_nan_string(Some(b"nan"));
     opts.set_inf_string(Some(b"Infinity"));
+    opts.set_exponent_string(Some(b"*10^"));
+    opts.set_min_exponent_digits(num::NonZeroUsize::new(2));
+    opts.set_trim_scientific_floats(true);
+    opts.set_positive_inf_sign(true);
+    opts.set_negative_zero(options::NegativeZero::Unsigned);
+    opts.set_flush_subnormals(true);
+    opts.set_percent(true);
+    opts.set_imaginary_unit(b'j');
 
     assert_eq!(opts.max_significant_digits().unwrap().get(), 10);
     assert_eq!(opts.min_significant_digits().unwrap().get(), 5);
@@ -113,12 +256,62 @@ fn options_test() {
     assert_eq!(opts.negative_exponent_break().unwrap().get(), -9);
     assert_eq!(opts.round_mode(), options::RoundMode::Truncate);
     assert_eq!(opts.trim_floats(), true);
+    assert!(opts.lossy());
+    assert_eq!(opts.algorithm(), options::Algorithm::Lossy);
     assert_eq!(opts.exponent(), b'^');
     assert_eq!(opts.decimal_point(), b',');
     assert_eq!(opts.nan_string(), Some("nan".as_bytes()));
     assert_eq!(opts.inf_string(), Some("Infinity".as_bytes()));
+    assert_eq!(opts.exponent_string(), Some("*10^".as_bytes()));
+    assert_eq!(opts.min_exponent_digits().unwrap().get(), 2);
+    assert!(opts.trim_scientific_floats());
+    assert!(opts.positive_inf_sign());
+    assert_eq!(opts.negative_zero(), options::NegativeZero::Unsigned);
+    assert!(opts.flush_subnormals());
+    assert!(opts.percent());
+    assert_eq!(opts.imaginary_unit(), b'j');
     assert!(opts.is_valid());
 
     assert_eq!(Options::builder(), OptionsBuilder::new());
     assert_eq!(opts.rebuild().build(), Ok(opts));
 }
+
+#[test]
+fn buffer_size_const_test() {
+    use lexical_util::options::WriteOptions;
+    use lexical_write_float::format::STANDARD;
+
+    const OPTIONS: Options = Options::new();
+    const SIZE: usize = OPTIONS.buffer_size_const::<f64, { STANDARD }>();
+    let buffer = [0u8; SIZE];
+    assert_eq!(buffer.len(), OPTIONS.buffer_size::<f64, { STANDARD }>());
+
+    let options = Options::builder()
+        .max_significant_digits(num::NonZeroUsize::new(5))
+        .positive_exponent_break(num::NonZeroI32::new(20))
+        .build()
+        .unwrap();
+    assert_eq!(options.buffer_size_const::<f64, { STANDARD }>(), 27);
+    let options =
+        Options::builder().min_significant_digits(num::NonZeroUsize::new(50)).build().unwrap();
+    assert_eq!(options.buffer_size_const::<f64, { STANDARD }>(), SIZE + 22);
+    let options = Options::builder().exponent_string(Some(b"*10^")).build().unwrap();
+    assert_eq!(options.buffer_size_const::<f64, { STANDARD }>(), SIZE + 3);
+    let options =
+        Options::builder().min_exponent_digits(num::NonZeroUsize::new(30)).build().unwrap();
+    assert_eq!(options.buffer_size_const::<f64, { STANDARD }>(), SIZE + 30);
+    let options = Options::builder().min_width(num::NonZeroUsize::new(SIZE + 8)).build().unwrap();
+    assert_eq!(options.buffer_size_const::<f64, { STANDARD }>(), SIZE + 8);
+    let options = Options::builder().min_width(num::NonZeroUsize::new(8)).build().unwrap();
+    assert_eq!(options.buffer_size_const::<f64, { STANDARD }>(), SIZE);
+    let options = Options::builder().percent(true).build().unwrap();
+    assert_eq!(options.buffer_size_const::<f64, { STANDARD }>(), SIZE + 3);
+}
+
+#[cfg(feature = "proptest")]
+proptest::proptest! {
+    #[test]
+    fn arbitrary_options_proptest(options in proptest::arbitrary::any::<Options>()) {
+        proptest::prop_assert!(options.is_valid());
+    }
+}
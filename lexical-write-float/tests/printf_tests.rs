@@ -0,0 +1,400 @@
+#![allow(clippy::disallowed_macros)]
+
+mod util;
+
+use core::num;
+
+use lexical_util::constants::BUFFER_SIZE;
+use lexical_util::error::Error;
+use lexical_util::format::STANDARD;
+use lexical_write_float::{
+    exponential_buffer_size,
+    fixed_buffer_size,
+    write_exponential,
+    write_exponential_with_rounding,
+    write_fixed,
+    write_fixed_with_rounding,
+    write_printf,
+    Algorithm,
+    DigitRounding,
+    Options,
+    PrintfSpec,
+    RoundMode,
+    ToLexicalWithOptions,
+};
+use proptest::prelude::*;
+
+use crate::util::default_proptest_config;
+
+fn fixed(float: f64, precision: usize) -> String {
+    let mut buffer = vec![0u8; fixed_buffer_size(precision)];
+    String::from_utf8(write_fixed(float, precision, &mut buffer).to_vec()).unwrap()
+}
+
+fn exponential(float: f64, precision: usize) -> String {
+    let mut buffer = vec![0u8; exponential_buffer_size(precision)];
+    String::from_utf8(write_exponential(float, precision, &mut buffer).to_vec()).unwrap()
+}
+
+fn printf(spec: &str, float: f64) -> String {
+    let spec = PrintfSpec::parse(spec.as_bytes()).unwrap();
+    let mut buffer = vec![0u8; spec.buffer_size()];
+    String::from_utf8(spec.write(float, &mut buffer).to_vec()).unwrap()
+}
+
+fn write_with<T: ToLexicalWithOptions<Options = Options>>(float: T, options: &Options) -> String {
+    let mut buffer = vec![0u8; options.buffer_size_const::<T, STANDARD>()];
+    let bytes = float.to_lexical_with_options::<STANDARD>(&mut buffer, options);
+    String::from_utf8(bytes.to_vec()).unwrap()
+}
+
+fn exact_options(max_digits: usize) -> Options {
+    Options::builder()
+        .algorithm(Algorithm::Exact)
+        .max_significant_digits(num::NonZeroUsize::new(max_digits))
+        .build()
+        .unwrap()
+}
+
+/// Get the significant digits, without leading or trailing zeros, and the
+/// scientific exponent of a formatted float.
+fn significant_digits(formatted: &str) -> (String, i32) {
+    let (mantissa, exponent) = match formatted.split_once('e') {
+        Some((mantissa, exponent)) => (mantissa, exponent.parse::<i32>().unwrap()),
+        None => (formatted, 0),
+    };
+    let (integer, fraction) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+    let digits = format!("{}{}", integer, fraction);
+    let leading = digits.len() - digits.trim_start_matches('0').len();
+    let significant = digits.trim_start_matches('0').trim_end_matches('0');
+    (significant.to_string(), exponent + integer.len() as i32 - 1 - leading as i32)
+}
+
+/// Format like `printf`, from the exact formatting in Rust's core library.
+fn expected_exponential(float: f64, precision: usize) -> String {
+    let formatted = format!("{:.*e}", precision, float);
+    let (mantissa, exponent) = formatted.split_once('e').unwrap();
+    let exponent = exponent.parse::<i32>().unwrap();
+    let sign = if exponent < 0 {
+        '-'
+    } else {
+        '+'
+    };
+    format!("{}e{}{:02}", mantissa, sign, exponent.unsigned_abs())
+}
+
+#[test]
+fn fixed_test() {
+    assert_eq!(fixed(0.0, 0), "0");
+    assert_eq!(fixed(0.0, 3), "0.000");
+    assert_eq!(fixed(-0.0, 1), "-0.0");
+    assert_eq!(fixed(1.0, 0), "1");
+    assert_eq!(fixed(0.5, 0), "0");
+    assert_eq!(fixed(1.5, 0), "2");
+    assert_eq!(fixed(2.5, 0), "2");
+    assert_eq!(fixed(0.125, 2), "0.12");
+    assert_eq!(fixed(0.375, 2), "0.38");
+    assert_eq!(fixed(0.3, 17), "0.29999999999999999");
+    assert_eq!(fixed(0.3, 18), "0.299999999999999989");
+    assert_eq!(fixed(-99.99, 1), "-100.0");
+    assert_eq!(fixed(1e22, 1), "10000000000000000000000.0");
+    assert_eq!(fixed(f64::NAN, 2), "NaN");
+    assert_eq!(fixed(f64::INFINITY, 2), "inf");
+    assert_eq!(fixed(f64::NEG_INFINITY, 2), "-inf");
+    assert_eq!(fixed(f64::MAX, 0), format!("{:.0}", f64::MAX));
+    assert_eq!(fixed(-f64::MAX, 3), format!("{:.3}", -f64::MAX));
+
+    // The exact expansion, and then zeros.
+    let denormal = f64::from_bits(1);
+    assert_eq!(fixed(denormal, 1074), format!("{:.1074}", denormal));
+    assert_eq!(fixed(denormal, 1100), format!("{:.1100}", denormal));
+    assert_eq!(fixed(denormal, 1073), format!("{:.1073}", denormal));
+    assert_eq!(fixed(denormal, 323), format!("0.{}", "0".repeat(323)));
+    assert_eq!(fixed(denormal, 324), format!("0.{}5", "0".repeat(323)));
+
+    let mut buffer = [0u8; 4];
+    assert_eq!(write_fixed(1.5f32, 1, &mut buffer), b"1.5");
+    assert_eq!(write_fixed(0.1f32, 2, &mut buffer), b"0.10");
+}
+
+#[test]
+#[should_panic]
+fn fixed_buffer_test() {
+    let mut buffer = [0u8; 4];
+    write_fixed(1.5f64, 3, &mut buffer);
+}
+
+#[test]
+fn exponential_test() {
+    assert_eq!(exponential(0.0, 0), "0e+00");
+    assert_eq!(exponential(0.0, 2), "0.00e+00");
+    assert_eq!(exponential(1.0, 0), "1e+00");
+    assert_eq!(exponential(25.0, 0), "2e+01");
+    assert_eq!(exponential(35.0, 0), "4e+01");
+    assert_eq!(exponential(9.5, 0), "1e+01");
+    assert_eq!(exponential(0.001234, 2), "1.23e-03");
+    assert_eq!(exponential(-1.5e300, 3), "-1.500e+300");
+    assert_eq!(exponential(f64::MAX, 16), "1.7976931348623157e+308");
+    assert_eq!(exponential(f64::MIN_POSITIVE, 5), "2.22507e-308");
+    assert_eq!(exponential(f64::from_bits(1), 0), "5e-324");
+    assert_eq!(exponential(f64::NAN, 2), "NaN");
+    assert_eq!(exponential(f64::NEG_INFINITY, 2), "-inf");
+
+    // The exact expansion, and then zeros.
+    let denormal = f64::from_bits(1);
+    assert_eq!(exponential(denormal, 750), expected_exponential(denormal, 750));
+    assert_eq!(exponential(denormal, 800), expected_exponential(denormal, 800));
+    assert_eq!(exponential(f64::MAX, 400), expected_exponential(f64::MAX, 400));
+
+    let mut buffer = [0u8; 9];
+    assert_eq!(write_exponential(1.5f32, 1, &mut buffer), b"1.5e+00");
+}
+
+#[test]
+fn exact_algorithm_test() {
+    let shortest =
+        Options::builder().max_significant_digits(num::NonZeroUsize::new(1)).build().unwrap();
+    let mut buffer = [0u8; BUFFER_SIZE];
+    assert_eq!(0.15f64.to_lexical_with_options::<STANDARD>(&mut buffer, &shortest), b"0.2");
+    assert_eq!(write_with(0.15f64, &exact_options(1)), "0.1");
+    assert_eq!(write_with(2.675f64, &exact_options(3)), "2.67");
+    assert_eq!(write_with(2.5f64, &exact_options(1)), "2.0");
+    assert_eq!(write_with(9.96f64, &exact_options(2)), "10.0");
+    assert_eq!(write_with(0.3f64, &exact_options(17)), "0.29999999999999999");
+    assert_eq!(write_with(0.3f64, &exact_options(30)), "0.299999999999999988897769753748");
+    assert_eq!(write_with(0.1f32, &exact_options(10)), "0.1000000015");
+    assert_eq!(write_with(1e23f64, &exact_options(25)), "9.9999999999999991611392e22");
+    assert_eq!(write_with(0.0f64, &exact_options(5)), "0.0");
+    assert_eq!(write_with(-0.5f64, &exact_options(5)), "-0.5");
+    assert_eq!(write_with(f64::NAN, &exact_options(5)), "NaN");
+
+    // Truncate the exact digits.
+    let options = exact_options(3).rebuild().round_mode(RoundMode::Truncate).build().unwrap();
+    assert_eq!(write_with(2.679f64, &options), "2.67");
+    assert_eq!(write_with(0.3f64, &options), "0.299");
+
+    // Round the exact digits with ties away from zero.
+    let options = exact_options(2).rebuild().digit_rounding(DigitRounding::HalfUp).build().unwrap();
+    assert_eq!(write_with(0.125f64, &options), "0.13");
+    assert_eq!(write_with(-0.125f64, &options), "-0.13");
+    assert_eq!(write_with(0.135f64, &options), "0.14");
+
+    // Pad with trailing zeros.
+    let options = exact_options(5)
+        .rebuild()
+        .min_significant_digits(num::NonZeroUsize::new(5))
+        .build()
+        .unwrap();
+    assert_eq!(write_with(0.5f64, &options), "0.50000");
+
+    // Without a maximum, this is the shortest representation.
+    let options = Options::builder().algorithm(Algorithm::Exact).build().unwrap();
+    assert_eq!(write_with(0.3f64, &options), "0.3");
+
+    // Every digit of the exact value.
+    let denormal = f64::from_bits(1);
+    let expected = significant_digits(&format!("{:.766e}", denormal));
+    assert_eq!(significant_digits(&write_with(denormal, &exact_options(800))), expected);
+    let expected = significant_digits(&format!("{:.766e}", f64::MAX));
+    assert_eq!(significant_digits(&write_with(f64::MAX, &exact_options(767))), expected);
+}
+
+// The expected strings are from glibc's `snprintf`.
+#[test]
+fn printf_test() {
+    assert_eq!(printf("%f", 1.5), "1.500000");
+    assert_eq!(printf("%lf", -123.456), "-123.456000");
+    assert_eq!(printf("%.0f", 2.5), "2");
+    assert_eq!(printf("%#.0f", 2.5), "2.");
+    assert_eq!(printf("%#.0f", -0.0), "-0.");
+    assert_eq!(printf("%10.3f", 1.5), "     1.500");
+    assert_eq!(printf("%-10.3f", 1.5), "1.500     ");
+    assert_eq!(printf("%010.3f", -123.456), "-00123.456");
+    assert_eq!(printf("%+f", 0.0001), "+0.000100");
+    assert_eq!(printf("% f", 1.5), " 1.500000");
+    assert_eq!(printf("%+ f", 1.5), "+1.500000");
+    assert_eq!(printf("%F", 1.5), "1.500000");
+
+    assert_eq!(printf("%e", 1234567.0), "1.234567e+06");
+    assert_eq!(printf("%.0e", 2.5), "2e+00");
+    assert_eq!(printf("%#.0e", -123.456), "-1.e+02");
+    assert_eq!(printf("%E", 1e100), "1.000000E+100");
+    assert_eq!(printf("%-+12.4e", 5e-324), "+4.9407e-324");
+    assert_eq!(printf("%-+12.4e", 1.5), "+1.5000e+00 ");
+    assert_eq!(printf("%012.4e", -1.9999), "-01.9999e+00");
+
+    assert_eq!(printf("%g", 0.0), "0");
+    assert_eq!(printf("%g", -0.0), "-0");
+    assert_eq!(printf("%g", 0.0001), "0.0001");
+    assert_eq!(printf("%g", 1e-5), "1e-05");
+    assert_eq!(printf("%g", 123456.0), "123456");
+    assert_eq!(printf("%g", 1234567.0), "1.23457e+06");
+    assert_eq!(printf("%g", 5e-324), "4.94066e-324");
+    assert_eq!(printf("%.0g", 2.5), "2");
+    assert_eq!(printf("%.3g", -123.456), "-123");
+    assert_eq!(printf("%.3g", 1.9999), "2");
+    assert_eq!(printf("%#g", 123456.0), "123456.");
+    assert_eq!(printf("%#g", 0.0001), "0.000100000");
+    assert_eq!(printf("%#.3g", 1e100), "1.00e+100");
+    assert_eq!(printf("%G", 1234567.0), "1.23457E+06");
+    assert_eq!(printf("%010g", 1e100), "00001e+100");
+    assert_eq!(printf("%010g", -0.0), "-000000000");
+
+    // Special values are never padded with zeros.
+    assert_eq!(printf("%010.3f", f64::INFINITY), "       inf");
+    assert_eq!(printf("%+e", f64::NAN), "+nan");
+    assert_eq!(printf("%-5g", f64::NEG_INFINITY), "-inf ");
+    assert_eq!(printf("%G", f64::NAN), "NAN");
+    assert_eq!(printf("%#A", f64::INFINITY), "INF");
+
+    let mut buffer = [0u8; 16];
+    assert_eq!(write_printf(b"%.2f", 0.1f32, &mut buffer).unwrap(), b"0.10");
+    assert_eq!(write_printf(b"%.1e", 0.1f32, &mut buffer).unwrap(), b"1.0e-01");
+}
+
+// The expected strings are from glibc's `snprintf`.
+#[test]
+fn printf_hex_test() {
+    assert_eq!(printf("%a", 0.0), "0x0p+0");
+    assert_eq!(printf("%a", -0.0), "-0x0p+0");
+    assert_eq!(printf("%a", 1.0), "0x1p+0");
+    assert_eq!(printf("%a", 2.5), "0x1.4p+1");
+    assert_eq!(printf("%a", 0.1), "0x1.999999999999ap-4");
+    assert_eq!(printf("%a", -123.456), "-0x1.edd2f1a9fbe77p+6");
+    assert_eq!(printf("%a", 1e100), "0x1.249ad2594c37dp+332");
+    assert_eq!(printf("%a", f64::MIN_POSITIVE), "0x1p-1022");
+    assert_eq!(printf("%a", 5e-324), "0x0.0000000000001p-1022");
+    assert_eq!(printf("%A", 0.1), "0X1.999999999999AP-4");
+    assert_eq!(printf("%#a", 0.0), "0x0.p+0");
+    assert_eq!(printf("%#a", f64::MIN_POSITIVE), "0x1.p-1022");
+
+    // Round to the nearest, tie-even, which may carry to the leading digit.
+    assert_eq!(printf("%.0a", 1.5), "0x2p+0");
+    assert_eq!(printf("%.0a", 0.1), "0x2p-4");
+    assert_eq!(printf("%.1a", 0.1), "0x1.ap-4");
+    assert_eq!(printf("%.1a", -123.456), "-0x1.fp+6");
+    assert_eq!(printf("%.1a", 1.9999), "0x2.0p+0");
+    assert_eq!(printf("%.3a", 0.1), "0x1.99ap-4");
+    assert_eq!(printf("%.3a", 5e-324), "0x0.000p-1022");
+    assert_eq!(printf("%.15a", -123.456), "-0x1.edd2f1a9fbe7700p+6");
+    assert_eq!(printf("%.15a", 5e-324), "0x0.000000000000100p-1022");
+
+    // Zeros are padded after the prefix.
+    assert_eq!(printf("%020a", 1.5), "0x0000000000001.8p+0");
+    assert_eq!(printf("%020a", -0.0), "-0x00000000000000p+0");
+    assert_eq!(printf("%20a", 1.5), "            0x1.8p+0");
+}
+
+#[test]
+fn printf_spec_test() {
+    let spec = PrintfSpec::parse(b"%-+#012.4le").unwrap();
+    assert_eq!(spec.width(), 12);
+    assert_eq!(spec.precision(), Some(4));
+    assert_eq!(spec.conversion(), b'e');
+    assert_eq!(PrintfSpec::parse(b"%.g").unwrap().precision(), Some(0));
+    assert_eq!(PrintfSpec::parse(b"%f").unwrap().precision(), None);
+
+    // The buffer is large enough for any float, and the width.
+    assert_eq!(PrintfSpec::parse(b"%.3f").unwrap().buffer_size(), fixed_buffer_size(3) + 1);
+    assert_eq!(PrintfSpec::parse(b"%500e").unwrap().buffer_size(), 500);
+    assert_eq!(printf("%.1000f", 5e-324), format!("{:.1000}", 5e-324));
+    assert_eq!(printf("%#.0f", -f64::MAX), format!("{:.0}.", -f64::MAX));
+    assert_eq!(printf("%#.5g", -1e-300), "-1.0000e-300");
+    assert_eq!(printf("%+#.20a", -5e-324), "-0x0.00000000000010000000p-1022");
+
+    let invalid: [&[u8]; 12] =
+        [b"", b"f", b"%", b"%d", b"%.", b"%5", b"%*f", b"%.*f", b"%hf", b"%llf", b"%f ", b"x%f"];
+    for spec in invalid {
+        assert_eq!(PrintfSpec::parse(spec), Err(Error::InvalidPrintfSpec));
+    }
+    assert_eq!(PrintfSpec::parse(b"%99999999999999999999999f"), Err(Error::InvalidPrintfSpec));
+    let mut buffer = [0u8; 16];
+    assert_eq!(write_printf(b"%s", 1.0f64, &mut buffer), Err(Error::InvalidPrintfSpec));
+}
+
+proptest! {
+    #![proptest_config(default_proptest_config())]
+
+    #[test]
+    fn f64_fixed_proptest(f in f64::MIN..f64::MAX, precision in 0usize..40) {
+        prop_assert_eq!(fixed(f, precision), format!("{:.*}", precision, f));
+    }
+
+    #[test]
+    fn f64_fixed_small_proptest(f in -1e-5f64..1e-5, precision in 300usize..400) {
+        prop_assert_eq!(fixed(f, precision), format!("{:.*}", precision, f));
+    }
+
+    #[test]
+    fn f64_exponential_proptest(f in f64::MIN..f64::MAX, precision in 0usize..40) {
+        prop_assert_eq!(exponential(f, precision), expected_exponential(f, precision));
+    }
+
+    #[test]
+    fn f64_exponential_bits_proptest(bits in 0u64..0x7FF0_0000_0000_0000, precision in 0usize..800) {
+        let f = f64::from_bits(bits);
+        prop_assert_eq!(exponential(f, precision), expected_exponential(f, precision));
+    }
+
+    #[test]
+    fn f64_exact_algorithm_proptest(bits in 1u64..0x7FF0_0000_0000_0000, digits in 1usize..40) {
+        let f = f64::from_bits(bits);
+        let expected = significant_digits(&format!("{:.*e}", digits - 1, f));
+        prop_assert_eq!(significant_digits(&write_with(f, &exact_options(digits))), expected);
+    }
+
+    #[test]
+    fn f64_printf_proptest(f in f64::MIN..f64::MAX, precision in 0usize..40) {
+        prop_assert_eq!(printf(&format!("%.{}f", precision), f), format!("{:.*}", precision, f));
+        prop_assert_eq!(printf(&format!("%.{}e", precision), f), expected_exponential(f, precision));
+    }
+
+    #[test]
+    fn f32_fixed_proptest(f in f32::MIN..f32::MAX, precision in 0usize..60) {
+        prop_assert_eq!(fixed(f as f64, precision), format!("{:.*}", precision, f));
+    }
+}
+
+#[test]
+fn digit_rounding_test() {
+    let fixed = |float: f64, precision: usize, rounding: DigitRounding| {
+        let mut buffer = vec![0u8; fixed_buffer_size(precision)];
+        let bytes = write_fixed_with_rounding(float, precision, rounding, &mut buffer);
+        String::from_utf8(bytes.to_vec()).unwrap()
+    };
+    let exponential = |float: f64, precision: usize, rounding: DigitRounding| {
+        let mut buffer = vec![0u8; exponential_buffer_size(precision)];
+        let bytes = write_exponential_with_rounding(float, precision, rounding, &mut buffer);
+        String::from_utf8(bytes.to_vec()).unwrap()
+    };
+
+    // Exact ties, which are representable in binary.
+    assert_eq!(fixed(0.125, 2, DigitRounding::HalfEven), "0.12");
+    assert_eq!(fixed(0.125, 2, DigitRounding::HalfUp), "0.13");
+    assert_eq!(fixed(0.125, 2, DigitRounding::HalfDown), "0.12");
+    assert_eq!(fixed(0.375, 2, DigitRounding::HalfEven), "0.38");
+    assert_eq!(fixed(0.375, 2, DigitRounding::HalfDown), "0.37");
+    assert_eq!(fixed(-2.5, 0, DigitRounding::HalfUp), "-3");
+    assert_eq!(fixed(-2.5, 0, DigitRounding::HalfDown), "-2");
+
+    // Inexact values, where only the directed modes differ.
+    assert_eq!(fixed(2.675, 2, DigitRounding::HalfUp), "2.67");
+    assert_eq!(fixed(2.675, 2, DigitRounding::AwayFromZero), "2.68");
+    assert_eq!(fixed(2.679, 2, DigitRounding::TowardZero), "2.67");
+    assert_eq!(fixed(-2.679, 2, DigitRounding::TowardZero), "-2.67");
+    assert_eq!(fixed(-2.671, 2, DigitRounding::AwayFromZero), "-2.68");
+    assert_eq!(fixed(2.0, 2, DigitRounding::AwayFromZero), "2.00");
+    assert_eq!(fixed(0.0, 2, DigitRounding::AwayFromZero), "0.00");
+    assert_eq!(fixed(999.01, 0, DigitRounding::AwayFromZero), "1000");
+
+    assert_eq!(exponential(1234.5, 3, DigitRounding::HalfEven), "1.234e+03");
+    assert_eq!(exponential(1234.5, 3, DigitRounding::HalfUp), "1.235e+03");
+    assert_eq!(exponential(1235.5, 3, DigitRounding::HalfDown), "1.235e+03");
+    assert_eq!(exponential(9999.1, 2, DigitRounding::AwayFromZero), "1.00e+04");
+    assert_eq!(exponential(9999.9, 2, DigitRounding::TowardZero), "9.99e+03");
+    assert_eq!(exponential(f64::NAN, 2, DigitRounding::HalfUp), "NaN");
+
+    // The default rounding mode is the same as `write_fixed`.
+    assert_eq!(fixed(2.5, 0, DigitRounding::HalfEven), self::fixed(2.5, 0));
+    assert_eq!(exponential(1.25, 1, DigitRounding::HalfEven), self::exponential(1.25, 1));
+}
@@ -9,7 +9,7 @@ use approx::{assert_relative_eq, relative_eq};
 use lexical_util::constants::{FormattedSize, BUFFER_SIZE};
 use lexical_util::format::NumberFormatBuilder;
 use lexical_util::num::Float;
-use lexical_write_float::options::RoundMode;
+use lexical_write_float::options::{DigitRounding, RoundMode};
 use lexical_write_float::{radix, Options};
 use lexical_write_integer::write::WriteInteger;
 use parse_radix::{parse_f32, parse_f64};
@@ -116,10 +116,10 @@ fn write_float_test() {
     write_float::<_, BASE3>(
         1.7976931348623157e+308f64,
         &options,
-        "1.0020200012020012100112000100111212e212221",
+        "1.0020200012020012100112000100111021e212221",
     );
     // Adapted from bugs in quickcheck.
-    write_float::<_, BASE3>(3.4028235e+38f32, &options, "2.022011021210002e2222");
+    write_float::<_, BASE3>(3.4028235e+38f32, &options, "2.022011021210012e2222");
 
     // Try changing the exponent limits.
     let options = Options::builder()
@@ -188,16 +188,8 @@ fn write_float_test() {
         &options,
         "0.0222222222222222212010101201000200000000000000000",
     );
-    write_float::<_, BASE3>(
-        12157665459056928801.0f64,
-        &options,
-        "2.2222222222222222222222222222222220000000000000000e1110",
-    );
-    write_float::<_, BASE3>(
-        8.225263339969959e-20f64,
-        &options,
-        "2.2222222222222222222222222222222020000000000000000e-1112",
-    );
+    write_float::<_, BASE3>(12157665459056928801.0f64, &options, "1e1111");
+    write_float::<_, BASE3>(8.225263339969959e-20f64, &options, "1e-1111");
 
     // Check carry.
     let options =
@@ -233,6 +225,32 @@ fn write_float_test() {
         .unwrap();
     write_float::<_, BASE3>(23.45678901234567890f64, &round, "220.0");
     write_float::<_, BASE3>(23.45678901234567890f64, &truncate, "210.0");
+
+    let options = |rounding| {
+        Options::builder()
+            .max_significant_digits(num::NonZeroUsize::new(2))
+            .digit_rounding(rounding)
+            .build()
+            .unwrap()
+    };
+    write_float::<_, BASE3>(4.0f64, &options(DigitRounding::HalfEven), "11.0");
+    write_float::<_, BASE3>(4.0f64, &options(DigitRounding::AwayFromZero), "11.0");
+    write_float::<_, BASE3>(4.5f64, &options(DigitRounding::HalfUp), "12.0");
+    write_float::<_, BASE3>(4.5f64, &options(DigitRounding::HalfDown), "12.0");
+    write_float::<_, BASE3>(4.25f64, &options(DigitRounding::HalfDown), "11.0");
+    write_float::<_, BASE3>(4.25f64, &options(DigitRounding::AwayFromZero), "12.0");
+
+    // Check max digits only counts the significant digits.
+    let round =
+        Options::builder().max_significant_digits(num::NonZeroUsize::new(1)).build().unwrap();
+    let truncate = Options::builder()
+        .max_significant_digits(num::NonZeroUsize::new(7))
+        .round_mode(RoundMode::Truncate)
+        .build()
+        .unwrap();
+    write_float::<_, BASE3>(0.027036422487700867f64, &round, "0.0002");
+    write_float::<_, BASE3>(0.23712215715048218f64, &truncate, "0.02010121");
+    write_float::<_, BASE3>(427.046875f64, &truncate, "120211.0");
 }
 
 macro_rules! test_radix {
@@ -342,7 +360,32 @@ fn base21_test() {
         .unwrap();
     let f = 48205284000000000000000000000000000000.0f32;
     let count = radix::write_float::<_, BASE21>(f, &mut buffer, &options);
-    assert_eq!(b"4C440700000000000000000000000.0", &buffer[..count]);
+    assert_eq!(b"4C441800000000000000000000000.0", &buffer[..count]);
+}
+
+#[test]
+fn lossy_test() {
+    // The naive algorithm accumulates rounding error, which can write
+    // digits above the halfway point to the next float.
+    let options = Options::builder().lossy(true).build().unwrap();
+    write_float::<_, BASE3>(0.5f64, &options, "0.1111111111111111111111111111111112");
+    write_float::<_, BASE3>(
+        1.7976931348623157e+308f64,
+        &options,
+        "1.0020200012020012100112000100111212e212221",
+    );
+
+    let options = Options::builder()
+        .lossy(true)
+        .min_significant_digits(num::NonZeroUsize::new(15))
+        .positive_exponent_break(num::NonZeroI32::new(0x1000))
+        .build()
+        .unwrap();
+    write_float::<_, BASE21>(
+        48205284000000000000000000000000000000.0f32,
+        &options,
+        "4C440700000000000000000000000.0",
+    );
 }
 
 //  NOTE:
@@ -19,7 +19,7 @@ use lexical_util::digit::digit_to_char;
 use lexical_util::div128::u128_divrem;
 use lexical_util::format::{radix_from_flags, NumberFormat};
 use lexical_util::num::{AsCast, UnsignedInteger};
-use lexical_util::step::u64_step;
+use lexical_util::step::{u32_step, u64_step};
 
 use crate::digit_count::DigitCount;
 
@@ -31,6 +31,7 @@ use crate::digit_count::DigitCount;
 /// By default, writers tend to be safe, due to Miri, Valgrind,
 /// and other tests and careful validation against a wide range
 /// of randomized input. Parsers are much trickier to validate.
+#[cfg(not(feature = "safe"))]
 #[allow(unknown_lints, unused_macro_rules)]
 macro_rules! i {
     ($x:ident[$i:expr]) => {
@@ -42,6 +43,19 @@ macro_rules! i {
     };
 }
 
+/// Index a buffer and get a mutable reference, with bounds checking.
+#[cfg(feature = "safe")]
+#[allow(unknown_lints, unused_macro_rules)]
+macro_rules! i {
+    ($x:ident[$i:expr]) => {
+        $x[$i]
+    };
+
+    ($x:ident[$i:expr] = $y:ident[$j:expr]) => {
+        $x[$i] = $y[$j]
+    };
+}
+
 /// Write 2 digits to buffer.
 ///
 /// # Safety
@@ -208,6 +222,39 @@ unsafe fn write_step_digits<T: UnsignedInteger>(
     end
 }
 
+/// Write a 64-bit value in 32-bit chunks of digits.
+///
+/// 64-bit division is emulated on 32-bit targets, so this only divides
+/// as a `u64` to split off each chunk, and writes the digits of each chunk
+/// using 32-bit arithmetic.
+///
+/// # Safety
+///
+/// This is safe as long as the buffer is large enough to hold `u64::MAX`
+/// digits in radix `N` and the index >= digit count. See [algorithm] for
+/// more safety considerations.
+#[inline(always)]
+#[cfg_attr(all(target_pointer_width = "64", not(target_arch = "sparc")), allow(dead_code))]
+unsafe fn write_digits_u32_chunks(
+    mut value: u64,
+    radix: u32,
+    table: &[u8],
+    buffer: &mut [u8],
+    mut index: usize,
+    count: usize,
+) -> usize {
+    let step = u32_step(radix);
+    let divisor = (radix as u64).pow(step as u32);
+    while value > u32::MAX as u64 {
+        let rem = (value % divisor) as u32;
+        value /= divisor;
+        // SAFETY: safe as long as the call to `write_digits_u32_chunks` is safe.
+        index = unsafe { write_step_digits(rem, radix, table, buffer, index, step, count) };
+    }
+    // SAFETY: safe as long as the call to `write_digits_u32_chunks` is safe.
+    unsafe { write_digits(value as u32, radix, table, buffer, index, count) }
+}
+
 /// Optimized implementation for radix-N numbers.
 ///
 /// This uses an Alexandrescu algorithm, which prints 2 digits at a time
@@ -256,6 +303,14 @@ where
     // The buffer is ensured to have at least `FORMATTED_SIZE` or
     // `FORMATTED_SIZE_DECIMAL` characters, which is the maximum number of
     // digits an integer of that size may write.
+    #[cfg(not(all(target_pointer_width = "64", not(target_arch = "sparc"))))]
+    if T::BITS > 32 {
+        debug_assert!(T::BITS <= 64);
+        _ = unsafe { write_digits_u32_chunks(value.as_u64(), radix, table, buffer, count, count) };
+        return count;
+    }
+
+    // SAFETY: See above.
     _ = unsafe { write_digits(value, radix, table, buffer, buffer.len(), count) };
 
     count
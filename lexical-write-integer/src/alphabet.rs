@@ -0,0 +1,46 @@
+//! Integer-to-string conversion using a custom digit alphabet.
+//!
+//! These routines are not optimized: each loop generates a single digit,
+//! since the radix is only known at runtime and may be larger than 36.
+
+#![doc(hidden)]
+
+use lexical_util::alphabet::DigitAlphabet;
+use lexical_util::num::{AsCast, UnsignedInteger};
+
+/// Calculate the number of digits required to write the value.
+#[inline]
+pub fn digit_count<T: UnsignedInteger>(value: T, alphabet: &DigitAlphabet) -> usize {
+    let radix = T::from_u32(alphabet.radix());
+    let mut value = value;
+    let mut count = 1;
+    while value >= radix {
+        value /= radix;
+        count += 1;
+    }
+    count
+}
+
+/// Write the value to the buffer using the alphabet's digits.
+///
+/// Returns the number of digits written.
+///
+/// # Panics
+///
+/// Panics if the buffer cannot hold [`digit_count`] digits.
+#[inline]
+pub fn write_digits<T: UnsignedInteger>(
+    value: T,
+    alphabet: &DigitAlphabet,
+    buffer: &mut [u8],
+) -> usize {
+    let count = digit_count(value, alphabet);
+    let bytes = &mut buffer[..count];
+    let radix = T::from_u32(alphabet.radix());
+    let mut value = value;
+    for byte in bytes.iter_mut().rev() {
+        *byte = alphabet.digit_to_char(u32::as_cast(value % radix));
+        value /= radix;
+    }
+    count
+}
@@ -2,13 +2,142 @@
 
 #![doc(hidden)]
 
-use lexical_util::format::{NumberFormat, STANDARD};
+use core::num::NonZeroUsize;
+
+use lexical_util::format::{is_valid_radix, NumberFormat, STANDARD};
 use lexical_util::num::SignedInteger;
+use lexical_util::options::Alignment;
 use lexical_util::{to_lexical, to_lexical_with_options};
 
-use crate::options::Options;
+use crate::alphabet;
+use crate::options::{Options, STANDARD as STANDARD_OPTIONS};
 use crate::write::WriteInteger;
 
+// DIGIT COUNT
+
+/// Get the number of digits to write an unsigned integer in a radix.
+///
+/// This is the exact number of digits written for the value, without
+/// any sign or padding, so the digits can be written directly into an
+/// exactly-sized slice. The count is calculated from the number of
+/// leading zeros for powers of two and decimal integers, rather than by
+/// dividing by the radix for each digit.
+///
+/// # Panics
+///
+/// Panics if the radix is not supported by the enabled features: 10, and
+/// with `power-of-two`, 2, 4, 8, 16 and 32, and with `radix`, any radix
+/// from 2 to 36.
+///
+/// # Examples
+///
+/// ```rust
+/// use lexical_write_integer::digit_count;
+///
+/// assert_eq!(digit_count(0u32, 10), 1);
+/// assert_eq!(digit_count(12345u64, 10), 5);
+/// assert_eq!(digit_count(u128::MAX, 10), 39);
+/// assert_eq!(digit_count(i64::MIN.unsigned_abs(), 10), 19);
+/// ```
+#[inline]
+pub fn digit_count<T: WriteInteger>(value: T, radix: u32) -> usize {
+    assert!(is_valid_radix(radix), "radix is not supported by the enabled features");
+    value.digit_count(radix)
+}
+
+// PADDING
+
+/// Get the minimum width to pad to before the number.
+///
+/// Only right alignment pads before the number while writing it: left
+/// and centered alignment are applied by [`align`] afterwards.
+#[cfg_attr(not(feature = "compact"), inline(always))]
+fn right_width(options: &Options) -> usize {
+    match options.alignment() {
+        Alignment::Right => options.min_width().map_or(0, |x| x.get()),
+        _ => 0,
+    }
+}
+
+/// Write the sign and any padding required to reach the minimum width.
+///
+/// Returns the index where the significant digits start. Padding with `0`
+/// is placed after the sign, while any other pad character is placed
+/// before it, so the padding is written in the same pass as the digits.
+#[cfg_attr(not(feature = "compact"), inline(always))]
+fn write_prefix(buffer: &mut [u8], sign: Option<u8>, digits: usize, options: &Options) -> usize {
+    let min_width = right_width(options);
+    let padding = min_width.saturating_sub(digits + sign.is_some() as usize);
+    let pad_char = options.pad_char();
+    let mut index = 0;
+    if padding != 0 && pad_char != b'0' {
+        buffer[..padding].fill(pad_char);
+        index += padding;
+    }
+    if let Some(sign) = sign {
+        buffer[index] = sign;
+        index += 1;
+    }
+    if padding != 0 && pad_char == b'0' {
+        buffer[index..index + padding].fill(b'0');
+        index += padding;
+    }
+    index
+}
+
+/// Write the sign, any padding, and the significant digits.
+///
+/// The digit writers may require up to `FORMATTED_SIZE` bytes after the
+/// start of the digits, more than the digits written. If there is padding,
+/// the digits are written to the start of the buffer and then moved after
+/// the padding, so a buffer of [`buffer_size`] bytes is always large enough.
+///
+/// [`buffer_size`]: lexical_util::options::WriteOptions::buffer_size
+#[cfg_attr(not(feature = "compact"), inline(always))]
+fn write_padded<Write>(
+    buffer: &mut [u8],
+    sign: Option<u8>,
+    digits: usize,
+    options: &Options,
+    write_digits: Write,
+) -> usize
+where
+    Write: FnOnce(&mut [u8]) -> usize,
+{
+    let min_width = right_width(options);
+    if min_width <= digits + sign.is_some() as usize {
+        let index = write_prefix(buffer, sign, digits, options);
+        return index + write_digits(&mut buffer[index..]);
+    }
+    let count = write_digits(buffer);
+    debug_assert_eq!(count, digits);
+    buffer.copy_within(..count, min_width - count);
+    write_prefix(buffer, sign, count, options);
+    min_width
+}
+
+/// Pad the written number to the minimum width with left or centered
+/// alignment.
+///
+/// Returns the number of bytes written, including the padding.
+#[cfg_attr(not(feature = "compact"), inline(always))]
+fn align(buffer: &mut [u8], count: usize, options: &Options) -> usize {
+    let min_width = options.min_width().map_or(0, |x| x.get());
+    if count >= min_width {
+        return count;
+    }
+    let before = match options.alignment() {
+        Alignment::Right => return count,
+        Alignment::Left => 0,
+        Alignment::Center => (min_width - count) / 2,
+    };
+    let pad_char = options.pad_char();
+    buffer.copy_within(..count, before);
+    buffer[..before].fill(pad_char);
+    buffer[before + count..min_width].fill(pad_char);
+    min_width
+}
+
 // UNSIGNED
 
 /// Callback for unsigned integer formatter.
@@ -16,20 +145,30 @@ use crate::write::WriteInteger;
 /// # Safety
 ///
 /// Safe as long as the buffer can hold `FORMATTED_SIZE` elements
-/// (or `FORMATTED_SIZE_DECIMAL` for decimal).
+/// (or `FORMATTED_SIZE_DECIMAL` for decimal), and at least the
+/// minimum width.
 #[cfg_attr(not(feature = "compact"), inline(always))]
-fn unsigned<T, const FORMAT: u128>(value: T, buffer: &mut [u8]) -> usize
+fn unsigned<T, const FORMAT: u128>(value: T, buffer: &mut [u8], options: &Options) -> usize
 where
     T: WriteInteger,
 {
     let format = NumberFormat::<FORMAT> {};
-    if cfg!(feature = "format") && format.required_mantissa_sign() {
-        buffer[0] = b'+';
-        let buffer = &mut buffer[1..];
-        value.write_mantissa::<FORMAT>(buffer) + 1
+    let sign = if cfg!(feature = "format") && format.required_mantissa_sign() {
+        Some(b'+')
     } else {
-        value.write_mantissa::<FORMAT>(buffer)
+        None
+    };
+    if let Some(alphabet) = options.alphabet() {
+        let digits = alphabet::digit_count(value, &alphabet);
+        let index = write_prefix(buffer, sign, digits, options);
+        return index + alphabet::write_digits(value, &alphabet, &mut buffer[index..]);
     }
+    let digits = if options.min_width().is_some() {
+        value.mantissa_digit_count::<FORMAT>()
+    } else {
+        0
+    };
+    write_padded(buffer, sign, digits, options, |bytes| value.write_mantissa::<FORMAT>(bytes))
 }
 
 // SIGNED
@@ -39,32 +178,86 @@ where
 /// # Safety
 ///
 /// Safe as long as the buffer can hold `FORMATTED_SIZE` elements
-/// (or `FORMATTED_SIZE_DECIMAL` for decimal).
+/// (or `FORMATTED_SIZE_DECIMAL` for decimal), and at least the
+/// minimum width.
 #[cfg_attr(not(feature = "compact"), inline(always))]
-fn signed<Signed, Unsigned, const FORMAT: u128>(value: Signed, buffer: &mut [u8]) -> usize
+fn signed<Signed, Unsigned, const FORMAT: u128>(
+    value: Signed,
+    buffer: &mut [u8],
+    options: &Options,
+) -> usize
 where
     Signed: SignedInteger,
     Unsigned: WriteInteger,
 {
     let format = NumberFormat::<FORMAT> {};
-    if value < Signed::ZERO {
+    if cfg!(feature = "format") && format.parenthesized_negatives() && value < Signed::ZERO {
+        return parenthesized::<Unsigned, FORMAT>(
+            Unsigned::as_cast(value.wrapping_neg()),
+            buffer,
+            options,
+        );
+    }
+    let (unsigned, sign) = if value < Signed::ZERO {
         // Need to cast the value to the same size as unsigned type, since if
         // the value is **exactly** `Narrow::MIN`, and it it is then cast
         // as the wrapping negative as the unsigned value, a wider type
         // will have a very different value.
-        let unsigned = Unsigned::as_cast(value.wrapping_neg());
-        buffer[0] = b'-';
-        let buffer = &mut buffer[1..];
-        unsigned.write_mantissa_signed::<FORMAT>(buffer) + 1
+        (Unsigned::as_cast(value.wrapping_neg()), Some(b'-'))
     } else if cfg!(feature = "format") && format.required_mantissa_sign() {
-        let unsigned = Unsigned::as_cast(value);
-        buffer[0] = b'+';
-        let buffer = &mut buffer[1..];
-        unsigned.write_mantissa_signed::<FORMAT>(buffer) + 1
+        (Unsigned::as_cast(value), Some(b'+'))
     } else {
-        let unsigned = Unsigned::as_cast(value);
-        unsigned.write_mantissa_signed::<FORMAT>(buffer)
+        (Unsigned::as_cast(value), None)
+    };
+    if let Some(alphabet) = options.alphabet() {
+        let digits = alphabet::digit_count(unsigned, &alphabet);
+        let index = write_prefix(buffer, sign, digits, options);
+        return index + alphabet::write_digits(unsigned, &alphabet, &mut buffer[index..]);
     }
+    let digits = if options.min_width().is_some() {
+        unsigned.mantissa_digit_count::<FORMAT>()
+    } else {
+        0
+    };
+    write_padded(buffer, sign, digits, options, |bytes| {
+        unsigned.write_mantissa_signed::<FORMAT>(bytes)
+    })
+}
+
+/// Write the absolute value of a negative integer in parentheses.
+///
+/// The opening parenthesis is written as the sign, so the padding is
+/// placed like the padding for a `-` sign, and the closing parenthesis
+/// is written after the digits. The minimum width includes both
+/// parentheses.
+#[cfg_attr(not(feature = "compact"), inline(always))]
+fn parenthesized<Unsigned, const FORMAT: u128>(
+    unsigned: Unsigned,
+    buffer: &mut [u8],
+    options: &Options,
+) -> usize
+where
+    Unsigned: WriteInteger,
+{
+    let mut options = options.clone();
+    options.set_min_width(options.min_width().and_then(|x| NonZeroUsize::new(x.get() - 1)));
+    let sign = Some(b'(');
+    let count = if let Some(alphabet) = options.alphabet() {
+        let digits = alphabet::digit_count(unsigned, &alphabet);
+        let index = write_prefix(buffer, sign, digits, &options);
+        index + alphabet::write_digits(unsigned, &alphabet, &mut buffer[index..])
+    } else {
+        let digits = if options.min_width().is_some() {
+            unsigned.mantissa_digit_count::<FORMAT>()
+        } else {
+            0
+        };
+        write_padded(buffer, sign, digits, &options, |bytes| {
+            unsigned.write_mantissa_signed::<FORMAT>(bytes)
+        })
+    };
+    buffer[count] = b')';
+    count + 1
 }
 
 // API
@@ -77,7 +270,7 @@ macro_rules! unsigned_to_lexical {
             fn to_lexical(self, bytes: &mut [u8])
                 -> &mut [u8]
             {
-                let len = unsigned::<$t, { STANDARD }>(self, bytes);
+                let len = unsigned::<$t, { STANDARD }>(self, bytes, &STANDARD_OPTIONS);
                 &mut bytes[..len]
             }
         }
@@ -92,9 +285,9 @@ macro_rules! unsigned_to_lexical {
                 options: &Self::Options,
             ) -> &'a mut [u8]
             {
-                _ = options;
                 assert!(NumberFormat::<{ FORMAT }> {}.is_valid());
-                let len = unsigned::<$t, FORMAT>(self, bytes);
+                let len = unsigned::<$t, FORMAT>(self, bytes, options);
+                let len = align(bytes, len, options);
                 &mut bytes[..len]
             }
         }
@@ -113,7 +306,7 @@ macro_rules! signed_to_lexical {
             fn to_lexical(self, bytes: &mut [u8])
                 -> &mut [u8]
             {
-                let len = signed::<$signed, $unsigned, { STANDARD }>(self, bytes);
+                let len = signed::<$signed, $unsigned, { STANDARD }>(self, bytes, &STANDARD_OPTIONS);
                 &mut bytes[..len]
             }
         }
@@ -127,9 +320,9 @@ macro_rules! signed_to_lexical {
                 options: &Self::Options,
             ) -> &'a mut [u8]
             {
-                _ = options;
                 assert!(NumberFormat::<{ FORMAT }> {}.is_valid());
-                let len = signed::<$signed, $unsigned, FORMAT>(self, bytes);
+                let len = signed::<$signed, $unsigned, FORMAT>(self, bytes, options);
+                let len = align(bytes, len, options);
                 &mut bytes[..len]
             }
         }
@@ -6,7 +6,6 @@
 #![cfg(feature = "compact")]
 #![doc(hidden)]
 
-use lexical_util::algorithm::copy_to_dst;
 use lexical_util::constants::FormattedSize;
 use lexical_util::digit::digit_to_char;
 use lexical_util::num::{AsCast, UnsignedInteger};
@@ -33,34 +32,35 @@ use lexical_util::num::{AsCast, UnsignedInteger};
 pub trait Compact: UnsignedInteger + FormattedSize {
     /// Write our integer to string without optimizations.
     ///
-    /// This iterates over the buffer in reverse, and ensures that at least
-    /// 128 elements exist in the temporary buffer. This ensures that the digits
-    /// can never overflow, even in base 2. The buffer then must be able to hold
-    /// at least 128 digits as well, after subslicing the data. This guarantee
-    /// is likely already made.
+    /// This first calculates the number of digits, and then writes the
+    /// digits in reverse into the exactly-sized start of the buffer, so
+    /// no temporary buffer or copy is required.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the buffer cannot hold the number of digits.
     fn compact(self, radix: u32, buffer: &mut [u8]) -> usize {
-        // NOTE: We do not have to validate the buffer length because `copy_to_dst` is
-        // safe.
-        assert!(Self::BITS <= 128);
-        let mut digits: [u8; 128] = [0u8; 128];
-        let mut index = digits.len();
+        let count = self.digit_count(radix);
+        let digits = &mut buffer[..count];
+        let radix = Self::from_u32(radix);
+        let mut value = self;
+        for digit in digits.iter_mut().rev() {
+            *digit = digit_to_char(u32::as_cast(value % radix));
+            value /= radix;
+        }
+        count
+    }
 
-        // Decode all but the last digit.
+    /// Calculate the number of digits written by [`compact`](Self::compact).
+    fn digit_count(self, radix: u32) -> usize {
         let radix = Self::from_u32(radix);
         let mut value = self;
+        let mut count = 1;
         while value >= radix {
-            let r = value % radix;
             value /= radix;
-            index -= 1;
-            digits[index] = digit_to_char(u32::as_cast(r));
+            count += 1;
         }
-
-        // Decode last digit.
-        let r = value % radix;
-        index -= 1;
-        digits[index] = digit_to_char(u32::as_cast(r));
-        let slc = &digits[index..];
-        copy_to_dst(buffer, slc)
+        count
     }
 }
 
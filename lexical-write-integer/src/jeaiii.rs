@@ -62,6 +62,7 @@ fn div128_rem_1e10(n: u128) -> (u128, u64) {
 }
 
 // Index a value from a buffer without bounds checking.
+#[cfg(not(feature = "safe"))]
 macro_rules! i {
     ($array:ident[$index:expr]) => {
         // SAFETY: Safe if `array.len() > index`.
@@ -69,6 +70,14 @@ macro_rules! i {
     };
 }
 
+// Index a value from a buffer with bounds checking.
+#[cfg(feature = "safe")]
+macro_rules! i {
+    ($array:ident[$index:expr]) => {
+        $array[$index]
+    };
+}
+
 // Write N digits to our buffer.
 macro_rules! write_n {
     (@1 $buffer:ident, $index:expr, $n:expr) => {{
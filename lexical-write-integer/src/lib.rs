@@ -48,8 +48,8 @@
 //! This module uses a some more unsafe code for moderately acceptable
 //! performance. The compact decimal serializer has no non-local safety
 //! invariants, which since it's focused on code size rather than performance,
-//! this trade-off is acceptable and it uses a temporary, over-allocated buffer
-//! as an intermediate.
+//! this trade-off is acceptable and it uses bounds-checked indexing into a
+//! slice of exactly [`digit_count`] digits.
 //!
 //! The decimal writer relies on pre-computed tables and an exact calculation
 //! of the digit count ([`digit_count`]) to avoid any overhead. Avoid
@@ -111,7 +111,7 @@
 //! as the direct caller has ensure the proper buffer is allocated, there are
 //! non-local safety invariants.
 //!
-//! [`digit_count`]: crate::decimal::DigitCount
+//! [`digit_count`]: crate::digit_count()
 //! [`to_lexical`]: crate::ToLexical::to_lexical
 //! [dec]: crate::decimal::Decimal::decimal
 //! [`algorithm`]: crate::algorithm::algorithm
@@ -147,6 +147,7 @@
 )]
 
 pub mod algorithm;
+pub mod alphabet;
 pub mod compact;
 pub mod decimal;
 pub mod digit_count;
@@ -155,6 +156,7 @@ pub mod options;
 pub mod radix;
 pub mod table;
 pub mod write;
+pub mod write_const;
 
 mod api;
 mod table_binary;
@@ -164,8 +166,8 @@ mod table_radix;
 // Re-exports
 pub use lexical_util::constants::{FormattedSize, BUFFER_SIZE};
 pub use lexical_util::format::{self, NumberFormatBuilder};
-pub use lexical_util::options::WriteOptions;
+pub use lexical_util::options::{Alignment, WriteOptions};
 
-pub use self::api::{ToLexical, ToLexicalWithOptions};
+pub use self::api::{digit_count, ToLexical, ToLexicalWithOptions};
 #[doc(inline)]
 pub use self::options::{Options, OptionsBuilder};
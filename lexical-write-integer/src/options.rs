@@ -1,21 +1,103 @@
 //! Configuration options for writing integers.
-//!
-//! This is a dummy implementation, since writing integers never have options.
 
+use core::{mem, num};
+
+use lexical_util::alphabet::DigitAlphabet;
+use lexical_util::ascii::is_valid_ascii;
 use lexical_util::constants::FormattedSize;
-use lexical_util::options::WriteOptions;
+use lexical_util::error::Error;
+use lexical_util::format::NumberFormat;
+use lexical_util::options::{Alignment, WriteOptions};
 use lexical_util::result::Result;
 use static_assertions::const_assert;
 
+/// Type with the exact same size as a `usize`.
+pub type OptionUsize = Option<num::NonZeroUsize>;
+
+// Ensure the sizes are identical.
+const_assert!(mem::size_of::<OptionUsize>() == mem::size_of::<usize>());
+
 /// Builder for `Options`.
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
-pub struct OptionsBuilder {}
+pub struct OptionsBuilder {
+    /// Minimum number of characters to write, including the sign.
+    /// If not set, no padding is written.
+    min_width: OptionUsize,
+    /// Character used to pad the output to the minimum width.
+    pad_char: u8,
+    /// Alignment of the number within the minimum width.
+    alignment: Alignment,
+    /// Custom digit alphabet, which overrides the radix from the format.
+    /// If not set, the `0-9A-Z` digits are used.
+    alphabet: Option<DigitAlphabet>,
+}
 
 impl OptionsBuilder {
     /// Create new options builder with default options.
     #[inline(always)]
     pub const fn new() -> Self {
-        Self {}
+        Self {
+            min_width: None,
+            pad_char: b'0',
+            alignment: Alignment::Right,
+            alphabet: None,
+        }
+    }
+
+    // GETTERS
+
+    /// Get the minimum number of characters to write.
+    #[inline(always)]
+    pub const fn get_min_width(&self) -> OptionUsize {
+        self.min_width
+    }
+
+    /// Get the character used to pad the output to the minimum width.
+    #[inline(always)]
+    pub const fn get_pad_char(&self) -> u8 {
+        self.pad_char
+    }
+
+    /// Get the alignment of the number within the minimum width.
+    #[inline(always)]
+    pub const fn get_alignment(&self) -> Alignment {
+        self.alignment
+    }
+
+    /// Get the custom digit alphabet.
+    #[inline(always)]
+    pub const fn get_alphabet(&self) -> Option<DigitAlphabet> {
+        self.alphabet
+    }
+
+    // SETTERS
+
+    /// Set the minimum number of characters to write.
+    #[inline(always)]
+    pub const fn min_width(mut self, min_width: OptionUsize) -> Self {
+        self.min_width = min_width;
+        self
+    }
+
+    /// Set the character used to pad the output to the minimum width.
+    #[inline(always)]
+    pub const fn pad_char(mut self, pad_char: u8) -> Self {
+        self.pad_char = pad_char;
+        self
+    }
+
+    /// Set the alignment of the number within the minimum width.
+    #[inline(always)]
+    pub const fn alignment(mut self, alignment: Alignment) -> Self {
+        self.alignment = alignment;
+        self
+    }
+
+    /// Set the custom digit alphabet.
+    #[inline(always)]
+    pub const fn alphabet(mut self, alphabet: Option<DigitAlphabet>) -> Self {
+        self.alphabet = alphabet;
+        self
     }
 
     // BUILDERS
@@ -23,18 +105,37 @@ impl OptionsBuilder {
     /// Check if the builder state is valid.
     #[inline(always)]
     pub const fn is_valid(&self) -> bool {
-        true
+        if !is_valid_ascii(self.pad_char) {
+            false
+        } else if let Some(alphabet) = self.alphabet {
+            alphabet.is_valid()
+        } else {
+            true
+        }
     }
 
-    /// Build the `Options` struct with bounds validation.
+    /// Build the `Options` struct without validation.
     #[inline(always)]
     pub const fn build_unchecked(&self) -> Options {
-        Options {}
+        Options {
+            min_width: self.min_width,
+            pad_char: self.pad_char,
+            alignment: self.alignment,
+            alphabet: self.alphabet,
+        }
     }
 
     /// Build the `Options` struct.
     #[inline(always)]
     pub const fn build(&self) -> Result<Options> {
+        if !is_valid_ascii(self.pad_char) {
+            return Err(Error::InvalidPadCharacter);
+        }
+        if let Some(alphabet) = self.alphabet {
+            if !alphabet.is_valid() {
+                return Err(Error::InvalidDigitAlphabet);
+            }
+        }
         Ok(self.build_unchecked())
     }
 }
@@ -48,31 +149,159 @@ impl Default for OptionsBuilder {
 
 /// Immutable options to customize writing integers.
 ///
+/// The minimum width includes the sign. Like `printf`, padding with
+/// `0` is placed between the sign and the digits, while any other pad
+/// character is placed before the sign: `-42` with a minimum width of
+/// 5 is written as `-0042` or `  -42`, respectively. With left or
+/// centered [`Alignment`], the padding is placed after or around the
+/// number, as in `-42  ` or ` -42 `.
+///
+/// A custom digit alphabet, such as base58 or base62, overrides the
+/// radix from the number format: the radix is the number of digits in
+/// the alphabet.
+///
 /// # Examples
 ///
 /// ```rust
+/// use core::num::NonZeroUsize;
+///
 /// use lexical_write_integer::options::Options;
 ///
 /// # pub fn main() {
 /// let options = Options::builder()
+///     .min_width(NonZeroUsize::new(8))
 ///     .build()
 ///     .unwrap();
 /// # }
 /// ```
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
-pub struct Options {}
+pub struct Options {
+    /// Minimum number of characters to write, including the sign.
+    /// If not set, no padding is written.
+    min_width: OptionUsize,
+    /// Character used to pad the output to the minimum width.
+    pad_char: u8,
+    /// Alignment of the number within the minimum width.
+    alignment: Alignment,
+    /// Custom digit alphabet, which overrides the radix from the format.
+    /// If not set, the `0-9A-Z` digits are used.
+    alphabet: Option<DigitAlphabet>,
+}
 
 impl Options {
     /// Create options with default values.
     #[inline(always)]
     pub const fn new() -> Self {
-        Self {}
+        Self::builder().build_unchecked()
     }
 
     /// Check if the options state is valid.
     #[inline(always)]
     pub const fn is_valid(&self) -> bool {
-        true
+        self.rebuild().is_valid()
+    }
+
+    /// Get an upper bound on the buffer size, usable in const contexts.
+    ///
+    /// This is the same as [`WriteOptions::buffer_size`], and can be used
+    /// to declare exactly-sized arrays for a given format and options.
+    ///
+    /// ```rust
+    /// use lexical_write_integer::{format, Options, ToLexicalWithOptions};
+    ///
+    /// const OPTIONS: Options = Options::new();
+    /// const SIZE: usize = OPTIONS.buffer_size_const::<u64, { format::STANDARD }>();
+    /// let mut buffer = [0u8; SIZE];
+    /// let bytes = 12345u64.to_lexical_with_options::<{ format::STANDARD }>(&mut buffer, &OPTIONS);
+    /// assert_eq!(bytes, b"12345");
+    /// ```
+    #[inline(always)]
+    pub const fn buffer_size_const<T: FormattedSize, const FORMAT: u128>(&self) -> usize {
+        let format = NumberFormat::<{ FORMAT }> {};
+        let min_width = match self.min_width {
+            Some(x) => x.get(),
+            None => 0,
+        };
+        let size = match self.alphabet {
+            // Any radix of at least 10 needs no more digits than decimal,
+            // and smaller radixes need at most `log2(10) < 4` times as many.
+            Some(alphabet) if alphabet.radix() >= 10 => T::FORMATTED_SIZE_DECIMAL,
+            Some(_) => 4 * T::FORMATTED_SIZE_DECIMAL,
+            None if format.radix() == 10 => T::FORMATTED_SIZE_DECIMAL,
+            None => T::FORMATTED_SIZE,
+        };
+        // Unsigned values are written with a `+` sign if one is required,
+        // and negative values may be enclosed in parentheses.
+        let size = size
+            + format.required_mantissa_sign() as usize
+            + format.parenthesized_negatives() as usize;
+        if size > min_width {
+            size
+        } else {
+            min_width
+        }
+    }
+
+    // GETTERS
+
+    /// Get the minimum number of characters to write.
+    #[inline(always)]
+    pub const fn min_width(&self) -> OptionUsize {
+        self.min_width
+    }
+
+    /// Get the character used to pad the output to the minimum width.
+    #[inline(always)]
+    pub const fn pad_char(&self) -> u8 {
+        self.pad_char
+    }
+
+    /// Get the alignment of the number within the minimum width.
+    #[inline(always)]
+    pub const fn alignment(&self) -> Alignment {
+        self.alignment
+    }
+
+    /// Get the custom digit alphabet.
+    #[inline(always)]
+    pub const fn alphabet(&self) -> Option<DigitAlphabet> {
+        self.alphabet
+    }
+
+    // SETTERS
+
+    /// Set the minimum number of characters to write.
+    #[inline(always)]
+    pub fn set_min_width(&mut self, min_width: OptionUsize) {
+        self.min_width = min_width;
+    }
+
+    /// Set the character used to pad the output to the minimum width.
+    ///
+    /// # Safety
+    ///
+    /// Always safe, but may produce invalid output if the pad character
+    /// is not a valid ASCII character.
+    #[inline(always)]
+    pub fn set_pad_char(&mut self, pad_char: u8) {
+        self.pad_char = pad_char;
+    }
+
+    /// Set the alignment of the number within the minimum width.
+    #[inline(always)]
+    pub fn set_alignment(&mut self, alignment: Alignment) {
+        self.alignment = alignment;
+    }
+
+    /// Set the custom digit alphabet.
+    ///
+    /// # Safety
+    ///
+    /// Always safe, but may produce invalid output if the alphabet
+    /// is not valid.
+    #[inline(always)]
+    pub fn set_alphabet(&mut self, alphabet: Option<DigitAlphabet>) {
+        self.alphabet = alphabet;
     }
 
     // BUILDERS
@@ -86,7 +315,12 @@ impl Options {
     /// Create `OptionsBuilder` using existing values.
     #[inline(always)]
     pub const fn rebuild(&self) -> OptionsBuilder {
-        OptionsBuilder {}
+        OptionsBuilder {
+            min_width: self.min_width,
+            pad_char: self.pad_char,
+            alignment: self.alignment,
+            alphabet: self.alphabet,
+        }
     }
 }
 
@@ -105,7 +339,7 @@ impl WriteOptions for Options {
 
     #[inline(always)]
     fn buffer_size<T: FormattedSize, const FORMAT: u128>(&self) -> usize {
-        T::FORMATTED_SIZE
+        self.buffer_size_const::<T, FORMAT>()
     }
 }
 
@@ -116,3 +350,31 @@ impl WriteOptions for Options {
 #[rustfmt::skip]
 pub const STANDARD: Options = Options::new();
 const_assert!(STANDARD.is_valid());
+
+#[cfg(feature = "proptest")]
+impl proptest::arbitrary::Arbitrary for Options {
+    type Parameters = ();
+    type Strategy = proptest::strategy::BoxedStrategy<Self>;
+
+    /// Generate valid options, with an optional minimum width, alignment,
+    /// and custom digit alphabet.
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        use proptest::prelude::*;
+
+        (
+            proptest::option::of(1usize..=64),
+            proptest::sample::select(vec![b'0', b' ', b'*', b'_']),
+            proptest::sample::select(vec![Alignment::Right, Alignment::Left, Alignment::Center]),
+            proptest::option::of(any::<DigitAlphabet>()),
+        )
+            .prop_map(|(min_width, pad_char, alignment, alphabet)| {
+                Self::builder()
+                    .min_width(min_width.and_then(num::NonZeroUsize::new))
+                    .pad_char(pad_char)
+                    .alignment(alignment)
+                    .alphabet(alphabet)
+                    .build_unchecked()
+            })
+            .boxed()
+    }
+}
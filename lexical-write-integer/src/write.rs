@@ -9,6 +9,8 @@ use lexical_util::format;
 use crate::compact::Compact;
 #[cfg(not(feature = "compact"))]
 use crate::decimal::Decimal;
+#[cfg(not(feature = "compact"))]
+use crate::digit_count::DigitCount;
 #[cfg(all(not(feature = "compact"), feature = "power-of-two"))]
 use crate::radix::Radix;
 
@@ -28,6 +30,13 @@ macro_rules! write_mantissa {
         fn write_mantissa_signed<const FORMAT: u128>(self, buffer: &mut [u8]) -> usize {
             self.write_integer_signed::<FORMAT, { format::RADIX }, { format::RADIX_SHIFT }>(buffer)
         }
+
+        /// Internal implementation to get the number of significant digits written.
+        #[doc(hidden)]
+        #[inline(always)]
+        fn mantissa_digit_count<const FORMAT: u128>(self) -> usize {
+            self.digit_count(format::radix_from_flags(FORMAT, format::RADIX, format::RADIX_SHIFT))
+        }
     };
 }
 
@@ -100,7 +109,7 @@ pub trait WriteInteger: Compact {
 /// Write integer trait, implemented in terms of the optimized, decimal
 /// back-end.
 #[cfg(all(not(feature = "compact"), not(feature = "power-of-two")))]
-pub trait WriteInteger: Decimal {
+pub trait WriteInteger: Decimal + DigitCount {
     /// Forward write integer parameters to an optimized backend.
     ///
     /// # Preconditions
@@ -133,14 +142,14 @@ pub trait WriteInteger: Decimal {
         self.decimal_signed(buffer)
     }
 
-    write_mantissa!(Decimal);
-    write_exponent!(Decimal);
+    write_mantissa!(Decimal + DigitCount);
+    write_exponent!(Decimal + DigitCount);
 }
 
 /// Write integer trait, implemented in terms of the optimized, decimal or radix
 /// back-end.
 #[cfg(all(not(feature = "compact"), feature = "power-of-two"))]
-pub trait WriteInteger: Decimal + Radix {
+pub trait WriteInteger: Decimal + DigitCount + Radix {
     /// Forward write integer parameters to an optimized backend.
     ///
     /// # Preconditions
@@ -182,8 +191,8 @@ pub trait WriteInteger: Decimal + Radix {
         }
     }
 
-    write_mantissa!(Decimal + Radix);
-    write_exponent!(Decimal + Radix);
+    write_mantissa!(Decimal + DigitCount + Radix);
+    write_exponent!(Decimal + DigitCount + Radix);
 }
 
 macro_rules! write_integer_impl {
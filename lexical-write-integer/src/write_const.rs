@@ -0,0 +1,140 @@
+//! Write integers in `const` contexts.
+//!
+//! Each function is named after the integer type, and writes the value
+//! as a decimal integer using the standard format, returning the digits
+//! in a fixed-size [`ConstBuffer`]. The output is identical to
+//! [`ToLexical::to_lexical`], but the functions may be evaluated at
+//! compile time, to embed formatted constants in the binary. These
+//! routines are not optimized, and write a single digit at a time, so
+//! prefer [`ToLexical`] at runtime.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use lexical_write_integer::write_const;
+//!
+//! const MAJOR: write_const::ConstBuffer<3> = write_const::u8(12);
+//! assert_eq!(MAJOR.len(), 2);
+//! assert_eq!(MAJOR.as_array()[..2], *b"12");
+//! assert_eq!(MAJOR.as_str(), "12");
+//! assert_eq!(write_const::i64(-45).as_bytes(), b"-45");
+//! ```
+//!
+//! [`ToLexical`]: crate::ToLexical
+//! [`ToLexical::to_lexical`]: crate::ToLexical::to_lexical
+
+use core::str;
+
+use lexical_util::constants::FormattedSize;
+
+/// Fixed-size buffer containing a number written in a `const` context.
+///
+/// The first [`len`](Self::len) bytes of the array contain the written
+/// number, and the remaining bytes are zero.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct ConstBuffer<const N: usize> {
+    bytes: [u8; N],
+    len: usize,
+}
+
+impl<const N: usize> ConstBuffer<N> {
+    /// Get the underlying array, including any unused bytes.
+    #[inline(always)]
+    pub const fn as_array(&self) -> &[u8; N] {
+        &self.bytes
+    }
+
+    /// Get the number of written bytes.
+    #[inline(always)]
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Get if no bytes were written. This is always false.
+    #[inline(always)]
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Get the written bytes.
+    #[inline(always)]
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes[..self.len]
+    }
+
+    /// Get the written bytes as a string.
+    #[inline(always)]
+    pub fn as_str(&self) -> &str {
+        #[cfg(not(feature = "safe"))]
+        // SAFETY: safe since the written number is always ASCII.
+        let string = unsafe { str::from_utf8_unchecked(self.as_bytes()) };
+        #[cfg(feature = "safe")]
+        let string = match str::from_utf8(self.as_bytes()) {
+            Ok(string) => string,
+            Err(_) => unreachable!("the written number is always ASCII"),
+        };
+        string
+    }
+}
+
+/// Write the digits of the magnitude, and a sign if negative.
+macro_rules! write_digits {
+    ($magnitude:ident, $is_negative:expr, $size:ident) => {{
+        // Write the digits from the end of a scratch buffer, then
+        // move them to the start of the output.
+        let mut digits = [0u8; $size];
+        let mut start = $size;
+        loop {
+            start -= 1;
+            digits[start] = b'0' + ($magnitude % 10) as u8;
+            $magnitude /= 10;
+            if $magnitude == 0 {
+                break;
+            }
+        }
+        if $is_negative {
+            start -= 1;
+            digits[start] = b'-';
+        }
+
+        let mut bytes = [0u8; $size];
+        let len = $size - start;
+        let mut index = 0;
+        while index < len {
+            bytes[index] = digits[start + index];
+            index += 1;
+        }
+        ConstBuffer {
+            bytes,
+            len,
+        }
+    }};
+}
+
+macro_rules! write_unsigned {
+    ($($t:ident)*) => ($(
+        #[doc = concat!("Write a `", stringify!($t), "` as a decimal string in a `const` context.")]
+        #[inline]
+        pub const fn $t(value: $t) -> ConstBuffer<{ <$t as FormattedSize>::FORMATTED_SIZE_DECIMAL }> {
+            const SIZE: usize = <$t as FormattedSize>::FORMATTED_SIZE_DECIMAL;
+            let mut magnitude = value;
+            write_digits!(magnitude, false, SIZE)
+        }
+    )*);
+}
+
+write_unsigned! { u8 u16 u32 u64 u128 usize }
+
+macro_rules! write_signed {
+    ($($t:ident)*) => ($(
+        #[doc = concat!("Write an `", stringify!($t), "` as a decimal string in a `const` context.")]
+        #[inline]
+        pub const fn $t(value: $t) -> ConstBuffer<{ <$t as FormattedSize>::FORMATTED_SIZE_DECIMAL }> {
+            const SIZE: usize = <$t as FormattedSize>::FORMATTED_SIZE_DECIMAL;
+            let mut magnitude = value.unsigned_abs();
+            write_digits!(magnitude, value < 0, SIZE)
+        }
+    )*);
+}
+
+write_signed! { i8 i16 i32 i64 i128 isize }
@@ -1,14 +1,22 @@
 mod util;
 
-use core::fmt::Debug;
+use core::fmt::{Debug, Display};
+use core::num::NonZeroUsize;
 use core::str::{from_utf8_unchecked, FromStr};
 
-#[cfg(feature = "radix")]
+use lexical_util::alphabet;
 use lexical_util::constants::BUFFER_SIZE;
 #[cfg(feature = "format")]
 use lexical_util::format::NumberFormatBuilder;
 use lexical_util::format::STANDARD;
-use lexical_write_integer::{Options, ToLexical, ToLexicalWithOptions};
+use lexical_write_integer::{
+    digit_count,
+    Alignment,
+    Options,
+    ToLexical,
+    ToLexicalWithOptions,
+    WriteOptions,
+};
 use proptest::prelude::*;
 #[cfg(feature = "radix")]
 use util::from_radix;
@@ -193,6 +201,45 @@ fn proptest_failures_radix() {
     assert_eq!(b"A8", 128u8.to_lexical_with_options::<{ FORMAT }>(&mut buffer, &options));
 }
 
+#[test]
+fn digit_count_test() {
+    let mut buffer = [b'\x00'; BUFFER_SIZE];
+    assert_eq!(digit_count(0u8, 10), 1);
+    assert_eq!(digit_count(9u16, 10), 1);
+    assert_eq!(digit_count(10u32, 10), 2);
+    assert_eq!(digit_count(u64::MAX, 10), 20);
+    assert_eq!(digit_count(u128::MAX, 10), 39);
+    if cfg!(feature = "power-of-two") {
+        assert_eq!(digit_count(255u8, 16), 2);
+        assert_eq!(digit_count(256u32, 16), 3);
+        assert_eq!(digit_count(u128::MAX, 2), 128);
+    }
+    if cfg!(feature = "radix") {
+        assert_eq!(digit_count(u64::MAX, 36), 13);
+    }
+    for value in [0u64, 1, 99, 100, 12345678, 1 << 40, u64::MAX] {
+        assert_eq!(digit_count(value, 10), value.to_lexical(&mut buffer).len());
+    }
+}
+
+#[test]
+#[cfg(feature = "radix")]
+fn digit_count_radix_test() {
+    const FORMAT: u128 = from_radix(12);
+    let mut buffer = [b'\x00'; BUFFER_SIZE];
+    let options = Options::new();
+    for value in [0u128, 11, 12, 143, 144, u64::MAX as u128, u128::MAX] {
+        let digits = value.to_lexical_with_options::<{ FORMAT }>(&mut buffer, &options).len();
+        assert_eq!(digit_count(value, 12), digits);
+    }
+}
+
+#[test]
+#[should_panic]
+fn digit_count_invalid_radix_test() {
+    _ = digit_count(10u32, 37);
+}
+
 #[test]
 fn options_test() {
     let mut buffer = [b'\x00'; 48];
@@ -218,6 +265,166 @@ fn options_radix_test() {
     assert_eq!(b"A8", 128u8.to_lexical_with_options::<{ FORMAT }>(&mut buffer, &options));
 }
 
+#[test]
+fn min_width_buffer_size_test() {
+    // The buffer size is enough even if the digits are written after padding.
+    let options = Options::builder().min_width(NonZeroUsize::new(12)).build().unwrap();
+    let mut buffer = [b'\x00'; 12];
+    assert_eq!(options.buffer_size::<u32, { STANDARD }>(), buffer.len());
+    assert_eq!(
+        b"000000000007",
+        7u32.to_lexical_with_options::<{ STANDARD }>(&mut buffer, &options)
+    );
+    assert_eq!(
+        b"-00000000007",
+        (-7i32).to_lexical_with_options::<{ STANDARD }>(&mut buffer, &options)
+    );
+    let options =
+        Options::builder().min_width(NonZeroUsize::new(12)).pad_char(b' ').build().unwrap();
+    assert_eq!(
+        b"         -12",
+        (-12i32).to_lexical_with_options::<{ STANDARD }>(&mut buffer, &options)
+    );
+}
+
+#[test]
+fn min_width_test() {
+    let mut buffer = [b'\x00'; 48];
+    let options = Options::builder().min_width(NonZeroUsize::new(8)).build().unwrap();
+    assert_eq!(b"00001234", 1234u32.to_lexical_with_options::<{ STANDARD }>(&mut buffer, &options));
+    assert_eq!(
+        b"-0001234",
+        (-1234i32).to_lexical_with_options::<{ STANDARD }>(&mut buffer, &options)
+    );
+    assert_eq!(b"00000000", 0u8.to_lexical_with_options::<{ STANDARD }>(&mut buffer, &options));
+    assert_eq!(
+        b"123456789",
+        123456789u64.to_lexical_with_options::<{ STANDARD }>(&mut buffer, &options)
+    );
+    assert_eq!(
+        b"-170141183460469231731687303715884105728",
+        i128::MIN.to_lexical_with_options::<{ STANDARD }>(&mut buffer, &options)
+    );
+
+    let options =
+        Options::builder().min_width(NonZeroUsize::new(6)).pad_char(b' ').build().unwrap();
+    assert_eq!(b"  1234", 1234u16.to_lexical_with_options::<{ STANDARD }>(&mut buffer, &options));
+    assert_eq!(b"   -42", (-42i64).to_lexical_with_options::<{ STANDARD }>(&mut buffer, &options));
+    assert_eq!(
+        b"-12345",
+        (-12345i32).to_lexical_with_options::<{ STANDARD }>(&mut buffer, &options)
+    );
+}
+
+#[test]
+fn alignment_test() {
+    let mut buffer = [b'\x00'; 48];
+    let options = Options::builder()
+        .min_width(NonZeroUsize::new(6))
+        .pad_char(b' ')
+        .alignment(Alignment::Left)
+        .build()
+        .unwrap();
+    assert_eq!(b"-42   ", (-42i32).to_lexical_with_options::<{ STANDARD }>(&mut buffer, &options));
+    assert_eq!(b"123456", 123456u32.to_lexical_with_options::<{ STANDARD }>(&mut buffer, &options));
+
+    let options = Options::builder()
+        .min_width(NonZeroUsize::new(6))
+        .pad_char(b'*')
+        .alignment(Alignment::Center)
+        .build()
+        .unwrap();
+    assert_eq!(b"*-42**", (-42i32).to_lexical_with_options::<{ STANDARD }>(&mut buffer, &options));
+    assert_eq!(b"*1234*", 1234u16.to_lexical_with_options::<{ STANDARD }>(&mut buffer, &options));
+
+    // Zeros are only placed after the sign for right alignment.
+    let options = Options::builder()
+        .min_width(NonZeroUsize::new(6))
+        .alignment(Alignment::Left)
+        .build()
+        .unwrap();
+    assert_eq!(b"-42000", (-42i32).to_lexical_with_options::<{ STANDARD }>(&mut buffer, &options));
+}
+
+#[test]
+#[cfg(feature = "format")]
+fn parenthesized_negatives_test() {
+    let mut buffer = [b'\x00'; 64];
+    let options = Options::new();
+    const FORMAT: u128 = NumberFormatBuilder::new().parenthesized_negatives(true).build();
+    assert_eq!(b"(1)", (-1i8).to_lexical_with_options::<{ FORMAT }>(&mut buffer, &options));
+    assert_eq!(b"0", 0i8.to_lexical_with_options::<{ FORMAT }>(&mut buffer, &options));
+    assert_eq!(b"12", 12i32.to_lexical_with_options::<{ FORMAT }>(&mut buffer, &options));
+    assert_eq!(b"12", 12u32.to_lexical_with_options::<{ FORMAT }>(&mut buffer, &options));
+    assert_eq!(
+        b"(170141183460469231731687303715884105728)",
+        i128::MIN.to_lexical_with_options::<{ FORMAT }>(&mut buffer, &options)
+    );
+
+    let options = Options::builder().min_width(NonZeroUsize::new(6)).build().unwrap();
+    assert_eq!(b"(0042)", (-42i32).to_lexical_with_options::<{ FORMAT }>(&mut buffer, &options));
+    assert_eq!(b"000042", 42i32.to_lexical_with_options::<{ FORMAT }>(&mut buffer, &options));
+    let options =
+        Options::builder().min_width(NonZeroUsize::new(6)).pad_char(b' ').build().unwrap();
+    assert_eq!(b"  (42)", (-42i32).to_lexical_with_options::<{ FORMAT }>(&mut buffer, &options));
+}
+
+#[test]
+#[cfg(feature = "format")]
+fn min_width_mandatory_sign_test() {
+    let mut buffer = [b'\x00'; 16];
+    let options = Options::builder().min_width(NonZeroUsize::new(4)).build().unwrap();
+    const FORMAT: u128 = NumberFormatBuilder::new().required_mantissa_sign(true).build();
+    assert_eq!(b"+001", 1u8.to_lexical_with_options::<{ FORMAT }>(&mut buffer, &options));
+    assert_eq!(b"-001", (-1i8).to_lexical_with_options::<{ FORMAT }>(&mut buffer, &options));
+}
+
+#[test]
+#[cfg(feature = "radix")]
+fn min_width_radix_test() {
+    const FORMAT: u128 = from_radix(16);
+    let mut buffer = [b'\x00'; 128];
+    let options = Options::builder().min_width(NonZeroUsize::new(8)).build().unwrap();
+    assert_eq!(b"000000FF", 255u8.to_lexical_with_options::<{ FORMAT }>(&mut buffer, &options));
+    assert_eq!(b"-0000080", i8::MIN.to_lexical_with_options::<{ FORMAT }>(&mut buffer, &options));
+    assert_eq!(
+        b"100000000000000000",
+        (1u128 << 68).to_lexical_with_options::<{ FORMAT }>(&mut buffer, &options)
+    );
+}
+
+#[test]
+fn alphabet_test() {
+    let mut buffer = [b'\x00'; 128];
+    let options = Options::builder().alphabet(Some(alphabet::BASE58)).build().unwrap();
+    assert_eq!(b"1", 0u8.to_lexical_with_options::<{ STANDARD }>(&mut buffer, &options));
+    assert_eq!(b"z", 57u8.to_lexical_with_options::<{ STANDARD }>(&mut buffer, &options));
+    assert_eq!(b"21", 58u32.to_lexical_with_options::<{ STANDARD }>(&mut buffer, &options));
+    assert_eq!(b"-21", (-58i32).to_lexical_with_options::<{ STANDARD }>(&mut buffer, &options));
+    assert_eq!(
+        b"jpXCZedGfVQ",
+        u64::MAX.to_lexical_with_options::<{ STANDARD }>(&mut buffer, &options)
+    );
+
+    let options = Options::builder().alphabet(Some(alphabet::BASE62)).build().unwrap();
+    assert_eq!(
+        b"LygHa16AHYF",
+        u64::MAX.to_lexical_with_options::<{ STANDARD }>(&mut buffer, &options)
+    );
+    assert_eq!(b"-2LKcb2", i32::MIN.to_lexical_with_options::<{ STANDARD }>(&mut buffer, &options));
+
+    let options = Options::builder().alphabet(Some(alphabet::BASE32)).build().unwrap();
+    assert_eq!(b"7777", 0xFFFFFu32.to_lexical_with_options::<{ STANDARD }>(&mut buffer, &options));
+
+    let options = Options::builder()
+        .alphabet(Some(alphabet::BASE58))
+        .min_width(NonZeroUsize::new(6))
+        .pad_char(b'1')
+        .build()
+        .unwrap();
+    assert_eq!(b"111121", 58u16.to_lexical_with_options::<{ STANDARD }>(&mut buffer, &options));
+}
+
 fn roundtrip<T>(x: T) -> T
 where
     T: Roundtrip,
@@ -229,6 +436,22 @@ where
     string.parse::<T>().unwrap()
 }
 
+fn is_core_fmt<T: ToLexical + Display + Debug>(x: T) -> bool {
+    let mut buffer = [b'\x00'; 48];
+    let string = unsafe { from_utf8_unchecked(x.to_lexical(&mut buffer)) };
+    string == format!("{}", x) && string == format!("{:?}", x)
+}
+
+#[test]
+fn core_fmt_test() {
+    assert!((u8::MIN..=u8::MAX).all(is_core_fmt));
+    assert!((i8::MIN..=i8::MAX).all(is_core_fmt));
+    assert!((u16::MIN..=u16::MAX).all(is_core_fmt));
+    assert!((i16::MIN..=i16::MAX).all(is_core_fmt));
+    assert!(is_core_fmt(u128::MAX));
+    assert!(is_core_fmt(i128::MIN));
+}
+
 #[cfg(feature = "radix")]
 fn roundtrip_radix<T>(x: T, radix: u32) -> T
 where
@@ -1300,6 +1523,36 @@ proptest! {
         prop_assert_eq!(i, roundtrip(i));
     }
 
+    #[test]
+    fn u32_core_fmt_proptest(i in u32::MIN..u32::MAX) {
+        prop_assert!(is_core_fmt(i));
+    }
+
+    #[test]
+    fn i32_core_fmt_proptest(i in i32::MIN..i32::MAX) {
+        prop_assert!(is_core_fmt(i));
+    }
+
+    #[test]
+    fn u64_core_fmt_proptest(i in u64::MIN..u64::MAX) {
+        prop_assert!(is_core_fmt(i));
+    }
+
+    #[test]
+    fn i64_core_fmt_proptest(i in i64::MIN..i64::MAX) {
+        prop_assert!(is_core_fmt(i));
+    }
+
+    #[test]
+    fn u128_core_fmt_proptest(i in u128::MIN..u128::MAX) {
+        prop_assert!(is_core_fmt(i));
+    }
+
+    #[test]
+    fn i128_core_fmt_proptest(i in i128::MIN..i128::MAX) {
+        prop_assert!(is_core_fmt(i));
+    }
+
     #[test]
     fn jeaiii_magic_10u64_proptest(i in 10_0000_0000..100_0000_0000u64) {
         prop_assert_eq!(i, roundtrip(i));
@@ -1,3 +1,11 @@
+#![allow(clippy::disallowed_macros)]
+
+use core::num::NonZeroUsize;
+
+use lexical_util::alphabet::{self, alphabet_inverse, DigitAlphabet};
+use lexical_util::constants::FormattedSize;
+use lexical_util::options::{Alignment, WriteOptions};
+use lexical_write_integer::format::STANDARD;
 use lexical_write_integer::options::{Options, OptionsBuilder};
 
 #[test]
@@ -10,3 +18,117 @@ fn options_tests() {
     assert!(OptionsBuilder::default().is_valid());
     assert_eq!(X.rebuild(), Options::builder());
 }
+
+#[test]
+fn min_width_tests() {
+    let builder = OptionsBuilder::new().min_width(NonZeroUsize::new(64)).pad_char(b' ');
+    assert!(builder.is_valid());
+    assert_eq!(builder.get_min_width(), NonZeroUsize::new(64));
+    assert_eq!(builder.get_pad_char(), b' ');
+
+    let mut options = builder.build().unwrap();
+    assert_eq!(options.buffer_size::<u8, { STANDARD }>(), 64);
+    options.set_min_width(NonZeroUsize::new(1));
+    assert_eq!(options.buffer_size::<u128, { STANDARD }>(), u128::FORMATTED_SIZE_DECIMAL);
+    options.set_min_width(None);
+    assert_eq!(options.buffer_size::<u8, { STANDARD }>(), u8::FORMATTED_SIZE_DECIMAL);
+
+    let builder = OptionsBuilder::new().pad_char(b'\x00');
+    assert!(!builder.is_valid());
+    assert!(builder.build().is_err());
+}
+
+#[test]
+fn alignment_tests() {
+    let builder = OptionsBuilder::new();
+    assert_eq!(builder.get_alignment(), Alignment::Right);
+    let builder = builder.alignment(Alignment::Center);
+    assert!(builder.is_valid());
+    assert_eq!(builder.get_alignment(), Alignment::Center);
+
+    let mut options = builder.min_width(NonZeroUsize::new(8)).build().unwrap();
+    assert_eq!(options.alignment(), Alignment::Center);
+    assert_eq!(options.buffer_size::<u8, { STANDARD }>(), 8);
+    options.set_alignment(Alignment::Left);
+    assert_eq!(options.alignment(), Alignment::Left);
+    assert_eq!(options.rebuild().get_alignment(), Alignment::Left);
+}
+
+#[test]
+fn alphabet_tests() {
+    let builder = OptionsBuilder::new().alphabet(Some(alphabet::BASE58));
+    assert!(builder.is_valid());
+    assert_eq!(builder.get_alphabet(), Some(alphabet::BASE58));
+
+    let mut options = builder.build().unwrap();
+    assert_eq!(options.alphabet(), Some(alphabet::BASE58));
+    assert_eq!(options.buffer_size::<u64, { STANDARD }>(), u64::FORMATTED_SIZE_DECIMAL);
+    options.set_alphabet(Some(alphabet::BASE32));
+    assert_eq!(options.buffer_size::<u64, { STANDARD }>(), u64::FORMATTED_SIZE_DECIMAL);
+
+    const DIGITS: &[u8] = b"001";
+    const INVERSE: [u8; 256] = alphabet_inverse(DIGITS);
+    let builder = OptionsBuilder::new().alphabet(Some(DigitAlphabet::new(DIGITS, &INVERSE)));
+    assert!(!builder.is_valid());
+    assert!(builder.build().is_err());
+}
+
+#[test]
+fn buffer_size_const_tests() {
+    const OPTIONS: Options = Options::new();
+    const SIZE: usize = OPTIONS.buffer_size_const::<i32, { STANDARD }>();
+    let buffer = [0u8; SIZE];
+    assert_eq!(buffer.len(), i32::FORMATTED_SIZE_DECIMAL);
+    assert_eq!(
+        OPTIONS.buffer_size_const::<u16, { STANDARD }>(),
+        OPTIONS.buffer_size::<u16, { STANDARD }>()
+    );
+
+    const PADDED: Options = Options::builder().min_width(NonZeroUsize::new(32)).build_unchecked();
+    assert_eq!(PADDED.buffer_size_const::<u8, { STANDARD }>(), 32);
+    assert_eq!(PADDED.buffer_size_const::<u128, { STANDARD }>(), u128::FORMATTED_SIZE_DECIMAL);
+}
+
+#[test]
+#[cfg(feature = "power-of-two")]
+fn buffer_size_radix_tests() {
+    use lexical_util::format::NumberFormatBuilder;
+
+    const BINARY: u128 = NumberFormatBuilder::from_radix(2);
+    let options = Options::new();
+    assert_eq!(options.buffer_size_const::<u64, { BINARY }>(), u64::FORMATTED_SIZE);
+    assert_eq!(options.buffer_size_const::<u64, { STANDARD }>(), u64::FORMATTED_SIZE_DECIMAL);
+}
+
+#[test]
+#[cfg(feature = "format")]
+fn buffer_size_sign_tests() {
+    use lexical_util::format::NumberFormatBuilder;
+
+    const FORMAT: u128 = NumberFormatBuilder::new().required_mantissa_sign(true).build();
+    let options = Options::new();
+    let mut buffer = [0u8; Options::new().buffer_size_const::<u8, { FORMAT }>()];
+    assert_eq!(buffer.len(), 4);
+    assert_eq!(
+        lexical_write_integer::ToLexicalWithOptions::to_lexical_with_options::<FORMAT>(
+            255u8,
+            &mut buffer,
+            &options
+        ),
+        b"+255"
+    );
+}
+
+#[cfg(feature = "proptest")]
+proptest::proptest! {
+    #[test]
+    fn arbitrary_options_proptest(options in proptest::arbitrary::any::<Options>(), value: i64) {
+        use lexical_write_integer::ToLexicalWithOptions;
+
+        proptest::prop_assert!(options.is_valid());
+        let mut buffer = [0u8; 128];
+        let size = options.buffer_size_const::<i64, STANDARD>();
+        let written = value.to_lexical_with_options::<STANDARD>(&mut buffer, &options);
+        proptest::prop_assert!(written.len() <= size);
+    }
+}
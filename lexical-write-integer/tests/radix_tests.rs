@@ -40,6 +40,24 @@ fn u128toa_test() {
     }
 }
 
+#[test]
+#[cfg(feature = "radix")]
+fn u64toa_chunk_test() {
+    // Values around the 32-bit chunks of digits, which have internal zeros.
+    for radix in 2u32..=36 {
+        let mut power = 1u64;
+        while let Some(next) = power.checked_mul(radix as u64) {
+            power = next;
+            for x in [power - 1, power, power + 1] {
+                mockup(x, radix).unwrap();
+            }
+        }
+        for x in [u32::MAX as u64, u32::MAX as u64 + 1, u64::MAX] {
+            mockup(x, radix).unwrap();
+        }
+    }
+}
+
 #[cfg(feature = "power-of-two")]
 fn write_integer<T: WriteInteger, const FORMAT: u128>(x: T, actual: &[u8]) {
     let mut buffer = [b'\x00'; BUFFER_SIZE];
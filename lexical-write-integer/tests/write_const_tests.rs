@@ -0,0 +1,64 @@
+#![allow(clippy::disallowed_macros)]
+
+mod util;
+
+use lexical_util::constants::BUFFER_SIZE;
+use lexical_write_integer::{write_const, ToLexical};
+use proptest::prelude::*;
+
+use crate::util::default_proptest_config;
+
+const VERSION: write_const::ConstBuffer<10> = write_const::u32(1024);
+
+#[test]
+fn const_test() {
+    assert_eq!(VERSION.len(), 4);
+    assert_eq!(VERSION.as_array(), b"1024\0\0\0\0\0\0");
+    assert_eq!(VERSION.as_str(), "1024");
+}
+
+#[test]
+fn unsigned_test() {
+    assert_eq!(write_const::u8(0).as_bytes(), b"0");
+    assert_eq!(write_const::u8(255).as_bytes(), b"255");
+    assert_eq!(write_const::u64(u64::MAX).as_bytes(), b"18446744073709551615");
+    assert_eq!(write_const::u128(u128::MAX).as_str(), "340282366920938463463374607431768211455");
+    assert!(!write_const::usize(0).is_empty());
+}
+
+#[test]
+fn signed_test() {
+    assert_eq!(write_const::i8(i8::MIN).as_bytes(), b"-128");
+    assert_eq!(write_const::i8(-1).as_bytes(), b"-1");
+    assert_eq!(write_const::i32(0).as_bytes(), b"0");
+    assert_eq!(write_const::i64(i64::MIN).as_bytes(), b"-9223372036854775808");
+    assert_eq!(write_const::i128(i128::MIN).as_str(), "-170141183460469231731687303715884105728");
+}
+
+proptest! {
+    #![proptest_config(default_proptest_config())]
+
+    #[test]
+    fn u64_proptest(value: u64) {
+        let mut buffer = [0u8; BUFFER_SIZE];
+        let expected = value.to_lexical(&mut buffer);
+        let written = write_const::u64(value);
+        prop_assert_eq!(written.as_bytes(), expected);
+    }
+
+    #[test]
+    fn i16_proptest(value: i16) {
+        let mut buffer = [0u8; BUFFER_SIZE];
+        let expected = value.to_lexical(&mut buffer);
+        let written = write_const::i16(value);
+        prop_assert_eq!(written.as_bytes(), expected);
+    }
+
+    #[test]
+    fn i128_proptest(value: i128) {
+        let mut buffer = [0u8; BUFFER_SIZE];
+        let expected = value.to_lexical(&mut buffer);
+        let written = write_const::i128(value);
+        prop_assert_eq!(written.as_bytes(), expected);
+    }
+}
@@ -0,0 +1,121 @@
+//! Write numbers directly into a [`BufMut`](::bytes::BufMut).
+//!
+//! Each writer formats the number and appends it to the buffer, returning
+//! the number of bytes written. If the current chunk of the buffer has
+//! room for the largest number of the type, the number is written in
+//! place, without an intermediate buffer or zeroing the spare capacity.
+//! Otherwise, the number is written to a temporary buffer and copied with
+//! [`BufMut::put_slice`], which may span multiple chunks.
+//!
+//! This lets protocol encoders write numbers into a `BytesMut`, a
+//! `Vec<u8>`, or any other `BufMut` without formatting to a string first.
+//!
+//! ```rust
+//! # #[cfg(all(feature = "write-floats", feature = "write-integers"))] {
+//! use bytes::{BufMut, BytesMut};
+//!
+//! let mut buf = BytesMut::new();
+//! buf.put_slice(b"Content-Length: ");
+//! assert_eq!(lexical::bytes::write(1024u32, &mut buf), 4);
+//! buf.put_slice(b"\r\n");
+//! assert_eq!(&buf[..], b"Content-Length: 1024\r\n");
+//!
+//! let mut vec = Vec::new();
+//! lexical::bytes::write(-1.5f64, &mut vec);
+//! assert_eq!(vec, b"-1.5");
+//! # }
+//! ```
+
+#![cfg(all(feature = "bytes", feature = "write"))]
+
+use ::bytes::BufMut;
+use lexical_core::{ToLexical, ToLexicalWithOptions, WriteOptions, BUFFER_SIZE};
+
+/// Write a number to a buffer, using the standard format.
+///
+/// Returns the number of bytes written, and advances the buffer by the
+/// same amount.
+///
+/// * `n`   - Number to serialize.
+/// * `buf` - Buffer to append the number to.
+///
+/// # Panics
+///
+/// Panics if the buffer does not have enough remaining capacity for the
+/// number, like [`BufMut::put_slice`].
+#[inline]
+pub fn write<N: ToLexical, B: BufMut + ?Sized>(n: N, buf: &mut B) -> usize {
+    #[cfg(not(feature = "safe"))]
+    {
+        let chunk = buf.chunk_mut();
+        if chunk.len() >= N::FORMATTED_SIZE_DECIMAL {
+            // SAFETY: safe since only initialized bytes are written to the chunk.
+            let bytes = unsafe { chunk.as_uninit_slice_mut() };
+            let len = lexical_core::write_uninit(n, bytes).len();
+            // SAFETY: safe since the first `len` bytes of the chunk were initialized.
+            unsafe { buf.advance_mut(len) };
+            return len;
+        }
+    }
+
+    let mut buffer = [0u8; BUFFER_SIZE];
+    let bytes = lexical_core::write(n, &mut buffer);
+    buf.put_slice(bytes);
+    bytes.len()
+}
+
+/// Write a number to a buffer, using a custom format and options.
+///
+/// Returns the number of bytes written, and advances the buffer by the
+/// same amount. The number is written in place if the current chunk has
+/// room for [`WriteOptions::buffer_size`] bytes.
+///
+/// * `FORMAT`  - Packed struct containing the number format.
+/// * `n`       - Number to serialize.
+/// * `buf`     - Buffer to append the number to.
+/// * `options` - Options to customize number formatting.
+///
+/// # Panics
+///
+/// Panics if the buffer does not have enough remaining capacity for the
+/// number, like [`BufMut::put_slice`]. If the provided format is not valid,
+/// this will panic or fail to compile, like
+/// [`write_with_options`](crate::write_with_options).
+#[inline]
+pub fn write_with_options<N, B, const FORMAT: u128>(
+    n: N,
+    buf: &mut B,
+    options: &N::Options,
+) -> usize
+where
+    N: ToLexicalWithOptions,
+    B: BufMut + ?Sized,
+{
+    let size = options.buffer_size::<N, FORMAT>();
+    #[cfg(not(feature = "safe"))]
+    {
+        let chunk = buf.chunk_mut();
+        if chunk.len() >= size {
+            // SAFETY: safe since only initialized bytes are written to the chunk.
+            let bytes = unsafe { chunk.as_uninit_slice_mut() };
+            let len = lexical_core::write_with_options_uninit::<_, FORMAT>(n, bytes, options).len();
+            // SAFETY: safe since the first `len` bytes of the chunk were initialized.
+            unsafe { buf.advance_mut(len) };
+            return len;
+        }
+    }
+
+    if size <= BUFFER_SIZE {
+        let mut buffer = [0u8; BUFFER_SIZE];
+        let bytes = lexical_core::write_with_options::<_, FORMAT>(n, &mut buffer, options);
+        buf.put_slice(bytes);
+        bytes.len()
+    } else {
+        // Padding or significant digits options may require more than the
+        // stack buffer, which is rare, so allocate instead.
+        let mut buffer = alloc::vec![0u8; size];
+        let bytes = lexical_core::write_with_options::<_, FORMAT>(n, &mut buffer, options);
+        buf.put_slice(bytes);
+        bytes.len()
+    }
+}
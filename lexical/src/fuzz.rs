@@ -0,0 +1,124 @@
+//! Differential-testing oracles for fuzzers and property tests.
+//!
+//! Each function checks the invariants for a single input, and panics if
+//! any are violated, so a fuzz target or property test only needs to call
+//! one function:
+//!
+//! ```rust,ignore
+//! #![no_main]
+//! use libfuzzer_sys::fuzz_target;
+//!
+//! fuzz_target!(|data: &[u8]| lexical::fuzz::parse::<f64>(data));
+//! ```
+//!
+//! The oracles are:
+//!
+//! - [`parse`]: the result agrees with the parser from Rust's core library, and
+//!   with the partial parser.
+//! - [`roundtrip`]: writing then parsing a value returns the same value.
+
+#![cfg(all(feature = "fuzz", feature = "parse", feature = "write"))]
+
+use core::fmt::Debug;
+use core::str::FromStr;
+
+use lexical_core::{FromLexical, ToLexical, BUFFER_SIZE};
+
+/// A number that can be checked by the fuzzing oracles.
+pub trait FuzzNumber: FromLexical + ToLexical + FromStr + Debug {
+    /// Determine if two values are identical, treating all NaNs as equal.
+    fn is_identical(&self, other: &Self) -> bool;
+}
+
+#[cfg(all(feature = "parse-integers", feature = "write-integers"))]
+macro_rules! fuzz_integer {
+    ($($t:ty)*) => ($(
+        impl FuzzNumber for $t {
+            #[inline(always)]
+            fn is_identical(&self, other: &Self) -> bool {
+                self == other
+            }
+        }
+    )*);
+}
+
+#[cfg(all(feature = "parse-integers", feature = "write-integers"))]
+fuzz_integer! { u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize }
+
+#[cfg(all(feature = "parse-floats", feature = "write-floats"))]
+macro_rules! fuzz_float {
+    ($($t:ty)*) => ($(
+        impl FuzzNumber for $t {
+            #[inline(always)]
+            fn is_identical(&self, other: &Self) -> bool {
+                (self.is_nan() && other.is_nan()) || self.to_bits() == other.to_bits()
+            }
+        }
+    )*);
+}
+
+#[cfg(all(feature = "parse-floats", feature = "write-floats"))]
+fuzz_float! { f32 f64 }
+
+/// Check that parsing the bytes agrees with Rust's core library.
+///
+/// This checks that:
+///
+/// - Lexical accepts exactly the strings the core library accepts, and parses
+///   them to identical values.
+/// - The partial parser consumes the entire input for valid numbers, and never
+///   reports more bytes than the input contains.
+///
+/// # Panics
+///
+/// Panics if any invariant is violated.
+pub fn parse<N: FuzzNumber>(bytes: &[u8]) {
+    let result = N::from_lexical(bytes);
+    let expected = core::str::from_utf8(bytes).ok().and_then(|s| s.parse::<N>().ok());
+    match (&result, expected) {
+        (Ok(value), Some(expected)) => assert!(
+            value.is_identical(&expected),
+            "parsed {:?} from {:?}, expected {:?}",
+            value,
+            bytes,
+            expected
+        ),
+        (Ok(value), None) => panic!("parsed {:?} from {:?}, expected an error", value, bytes),
+        (Err(error), Some(expected)) => {
+            panic!("failed to parse {:?}, expected {:?}: {}", bytes, expected, error)
+        },
+        (Err(_), None) => (),
+    }
+
+    match N::from_lexical_partial(bytes) {
+        Ok((partial, count)) => {
+            assert!(count <= bytes.len(), "consumed {} of {} bytes", count, bytes.len());
+            if let Ok(value) = result {
+                assert_eq!(count, bytes.len(), "partial parser stopped early for {:?}", bytes);
+                assert!(partial.is_identical(&value), "partial parser disagrees for {:?}", bytes);
+            }
+        },
+        Err(error) => assert!(result.is_err(), "partial parser failed for {:?}: {}", bytes, error),
+    }
+}
+
+/// Check that writing a value and parsing it back returns the same value.
+///
+/// # Panics
+///
+/// Panics if the written string cannot be parsed, or if the parsed value
+/// differs from the original.
+pub fn roundtrip<N: FuzzNumber>(value: N) {
+    let mut buffer = [0u8; BUFFER_SIZE];
+    let bytes = value.to_lexical(&mut buffer);
+    match N::from_lexical(bytes) {
+        Ok(parsed) => assert!(
+            parsed.is_identical(&value),
+            "wrote {:?} as {:?}, parsed {:?}",
+            value,
+            bytes,
+            parsed
+        ),
+        Err(error) => panic!("failed to parse {:?}, written from {:?}: {}", bytes, value, error),
+    }
+}
@@ -0,0 +1,128 @@
+//! Write numbers into an [`String`](::heapless::String).
+//!
+//! Each writer formats the number on the stack and appends it to a
+//! fixed-capacity string, without allocating. If the string does not have
+//! enough remaining capacity for the number, it is left unchanged and
+//! an error with the number of bytes required is returned. This is useful
+//! to build display strings on targets without an allocator.
+//!
+//! ```rust
+//! # #[cfg(all(feature = "write-floats", feature = "write-integers"))] {
+//! use heapless::String;
+//! use lexical::BufferTooSmall;
+//!
+//! let mut string = String::<16>::new();
+//! string.push_str("T=").unwrap();
+//! assert_eq!(lexical::heapless::write(21.5f32, &mut string), Ok(4));
+//! assert_eq!(string.as_str(), "T=21.5");
+//!
+//! let string = lexical::heapless::to_string::<_, 4>(-128i16);
+//! assert_eq!(string.map(|x| x.as_str().to_owned()), Ok("-128".to_owned()));
+//! assert_eq!(lexical::heapless::to_string::<_, 4>(12345u32), Err(BufferTooSmall {
+//!     max_needed: 5,
+//! }));
+//! # }
+//! ```
+
+#![cfg(all(feature = "heapless", feature = "write"))]
+
+use ::heapless::String;
+use lexical_core::{BufferTooSmall, ToLexical, ToLexicalWithOptions, BUFFER_SIZE};
+
+/// Append the written bytes to the string, if they fit.
+#[inline(always)]
+fn push_bytes<const CAP: usize>(
+    string: &mut String<CAP>,
+    bytes: &[u8],
+) -> Result<usize, BufferTooSmall> {
+    match string.push_str(crate::ascii_to_str(bytes)) {
+        Ok(()) => Ok(bytes.len()),
+        Err(()) => Err(BufferTooSmall {
+            max_needed: bytes.len(),
+        }),
+    }
+}
+
+/// Append a number to a string, using the standard format.
+///
+/// Returns the number of bytes written. If the string does not have
+/// enough remaining capacity, it is left unchanged and this returns an
+/// error with the number of bytes required to write `n`.
+///
+/// * `n`       - Number to serialize.
+/// * `string`  - String to append the number to.
+#[inline]
+pub fn write<N: ToLexical, const CAP: usize>(
+    n: N,
+    string: &mut String<CAP>,
+) -> Result<usize, BufferTooSmall> {
+    let mut buffer = [0u8; BUFFER_SIZE];
+    push_bytes(string, lexical_core::write(n, &mut buffer))
+}
+
+/// Append a number to a string, using a custom format and options.
+///
+/// Returns the number of bytes written. If the string does not have
+/// enough remaining capacity, it is left unchanged and this returns an
+/// error with the number of bytes required to write `n`. If the options
+/// require more than [`BUFFER_SIZE`] bytes, the required number of bytes
+/// is the upper bound from
+/// [`WriteOptions::buffer_size`](crate::WriteOptions::buffer_size).
+///
+/// * `FORMAT`  - Packed struct containing the number format.
+/// * `n`       - Number to serialize.
+/// * `string`  - String to append the number to.
+/// * `options` - Options to customize number formatting.
+///
+/// # Panics
+///
+/// If the provided `FORMAT` is not valid, the function may panic. Please
+/// ensure `is_valid()` is called prior to using the format, or checking
+/// its validity using a static assertion.
+#[inline]
+pub fn write_with_options<N: ToLexicalWithOptions, const CAP: usize, const FORMAT: u128>(
+    n: N,
+    string: &mut String<CAP>,
+    options: &N::Options,
+) -> Result<usize, BufferTooSmall> {
+    let mut buffer = [0u8; BUFFER_SIZE];
+    let bytes = lexical_core::try_write_with_options::<_, FORMAT>(n, &mut buffer, options)?;
+    push_bytes(string, bytes)
+}
+
+/// Write a number to a new string, using the standard format.
+///
+/// Returns an error with the number of bytes required to write `n` if
+/// it does not fit in `CAP` bytes.
+///
+/// * `n`   - Number to serialize.
+#[inline]
+pub fn to_string<N: ToLexical, const CAP: usize>(n: N) -> Result<String<CAP>, BufferTooSmall> {
+    let mut string = String::new();
+    write(n, &mut string)?;
+    Ok(string)
+}
+
+/// Write a number to a new string, using a custom format and options.
+///
+/// Returns an error with the number of bytes required to write `n` if
+/// it does not fit in `CAP` bytes. See [`write_with_options`].
+///
+/// * `FORMAT`  - Packed struct containing the number format.
+/// * `n`       - Number to serialize.
+/// * `options` - Options to customize number formatting.
+///
+/// # Panics
+///
+/// If the provided `FORMAT` is not valid, the function may panic. Please
+/// ensure `is_valid()` is called prior to using the format, or checking
+/// its validity using a static assertion.
+#[inline]
+pub fn to_string_with_options<N: ToLexicalWithOptions, const CAP: usize, const FORMAT: u128>(
+    n: N,
+    options: &N::Options,
+) -> Result<String<CAP>, BufferTooSmall> {
+    let mut string = String::new();
+    write_with_options::<_, CAP, FORMAT>(n, &mut string, options)?;
+    Ok(string)
+}
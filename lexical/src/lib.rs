@@ -47,6 +47,18 @@
 #![cfg_attr(feature = "parse", doc = " - [`parse_partial`]")]
 #![cfg_attr(feature = "parse", doc = " - [`parse_with_options`]")]
 #![cfg_attr(feature = "parse", doc = " - [`parse_partial_with_options`]")]
+#![cfg_attr(feature = "parse", doc = " - [`parse_or`]")]
+#![cfg_attr(feature = "parse", doc = " - [`parse_or_else`]")]
+#![cfg_attr(feature = "parse", doc = " - [`parse_optional`]")]
+#![cfg_attr(feature = "parse", doc = " - [`parse_optional_with_options`]")]
+#![cfg_attr(feature = "parse", doc = " - [`parse_in_range`]")]
+#![cfg_attr(feature = "parse", doc = " - [`parse_in_range_with_options`]")]
+#![cfg_attr(all(feature = "parse", feature = "std"), doc = " - [`parse_cstr`]")]
+#![cfg_attr(all(feature = "parse", feature = "std"), doc = " - [`parse_cstr_with_options`]")]
+#![cfg_attr(feature = "parse", doc = " - [`parse_until_nul`]")]
+#![cfg_attr(feature = "parse", doc = " - [`parse_until_nul_with_options`]")]
+#![cfg_attr(feature = "parse", doc = " - [`parse_many`]")]
+#![cfg_attr(feature = "parse", doc = " - [`parse_many_with_options`]")]
 //!
 //! # Features
 //!
@@ -133,7 +145,9 @@
 //! This requires substantially more static storage than `power-of-two`,
 //! and increases compile times by a fair amount, but can be quite useful
 //! for esoteric programming languages which use duodecimal floats, for
-//! example.
+//! example. With `parse-floats`, this also adds `rebase` and
+//! `rebase_integer`, to convert numeric strings between radixes using
+//! exact arithmetic.
 //!
 //! #### compact
 //!
@@ -141,15 +155,86 @@
 //! the number of static tables, inlining, and generics used, drastically
 //! reducing the size of the generated binaries.
 //!
+//! #### simd
+//!
+//! Use SIMD instructions (SSE4.1 on x86, NEON on `aarch64`) to validate
+//! and convert 16 decimal digits at a time when parsing long numbers,
+//! with a scalar fallback. On x86, the CPU features are detected at
+//! runtime when the `std` feature is enabled. This requires the multi-digit
+//! optimizations to be enabled for integers.
+//!
+//! #### unicode
+//!
+//! Enable parsing integers from strings containing Unicode decimal digits,
+//! such as the Arabic-Indic `٠١٢` or fullwidth `０１２` digits, through
+//! the `FromLexicalUnicode` trait. Only decimal integers are supported.
+//!
+//! #### serde
+//!
+//! Add the [`serde`](crate::serde) module, with helpers for
+//! `#[serde(with = "lexical::serde::f64")]` to serialize numbers as
+//! strings, and deserialize them from numeric strings.
+//!
+//! #### nom
+//!
+//! Add the [`nom`](crate::nom) module, with adapters that wrap the partial
+//! parsers as [`nom`](::nom) parsers returning an `IResult`, to use lexical
+//! directly in parser-combinator grammars.
+//!
+//! #### bytes
+//!
+//! Add the [`bytes`](crate::bytes) module, with writers that format
+//! numbers directly into any [`BufMut`](::bytes::BufMut), such as a
+//! `BytesMut`, without an intermediate buffer.
+//!
+//! #### arrayvec
+//!
+//! Add the [`arrayvec`](crate::arrayvec) module, with writers that format
+//! numbers into a fixed-capacity [`ArrayString`](::arrayvec::ArrayString),
+//! returning an error if the capacity is insufficient.
+//!
+//! #### heapless
+//!
+//! Add the [`heapless`](crate::heapless) module, with writers that format
+//! numbers into a fixed-capacity [`String`](::heapless::String), returning
+//! an error if the capacity is insufficient.
+//!
+//! #### fuzz
+//!
+//! Add the [`fuzz`](crate::fuzz) module, with oracles that compare
+//! parsing against Rust's core library and check that written numbers
+//! round-trip, so fuzz targets and property tests do not need to
+//! reimplement them.
+//!
+//! #### macros
+//!
+//! Add the `lexical_parse!` macro, which parses a numeric string literal
+//! at compile time and expands to a typed constant, supporting signs,
+//! digit separators, and non-decimal radixes:
+//! `lexical_parse!("1_234.5e6", f64)`.
+//!
+//! #### proptest
+//!
+//! Implement [`Arbitrary`] for the options and number format builder,
+//! so downstream crates can property-test their code against random,
+//! valid configurations. This requires the `std` feature.
+//!
+//! [`Arbitrary`]: https://docs.rs/proptest/latest/proptest/arbitrary/trait.Arbitrary.html
+//!
 //! #### safe
 //!
-//! This replaces most unchecked indexing, required in cases where the
-//! compiler cannot elide the check, with checked indexing. However,
-//! it does not fully replace all unsafe behavior with safe behavior.
-//! To minimize the risk of undefined behavior and out-of-bounds reads/writers,
-//! extensive edge-cases, property-based tests, and fuzzing is done with both
-//! the safe feature enabled and disabled, with the tests verified by Miri
-//! and Valgrind.
+//! This replaces all unchecked indexing, required in cases where the
+//! compiler cannot elide the check, with checked indexing, and all raw
+//! pointer reads and writes with safe slice operations. The SIMD routines
+//! enabled by `simd` are also replaced with the scalar fallback. This is
+//! slower, but an out-of-bounds access panics rather than causing undefined
+//! behavior. The only remaining unsafe code converts the initialized bytes
+//! for `write_uninit`, since uninitialized buffers cannot be written to
+//! safely otherwise, and sets the x87 FPU precision on x86 targets without
+//! SSE2. To minimize the risk of undefined behavior and out-of-bounds
+//! reads/writes in the default algorithms, extensive edge-cases,
+//! property-based tests, and fuzzing is done with both the safe feature
+//! enabled and disabled, with the tests verified by Miri and Valgrind.
 //!
 //! # Configuration API
 //!
@@ -323,37 +408,125 @@ compile_error!(
 
 // Need an allocator for String/Vec.
 #[cfg(feature = "write")]
-#[macro_use(vec)]
 extern crate alloc;
 
 #[cfg(feature = "write")]
 use alloc::string::String;
+#[cfg(feature = "write")]
+use alloc::vec::Vec;
+#[cfg(feature = "parse")]
+use core::ops::RangeBounds;
+
+pub mod arrayvec;
+pub mod bytes;
+pub mod fuzz;
+pub mod heapless;
+pub mod nom;
+pub mod serde;
 
+#[cfg(feature = "write-integers")]
+pub use lexical_core::digit_count;
 pub use lexical_core::format::{self, format_error, format_is_valid, NumberFormatBuilder};
-#[cfg(feature = "parse")]
-pub use lexical_core::Error;
+#[cfg(feature = "parse-integers")]
+pub use lexical_core::parse_const;
+#[cfg(feature = "write-floats")]
+pub use lexical_core::shortest_digits;
+#[cfg(feature = "write-integers")]
+pub use lexical_core::write_const;
+#[cfg(all(feature = "std", any(feature = "parse-floats", feature = "write-floats")))]
+pub use lexical_core::Decimal;
+#[cfg(all(feature = "std", any(feature = "parse", feature = "write")))]
+pub use lexical_core::Format;
+#[cfg(all(feature = "parse-integers", feature = "unicode"))]
+pub use lexical_core::FromLexicalUnicode;
 #[cfg(feature = "parse")]
 pub use lexical_core::ParseOptions;
 #[cfg(feature = "parse")]
 pub use lexical_core::Result;
 #[cfg(feature = "write")]
-pub use lexical_core::WriteOptions;
+pub use lexical_core::{Alignment, WriteOptions};
+#[cfg(feature = "parse-floats")]
+pub use lexical_core::{assemble, parse_raw, RawNumber};
 #[cfg(feature = "f16")]
 pub use lexical_core::{bf16, f16};
 #[cfg(feature = "parse-floats")]
-pub use lexical_core::{parse_float_options, ParseFloatOptions, ParseFloatOptionsBuilder};
+pub use lexical_core::{
+    compare_halfway,
+    compare_halfway_with_options,
+    parse_bigint,
+    parse_bigint_with_options,
+};
+#[cfg(feature = "write-floats")]
+pub use lexical_core::{
+    exponential_buffer_size,
+    fixed_buffer_size,
+    write_complex,
+    write_complex_with_options,
+    write_exponential,
+    write_exponential_with_rounding,
+    write_fixed,
+    write_fixed_with_rounding,
+    write_printf,
+    PrintfSpec,
+};
+#[cfg(feature = "parse-floats")]
+pub use lexical_core::{is_exact, is_exact_with_options};
+#[cfg(feature = "parse-floats")]
+pub use lexical_core::{is_float, is_float_with_options};
+#[cfg(feature = "parse-integers")]
+pub use lexical_core::{is_integer, is_integer_with_options};
+#[cfg(feature = "parse-floats")]
+pub use lexical_core::{parse_complex, parse_complex_with_options};
+#[cfg(all(feature = "parse", feature = "std"))]
+pub use lexical_core::{parse_cstr, parse_cstr_with_options};
+#[cfg(feature = "parse-floats")]
+pub use lexical_core::{parse_exactness, parse_exactness_with_options};
+#[cfg(feature = "parse-floats")]
+pub use lexical_core::{
+    parse_float_options,
+    FloatParser,
+    ParseFloatOptions,
+    ParseFloatOptionsBuilder,
+};
 #[cfg(feature = "parse-integers")]
 pub use lexical_core::{parse_integer_options, ParseIntegerOptions, ParseIntegerOptionsBuilder};
+#[cfg(feature = "parse-floats")]
+pub use lexical_core::{parse_interval, parse_interval_with_options};
+#[cfg(feature = "parse-floats")]
+pub use lexical_core::{parse_lossy, parse_partial_lossy};
+#[cfg(feature = "parse-floats")]
+pub use lexical_core::{parse_parts, parse_parts_with_options, NumberParts};
+#[cfg(feature = "parse-floats")]
+pub use lexical_core::{parse_ratio, parse_ratio_parts, Ratio};
+#[cfg(all(feature = "parse-floats", feature = "radix"))]
+pub use lexical_core::{rebase, rebase_buffer_size, rebase_integer};
 #[cfg(feature = "write-floats")]
 pub use lexical_core::{write_float_options, WriteFloatOptions, WriteFloatOptionsBuilder};
 #[cfg(feature = "write-integers")]
 pub use lexical_core::{write_integer_options, WriteIntegerOptions, WriteIntegerOptionsBuilder};
 #[cfg(feature = "write")]
-pub use lexical_core::{FormattedSize, BUFFER_SIZE};
+pub use lexical_core::{
+    BufferTooSmall,
+    Digits,
+    DisplayLexical,
+    DisplayLexicalWithOptions,
+    FormattedSize,
+    BUFFER_SIZE,
+};
+#[cfg(feature = "parse")]
+pub use lexical_core::{Delimiter, ParseMany};
+#[cfg(feature = "parse")]
+pub use lexical_core::{CharacterClass, Error, ErrorKind};
 #[cfg(feature = "parse")]
 pub use lexical_core::{FromLexical, FromLexicalWithOptions};
+#[cfg(all(feature = "parse-floats", feature = "format"))]
+pub use lexical_core::{JsonF32, JsonF64};
+#[cfg(feature = "parse-floats")]
+pub use lexical_core::{LossyF32, LossyF64};
 #[cfg(feature = "write")]
 pub use lexical_core::{ToLexical, ToLexicalWithOptions};
+#[cfg(feature = "macros")]
+pub use lexical_macros::lexical_parse;
 
 // NOTE: We cannot just use an uninitialized vector with excess capacity and
 // then use read-assign rather than `ptr::write` or `MaybeUninit.write` to
@@ -378,6 +551,8 @@ pub use lexical_core::{ToLexical, ToLexicalWithOptions};
 // Currently, since LLVM treats it as unspecified behavior and will not drop
 // values, there is no risk of a memory leak and this is **currently** safe.
 // However, this can explode at any time, just like any undefined behavior.
+// Instead, we write to the spare capacity as `MaybeUninit<u8>`, which
+// initializes the bytes before they are written.
 
 /// High-level conversion of a number to a decimal-encoded string.
 ///
@@ -395,15 +570,20 @@ pub use lexical_core::{ToLexical, ToLexicalWithOptions};
 #[inline]
 #[cfg(feature = "write")]
 pub fn to_string<N: ToLexical>(n: N) -> String {
-    let mut buf = vec![0u8; N::FORMATTED_SIZE_DECIMAL];
-    let len = lexical_core::write(n, buf.as_mut_slice()).len();
-
-    // SAFETY: safe since the buffer is of sufficient size, len() must be <= the vec
-    // size.
-    unsafe {
-        buf.set_len(len);
-        String::from_utf8_unchecked(buf)
+    let mut buf = Vec::with_capacity(N::FORMATTED_SIZE_DECIMAL);
+    #[cfg(not(feature = "safe"))]
+    {
+        let len = lexical_core::write_uninit(n, buf.spare_capacity_mut()).len();
+        // SAFETY: safe since the first `len` bytes were initialized.
+        unsafe { buf.set_len(len) };
     }
+    #[cfg(feature = "safe")]
+    {
+        buf.resize(N::FORMATTED_SIZE_DECIMAL, 0);
+        let len = lexical_core::write(n, &mut buf).len();
+        buf.truncate(len);
+    }
+    into_string(buf)
 }
 
 /// High-level conversion of a number to a string with custom writing options.
@@ -434,16 +614,51 @@ pub fn to_string_with_options<N: ToLexicalWithOptions, const FORMAT: u128>(
     // Need to use the `buffer_size` hint to properly deal with float formatting
     // options.
     let size = N::Options::buffer_size::<N, FORMAT>(options);
-    let mut buf = vec![0u8; size];
-    let slc = buf.as_mut_slice();
-    let len = lexical_core::write_with_options::<_, FORMAT>(n, slc, options).len();
-
-    // SAFETY: safe since the buffer is of sufficient size, `len()` must be <= the
-    // vec size.
-    unsafe {
-        buf.set_len(len);
-        String::from_utf8_unchecked(buf)
+    let mut buf = Vec::with_capacity(size);
+    #[cfg(not(feature = "safe"))]
+    {
+        let slc = buf.spare_capacity_mut();
+        let len = lexical_core::write_with_options_uninit::<_, FORMAT>(n, slc, options).len();
+        // SAFETY: safe since the first `len` bytes were initialized.
+        unsafe { buf.set_len(len) };
+    }
+    #[cfg(feature = "safe")]
+    {
+        buf.resize(size, 0);
+        let len = lexical_core::write_with_options::<_, FORMAT>(n, &mut buf, options).len();
+        buf.truncate(len);
     }
+    into_string(buf)
+}
+
+/// Convert the bytes of a written number to a string.
+#[inline(always)]
+#[cfg(feature = "write")]
+fn into_string(buf: Vec<u8>) -> String {
+    #[cfg(not(feature = "safe"))]
+    // SAFETY: safe since the written number is always ASCII.
+    let string = unsafe { String::from_utf8_unchecked(buf) };
+    #[cfg(feature = "safe")]
+    let string = match String::from_utf8(buf) {
+        Ok(string) => string,
+        Err(_) => unreachable!("the written number is always ASCII"),
+    };
+    string
+}
+
+/// Convert the bytes of a written number to a string slice.
+#[inline(always)]
+#[cfg(all(feature = "write", any(feature = "arrayvec", feature = "heapless")))]
+fn ascii_to_str(bytes: &[u8]) -> &str {
+    #[cfg(not(feature = "safe"))]
+    // SAFETY: safe since the written number is always ASCII.
+    let string = unsafe { core::str::from_utf8_unchecked(bytes) };
+    #[cfg(feature = "safe")]
+    let string = match core::str::from_utf8(bytes) {
+        Ok(string) => string,
+        Err(_) => unreachable!("the written number is always ASCII"),
+    };
+    string
 }
 
 /// High-level conversion of decimal-encoded bytes to a number.
@@ -611,3 +826,310 @@ pub fn parse_partial_with_options<
 ) -> Result<(N, usize)> {
     N::from_lexical_partial_with_options::<FORMAT>(bytes.as_ref(), options)
 }
+
+/// High-level conversion of bytes to a number, returning `default` on any
+/// error.
+///
+/// This is identical to [`parse`], except invalid input returns `default`
+/// rather than an error. This is inlined, so the error is never used and
+/// the compiler can remove the code to create it, which is useful when
+/// malformed input is expected, such as cleaning bulk data.
+///
+/// * `bytes`   - Byte slice to convert to number.
+/// * `default` - Value for invalid input.
+///
+/// # Examples
+///
+/// ```rust
+/// # pub fn main() {
+/// #[cfg(all(feature = "parse-integers", feature = "parse-floats"))] {
+/// assert_eq!(lexical::parse_or("15", 0u32), 15);
+/// assert_eq!(lexical::parse_or("1a", 0u32), 0);
+/// assert!(lexical::parse_or(b"NA", f64::NAN).is_nan());
+/// # }
+/// # }
+/// ```
+#[inline(always)]
+#[cfg(feature = "parse")]
+pub fn parse_or<N: FromLexical, Bytes: AsRef<[u8]>>(bytes: Bytes, default: N) -> N {
+    lexical_core::parse_or(bytes.as_ref(), default)
+}
+
+/// High-level conversion of bytes to a number, computing a fallback from
+/// a closure on any error.
+///
+/// This is identical to [`parse_or`], except the fallback is only computed
+/// if the input is invalid.
+///
+/// * `bytes`   - Byte slice to convert to number.
+/// * `default` - Closure to compute the value for invalid input.
+///
+/// # Examples
+///
+/// ```rust
+/// # pub fn main() {
+/// #[cfg(feature = "parse-integers")] {
+/// assert_eq!(lexical::parse_or_else("-3", || i8::MIN), -3);
+/// assert_eq!(lexical::parse_or_else("300", || i8::MIN), i8::MIN);
+/// # }
+/// # }
+/// ```
+#[inline(always)]
+#[cfg(feature = "parse")]
+pub fn parse_or_else<N: FromLexical, Bytes: AsRef<[u8]>, F: FnOnce() -> N>(
+    bytes: Bytes,
+    default: F,
+) -> N {
+    lexical_core::parse_or_else(bytes.as_ref(), default)
+}
+
+/// High-level conversion of bytes to a number, rejecting values outside
+/// `range`.
+///
+/// This is identical to [`parse`], except a value outside `range` returns
+/// [`Error::OutOfRange`]. A `NaN` is rejected unless the range is unbounded.
+///
+/// * `bytes` - Byte slice to convert to number.
+/// * `range` - Range of allowed values.
+///
+/// # Examples
+///
+/// ```rust
+/// # pub fn main() {
+/// #[cfg(all(feature = "parse-integers", feature = "parse-floats"))] {
+/// use lexical::Error;
+///
+/// assert_eq!(lexical::parse_in_range("443", 1u16..), Ok(443));
+/// assert_eq!(lexical::parse_in_range("0", 1u16..), Err(Error::OutOfRange(0)));
+/// assert_eq!(lexical::parse_in_range("-0.5", 0.0f64..1.0), Err(Error::OutOfRange(0)));
+/// assert_eq!(lexical::parse_in_range("NaN", 0.0f64..1.0), Err(Error::OutOfRange(0)));
+/// # }
+/// # }
+/// ```
+#[inline]
+#[cfg(feature = "parse")]
+pub fn parse_in_range<N, Bytes, R>(bytes: Bytes, range: R) -> Result<N>
+where
+    N: FromLexical + PartialOrd,
+    Bytes: AsRef<[u8]>,
+    R: RangeBounds<N>,
+{
+    lexical_core::parse_in_range(bytes.as_ref(), range)
+}
+
+/// High-level conversion of bytes to a number with custom parsing
+/// options, rejecting values outside `range`.
+///
+/// This is identical to [`parse_with_options`], except a value outside
+/// `range` returns [`Error::OutOfRange`].
+///
+/// * `FORMAT`  - Packed struct containing the number format.
+/// * `bytes`   - Byte slice to convert to number.
+/// * `options` - Options to specify number parsing.
+/// * `range`   - Range of allowed values.
+///
+/// # Examples
+///
+/// ```rust
+/// # pub fn main() {
+/// #[cfg(all(feature = "parse-integers", feature = "format"))] {
+/// const FORMAT: u128 = lexical::format::STANDARD;
+/// let options = lexical::ParseIntegerOptions::new();
+/// let result = lexical::parse_in_range_with_options::<u8, _, _, FORMAT>("90", &options, 0..=100);
+/// assert_eq!(result, Ok(90));
+/// # }
+/// # }
+/// ```
+#[inline]
+#[cfg(feature = "parse")]
+pub fn parse_in_range_with_options<N, Bytes, R, const FORMAT: u128>(
+    bytes: Bytes,
+    options: &N::Options,
+    range: R,
+) -> Result<N>
+where
+    N: FromLexicalWithOptions + PartialOrd,
+    Bytes: AsRef<[u8]>,
+    R: RangeBounds<N>,
+{
+    lexical_core::parse_in_range_with_options::<N, R, FORMAT>(bytes.as_ref(), options, range)
+}
+
+/// High-level conversion of bytes to a number, stopping at the first NUL
+/// byte.
+///
+/// This is identical to [`parse`], except only the bytes before the first
+/// NUL byte are parsed, or all the bytes if there is no NUL byte, like a
+/// fixed-size buffer filled by C code.
+///
+/// * `bytes` - Byte slice to convert to number.
+///
+/// # Examples
+///
+/// ```rust
+/// # pub fn main() {
+/// #[cfg(feature = "parse-integers")] {
+/// assert_eq!(lexical::parse_until_nul::<u32, _>(b"443\0\0\0"), Ok(443));
+/// assert_eq!(lexical::parse_until_nul::<u32, _>("443"), Ok(443));
+/// # }
+/// # }
+/// ```
+#[inline]
+#[cfg(feature = "parse")]
+pub fn parse_until_nul<N: FromLexical, Bytes: AsRef<[u8]>>(bytes: Bytes) -> Result<N> {
+    lexical_core::parse_until_nul(bytes.as_ref())
+}
+
+/// High-level conversion of bytes to a number with custom parsing
+/// options, stopping at the first NUL byte.
+///
+/// This is identical to [`parse_with_options`], except only the bytes
+/// before the first NUL byte are parsed. See [`parse_until_nul`].
+///
+/// * `FORMAT`  - Packed struct containing the number format.
+/// * `bytes`   - Byte slice to convert to number.
+/// * `options` - Options to specify number parsing.
+///
+/// # Examples
+///
+/// ```rust
+/// # pub fn main() {
+/// #[cfg(all(feature = "parse-floats", feature = "format"))] {
+/// const FORMAT: u128 = lexical::format::STANDARD;
+/// let options = lexical::ParseFloatOptions::new();
+/// let result = lexical::parse_until_nul_with_options::<f64, _, FORMAT>(b"1.5\0", &options);
+/// assert_eq!(result, Ok(1.5));
+/// # }
+/// # }
+/// ```
+#[inline]
+#[cfg(feature = "parse")]
+pub fn parse_until_nul_with_options<N, Bytes, const FORMAT: u128>(
+    bytes: Bytes,
+    options: &N::Options,
+) -> Result<N>
+where
+    N: FromLexicalWithOptions,
+    Bytes: AsRef<[u8]>,
+{
+    lexical_core::parse_until_nul_with_options::<N, FORMAT>(bytes.as_ref(), options)
+}
+
+/// High-level conversion of bytes to a number, returning `None` for blank
+/// input.
+///
+/// Empty input, or input with only ASCII whitespace, returns `Ok(None)`,
+/// like a null column in a CSV file or database. Otherwise, this is
+/// identical to [`parse`].
+///
+/// * `bytes` - Byte slice to convert to number.
+///
+/// # Examples
+///
+/// ```rust
+/// # pub fn main() {
+/// #[cfg(feature = "parse-floats")] {
+/// assert_eq!(lexical::parse_optional::<f64, _>("2.5"), Ok(Some(2.5)));
+/// assert_eq!(lexical::parse_optional::<f64, _>("  "), Ok(None));
+/// assert!(lexical::parse_optional::<f64, _>("2.5 ").is_err());
+/// # }
+/// # }
+/// ```
+#[inline]
+#[cfg(feature = "parse")]
+pub fn parse_optional<N: FromLexical, Bytes: AsRef<[u8]>>(bytes: Bytes) -> Result<Option<N>> {
+    lexical_core::parse_optional(bytes.as_ref())
+}
+
+/// High-level conversion of bytes to a number with custom parsing
+/// options, returning `None` for blank input.
+///
+/// This is identical to [`parse_with_options`], except empty input, or
+/// input with only ASCII whitespace, returns `Ok(None)`.
+///
+/// * `FORMAT`  - Packed struct containing the number format.
+/// * `bytes`   - Byte slice to convert to number.
+/// * `options` - Options to specify number parsing.
+///
+/// # Examples
+///
+/// ```rust
+/// # pub fn main() {
+/// #[cfg(all(feature = "parse-integers", feature = "format"))] {
+/// const FORMAT: u128 = lexical::format::STANDARD;
+/// let options = lexical::ParseIntegerOptions::new();
+/// let result = lexical::parse_optional_with_options::<i64, _, FORMAT>("", &options);
+/// assert_eq!(result, Ok(None));
+/// # }
+/// # }
+/// ```
+#[inline]
+#[cfg(feature = "parse")]
+pub fn parse_optional_with_options<
+    N: FromLexicalWithOptions,
+    Bytes: AsRef<[u8]>,
+    const FORMAT: u128,
+>(
+    bytes: Bytes,
+    options: &N::Options,
+) -> Result<Option<N>> {
+    lexical_core::parse_optional_with_options::<N, FORMAT>(bytes.as_ref(), options)
+}
+
+/// High-level conversion of a delimited buffer to many numbers.
+///
+/// Returns an iterator over the result of parsing each number, without
+/// splitting the buffer first. See [`lexical_core::parse_many`] for how
+/// the buffer is split. Error indexes are relative to the start of the
+/// buffer.
+///
+/// * `bytes`     - Byte slice containing the numbers.
+/// * `delimiter` - Delimiter between the numbers.
+///
+/// # Examples
+///
+/// ```rust
+/// # pub fn main() {
+/// #[cfg(feature = "parse-integers")] {
+/// use lexical::Delimiter;
+///
+/// let values = lexical::parse_many::<i32, _>("1 2\n-3", Delimiter::Whitespace);
+/// assert_eq!(values.collect::<Result<Vec<_>, _>>(), Ok(vec![1, 2, -3]));
+/// let values = lexical::parse_many::<i32, _>("4; 5", Delimiter::Byte(b';'));
+/// assert_eq!(values.collect::<Result<Vec<_>, _>>(), Ok(vec![4, 5]));
+/// # }
+/// # }
+/// ```
+#[inline]
+#[cfg(feature = "parse")]
+pub fn parse_many<'a, N, Bytes>(bytes: &'a Bytes, delimiter: Delimiter) -> ParseMany<'a, N>
+where
+    N: FromLexical + FromLexicalWithOptions,
+    Bytes: AsRef<[u8]> + ?Sized,
+{
+    lexical_core::parse_many(bytes.as_ref(), delimiter)
+}
+
+/// High-level conversion of a delimited buffer to many numbers with
+/// custom parsing options.
+///
+/// This is identical to [`parse_many`], except each number is parsed with
+/// [`parse_with_options`].
+///
+/// * `FORMAT`    - Packed struct containing the number format.
+/// * `bytes`     - Byte slice containing the numbers.
+/// * `delimiter` - Delimiter between the numbers.
+/// * `options`   - Options to specify number parsing.
+#[inline]
+#[cfg(feature = "parse")]
+pub fn parse_many_with_options<'a, N, Bytes, const FORMAT: u128>(
+    bytes: &'a Bytes,
+    delimiter: Delimiter,
+    options: &'a N::Options,
+) -> ParseMany<'a, N, FORMAT>
+where
+    N: FromLexical + FromLexicalWithOptions,
+    Bytes: AsRef<[u8]> + ?Sized,
+{
+    lexical_core::parse_many_with_options::<N, FORMAT>(bytes.as_ref(), delimiter, options)
+}
@@ -0,0 +1,161 @@
+//! Adapters to use lexical's parsers as [`nom`](::nom) parsers.
+//!
+//! Each parser consumes the longest number at the start of the input,
+//! using [`parse_partial`], and returns the remaining input and the parsed
+//! value as an [`IResult`]. The parsers are generic over the input type,
+//! so they may be used with byte slices, strings, or custom span types
+//! such as those from `nom_locate`. Every parser has complete semantics:
+//! a number at the end of the input is never reported as incomplete.
+//!
+//! The lexical [`Error`] is passed to [`FromExternalError`], so error
+//! types that store external errors can retain the cause and index
+//! of the failure.
+//!
+//! ```rust
+//! # #[cfg(all(feature = "parse-floats", feature = "parse-integers"))] {
+//! use nom::bytes::complete::tag;
+//! use nom::sequence::separated_pair;
+//! use nom::IResult;
+//!
+//! fn point(input: &str) -> IResult<&str, (f64, i32)> {
+//!     separated_pair(lexical::nom::f64, tag(","), lexical::nom::i32)(input)
+//! }
+//!
+//! assert_eq!(point("1.5,-3 rest"), Ok((" rest", (1.5, -3))));
+//! assert!(point("x,-3").is_err());
+//! # }
+//! ```
+//!
+//! [`parse_partial`]: crate::parse_partial
+
+#![cfg(all(feature = "nom", feature = "parse"))]
+
+use core::ops::RangeFrom;
+
+use ::nom::error::{ErrorKind as NomErrorKind, FromExternalError, ParseError};
+use ::nom::{AsBytes, Err, IResult, Slice};
+use lexical_core::{Error, FromLexical, FromLexicalWithOptions};
+
+/// Convert the result of a partial parser to an [`IResult`].
+#[inline(always)]
+fn to_iresult<I, N, E>(
+    input: I,
+    result: Result<(N, usize), Error>,
+    kind: NomErrorKind,
+) -> IResult<I, N, E>
+where
+    I: AsBytes + Slice<RangeFrom<usize>>,
+    E: ParseError<I> + FromExternalError<I, Error>,
+{
+    match result {
+        // The partial integer parsers succeed without consuming any digits
+        // if the input starts with an invalid digit, which would cause
+        // combinators such as `many0` to loop forever.
+        Ok((_, count)) if !has_digits(&input.as_bytes()[..count]) => {
+            Err(Err::Error(E::from_external_error(input, kind, Error::Empty(count))))
+        },
+        Ok((value, count)) => Ok((input.slice(count..), value)),
+        Err(error) => Err(Err::Error(E::from_external_error(input, kind, error))),
+    }
+}
+
+/// Determine if the consumed bytes contain more than a sign.
+#[inline(always)]
+fn has_digits(bytes: &[u8]) -> bool {
+    !matches!(bytes, [] | [b'+' | b'-'])
+}
+
+/// Parse the number at the start of the input.
+///
+/// Integers report errors using [`ErrorKind::Digit`], and floats
+/// using [`ErrorKind::Float`].
+///
+/// # Errors
+///
+/// Returns [`Err::Error`] if the input does not start with a valid
+/// number, or if the number overflows.
+///
+/// [`ErrorKind::Digit`]: ::nom::error::ErrorKind::Digit
+/// [`ErrorKind::Float`]: ::nom::error::ErrorKind::Float
+#[inline]
+pub fn parse<I, N, E>(input: I) -> IResult<I, N, E>
+where
+    I: AsBytes + Slice<RangeFrom<usize>>,
+    N: FromLexical + NomNumber,
+    E: ParseError<I> + FromExternalError<I, Error>,
+{
+    let result = N::from_lexical_partial(input.as_bytes());
+    to_iresult(input, result, N::ERROR_KIND)
+}
+
+/// Create a parser for the number at the start of the input, using custom
+/// parsing options.
+///
+/// # Errors
+///
+/// The parser returns [`Err::Error`] if the input does not start with
+/// a valid number, or if the number overflows.
+///
+/// # Examples
+///
+/// ```rust
+/// # #[cfg(all(feature = "parse-floats", feature = "format"))] {
+/// use lexical::{format::JSON, parse_float_options};
+/// use nom::IResult;
+///
+/// let options = parse_float_options::JSON;
+/// let mut parser = lexical::nom::parse_with_options::<_, f64, _, JSON>(&options);
+/// let result: IResult<&[u8], f64> = parser(b"1.5]");
+/// assert_eq!(result, Ok((&b"]"[..], 1.5)));
+/// assert!(parser(b"NaN").is_err());
+/// # }
+/// ```
+#[inline]
+pub fn parse_with_options<I, N, E, const FORMAT: u128>(
+    options: &N::Options,
+) -> impl FnMut(I) -> IResult<I, N, E> + '_
+where
+    I: AsBytes + Slice<RangeFrom<usize>>,
+    N: FromLexicalWithOptions + NomNumber,
+    E: ParseError<I> + FromExternalError<I, Error>,
+{
+    move |input: I| {
+        let result = N::from_lexical_partial_with_options::<FORMAT>(input.as_bytes(), options);
+        to_iresult(input, result, N::ERROR_KIND)
+    }
+}
+
+/// A number that can be parsed with the nom adapters.
+pub trait NomNumber {
+    /// The kind of error to report when parsing fails.
+    const ERROR_KIND: NomErrorKind;
+}
+
+macro_rules! nom_parser {
+    ($kind:ident ; $($t:ident)*) => ($(
+        impl NomNumber for $t {
+            const ERROR_KIND: NomErrorKind = NomErrorKind::$kind;
+        }
+
+        #[doc = concat!("Parse a `", stringify!($t), "` at the start of the input.")]
+        ///
+        /// # Errors
+        ///
+        /// Returns [`Err::Error`] if the input does not start with a valid
+        /// number, or if the number overflows.
+        #[inline]
+        pub fn $t<I, E>(input: I) -> IResult<I, $t, E>
+        where
+            I: AsBytes + Slice<RangeFrom<usize>>,
+            E: ParseError<I> + FromExternalError<I, Error>,
+        {
+            parse(input)
+        }
+    )*);
+}
+
+#[cfg(feature = "parse-integers")]
+nom_parser! { Digit ; u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize }
+
+#[cfg(feature = "parse-floats")]
+nom_parser! { Float ; f32 f64 }
@@ -0,0 +1,121 @@
+//! Helpers to serialize and deserialize numbers as strings with serde.
+//!
+//! Each module is named after the number type, and is meant to be used
+//! with `#[serde(with = "...")]`, to preserve precision for APIs that
+//! quote numbers, such as 64-bit integers or decimals in JSON. Numbers
+//! are serialized using [`to_string`], and deserialized from strings
+//! using [`parse`].
+//!
+//! ```rust,ignore
+//! #[derive(Serialize, Deserialize)]
+//! struct Balance {
+//!     #[serde(with = "lexical::serde::u64")]
+//!     id: u64,
+//!     #[serde(with = "lexical::serde::f64")]
+//!     amount: f64,
+//! }
+//! ```
+//!
+//! [`to_string`]: crate::to_string
+//! [`parse`]: crate::parse
+
+#![cfg(feature = "serde")]
+
+#[cfg(feature = "parse")]
+use core::{fmt, marker::PhantomData};
+
+#[cfg(feature = "write")]
+use ::serde::Serializer;
+#[cfg(feature = "parse")]
+use ::serde::{de, Deserializer};
+#[cfg(feature = "parse")]
+use lexical_core::FromLexical;
+#[cfg(feature = "write")]
+use lexical_core::{ToLexical, BUFFER_SIZE};
+
+/// Serialize a number as a string.
+#[inline]
+#[cfg(feature = "write")]
+fn serialize<N: ToLexical, S: Serializer>(value: N, serializer: S) -> Result<S::Ok, S::Error> {
+    let mut buffer = [0u8; BUFFER_SIZE];
+    let bytes = lexical_core::write(value, &mut buffer);
+    #[cfg(not(feature = "safe"))]
+    // SAFETY: safe since the written number is always ASCII.
+    let string = unsafe { core::str::from_utf8_unchecked(bytes) };
+    #[cfg(feature = "safe")]
+    let string = match core::str::from_utf8(bytes) {
+        Ok(string) => string,
+        Err(_) => unreachable!("the written number is always ASCII"),
+    };
+    serializer.serialize_str(string)
+}
+
+/// Visitor to parse a number from a string.
+#[cfg(feature = "parse")]
+struct NumberVisitor<N>(PhantomData<N>);
+
+#[cfg(feature = "parse")]
+impl<N: FromLexical> de::Visitor<'_> for NumberVisitor<N> {
+    type Value = N;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("a numeric string")
+    }
+
+    fn visit_str<E: de::Error>(self, value: &str) -> Result<N, E> {
+        self.visit_bytes(value.as_bytes())
+    }
+
+    fn visit_bytes<E: de::Error>(self, value: &[u8]) -> Result<N, E> {
+        lexical_core::parse(value).map_err(E::custom)
+    }
+}
+
+/// Deserialize a number from a string.
+#[inline]
+#[cfg(feature = "parse")]
+fn deserialize<'de, N: FromLexical, D: Deserializer<'de>>(deserializer: D) -> Result<N, D::Error> {
+    deserializer.deserialize_str(NumberVisitor(PhantomData))
+}
+
+macro_rules! serde_module {
+    ($write:meta, $parse:meta ; $($t:ident)*) => ($(
+        #[doc = concat!("Serialize and deserialize `", stringify!($t), "` as a string.")]
+        pub mod $t {
+            /// Serialize the number as a string.
+            ///
+            /// # Errors
+            ///
+            /// Returns an error if the serializer fails.
+            #[inline]
+            #[cfg($write)]
+            pub fn serialize<S: ::serde::Serializer>(value: &$t, serializer: S) -> Result<S::Ok, S::Error> {
+                super::serialize(*value, serializer)
+            }
+
+            /// Deserialize the number from a string.
+            ///
+            /// # Errors
+            ///
+            /// Returns an error if the value is not a string, or if the
+            /// string is not a valid number.
+            #[inline]
+            #[cfg($parse)]
+            pub fn deserialize<'de, D: ::serde::Deserializer<'de>>(deserializer: D) -> Result<$t, D::Error> {
+                super::deserialize(deserializer)
+            }
+        }
+    )*);
+}
+
+#[cfg(feature = "integers")]
+serde_module! {
+    feature = "write-integers", feature = "parse-integers" ;
+    u8 u16 u32 u64 u128 usize i8 i16 i32 i64 i128 isize
+}
+
+#[cfg(feature = "floats")]
+serde_module! {
+    feature = "write-floats", feature = "parse-floats" ;
+    f32 f64
+}
@@ -31,6 +31,62 @@ fn string_to_integer_test() {
     );
 }
 
+#[test]
+#[cfg(feature = "parse-integers")]
+fn string_to_integer_or_test() {
+    assert_eq!(lexical::parse_or("12345", 0u32), 12345);
+    assert_eq!(lexical::parse_or(b"1a", 0u32), 0);
+    assert_eq!(lexical::parse_or_else(String::from("-7"), || i16::MAX), -7);
+    assert_eq!(lexical::parse_or_else("", || i16::MAX), i16::MAX);
+}
+
+#[test]
+#[cfg(feature = "parse-integers")]
+fn string_to_integer_optional_test() {
+    assert_eq!(lexical::parse_optional::<u32, _>("12345"), Ok(Some(12345)));
+    assert_eq!(lexical::parse_optional::<u32, _>(String::new()), Ok(None));
+    assert_eq!(lexical::parse_optional::<u32, _>(b"\t"), Ok(None));
+    assert!(lexical::parse_optional::<u32, _>("1 2").is_err());
+}
+
+#[test]
+#[cfg(feature = "parse-integers")]
+fn string_to_integer_in_range_test() {
+    assert_eq!(lexical::parse_in_range("80", 1u16..), Ok(80));
+    assert_eq!(
+        lexical::parse_in_range(String::from("0"), 1u16..),
+        Err(lexical::Error::OutOfRange(0))
+    );
+}
+
+#[test]
+#[cfg(feature = "parse-integers")]
+fn string_to_integer_until_nul_test() {
+    use std::ffi::CString;
+
+    assert_eq!(lexical::parse_until_nul::<u32, _>([b'4', b'2', 0, 0]), Ok(42));
+    assert_eq!(lexical::parse_until_nul::<u32, _>("42"), Ok(42));
+    assert_eq!(lexical::parse_until_nul::<u32, _>([0, b'4', b'2']), Err(lexical::Error::Empty(0)));
+    let string = CString::new("-42").unwrap();
+    assert_eq!(lexical::parse_cstr::<i32>(&string), Ok(-42));
+}
+
+#[test]
+#[cfg(feature = "parse-integers")]
+fn string_to_integer_many_test() {
+    use lexical::Delimiter;
+
+    let values = lexical::parse_many::<i32, _>("1 2 3", Delimiter::Whitespace);
+    assert_eq!(values.collect::<Vec<_>>(), [Ok(1), Ok(2), Ok(3)]);
+    let values = lexical::parse_many::<i32, _>(&b"4,5"[..], Delimiter::Comma);
+    assert_eq!(values.collect::<Vec<_>>(), [Ok(4), Ok(5)]);
+    let options = lexical::ParseIntegerOptions::new();
+    const FORMAT: u128 = lexical::format::STANDARD;
+    let values =
+        lexical::parse_many_with_options::<i32, _, FORMAT>("6;x", Delimiter::Byte(b';'), &options);
+    assert_eq!(values.collect::<Vec<_>>(), [Ok(6), Err(lexical::Error::InvalidDigit(2))]);
+}
+
 #[test]
 #[cfg(feature = "parse-floats")]
 fn string_to_float_test() {
@@ -0,0 +1,100 @@
+#![cfg(feature = "bytes")]
+
+use bytes::{BufMut, BytesMut};
+
+#[test]
+#[cfg(feature = "write-integers")]
+fn integer_write_test() {
+    let mut buf = BytesMut::new();
+    assert_eq!(lexical::bytes::write(12345u32, &mut buf), 5);
+    buf.put_u8(b',');
+    assert_eq!(lexical::bytes::write(-128i8, &mut buf), 4);
+    assert_eq!(&buf[..], b"12345,-128");
+
+    let mut vec = b"x=".to_vec();
+    assert_eq!(lexical::bytes::write(u64::MAX, &mut vec), 20);
+    assert_eq!(vec, b"x=18446744073709551615");
+}
+
+#[test]
+#[cfg(feature = "write-floats")]
+fn float_write_test() {
+    let mut buf = BytesMut::with_capacity(64);
+    assert_eq!(lexical::bytes::write(12345.0f32, &mut buf), 7);
+    buf.put_u8(b' ');
+    assert_eq!(lexical::bytes::write(-1.5e300f64, &mut buf), 8);
+    assert_eq!(&buf[..], b"12345.0 -1.5e300");
+}
+
+#[test]
+#[cfg(feature = "write-integers")]
+fn small_chunk_test() {
+    // The slice has room for the number, but not the largest `u64`.
+    let mut array = [0u8; 4];
+    let mut slc = &mut array[..];
+    assert_eq!(lexical::bytes::write(123u64, &mut slc), 3);
+    assert_eq!(slc.len(), 1);
+    assert_eq!(&array, b"123\x00");
+
+    // The number spans multiple chunks.
+    let mut first = [0u8; 2];
+    let mut second = [0u8; 8];
+    let mut chain = (&mut first[..]).chain_mut(&mut second[..]);
+    assert_eq!(lexical::bytes::write(-98765i32, &mut chain), 6);
+    assert_eq!(&first, b"-9");
+    assert_eq!(&second[..4], b"8765");
+}
+
+#[test]
+#[should_panic]
+#[cfg(feature = "write-integers")]
+fn insufficient_capacity_test() {
+    let mut array = [0u8; 2];
+    let mut slc = &mut array[..];
+    lexical::bytes::write(123u32, &mut slc);
+}
+
+#[test]
+#[cfg(feature = "write-integers")]
+fn integer_write_with_options_test() {
+    const FORMAT: u128 = lexical::format::STANDARD;
+    let options = lexical::WriteIntegerOptions::new();
+    let mut buf = BytesMut::new();
+    assert_eq!(lexical::bytes::write_with_options::<_, _, FORMAT>(12345u32, &mut buf, &options), 5);
+    assert_eq!(&buf[..], b"12345");
+
+    // The buffer size for the options is larger than the slice.
+    let options = lexical::WriteIntegerOptions::builder()
+        .min_width(core::num::NonZeroUsize::new(8))
+        .build()
+        .unwrap();
+    let expected = lexical::to_string_with_options::<_, FORMAT>(-42i32, &options);
+    let mut array = [0u8; 10];
+    let mut slc = &mut array[..];
+    assert_eq!(lexical::bytes::write_with_options::<_, _, FORMAT>(-42i32, &mut slc, &options), 8);
+    assert_eq!(&array[..8], expected.as_bytes());
+}
+
+#[test]
+#[cfg(feature = "write-floats")]
+fn float_write_with_options_test() {
+    const FORMAT: u128 = lexical::format::STANDARD;
+    let options = lexical::WriteFloatOptions::builder()
+        .min_significant_digits(core::num::NonZeroUsize::new(1000))
+        .build()
+        .unwrap();
+    let expected = lexical::to_string_with_options::<_, FORMAT>(1.5f64, &options);
+    assert!(expected.len() > lexical::BUFFER_SIZE);
+
+    let mut first = [0u8; 10];
+    let mut second = [0u8; 2000];
+    let mut chain = (&mut first[..]).chain_mut(&mut second[..]);
+    let count = lexical::bytes::write_with_options::<_, _, FORMAT>(1.5f64, &mut chain, &options);
+    assert_eq!(count, expected.len());
+    assert_eq!(&first[..], &expected.as_bytes()[..10]);
+    assert_eq!(&second[..count - 10], &expected.as_bytes()[10..]);
+
+    let mut buf = BytesMut::new();
+    lexical::bytes::write_with_options::<_, _, FORMAT>(1.5f64, &mut buf, &options);
+    assert_eq!(&buf[..], expected.as_bytes());
+}
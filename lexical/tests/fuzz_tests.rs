@@ -0,0 +1,53 @@
+#![cfg(feature = "fuzz")]
+
+use proptest::prelude::*;
+
+#[test]
+#[cfg(all(feature = "parse-integers", feature = "write-integers"))]
+fn integer_test() {
+    for bytes in [&b""[..], b"-", b"+1", b"-128", b"255", b"256", b"1x", b" 1"] {
+        lexical::fuzz::parse::<u8>(bytes);
+        lexical::fuzz::parse::<i8>(bytes);
+    }
+    lexical::fuzz::roundtrip(i128::MIN);
+    lexical::fuzz::roundtrip(u64::MAX);
+}
+
+#[test]
+#[cfg(all(feature = "parse-floats", feature = "write-floats"))]
+fn float_test() {
+    let cases =
+        [&b""[..], b".", b"1.", b".1", b"1e", b"1e-400", b"-inf", b"NaN", b"Infinity", b"1_0"];
+    for bytes in cases {
+        lexical::fuzz::parse::<f32>(bytes);
+        lexical::fuzz::parse::<f64>(bytes);
+    }
+    lexical::fuzz::roundtrip(f64::NAN);
+    lexical::fuzz::roundtrip(-0.0f64);
+    lexical::fuzz::roundtrip(f32::MIN_POSITIVE);
+}
+
+proptest! {
+    #[test]
+    #[cfg(all(feature = "parse-integers", feature = "write-integers"))]
+    fn integer_proptest(bytes in r"[+-]?[0-9]{0,40}[a-z]?", value: i64) {
+        lexical::fuzz::parse::<i64>(bytes.as_bytes());
+        lexical::fuzz::parse::<u32>(bytes.as_bytes());
+        lexical::fuzz::roundtrip(value);
+    }
+
+    #[test]
+    #[cfg(all(feature = "parse-floats", feature = "write-floats"))]
+    fn float_proptest(bytes in r"[+-]?[0-9]{0,30}(\.[0-9]{0,30})?([eE][+-]?[0-9]{0,4})?", value: f64) {
+        lexical::fuzz::parse::<f64>(bytes.as_bytes());
+        lexical::fuzz::parse::<f32>(bytes.as_bytes());
+        lexical::fuzz::roundtrip(value);
+    }
+
+    #[test]
+    #[cfg(all(feature = "parse-floats", feature = "write-floats"))]
+    fn bytes_proptest(bytes: Vec<u8>) {
+        lexical::fuzz::parse::<f64>(&bytes);
+        lexical::fuzz::parse::<i16>(&bytes);
+    }
+}
@@ -0,0 +1,91 @@
+#![cfg(feature = "heapless")]
+
+use heapless::String;
+use lexical::BufferTooSmall;
+
+#[test]
+#[cfg(feature = "write-integers")]
+fn integer_write_test() {
+    let mut string = String::<8>::new();
+    assert_eq!(lexical::heapless::write(12345u32, &mut string), Ok(5));
+    assert_eq!(lexical::heapless::write(-12i8, &mut string), Ok(3));
+    assert_eq!(string.as_str(), "12345-12");
+    assert_eq!(
+        lexical::heapless::write(1u8, &mut string),
+        Err(BufferTooSmall {
+            max_needed: 1,
+        })
+    );
+    assert_eq!(string.as_str(), "12345-12");
+
+    let string = lexical::heapless::to_string::<_, 20>(u64::MAX).unwrap();
+    assert_eq!(string.as_str(), "18446744073709551615");
+    assert_eq!(
+        lexical::heapless::to_string::<_, 19>(u64::MAX),
+        Err(BufferTooSmall {
+            max_needed: 20,
+        })
+    );
+}
+
+#[test]
+#[cfg(feature = "write-floats")]
+fn float_write_test() {
+    let mut string = String::<16>::new();
+    string.push_str("x=").unwrap();
+    assert_eq!(lexical::heapless::write(-1.5e300f64, &mut string), Ok(8));
+    assert_eq!(string.as_str(), "x=-1.5e300");
+
+    let string = lexical::heapless::to_string::<_, 7>(12345.0f32).unwrap();
+    assert_eq!(string.as_str(), "12345.0");
+    assert_eq!(
+        lexical::heapless::to_string::<_, 6>(12345.0f32),
+        Err(BufferTooSmall {
+            max_needed: 7,
+        })
+    );
+}
+
+#[test]
+#[cfg(feature = "write-integers")]
+fn integer_write_with_options_test() {
+    const FORMAT: u128 = lexical::format::STANDARD;
+    let options = lexical::WriteIntegerOptions::new();
+    let mut string = String::<4>::new();
+    let result =
+        lexical::heapless::write_with_options::<_, 4, FORMAT>(1234u32, &mut string, &options);
+    assert_eq!(result, Ok(4));
+    assert_eq!(string.as_str(), "1234");
+
+    let string = lexical::heapless::to_string_with_options::<_, 3, FORMAT>(1234u32, &options);
+    assert_eq!(
+        string,
+        Err(BufferTooSmall {
+            max_needed: 4,
+        })
+    );
+}
+
+#[test]
+#[cfg(feature = "write-floats")]
+fn float_write_with_options_test() {
+    const FORMAT: u128 = lexical::format::STANDARD;
+    let options = lexical::WriteFloatOptions::new();
+    let string = lexical::heapless::to_string_with_options::<_, 8, FORMAT>(1.5f64, &options);
+    assert_eq!(string.unwrap().as_str(), "1.5");
+
+    // The options require more bytes than the stack buffer.
+    let options = lexical::WriteFloatOptions::builder()
+        .min_significant_digits(core::num::NonZeroUsize::new(1000))
+        .build()
+        .unwrap();
+    let size = lexical::WriteOptions::buffer_size::<f64, FORMAT>(&options);
+    assert!(size > lexical::BUFFER_SIZE);
+    let string = lexical::heapless::to_string_with_options::<_, 2000, FORMAT>(1.5f64, &options);
+    assert_eq!(
+        string,
+        Err(BufferTooSmall {
+            max_needed: size,
+        })
+    );
+}
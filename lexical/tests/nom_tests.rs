@@ -0,0 +1,99 @@
+#![cfg(feature = "nom")]
+
+use lexical::ErrorKind;
+use nom::bytes::complete::tag;
+use nom::error::{Error, ErrorKind as NomErrorKind, FromExternalError, ParseError};
+use nom::sequence::{delimited, separated_pair};
+use nom::IResult;
+
+/// Error type that retains the lexical error.
+#[derive(Debug, PartialEq)]
+struct LexicalError<'a> {
+    input: &'a [u8],
+    error: Option<lexical::Error>,
+}
+
+impl<'a> ParseError<&'a [u8]> for LexicalError<'a> {
+    fn from_error_kind(input: &'a [u8], _: NomErrorKind) -> Self {
+        Self {
+            input,
+            error: None,
+        }
+    }
+
+    fn append(_: &'a [u8], _: NomErrorKind, other: Self) -> Self {
+        other
+    }
+}
+
+impl<'a> FromExternalError<&'a [u8], lexical::Error> for LexicalError<'a> {
+    fn from_external_error(input: &'a [u8], _: NomErrorKind, error: lexical::Error) -> Self {
+        Self {
+            input,
+            error: Some(error),
+        }
+    }
+}
+
+#[test]
+#[cfg(feature = "parse-integers")]
+fn integer_test() {
+    let result: IResult<&[u8], u8> = lexical::nom::u8(b"255;");
+    assert_eq!(result, Ok((&b";"[..], 255)));
+
+    let result: IResult<&str, i64> = lexical::nom::i64("-12 34");
+    assert_eq!(result, Ok((" 34", -12)));
+
+    let result: IResult<&[u8], u8> = lexical::nom::u8(b"x");
+    assert_eq!(result, Err(nom::Err::Error(Error::new(&b"x"[..], NomErrorKind::Digit))));
+
+    let result: IResult<&[u8], i8> = lexical::nom::i8(b"-x");
+    assert_eq!(result, Err(nom::Err::Error(Error::new(&b"-x"[..], NomErrorKind::Digit))));
+
+    let result: IResult<&[u8], u8, LexicalError> = lexical::nom::u8(b"256");
+    let error = match result {
+        Err(nom::Err::Error(error)) => error,
+        _ => panic!("expected an error"),
+    };
+    assert_eq!(error.input, b"256");
+    assert_eq!(error.error.map(|x| x.kind()), Some(ErrorKind::Overflow));
+}
+
+#[test]
+#[cfg(feature = "parse-floats")]
+fn float_test() {
+    let result: IResult<&[u8], f64> = lexical::nom::f64(b"1.5e3]");
+    assert_eq!(result, Ok((&b"]"[..], 1500.0)));
+
+    let result: IResult<&str, f32> = lexical::nom::parse("0.1");
+    assert_eq!(result, Ok(("", 0.1)));
+
+    let result: IResult<&str, f64> = lexical::nom::f64("e5");
+    assert_eq!(result, Err(nom::Err::Error(Error::new("e5", NomErrorKind::Float))));
+}
+
+#[test]
+#[cfg(feature = "parse-floats")]
+fn combinator_test() {
+    fn point(input: &str) -> IResult<&str, (f64, f64)> {
+        delimited(
+            tag("("),
+            separated_pair(lexical::nom::f64, tag(","), lexical::nom::f64),
+            tag(")"),
+        )(input)
+    }
+
+    assert_eq!(point("(1,-2.5)"), Ok(("", (1.0, -2.5))));
+    assert_eq!(point("(1,-2.5) rest"), Ok((" rest", (1.0, -2.5))));
+    assert!(point("(1,x)").is_err());
+}
+
+#[test]
+#[cfg(all(feature = "parse-integers", feature = "format"))]
+fn options_test() {
+    use lexical::{format::STANDARD, ParseIntegerOptions};
+
+    let options = ParseIntegerOptions::new();
+    let mut parser = lexical::nom::parse_with_options::<_, u32, Error<_>, STANDARD>(&options);
+    assert_eq!(parser("42 "), Ok((" ", 42)));
+}
@@ -0,0 +1,53 @@
+#![cfg(all(feature = "serde", feature = "std"))]
+
+use serde_json::{Deserializer, Serializer};
+
+fn serialize<F>(cb: F) -> String
+where
+    F: FnOnce(&mut Serializer<&mut Vec<u8>>) -> serde_json::Result<()>,
+{
+    let mut bytes = Vec::new();
+    cb(&mut Serializer::new(&mut bytes)).unwrap();
+    String::from_utf8(bytes).unwrap()
+}
+
+#[test]
+#[cfg(feature = "write-integers")]
+fn serialize_integer_test() {
+    assert_eq!(
+        serialize(|s| lexical::serde::u64::serialize(&u64::MAX, s)),
+        "\"18446744073709551615\""
+    );
+    assert_eq!(serialize(|s| lexical::serde::i8::serialize(&-128, s)), "\"-128\"");
+}
+
+#[test]
+#[cfg(feature = "write-floats")]
+fn serialize_float_test() {
+    assert_eq!(serialize(|s| lexical::serde::f64::serialize(&0.1, s)), "\"0.1\"");
+    assert_eq!(serialize(|s| lexical::serde::f32::serialize(&-1e20, s)), "\"-1.0e20\"");
+}
+
+#[test]
+#[cfg(feature = "parse-integers")]
+fn deserialize_integer_test() {
+    let mut de = Deserializer::from_str("\"18446744073709551615\"");
+    assert_eq!(lexical::serde::u64::deserialize(&mut de).unwrap(), u64::MAX);
+
+    let mut de = Deserializer::from_str("\"256\"");
+    assert!(lexical::serde::u8::deserialize(&mut de).is_err());
+
+    let mut de = Deserializer::from_str("12");
+    assert!(lexical::serde::u8::deserialize(&mut de).is_err());
+}
+
+#[test]
+#[cfg(feature = "parse-floats")]
+fn deserialize_float_test() {
+    let mut de = Deserializer::from_str("\"1.5e3\"");
+    assert_eq!(lexical::serde::f64::deserialize(&mut de).unwrap(), 1500.0);
+
+    let mut de = Deserializer::from_str("\"1.5x\"");
+    let error = lexical::serde::f32::deserialize(&mut de).unwrap_err();
+    assert!(error.to_string().contains("invalid digit found"));
+}
@@ -10,6 +10,8 @@ cd "${script_home}"/..
 
 WORKSPACES=(
     "lexical"
+    "lexical-capi"
+    "lexical-macros"
     "lexical-core"
     "lexical-parse-float"
     "lexical-parse-integer"